@@ -1,8 +1,6 @@
 use dotenvy::dotenv;
-use futures_util::{SinkExt, StreamExt};
-use kis_api::{KisClient, KisEnv};
+use kis_api::{KisClient, KisEnv, KisEvent, OverseasRealtimeKey, SubscriptionKind};
 use std::env;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -12,49 +10,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("--- Initializing Real Client for WebSocket ---");
     let client = KisClient::new(&app_key, &app_secret, KisEnv::Real).await?;
-    let approval_key = client.approval_key().await?;
-    println!("Approval Key: {}", approval_key);
 
-    let ws_url = "ws://ops.koreainvestment.com:21000";
-    println!("Connecting to: {}", ws_url);
-
-    let (mut ws_stream, _) = connect_async(ws_url).await?;
+    println!("Connecting to: {}", client.ws_url());
+    let stream = client.stream().await?;
     println!("Connected.");
 
-    // Subscribe to NVDA with CORRECT Real Key: DNAS + NVDA
-    let sub_msg = serde_json::json!({
-        "header": {
-            "approval_key": approval_key,
-            "custtype": "P",
-            "tr_type": "1",
-            "content-type": "utf-8"
-        },
-        "body": {
-            "input": {
-                "tr_id": "HDFSCNT0",
-                "tr_key": "DNASNVDA"
-            }
-        }
-    });
-
-    ws_stream
-        .send(Message::Text(sub_msg.to_string().into()))
+    // 해외 실시간 tr_key: D + 거래소(NAS) + 종목코드
+    let key = OverseasRealtimeKey::delayed("NAS", "NVDA");
+    stream
+        .subscribe_overseas(&key, SubscriptionKind::Price)
         .await?;
-    println!("Subscribed to NVDA (DNASNVDA)");
+    println!("Subscribed to NVDA ({key})");
 
+    let mut rx = stream.receiver();
     println!("Waiting for ticks (10 seconds)...");
     let timeout = tokio::time::sleep(std::time::Duration::from_secs(10));
     tokio::pin!(timeout);
 
     loop {
         tokio::select! {
-            msg = ws_stream.next() => {
-                if let Some(Ok(Message::Text(text))) = msg {
-                    println!("\n[RAW WS DATA RECEIVED] {}", text);
-                    if text.contains('|') {
-                        println!("SUCCESS: Real-time data is arriving!");
-                        return Ok(());
-                    }
+            event = rx.recv() => {
+                if let Ok(KisEvent::Transaction(tx)) = event {
+                    println!("\n[TICK] {} {} x {} @ {}", tx.symbol, tx.price, tx.qty, tx.time);
+                    println!("SUCCESS: Real-time data is arriving!");
+                    break;
                 }
             }
             _ = &mut timeout => {
@@ -64,5 +43,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    stream.close();
     Ok(())
 }
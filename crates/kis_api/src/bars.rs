@@ -0,0 +1,745 @@
+//! 실시간 체결 → OHLCV 봉 집계.
+//!
+//! [`BarAggregator`]는 체결 이벤트(`Transaction`, `DerivativeTrade`, `OverseasDerivativeTrade`)를
+//! 종목별로 시간·거래량·틱 봉으로 묶는다. 분봉 조회 결과로 [`seed`](BarAggregator::seed)하면
+//! 진행 중인 봉이 과거 분봉에서 이어진다.
+//!
+//! ```no_run
+//! # async fn run(client: kis_api::KisClient, stream: kis_api::KisStream) -> Result<(), kis_api::KisError> {
+//! use kis_api::bars::{domestic_minute_bars, BarAggregator, BarInterval};
+//! use kis_api::DomesticStockV1QuotationsInquireTimeItemchartpriceRequest;
+//!
+//! let history = client
+//!     .stock()
+//!     .quotations()
+//!     .domestic_stock_v1_quotations_inquire_time_itemchartprice(
+//!         DomesticStockV1QuotationsInquireTimeItemchartpriceRequest {
+//!             fid_cond_mrkt_div_code: "J".into(),
+//!             fid_input_iscd: "005930".into(),
+//!             fid_input_hour_1: "153000".into(),
+//!             fid_pw_data_incu_yn: "N".into(),
+//!             ..Default::default()
+//!         },
+//!     )
+//!     .await?;
+//!
+//! let mut bars = BarAggregator::new([BarInterval::Minutes(5)]);
+//! let backfilled = bars.seed("005930", &domestic_minute_bars("005930", &history));
+//! let mut rx = bars.spawn(stream.receiver());
+//! while let Some(bar) = rx.recv().await {
+//!     println!("{bar:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Timelike, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::error::KisError;
+use crate::event::{DerivativeProduct, KisEvent, MarketPhase};
+use crate::stream::EventReceiver;
+
+/// 시간 봉을 닫기 전에 늦게 도착하는 체결을 기다리는 시간
+const DEFAULT_CLOSE_DELAY: Duration = Duration::from_secs(2);
+
+/// [`BarAggregator::spawn`]이 시간 봉 마감을 확인하는 주기
+const CLOSE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// KRX 야간 거래(18:00~다음날 05:00)를 시작일 세션으로 묶기 위해 체결 시각에서 빼는 시간
+const NIGHT_SESSION_SHIFT: chrono::TimeDelta = chrono::TimeDelta::hours(12);
+
+/// 봉 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BarInterval {
+    /// N초 봉
+    Seconds(u32),
+    /// N분 봉
+    Minutes(u32),
+    /// 누적 체결량이 N 이상이 되면 닫는 봉. 넘친 체결도 나누지 않고 그 봉에 넣는다
+    Volume(Decimal),
+    /// 체결 N건마다 닫는 봉
+    Ticks(u32),
+}
+
+impl BarInterval {
+    /// 시간 봉의 길이 (초)
+    fn seconds(self) -> Option<i64> {
+        match self {
+            BarInterval::Seconds(n) => Some(i64::from(n.max(1))),
+            BarInterval::Minutes(n) => Some(i64::from(n.max(1)) * 60),
+            BarInterval::Volume(_) | BarInterval::Ticks(_) => None,
+        }
+    }
+
+    /// 시간 봉인지
+    pub fn is_time(self) -> bool {
+        self.seconds().is_some()
+    }
+}
+
+/// OHLCV 봉
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    pub symbol: String,
+    pub interval: BarInterval,
+    /// 시간 봉은 구간 시작, 거래량·틱 봉은 첫 체결 시각
+    pub start: DateTime<FixedOffset>,
+    /// 시간 봉은 구간 끝, 거래량·틱 봉은 마지막 체결 시각
+    pub end: DateTime<FixedOffset>,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    /// 체결 건수 (분봉 조회로 채운 부분은 세지 않는다)
+    pub ticks: u64,
+}
+
+impl Bar {
+    fn new(
+        symbol: &str,
+        interval: BarInterval,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            interval,
+            start,
+            end,
+            open: Decimal::ZERO,
+            high: Decimal::ZERO,
+            low: Decimal::ZERO,
+            close: Decimal::ZERO,
+            volume: Decimal::ZERO,
+            ticks: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ticks == 0 && self.open.is_zero()
+    }
+
+    fn add_trade(&mut self, time: DateTime<FixedOffset>, price: Decimal, qty: Decimal) {
+        self.add_range(price, price, price, price, qty);
+        self.ticks += 1;
+        if !self.interval.is_time() {
+            self.end = time;
+        }
+    }
+
+    fn add_range(
+        &mut self,
+        open: Decimal,
+        high: Decimal,
+        low: Decimal,
+        close: Decimal,
+        volume: Decimal,
+    ) {
+        if self.is_empty() {
+            self.open = open;
+            self.high = high;
+            self.low = low;
+        } else {
+            self.high = self.high.max(high);
+            self.low = self.low.min(low);
+        }
+        self.close = close;
+        self.volume += volume;
+    }
+
+    /// 거래량·틱 봉이 가득 찼는지
+    fn is_full(&self) -> bool {
+        match self.interval {
+            BarInterval::Volume(limit) => self.volume >= limit,
+            BarInterval::Ticks(limit) => self.ticks >= u64::from(limit.max(1)),
+            BarInterval::Seconds(_) | BarInterval::Minutes(_) => false,
+        }
+    }
+}
+
+/// `time`이 속한 시간 봉 구간의 시작. 구간은 현지 자정부터 센다
+fn bucket_start(time: DateTime<FixedOffset>, seconds: i64) -> DateTime<FixedOffset> {
+    let since_midnight = i64::from(time.num_seconds_from_midnight());
+    time.with_nanosecond(0).unwrap_or(time) - chrono::Duration::seconds(since_midnight % seconds)
+}
+
+/// 체결이 속한 세션(영업일). 바뀌면 거래량·틱 봉도 닫는다.
+///
+/// 국내·해외주식과 주간 선물옵션은 현지 날짜 안에서 장이 끝나므로 체결 날짜,
+/// KRX 야간 선물옵션은 자정을 넘겨도 시작일, 해외선물옵션은 거래소 영업일자다.
+fn trade_session(event: &KisEvent) -> Option<NaiveDate> {
+    match event {
+        KisEvent::Transaction(d) => Some(d.time.date_naive()),
+        KisEvent::DerivativeTrade(d) => match d.product {
+            DerivativeProduct::NightFuture | DerivativeProduct::NightOption => {
+                Some((d.time - NIGHT_SESSION_SHIFT).date_naive())
+            }
+            _ => Some(d.time.date_naive()),
+        },
+        KisEvent::OverseasDerivativeTrade(d) => d.business_date,
+        _ => None,
+    }
+}
+
+/// 종목·봉 단위별 상태
+#[derive(Default)]
+struct Slot {
+    open: Option<Bar>,
+    /// 진행 중인 봉의 세션 ([`trade_session`])
+    session: Option<NaiveDate>,
+    /// 마지막으로 닫은 시간 봉의 끝. 이보다 이른 체결은 늦게 온 것으로 보고 버린다
+    closed_until: Option<DateTime<FixedOffset>>,
+}
+
+impl Slot {
+    fn close(&mut self) -> Option<Bar> {
+        let bar = self.open.take()?;
+        if bar.interval.is_time() {
+            self.closed_until = Some(bar.end);
+        }
+        Some(bar)
+    }
+
+    /// 분봉 채우기에서 완성된 봉을 닫힌 것으로 기록
+    fn close_with(&mut self, bar: Option<Bar>) -> Option<Bar> {
+        let bar = bar?;
+        self.closed_until = Some(bar.end);
+        Some(bar)
+    }
+}
+
+/// 체결 이벤트 → 봉 집계기.
+///
+/// 봉은 다음 구간의 체결이 오거나, [`close_due`](Self::close_due)에서 구간이 끝났거나,
+/// 세션(영업일)이 바뀌거나, 장운영정보가 장종료(`MarketPhase::Closed`)를 알리면 닫힌다.
+/// 야간 선물옵션처럼 자정을 넘기는 세션은 날짜가 바뀌어도 이어진다.
+pub struct BarAggregator {
+    intervals: Vec<BarInterval>,
+    close_delay: Duration,
+    slots: HashMap<(String, BarInterval), Slot>,
+}
+
+impl BarAggregator {
+    pub fn new(intervals: impl IntoIterator<Item = BarInterval>) -> Self {
+        Self {
+            intervals: intervals.into_iter().collect(),
+            close_delay: DEFAULT_CLOSE_DELAY,
+            slots: HashMap::new(),
+        }
+    }
+
+    /// 구간이 끝난 시간 봉을 닫기 전 기다리는 시간 (기본 2초)
+    pub fn close_delay(mut self, delay: Duration) -> Self {
+        self.close_delay = delay;
+        self
+    }
+
+    /// 진행 중인 봉
+    pub fn current(&self, symbol: &str, interval: BarInterval) -> Option<&Bar> {
+        self.slots
+            .get(&(symbol.to_string(), interval))
+            .and_then(|slot| slot.open.as_ref())
+    }
+
+    /// 과거 봉(분봉 조회 결과 등)으로 시간 봉을 채운다.
+    ///
+    /// 완성된 봉은 시간순으로 돌려주고, 마지막 구간은 진행 중인 봉으로 남아 실시간 체결이
+    /// 이어 붙는다. 원본보다 짧은 봉 단위와 거래량·틱 봉은 채우지 않는다.
+    /// 실시간 체결을 넣기 전에 호출해야 한다.
+    pub fn seed(&mut self, symbol: &str, history: &[Bar]) -> Vec<Bar> {
+        let mut history: Vec<&Bar> = history.iter().collect();
+        history.sort_by_key(|bar| bar.start);
+        let mut completed = Vec::new();
+
+        for interval in self.intervals.clone() {
+            let Some(seconds) = interval.seconds() else {
+                continue;
+            };
+            let slot = self
+                .slots
+                .entry((symbol.to_string(), interval))
+                .or_default();
+            if slot.open.is_some() {
+                continue;
+            }
+            let mut current: Option<Bar> = None;
+            for source in &history {
+                if source.interval.seconds().is_none_or(|s| s > seconds) {
+                    continue;
+                }
+                let start = bucket_start(source.start, seconds);
+                if current.as_ref().is_some_and(|bar| bar.start != start) {
+                    completed.extend(slot.close_with(current.take()));
+                }
+                current
+                    .get_or_insert_with(|| {
+                        Bar::new(
+                            symbol,
+                            interval,
+                            start,
+                            start + chrono::Duration::seconds(seconds),
+                        )
+                    })
+                    .add_range(
+                        source.open,
+                        source.high,
+                        source.low,
+                        source.close,
+                        source.volume,
+                    );
+            }
+            slot.open = current;
+        }
+        completed.sort_by_key(|bar| bar.end);
+        completed
+    }
+
+    /// 이벤트를 반영하고 닫힌 봉을 돌려준다
+    pub fn on_event(&mut self, event: &KisEvent) -> Vec<Bar> {
+        let session = trade_session(event);
+        match event {
+            KisEvent::Transaction(d) => self.on_trade(&d.symbol, session, d.time, d.price, d.qty),
+            KisEvent::DerivativeTrade(d) => {
+                self.on_trade(&d.symbol, session, d.time, d.price, d.qty)
+            }
+            KisEvent::OverseasDerivativeTrade(d) => {
+                self.on_trade(&d.symbol, session, d.time, d.price, d.qty)
+            }
+            KisEvent::MarketStatus(d) if d.phase == MarketPhase::Closed => {
+                self.close_symbol(&d.symbol)
+            }
+            _ => vec![],
+        }
+    }
+
+    /// 체결 한 건 반영. `session`(영업일)이 진행 중인 봉과 다르면 그 봉을 닫고 시작한다.
+    /// `None`이면 세션 경계를 보지 않는다.
+    pub fn on_trade(
+        &mut self,
+        symbol: &str,
+        session: Option<NaiveDate>,
+        time: DateTime<FixedOffset>,
+        price: Decimal,
+        qty: Decimal,
+    ) -> Vec<Bar> {
+        let mut closed = Vec::new();
+        for &interval in &self.intervals {
+            let slot = self
+                .slots
+                .entry((symbol.to_string(), interval))
+                .or_default();
+            if slot.closed_until.is_some_and(|until| time < until) {
+                tracing::debug!(target: "kis_api", "[{symbol}] 이미 닫힌 봉 구간의 체결 무시 ({time})");
+                continue;
+            }
+            // 세션이 바뀌면 거래량·틱 봉도 닫는다. 분봉으로 채운 봉은 첫 체결의 세션을 따른다
+            if session.is_some()
+                && slot.open.is_some()
+                && slot.session.is_some_and(|s| Some(s) != session)
+            {
+                closed.extend(slot.close());
+            }
+            if session.is_some() {
+                slot.session = session;
+            }
+            if let Some(seconds) = interval.seconds() {
+                let start = bucket_start(time, seconds);
+                if slot.open.as_ref().is_some_and(|bar| bar.start != start) {
+                    closed.extend(slot.close());
+                }
+                slot.open
+                    .get_or_insert_with(|| {
+                        Bar::new(
+                            symbol,
+                            interval,
+                            start,
+                            start + chrono::Duration::seconds(seconds),
+                        )
+                    })
+                    .add_trade(time, price, qty);
+            } else {
+                let bar = slot
+                    .open
+                    .get_or_insert_with(|| Bar::new(symbol, interval, time, time));
+                bar.add_trade(time, price, qty);
+                if bar.is_full() {
+                    closed.extend(slot.close());
+                }
+            }
+        }
+        closed
+    }
+
+    /// 구간이 끝나고 `close_delay`가 지난 시간 봉을 닫는다 (체결이 끊긴 종목용)
+    pub fn close_due(&mut self, now: DateTime<Utc>) -> Vec<Bar> {
+        let delay = chrono::Duration::from_std(self.close_delay).unwrap_or_default();
+        let mut closed: Vec<Bar> = self
+            .slots
+            .values_mut()
+            .filter(|slot| {
+                slot.open
+                    .as_ref()
+                    .is_some_and(|bar| bar.interval.is_time() && bar.end + delay <= now)
+            })
+            .filter_map(Slot::close)
+            .collect();
+        closed.sort_by_key(|bar| bar.end);
+        closed
+    }
+
+    /// 종목의 진행 중인 봉을 모두 닫는다 (세션 종료)
+    pub fn close_symbol(&mut self, symbol: &str) -> Vec<Bar> {
+        let mut closed: Vec<Bar> = self
+            .slots
+            .iter_mut()
+            .filter(|((s, _), _)| s == symbol)
+            .filter_map(|(_, slot)| slot.close())
+            .collect();
+        closed.sort_by_key(|bar| bar.end);
+        closed
+    }
+
+    /// `events`를 소비하며 닫힌 봉을 보내는 태스크를 띄운다.
+    ///
+    /// 스트림이 닫히거나 반환된 수신기를 drop하면 끝난다. 진행 중인 봉은 보내지 않는다.
+    ///
+    /// 시간 봉 마감의 기준 시각은 벽시계가 아니라 마지막 체결 시각에 그 뒤 흐른 시간을
+    /// 더한 값이다. 기록 재생(`recorder::Replayer`)에도 그대로 쓸 수 있다.
+    pub fn spawn(mut self, mut events: EventReceiver) -> mpsc::UnboundedReceiver<Bar> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(CLOSE_CHECK_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            // (가장 늦은 체결 시각, 그 체결을 받은 순간)
+            let mut clock: Option<(DateTime<Utc>, tokio::time::Instant)> = None;
+            loop {
+                let bars = tokio::select! {
+                    event = events.recv() => match event {
+                        Ok(event) => {
+                            if let Some(time) = trade_time(&event) {
+                                if clock.is_none_or(|(latest, _)| time > latest) {
+                                    clock = Some((time, tokio::time::Instant::now()));
+                                }
+                            }
+                            self.on_event(&event)
+                        }
+                        Err(KisError::Lagged(n)) => {
+                            tracing::warn!(target: "kis_api", "봉 집계가 체결 {n}건을 놓쳤습니다");
+                            continue;
+                        }
+                        Err(_) => break,
+                    },
+                    _ = ticker.tick() => match clock {
+                        Some((latest, seen)) => self.close_due(
+                            latest + chrono::Duration::from_std(seen.elapsed()).unwrap_or_default(),
+                        ),
+                        None => vec![],
+                    },
+                };
+                for bar in bars {
+                    if tx.send(bar).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        rx
+    }
+}
+
+/// 체결 이벤트의 시각
+fn trade_time(event: &KisEvent) -> Option<DateTime<Utc>> {
+    let time = match event {
+        KisEvent::Transaction(d) => d.time,
+        KisEvent::DerivativeTrade(d) => d.time,
+        KisEvent::OverseasDerivativeTrade(d) => d.time,
+        _ => return None,
+    };
+    Some(time.with_timezone(&Utc))
+}
+
+/// 국내 주식당일분봉조회 결과 → 1분봉. 체결시간을 봉 시작으로 본다
+#[cfg(feature = "domestic-stock")]
+pub fn domestic_minute_bars(
+    symbol: &str,
+    response: &crate::models::DomesticStockV1QuotationsInquireTimeItemchartpriceResponse,
+) -> Vec<Bar> {
+    use std::str::FromStr;
+
+    response
+        .output2
+        .iter()
+        .filter_map(|row| {
+            let start = crate::stream::kst_at(&row.stck_bsop_date, &row.stck_cntg_hour)?;
+            let mut bar = Bar::new(
+                symbol,
+                BarInterval::Minutes(1),
+                start,
+                start + chrono::Duration::minutes(1),
+            );
+            bar.add_range(
+                Decimal::from_str(&row.stck_oprc).ok()?,
+                Decimal::from_str(&row.stck_hgpr).ok()?,
+                Decimal::from_str(&row.stck_lwpr).ok()?,
+                Decimal::from_str(&row.stck_prpr).ok()?,
+                row.cntg_vol,
+            );
+            Some(bar)
+        })
+        .collect()
+}
+
+/// 해외주식분봉조회 결과 → `minutes`분봉 (요청의 `NMIN`). 현지 시각 기준
+#[cfg(feature = "overseas-stock")]
+pub fn overseas_minute_bars(
+    symbol: &str,
+    minutes: u32,
+    response: &crate::models::OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse,
+) -> Vec<Bar> {
+    use std::str::FromStr;
+
+    let interval = BarInterval::Minutes(minutes.max(1));
+    response
+        .output2
+        .iter()
+        .filter_map(|row| {
+            let start =
+                crate::stream::exchange_local_at(&row.xymd, &row.xhms, &row.kymd, &row.khms)?;
+            let mut bar = Bar::new(
+                symbol,
+                interval,
+                start,
+                start + chrono::Duration::seconds(interval.seconds()?),
+            );
+            bar.add_range(
+                Decimal::from_str(&row.open).ok()?,
+                Decimal::from_str(&row.high).ok()?,
+                Decimal::from_str(&row.low).ok()?,
+                Decimal::from_str(&row.last).ok()?,
+                Decimal::from_str(&row.evol).unwrap_or_default(),
+            );
+            Some(bar)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::TransactionData;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn at(h: u32, m: u32, s: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(9 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 3, 20, h, m, s)
+            .unwrap()
+    }
+
+    fn trade(time: DateTime<FixedOffset>, price: Decimal, qty: Decimal) -> KisEvent {
+        KisEvent::Transaction(TransactionData {
+            symbol: "005930".into(),
+            overseas_key: None,
+            price,
+            qty,
+            time,
+            is_buy: true,
+        })
+    }
+
+    #[test]
+    fn time_bars_close_on_next_bucket_and_after_delay() {
+        let mut bars = BarAggregator::new([BarInterval::Minutes(1)]);
+        assert!(bars
+            .on_event(&trade(at(9, 0, 5), dec!(75000), dec!(10)))
+            .is_empty());
+        assert!(bars
+            .on_event(&trade(at(9, 0, 40), dec!(75300), dec!(5)))
+            .is_empty());
+        bars.on_event(&trade(at(9, 0, 50), dec!(74900), dec!(1)));
+
+        let closed = bars.on_event(&trade(at(9, 1, 2), dec!(75100), dec!(3)));
+        assert_eq!(closed.len(), 1);
+        let bar = &closed[0];
+        assert_eq!((bar.start, bar.end), (at(9, 0, 0), at(9, 1, 0)));
+        assert_eq!(
+            (bar.open, bar.high, bar.low, bar.close),
+            (dec!(75000), dec!(75300), dec!(74900), dec!(74900))
+        );
+        assert_eq!((bar.volume, bar.ticks), (dec!(16), 3));
+
+        // 체결이 끊겨도 구간 끝 + 지연이 지나면 닫는다
+        assert!(bars.close_due(at(9, 2, 1).with_timezone(&Utc)).is_empty());
+        let closed = bars.close_due(at(9, 2, 2).with_timezone(&Utc));
+        assert_eq!(closed[0].start, at(9, 1, 0));
+        // 닫힌 구간에 늦게 온 체결은 버린다
+        assert!(bars
+            .on_event(&trade(at(9, 1, 59), dec!(75200), dec!(1)))
+            .is_empty());
+        assert!(bars.current("005930", BarInterval::Minutes(1)).is_none());
+    }
+
+    #[test]
+    fn volume_and_tick_bars_close_on_threshold_and_session_end() {
+        let volume = BarInterval::Volume(dec!(10));
+        let ticks = BarInterval::Ticks(2);
+        let mut bars = BarAggregator::new([volume, ticks]);
+
+        assert!(bars
+            .on_event(&trade(at(9, 0, 0), dec!(100), dec!(4)))
+            .is_empty());
+        let closed = bars.on_event(&trade(at(9, 0, 3), dec!(101), dec!(7)));
+        assert_eq!(closed.len(), 2);
+        let by_volume = closed.iter().find(|b| b.interval == volume).unwrap();
+        assert_eq!((by_volume.volume, by_volume.end), (dec!(11), at(9, 0, 3)));
+
+        bars.on_event(&trade(at(15, 20, 0), dec!(102), dec!(1)));
+        let mut status = crate::event::MarketStatusEvent {
+            symbol: "005930".into(),
+            venue: crate::event::Venue::Krx,
+            halted: false,
+            halt_reason: None,
+            phase: MarketPhase::Continuous,
+            expected_phase_code: String::new(),
+            vi: crate::event::ViStatus::Released,
+            overtime_vi: crate::event::ViStatus::Released,
+            status_code: String::new(),
+            time: at(15, 30, 0),
+        };
+        assert!(bars
+            .on_event(&KisEvent::MarketStatus(status.clone()))
+            .is_empty());
+        status.phase = MarketPhase::Closed;
+        let closed = bars.on_event(&KisEvent::MarketStatus(status));
+        assert_eq!(closed.len(), 2);
+        assert!(closed.iter().all(|b| b.close == dec!(102)));
+    }
+
+    #[test]
+    fn night_session_bars_continue_past_midnight() {
+        let night = |d: u32, h: u32, m: u32, price: Decimal| {
+            KisEvent::DerivativeTrade(crate::event::DerivativeTradeData {
+                symbol: "A05W09".into(),
+                product: DerivativeProduct::NightFuture,
+                price,
+                qty: dec!(1),
+                change: Decimal::ZERO,
+                change_rate: Decimal::ZERO,
+                accumulated_volume: Decimal::ZERO,
+                open_interest: Decimal::ZERO,
+                open_interest_change: Decimal::ZERO,
+                theoretical_price: None,
+                market_basis: None,
+                theoretical_basis: None,
+                ask_price: None,
+                bid_price: None,
+                greeks: None,
+                time: FixedOffset::east_opt(9 * 3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2026, 3, d, h, m, 0)
+                    .unwrap(),
+            })
+        };
+        let ticks = BarInterval::Ticks(3);
+        let mut bars = BarAggregator::new([ticks]);
+
+        // 야간 세션(18:00~05:00)은 자정을 넘겨도 한 봉으로 이어진다
+        assert!(bars.on_event(&night(20, 23, 59, dec!(350))).is_empty());
+        assert!(bars.on_event(&night(21, 0, 1, dec!(351))).is_empty());
+        assert_eq!(bars.current("A05W09", ticks).unwrap().ticks, 2);
+
+        // 다음 야간 세션의 첫 체결이 오면 지난 세션의 봉을 닫는다
+        let closed = bars.on_event(&night(21, 18, 0, dec!(352)));
+        assert_eq!(closed.len(), 1);
+        assert_eq!((closed[0].ticks, closed[0].close), (2, dec!(351)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn spawned_aggregator_closes_by_event_time() {
+        let (stream, injector) = crate::stream::KisStream::test_pair();
+        let mut rx = BarAggregator::new([BarInterval::Minutes(1)]).spawn(stream.receiver());
+        let other = |time| {
+            let KisEvent::Transaction(mut d) = trade(time, dec!(200), dec!(1)) else {
+                unreachable!()
+            };
+            d.symbol = "000660".into();
+            KisEvent::Transaction(d)
+        };
+
+        // 과거 시각의 체결(기록 재생)도 벽시계로 바로 닫지 않는다
+        injector.send(trade(at(9, 0, 5), dec!(100), dec!(1))).await;
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert!(rx.try_recv().is_err());
+
+        // 다른 종목의 체결로 시각이 구간 끝 + 지연을 넘기면 닫는다
+        injector.send(other(at(9, 1, 10))).await;
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        let bar = rx.recv().await.unwrap();
+        assert_eq!((bar.symbol.as_str(), bar.start), ("005930", at(9, 0, 0)));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn seeded_minute_bars_join_live_bars() {
+        let five = BarInterval::Minutes(5);
+        let mut bars = BarAggregator::new([five, BarInterval::Ticks(10)]);
+        let minute = |m: u32, price: Decimal| {
+            let mut bar = Bar::new(
+                "005930",
+                BarInterval::Minutes(1),
+                at(9, m, 0),
+                at(9, m + 1, 0),
+            );
+            bar.add_range(price, price + dec!(10), price - dec!(10), price, dec!(100));
+            bar
+        };
+        // 조회 결과는 최신순으로 온다
+        let history: Vec<Bar> = (0..7)
+            .rev()
+            .map(|m| minute(m, dec!(75000) + Decimal::from(m)))
+            .collect();
+
+        let completed = bars.seed("005930", &history);
+        assert_eq!(completed.len(), 1);
+        assert_eq!(
+            (completed[0].start, completed[0].volume),
+            (at(9, 0, 0), dec!(500))
+        );
+        assert_eq!(
+            (completed[0].open, completed[0].close),
+            (dec!(75000), dec!(75004))
+        );
+        assert!(bars.current("005930", BarInterval::Ticks(10)).is_none());
+
+        // 09:05 구간은 분봉 두 개에 실시간 체결이 이어진다
+        assert!(bars
+            .on_event(&trade(at(9, 7, 30), dec!(75100), dec!(5)))
+            .is_empty());
+        let closed = bars.on_event(&trade(at(9, 10, 0), dec!(75200), dec!(1)));
+        let bar = closed.iter().find(|b| b.interval == five).unwrap();
+        assert_eq!((bar.start, bar.open), (at(9, 5, 0), dec!(75005)));
+        assert_eq!((bar.high, bar.close), (dec!(75100), dec!(75100)));
+        assert_eq!((bar.volume, bar.ticks), (dec!(205), 1));
+    }
+
+    #[tokio::test]
+    async fn spawned_aggregator_emits_closed_bars() {
+        let (stream, injector) = crate::stream::KisStream::test_pair();
+        let mut rx = BarAggregator::new([BarInterval::Ticks(2)]).spawn(stream.receiver());
+        injector.send(trade(at(9, 0, 0), dec!(100), dec!(1))).await;
+        injector.send(trade(at(9, 0, 1), dec!(101), dec!(1))).await;
+
+        let bar = rx.recv().await.unwrap();
+        assert_eq!((bar.open, bar.close, bar.ticks), (dec!(100), dec!(101), 2));
+        drop((stream, injector));
+        assert!(rx.recv().await.is_none());
+    }
+}
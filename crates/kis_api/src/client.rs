@@ -1,16 +1,48 @@
 use crate::auth::{TokenRequest, TokenResponse};
-use crate::endpoints;
 use crate::error::{ApiResponseHeader, KisError};
+use crate::models::UnknownFields;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::marker::PhantomData;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tokio::sync::{Mutex, RwLock};
 
-#[derive(Clone)]
-pub struct KisClient {
+/// KIS REST 클라이언트.
+///
+/// 기본 타입 `KisClient`(= `KisClient<AnyEnv>`)는 실행 환경을 런타임 [`KisEnv`]로 결정하며,
+/// 모의투자 미지원 API는 호출 시 `KisError::NotSupportedInVts`를 반환한다.
+/// `KisClient<Real>` / `KisClient<Vts>`는 환경을 타입으로 고정하며, VTS TR ID가 없는
+/// API는 `KisClient<Vts>`에 존재하지 않아 컴파일 단계에서 걸러진다.
+///
+/// ```no_run
+/// # async fn f(real: kis_api::KisClient<kis_api::Real>) {
+/// let req = kis_api::models::DomesticStockV1TradingOrderCreditRequest::default();
+/// let _ = real.stock().trading().domestic_stock_v1_trading_order_credit_buy(req).await;
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # async fn f(vts: kis_api::KisClient<kis_api::Vts>) {
+/// // 신용주문은 VTS TR ID가 없으므로 KisClient<Vts>에서 호출할 수 없다.
+/// let req = kis_api::models::DomesticStockV1TradingOrderCreditRequest::default();
+/// let _ = vts.stock().trading().domestic_stock_v1_trading_order_credit_buy(req).await;
+/// # }
+/// ```
+pub struct KisClient<E = AnyEnv> {
     inner: Arc<Inner>,
+    _env: PhantomData<fn() -> E>,
+}
+
+impl<E> Clone for KisClient<E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _env: PhantomData,
+        }
+    }
 }
 
 struct Inner {
@@ -29,6 +61,44 @@ pub enum KisEnv {
     Vts,
 }
 
+/// 런타임 [`KisEnv`]로 환경을 결정하는 클라이언트 타입 (기본값)
+#[derive(Debug)]
+pub enum AnyEnv {}
+
+/// 실전투자 환경으로 고정된 클라이언트 타입
+#[derive(Debug)]
+pub enum Real {}
+
+/// 모의투자(VTS) 환경으로 고정된 클라이언트 타입
+#[derive(Debug)]
+pub enum Vts {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::AnyEnv {}
+    impl Sealed for super::Real {}
+    impl Sealed for super::Vts {}
+}
+
+/// 타입으로 고정된 실행 환경 (`Real`, `Vts`)
+pub trait TypedEnv: sealed::Sealed {
+    const ENV: KisEnv;
+}
+
+impl TypedEnv for Real {
+    const ENV: KisEnv = KisEnv::Real;
+}
+
+impl TypedEnv for Vts {
+    const ENV: KisEnv = KisEnv::Vts;
+}
+
+/// 실전 전용(VTS TR ID 없음) API를 호출할 수 있는 환경 (`Real`, `AnyEnv`)
+pub trait RealOnly: sealed::Sealed {}
+
+impl RealOnly for Real {}
+impl RealOnly for AnyEnv {}
+
 #[derive(Serialize, Deserialize)]
 struct TokenCache {
     access_token: String,
@@ -51,6 +121,7 @@ impl KisClient {
             .build()?;
 
         let this = Self {
+            _env: PhantomData,
             inner: Arc::new(Inner {
                 access_token: RwLock::new(String::new()),
                 token_expires_at: Mutex::new(None),
@@ -83,7 +154,38 @@ impl KisClient {
         this.refresh_token().await?;
         Ok(this)
     }
+}
+
+impl<E: TypedEnv> KisClient<E> {
+    /// 환경이 타입으로 고정된 클라이언트 생성. 예: `KisClient::<Vts>::typed(key, secret, None)`
+    pub async fn typed(
+        app_key: &str,
+        app_secret: &str,
+        cache_path: Option<PathBuf>,
+    ) -> Result<Self, KisError> {
+        let client = KisClient::with_cache(app_key, app_secret, E::ENV, cache_path).await?;
+        Ok(Self {
+            inner: client.inner,
+            _env: PhantomData,
+        })
+    }
 
+    /// 런타임 환경 클라이언트(`KisClient`)로 변환
+    pub fn erase(self) -> KisClient {
+        KisClient {
+            inner: self.inner,
+            _env: PhantomData,
+        }
+    }
+}
+
+impl<E: TypedEnv> From<KisClient<E>> for KisClient {
+    fn from(client: KisClient<E>) -> Self {
+        client.erase()
+    }
+}
+
+impl<E> KisClient<E> {
     pub async fn refresh_token(&self) -> Result<(), KisError> {
         let base_url = match self.env() {
             KisEnv::Real => "https://openapi.koreainvestment.com:9443",
@@ -123,28 +225,34 @@ impl KisClient {
         Ok(())
     }
 
-    pub fn stock(&self) -> endpoints::Stock {
-        endpoints::Stock(self.clone())
+    #[cfg(any(feature = "domestic-stock", feature = "domestic-futureoption"))]
+    pub fn stock(&self) -> crate::endpoints::Stock<E> {
+        crate::endpoints::Stock(self.clone())
     }
 
-    pub fn overseas(&self) -> endpoints::Overseas {
-        endpoints::Overseas(self.clone())
+    #[cfg(feature = "overseas-stock")]
+    pub fn overseas(&self) -> crate::endpoints::Overseas<E> {
+        crate::endpoints::Overseas(self.clone())
     }
 
-    pub fn overseas_future_option(&self) -> endpoints::OverseasFutureOption {
-        endpoints::OverseasFutureOption(self.clone())
+    #[cfg(feature = "overseas-futureoption")]
+    pub fn overseas_future_option(&self) -> crate::endpoints::OverseasFutureOption<E> {
+        crate::endpoints::OverseasFutureOption(self.clone())
     }
 
-    pub fn domestic_bond(&self) -> endpoints::DomesticBond {
-        endpoints::DomesticBond(self.clone())
+    #[cfg(feature = "bond")]
+    pub fn domestic_bond(&self) -> crate::endpoints::DomesticBond<E> {
+        crate::endpoints::DomesticBond(self.clone())
     }
 
-    pub fn etfetn(&self) -> endpoints::EtfEtn {
-        endpoints::EtfEtn(self.clone())
+    #[cfg(feature = "etfetn")]
+    pub fn etfetn(&self) -> crate::endpoints::EtfEtn<E> {
+        crate::endpoints::EtfEtn(self.clone())
     }
 
-    pub fn elw(&self) -> endpoints::Elw {
-        endpoints::Elw(self.clone())
+    #[cfg(feature = "elw")]
+    pub fn elw(&self) -> crate::endpoints::Elw<E> {
+        crate::endpoints::Elw(self.clone())
     }
 
     pub fn env(&self) -> KisEnv {
@@ -178,6 +286,33 @@ impl KisClient {
         }
     }
 
+    /// 실시간(WebSocket) 스트림 연결.
+    ///
+    /// approval key를 발급받아 환경별 `ws_url()`로 접속하고, 끊기면 재접속 후 기존 구독을 복구한다.
+    /// 재연결은 기본 [`ReconnectPolicy`](crate::stream::ReconnectPolicy)(무제한)를 따른다.
+    #[cfg(feature = "websocket")]
+    pub async fn stream(&self) -> Result<crate::stream::KisStream, KisError>
+    where
+        E: 'static,
+    {
+        self.stream_with(crate::stream::ReconnectPolicy::default())
+            .await
+    }
+
+    /// 재연결 정책을 지정한 실시간 스트림 연결.
+    ///
+    /// 서버가 approval key를 거부하면 재연결 전에 이 클라이언트로 새 키를 발급받는다.
+    #[cfg(feature = "websocket")]
+    pub async fn stream_with(
+        &self,
+        policy: crate::stream::ReconnectPolicy,
+    ) -> Result<crate::stream::KisStream, KisError>
+    where
+        E: 'static,
+    {
+        crate::stream::KisStream::connect_client(self, policy).await
+    }
+
     pub fn app_key(&self) -> &str {
         &self.inner.app_key
     }
@@ -194,7 +329,7 @@ impl KisClient {
         body: B,
     ) -> Result<R, KisError>
     where
-        R: for<'de> Deserialize<'de> + Default,
+        R: for<'de> Deserialize<'de> + Default + UnknownFields,
         B: Serialize,
     {
         if tr_id == "모의투자 미지원" {
@@ -224,16 +359,9 @@ impl KisClient {
             .await?;
 
         let full_body: serde_json::Value = resp.json().await?;
-        let header: ApiResponseHeader = serde_json::from_value(full_body.clone())?;
-
-        if header.is_success() {
-            // Success: Return the full JSON so the caller can pick output, output1, etc.
-            Ok(serde_json::from_value(normalize_empty_obj_to_arr(
-                full_body,
-            ))?)
-        } else {
-            Err(header.to_error())
-        }
+        let resp: R = decode_response(full_body)?;
+        warn_unknown_fields(tr_id, &resp);
+        Ok(resp)
     }
 
     pub async fn get<R, Q>(
@@ -244,7 +372,7 @@ impl KisClient {
         query: Q,
     ) -> Result<R, KisError>
     where
-        R: for<'de> Deserialize<'de> + Default,
+        R: for<'de> Deserialize<'de> + Default + UnknownFields,
         Q: Serialize,
     {
         if tr_id == "모의투자 미지원" {
@@ -274,15 +402,58 @@ impl KisClient {
             .await?;
 
         let full_body: serde_json::Value = resp.json().await?;
-        let header: ApiResponseHeader = serde_json::from_value(full_body.clone())?;
+        let resp: R = decode_response(full_body)?;
+        warn_unknown_fields(tr_id, &resp);
+        Ok(resp)
+    }
+}
 
-        if header.is_success() {
-            Ok(serde_json::from_value(normalize_empty_obj_to_arr(
-                full_body,
-            ))?)
-        } else {
-            Err(header.to_error())
-        }
+/// KIS 응답 본문을 응답 모델로 변환한다.
+///
+/// `rt_cd`가 실패면 [`KisError::Api`], 성공이면 `output1`/`output2` 정규화 후
+/// 역직렬화한다. `post`/`get`과 계약 테스트가 같은 경로를 쓴다.
+/// 공개 API가 아니며 `tests/contract.rs`에서 쓰려고 노출한다.
+#[doc(hidden)]
+pub fn decode_response<R>(full_body: serde_json::Value) -> Result<R, KisError>
+where
+    R: for<'de> Deserialize<'de>,
+{
+    let header: ApiResponseHeader = serde_json::from_value(full_body.clone())?;
+    if !header.is_success() {
+        return Err(header.to_error());
+    }
+    Ok(serde_json::from_value(normalize_empty_obj_to_arr(
+        full_body,
+    ))?)
+}
+
+/// 디버그 빌드에서 명세에 없는 응답 필드를 TR ID별로 경고한다.
+/// 같은 TR ID/필드 조합은 프로세스당 한 번만 보고한다.
+fn warn_unknown_fields<R: UnknownFields>(tr_id: &str, resp: &R) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    static REPORTED: OnceLock<std::sync::Mutex<HashSet<String>>> = OnceLock::new();
+
+    let mut fields = resp.unknown_fields();
+    if fields.is_empty() {
+        return;
+    }
+    fields.sort();
+    fields.dedup();
+
+    let mut reported = REPORTED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    fields.retain(|f| reported.insert(format!("{tr_id}:{f}")));
+    if !fields.is_empty() {
+        tracing::warn!(
+            target: "kis_api",
+            "[{}] 명세에 없는 응답 필드: {}",
+            tr_id,
+            fields.join(", ")
+        );
     }
 }
 
@@ -0,0 +1,822 @@
+//! 실시간 이벤트 배포.
+//!
+//! 시세 이벤트는 수신기마다 고른 [`DeliveryMode`]로 전달한다. 주문체결통보, 장운영정보와
+//! 연결 상태 이벤트는 전달 방식과 관계없이 수신기별 무제한 채널로 보내므로 시세가 밀려도
+//! 유실되지 않는다.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use tokio::sync::{broadcast, mpsc};
+
+use crate::error::KisError;
+use crate::event::KisEvent;
+
+/// bounded 수신기가 가득 찼을 때 수신 루프가 기다리는 최대 시간
+pub const BOUNDED_STALL_LIMIT: Duration = Duration::from_secs(1);
+
+/// 수신기의 시세 이벤트 전달 방식
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryMode {
+    /// 공유 broadcast 버퍼. 소비가 느리면 `KisError::Lagged(n)`로 이벤트가 유실된다.
+    Broadcast,
+    /// 수신기 전용 bounded 채널. 가득 차면 수신 루프가 자리가 날 때까지 기다린다(backpressure).
+    ///
+    /// 실시간 연결에서는 PINGPONG 응답과 다른 수신기가 멈추지 않도록 [`BOUNDED_STALL_LIMIT`]까지만
+    /// 기다린다. 그래도 자리가 없으면 그 뒤 시세는 자리가 날 때까지 버리고, 빠진 위치에서
+    /// `KisError::Lagged(n)`를 돌려준다. 기록 재생([`Replayer`](crate::recorder::Replayer) 등)은
+    /// 끝까지 기다린다.
+    Bounded(usize),
+    /// 무제한 큐. 메모리에 `memory`건을 넘으면 `dir`의 임시 파일에 쌓는다.
+    ///
+    /// 파일 입출력은 수신기 전용 스레드에서 한다. 디스크에 쓰지 못하면 그 전까지 받은 시세를
+    /// 순서대로 모두 전달한 뒤 `KisError::Io`를 돌려주고 시세 전달을 멈춘다.
+    Spill { dir: PathBuf, memory: usize },
+}
+
+/// 수신기 필터
+pub(crate) type EventFilter = Arc<dyn Fn(&KisEvent) -> bool + Send + Sync>;
+
+/// 수신기 등록 정보 (배포 측)
+#[derive(Clone)]
+struct Subscriber {
+    filter: Option<EventFilter>,
+    /// 무손실 이벤트 채널 ([`KisEvent::is_lossless`])
+    orders: mpsc::UnboundedSender<KisEvent>,
+    market: MarketSink,
+}
+
+#[derive(Clone)]
+enum MarketSink {
+    /// 공유 broadcast 채널로 받는다
+    Broadcast,
+    Bounded(BoundedSink),
+    Spill(Arc<SpillQueue>),
+}
+
+/// bounded 채널 송신 측. 항목은 (바로 앞에서 버린 건수, 이벤트)
+#[derive(Clone)]
+struct BoundedSink {
+    tx: mpsc::Sender<(u64, KisEvent)>,
+    /// 자리가 없어 버린 뒤 아직 알리지 못한 건수
+    dropped: Arc<AtomicU64>,
+}
+
+impl BoundedSink {
+    /// 자리가 날 때까지 `limit`만큼 기다린다 (`None`이면 끝까지).
+    /// 이미 밀려 있는 수신기는 기다리지 않고 버린다.
+    async fn send(&self, event: KisEvent, limit: Option<Duration>) {
+        let dropped = self.dropped.load(Ordering::Relaxed);
+        if dropped > 0 {
+            match self.tx.try_send((dropped, event)) {
+                Ok(()) => {
+                    self.dropped.fetch_sub(dropped, Ordering::Relaxed);
+                }
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => {}
+            }
+            return;
+        }
+        let Some(limit) = limit else {
+            let _ = self.tx.send((0, event)).await;
+            return;
+        };
+        if tokio::time::timeout(limit, self.tx.send((0, event)))
+            .await
+            .is_err()
+        {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// 이벤트 배포기: 공유 broadcast 채널 + 수신기별 무손실 채널
+pub(crate) struct Fanout {
+    tx: broadcast::Sender<KisEvent>,
+    subscribers: Mutex<Vec<Subscriber>>,
+    /// bounded 수신기를 기다리는 최대 시간 (`None`이면 끝까지)
+    stall_limit: Option<Duration>,
+}
+
+impl Fanout {
+    pub(crate) fn new(buffer: usize) -> Self {
+        let (tx, _) = broadcast::channel(buffer);
+        Self {
+            tx,
+            subscribers: Mutex::new(Vec::new()),
+            stall_limit: Some(BOUNDED_STALL_LIMIT),
+        }
+    }
+
+    /// bounded 수신기를 자리가 날 때까지 기다린다 (연결 없는 스트림용)
+    pub(crate) fn wait_for_bounded(mut self) -> Self {
+        self.stall_limit = None;
+        self
+    }
+
+    pub(crate) fn receiver(
+        &self,
+        mode: DeliveryMode,
+        filter: Option<EventFilter>,
+    ) -> EventReceiver {
+        let (orders_tx, orders) = mpsc::unbounded_channel();
+        let (sink, market) = match mode {
+            DeliveryMode::Broadcast => (
+                MarketSink::Broadcast,
+                MarketSource::Broadcast(RecvFuture::new(recv_owned(self.tx.subscribe()))),
+            ),
+            DeliveryMode::Bounded(capacity) => {
+                let (tx, rx) = mpsc::channel(capacity.max(1));
+                let sink = BoundedSink {
+                    tx,
+                    dropped: Arc::new(AtomicU64::new(0)),
+                };
+                (
+                    MarketSink::Bounded(sink),
+                    MarketSource::Bounded { rx, next: None },
+                )
+            }
+            DeliveryMode::Spill { dir, memory } => {
+                let queue = Arc::new(SpillQueue::new(dir, memory));
+                (MarketSink::Spill(queue.clone()), MarketSource::Spill(queue))
+            }
+        };
+        self.subscribers.lock().unwrap().push(Subscriber {
+            filter: filter.clone(),
+            orders: orders_tx,
+            market: sink,
+        });
+        EventReceiver {
+            orders,
+            market,
+            filter,
+        }
+    }
+
+    /// 이벤트 1건 배포.
+    ///
+    /// 무손실 이벤트와 broadcast·스풀 수신기는 기다리지 않는다. 가득 찬 bounded 수신기는
+    /// 함께 기다리되 `stall_limit`을 넘기지 않는다.
+    pub(crate) async fn publish(&self, event: KisEvent) {
+        let subscribers = {
+            let mut guard = self.subscribers.lock().unwrap();
+            guard.retain(|s| !s.orders.is_closed());
+            guard.clone()
+        };
+        let subscribers = subscribers
+            .into_iter()
+            .filter(|sub| sub.filter.as_ref().is_none_or(|f| f(&event)));
+        if event.is_lossless() {
+            for sub in subscribers {
+                let _ = sub.orders.send(event.clone());
+            }
+            return;
+        }
+
+        let _ = self.tx.send(event.clone());
+        let mut bounded = Vec::new();
+        for sub in subscribers {
+            match sub.market {
+                MarketSink::Broadcast => {}
+                MarketSink::Bounded(sink) => bounded.push(sink),
+                MarketSink::Spill(queue) => queue.push(event.clone()),
+            }
+        }
+        futures_util::future::join_all(
+            bounded
+                .iter()
+                .map(|sink| sink.send(event.clone(), self.stall_limit)),
+        )
+        .await;
+    }
+}
+
+impl Drop for Fanout {
+    fn drop(&mut self) {
+        // 스풀 큐는 송신 측 drop을 스스로 알 수 없으므로 여기서 닫는다
+        for sub in self.subscribers.get_mut().unwrap().iter() {
+            if let MarketSink::Spill(queue) = &sub.market {
+                queue.close();
+            }
+        }
+    }
+}
+
+type RecvFuture = tokio_util::sync::ReusableBoxFuture<
+    'static,
+    (
+        Result<KisEvent, broadcast::error::RecvError>,
+        broadcast::Receiver<KisEvent>,
+    ),
+>;
+
+async fn recv_owned(
+    mut rx: broadcast::Receiver<KisEvent>,
+) -> (
+    Result<KisEvent, broadcast::error::RecvError>,
+    broadcast::Receiver<KisEvent>,
+) {
+    let result = rx.recv().await;
+    (result, rx)
+}
+
+enum MarketSource {
+    Broadcast(RecvFuture),
+    Bounded {
+        rx: mpsc::Receiver<(u64, KisEvent)>,
+        /// `Lagged`를 먼저 돌려주고 다음에 전달할 이벤트
+        next: Option<Box<KisEvent>>,
+    },
+    Spill(Arc<SpillQueue>),
+}
+
+/// WebSocket 이벤트 수신기
+///
+/// [`recv`](Self::recv)로 받거나 `futures::Stream`으로 쓸 수 있다. 스트림은
+/// `Err(KisError::Lagged(n))`를 항목으로 내보내고, 스트림이 닫히면 끝난다.
+/// 주문체결통보, 장운영정보와 연결 상태 이벤트는 시세보다 먼저 전달되며 유실되지 않는다.
+pub struct EventReceiver {
+    orders: mpsc::UnboundedReceiver<KisEvent>,
+    market: MarketSource,
+    /// broadcast 수신 시 적용 (무손실 채널은 배포 측에서 거른다)
+    filter: Option<EventFilter>,
+}
+
+impl EventReceiver {
+    /// 다음 이벤트를 기다린다. `KisError::Lagged(n)` 또는 `KisError::StreamClosed` 반환 가능.
+    pub async fn recv(&mut self) -> Result<KisEvent, KisError> {
+        std::future::poll_fn(|cx| self.poll_event(cx)).await
+    }
+
+    fn poll_event(&mut self, cx: &mut Context<'_>) -> Poll<Result<KisEvent, KisError>> {
+        let orders_closed = match self.orders.poll_recv(cx) {
+            Poll::Ready(Some(event)) => return Poll::Ready(Ok(event)),
+            Poll::Ready(None) => true,
+            Poll::Pending => false,
+        };
+        let market = match &mut self.market {
+            MarketSource::Broadcast(fut) => loop {
+                let (result, rx) = std::task::ready!(fut.poll(cx));
+                fut.set(recv_owned(rx));
+                match result {
+                    Ok(event) if self.filter.as_ref().is_some_and(|f| !f(&event)) => continue,
+                    Ok(event) => break Some(Ok(event)),
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        break Some(Err(KisError::Lagged(n)))
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break None,
+                }
+            },
+            MarketSource::Bounded { rx, next } => match next.take() {
+                Some(event) => Some(Ok(*event)),
+                None => match std::task::ready!(rx.poll_recv(cx)) {
+                    Some((0, event)) => Some(Ok(event)),
+                    Some((dropped, event)) => {
+                        *next = Some(Box::new(event));
+                        Some(Err(KisError::Lagged(dropped)))
+                    }
+                    None => None,
+                },
+            },
+            MarketSource::Spill(queue) => std::task::ready!(queue.poll_pop(cx)),
+        };
+        match market {
+            Some(item) => Poll::Ready(item),
+            // 시세 채널이 닫혀도 남은 주문체결통보는 모두 전달한다
+            None if orders_closed => Poll::Ready(Err(KisError::StreamClosed)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        if let MarketSource::Spill(queue) = &self.market {
+            queue.abandon();
+        }
+    }
+}
+
+impl futures_util::Stream for EventReceiver {
+    type Item = Result<KisEvent, KisError>;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match std::task::ready!(self.get_mut().poll_event(cx)) {
+            Err(KisError::StreamClosed) => Poll::Ready(None),
+            item => Poll::Ready(Some(item)),
+        }
+    }
+}
+
+static SPILL_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// 메모리 + 디스크 무제한 FIFO 큐.
+///
+/// 순서는 `memory` → 파일 → `incoming`이다. 배포 측은 잠금만 잡고 큐에 넣으며, 파일 쓰기와
+/// 읽기(메모리 채우기)는 처음 넘칠 때 띄우는 전용 스레드가 한다.
+struct SpillQueue {
+    state: Mutex<SpillState>,
+    /// 스풀 스레드를 깨운다
+    work: Condvar,
+}
+
+struct SpillState {
+    dir: PathBuf,
+    /// 수신기가 바로 꺼내 가는 이벤트
+    memory: VecDeque<KisEvent>,
+    memory_limit: usize,
+    /// 스풀 스레드가 파일에 쓸 이벤트 (가장 최근)
+    incoming: VecDeque<KisEvent>,
+    /// 스풀 스레드가 입출력 중이면 파일을 가져가 `None`
+    file: Option<SpillFile>,
+    /// 파일에 쌓여 아직 읽지 않은 건수
+    spilled: usize,
+    /// 스풀 스레드가 쓰는 중인 건수
+    writing: usize,
+    thread_started: bool,
+    /// 디스크 오류. 남은 이벤트를 모두 전달한 뒤 수신기에 돌려준다
+    failed: Option<std::io::Error>,
+    /// 디스크 오류 뒤로는 받지 않는다
+    broken: bool,
+    /// 배포 측이 닫힘
+    closed: bool,
+    /// 수신기가 닫힘
+    abandoned: bool,
+    waker: Option<Waker>,
+}
+
+impl SpillState {
+    /// 파일·스풀 스레드를 거치지 않고 바로 메모리에 넣어도 순서가 유지되는지
+    fn memory_only(&self) -> bool {
+        self.spilled == 0 && self.writing == 0 && self.incoming.is_empty()
+    }
+
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn fail(&mut self, e: std::io::Error) {
+        tracing::warn!(target: "kis_api", "spill queue stopped: {e}");
+        self.failed.get_or_insert(e);
+        self.broken = true;
+        self.incoming.clear();
+        self.wake_receiver();
+    }
+}
+
+/// 스풀 파일 (JSON Lines). 모두 읽으면 비우고 처음부터 다시 쓴다.
+struct SpillFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    reader: BufReader<File>,
+}
+
+impl SpillFile {
+    fn create(dir: &std::path::Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "kis-spill-{}-{}.jsonl",
+            std::process::id(),
+            SPILL_SEQ.fetch_add(1, Ordering::Relaxed)
+        ));
+        let writer = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&path)?;
+        let reader = File::open(&path)?;
+        Ok(Self {
+            path,
+            writer: BufWriter::new(writer),
+            reader: BufReader::new(reader),
+        })
+    }
+
+    fn append(&mut self, events: &[KisEvent]) -> std::io::Result<()> {
+        for event in events {
+            serde_json::to_writer(&mut self.writer, event)?;
+            self.writer.write_all(b"\n")?;
+        }
+        self.writer.flush()
+    }
+
+    fn read_next(&mut self) -> std::io::Result<KisEvent> {
+        let mut line = String::new();
+        self.reader.read_line(&mut line)?;
+        Ok(serde_json::from_str(&line)?)
+    }
+
+    fn reset(&mut self) -> std::io::Result<()> {
+        self.writer.seek(SeekFrom::Start(0))?;
+        self.writer.get_ref().set_len(0)?;
+        self.reader.seek(SeekFrom::Start(0))?;
+        Ok(())
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl SpillQueue {
+    fn new(dir: PathBuf, memory_limit: usize) -> Self {
+        Self {
+            state: Mutex::new(SpillState {
+                dir,
+                memory: VecDeque::new(),
+                memory_limit: memory_limit.max(1),
+                incoming: VecDeque::new(),
+                file: None,
+                spilled: 0,
+                writing: 0,
+                thread_started: false,
+                failed: None,
+                broken: false,
+                closed: false,
+                abandoned: false,
+                waker: None,
+            }),
+            work: Condvar::new(),
+        }
+    }
+
+    /// 배포 측에서 호출. 입출력 없이 잠금만 잡는다.
+    fn push(self: &Arc<Self>, event: KisEvent) {
+        let mut state = self.state.lock().unwrap();
+        if state.broken || state.abandoned {
+            return;
+        }
+        if state.memory_only() && state.memory.len() < state.memory_limit {
+            state.memory.push_back(event);
+            state.wake_receiver();
+            return;
+        }
+        state.incoming.push_back(event);
+        if !state.thread_started {
+            state.thread_started = true;
+            let queue = self.clone();
+            let spawned = std::thread::Builder::new()
+                .name("kis-spill".into())
+                .spawn(move || queue.run());
+            if let Err(e) = spawned {
+                state.fail(e);
+            }
+        }
+        self.work.notify_one();
+    }
+
+    /// 스풀 스레드: 넘친 이벤트를 파일에 쓰고, 메모리에 자리가 나면 파일에서 채운다.
+    fn run(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.abandoned {
+                state.file = None;
+                break;
+            }
+            let room = state.memory_limit.saturating_sub(state.memory.len());
+            if state.spilled > 0 && room > 0 {
+                // 파일 → 메모리
+                let count = room.min(state.spilled);
+                let Some(mut file) = state.file.take() else {
+                    state.fail(std::io::Error::other("spill file missing"));
+                    continue;
+                };
+                drop(state);
+                let mut events = Vec::with_capacity(count);
+                let mut result = Ok(());
+                for _ in 0..count {
+                    match file.read_next() {
+                        Ok(event) => events.push(event),
+                        Err(e) => {
+                            result = Err(e);
+                            break;
+                        }
+                    }
+                }
+                state = self.state.lock().unwrap();
+                state.memory.extend(events);
+                match result {
+                    Ok(()) => {
+                        state.spilled -= count;
+                        if state.spilled == 0 {
+                            if let Err(e) = file.reset() {
+                                state.fail(e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        // 읽지 못한 나머지는 잃는다
+                        state.spilled = 0;
+                        state.fail(e);
+                    }
+                }
+                state.file = Some(file);
+                state.wake_receiver();
+            } else if !state.incoming.is_empty() && state.spilled == 0 && room > 0 {
+                // 파일이 비어 있으면 파일을 거치지 않는다
+                let count = room.min(state.incoming.len());
+                let moved: Vec<_> = state.incoming.drain(..count).collect();
+                state.memory.extend(moved);
+                state.wake_receiver();
+            } else if !state.incoming.is_empty() {
+                // 메모리 → 파일
+                let batch: Vec<_> = state.incoming.drain(..).collect();
+                state.writing = batch.len();
+                let file = state.file.take();
+                let dir = state.dir.clone();
+                drop(state);
+                let result = match file {
+                    Some(file) => Ok(file),
+                    None => SpillFile::create(&dir),
+                }
+                .map(|mut file| {
+                    let written = file.append(&batch);
+                    (file, written)
+                });
+                state = self.state.lock().unwrap();
+                state.writing = 0;
+                match result {
+                    Ok((file, written)) => {
+                        // 쓰다 만 줄은 `spilled`를 넘어서므로 읽지 않는다
+                        state.file = Some(file);
+                        match written {
+                            Ok(()) => state.spilled += batch.len(),
+                            Err(e) => state.fail(e),
+                        }
+                    }
+                    Err(e) => state.fail(e),
+                }
+            } else if state.closed && state.spilled == 0 {
+                break;
+            } else {
+                state = self.work.wait(state).unwrap();
+            }
+        }
+    }
+
+    fn poll_pop(&self, cx: &mut Context<'_>) -> Poll<Option<Result<KisEvent, KisError>>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(event) = state.memory.pop_front() {
+            if !state.memory_only() {
+                // 자리가 났으니 파일에서 채운다
+                self.work.notify_one();
+            }
+            return Poll::Ready(Some(Ok(event)));
+        }
+        if state.memory_only() {
+            if let Some(e) = state.failed.take() {
+                return Poll::Ready(Some(Err(KisError::Io(e))));
+            }
+            if state.closed || state.broken {
+                return Poll::Ready(None);
+            }
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// 배포 측이 닫힘. 남은 이벤트는 계속 전달한다
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.wake_receiver();
+        self.work.notify_one();
+    }
+
+    /// 수신기가 닫힘. 남은 이벤트와 스풀 파일을 버린다
+    fn abandon(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.abandoned = true;
+        state.memory.clear();
+        state.incoming.clear();
+        state.file = None;
+        self.work.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{
+        OrderConfirmData, OrderMarket, OrderNoticeKind, OrderSide, TransactionData,
+    };
+    use futures_util::StreamExt;
+    use rust_decimal::Decimal;
+
+    fn time() -> chrono::DateTime<chrono::FixedOffset> {
+        chrono::DateTime::parse_from_rfc3339("2026-03-21T09:30:00+09:00").unwrap()
+    }
+
+    fn trade(n: i64) -> KisEvent {
+        KisEvent::Transaction(TransactionData {
+            symbol: "005930".into(),
+            overseas_key: None,
+            price: Decimal::new(n, 0),
+            qty: Decimal::ONE,
+            time: time(),
+            is_buy: true,
+        })
+    }
+
+    fn fill(order_id: &str) -> KisEvent {
+        KisEvent::OrderConfirm(OrderConfirmData {
+            market: OrderMarket::DomesticStock,
+            customer_id: "HTSID01".into(),
+            account: "5012345601".into(),
+            order_id: order_id.into(),
+            original_order_id: None,
+            symbol: "005930".into(),
+            kind: OrderNoticeKind::Filled,
+            side: Some(OrderSide::Buy),
+            order_qty: Decimal::ONE,
+            order_price: Some(Decimal::new(75400, 0)),
+            filled_qty: Decimal::ONE,
+            filled_price: Decimal::new(75400, 0),
+            remaining_qty: Decimal::ZERO,
+            time: time(),
+        })
+    }
+
+    fn price(event: KisEvent) -> i64 {
+        match event {
+            KisEvent::Transaction(t) => t.price.try_into().unwrap(),
+            other => panic!("expected Transaction, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn broadcast_lags_but_order_notices_do_not() {
+        let fanout = Fanout::new(1);
+        let mut rx = fanout.receiver(DeliveryMode::Broadcast, None);
+        fanout.publish(fill("A")).await;
+        for n in 1..=3 {
+            fanout.publish(trade(n)).await;
+        }
+        fanout.publish(fill("B")).await;
+        drop(fanout);
+
+        // 주문체결통보가 먼저, 시세는 버퍼(1건)를 넘은 만큼 유실
+        assert!(
+            matches!(rx.next().await, Some(Ok(KisEvent::OrderConfirm(o))) if o.order_id == "A")
+        );
+        assert!(
+            matches!(rx.next().await, Some(Ok(KisEvent::OrderConfirm(o))) if o.order_id == "B")
+        );
+        assert!(matches!(rx.next().await, Some(Err(KisError::Lagged(2)))));
+        assert_eq!(price(rx.next().await.unwrap().unwrap()), 3);
+        assert!(rx.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn bounded_applies_backpressure_without_loss() {
+        let fanout = Arc::new(Fanout::new(1));
+        let mut rx = fanout.receiver(DeliveryMode::Bounded(2), None);
+
+        let publisher = {
+            let fanout = fanout.clone();
+            tokio::spawn(async move {
+                for n in 1..=5 {
+                    fanout.publish(trade(n)).await;
+                }
+            })
+        };
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        // 2건이 차면 배포가 멈춘다
+        assert!(!publisher.is_finished());
+
+        let mut got = Vec::new();
+        for _ in 0..5 {
+            got.push(price(rx.recv().await.unwrap()));
+        }
+        publisher.await.unwrap();
+        assert_eq!(got, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn spill_queue_keeps_order_across_disk() {
+        let dir = std::env::temp_dir().join(format!("kis-spill-test-{}", std::process::id()));
+        let fanout = Fanout::new(1);
+        let mut rx = fanout.receiver(
+            DeliveryMode::Spill {
+                dir: dir.clone(),
+                memory: 2,
+            },
+            Some(Arc::new(|e: &KisEvent| !matches!(e, KisEvent::Transaction(t) if t.price == Decimal::new(4, 0)))),
+        );
+        for n in 1..=6 {
+            fanout.publish(trade(n)).await;
+        }
+        let spill_files = || std::fs::read_dir(&dir).map_or(0, |d| d.count());
+        // 파일은 스풀 스레드가 만든다
+        for _ in 0..200 {
+            if spill_files() == 1 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(spill_files(), 1);
+
+        // 파일을 모두 비운 뒤 다시 쌓여도 순서 유지
+        let mut got = vec![
+            price(rx.recv().await.unwrap()),
+            price(rx.recv().await.unwrap()),
+        ];
+        for n in 7..=8 {
+            fanout.publish(trade(n)).await;
+        }
+        drop(fanout);
+        while let Some(event) = rx.next().await {
+            got.push(price(event.unwrap()));
+        }
+        assert_eq!(got, vec![1, 2, 3, 5, 6, 7, 8]);
+
+        drop(rx);
+        assert_eq!(spill_files(), 0);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[tokio::test]
+    async fn spill_disk_failure_is_reported_after_earlier_events() {
+        // 디렉터리 자리에 파일이 있어 스풀 파일을 만들 수 없다
+        let dir = std::env::temp_dir().join(format!("kis-spill-blocked-{}", std::process::id()));
+        std::fs::write(&dir, b"").unwrap();
+        let fanout = Fanout::new(1);
+        let mut rx = fanout.receiver(
+            DeliveryMode::Spill {
+                dir: dir.clone(),
+                memory: 1,
+            },
+            None,
+        );
+        for n in 1..=3 {
+            fanout.publish(trade(n)).await;
+        }
+        fanout.publish(fill("A")).await;
+        let queue = match &fanout.subscribers.lock().unwrap()[0].market {
+            MarketSink::Spill(queue) => queue.clone(),
+            _ => unreachable!(),
+        };
+        // 스풀 스레드가 파일 생성에 실패할 때까지
+        while !queue.state.lock().unwrap().broken {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        drop(queue);
+
+        assert!(matches!(rx.recv().await, Ok(KisEvent::OrderConfirm(o)) if o.order_id == "A"));
+        assert_eq!(price(rx.recv().await.unwrap()), 1);
+        assert!(matches!(rx.recv().await, Err(KisError::Io(_))));
+        // 오류 뒤 시세는 받지 않지만 주문체결통보는 계속 전달된다
+        fanout.publish(trade(4)).await;
+        fanout.publish(fill("B")).await;
+        assert!(matches!(rx.recv().await, Ok(KisEvent::OrderConfirm(o)) if o.order_id == "B"));
+        drop(fanout);
+        assert!(matches!(rx.recv().await, Err(KisError::StreamClosed)));
+        let _ = std::fs::remove_file(&dir);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn full_bounded_receiver_stalls_publish_at_most_once() {
+        let fanout = Fanout::new(16);
+        let mut slow = fanout.receiver(DeliveryMode::Bounded(1), None);
+        let mut other = fanout.receiver(DeliveryMode::Broadcast, None);
+
+        let started = tokio::time::Instant::now();
+        for n in 1..=3 {
+            fanout.publish(trade(n)).await;
+        }
+        fanout.publish(fill("A")).await;
+        // 2는 한도만큼 기다린 뒤 버리고, 밀린 동안의 3은 기다리지 않는다
+        assert_eq!(started.elapsed(), BOUNDED_STALL_LIMIT);
+        assert!(matches!(other.recv().await, Ok(KisEvent::OrderConfirm(o)) if o.order_id == "A"));
+
+        assert!(matches!(slow.recv().await, Ok(KisEvent::OrderConfirm(_))));
+        assert_eq!(price(slow.recv().await.unwrap()), 1);
+        fanout.publish(trade(4)).await;
+        // 빠진 위치에서 유실을 알린다
+        assert!(matches!(slow.recv().await, Err(KisError::Lagged(2))));
+        assert_eq!(price(slow.recv().await.unwrap()), 4);
+    }
+
+    #[tokio::test]
+    async fn dropped_receivers_are_pruned() {
+        let fanout = Fanout::new(4);
+        let rx = fanout.receiver(DeliveryMode::Bounded(1), None);
+        drop(rx);
+        // 닫힌 bounded 수신기 때문에 배포가 멈추지 않는다
+        fanout.publish(trade(1)).await;
+        fanout.publish(trade(2)).await;
+        assert!(fanout.subscribers.lock().unwrap().is_empty());
+    }
+}
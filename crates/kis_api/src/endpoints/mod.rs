@@ -1,14 +1,18 @@
-use crate::client::KisClient;
-
+#[cfg(any(feature = "domestic-stock", feature = "domestic-futureoption"))]
 #[allow(dead_code)]
-pub struct Stock(pub(crate) KisClient);
+pub struct Stock<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
+#[cfg(feature = "overseas-stock")]
 #[allow(dead_code)]
-pub struct Overseas(pub(crate) KisClient);
+pub struct Overseas<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
+#[cfg(feature = "overseas-futureoption")]
 #[allow(dead_code)]
-pub struct OverseasFutureOption(pub(crate) KisClient);
+pub struct OverseasFutureOption<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
+#[cfg(feature = "bond")]
 #[allow(dead_code)]
-pub struct DomesticBond(pub(crate) KisClient);
+pub struct DomesticBond<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
+#[cfg(feature = "etfetn")]
 #[allow(dead_code)]
-pub struct EtfEtn(pub(crate) KisClient);
+pub struct EtfEtn<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
+#[cfg(feature = "elw")]
 #[allow(dead_code)]
-pub struct Elw(pub(crate) KisClient);
+pub struct Elw<E = crate::client::AnyEnv>(pub(crate) crate::client::KisClient<E>);
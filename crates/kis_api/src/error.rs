@@ -15,12 +15,27 @@ pub enum KisError {
     Network(#[from] reqwest::Error),
     #[error("데이터 파싱 에러: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("입출력 에러: {0}")]
+    Io(#[from] std::io::Error),
     #[error("WebSocket 에러: {0}")]
     WebSocket(String),
     #[error("Stream lagged by {0} messages")]
     Lagged(u64),
     #[error("WebSocket stream closed")]
     StreamClosed,
+    #[error("실시간 구독 한도 초과 (최대 {0}건)")]
+    SubscriptionLimit(usize),
+    #[error("실시간 구독 거부 [{tr_id}/{tr_key}]: {msg_cd} - {message}")]
+    SubscribeRejected {
+        tr_id: String,
+        tr_key: String,
+        msg_cd: String,
+        message: String,
+    },
+    #[error("실시간 구독 응답 시간 초과 [{tr_id}/{tr_key}]")]
+    SubscribeTimeout { tr_id: String, tr_key: String },
+    #[error("잘못된 해외 실시간 종목 키: {0}")]
+    InvalidRealtimeKey(String),
     #[error("이 API는 모의투자(VTS) 환경에서 지원되지 않습니다.")]
     NotSupportedInVts,
 }
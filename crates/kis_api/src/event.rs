@@ -1,46 +1,609 @@
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::error::KisError;
+
+/// 해외 실시간 시세 구분 (`tr_key` 첫 글자)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OverseasFeed {
+    /// `D`: 무료(지연) 시세, 미국 주간거래 포함
+    Delayed,
+    /// `R`: 유료 실시간 시세
+    Realtime,
+}
+
+impl OverseasFeed {
+    pub fn code(self) -> char {
+        match self {
+            OverseasFeed::Delayed => 'D',
+            OverseasFeed::Realtime => 'R',
+        }
+    }
+}
+
+/// 해외 실시간 종목 키 (예: `DNASNVDA` = 지연 시세 + 나스닥 + NVDA)
+///
+/// 구독 시 `tr_key`로 쓰이고, 해외 체결/호가 이벤트에 `RSYM`에서 파싱되어 되돌아온다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OverseasRealtimeKey {
+    /// 거래소 코드 3자리 (NAS, NYS, AMS, TSE, HKS, SHS, SZS, HSX, HNX, BAQ, BAY, BAA 등)
+    pub exchange: String,
+    /// 종목코드
+    pub ticker: String,
+    pub feed: OverseasFeed,
+}
+
+impl OverseasRealtimeKey {
+    pub fn new(feed: OverseasFeed, exchange: impl Into<String>, ticker: impl Into<String>) -> Self {
+        Self {
+            exchange: exchange.into(),
+            ticker: ticker.into(),
+            feed,
+        }
+    }
+
+    /// 지연 시세 키 (`D` + 거래소 + 종목)
+    pub fn delayed(exchange: impl Into<String>, ticker: impl Into<String>) -> Self {
+        Self::new(OverseasFeed::Delayed, exchange, ticker)
+    }
+
+    /// 실시간 시세 키 (`R` + 거래소 + 종목)
+    pub fn realtime(exchange: impl Into<String>, ticker: impl Into<String>) -> Self {
+        Self::new(OverseasFeed::Realtime, exchange, ticker)
+    }
+
+    /// 구독 요청의 `tr_key`
+    pub fn tr_key(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::fmt::Display for OverseasRealtimeKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.feed.code(), self.exchange, self.ticker)
+    }
+}
+
+impl std::str::FromStr for OverseasRealtimeKey {
+    type Err = KisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || KisError::InvalidRealtimeKey(s.to_string());
+        let feed = match s.chars().next() {
+            Some('D') => OverseasFeed::Delayed,
+            Some('R') => OverseasFeed::Realtime,
+            _ => return Err(invalid()),
+        };
+        let exchange = s.get(1..4).ok_or_else(invalid)?;
+        let ticker = &s[4..];
+        if !exchange.bytes().all(|b| b.is_ascii_uppercase()) || ticker.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self::new(feed, exchange, ticker))
+    }
+}
 
 /// 실시간 체결 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionData {
     pub symbol: String,
+    /// 해외 종목의 실시간 키 (국내는 `None`)
+    pub overseas_key: Option<OverseasRealtimeKey>,
     pub price: Decimal,
     pub qty: Decimal,
+    /// 국내는 KST, 해외는 거래소 현지 오프셋
     pub time: DateTime<FixedOffset>,
     pub is_buy: bool,
 }
 
 /// 실시간 호가 데이터 (최우선 호가)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuoteData {
     pub symbol: String,
+    /// 해외 종목의 실시간 키 (국내는 `None`)
+    pub overseas_key: Option<OverseasRealtimeKey>,
     pub ask_price: Decimal,
     pub bid_price: Decimal,
     pub ask_qty: Decimal,
     pub bid_qty: Decimal,
+    /// 국내는 KST, 해외는 거래소 현지 오프셋
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 호가 1단계 (가격, 잔량)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+/// 실시간 호가 데이터 (전체 단계)
+///
+/// 국내(H0STASP0)는 10단계, 해외(HDFSASP0)는 10단계, 아시아(HDFSASP1)는 1단계.
+/// 가격이 비었거나 0인 단계는 제외된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookData {
+    pub symbol: String,
+    /// 해외 종목의 실시간 키 (국내는 `None`)
+    pub overseas_key: Option<OverseasRealtimeKey>,
+    /// 매도호가, 1호가부터
+    pub asks: Vec<PriceLevel>,
+    /// 매수호가, 1호가부터
+    pub bids: Vec<PriceLevel>,
+    /// 총 매도호가 잔량
+    pub total_ask_qty: Decimal,
+    /// 총 매수호가 잔량
+    pub total_bid_qty: Decimal,
+    /// 예상 체결가 (국내 동시호가 중에만 제공)
+    pub expected_price: Option<Decimal>,
+    /// 예상 체결량 (국내 동시호가 중에만 제공)
+    pub expected_qty: Option<Decimal>,
+    /// 시간 구분 코드 (국내 HOUR_CLS_CODE: 0 장중, A 장후예상, B 장전예상, C 9시이후 예상/VI, D 시간외 단일가 예상)
+    pub session_code: Option<String>,
+    /// 국내는 KST, 해외는 거래소 현지 오프셋
     pub time: DateTime<FixedOffset>,
 }
 
+/// 체결통보 TR 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderMarket {
+    /// 국내주식 (H0STCNI0, 모의 H0STCNI9)
+    DomesticStock,
+    /// 해외주식 (H0GSCNI0, 모의 H0GSCNI9)
+    OverseasStock,
+    /// 국내 선물옵션 (H0IFCNI0, 모의 H0IFCNI9)
+    FutureOption,
+    /// KRX 야간선물 (H0MFCNI0)
+    NightFuture,
+    /// KRX 야간옵션 (H0EUCNI0)
+    NightOption,
+    /// 해외선물옵션 (주문내역 HDFFF1C0, 체결내역 HDFFF2C0)
+    OverseasFutureOption,
+}
+
+/// 체결통보 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderNoticeKind {
+    /// 주문 접수
+    Accepted,
+    /// 체결
+    Filled,
+    /// 정정 접수
+    Modified,
+    /// 취소 접수 또는 IOC/FOK 잔량 취소
+    Cancelled,
+    /// 주문 거부
+    Rejected,
+}
+
+/// 매도/매수 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
 /// 주문체결통보 데이터
-#[derive(Debug, Clone)]
+///
+/// 접수·정정·취소·거부 통보는 `filled_qty`가 0이고 주문 수량과 가격만 채워진다.
+/// 체결통보 TR(국내·해외주식, 국내·해외선물옵션)에는 거부 사유 필드가 없으므로 거부는
+/// [`OrderNoticeKind::Rejected`]로만 알 수 있다. 사유는 주문 조회 API로 확인한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderConfirmData {
+    pub market: OrderMarket,
+    /// 고객 ID (HTS ID, 구독 `tr_key`)
+    pub customer_id: String,
+    /// 계좌번호
+    pub account: String,
     pub order_id: String,
+    /// 원주문번호 (정정·취소 통보)
+    pub original_order_id: Option<String>,
     pub symbol: String,
+    pub kind: OrderNoticeKind,
+    /// 매도/매수. 알 수 없는 구분 코드면 `None` (통보는 버리지 않는다)
+    pub side: Option<OrderSide>,
+    pub order_qty: Decimal,
+    /// 주문 가격 (시장가 등 가격이 없으면 `None`)
+    pub order_price: Option<Decimal>,
     pub filled_qty: Decimal,
     pub filled_price: Decimal,
+    /// 미체결 잔량. 체결통보는 같은 주문번호의 누적 체결 수량으로 계산한다
+    /// (해외선물옵션은 통보에 실린 잔량)
+    pub remaining_qty: Decimal,
+    /// 통보 시각 (KST)
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 국내주식 시장 구분 (실시간 TR의 KRX / NXT / 통합 변형)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Venue {
+    /// 한국거래소 (`H0ST…`)
+    Krx,
+    /// 넥스트레이드 (`H0NX…`)
+    Nxt,
+    /// KRX + NXT 통합 (`H0UN…`)
+    Integrated,
+}
+
+/// 장운영구분코드 (`MKOP_CLS_CODE`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketPhase {
+    /// 110 장전 동시호가 개시
+    OpeningAuction,
+    /// 112 장 개시 (접속매매, 시가 단일가 종료)
+    Continuous,
+    /// 121 장후 동시호가 개시
+    ClosingAuction,
+    /// 129 장 마감 (종가 단일가 종료)
+    Closed,
+    /// 그 밖의 코드 (시간외, 서킷브레이커, 사이드카 등)
+    Other(String),
+}
+
+impl MarketPhase {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "110" => MarketPhase::OpeningAuction,
+            "112" => MarketPhase::Continuous,
+            "121" => MarketPhase::ClosingAuction,
+            "129" => MarketPhase::Closed,
+            other => MarketPhase::Other(other.to_string()),
+        }
+    }
+
+    /// 단일가(동시호가) 매매 중인지
+    pub fn is_auction(&self) -> bool {
+        matches!(
+            self,
+            MarketPhase::OpeningAuction | MarketPhase::ClosingAuction
+        )
+    }
+}
+
+/// 변동성완화장치(VI) 발동 상태 (`VI_CLS_CODE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViStatus {
+    /// `N`: 미발동·해제
+    Released,
+    /// `1`: 정적 VI
+    Static,
+    /// `2`: 동적 VI
+    Dynamic,
+    /// `3`: 정적 + 동적 VI
+    StaticAndDynamic,
+}
+
+impl ViStatus {
+    /// 알 수 없는 코드는 미발동으로 본다
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "1" => ViStatus::Static,
+            "2" => ViStatus::Dynamic,
+            "3" => ViStatus::StaticAndDynamic,
+            _ => ViStatus::Released,
+        }
+    }
+
+    pub fn is_active(self) -> bool {
+        self != ViStatus::Released
+    }
+}
+
+/// 장운영정보 (H0STMKO0 / H0NXMKO0 / H0UNMKO0). 거래정지·재개, VI, 장운영 단계 변화마다 온다
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketStatusEvent {
+    pub symbol: String,
+    pub venue: Venue,
+    /// 거래정지 여부 (`TRHT_YN`)
+    pub halted: bool,
+    /// 거래정지 사유
+    pub halt_reason: Option<String>,
+    pub phase: MarketPhase,
+    /// 예상 장운영구분코드 원문
+    pub expected_phase_code: String,
+    pub vi: ViStatus,
+    /// 시간외 단일가 VI
+    pub overtime_vi: ViStatus,
+    /// 종목상태구분코드 원문
+    pub status_code: String,
+    /// 수신 시각 (KST, 기록 재생이면 기록된 수신 시각). TR에 시각 필드가 없다
     pub time: DateTime<FixedOffset>,
 }
 
+impl MarketStatusEvent {
+    /// 주문을 낼 수 있는 상태인지 (거래정지가 아님). VI 중에도 단일가 주문은 받는다
+    pub fn is_tradable(&self) -> bool {
+        !self.halted
+    }
+}
+
+/// 실시간 예상체결 (H0STANC0 / H0NXANC0 / H0UNANC0). 동시호가 중 예상 체결가·수량
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedExecutionData {
+    pub symbol: String,
+    pub venue: Venue,
+    /// 예상 체결가
+    pub price: Decimal,
+    /// 예상 체결량
+    pub qty: Decimal,
+    /// 전일 대비
+    pub change: Decimal,
+    /// 전일 대비 등락률 (%)
+    pub change_rate: Decimal,
+    /// 누적 거래량
+    pub accumulated_volume: Decimal,
+    /// 시간 구분 코드 (HOUR_CLS_CODE: A 장후예상, B 장전예상, C 9시이후 예상/VI, D 시간외 단일가 예상)
+    pub session_code: Option<String>,
+    /// KST
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 거래원(회원사) 1곳의 누적 매매
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberFlow {
+    /// 거래원 코드
+    pub member_no: String,
+    pub name: String,
+    /// 누적 수량
+    pub qty: Decimal,
+    /// 직전 대비 수량 증감
+    pub qty_change: Decimal,
+    /// 비중 (%)
+    pub ratio: Decimal,
+    /// 외국계 거래원인지
+    pub foreign: bool,
+}
+
+/// 실시간 회원사 (H0STMBC0 / H0NXMBC0 / H0UNMBC0). 매도·매수 상위 5개 거래원과 외국계 합계
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberTradingData {
+    pub symbol: String,
+    pub venue: Venue,
+    /// 매도 상위 거래원, 1위부터
+    pub sellers: Vec<MemberFlow>,
+    /// 매수 상위 거래원, 1위부터
+    pub buyers: Vec<MemberFlow>,
+    pub foreign_sell_qty: Decimal,
+    pub foreign_buy_qty: Decimal,
+    pub foreign_net_qty: Decimal,
+    /// 수신 시각 (KST, 기록 재생이면 기록된 수신 시각). TR에 시각 필드가 없다
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 실시간 프로그램매매 (H0STPGM0 / H0NXPGM0 / H0UNPGM0). 당일 누적
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramTradingData {
+    pub symbol: String,
+    pub venue: Venue,
+    pub sell_qty: Decimal,
+    pub sell_amount: Decimal,
+    pub buy_qty: Decimal,
+    pub buy_amount: Decimal,
+    pub net_qty: Decimal,
+    pub net_amount: Decimal,
+    /// 프로그램 매도호가 잔량
+    pub ask_qty: Decimal,
+    /// 프로그램 매수호가 잔량
+    pub bid_qty: Decimal,
+    /// 전체 순매수호가 잔량
+    pub net_bid_qty: Decimal,
+    /// KST
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 국내 파생상품 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DerivativeProduct {
+    /// 지수선물 (H0IFCNT0 / H0IFASP0)
+    IndexFuture,
+    /// 지수옵션 (H0IOCNT0 / H0IOASP0)
+    IndexOption,
+    /// 상품선물 (H0CFCNT0 / H0CFASP0)
+    CommodityFuture,
+    /// 주식선물 (H0ZFCNT0 / H0ZFASP0)
+    StockFuture,
+    /// 주식옵션 (H0ZOCNT0 / H0ZOASP0)
+    StockOption,
+    /// KRX 야간선물 (H0MFCNT0 / H0MFASP0)
+    NightFuture,
+    /// KRX 야간옵션 (H0EUCNT0 / H0EUASP0)
+    NightOption,
+}
+
+impl DerivativeProduct {
+    pub fn is_option(self) -> bool {
+        matches!(
+            self,
+            DerivativeProduct::IndexOption
+                | DerivativeProduct::StockOption
+                | DerivativeProduct::NightOption
+        )
+    }
+}
+
+/// 옵션 민감도 (KIS 제공값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Greeks {
+    pub delta: Decimal,
+    pub gamma: Decimal,
+    pub vega: Decimal,
+    pub theta: Decimal,
+    pub rho: Decimal,
+    /// 내재변동성 (%)
+    pub implied_volatility: Decimal,
+    /// 내재가치
+    pub intrinsic_value: Decimal,
+    /// 시간가치
+    pub time_value: Decimal,
+}
+
+/// 국내 선물옵션 실시간 체결
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DerivativeTradeData {
+    pub symbol: String,
+    pub product: DerivativeProduct,
+    pub price: Decimal,
+    /// 최종 체결량
+    pub qty: Decimal,
+    /// 전일 대비
+    pub change: Decimal,
+    /// 전일 대비 등락률 (%)
+    pub change_rate: Decimal,
+    pub accumulated_volume: Decimal,
+    /// 미결제약정 수량
+    pub open_interest: Decimal,
+    /// 미결제약정 수량 증감
+    pub open_interest_change: Decimal,
+    /// HTS 이론가
+    pub theoretical_price: Option<Decimal>,
+    /// 시장 베이시스
+    pub market_basis: Option<Decimal>,
+    /// 이론 베이시스
+    pub theoretical_basis: Option<Decimal>,
+    /// 매도 1호가
+    pub ask_price: Option<Decimal>,
+    /// 매수 1호가
+    pub bid_price: Option<Decimal>,
+    /// 옵션만 제공
+    pub greeks: Option<Greeks>,
+    /// KST. 야간 거래는 자정을 넘기면 다음 날짜가 된다
+    pub time: DateTime<FixedOffset>,
+}
+
+/// 해외선물옵션 실시간 체결 (HDFFF020)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasDerivativeTradeData {
+    /// 종목코드 (예: `6AM24`)
+    pub symbol: String,
+    pub price: Decimal,
+    /// 체결량
+    pub qty: Decimal,
+    /// 전일 대비
+    pub change: Decimal,
+    /// 전일 대비 등락률 (%)
+    pub change_rate: Decimal,
+    pub open: Option<Decimal>,
+    pub high: Option<Decimal>,
+    pub low: Option<Decimal>,
+    /// 전일 종가
+    pub prev_close: Option<Decimal>,
+    pub accumulated_volume: Decimal,
+    /// 전일 정산가
+    pub settlement_price: Option<Decimal>,
+    /// 영업일자 (거래소 기준)
+    pub business_date: Option<NaiveDate>,
+    /// 수신 시각 (KST)
+    pub time: DateTime<FixedOffset>,
+}
+
+/// WebSocket 연결 상태 변화. `session`은 세션 번호 (단일 세션이면 0)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionEvent {
+    /// 연결 또는 재연결 완료
+    Connected { session: usize },
+    /// 연결 끊김. 재연결될 때까지 이 세션의 데이터가 비어 있다
+    Disconnected {
+        session: usize,
+        reason: String,
+        /// 이 세션에 등록된 구독의 `tr_key` (종목코드, 해외는 `DNASAAPL` 형식)
+        tr_keys: Vec<String>,
+    },
+    /// 재연결 시도 (끊긴 뒤 1부터)
+    Reconnecting { session: usize, attempt: u32 },
+    /// 재연결 후 재구독을 마침. `count`는 서버가 받아들인 건수, `failed`는 실패 건수
+    Resubscribed {
+        session: usize,
+        count: usize,
+        failed: usize,
+    },
+    /// 재연결 후 재구독 실패 (`reason`은 거부 메시지 등). 서버가 거부한 구독은 구독 목록에서 빠진다
+    ResubscribeFailed {
+        session: usize,
+        tr_id: String,
+        tr_key: String,
+        reason: String,
+    },
+    /// 재연결 정책 한도를 넘어 재연결을 포기함
+    GaveUp { session: usize, attempts: u32 },
+}
+
 /// WebSocket으로 수신되는 실시간 이벤트
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KisEvent {
     /// 실시간 체결가
     Transaction(TransactionData),
-    /// 실시간 호가
+    /// 실시간 호가 (최우선)
     Quote(QuoteData),
+    /// 실시간 호가 (전체 단계, 잔량 합계, 예상 체결)
+    OrderBook(OrderBookData),
     /// 주문체결통보
     OrderConfirm(OrderConfirmData),
+    /// 장운영정보 (거래정지, VI, 장운영 단계)
+    MarketStatus(MarketStatusEvent),
+    /// 예상체결 (동시호가)
+    ExpectedExecution(ExpectedExecutionData),
+    /// 회원사(거래원)별 매매
+    MemberTrading(MemberTradingData),
+    /// 프로그램매매
+    ProgramTrading(ProgramTradingData),
+    /// 국내 선물옵션 체결 (호가는 `Quote`/`OrderBook`)
+    DerivativeTrade(DerivativeTradeData),
+    /// 해외선물옵션 체결
+    OverseasDerivativeTrade(OverseasDerivativeTradeData),
+    /// 연결 상태 변화
+    Connection(ConnectionEvent),
+}
+
+impl KisEvent {
+    /// 종목코드. 연결 이벤트는 빈 문자열
+    pub fn symbol(&self) -> &str {
+        match self {
+            KisEvent::Transaction(d) => &d.symbol,
+            KisEvent::Quote(d) => &d.symbol,
+            KisEvent::OrderBook(d) => &d.symbol,
+            KisEvent::OrderConfirm(d) => &d.symbol,
+            KisEvent::MarketStatus(d) => &d.symbol,
+            KisEvent::ExpectedExecution(d) => &d.symbol,
+            KisEvent::MemberTrading(d) => &d.symbol,
+            KisEvent::ProgramTrading(d) => &d.symbol,
+            KisEvent::DerivativeTrade(d) => &d.symbol,
+            KisEvent::OverseasDerivativeTrade(d) => &d.symbol,
+            KisEvent::Connection(_) => "",
+        }
+    }
+
+    /// 해외 종목의 실시간 키
+    pub fn overseas_key(&self) -> Option<&OverseasRealtimeKey> {
+        match self {
+            KisEvent::Transaction(d) => d.overseas_key.as_ref(),
+            KisEvent::Quote(d) => d.overseas_key.as_ref(),
+            KisEvent::OrderBook(d) => d.overseas_key.as_ref(),
+            KisEvent::OrderConfirm(_)
+            | KisEvent::MarketStatus(_)
+            | KisEvent::ExpectedExecution(_)
+            | KisEvent::MemberTrading(_)
+            | KisEvent::ProgramTrading(_)
+            | KisEvent::DerivativeTrade(_)
+            | KisEvent::OverseasDerivativeTrade(_)
+            | KisEvent::Connection(_) => None,
+        }
+    }
+
+    /// 수신기 전달 방식과 무관하게 유실 없이 전달되는 이벤트인지 (주문체결통보, 장운영정보, 연결 상태)
+    pub fn is_lossless(&self) -> bool {
+        matches!(
+            self,
+            KisEvent::OrderConfirm(_) | KisEvent::MarketStatus(_) | KisEvent::Connection(_)
+        )
+    }
+
+    /// 종목코드, 해외 실시간 키(`DNASNVDA`) 또는 체결통보의 HTS ID가 `symbol`과 같은지
+    pub fn matches_symbol(&self, symbol: &str) -> bool {
+        self.symbol() == symbol
+            || self.overseas_key().is_some_and(|k| k.tr_key() == symbol)
+            || matches!(self, KisEvent::OrderConfirm(d) if d.customer_id == symbol)
+    }
 }
 
 #[cfg(test)]
@@ -56,6 +619,7 @@ mod tests {
     fn sample_transaction() -> TransactionData {
         TransactionData {
             symbol: "AAPL".into(),
+            overseas_key: Some(OverseasRealtimeKey::delayed("NAS", "AAPL")),
             price: dec!(190.50),
             qty: dec!(10),
             time: kst().with_ymd_and_hms(2026, 3, 21, 10, 0, 0).unwrap(),
@@ -73,6 +637,7 @@ mod tests {
     fn quote_event_fields() {
         let q = QuoteData {
             symbol: "AAPL".into(),
+            overseas_key: None,
             ask_price: dec!(191.00),
             bid_price: dec!(190.90),
             ask_qty: dec!(100),
@@ -82,6 +647,32 @@ mod tests {
         assert_eq!(q.ask_price, dec!(191.00));
     }
 
+    #[test]
+    fn overseas_key_round_trips() {
+        let key: OverseasRealtimeKey = "DNASNVDA".parse().unwrap();
+        assert_eq!(key, OverseasRealtimeKey::delayed("NAS", "NVDA"));
+        assert_eq!(key.tr_key(), "DNASNVDA");
+
+        let key: OverseasRealtimeKey = "RHKS00700".parse().unwrap();
+        assert_eq!(key.feed, OverseasFeed::Realtime);
+        assert_eq!(key.exchange, "HKS");
+        assert_eq!(key.ticker, "00700");
+
+        for bad in ["", "NVDA", "DNAS", "Dnasnvda", "XNASNVDA"] {
+            assert!(bad.parse::<OverseasRealtimeKey>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn event_matches_symbol_or_overseas_key() {
+        let ev = KisEvent::Transaction(sample_transaction());
+        assert_eq!(ev.symbol(), "AAPL");
+        assert!(ev.matches_symbol("AAPL"));
+        assert!(ev.matches_symbol("DNASAAPL"));
+        assert!(!ev.matches_symbol("RNASAAPL"));
+        assert!(!ev.matches_symbol("NVDA"));
+    }
+
     fn assert_send_sync<T: Send + Sync>() {}
 
     #[test]
@@ -3,34 +3,37 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct DomesticBondTrading(pub(crate) KisClient);
+pub struct DomesticBondTrading<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct DomesticBondQuotations(pub(crate) KisClient);
+pub struct DomesticBondQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::DomesticBond {
-    pub fn trading(&self) -> DomesticBondTrading {
+impl<E> crate::endpoints::DomesticBond<E> {
+    pub fn trading(&self) -> DomesticBondTrading<E> {
         DomesticBondTrading(self.0.clone())
     }
-    pub fn quotations(&self) -> DomesticBondQuotations {
+    pub fn quotations(&self) -> DomesticBondQuotations<E> {
         DomesticBondQuotations(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl DomesticBondTrading {
+impl<E> DomesticBondTrading<E> {
     /// 장내채권 매수주문 [국내주식-124]
     /// - TR_ID: Real=TTTC0952U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-bond/v1/trading/buy
     pub async fn domestic_bond_v1_trading_buy(
         &self,
         req: DomesticBondV1TradingBuyRequest,
-    ) -> Result<DomesticBondV1TradingBuyResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingBuyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0952U", "https://openapi.koreainvestment.com:9443")
@@ -48,7 +51,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_sell(
         &self,
         req: DomesticBondV1TradingSellRequest,
-    ) -> Result<DomesticBondV1TradingSellResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingSellResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0958U", "https://openapi.koreainvestment.com:9443")
@@ -66,7 +72,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_order_rvsecncl(
         &self,
         req: DomesticBondV1TradingOrderRvsecnclRequest,
-    ) -> Result<DomesticBondV1TradingOrderRvsecnclResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0953U", "https://openapi.koreainvestment.com:9443")
@@ -89,7 +98,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_inquire_psbl_rvsecncl(
         &self,
         req: DomesticBondV1TradingInquirePsblRvsecnclRequest,
-    ) -> Result<DomesticBondV1TradingInquirePsblRvsecnclResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingInquirePsblRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC8035R", "https://openapi.koreainvestment.com:9443")
@@ -112,7 +124,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_inquire_daily_ccld(
         &self,
         req: DomesticBondV1TradingInquireDailyCcldRequest,
-    ) -> Result<DomesticBondV1TradingInquireDailyCcldResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingInquireDailyCcldResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC8013R", "https://openapi.koreainvestment.com:9443")
@@ -135,7 +150,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_inquire_balance(
         &self,
         req: DomesticBondV1TradingInquireBalanceRequest,
-    ) -> Result<DomesticBondV1TradingInquireBalanceResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingInquireBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC8407R", "https://openapi.koreainvestment.com:9443")
@@ -158,7 +176,10 @@ impl DomesticBondTrading {
     pub async fn domestic_bond_v1_trading_inquire_psbl_order(
         &self,
         req: DomesticBondV1TradingInquirePsblOrderRequest,
-    ) -> Result<DomesticBondV1TradingInquirePsblOrderResponse, KisError> {
+    ) -> Result<DomesticBondV1TradingInquirePsblOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8910R", "https://openapi.koreainvestment.com:9443")
@@ -177,14 +198,17 @@ impl DomesticBondTrading {
 }
 
 #[allow(non_snake_case)]
-impl DomesticBondQuotations {
+impl<E> DomesticBondQuotations<E> {
     /// 장내채권현재가(호가) [국내주식-132]
     /// - TR_ID: Real=FHKBJ773401C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-bond/v1/quotations/inquire-asking-price
     pub async fn domestic_bond_v1_quotations_inquire_asking_price(
         &self,
         req: DomesticBondV1QuotationsInquireAskingPriceRequest,
-    ) -> Result<DomesticBondV1QuotationsInquireAskingPriceResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsInquireAskingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKBJ773401C0", "https://openapi.koreainvestment.com:9443")
@@ -207,7 +231,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_inquire_price(
         &self,
         req: DomesticBondV1QuotationsInquirePriceRequest,
-    ) -> Result<DomesticBondV1QuotationsInquirePriceResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsInquirePriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKBJ773400C0", "https://openapi.koreainvestment.com:9443")
@@ -230,7 +257,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_inquire_ccnl(
         &self,
         req: DomesticBondV1QuotationsInquireCcnlRequest,
-    ) -> Result<DomesticBondV1QuotationsInquireCcnlResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsInquireCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKBJ773403C0", "https://openapi.koreainvestment.com:9443")
@@ -253,7 +283,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_inquire_daily_price(
         &self,
         req: DomesticBondV1QuotationsInquireDailyPriceRequest,
-    ) -> Result<DomesticBondV1QuotationsInquireDailyPriceResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsInquireDailyPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKBJ773404C0", "https://openapi.koreainvestment.com:9443")
@@ -276,7 +309,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_inquire_daily_itemchartprice(
         &self,
         req: DomesticBondV1QuotationsInquireDailyItemchartpriceRequest,
-    ) -> Result<DomesticBondV1QuotationsInquireDailyItemchartpriceResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsInquireDailyItemchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKBJ773701C0", "https://openapi.koreainvestment.com:9443")
@@ -299,7 +335,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_avg_unit(
         &self,
         req: DomesticBondV1QuotationsAvgUnitRequest,
-    ) -> Result<DomesticBondV1QuotationsAvgUnitResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsAvgUnitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF2005R", "https://openapi.koreainvestment.com:9443")
@@ -322,7 +361,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_issue_info(
         &self,
         req: DomesticBondV1QuotationsIssueInfoRequest,
-    ) -> Result<DomesticBondV1QuotationsIssueInfoResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsIssueInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF1101R", "https://openapi.koreainvestment.com:9443")
@@ -345,7 +387,10 @@ impl DomesticBondQuotations {
     pub async fn domestic_bond_v1_quotations_search_bond_info(
         &self,
         req: DomesticBondV1QuotationsSearchBondInfoRequest,
-    ) -> Result<DomesticBondV1QuotationsSearchBondInfoResponse, KisError> {
+    ) -> Result<DomesticBondV1QuotationsSearchBondInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF1114R", "https://openapi.koreainvestment.com:9443")
@@ -3,34 +3,37 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct ElwQuotations(pub(crate) KisClient);
+pub struct ElwQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct ElwRanking(pub(crate) KisClient);
+pub struct ElwRanking<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::Elw {
-    pub fn quotations(&self) -> ElwQuotations {
+impl<E> crate::endpoints::Elw<E> {
+    pub fn quotations(&self) -> ElwQuotations<E> {
         ElwQuotations(self.0.clone())
     }
-    pub fn ranking(&self) -> ElwRanking {
+    pub fn ranking(&self) -> ElwRanking<E> {
         ElwRanking(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl ElwQuotations {
+impl<E> ElwQuotations<E> {
     /// ELW 신규상장종목 [국내주식-181]
     /// - TR_ID: Real=FHKEW154800C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/elw/v1/quotations/newly-listed
     pub async fn elw_v1_quotations_newly_listed(
         &self,
         req: ElwV1QuotationsNewlyListedRequest,
-    ) -> Result<ElwV1QuotationsNewlyListedResponse, KisError> {
+    ) -> Result<ElwV1QuotationsNewlyListedResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW154800C0", "https://openapi.koreainvestment.com:9443")
@@ -48,7 +51,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_udrl_asset_price(
         &self,
         req: ElwV1QuotationsUdrlAssetPriceRequest,
-    ) -> Result<ElwV1QuotationsUdrlAssetPriceResponse, KisError> {
+    ) -> Result<ElwV1QuotationsUdrlAssetPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW154101C0", "https://openapi.koreainvestment.com:9443")
@@ -71,7 +77,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_cond_search(
         &self,
         req: ElwV1QuotationsCondSearchRequest,
-    ) -> Result<ElwV1QuotationsCondSearchResponse, KisError> {
+    ) -> Result<ElwV1QuotationsCondSearchResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW15100000", "https://openapi.koreainvestment.com:9443")
@@ -89,7 +98,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_udrl_asset_list(
         &self,
         req: ElwV1QuotationsUdrlAssetListRequest,
-    ) -> Result<ElwV1QuotationsUdrlAssetListResponse, KisError> {
+    ) -> Result<ElwV1QuotationsUdrlAssetListResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW154100C0", "https://openapi.koreainvestment.com:9443")
@@ -112,7 +124,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_compare_stocks(
         &self,
         req: ElwV1QuotationsCompareStocksRequest,
-    ) -> Result<ElwV1QuotationsCompareStocksResponse, KisError> {
+    ) -> Result<ElwV1QuotationsCompareStocksResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW151701C0", "https://openapi.koreainvestment.com:9443")
@@ -135,7 +150,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_lp_trade_trend(
         &self,
         req: ElwV1QuotationsLpTradeTrendRequest,
-    ) -> Result<ElwV1QuotationsLpTradeTrendResponse, KisError> {
+    ) -> Result<ElwV1QuotationsLpTradeTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW03760000", "https://openapi.koreainvestment.com:9443")
@@ -158,7 +176,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_indicator_trend_ccnl(
         &self,
         req: ElwV1QuotationsIndicatorTrendCcnlRequest,
-    ) -> Result<ElwV1QuotationsIndicatorTrendCcnlResponse, KisError> {
+    ) -> Result<ElwV1QuotationsIndicatorTrendCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02740100", "https://openapi.koreainvestment.com:9443")
@@ -181,7 +202,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_indicator_trend_minute(
         &self,
         req: ElwV1QuotationsIndicatorTrendMinuteRequest,
-    ) -> Result<ElwV1QuotationsIndicatorTrendMinuteResponse, KisError> {
+    ) -> Result<ElwV1QuotationsIndicatorTrendMinuteResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02740300", "https://openapi.koreainvestment.com:9443")
@@ -204,7 +228,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_indicator_trend_daily(
         &self,
         req: ElwV1QuotationsIndicatorTrendDailyRequest,
-    ) -> Result<ElwV1QuotationsIndicatorTrendDailyResponse, KisError> {
+    ) -> Result<ElwV1QuotationsIndicatorTrendDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02740200", "https://openapi.koreainvestment.com:9443")
@@ -227,7 +254,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_volatility_trend_tick(
         &self,
         req: ElwV1QuotationsVolatilityTrendTickRequest,
-    ) -> Result<ElwV1QuotationsVolatilityTrendTickResponse, KisError> {
+    ) -> Result<ElwV1QuotationsVolatilityTrendTickResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02840400", "https://openapi.koreainvestment.com:9443")
@@ -250,7 +280,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_volatility_trend_ccnl(
         &self,
         req: ElwV1QuotationsVolatilityTrendCcnlRequest,
-    ) -> Result<ElwV1QuotationsVolatilityTrendCcnlResponse, KisError> {
+    ) -> Result<ElwV1QuotationsVolatilityTrendCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02840100", "https://openapi.koreainvestment.com:9443")
@@ -273,7 +306,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_volatility_trend_daily(
         &self,
         req: ElwV1QuotationsVolatilityTrendDailyRequest,
-    ) -> Result<ElwV1QuotationsVolatilityTrendDailyResponse, KisError> {
+    ) -> Result<ElwV1QuotationsVolatilityTrendDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02840200", "https://openapi.koreainvestment.com:9443")
@@ -296,7 +332,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_sensitivity_trend_ccnl(
         &self,
         req: ElwV1QuotationsSensitivityTrendCcnlRequest,
-    ) -> Result<ElwV1QuotationsSensitivityTrendCcnlResponse, KisError> {
+    ) -> Result<ElwV1QuotationsSensitivityTrendCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02830100", "https://openapi.koreainvestment.com:9443")
@@ -319,7 +358,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_volatility_trend_minute(
         &self,
         req: ElwV1QuotationsVolatilityTrendMinuteRequest,
-    ) -> Result<ElwV1QuotationsVolatilityTrendMinuteResponse, KisError> {
+    ) -> Result<ElwV1QuotationsVolatilityTrendMinuteResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02840300", "https://openapi.koreainvestment.com:9443")
@@ -342,7 +384,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_sensitivity_trend_daily(
         &self,
         req: ElwV1QuotationsSensitivityTrendDailyRequest,
-    ) -> Result<ElwV1QuotationsSensitivityTrendDailyResponse, KisError> {
+    ) -> Result<ElwV1QuotationsSensitivityTrendDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02830200", "https://openapi.koreainvestment.com:9443")
@@ -365,7 +410,10 @@ impl ElwQuotations {
     pub async fn elw_v1_quotations_expiration_stocks(
         &self,
         req: ElwV1QuotationsExpirationStocksRequest,
-    ) -> Result<ElwV1QuotationsExpirationStocksResponse, KisError> {
+    ) -> Result<ElwV1QuotationsExpirationStocksResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKEW154700C0", "https://openapi.koreainvestment.com:9443")
@@ -384,14 +432,17 @@ impl ElwQuotations {
 }
 
 #[allow(non_snake_case)]
-impl ElwRanking {
+impl<E> ElwRanking<E> {
     /// ELW 민감도 순위[국내주식-170]
     /// - TR_ID: Real=FHPEW02850000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/elw/v1/ranking/sensitivity
     pub async fn elw_v1_ranking_sensitivity(
         &self,
         req: ElwV1RankingSensitivityRequest,
-    ) -> Result<ElwV1RankingSensitivityResponse, KisError> {
+    ) -> Result<ElwV1RankingSensitivityResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02850000", "https://openapi.koreainvestment.com:9443")
@@ -409,7 +460,10 @@ impl ElwRanking {
     pub async fn elw_v1_ranking_quick_change(
         &self,
         req: ElwV1RankingQuickChangeRequest,
-    ) -> Result<ElwV1RankingQuickChangeResponse, KisError> {
+    ) -> Result<ElwV1RankingQuickChangeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02870000", "https://openapi.koreainvestment.com:9443")
@@ -427,7 +481,10 @@ impl ElwRanking {
     pub async fn elw_v1_ranking_indicator(
         &self,
         req: ElwV1RankingIndicatorRequest,
-    ) -> Result<ElwV1RankingIndicatorResponse, KisError> {
+    ) -> Result<ElwV1RankingIndicatorResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02790000", "https://openapi.koreainvestment.com:9443")
@@ -445,7 +502,10 @@ impl ElwRanking {
     pub async fn elw_v1_ranking_updown_rate(
         &self,
         req: ElwV1RankingUpdownRateRequest,
-    ) -> Result<ElwV1RankingUpdownRateResponse, KisError> {
+    ) -> Result<ElwV1RankingUpdownRateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02770000", "https://openapi.koreainvestment.com:9443")
@@ -463,7 +523,10 @@ impl ElwRanking {
     pub async fn elw_v1_ranking_volume_rank(
         &self,
         req: ElwV1RankingVolumeRankRequest,
-    ) -> Result<ElwV1RankingVolumeRankResponse, KisError> {
+    ) -> Result<ElwV1RankingVolumeRankResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPEW02780000", "https://openapi.koreainvestment.com:9443")
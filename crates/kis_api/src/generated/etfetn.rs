@@ -3,28 +3,31 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct EtfEtnQuotations(pub(crate) KisClient);
+pub struct EtfEtnQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::EtfEtn {
-    pub fn quotations(&self) -> EtfEtnQuotations {
+impl<E> crate::endpoints::EtfEtn<E> {
+    pub fn quotations(&self) -> EtfEtnQuotations<E> {
         EtfEtnQuotations(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl EtfEtnQuotations {
+impl<E> EtfEtnQuotations<E> {
     /// ETF/ETN 현재가[v1_국내주식-068]
     /// - TR_ID: Real=FHPST02400000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/etfetn/v1/quotations/inquire-price
     pub async fn etfetn_v1_quotations_inquire_price(
         &self,
         req: EtfetnV1QuotationsInquirePriceRequest,
-    ) -> Result<EtfetnV1QuotationsInquirePriceResponse, KisError> {
+    ) -> Result<EtfetnV1QuotationsInquirePriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02400000", "https://openapi.koreainvestment.com:9443")
@@ -47,7 +50,10 @@ impl EtfEtnQuotations {
     pub async fn etfetn_v1_quotations_inquire_component_stock_price(
         &self,
         req: EtfetnV1QuotationsInquireComponentStockPriceRequest,
-    ) -> Result<EtfetnV1QuotationsInquireComponentStockPriceResponse, KisError> {
+    ) -> Result<EtfetnV1QuotationsInquireComponentStockPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST121600C0", "https://openapi.koreainvestment.com:9443")
@@ -70,7 +76,10 @@ impl EtfEtnQuotations {
     pub async fn etfetn_v1_quotations_nav_comparison_trend(
         &self,
         req: EtfetnV1QuotationsNavComparisonTrendRequest,
-    ) -> Result<EtfetnV1QuotationsNavComparisonTrendResponse, KisError> {
+    ) -> Result<EtfetnV1QuotationsNavComparisonTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02440000", "https://openapi.koreainvestment.com:9443")
@@ -93,7 +102,10 @@ impl EtfEtnQuotations {
     pub async fn etfetn_v1_quotations_nav_comparison_daily_trend(
         &self,
         req: EtfetnV1QuotationsNavComparisonDailyTrendRequest,
-    ) -> Result<EtfetnV1QuotationsNavComparisonDailyTrendResponse, KisError> {
+    ) -> Result<EtfetnV1QuotationsNavComparisonDailyTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => ("FHPST02440200", ""),
             crate::client::KisEnv::Vts => ("모의투자 미지원", ""),
@@ -114,7 +126,10 @@ impl EtfEtnQuotations {
     pub async fn etfetn_v1_quotations_nav_comparison_time_trend(
         &self,
         req: EtfetnV1QuotationsNavComparisonTimeTrendRequest,
-    ) -> Result<EtfetnV1QuotationsNavComparisonTimeTrendResponse, KisError> {
+    ) -> Result<EtfetnV1QuotationsNavComparisonTimeTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02440100", "https://openapi.koreainvestment.com:9443")
@@ -1,8 +1,16 @@
 pub mod config;
+#[cfg(feature = "bond")]
 pub mod domestic_bond;
+#[cfg(feature = "elw")]
 pub mod elw;
+#[cfg(feature = "etfetn")]
 pub mod etfetn;
 pub mod models;
+#[cfg(feature = "overseas-stock")]
 pub mod overseas;
+#[cfg(feature = "overseas-futureoption")]
 pub mod overseas_future_option;
+#[cfg(feature = "websocket")]
+pub mod realtime;
+#[cfg(any(feature = "domestic-stock", feature = "domestic-futureoption"))]
 pub mod stock;
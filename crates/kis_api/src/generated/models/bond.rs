@@ -0,0 +1,2054 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [장내채권 매수주문 [국내주식-124]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingBuyRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량2 (String, 필수)
+    #[serde(rename = "ORD_QTY2")]
+    pub ord_qty2: String,
+    /// 채권주문단가 (String, 필수)
+    #[serde(rename = "BOND_ORD_UNPR")]
+    pub bond_ord_unpr: String,
+    /// 소액시장참여여부 (String, 필수)
+    #[serde(rename = "SAMT_MKET_PTCI_YN")]
+    pub samt_mket_ptci_yn: String,
+    /// 채권소매시장여부 (String, 필수)
+    #[serde(rename = "BOND_RTL_MKET_YN")]
+    pub bond_rtl_mket_yn: String,
+    /// 유치자직원번호 (String, 필수)
+    #[serde(rename = "IDCR_STFNO")]
+    pub idcr_stfno: String,
+    /// 운용사지정주문번호 (String, 필수)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 연락전화번호 (String, 필수)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+}
+
+/// [장내채권 매도주문 [국내주식-123]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingSellRequest {
+    ///  종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    ///  상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량2 (String, 필수)
+    #[serde(rename = "ORD_QTY2")]
+    pub ord_qty2: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "BOND_ORD_UNPR")]
+    pub bond_ord_unpr: String,
+    /// 분리과세여부 (String, 필수)
+    #[serde(rename = "SPRX_YN")]
+    pub sprx_yn: String,
+    /// 매수일자 (String, 필수)
+    #[serde(rename = "BUY_DT")]
+    pub buy_dt: String,
+    /// 매수순번 (String, 필수)
+    #[serde(rename = "BUY_SEQ")]
+    pub buy_seq: String,
+    /// 소액시장참여여부 (String, 필수)
+    #[serde(rename = "SAMT_MKET_PTCI_YN")]
+    pub samt_mket_ptci_yn: String,
+    /// 매도대행사반대매도여부 (String, 필수)
+    #[serde(rename = "SLL_AGCO_OPPS_SLL_YN")]
+    pub sll_agco_opps_sll_yn: String,
+    /// 채권소매시장여부 (String, 필수)
+    #[serde(rename = "BOND_RTL_MKET_YN")]
+    pub bond_rtl_mket_yn: String,
+    /// 운용사지정주문번호 (String, 필수)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 연락전화번호 (String, 필수)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+}
+
+/// [장내채권 정정취소주문 [국내주식-125]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingOrderRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 주문수량2 (String, 필수)
+    #[serde(rename = "ORD_QTY2")]
+    pub ord_qty2: String,
+    /// 채권주문단가 (String, 필수)
+    #[serde(rename = "BOND_ORD_UNPR")]
+    pub bond_ord_unpr: String,
+    /// 잔량전부주문여부 (String, 필수)
+    #[serde(rename = "QTY_ALL_ORD_YN")]
+    pub qty_all_ord_yn: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 운용사지정주문번호 (String, 필수)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 연락전화번호 (String, 필수)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+}
+
+/// [채권정정취소가능주문조회  [국내주식-126]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquirePsblRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자 (String, 필수)
+    #[serde(rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문번호 (String, 필수)
+    #[serde(rename = "ODNO")]
+    pub odno: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [장내채권 주문체결내역 [국내주식-127]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquireDailyCcldRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 정렬순서구분 (String, 필수)
+    #[serde(rename = "SORT_SQN_DVSN")]
+    pub sort_sqn_dvsn: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 미체결여부 (String, 필수)
+    #[serde(rename = "NCCS_YN")]
+    pub nccs_yn: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+}
+
+/// [장내채권 잔고조회  [국내주식-198]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquireBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회조건 (String, 필수)
+    #[serde(rename = "INQR_CNDT")]
+    pub inqr_cndt: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매수일자 (String, 필수)
+    #[serde(rename = "BUY_DT")]
+    pub buy_dt: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [장내채권 매수가능조회 [국내주식-199]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquirePsblOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 채권주문단가 (String, 필수)
+    #[serde(rename = "BOND_ORD_UNPR")]
+    pub bond_ord_unpr: String,
+    /// 소액시장참여여부 (String, 필수)
+    #[serde(rename = "SAMT_MKET_PTCI_YN")]
+    pub samt_mket_ptci_yn: String,
+}
+
+/// [장내채권현재가(호가) [국내주식-132]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireAskingPriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [장내채권현재가(시세) [국내주식-200]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquirePriceRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [장내채권현재가(체결) [국내주식-201]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireCcnlRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [장내채권현재가(일별) [국내주식-202]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireDailyPriceRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [장내채권 기간별시세(일) [국내주식-159]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireDailyItemchartpriceRequest {
+    /// 조건 시장 구분 코드  (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [장내채권 평균단가조회 [국내주식-158]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsAvgUnitRequest {
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 검증종류코드 (String, 필수)
+    #[serde(rename = "VRFC_KIND_CD")]
+    pub vrfc_kind_cd: String,
+    /// 연속조회키30 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK30")]
+    pub ctx_area_nk30: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+}
+
+/// [장내채권 발행정보[국내주식-156]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsIssueInfoRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+}
+
+/// [장내채권 기본조회 [국내주식-129]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsSearchBondInfoRequest {
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+}
+
+/// [장내채권 매수주문 [국내주식-124]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingBuyOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "krx_fwdg_ord_orgno")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 매수주문 [국내주식-124]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingBuyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingBuyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingBuyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 매도주문 [국내주식-123]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingSellOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "krx_fwdg_ord_orgno")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    ///  주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 매도주문 [국내주식-123]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingSellResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingSellOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingSellResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 정정취소주문 [국내주식-125]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingOrderRvsecnclOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "krx_fwdg_ord_orgno")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 정정취소주문 [국내주식-125]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [채권정정취소가능주문조회  [국내주식-126]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquirePsblRvsecnclOutputItem {
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 정정취소구분명
+    #[serde(default, rename = "rvse_cncl_dvsn_name")]
+    pub rvse_cncl_dvsn_name: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 채권주문단가
+    #[serde(default, rename = "bond_ord_unpr")]
+    pub bond_ord_unpr: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문구분코드
+    #[serde(default, rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: String,
+    /// 운용사지정주문번호
+    #[serde(default, rename = "mgco_aptm_odno")]
+    pub mgco_aptm_odno: String,
+    /// 소액시장참여여부
+    #[serde(default, rename = "samt_mket_ptci_yn")]
+    pub samt_mket_ptci_yn: String,
+    /// 상품약어명
+    #[serde(default, rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [채권정정취소가능주문조회  [국내주식-126]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingInquirePsblRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingInquirePsblRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingInquirePsblRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 주문체결내역 [국내주식-127]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquireDailyCcldOutput1Item {
+    /// 총주문수량
+    #[serde(default, rename = "tot_ord_qty")]
+    pub tot_ord_qty: Decimal,
+    /// 총체결수량합계
+    #[serde(default, rename = "tot_ccld_qty_smtl")]
+    pub tot_ccld_qty_smtl: String,
+    /// 총채권체결평균단가
+    #[serde(default, rename = "tot_bond_ccld_avg_unpr")]
+    pub tot_bond_ccld_avg_unpr: Decimal,
+    /// 총체결금액합계
+    #[serde(default, rename = "tot_ccld_amt_smtl")]
+    pub tot_ccld_amt_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 주문체결내역 [국내주식-127]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquireDailyCcldOutput2Item {
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 주문구분명
+    #[serde(default, rename = "ord_dvsn_name")]
+    pub ord_dvsn_name: String,
+    /// 매도매수구분코드명
+    #[serde(default, rename = "sll_buy_dvsn_cd_name")]
+    pub sll_buy_dvsn_cd_name: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품약어명
+    #[serde(default, rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 채권주문단가
+    #[serde(default, rename = "bond_ord_unpr")]
+    pub bond_ord_unpr: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 채권평균단가
+    #[serde(default, rename = "bond_avg_unpr")]
+    pub bond_avg_unpr: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 매수일자
+    #[serde(default, rename = "buy_dt")]
+    pub buy_dt: String,
+    /// 소액시장참여여부명
+    #[serde(default, rename = "samt_mket_ptci_yn_name")]
+    pub samt_mket_ptci_yn_name: String,
+    /// 분리과세가능여부알림
+    #[serde(default, rename = "sprx_psbl_yn_ifom")]
+    pub sprx_psbl_yn_ifom: String,
+    /// 주문매체구분명
+    #[serde(default, rename = "ord_mdia_dvsn_name")]
+    pub ord_mdia_dvsn_name: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 미체결수량
+    #[serde(default, rename = "nccs_qty")]
+    pub nccs_qty: Decimal,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 주문체결내역 [국내주식-127]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingInquireDailyCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticBondV1TradingInquireDailyCcldOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticBondV1TradingInquireDailyCcldOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingInquireDailyCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 잔고조회  [국내주식-198]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquireBalanceOutputItem {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매수일자
+    #[serde(default, rename = "buy_dt")]
+    pub buy_dt: String,
+    /// 매수일련번호
+    #[serde(default, rename = "buy_sqno")]
+    pub buy_sqno: String,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 종합과세수량
+    #[serde(default, rename = "agrx_qty")]
+    pub agrx_qty: Decimal,
+    /// 분리과세수량
+    #[serde(default, rename = "sprx_qty")]
+    pub sprx_qty: Decimal,
+    /// 만기일
+    #[serde(default, rename = "exdt")]
+    pub exdt: String,
+    /// 매수수익율
+    #[serde(default, rename = "buy_erng_rt")]
+    pub buy_erng_rt: Decimal,
+    /// 매수단가
+    #[serde(default, rename = "buy_unpr")]
+    pub buy_unpr: Decimal,
+    /// 매수금액
+    #[serde(default, rename = "buy_amt")]
+    pub buy_amt: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 잔고조회  [국내주식-198]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingInquireBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingInquireBalanceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingInquireBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 매수가능조회 [국내주식-199]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1TradingInquirePsblOrderOutputItem {
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 재사용가능금액
+    #[serde(default, rename = "ruse_psbl_amt")]
+    pub ruse_psbl_amt: Decimal,
+    /// 채권주문단가2
+    #[serde(default, rename = "bond_ord_unpr2")]
+    pub bond_ord_unpr2: Decimal,
+    /// 매수가능금액
+    #[serde(default, rename = "buy_psbl_amt")]
+    pub buy_psbl_amt: Decimal,
+    /// 매수가능수량
+    #[serde(default, rename = "buy_psbl_qty")]
+    pub buy_psbl_qty: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 매수가능조회 [국내주식-199]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1TradingInquirePsblOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1TradingInquirePsblOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1TradingInquirePsblOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권현재가(호가) [국내주식-132]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireAskingPriceOutputItem {
+    /// 호가 접수 시간
+    #[serde(default, rename = "aspr_acpt_hour")]
+    pub aspr_acpt_hour: String,
+    /// 채권 매도호가1
+    #[serde(default, rename = "bond_askp1")]
+    pub bond_askp1: String,
+    /// 채권 매도호가2
+    #[serde(default, rename = "bond_askp2")]
+    pub bond_askp2: String,
+    /// 채권 매도호가3
+    #[serde(default, rename = "bond_askp3")]
+    pub bond_askp3: String,
+    /// 채권 매도호가4
+    #[serde(default, rename = "bond_askp4")]
+    pub bond_askp4: String,
+    /// 채권 매도호가5
+    #[serde(default, rename = "bond_askp5")]
+    pub bond_askp5: String,
+    /// 채권 매수호가1
+    #[serde(default, rename = "bond_bidp1")]
+    pub bond_bidp1: String,
+    /// 채권 매수호가2
+    #[serde(default, rename = "bond_bidp2")]
+    pub bond_bidp2: String,
+    /// 채권 매수호가3
+    #[serde(default, rename = "bond_bidp3")]
+    pub bond_bidp3: String,
+    /// 채권 매수호가4
+    #[serde(default, rename = "bond_bidp4")]
+    pub bond_bidp4: String,
+    /// 채권 매수호가5
+    #[serde(default, rename = "bond_bidp5")]
+    pub bond_bidp5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "askp_rsqn1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "askp_rsqn2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "askp_rsqn3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "askp_rsqn4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "askp_rsqn5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "bidp_rsqn1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "bidp_rsqn2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "bidp_rsqn3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "bidp_rsqn4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "bidp_rsqn5")]
+    pub bidp_rsqn5: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 순매수 호가 잔량
+    #[serde(default, rename = "ntby_aspr_rsqn")]
+    pub ntby_aspr_rsqn: String,
+    /// 매도 수익 비율1
+    #[serde(default, rename = "seln_ernn_rate1")]
+    pub seln_ernn_rate1: String,
+    /// 매도 수익 비율2
+    #[serde(default, rename = "seln_ernn_rate2")]
+    pub seln_ernn_rate2: String,
+    /// 매도 수익 비율3
+    #[serde(default, rename = "seln_ernn_rate3")]
+    pub seln_ernn_rate3: String,
+    /// 매도 수익 비율4
+    #[serde(default, rename = "seln_ernn_rate4")]
+    pub seln_ernn_rate4: String,
+    /// 매도 수익 비율5
+    #[serde(default, rename = "seln_ernn_rate5")]
+    pub seln_ernn_rate5: String,
+    /// 매수2 수익 비율1
+    #[serde(default, rename = "shnu_ernn_rate1")]
+    pub shnu_ernn_rate1: String,
+    /// 매수2 수익 비율2
+    #[serde(default, rename = "shnu_ernn_rate2")]
+    pub shnu_ernn_rate2: String,
+    /// 매수2 수익 비율3
+    #[serde(default, rename = "shnu_ernn_rate3")]
+    pub shnu_ernn_rate3: String,
+    /// 매수2 수익 비율4
+    #[serde(default, rename = "shnu_ernn_rate4")]
+    pub shnu_ernn_rate4: String,
+    /// 매수2 수익 비율5
+    #[serde(default, rename = "shnu_ernn_rate5")]
+    pub shnu_ernn_rate5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권현재가(호가) [국내주식-132]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsInquireAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsInquireAskingPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsInquireAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권현재가(시세) [국내주식-200]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquirePriceOutputItem {
+    /// 표준종목코드
+    #[serde(default, rename = "stnd_iscd")]
+    pub stnd_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 채권현재가
+    #[serde(default, rename = "bond_prpr")]
+    pub bond_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 채권전일대비
+    #[serde(default, rename = "bond_prdy_vrss")]
+    pub bond_prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 채권전일종가
+    #[serde(default, rename = "bond_prdy_clpr")]
+    pub bond_prdy_clpr: String,
+    /// 채권시가2
+    #[serde(default, rename = "bond_oprc")]
+    pub bond_oprc: String,
+    /// 채권고가
+    #[serde(default, rename = "bond_hgpr")]
+    pub bond_hgpr: String,
+    /// 채권저가
+    #[serde(default, rename = "bond_lwpr")]
+    pub bond_lwpr: String,
+    /// 수익비율
+    #[serde(default, rename = "ernn_rate")]
+    pub ernn_rate: Decimal,
+    /// 시가2수익률
+    #[serde(default, rename = "oprc_ert")]
+    pub oprc_ert: String,
+    /// 최고가수익률
+    #[serde(default, rename = "hgpr_ert")]
+    pub hgpr_ert: String,
+    /// 최저가수익률
+    #[serde(default, rename = "lwpr_ert")]
+    pub lwpr_ert: String,
+    /// 채권상한가
+    #[serde(default, rename = "bond_mxpr")]
+    pub bond_mxpr: String,
+    /// 채권하한가
+    #[serde(default, rename = "bond_llam")]
+    pub bond_llam: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권현재가(시세) [국내주식-200]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsInquirePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsInquirePriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsInquirePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권현재가(체결) [국내주식-201]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireCcnlOutputItem {
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 채권 현재가
+    #[serde(default, rename = "bond_prpr")]
+    pub bond_prpr: String,
+    /// 채권 전일 대비
+    #[serde(default, rename = "bond_prdy_vrss")]
+    pub bond_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권현재가(체결) [국내주식-201]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsInquireCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsInquireCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsInquireCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권현재가(일별) [국내주식-202]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireDailyPriceOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 채권현재가
+    #[serde(default, rename = "bond_prpr")]
+    pub bond_prpr: String,
+    /// 채권전일대비
+    #[serde(default, rename = "bond_prdy_vrss")]
+    pub bond_prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 채권시가2
+    #[serde(default, rename = "bond_oprc")]
+    pub bond_oprc: String,
+    /// 채권고가
+    #[serde(default, rename = "bond_hgpr")]
+    pub bond_hgpr: String,
+    /// 채권저가
+    #[serde(default, rename = "bond_lwpr")]
+    pub bond_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권현재가(일별) [국내주식-202]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsInquireDailyPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsInquireDailyPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsInquireDailyPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 기간별시세(일) [국내주식-159]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsInquireDailyItemchartpriceOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 채권시가2
+    #[serde(default, rename = "bond_oprc")]
+    pub bond_oprc: String,
+    /// 채권고가
+    #[serde(default, rename = "bond_hgpr")]
+    pub bond_hgpr: String,
+    /// 채권저가
+    #[serde(default, rename = "bond_lwpr")]
+    pub bond_lwpr: String,
+    /// 채권현재가
+    #[serde(default, rename = "bond_prpr")]
+    pub bond_prpr: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 기간별시세(일) [국내주식-159]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsInquireDailyItemchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsInquireDailyItemchartpriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsInquireDailyItemchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 평균단가조회 [국내주식-158]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsAvgUnitOutput1Item {
+    /// 평가일자
+    #[serde(default, rename = "evlu_dt")]
+    pub evlu_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 한국신용평가단가
+    #[serde(default, rename = "kis_unpr")]
+    pub kis_unpr: Decimal,
+    /// 한국채권평가단가
+    #[serde(default, rename = "kbp_unpr")]
+    pub kbp_unpr: Decimal,
+    /// 한국신용정보평가단가
+    #[serde(default, rename = "nice_evlu_unpr")]
+    pub nice_evlu_unpr: Decimal,
+    /// 에프앤자산평가단가
+    #[serde(default, rename = "fnp_unpr")]
+    pub fnp_unpr: Decimal,
+    /// 평균평가단가
+    #[serde(default, rename = "avg_evlu_unpr")]
+    pub avg_evlu_unpr: Decimal,
+    /// 한국신용평가신용등급내용
+    #[serde(default, rename = "kis_crdt_grad_text")]
+    pub kis_crdt_grad_text: String,
+    /// 한국채권평가신용등급내용
+    #[serde(default, rename = "kbp_crdt_grad_text")]
+    pub kbp_crdt_grad_text: String,
+    /// 한국신용정보신용등급내용
+    #[serde(default, rename = "nice_crdt_grad_text")]
+    pub nice_crdt_grad_text: String,
+    /// 에프앤자산평가신용등급내용
+    #[serde(default, rename = "fnp_crdt_grad_text")]
+    pub fnp_crdt_grad_text: String,
+    /// 변경여부
+    #[serde(default, rename = "chng_yn")]
+    pub chng_yn: String,
+    /// 한국신용평가수익율
+    #[serde(default, rename = "kis_erng_rt")]
+    pub kis_erng_rt: Decimal,
+    /// 한국채권평가수익율
+    #[serde(default, rename = "kbp_erng_rt")]
+    pub kbp_erng_rt: Decimal,
+    /// 한국신용정보평가수익율
+    #[serde(default, rename = "nice_evlu_erng_rt")]
+    pub nice_evlu_erng_rt: Decimal,
+    /// 에프앤자산평가수익율
+    #[serde(default, rename = "fnp_erng_rt")]
+    pub fnp_erng_rt: Decimal,
+    /// 평균평가수익율
+    #[serde(default, rename = "avg_evlu_erng_rt")]
+    pub avg_evlu_erng_rt: Decimal,
+    /// 한국신용평가RF단가
+    #[serde(default, rename = "kis_rf_unpr")]
+    pub kis_rf_unpr: Decimal,
+    /// 한국채권평가RF단가
+    #[serde(default, rename = "kbp_rf_unpr")]
+    pub kbp_rf_unpr: Decimal,
+    /// 한국신용정보평가RF단가
+    #[serde(default, rename = "nice_evlu_rf_unpr")]
+    pub nice_evlu_rf_unpr: Decimal,
+    /// 평균평가RF단가
+    #[serde(default, rename = "avg_evlu_rf_unpr")]
+    pub avg_evlu_rf_unpr: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 평균단가조회 [국내주식-158]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsAvgUnitOutput2Item {
+    /// 평가일자
+    #[serde(default, rename = "evlu_dt")]
+    pub evlu_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 한국신용평가평가금액
+    #[serde(default, rename = "kis_evlu_amt")]
+    pub kis_evlu_amt: Decimal,
+    /// 한국채권평가평가금액
+    #[serde(default, rename = "kbp_evlu_amt")]
+    pub kbp_evlu_amt: Decimal,
+    /// 한국신용정보평가금액
+    #[serde(default, rename = "nice_evlu_amt")]
+    pub nice_evlu_amt: Decimal,
+    /// 에프앤자산평가평가금액
+    #[serde(default, rename = "fnp_evlu_amt")]
+    pub fnp_evlu_amt: Decimal,
+    /// 평균평가금액
+    #[serde(default, rename = "avg_evlu_amt")]
+    pub avg_evlu_amt: Decimal,
+    /// 변경여부
+    #[serde(default, rename = "chng_yn")]
+    pub chng_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 평균단가조회 [국내주식-158]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsAvgUnitOutput3Item {
+    /// 평가일자
+    #[serde(default, rename = "evlu_dt")]
+    pub evlu_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 한국신용평가통화코드
+    #[serde(default, rename = "kis_crcy_cd")]
+    pub kis_crcy_cd: String,
+    /// 한국신용평가평가단위가격
+    #[serde(default, rename = "kis_evlu_unit_pric")]
+    pub kis_evlu_unit_pric: Decimal,
+    /// 한국신용평가평가가격
+    #[serde(default, rename = "kis_evlu_pric")]
+    pub kis_evlu_pric: Decimal,
+    /// 한국채권평가통화코드
+    #[serde(default, rename = "kbp_crcy_cd")]
+    pub kbp_crcy_cd: String,
+    /// 한국채권평가평가단위가격
+    #[serde(default, rename = "kbp_evlu_unit_pric")]
+    pub kbp_evlu_unit_pric: Decimal,
+    /// 한국채권평가평가가격
+    #[serde(default, rename = "kbp_evlu_pric")]
+    pub kbp_evlu_pric: Decimal,
+    /// 한국신용정보통화코드
+    #[serde(default, rename = "nice_crcy_cd")]
+    pub nice_crcy_cd: String,
+    /// 한국신용정보평가단위가격
+    #[serde(default, rename = "nice_evlu_unit_pric")]
+    pub nice_evlu_unit_pric: Decimal,
+    /// 한국신용정보평가가격
+    #[serde(default, rename = "nice_evlu_pric")]
+    pub nice_evlu_pric: Decimal,
+    /// 평균평가단위가격
+    #[serde(default, rename = "avg_evlu_unit_pric")]
+    pub avg_evlu_unit_pric: Decimal,
+    /// 평균평가가격
+    #[serde(default, rename = "avg_evlu_pric")]
+    pub avg_evlu_pric: Decimal,
+    /// 변경여부
+    #[serde(default, rename = "chng_yn")]
+    pub chng_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 평균단가조회 [국내주식-158]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsAvgUnitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticBondV1QuotationsAvgUnitOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticBondV1QuotationsAvgUnitOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<DomesticBondV1QuotationsAvgUnitOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsAvgUnitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 발행정보[국내주식-156]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsIssueInfoOutputItem {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품영문명
+    #[serde(default, rename = "prdt_eng_name")]
+    pub prdt_eng_name: String,
+    /// 투자유의상품여부
+    #[serde(default, rename = "ivst_heed_prdt_yn")]
+    pub ivst_heed_prdt_yn: String,
+    /// 연장여부
+    #[serde(default, rename = "exts_yn")]
+    pub exts_yn: String,
+    /// 채권분류코드
+    #[serde(default, rename = "bond_clsf_cd")]
+    pub bond_clsf_cd: String,
+    /// 채권분류한글명
+    #[serde(default, rename = "bond_clsf_kor_name")]
+    pub bond_clsf_kor_name: String,
+    /// 액면가
+    #[serde(default, rename = "papr")]
+    pub papr: String,
+    /// 이자월말구분코드
+    #[serde(default, rename = "int_mned_dvsn_cd")]
+    pub int_mned_dvsn_cd: String,
+    /// 매출형태코드
+    #[serde(default, rename = "rvnu_shap_cd")]
+    pub rvnu_shap_cd: String,
+    /// 발행금액
+    #[serde(default, rename = "issu_amt")]
+    pub issu_amt: Decimal,
+    /// 상장잔액
+    #[serde(default, rename = "lstg_rmnd")]
+    pub lstg_rmnd: String,
+    /// 이자지급개월수
+    #[serde(default, rename = "int_dfrm_mcnt")]
+    pub int_dfrm_mcnt: String,
+    /// 채권이자지급방법코드
+    #[serde(default, rename = "bond_int_dfrm_mthd_cd")]
+    pub bond_int_dfrm_mthd_cd: String,
+    /// 분할상환횟수
+    #[serde(default, rename = "splt_rdpt_rcnt")]
+    pub splt_rdpt_rcnt: String,
+    /// 원금거치기간개월수
+    #[serde(default, rename = "prca_dfmt_term_mcnt")]
+    pub prca_dfmt_term_mcnt: String,
+    /// 이자선후급구분코드
+    #[serde(default, rename = "int_anap_dvsn_cd")]
+    pub int_anap_dvsn_cd: String,
+    /// 채권권리구분코드
+    #[serde(default, rename = "bond_rght_dvsn_cd")]
+    pub bond_rght_dvsn_cd: String,
+    /// 상품특성내용
+    #[serde(default, rename = "prdt_pclc_text")]
+    pub prdt_pclc_text: String,
+    /// 상품약어명
+    #[serde(default, rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: String,
+    /// 상품영문약어명
+    #[serde(default, rename = "prdt_eng_abrv_name")]
+    pub prdt_eng_abrv_name: String,
+    /// 분리과세가능여부
+    #[serde(default, rename = "sprx_psbl_yn")]
+    pub sprx_psbl_yn: String,
+    /// 공모사모모집방법코드
+    #[serde(default, rename = "pbff_pplc_ofrg_mthd_cd")]
+    pub pbff_pplc_ofrg_mthd_cd: String,
+    /// 주간사코드
+    #[serde(default, rename = "cmco_cd")]
+    pub cmco_cd: String,
+    /// 발행기관코드
+    #[serde(default, rename = "issu_istt_cd")]
+    pub issu_istt_cd: String,
+    /// 발행기관명
+    #[serde(default, rename = "issu_istt_name")]
+    pub issu_istt_name: String,
+    /// 원리금지급대행기관코드
+    #[serde(default, rename = "pnia_dfrm_agcy_istt_cd")]
+    pub pnia_dfrm_agcy_istt_cd: String,
+    /// 할인할증율
+    #[serde(default, rename = "dsct_ec_rt")]
+    pub dsct_ec_rt: Decimal,
+    /// 표면이율
+    #[serde(default, rename = "srfc_inrt")]
+    pub srfc_inrt: String,
+    /// 만기상환율
+    #[serde(default, rename = "expd_rdpt_rt")]
+    pub expd_rdpt_rt: Decimal,
+    /// 만기보장수익율
+    #[serde(default, rename = "expd_asrc_erng_rt")]
+    pub expd_asrc_erng_rt: Decimal,
+    /// 채권보증기관명
+    #[serde(default, rename = "bond_grte_istt_name")]
+    pub bond_grte_istt_name: String,
+    /// 이자지급일유형코드
+    #[serde(default, rename = "int_dfrm_day_type_cd")]
+    pub int_dfrm_day_type_cd: String,
+    /// 증권예탁결제원이자계산단위코드
+    #[serde(default, rename = "ksd_int_calc_unit_cd")]
+    pub ksd_int_calc_unit_cd: String,
+    /// 이자원화단위미만처리구분코드
+    #[serde(default, rename = "int_wunt_uder_prcs_dvsn_cd")]
+    pub int_wunt_uder_prcs_dvsn_cd: String,
+    /// 매출일자
+    #[serde(default, rename = "rvnu_dt")]
+    pub rvnu_dt: String,
+    /// 발행일자
+    #[serde(default, rename = "issu_dt")]
+    pub issu_dt: String,
+    /// 상장일자
+    #[serde(default, rename = "lstg_dt")]
+    pub lstg_dt: String,
+    /// 만기일자
+    #[serde(default, rename = "expd_dt")]
+    pub expd_dt: String,
+    /// 상환일자
+    #[serde(default, rename = "rdpt_dt")]
+    pub rdpt_dt: String,
+    /// 대용가격
+    #[serde(default, rename = "sbst_pric")]
+    pub sbst_pric: Decimal,
+    /// 직전이자지급일자
+    #[serde(default, rename = "rgbf_int_dfrm_dt")]
+    pub rgbf_int_dfrm_dt: String,
+    /// 차기이자지급일자
+    #[serde(default, rename = "nxtm_int_dfrm_dt")]
+    pub nxtm_int_dfrm_dt: String,
+    /// 최초이자지급일자
+    #[serde(default, rename = "frst_int_dfrm_dt")]
+    pub frst_int_dfrm_dt: String,
+    /// 행사가격
+    #[serde(default, rename = "ecis_pric")]
+    pub ecis_pric: Decimal,
+    /// 권리주식표준상품번호
+    #[serde(default, rename = "rght_stck_std_pdno")]
+    pub rght_stck_std_pdno: String,
+    /// 행사개시일자
+    #[serde(default, rename = "ecis_opng_dt")]
+    pub ecis_opng_dt: String,
+    /// 행사종료일자
+    #[serde(default, rename = "ecis_end_dt")]
+    pub ecis_end_dt: String,
+    /// 채권매출방법코드
+    #[serde(default, rename = "bond_rvnu_mthd_cd")]
+    pub bond_rvnu_mthd_cd: String,
+    /// 조작직원번호
+    #[serde(default, rename = "oprt_stfno")]
+    pub oprt_stfno: String,
+    /// 조작직원명
+    #[serde(default, rename = "oprt_stff_name")]
+    pub oprt_stff_name: String,
+    /// 직전이자지급요일
+    #[serde(default, rename = "rgbf_int_dfrm_wday")]
+    pub rgbf_int_dfrm_wday: String,
+    /// 차기이자지급요일
+    #[serde(default, rename = "nxtm_int_dfrm_wday")]
+    pub nxtm_int_dfrm_wday: String,
+    /// 한국신용평가신용등급내용
+    #[serde(default, rename = "kis_crdt_grad_text")]
+    pub kis_crdt_grad_text: String,
+    /// 한국채권평가신용등급내용
+    #[serde(default, rename = "kbp_crdt_grad_text")]
+    pub kbp_crdt_grad_text: String,
+    /// 한국신용정보신용등급내용
+    #[serde(default, rename = "nice_crdt_grad_text")]
+    pub nice_crdt_grad_text: String,
+    /// 에프앤자산평가신용등급내용
+    #[serde(default, rename = "fnp_crdt_grad_text")]
+    pub fnp_crdt_grad_text: String,
+    /// 예탁가능여부
+    #[serde(default, rename = "dpsi_psbl_yn")]
+    pub dpsi_psbl_yn: String,
+    /// 원리금이자계산단가
+    #[serde(default, rename = "pnia_int_calc_unpr")]
+    pub pnia_int_calc_unpr: Decimal,
+    /// 물가지수채권여부
+    #[serde(default, rename = "prcm_idx_bond_yn")]
+    pub prcm_idx_bond_yn: String,
+    /// 만기연장분할상환횟수
+    #[serde(default, rename = "expd_exts_srdp_rcnt")]
+    pub expd_exts_srdp_rcnt: String,
+    /// 만기연장분할상환율
+    #[serde(default, rename = "expd_exts_srdp_rt")]
+    pub expd_exts_srdp_rt: Decimal,
+    /// 대출가능여부
+    #[serde(default, rename = "loan_psbl_yn")]
+    pub loan_psbl_yn: String,
+    /// 보증구분코드
+    #[serde(default, rename = "grte_dvsn_cd")]
+    pub grte_dvsn_cd: String,
+    /// 선후순위구분코드
+    #[serde(default, rename = "fnrr_rank_dvsn_cd")]
+    pub fnrr_rank_dvsn_cd: String,
+    /// 한국거래소상장폐지구분코드
+    #[serde(default, rename = "krx_lstg_abol_dvsn_cd")]
+    pub krx_lstg_abol_dvsn_cd: String,
+    /// 자산유동화구분코드
+    #[serde(default, rename = "asst_rqdi_dvsn_cd")]
+    pub asst_rqdi_dvsn_cd: String,
+    /// 옵션부사채구분코드
+    #[serde(default, rename = "opcb_dvsn_cd")]
+    pub opcb_dvsn_cd: String,
+    /// 크라우드펀딩종목여부
+    #[serde(default, rename = "crfd_item_yn")]
+    pub crfd_item_yn: String,
+    /// 크라우드펀딩종목제한해지일자
+    #[serde(default, rename = "crfd_item_rstc_cclc_dt")]
+    pub crfd_item_rstc_cclc_dt: String,
+    /// 채권호가단위가격
+    #[serde(default, rename = "bond_nmpr_unit_pric")]
+    pub bond_nmpr_unit_pric: Decimal,
+    /// 투자유의채권구분명
+    #[serde(default, rename = "ivst_heed_bond_dvsn_name")]
+    pub ivst_heed_bond_dvsn_name: String,
+    /// 추가수익율
+    #[serde(default, rename = "add_erng_rt")]
+    pub add_erng_rt: Decimal,
+    /// 추가수익율적용일자
+    #[serde(default, rename = "add_erng_rt_aply_dt")]
+    pub add_erng_rt_aply_dt: String,
+    /// 채권거래정지구분코드
+    #[serde(default, rename = "bond_tr_stop_dvsn_cd")]
+    pub bond_tr_stop_dvsn_cd: String,
+    /// 투자유의채권구분코드
+    #[serde(default, rename = "ivst_heed_bond_dvsn_cd")]
+    pub ivst_heed_bond_dvsn_cd: String,
+    /// 특이조건내용
+    #[serde(default, rename = "pclr_cndt_text")]
+    pub pclr_cndt_text: String,
+    /// 하이브리드채권여부
+    #[serde(default, rename = "hbbd_yn")]
+    pub hbbd_yn: String,
+    /// 조건부자본증권유형코드
+    #[serde(default, rename = "cdtl_cptl_scty_type_cd")]
+    pub cdtl_cptl_scty_type_cd: String,
+    /// 전자증권여부
+    #[serde(default, rename = "elec_scty_yn")]
+    pub elec_scty_yn: String,
+    /// 1차콜옵션행사개시일자
+    #[serde(default, rename = "sq1_clop_ecis_opng_dt")]
+    pub sq1_clop_ecis_opng_dt: String,
+    /// 최초등록직원번호
+    #[serde(default, rename = "frst_erlm_stfno")]
+    pub frst_erlm_stfno: String,
+    /// 최초등록일자
+    #[serde(default, rename = "frst_erlm_dt")]
+    pub frst_erlm_dt: String,
+    /// 최초등록시각
+    #[serde(default, rename = "frst_erlm_tmd")]
+    pub frst_erlm_tmd: String,
+    /// 전문수신상세일시
+    #[serde(default, rename = "tlg_rcvg_dtl_dtime")]
+    pub tlg_rcvg_dtl_dtime: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 발행정보[국내주식-156]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsIssueInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsIssueInfoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsIssueInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [장내채권 기본조회 [국내주식-129]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticBondV1QuotationsSearchBondInfoOutputItem {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 증권예탁결제원채권종목명
+    #[serde(default, rename = "ksd_bond_item_name")]
+    pub ksd_bond_item_name: String,
+    /// 증권예탁결제원채권종목영문명
+    #[serde(default, rename = "ksd_bond_item_eng_name")]
+    pub ksd_bond_item_eng_name: String,
+    /// 증권예탁결제원채권상장유형코드
+    #[serde(default, rename = "ksd_bond_lstg_type_cd")]
+    pub ksd_bond_lstg_type_cd: String,
+    /// 증권예탁결제원모집구분코드
+    #[serde(default, rename = "ksd_ofrg_dvsn_cd")]
+    pub ksd_ofrg_dvsn_cd: String,
+    /// 증권예탁결제원채권이자지급구분
+    #[serde(default, rename = "ksd_bond_int_dfrm_dvsn_cd")]
+    pub ksd_bond_int_dfrm_dvsn_cd: String,
+    /// 발행일자
+    #[serde(default, rename = "issu_dt")]
+    pub issu_dt: String,
+    /// 상환일자
+    #[serde(default, rename = "rdpt_dt")]
+    pub rdpt_dt: String,
+    /// 매출일자
+    #[serde(default, rename = "rvnu_dt")]
+    pub rvnu_dt: String,
+    /// 통화코드
+    #[serde(default, rename = "iso_crcy_cd")]
+    pub iso_crcy_cd: String,
+    /// 중도상환일자
+    #[serde(default, rename = "mdwy_rdpt_dt")]
+    pub mdwy_rdpt_dt: String,
+    /// 증권예탁결제원수신채권할인율
+    #[serde(default, rename = "ksd_rcvg_bond_dsct_rt")]
+    pub ksd_rcvg_bond_dsct_rt: Decimal,
+    /// 증권예탁결제원수신채권표면이율
+    #[serde(default, rename = "ksd_rcvg_bond_srfc_inrt")]
+    pub ksd_rcvg_bond_srfc_inrt: String,
+    /// 채권만기상환율
+    #[serde(default, rename = "bond_expd_rdpt_rt")]
+    pub bond_expd_rdpt_rt: Decimal,
+    /// 증권예탁결제원원금상환방법코드
+    #[serde(default, rename = "ksd_prca_rdpt_mthd_cd")]
+    pub ksd_prca_rdpt_mthd_cd: String,
+    /// 이자계산기간개월수
+    #[serde(default, rename = "int_caltm_mcnt")]
+    pub int_caltm_mcnt: String,
+    /// 증권예탁결제원이자계산단위코드
+    #[serde(default, rename = "ksd_int_calc_unit_cd")]
+    pub ksd_int_calc_unit_cd: String,
+    /// 절상절사구분코드
+    #[serde(default, rename = "uval_cut_dvsn_cd")]
+    pub uval_cut_dvsn_cd: String,
+    /// 절상절사소수점자릿수
+    #[serde(default, rename = "uval_cut_dcpt_dgit")]
+    pub uval_cut_dcpt_dgit: String,
+    /// 증권예탁결제원일할계산기간적용
+    #[serde(default, rename = "ksd_dydv_caltm_aply_dvsn_cd")]
+    pub ksd_dydv_caltm_aply_dvsn_cd: String,
+    /// 일할계산일수
+    #[serde(default, rename = "dydv_calc_dcnt")]
+    pub dydv_calc_dcnt: String,
+    /// 채권만기보장수익율
+    #[serde(default, rename = "bond_expd_asrc_erng_rt")]
+    pub bond_expd_asrc_erng_rt: Decimal,
+    /// 원리금지급장소본점명
+    #[serde(default, rename = "padf_plac_hdof_name")]
+    pub padf_plac_hdof_name: String,
+    /// 상장일자
+    #[serde(default, rename = "lstg_dt")]
+    pub lstg_dt: String,
+    /// 상장폐지일자
+    #[serde(default, rename = "lstg_abol_dt")]
+    pub lstg_abol_dt: String,
+    /// 증권예탁결제원채권발행방법코드
+    #[serde(default, rename = "ksd_bond_issu_mthd_cd")]
+    pub ksd_bond_issu_mthd_cd: String,
+    /// 경과이자지급여부
+    #[serde(default, rename = "laps_indf_yn")]
+    pub laps_indf_yn: String,
+    /// 증권예탁결제원공휴일원리금지급
+    #[serde(default, rename = "ksd_lhdy_pnia_dfrm_mthd_cd")]
+    pub ksd_lhdy_pnia_dfrm_mthd_cd: String,
+    /// 최초이자지급일자
+    #[serde(default, rename = "frst_int_dfrm_dt")]
+    pub frst_int_dfrm_dt: String,
+    /// 증권예탁결제원물가연동국고채여
+    #[serde(default, rename = "ksd_prcm_lnkg_gvbd_yn")]
+    pub ksd_prcm_lnkg_gvbd_yn: String,
+    /// 예탁종료일자
+    #[serde(default, rename = "dpsi_end_dt")]
+    pub dpsi_end_dt: String,
+    /// 예탁시작일자
+    #[serde(default, rename = "dpsi_strt_dt")]
+    pub dpsi_strt_dt: String,
+    /// 예탁가능여부
+    #[serde(default, rename = "dpsi_psbl_yn")]
+    pub dpsi_psbl_yn: String,
+    /// 비정형상환채권등록여부
+    #[serde(default, rename = "atyp_rdpt_bond_erlm_yn")]
+    pub atyp_rdpt_bond_erlm_yn: String,
+    /// 부도발생여부
+    #[serde(default, rename = "dshn_occr_yn")]
+    pub dshn_occr_yn: String,
+    /// 만기연장여부
+    #[serde(default, rename = "expd_exts_yn")]
+    pub expd_exts_yn: String,
+    /// 특이사항내용
+    #[serde(default, rename = "pclr_ptcr_text")]
+    pub pclr_ptcr_text: String,
+    /// 예탁가능예외상태코드
+    #[serde(default, rename = "dpsi_psbl_excp_stat_cd")]
+    pub dpsi_psbl_excp_stat_cd: String,
+    /// 만기연장분할상환횟수
+    #[serde(default, rename = "expd_exts_srdp_rcnt")]
+    pub expd_exts_srdp_rcnt: String,
+    /// 만기연장분할상환율
+    #[serde(default, rename = "expd_exts_srdp_rt")]
+    pub expd_exts_srdp_rt: Decimal,
+    /// 만기상환율
+    #[serde(default, rename = "expd_rdpt_rt")]
+    pub expd_rdpt_rt: Decimal,
+    /// 만기보장수익율
+    #[serde(default, rename = "expd_asrc_erng_rt")]
+    pub expd_asrc_erng_rt: Decimal,
+    /// 채권이자지급방법코드
+    #[serde(default, rename = "bond_int_dfrm_mthd_cd")]
+    pub bond_int_dfrm_mthd_cd: String,
+    /// 이자지급일유형코드
+    #[serde(default, rename = "int_dfrm_day_type_cd")]
+    pub int_dfrm_day_type_cd: String,
+    /// 원금거치기간개월수
+    #[serde(default, rename = "prca_dfmt_term_mcnt")]
+    pub prca_dfmt_term_mcnt: String,
+    /// 분할상환횟수
+    #[serde(default, rename = "splt_rdpt_rcnt")]
+    pub splt_rdpt_rcnt: String,
+    /// 직전이자지급일자
+    #[serde(default, rename = "rgbf_int_dfrm_dt")]
+    pub rgbf_int_dfrm_dt: String,
+    /// 차기이자지급일자
+    #[serde(default, rename = "nxtm_int_dfrm_dt")]
+    pub nxtm_int_dfrm_dt: String,
+    /// 분리과세가능여부
+    #[serde(default, rename = "sprx_psbl_yn")]
+    pub sprx_psbl_yn: String,
+    /// 소득세율구분코드
+    #[serde(default, rename = "ictx_rt_dvsn_cd")]
+    pub ictx_rt_dvsn_cd: String,
+    /// 채권분류코드
+    #[serde(default, rename = "bond_clsf_cd")]
+    pub bond_clsf_cd: String,
+    /// 채권분류한글명
+    #[serde(default, rename = "bond_clsf_kor_name")]
+    pub bond_clsf_kor_name: String,
+    /// 이자월말구분코드
+    #[serde(default, rename = "int_mned_dvsn_cd")]
+    pub int_mned_dvsn_cd: String,
+    /// 원리금이자계산단가
+    #[serde(default, rename = "pnia_int_calc_unpr")]
+    pub pnia_int_calc_unpr: Decimal,
+    /// FRN금리
+    #[serde(default, rename = "frn_intr")]
+    pub frn_intr: String,
+    /// 적용일물가지수연동계수
+    #[serde(default, rename = "aply_day_prcm_idx_lnkg_cefc")]
+    pub aply_day_prcm_idx_lnkg_cefc: String,
+    /// 증권예탁결제원만기일할계산기준
+    #[serde(default, rename = "ksd_expd_dydv_calc_bass_cd")]
+    pub ksd_expd_dydv_calc_bass_cd: String,
+    /// 만기일할계산일수
+    #[serde(default, rename = "expd_dydv_calc_dcnt")]
+    pub expd_dydv_calc_dcnt: String,
+    /// 증권예탁결제원신종사채구분코드
+    #[serde(default, rename = "ksd_cbbw_dvsn_cd")]
+    pub ksd_cbbw_dvsn_cd: String,
+    /// 크라우드펀딩종목여부
+    #[serde(default, rename = "crfd_item_yn")]
+    pub crfd_item_yn: String,
+    /// 원리금은행휴무일지급방법코드
+    #[serde(default, rename = "pnia_bank_ofdy_dfrm_mthd_cd")]
+    pub pnia_bank_ofdy_dfrm_mthd_cd: String,
+    /// QIB여부
+    #[serde(default, rename = "qib_yn")]
+    pub qib_yn: String,
+    /// QIB해지일자
+    #[serde(default, rename = "qib_cclc_dt")]
+    pub qib_cclc_dt: String,
+    /// 영구채여부
+    #[serde(default, rename = "csbd_yn")]
+    pub csbd_yn: String,
+    /// 영구채해지일자
+    #[serde(default, rename = "csbd_cclc_dt")]
+    pub csbd_cclc_dt: String,
+    /// 증권예탁결제원옵션부사채여부
+    #[serde(default, rename = "ksd_opcb_yn")]
+    pub ksd_opcb_yn: String,
+    /// 증권예탁결제원후순위채권여부
+    #[serde(default, rename = "ksd_sodn_yn")]
+    pub ksd_sodn_yn: String,
+    /// 증권예탁결제원유동화증권여부
+    #[serde(default, rename = "ksd_rqdi_scty_yn")]
+    pub ksd_rqdi_scty_yn: String,
+    /// 전자증권여부
+    #[serde(default, rename = "elec_scty_yn")]
+    pub elec_scty_yn: String,
+    /// 권리행사주체구분코드
+    #[serde(default, rename = "rght_ecis_mbdy_dvsn_cd")]
+    pub rght_ecis_mbdy_dvsn_cd: String,
+    /// 이자산정방법구분코드
+    #[serde(default, rename = "int_rkng_mthd_dvsn_cd")]
+    pub int_rkng_mthd_dvsn_cd: String,
+    /// 모집구분코드
+    #[serde(default, rename = "ofrg_dvsn_cd")]
+    pub ofrg_dvsn_cd: String,
+    /// 증권예탁결제원총발행금액
+    #[serde(default, rename = "ksd_tot_issu_amt")]
+    pub ksd_tot_issu_amt: Decimal,
+    /// 다음이자지급체크제외여부
+    #[serde(default, rename = "next_indf_chk_ecls_yn")]
+    pub next_indf_chk_ecls_yn: String,
+    /// 증권예탁결제원채권금리구분코드
+    #[serde(default, rename = "ksd_bond_intr_dvsn_cd")]
+    pub ksd_bond_intr_dvsn_cd: String,
+    /// 증권예탁결제원이율적용구분코드
+    #[serde(default, rename = "ksd_inrt_aply_dvsn_cd")]
+    pub ksd_inrt_aply_dvsn_cd: String,
+    /// KRX발행기관코드
+    #[serde(default, rename = "krx_issu_istt_cd")]
+    pub krx_issu_istt_cd: String,
+    /// 증권예탁결제원이자지급주기미만
+    #[serde(default, rename = "ksd_indf_frqc_uder_calc_cd")]
+    pub ksd_indf_frqc_uder_calc_cd: String,
+    /// 증권예탁결제원이자지급주기미만
+    #[serde(default, rename = "ksd_indf_frqc_uder_calc_dcnt")]
+    pub ksd_indf_frqc_uder_calc_dcnt: String,
+    /// 전문수신상세일시
+    #[serde(default, rename = "tlg_rcvg_dtl_dtime")]
+    pub tlg_rcvg_dtl_dtime: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [장내채권 기본조회 [국내주식-129]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticBondV1QuotationsSearchBondInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticBondV1QuotationsSearchBondInfoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticBondV1QuotationsSearchBondInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
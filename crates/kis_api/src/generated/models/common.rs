@@ -0,0 +1,118 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use serde::{Deserialize, Serialize};
+
+/// [접근토큰발급(P)[인증-001]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct Oauth2TokenpRequest {
+    /// 권한부여 Type (String, 필수)
+    #[serde(rename = "grant_type")]
+    pub grant_type: String,
+    /// 앱키 (String, 필수)
+    #[serde(rename = "appkey")]
+    pub appkey: String,
+    /// 앱시크릿키 (String, 필수)
+    #[serde(rename = "appsecret")]
+    pub appsecret: String,
+}
+
+/// [접근토큰폐기(P)[인증-002]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct Oauth2RevokepRequest {
+    /// 고객 앱Key (String, 필수)
+    #[serde(rename = "appkey")]
+    pub appkey: String,
+    /// 고객 앱Secret (String, 필수)
+    #[serde(rename = "appsecret")]
+    pub appsecret: String,
+    /// 접근토큰 (String, 필수)
+    #[serde(rename = "token")]
+    pub token: String,
+}
+
+/// [실시간 (웹소켓) 접속키 발급[실시간-000]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct Oauth2ApprovalRequest {
+    /// 권한부여타입 (String, 필수)
+    #[serde(rename = "grant_type")]
+    pub grant_type: String,
+    /// 앱키 (String, 필수)
+    #[serde(rename = "appkey")]
+    pub appkey: String,
+    /// 시크릿키 (String, 필수)
+    #[serde(rename = "secretkey")]
+    pub secretkey: String,
+}
+
+/// [접근토큰발급(P)[인증-001]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Oauth2TokenpResponse {
+    /// 접근토큰
+    #[serde(default, rename = "access_token")]
+    pub access_token: String,
+    /// 접근토큰유형
+    #[serde(default, rename = "token_type")]
+    pub token_type: String,
+    /// 접근토큰 유효기간
+    #[serde(default, rename = "expires_in")]
+    pub expires_in: String,
+    /// 접근토큰 유효기간(일시표시)
+    #[serde(default, rename = "access_token_token_expired")]
+    pub access_token_token_expired: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for Oauth2TokenpResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [접근토큰폐기(P)[인증-002]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Oauth2RevokepResponse {
+    /// 응답코드
+    #[serde(default, rename = "code")]
+    pub code: String,
+    /// 응답메세지
+    #[serde(default, rename = "message")]
+    pub message: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for Oauth2RevokepResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [실시간 (웹소켓) 접속키 발급[실시간-000]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Oauth2ApprovalResponse {
+    /// 웹소켓 접속키
+    #[serde(default, rename = "approval_key")]
+    pub approval_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for Oauth2ApprovalResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
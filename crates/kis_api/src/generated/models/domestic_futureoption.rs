@@ -0,0 +1,3884 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [선물옵션 주문[v1_국내선물-001]] 요청 구조체
+/// [국내선물-001 v1] 선물옵션주문
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingOrderRequest {
+    /// 주문처리구분코드 (String, 필수)
+    #[serde(rename = "ORD_PRCS_DVSN_CD")]
+    pub ord_prcs_dvsn_cd: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 단축상품번호 (String, 필수)
+    #[serde(rename = "SHTN_PDNO")]
+    pub shtn_pdno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문가격1 (String, 필수)
+    #[serde(rename = "UNIT_PRICE")]
+    pub unit_price: String,
+    /// 호가유형코드 (String, 선택)
+    #[serde(rename = "NMPR_TYPE_CD")]
+    pub nmpr_type_cd: String,
+    /// 한국거래소호가조건코드 (String, 선택)
+    #[serde(rename = "KRX_NMPR_CNDT_CD")]
+    pub krx_nmpr_cndt_cd: String,
+    /// 연락전화번호 (String, 선택)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+    /// 선물옵션종목구분코드 (String, 선택)
+    #[serde(rename = "FUOP_ITEM_DVSN_CD")]
+    pub fuop_item_dvsn_cd: String,
+    /// 주문구분코드 (String, 필수)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+}
+
+/// [선물옵션 정정취소주문[v1_국내선물-002]] 요청 구조체
+/// [국내선물-002 v1] 선물옵션정정취소주문
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingOrderRvsecnclRequest {
+    /// 주문처리구분코드 (String, 필수)
+    #[serde(rename = "ORD_PRCS_DVSN_CD")]
+    pub ord_prcs_dvsn_cd: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문가격1 (String, 필수)
+    #[serde(rename = "UNIT_PRICE")]
+    pub unit_price: String,
+    /// 호가유형코드 (String, 필수)
+    #[serde(rename = "NMPR_TYPE_CD")]
+    pub nmpr_type_cd: String,
+    /// 한국거래소호가조건코드 (String, 필수)
+    #[serde(rename = "KRX_NMPR_CNDT_CD")]
+    pub krx_nmpr_cndt_cd: String,
+    /// 잔여수량여부 (String, 필수)
+    #[serde(rename = "RMN_QTY_YN")]
+    pub rmn_qty_yn: String,
+    /// 선물옵션종목구분코드 (String, 선택)
+    #[serde(rename = "FUOP_ITEM_DVSN_CD")]
+    pub fuop_item_dvsn_cd: String,
+    /// 주문구분코드 (String, 필수)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+}
+
+/// [선물옵션 주문체결내역조회[v1_국내선물-003]] 요청 구조체
+/// [국내선물-003 v1] 선물옵션주문체결내역조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시작주문일자 (String, 필수)
+    #[serde(rename = "STRT_ORD_DT")]
+    pub strt_ord_dt: String,
+    /// 종료주문일자 (String, 필수)
+    #[serde(rename = "END_ORD_DT")]
+    pub end_ord_dt: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 정렬순서 (String, 필수)
+    #[serde(rename = "SORT_SQN")]
+    pub sort_sqn: String,
+    /// 시작주문번호 (String, 필수)
+    #[serde(rename = "STRT_ODNO")]
+    pub strt_odno: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 시장ID코드 (String, 필수)
+    #[serde(rename = "MKET_ID_CD")]
+    pub mket_id_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 잔고현황[v1_국내선물-004]] 요청 구조체
+/// [국내선물-004 v1] 선물옵션 잔고현황
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 증거금 구분 (String, 필수)
+    #[serde(rename = "MGNA_DVSN")]
+    pub mgna_dvsn: String,
+    /// 정산상태코드 (String, 필수)
+    #[serde(rename = "EXCC_STAT_CD")]
+    pub excc_stat_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 주문가능[v1_국내선물-005]] 요청 구조체
+/// [국내선물-005 v1] 선물옵션 주문가능
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquirePsblOrderRequest {
+    /// 종합계좌번호 (String, 선택)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 선택)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 선택)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도매수구분코드 (String, 선택)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문가격1 (String, 선택)
+    #[serde(rename = "UNIT_PRICE")]
+    pub unit_price: String,
+    /// 주문구분코드 (String, 선택)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+}
+
+/// [(야간)선물옵션 주문체결 내역조회 [국내선물-009]] 요청 구조체
+/// (야간)선물옵션 주문체결 내역조회 [국내선물-009]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtCcnlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시작주문일자 (String, 필수)
+    #[serde(rename = "STRT_ORD_DT")]
+    pub strt_ord_dt: String,
+    /// 종료주문일자 (String, 필수)
+    #[serde(rename = "END_ORD_DT")]
+    pub end_ord_dt: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 정렬순서 (String, 필수)
+    #[serde(rename = "SORT_SQN")]
+    pub sort_sqn: String,
+    /// 시작주문번호 (String, 필수)
+    #[serde(rename = "STRT_ODNO")]
+    pub strt_odno: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 시장ID코드 (String, 필수)
+    #[serde(rename = "MKET_ID_CD")]
+    pub mket_id_cd: String,
+    /// 선물옵션구분코드 (String, 필수)
+    #[serde(rename = "FUOP_DVSN_CD")]
+    pub fuop_dvsn_cd: String,
+    /// 화면구분 (String, 필수)
+    #[serde(rename = "SCRN_DVSN")]
+    pub scrn_dvsn: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [(야간)선물옵션 잔고현황 [국내선물-010]] 요청 구조체
+/// (야간)선물옵션 잔고현황 [국내선물-010]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 계좌비밀번호 (String, 필수)
+    #[serde(rename = "ACNT_PWD")]
+    pub acnt_pwd: String,
+    /// 증거금구분 (String, 필수)
+    #[serde(rename = "MGNA_DVSN")]
+    pub mgna_dvsn: String,
+    /// 정산상태코드 (String, 필수)
+    #[serde(rename = "EXCC_STAT_CD")]
+    pub excc_stat_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [(야간)선물옵션 주문가능 조회 [국내선물-011]] 요청 구조체
+/// (야간)선물옵션 주문가능 조회 [국내선물-011]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquirePsblNgtOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문가격1 (String, 필수)
+    #[serde(rename = "UNIT_PRICE")]
+    pub unit_price: String,
+    /// 주문구분코드 (String, 필수)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+}
+
+/// [(야간)선물옵션 증거금 상세 [국내선물-024]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingNgtMarginDetailRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 증거금 구분코드 (String, 필수)
+    #[serde(rename = "MGNA_DVSN_CD")]
+    pub mgna_dvsn_cd: String,
+}
+
+/// [선물옵션 잔고정산손익내역[v1_국내선물-013]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceSettlementPlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회일자 (String, 필수)
+    #[serde(rename = "INQR_DT")]
+    pub inqr_dt: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 총자산현황[v1_국내선물-014]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireDepositRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+}
+
+/// [선물옵션 잔고평가손익내역[v1_국내선물-015]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceValuationPlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 증거금구분 (String, 필수)
+    #[serde(rename = "MGNA_DVSN")]
+    pub mgna_dvsn: String,
+    /// 정산상태코드 (String, 필수)
+    #[serde(rename = "EXCC_STAT_CD")]
+    pub excc_stat_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 기준일체결내역[v1_국내선물-016]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlBstimeRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자 (String, 필수)
+    #[serde(rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 선물옵션거래시작시각 (String, 필수)
+    #[serde(rename = "FUOP_TR_STRT_TMD")]
+    pub fuop_tr_strt_tmd: String,
+    /// 선물옵션거래종료시각 (String, 필수)
+    #[serde(rename = "FUOP_TR_END_TMD")]
+    pub fuop_tr_end_tmd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션기간약정수수료일별[v1_국내선물-017]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireDailyAmountFeeRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회시작일 (String, 필수)
+    #[serde(rename = "INQR_STRT_DAY")]
+    pub inqr_strt_day: String,
+    /// 조회종료일 (String, 필수)
+    #[serde(rename = "INQR_END_DAY")]
+    pub inqr_end_day: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 증거금률] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsMarginRateRequest {
+    /// 기준일자 (String, 필수)
+    #[serde(rename = "BASS_DT")]
+    pub bass_dt: String,
+    /// 기초자산ID (String, 필수)
+    #[serde(rename = "BAST_ID")]
+    pub bast_id: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [선물옵션 시세[v1_국내선물-006]] 요청 구조체
+/// [국내선물-006 v1] 선물옵션 시세
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquirePriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [선물옵션 시세호가[v1_국내선물-007]] 요청 구조체
+/// [국내선물-007 v1] 선물옵션 시세호가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireAskingPriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [선물옵션기간별시세(일/주/월/년)[v1_국내선물-008]] 요청 구조체
+/// [국내선물-008 v1] 선물옵션기간별시세(일/주/월/년)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조회 시작일자 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 조회 종료일자  (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 기간분류코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+}
+
+/// [선물옵션 분봉조회[v1_국내선물-012]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 시간 구분 코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+    /// FID 과거 데이터 포함 여부 (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+    /// FID 허봉 포함 여부 (String, 필수)
+    #[serde(rename = "FID_FAKE_TICK_INCU_YN")]
+    pub fid_fake_tick_incu_yn: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// FID 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+}
+
+/// [국내옵션전광판_옵션월물리스트[국내선물-020]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardOptionListRequest {
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+}
+
+/// [국내선물 기초자산 시세[국내선물-021]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardTopRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE1")]
+    pub fid_cond_mrkt_div_code1: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 만기 수 (String, 필수)
+    #[serde(rename = "FID_MTRT_CNT")]
+    pub fid_mtrt_cnt: String,
+    /// 조건 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+}
+
+/// [국내옵션전광판_콜풋[국내선물-022]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardCallputRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 만기 수 (String, 필수)
+    #[serde(rename = "FID_MTRT_CNT")]
+    pub fid_mtrt_cnt: String,
+    /// 조건 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE1")]
+    pub fid_mrkt_cls_code1: String,
+}
+
+/// [국내옵션전광판_선물[국내선물-023]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardFuturesRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 조건 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+}
+
+/// [선물옵션 일중예상체결추이[국내선물-018]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsExpPriceTrendRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [선물옵션 주문[v1_국내선물-001]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingOrderOutputItem {
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "TRAD_DVSN_NAME")]
+    pub trad_dvsn_name: String,
+    /// 종목명
+    #[serde(default, rename = "ITEM_NAME")]
+    pub item_name: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 주문[v1_국내선물-001]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1TradingOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 정정취소주문[v1_국내선물-002]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingOrderRvsecnclOutputItem {
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "TRAD_DVSN_NAME")]
+    pub trad_dvsn_name: String,
+    /// 종목명
+    #[serde(default, rename = "ITEM_NAME")]
+    pub item_name: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: String,
+    /// 원주문번호
+    #[serde(default, rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 정정취소주문[v1_국내선물-002]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1TradingOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 주문체결내역조회[v1_국내선물-003]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlOutput1Item {
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 종합계좌명
+    #[serde(default, rename = "csac_name")]
+    pub csac_name: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 호가유형코드
+    #[serde(default, rename = "nmpr_type_cd")]
+    pub nmpr_type_cd: String,
+    /// 호가유형명
+    #[serde(default, rename = "nmpr_type_name")]
+    pub nmpr_type_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 주문지수
+    #[serde(default, rename = "ord_idx")]
+    pub ord_idx: String,
+    /// 잔량
+    #[serde(default, rename = "qty")]
+    pub qty: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 평균지수
+    #[serde(default, rename = "avg_idx")]
+    pub avg_idx: String,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 거부수량
+    #[serde(default, rename = "rjct_qty")]
+    pub rjct_qty: Decimal,
+    /// 장내매매거부사유코드
+    #[serde(default, rename = "ingr_trad_rjct_rson_cd")]
+    pub ingr_trad_rjct_rson_cd: String,
+    /// 장내매매거부사유명
+    #[serde(default, rename = "ingr_trad_rjct_rson_name")]
+    pub ingr_trad_rjct_rson_name: String,
+    /// 주문직원번호
+    #[serde(default, rename = "ord_stfno")]
+    pub ord_stfno: String,
+    /// 스프레드종목여부
+    #[serde(default, rename = "sprd_item_yn")]
+    pub sprd_item_yn: String,
+    /// 주문IP주소
+    #[serde(default, rename = "ord_ip_addr")]
+    pub ord_ip_addr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 주문체결내역조회[v1_국내선물-003]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlOutput2Item {
+    /// 총주문수량
+    #[serde(default, rename = "tot_ord_qty")]
+    pub tot_ord_qty: Decimal,
+    /// 총체결금액합계
+    #[serde(default, rename = "tot_ccld_amt_smtl")]
+    pub tot_ccld_amt_smtl: String,
+    /// 총체결수량합계
+    #[serde(default, rename = "tot_ccld_qty_smtl")]
+    pub tot_ccld_qty_smtl: String,
+    /// 수수료합계
+    #[serde(default, rename = "fee_smtl")]
+    pub fee_smtl: String,
+    /// 연락전화번호
+    #[serde(default, rename = "ctac_tlno")]
+    pub ctac_tlno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 주문체결내역조회[v1_국내선물-003]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 잔고현황[v1_국내선물-004]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceOutput1Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매도매수구분명
+    #[serde(default, rename = "sll_buy_dvsn_name")]
+    pub sll_buy_dvsn_name: String,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 정산단가
+    #[serde(default, rename = "excc_unpr")]
+    pub excc_unpr: Decimal,
+    /// 체결평균단가1
+    #[serde(default, rename = "ccld_avg_unpr1")]
+    pub ccld_avg_unpr1: Decimal,
+    /// 지수종가
+    #[serde(default, rename = "idx_clpr")]
+    pub idx_clpr: String,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 매매손익금액
+    #[serde(default, rename = "trad_pfls_amt")]
+    pub trad_pfls_amt: Decimal,
+    /// 청산가능수량
+    #[serde(default, rename = "lqd_psbl_qty")]
+    pub lqd_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고현황[v1_국내선물-004]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceOutput2Item {
+    /// 예수금현금
+    #[serde(default, rename = "dnca_cash")]
+    pub dnca_cash: String,
+    /// 외화예수금액
+    #[serde(default, rename = "frcr_dncl_amt")]
+    pub frcr_dncl_amt: Decimal,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 현금증거금
+    #[serde(default, rename = "cash_mgna")]
+    pub cash_mgna: String,
+    /// 대용증거금
+    #[serde(default, rename = "sbst_mgna")]
+    pub sbst_mgna: String,
+    /// 증거금총액
+    #[serde(default, rename = "mgna_tota")]
+    pub mgna_tota: String,
+    /// 옵션차금
+    #[serde(default, rename = "opt_dfpa")]
+    pub opt_dfpa: String,
+    /// 당일차금
+    #[serde(default, rename = "thdt_dfpa")]
+    pub thdt_dfpa: String,
+    /// 갱신차금
+    #[serde(default, rename = "rnwl_dfpa")]
+    pub rnwl_dfpa: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 익일예수금
+    #[serde(default, rename = "nxdy_dnca")]
+    pub nxdy_dnca: String,
+    /// 익일예수금액
+    #[serde(default, rename = "nxdy_dncl_amt")]
+    pub nxdy_dncl_amt: Decimal,
+    /// 추정예탁자산
+    #[serde(default, rename = "prsm_dpast")]
+    pub prsm_dpast: String,
+    /// 추정예탁자산금액
+    #[serde(default, rename = "prsm_dpast_amt")]
+    pub prsm_dpast_amt: Decimal,
+    /// 적정주문가능현금
+    #[serde(default, rename = "pprt_ord_psbl_cash")]
+    pub pprt_ord_psbl_cash: String,
+    /// 추가증거금현금
+    #[serde(default, rename = "add_mgna_cash")]
+    pub add_mgna_cash: String,
+    /// 추가증거금총액
+    #[serde(default, rename = "add_mgna_tota")]
+    pub add_mgna_tota: String,
+    /// 선물매매손익금액
+    #[serde(default, rename = "futr_trad_pfls_amt")]
+    pub futr_trad_pfls_amt: Decimal,
+    /// 옵션매매손익금액
+    #[serde(default, rename = "opt_trad_pfls_amt")]
+    pub opt_trad_pfls_amt: Decimal,
+    /// 선물평가손익금액
+    #[serde(default, rename = "futr_evlu_pfls_amt")]
+    pub futr_evlu_pfls_amt: Decimal,
+    /// 옵션평가손익금액
+    #[serde(default, rename = "opt_evlu_pfls_amt")]
+    pub opt_evlu_pfls_amt: Decimal,
+    /// 매매손익금액합계
+    #[serde(default, rename = "trad_pfls_amt_smtl")]
+    pub trad_pfls_amt_smtl: String,
+    /// 평가손익금액합계
+    #[serde(default, rename = "evlu_pfls_amt_smtl")]
+    pub evlu_pfls_amt_smtl: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 주문가능총액
+    #[serde(default, rename = "ord_psbl_tota")]
+    pub ord_psbl_tota: String,
+    /// 매입금액합계
+    #[serde(default, rename = "pchs_amt_smtl")]
+    pub pchs_amt_smtl: String,
+    /// 평가금액합계
+    #[serde(default, rename = "evlu_amt_smtl")]
+    pub evlu_amt_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고현황[v1_국내선물-004]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 주문가능[v1_국내선물-005]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquirePsblOrderOutputItem {
+    /// 총가능수량
+    #[serde(default, rename = "tot_psbl_qty")]
+    pub tot_psbl_qty: Decimal,
+    /// 청산가능수량1
+    #[serde(default, rename = "lqd_psbl_qty1")]
+    pub lqd_psbl_qty1: String,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 기준지수
+    #[serde(default, rename = "bass_idx")]
+    pub bass_idx: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 주문가능[v1_국내선물-005]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquirePsblOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1TradingInquirePsblOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquirePsblOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [(야간)선물옵션 주문체결 내역조회 [국내선물-009]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtCcnlOutput2Item {
+    /// 총주문수량
+    #[serde(default, rename = "tot_ord_qty")]
+    pub tot_ord_qty: Decimal,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty_SMTL")]
+    pub tot_ccld_qty_smtl: String,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt_SMTL")]
+    pub tot_ccld_amt_smtl: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 연락전화번호
+    #[serde(default, rename = "ctac_tlno")]
+    pub ctac_tlno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 주문체결 내역조회 [국내선물-009]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtCcnlOutput1Item {
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 종합계좌명
+    #[serde(default, rename = "csac_name")]
+    pub csac_name: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 호가유형명
+    #[serde(default, rename = "nmpr_type_name")]
+    pub nmpr_type_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 주문지수
+    #[serde(default, rename = "ord_idx4")]
+    pub ord_idx4: String,
+    /// 잔량
+    #[serde(default, rename = "qty")]
+    pub qty: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 평균지수
+    #[serde(default, rename = "avg_idx")]
+    pub avg_idx: String,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 거부수량
+    #[serde(default, rename = "rjct_qty")]
+    pub rjct_qty: Decimal,
+    /// 장내매매거부사유코드
+    #[serde(default, rename = "ingr_trad_rjct_rson_cd")]
+    pub ingr_trad_rjct_rson_cd: String,
+    /// 장내매매거부사유명
+    #[serde(default, rename = "ingr_trad_rjct_rson_name")]
+    pub ingr_trad_rjct_rson_name: String,
+    /// 주문직원번호
+    #[serde(default, rename = "ord_stfno")]
+    pub ord_stfno: String,
+    /// 스프레드종목여부
+    #[serde(default, rename = "sprd_item_yn")]
+    pub sprd_item_yn: String,
+    /// 주문IP주소
+    #[serde(default, rename = "ord_ip_addr")]
+    pub ord_ip_addr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 주문체결 내역조회 [국내선물-009]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireNgtCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireNgtCcnlOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireNgtCcnlOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireNgtCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [(야간)선물옵션 잔고현황 [국내선물-010]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtBalanceOutput2Item {
+    /// 예수금현금
+    #[serde(default, rename = "dnca_cash")]
+    pub dnca_cash: String,
+    /// 외화예수금액
+    #[serde(default, rename = "frcr_dncl_amt")]
+    pub frcr_dncl_amt: Decimal,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// 현금증거금
+    #[serde(default, rename = "cash_mgna")]
+    pub cash_mgna: String,
+    /// 대용증거금
+    #[serde(default, rename = "sbst_mgna")]
+    pub sbst_mgna: String,
+    /// 증거금총액
+    #[serde(default, rename = "mgna_tota")]
+    pub mgna_tota: String,
+    /// 옵션차금
+    #[serde(default, rename = "opt_dfpa")]
+    pub opt_dfpa: String,
+    /// 당일차금
+    #[serde(default, rename = "thdt_dfpa")]
+    pub thdt_dfpa: String,
+    /// 갱신차금
+    #[serde(default, rename = "rnwl_dfpa")]
+    pub rnwl_dfpa: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 익일예수금
+    #[serde(default, rename = "nxdy_dnca")]
+    pub nxdy_dnca: String,
+    /// 익일예수금액
+    #[serde(default, rename = "nxdy_dncl_amt")]
+    pub nxdy_dncl_amt: Decimal,
+    /// 추정예탁자산
+    #[serde(default, rename = "prsm_dpast")]
+    pub prsm_dpast: String,
+    /// 적정주문가능현금
+    #[serde(default, rename = "pprt_ord_psbl_cash")]
+    pub pprt_ord_psbl_cash: String,
+    /// 추가증거금현금
+    #[serde(default, rename = "add_mgna_cash")]
+    pub add_mgna_cash: String,
+    /// 추가증거금총액
+    #[serde(default, rename = "add_mgna_tota")]
+    pub add_mgna_tota: String,
+    /// 선물매매손익금액
+    #[serde(default, rename = "futr_trad_pfls_amt")]
+    pub futr_trad_pfls_amt: Decimal,
+    /// 옵션매매손익금액
+    #[serde(default, rename = "opt_trad_pfls_amt")]
+    pub opt_trad_pfls_amt: Decimal,
+    /// 선물평가손익금액
+    #[serde(default, rename = "futr_evlu_pfls_amt")]
+    pub futr_evlu_pfls_amt: Decimal,
+    /// 옵션평가손익금액
+    #[serde(default, rename = "opt_evlu_pfls_amt")]
+    pub opt_evlu_pfls_amt: Decimal,
+    /// 매매손익금액합계
+    #[serde(default, rename = "trad_pfls_amt_smtl")]
+    pub trad_pfls_amt_smtl: String,
+    /// 평가손익금액합계
+    #[serde(default, rename = "evlu_pfls_amt_smtl")]
+    pub evlu_pfls_amt_smtl: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 주문가능총액
+    #[serde(default, rename = "ord_psbl_tota")]
+    pub ord_psbl_tota: String,
+    /// 유지증거금총금액
+    #[serde(default, rename = "mmga_tot_amt")]
+    pub mmga_tot_amt: Decimal,
+    /// 유지증거금현금금액
+    #[serde(default, rename = "mmga_cash_amt")]
+    pub mmga_cash_amt: Decimal,
+    /// 유지비율
+    #[serde(default, rename = "mtnc_rt")]
+    pub mtnc_rt: Decimal,
+    /// 부족금액
+    #[serde(default, rename = "isfc_amt")]
+    pub isfc_amt: Decimal,
+    /// 매입금액합계
+    #[serde(default, rename = "pchs_amt_smtl")]
+    pub pchs_amt_smtl: String,
+    /// 평가금액합계
+    #[serde(default, rename = "evlu_amt_smtl")]
+    pub evlu_amt_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 잔고현황 [국내선물-010]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireNgtBalanceOutput1Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매도매수구분명
+    #[serde(default, rename = "sll_buy_dvsn_name")]
+    pub sll_buy_dvsn_name: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 정산단가
+    #[serde(default, rename = "excc_unpr")]
+    pub excc_unpr: Decimal,
+    /// 체결평균단가1
+    #[serde(default, rename = "ccld_avg_unpr1")]
+    pub ccld_avg_unpr1: Decimal,
+    /// 지수종가
+    #[serde(default, rename = "idx_clpr")]
+    pub idx_clpr: String,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 매매손익금액
+    #[serde(default, rename = "trad_pfls_amt")]
+    pub trad_pfls_amt: Decimal,
+    /// 청산가능수량
+    #[serde(default, rename = "lqd_psbl_qty")]
+    pub lqd_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 잔고현황 [국내선물-010]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireNgtBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireNgtBalanceOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireNgtBalanceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireNgtBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [(야간)선물옵션 주문가능 조회 [국내선물-011]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquirePsblNgtOrderOutputItem {
+    /// 최대주문가능수량
+    #[serde(default, rename = "max_ord_psbl_qty")]
+    pub max_ord_psbl_qty: Decimal,
+    /// 최대주문가능수량
+    #[serde(default, rename = "tot_psbl_qty")]
+    pub tot_psbl_qty: Decimal,
+    /// 청산가능수량
+    #[serde(default, rename = "lqd_psbl_qty")]
+    pub lqd_psbl_qty: Decimal,
+    /// 청산가능수량
+    #[serde(default, rename = "lqd_psbl_qty_1")]
+    pub lqd_psbl_qty_1: String,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 기준지수
+    #[serde(default, rename = "bass_idx")]
+    pub bass_idx: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 주문가능 조회 [국내선물-011]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1TradingInquirePsblNgtOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [(야간)선물옵션 증거금 상세 [국내선물-024]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingNgtMarginDetailOutput1Item {
+    /// 현금금액
+    #[serde(default, rename = "cash_amt")]
+    pub cash_amt: Decimal,
+    /// 총금액
+    #[serde(default, rename = "tot_amt")]
+    pub tot_amt: Decimal,
+    /// 선물신규증거금액
+    #[serde(default, rename = "futr_new_mgn_amt")]
+    pub futr_new_mgn_amt: Decimal,
+    /// 선물스프레드주문증거금
+    #[serde(default, rename = "futr_sprd_ord_mgna")]
+    pub futr_sprd_ord_mgna: String,
+    /// 옵션매도신규증거금액
+    #[serde(default, rename = "opt_sll_new_mgn_amt")]
+    pub opt_sll_new_mgn_amt: Decimal,
+    /// 옵션매수신규증거금액
+    #[serde(default, rename = "opt_buy_new_mgn_amt")]
+    pub opt_buy_new_mgn_amt: Decimal,
+    /// 신규증거금액
+    #[serde(default, rename = "new_mgn_amt")]
+    pub new_mgn_amt: Decimal,
+    /// 옵션가격증거금
+    #[serde(default, rename = "opt_pric_mgna")]
+    pub opt_pric_mgna: String,
+    /// 선물옵션가격변동증거금
+    #[serde(default, rename = "fuop_pric_altr_mgna")]
+    pub fuop_pric_altr_mgna: String,
+    /// 선물스프레드증거금
+    #[serde(default, rename = "futr_sprd_mgna")]
+    pub futr_sprd_mgna: String,
+    /// 인수도증거금
+    #[serde(default, rename = "uwdl_mgna")]
+    pub uwdl_mgna: String,
+    /// 계약당최소증거금
+    #[serde(default, rename = "ctrt_per_min_mgna")]
+    pub ctrt_per_min_mgna: String,
+    /// 총위험증거금
+    #[serde(default, rename = "tot_risk_mgna")]
+    pub tot_risk_mgna: String,
+    /// 순위험위탁증거금
+    #[serde(default, rename = "netrisk_brkg_mgna")]
+    pub netrisk_brkg_mgna: String,
+    /// 옵션매도대금
+    #[serde(default, rename = "opt_sll_chgs")]
+    pub opt_sll_chgs: String,
+    /// 옵션매수대금
+    #[serde(default, rename = "opt_buy_chgs")]
+    pub opt_buy_chgs: String,
+    /// 선물손실금액
+    #[serde(default, rename = "futr_loss_amt")]
+    pub futr_loss_amt: Decimal,
+    /// 선물이익금액
+    #[serde(default, rename = "futr_prft_amt")]
+    pub futr_prft_amt: Decimal,
+    /// 당일체결순손실금액
+    #[serde(default, rename = "thdt_ccld_net_loss_amt")]
+    pub thdt_ccld_net_loss_amt: Decimal,
+    /// 위탁증거금
+    #[serde(default, rename = "brkg_mgna")]
+    pub brkg_mgna: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 증거금 상세 [국내선물-024]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingNgtMarginDetailOutput2Item {
+    /// 현금금액
+    #[serde(default, rename = "cash_amt")]
+    pub cash_amt: Decimal,
+    /// 대용금액
+    #[serde(default, rename = "sbst_amt")]
+    pub sbst_amt: Decimal,
+    /// 총금액
+    #[serde(default, rename = "tot_amt")]
+    pub tot_amt: Decimal,
+    /// 선물신규증거금액
+    #[serde(default, rename = "futr_new_mgn_amt")]
+    pub futr_new_mgn_amt: Decimal,
+    /// 선물스프레드주문증거금
+    #[serde(default, rename = "futr_sprd_ord_mgna")]
+    pub futr_sprd_ord_mgna: String,
+    /// 옵션매도신규증거금액
+    #[serde(default, rename = "opt_sll_new_mgn_amt")]
+    pub opt_sll_new_mgn_amt: Decimal,
+    /// 옵션매수신규증거금액
+    #[serde(default, rename = "opt_buy_new_mgn_amt")]
+    pub opt_buy_new_mgn_amt: Decimal,
+    /// 신규증거금액
+    #[serde(default, rename = "new_mgn_amt")]
+    pub new_mgn_amt: Decimal,
+    /// 옵션가격증거금
+    #[serde(default, rename = "opt_pric_mgna")]
+    pub opt_pric_mgna: String,
+    /// 선물옵션가격변동증거금
+    #[serde(default, rename = "fuop_pric_altr_mgna")]
+    pub fuop_pric_altr_mgna: String,
+    /// 선물스프레드증거금
+    #[serde(default, rename = "futr_sprd_mgna")]
+    pub futr_sprd_mgna: String,
+    /// 인수도증거금
+    #[serde(default, rename = "uwdl_mgna")]
+    pub uwdl_mgna: String,
+    /// 계약당최소증거금
+    #[serde(default, rename = "ctrt_per_min_mgna")]
+    pub ctrt_per_min_mgna: String,
+    /// 총위험증거금
+    #[serde(default, rename = "tot_risk_mgna")]
+    pub tot_risk_mgna: String,
+    /// 순위험위탁증거금
+    #[serde(default, rename = "netrisk_brkg_mgna")]
+    pub netrisk_brkg_mgna: String,
+    /// 옵션매도대금
+    #[serde(default, rename = "opt_sll_chgs")]
+    pub opt_sll_chgs: String,
+    /// 옵션매수대금
+    #[serde(default, rename = "opt_buy_chgs")]
+    pub opt_buy_chgs: String,
+    /// 선물손실금액
+    #[serde(default, rename = "futr_loss_amt")]
+    pub futr_loss_amt: Decimal,
+    /// 선물이익금액
+    #[serde(default, rename = "futr_prft_amt")]
+    pub futr_prft_amt: Decimal,
+    /// 당일체결순손실금액
+    #[serde(default, rename = "thdt_ccld_net_loss_amt")]
+    pub thdt_ccld_net_loss_amt: Decimal,
+    /// 위탁증거금
+    #[serde(default, rename = "brkg_mgna")]
+    pub brkg_mgna: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 증거금 상세 [국내선물-024]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingNgtMarginDetailOutput3Item {
+    /// 기본예탁금차등등급코드
+    #[serde(default, rename = "base_dpsa_gdat_grad_cd")]
+    pub base_dpsa_gdat_grad_cd: String,
+    /// 전일대용매도체결금액
+    #[serde(default, rename = "bfdy_sbst_sll_ccld_amt")]
+    pub bfdy_sbst_sll_ccld_amt: Decimal,
+    /// 전일대용매도대용금액
+    #[serde(default, rename = "bfdy_sbst_sll_sbst_amt")]
+    pub bfdy_sbst_sll_sbst_amt: Decimal,
+    /// 정산차금
+    #[serde(default, rename = "excc_dfpa")]
+    pub excc_dfpa: String,
+    /// 수수료금액
+    #[serde(default, rename = "fee_amt")]
+    pub fee_amt: Decimal,
+    /// 익일예수금액
+    #[serde(default, rename = "nxdy_dncl_amt")]
+    pub nxdy_dncl_amt: Decimal,
+    /// 옵션기본예탁금차등등급코드
+    #[serde(default, rename = "opt_base_dpsa_gdat_grad_cd")]
+    pub opt_base_dpsa_gdat_grad_cd: String,
+    /// 옵션매수전용계좌여부
+    #[serde(default, rename = "opt_buy_exus_acnt_yn")]
+    pub opt_buy_exus_acnt_yn: String,
+    /// 옵션차금
+    #[serde(default, rename = "opt_dfpa")]
+    pub opt_dfpa: String,
+    /// 추정예탁자산금액
+    #[serde(default, rename = "prsm_dpast_amt")]
+    pub prsm_dpast_amt: Decimal,
+    /// 당일대용매도체결금액
+    #[serde(default, rename = "thdt_sbst_sll_ccld_amt")]
+    pub thdt_sbst_sll_ccld_amt: Decimal,
+    /// 당일대용매도대용금액
+    #[serde(default, rename = "thdt_sbst_sll_sbst_amt")]
+    pub thdt_sbst_sll_sbst_amt: Decimal,
+    /// 예수금현금
+    #[serde(default, rename = "dnca_cash")]
+    pub dnca_cash: String,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 예수금총액
+    #[serde(default, rename = "dnca_tota")]
+    pub dnca_tota: String,
+    /// 인출가능현금금액
+    #[serde(default, rename = "wdrw_psbl_cash_amt")]
+    pub wdrw_psbl_cash_amt: Decimal,
+    /// 인출가능대용금액
+    #[serde(default, rename = "wdrw_psbl_sbsa")]
+    pub wdrw_psbl_sbsa: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 주문가능현금금액
+    #[serde(default, rename = "ord_psbl_cash_amt")]
+    pub ord_psbl_cash_amt: Decimal,
+    /// 주문가능대용금액
+    #[serde(default, rename = "ord_psbl_sbsa")]
+    pub ord_psbl_sbsa: String,
+    /// 주문가능총금액
+    #[serde(default, rename = "ord_psbl_tot_amt")]
+    pub ord_psbl_tot_amt: Decimal,
+    /// 위탁증거금현금금액
+    #[serde(default, rename = "brkg_mgna_cash_amt")]
+    pub brkg_mgna_cash_amt: Decimal,
+    /// 위탁증거금대용
+    #[serde(default, rename = "brkg_mgna_sbst")]
+    pub brkg_mgna_sbst: String,
+    /// 위탁증거금총금액
+    #[serde(default, rename = "brkg_mgna_tot_amt")]
+    pub brkg_mgna_tot_amt: Decimal,
+    /// 추가증거금현금금액
+    #[serde(default, rename = "add_mgna_cash_amt")]
+    pub add_mgna_cash_amt: Decimal,
+    /// 추가증거금대용금액
+    #[serde(default, rename = "add_mgna_sbsa")]
+    pub add_mgna_sbsa: String,
+    /// 추가증거금총금액
+    #[serde(default, rename = "add_mgna_tot_amt")]
+    pub add_mgna_tot_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [(야간)선물옵션 증거금 상세 [국내선물-024]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingNgtMarginDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingNgtMarginDetailOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingNgtMarginDetailOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<DomesticFutureoptionV1TradingNgtMarginDetailOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingNgtMarginDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 잔고정산손익내역[v1_국내선물-013]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceSettlementPlOutput2Item {
+    /// 익일예수금
+    #[serde(default, rename = "nxdy_dnca")]
+    pub nxdy_dnca: String,
+    /// 유지증거금현금
+    #[serde(default, rename = "mmga_cash")]
+    pub mmga_cash: String,
+    /// 위탁증거금현금
+    #[serde(default, rename = "brkg_mgna_cash")]
+    pub brkg_mgna_cash: String,
+    /// 옵션매수대금
+    #[serde(default, rename = "opt_buy_chgs")]
+    pub opt_buy_chgs: String,
+    /// 옵션청산평가금액
+    #[serde(default, rename = "opt_lqd_evlu_amt")]
+    pub opt_lqd_evlu_amt: Decimal,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 유지증거금총액
+    #[serde(default, rename = "mmga_tota")]
+    pub mmga_tota: String,
+    /// 위탁증거금총액
+    #[serde(default, rename = "brkg_mgna_tota")]
+    pub brkg_mgna_tota: String,
+    /// 옵션매도대금
+    #[serde(default, rename = "opt_sll_chgs")]
+    pub opt_sll_chgs: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 당일차금
+    #[serde(default, rename = "thdt_dfpa")]
+    pub thdt_dfpa: String,
+    /// 갱신차금
+    #[serde(default, rename = "rnwl_dfpa")]
+    pub rnwl_dfpa: String,
+    /// 예수금현금
+    #[serde(default, rename = "dnca_cash")]
+    pub dnca_cash: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고정산손익내역[v1_국내선물-013]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceSettlementPlOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 전일잔고수량
+    #[serde(default, rename = "bfdy_cblc_qty")]
+    pub bfdy_cblc_qty: Decimal,
+    /// 신규수량
+    #[serde(default, rename = "new_qty")]
+    pub new_qty: Decimal,
+    /// 전매환매수량
+    #[serde(default, rename = "mnpl_rpch_qty")]
+    pub mnpl_rpch_qty: Decimal,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 잔고금액
+    #[serde(default, rename = "cblc_amt")]
+    pub cblc_amt: Decimal,
+    /// 매매손익금액
+    #[serde(default, rename = "trad_pfls_amt")]
+    pub trad_pfls_amt: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고정산손익내역[v1_국내선물-013]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceSettlementPlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireBalanceSettlementPlOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireBalanceSettlementPlOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireBalanceSettlementPlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 총자산현황[v1_국내선물-014]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireDepositOutputItem {
+    /// 예수금총액
+    #[serde(default, rename = "dnca_tota")]
+    pub dnca_tota: String,
+    /// 전일수표금액
+    #[serde(default, rename = "bfdy_chck_amt")]
+    pub bfdy_chck_amt: Decimal,
+    /// 당일수표금액
+    #[serde(default, rename = "thdt_chck_amt")]
+    pub thdt_chck_amt: Decimal,
+    /// 실물인수도예치금액
+    #[serde(default, rename = "rlth_uwdl_dpos_amt")]
+    pub rlth_uwdl_dpos_amt: Decimal,
+    /// 위탁증거금현금
+    #[serde(default, rename = "brkg_mgna_cash")]
+    pub brkg_mgna_cash: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능총액
+    #[serde(default, rename = "ord_psbl_tota")]
+    pub ord_psbl_tota: String,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 유가증권대용금액
+    #[serde(default, rename = "scts_sbst_amt")]
+    pub scts_sbst_amt: Decimal,
+    /// 외화평가금액
+    #[serde(default, rename = "frcr_evlu_amt")]
+    pub frcr_evlu_amt: Decimal,
+    /// 위탁증거금대용
+    #[serde(default, rename = "brkg_mgna_sbst")]
+    pub brkg_mgna_sbst: String,
+    /// 대용해제가능금액
+    #[serde(default, rename = "sbst_rlse_psbl_amt")]
+    pub sbst_rlse_psbl_amt: Decimal,
+    /// 유지비율
+    #[serde(default, rename = "mtnc_rt")]
+    pub mtnc_rt: Decimal,
+    /// 추가증거금총액
+    #[serde(default, rename = "add_mgna_tota")]
+    pub add_mgna_tota: String,
+    /// 추가증거금현금
+    #[serde(default, rename = "add_mgna_cash")]
+    pub add_mgna_cash: String,
+    /// 미수금
+    #[serde(default, rename = "rcva")]
+    pub rcva: String,
+    /// 선물매매손익
+    #[serde(default, rename = "futr_trad_pfls")]
+    pub futr_trad_pfls: String,
+    /// 옵션매매손익금액
+    #[serde(default, rename = "opt_trad_pfls_amt")]
+    pub opt_trad_pfls_amt: Decimal,
+    /// 매매손익합계
+    #[serde(default, rename = "trad_pfls_smtl")]
+    pub trad_pfls_smtl: String,
+    /// 선물평가손익금액
+    #[serde(default, rename = "futr_evlu_pfls_amt")]
+    pub futr_evlu_pfls_amt: Decimal,
+    /// 옵션평가손익금액
+    #[serde(default, rename = "opt_evlu_pfls_amt")]
+    pub opt_evlu_pfls_amt: Decimal,
+    /// 평가손익합계
+    #[serde(default, rename = "evlu_pfls_smtl")]
+    pub evlu_pfls_smtl: String,
+    /// 정산차금
+    #[serde(default, rename = "excc_dfpa")]
+    pub excc_dfpa: String,
+    /// 옵션차금
+    #[serde(default, rename = "opt_dfpa")]
+    pub opt_dfpa: String,
+    /// 위탁수수료
+    #[serde(default, rename = "brkg_fee")]
+    pub brkg_fee: String,
+    /// 익일예수금
+    #[serde(default, rename = "nxdy_dnca")]
+    pub nxdy_dnca: String,
+    /// 추정예탁자산금액
+    #[serde(default, rename = "prsm_dpast_amt")]
+    pub prsm_dpast_amt: Decimal,
+    /// 현금유지금액
+    #[serde(default, rename = "cash_mntn_amt")]
+    pub cash_mntn_amt: Decimal,
+    /// 해킹사고계좌이전금액
+    #[serde(default, rename = "hack_acdt_acnt_move_amt")]
+    pub hack_acdt_acnt_move_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 총자산현황[v1_국내선물-014]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireDepositResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1TradingInquireDepositOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireDepositResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 잔고평가손익내역[v1_국내선물-015]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceValuationPlOutput2Item {
+    /// 예수금현금
+    #[serde(default, rename = "dnca_cash")]
+    pub dnca_cash: String,
+    /// 외화예수금액
+    #[serde(default, rename = "frcr_dncl_amt")]
+    pub frcr_dncl_amt: Decimal,
+    /// 예수금대용
+    #[serde(default, rename = "dnca_sbst")]
+    pub dnca_sbst: String,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 현금증거금
+    #[serde(default, rename = "cash_mgna")]
+    pub cash_mgna: String,
+    /// 대용증거금
+    #[serde(default, rename = "sbst_mgna")]
+    pub sbst_mgna: String,
+    /// 증거금총액
+    #[serde(default, rename = "mgna_tota")]
+    pub mgna_tota: String,
+    /// 옵션차금
+    #[serde(default, rename = "opt_dfpa")]
+    pub opt_dfpa: String,
+    /// 당일차금
+    #[serde(default, rename = "thdt_dfpa")]
+    pub thdt_dfpa: String,
+    /// 갱신차금
+    #[serde(default, rename = "rnwl_dfpa")]
+    pub rnwl_dfpa: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 익일예수금
+    #[serde(default, rename = "nxdy_dnca")]
+    pub nxdy_dnca: String,
+    /// 익일예수금액
+    #[serde(default, rename = "nxdy_dncl_amt")]
+    pub nxdy_dncl_amt: Decimal,
+    /// 추정예탁자산
+    #[serde(default, rename = "prsm_dpast")]
+    pub prsm_dpast: String,
+    /// 추정예탁자산금액
+    #[serde(default, rename = "prsm_dpast_amt")]
+    pub prsm_dpast_amt: Decimal,
+    /// 적정주문가능현금
+    #[serde(default, rename = "pprt_ord_psbl_cash")]
+    pub pprt_ord_psbl_cash: String,
+    /// 추가증거금현금
+    #[serde(default, rename = "add_mgna_cash")]
+    pub add_mgna_cash: String,
+    /// 추가증거금총액
+    #[serde(default, rename = "add_mgna_tota")]
+    pub add_mgna_tota: String,
+    /// 선물매매손익금액
+    #[serde(default, rename = "futr_trad_pfls_amt")]
+    pub futr_trad_pfls_amt: Decimal,
+    /// 옵션매매손익금액
+    #[serde(default, rename = "opt_trad_pfls_amt")]
+    pub opt_trad_pfls_amt: Decimal,
+    /// 선물평가손익금액
+    #[serde(default, rename = "futr_evlu_pfls_amt")]
+    pub futr_evlu_pfls_amt: Decimal,
+    /// 옵션평가손익금액
+    #[serde(default, rename = "opt_evlu_pfls_amt")]
+    pub opt_evlu_pfls_amt: Decimal,
+    /// 매매손익금액합계
+    #[serde(default, rename = "trad_pfls_amt_smtl")]
+    pub trad_pfls_amt_smtl: String,
+    /// 평가손익금액합계
+    #[serde(default, rename = "evlu_pfls_amt_smtl")]
+    pub evlu_pfls_amt_smtl: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 주문가능총액
+    #[serde(default, rename = "ord_psbl_tota")]
+    pub ord_psbl_tota: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고평가손익내역[v1_국내선물-015]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceValuationPlOutput1Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매도매수구분명
+    #[serde(default, rename = "sll_buy_dvsn_name")]
+    pub sll_buy_dvsn_name: String,
+    /// 잔고수량1
+    #[serde(default, rename = "cblc_qty1")]
+    pub cblc_qty1: String,
+    /// 정산단가
+    #[serde(default, rename = "excc_unpr")]
+    pub excc_unpr: Decimal,
+    /// 체결평균단가1
+    #[serde(default, rename = "ccld_avg_unpr1")]
+    pub ccld_avg_unpr1: Decimal,
+    /// 지수종가
+    #[serde(default, rename = "idx_clpr")]
+    pub idx_clpr: String,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 매매손익금액
+    #[serde(default, rename = "trad_pfls_amt")]
+    pub trad_pfls_amt: Decimal,
+    /// 청산가능수량
+    #[serde(default, rename = "lqd_psbl_qty")]
+    pub lqd_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 잔고평가손익내역[v1_국내선물-015]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireBalanceValuationPlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireBalanceValuationPlOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireBalanceValuationPlOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireBalanceValuationPlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 기준일체결내역[v1_국내선물-016]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlBstimeOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 거래유형명
+    #[serde(default, rename = "tr_type_name")]
+    pub tr_type_name: String,
+    /// 최종결제일
+    #[serde(default, rename = "last_sttldt")]
+    pub last_sttldt: String,
+    /// 체결지수
+    #[serde(default, rename = "ccld_idx")]
+    pub ccld_idx: String,
+    /// 체결량
+    #[serde(default, rename = "ccld_qty")]
+    pub ccld_qty: Decimal,
+    /// 매매금액
+    #[serde(default, rename = "trad_amt")]
+    pub trad_amt: Decimal,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 체결시간
+    #[serde(default, rename = "ccld_btwn")]
+    pub ccld_btwn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 기준일체결내역[v1_국내선물-016]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlBstimeOutput2Item {
+    /// 총체결수량합계
+    #[serde(default, rename = "tot_ccld_qty_smtl")]
+    pub tot_ccld_qty_smtl: String,
+    /// 총체결금액합계
+    #[serde(default, rename = "tot_ccld_amt_smtl")]
+    pub tot_ccld_amt_smtl: String,
+    /// 수수료조정
+    #[serde(default, rename = "fee_adjt")]
+    pub fee_adjt: String,
+    /// 수수료합계
+    #[serde(default, rename = "fee_smtl")]
+    pub fee_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 기준일체결내역[v1_국내선물-016]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireCcnlBstimeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireCcnlBstimeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireCcnlBstimeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireCcnlBstimeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션기간약정수수료일별[v1_국내선물-017]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireDailyAmountFeeOutput1Item {
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 종목명
+    #[serde(default, rename = "item_name")]
+    pub item_name: String,
+    /// 매도약정금액
+    #[serde(default, rename = "sll_agrm_amt")]
+    pub sll_agrm_amt: Decimal,
+    /// 매도수수료
+    #[serde(default, rename = "sll_fee")]
+    pub sll_fee: String,
+    /// 매수약정금액
+    #[serde(default, rename = "buy_agrm_amt")]
+    pub buy_agrm_amt: Decimal,
+    /// 매수수수료
+    #[serde(default, rename = "buy_fee")]
+    pub buy_fee: String,
+    /// 총수수료합계
+    #[serde(default, rename = "tot_fee_smtl")]
+    pub tot_fee_smtl: String,
+    /// 매매손익
+    #[serde(default, rename = "trad_pfls")]
+    pub trad_pfls: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션기간약정수수료일별[v1_국내선물-017]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1TradingInquireDailyAmountFeeOutput2Item {
+    /// 선물약정
+    #[serde(default, rename = "futr_agrm")]
+    pub futr_agrm: String,
+    /// 선물약정금액
+    #[serde(default, rename = "futr_agrm_amt")]
+    pub futr_agrm_amt: Decimal,
+    /// 선물약정금액합계
+    #[serde(default, rename = "futr_agrm_amt_smtl")]
+    pub futr_agrm_amt_smtl: String,
+    /// 선물매도수수료합계
+    #[serde(default, rename = "futr_sll_fee_smtl")]
+    pub futr_sll_fee_smtl: String,
+    /// 선물매수수수료합계
+    #[serde(default, rename = "futr_buy_fee_smtl")]
+    pub futr_buy_fee_smtl: String,
+    /// 선물수수료합계
+    #[serde(default, rename = "futr_fee_smtl")]
+    pub futr_fee_smtl: String,
+    /// 옵션약정
+    #[serde(default, rename = "opt_agrm")]
+    pub opt_agrm: String,
+    /// 옵션약정금액
+    #[serde(default, rename = "opt_agrm_amt")]
+    pub opt_agrm_amt: Decimal,
+    /// 옵션약정금액합계
+    #[serde(default, rename = "opt_agrm_amt_smtl")]
+    pub opt_agrm_amt_smtl: String,
+    /// 옵션매도수수료합계
+    #[serde(default, rename = "opt_sll_fee_smtl")]
+    pub opt_sll_fee_smtl: String,
+    /// 옵션매수수수료합계
+    #[serde(default, rename = "opt_buy_fee_smtl")]
+    pub opt_buy_fee_smtl: String,
+    /// 옵션수수료합계
+    #[serde(default, rename = "opt_fee_smtl")]
+    pub opt_fee_smtl: String,
+    /// 상품선물약정
+    #[serde(default, rename = "prdt_futr_agrm")]
+    pub prdt_futr_agrm: String,
+    /// 상품선물옵션
+    #[serde(default, rename = "prdt_fuop")]
+    pub prdt_fuop: String,
+    /// 상품선물평가금액
+    #[serde(default, rename = "prdt_futr_evlu_amt")]
+    pub prdt_futr_evlu_amt: Decimal,
+    /// 선물수수료
+    #[serde(default, rename = "futr_fee")]
+    pub futr_fee: String,
+    /// 옵션수수료
+    #[serde(default, rename = "opt_fee")]
+    pub opt_fee: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 매도약정금액
+    #[serde(default, rename = "sll_agrm_amt")]
+    pub sll_agrm_amt: Decimal,
+    /// 매수약정금액
+    #[serde(default, rename = "buy_agrm_amt")]
+    pub buy_agrm_amt: Decimal,
+    /// 약정금액합계
+    #[serde(default, rename = "agrm_amt_smtl")]
+    pub agrm_amt_smtl: String,
+    /// 매도수수료
+    #[serde(default, rename = "sll_fee")]
+    pub sll_fee: String,
+    /// 매수수수료
+    #[serde(default, rename = "buy_fee")]
+    pub buy_fee: String,
+    /// 수수료합계
+    #[serde(default, rename = "fee_smtl")]
+    pub fee_smtl: String,
+    /// 매매손익합계
+    #[serde(default, rename = "trad_pfls_smtl")]
+    pub trad_pfls_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션기간약정수수료일별[v1_국내선물-017]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1TradingInquireDailyAmountFeeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1TradingInquireDailyAmountFeeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1TradingInquireDailyAmountFeeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1TradingInquireDailyAmountFeeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 증거금률] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsMarginRateOutputItem {
+    /// 기초자산ID
+    #[serde(default, rename = "bast_id")]
+    pub bast_id: String,
+    /// 기초자산명
+    #[serde(default, rename = "bast_name")]
+    pub bast_name: String,
+    /// 위탁증거금율
+    #[serde(default, rename = "brkg_mgna_rt")]
+    pub brkg_mgna_rt: Decimal,
+    /// 거래증거금율
+    #[serde(default, rename = "tr_mgna_rt")]
+    pub tr_mgna_rt: Decimal,
+    /// 기초자산가격
+    #[serde(default, rename = "bast_pric")]
+    pub bast_pric: Decimal,
+    /// 거래승수지수
+    #[serde(default, rename = "tr_mtpl_idx")]
+    pub tr_mtpl_idx: String,
+    /// 계약당선물증거금
+    #[serde(default, rename = "ctrt_per_futr_mgna")]
+    pub ctrt_per_futr_mgna: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 증거금률] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsMarginRateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticFutureoptionV1QuotationsMarginRateOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsMarginRateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 시세[v1_국내선물-006]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquirePriceOutput1Item {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 종가
+    #[serde(default, rename = "futs_prdy_clpr")]
+    pub futs_prdy_clpr: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "otst_stpl_qty_icdc")]
+    pub otst_stpl_qty_icdc: String,
+    /// 선물 시가2
+    #[serde(default, rename = "futs_oprc")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "futs_lwpr")]
+    pub futs_lwpr: String,
+    /// 선물 상한가
+    #[serde(default, rename = "futs_mxpr")]
+    pub futs_mxpr: String,
+    /// 선물 하한가
+    #[serde(default, rename = "futs_llam")]
+    pub futs_llam: String,
+    /// 베이시스
+    #[serde(default, rename = "basis")]
+    pub basis: String,
+    /// 선물 기준가
+    #[serde(default, rename = "futs_sdpr")]
+    pub futs_sdpr: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 서킷브레이커 적용 상한가
+    #[serde(default, rename = "crbr_aply_mxpr")]
+    pub crbr_aply_mxpr: String,
+    /// 서킷브레이커 적용 하한가
+    #[serde(default, rename = "crbr_aply_llam")]
+    pub crbr_aply_llam: String,
+    /// 선물 최종 거래 일자
+    #[serde(default, rename = "futs_last_tr_date")]
+    pub futs_last_tr_date: String,
+    /// HTS 잔존 일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 선물 상장 중 최고가
+    #[serde(default, rename = "futs_lstn_medm_hgpr")]
+    pub futs_lstn_medm_hgpr: String,
+    /// 선물 상장 중 최저가
+    #[serde(default, rename = "futs_lstn_medm_lwpr")]
+    pub futs_lstn_medm_lwpr: String,
+    /// 델타 값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// 역사적 변동성
+    #[serde(default, rename = "hist_vltl")]
+    pub hist_vltl: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 시장 베이시스
+    #[serde(default, rename = "mrkt_basis")]
+    pub mrkt_basis: String,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 시세[v1_국내선물-006]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquirePriceOutput2Item {
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 시세[v1_국내선물-006]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquirePriceOutput3Item {
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 시세[v1_국내선물-006]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsInquirePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsInquirePriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsInquirePriceOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<DomesticFutureoptionV1QuotationsInquirePriceOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsInquirePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 시세호가[v1_국내선물-007]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireAskingPriceOutput1Item {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 선물 전일 종가
+    #[serde(default, rename = "futs_prdy_clpr")]
+    pub futs_prdy_clpr: String,
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "futs_shrn_iscd")]
+    pub futs_shrn_iscd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 시세호가[v1_국내선물-007]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireAskingPriceOutput2Item {
+    /// 선물 매도호가1
+    #[serde(default, rename = "futs_askp1")]
+    pub futs_askp1: String,
+    /// 선물 매도호가2
+    #[serde(default, rename = "futs_askp2")]
+    pub futs_askp2: String,
+    /// 선물 매도호가3
+    #[serde(default, rename = "futs_askp3")]
+    pub futs_askp3: String,
+    /// 선물 매도호가4
+    #[serde(default, rename = "futs_askp4")]
+    pub futs_askp4: String,
+    /// 선물 매도호가5
+    #[serde(default, rename = "futs_askp5")]
+    pub futs_askp5: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "futs_bidp1")]
+    pub futs_bidp1: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "futs_bidp2")]
+    pub futs_bidp2: String,
+    /// 선물 매수호가3
+    #[serde(default, rename = "futs_bidp3")]
+    pub futs_bidp3: String,
+    /// 선물 매수호가4
+    #[serde(default, rename = "futs_bidp4")]
+    pub futs_bidp4: String,
+    /// 선물 매수호가5
+    #[serde(default, rename = "futs_bidp5")]
+    pub futs_bidp5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "askp_rsqn1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "askp_rsqn2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "askp_rsqn3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "askp_rsqn4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "askp_rsqn5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "bidp_rsqn1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "bidp_rsqn2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "bidp_rsqn3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "bidp_rsqn4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "bidp_rsqn5")]
+    pub bidp_rsqn5: String,
+    /// 매도호가 건수1
+    #[serde(default, rename = "askp_csnu1")]
+    pub askp_csnu1: String,
+    /// 매도호가 건수2
+    #[serde(default, rename = "askp_csnu2")]
+    pub askp_csnu2: String,
+    /// 매도호가 건수3
+    #[serde(default, rename = "askp_csnu3")]
+    pub askp_csnu3: String,
+    /// 매도호가 건수4
+    #[serde(default, rename = "askp_csnu4")]
+    pub askp_csnu4: String,
+    /// 매도호가 건수5
+    #[serde(default, rename = "askp_csnu5")]
+    pub askp_csnu5: String,
+    /// 매수호가 건수1
+    #[serde(default, rename = "bidp_csnu1")]
+    pub bidp_csnu1: String,
+    /// 매수호가 건수2
+    #[serde(default, rename = "bidp_csnu2")]
+    pub bidp_csnu2: String,
+    /// 매수호가 건수3
+    #[serde(default, rename = "bidp_csnu3")]
+    pub bidp_csnu3: String,
+    /// 매수호가 건수4
+    #[serde(default, rename = "bidp_csnu4")]
+    pub bidp_csnu4: String,
+    /// 매수호가 건수5
+    #[serde(default, rename = "bidp_csnu5")]
+    pub bidp_csnu5: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 건수
+    #[serde(default, rename = "total_askp_csnu")]
+    pub total_askp_csnu: String,
+    /// 총 매수호가 건수
+    #[serde(default, rename = "total_bidp_csnu")]
+    pub total_bidp_csnu: String,
+    /// 호가 접수 시간
+    #[serde(default, rename = "aspr_acpt_hour")]
+    pub aspr_acpt_hour: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 시세호가[v1_국내선물-007]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsInquireAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsInquireAskingPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsInquireAskingPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsInquireAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션기간별시세(일/주/월/년)[v1_국내선물-008]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceOutput1Item {
+    /// 전일 대비
+    #[serde(default, rename = "-futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "-prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "-futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// 선물 전일 종가
+    #[serde(default, rename = "-futs_prdy_clpr")]
+    pub futs_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "-acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "-acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "-hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 현재가
+    #[serde(default, rename = "-futs_prpr")]
+    pub futs_prpr: String,
+    /// 단축 종목코드
+    #[serde(default, rename = "-futs_shrn_iscd")]
+    pub futs_shrn_iscd: String,
+    /// 전일 거래량
+    #[serde(default, rename = "-prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 상한가
+    #[serde(default, rename = "-futs_mxpr")]
+    pub futs_mxpr: String,
+    /// 하한가
+    #[serde(default, rename = "-futs_llam")]
+    pub futs_llam: String,
+    /// 시가
+    #[serde(default, rename = "-futs_oprc")]
+    pub futs_oprc: String,
+    /// 최고가
+    #[serde(default, rename = "-futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 최저가
+    #[serde(default, rename = "-futs_lwpr")]
+    pub futs_lwpr: String,
+    /// 전일 시가
+    #[serde(default, rename = "-futs_prdy_oprc")]
+    pub futs_prdy_oprc: String,
+    /// 전일 최고가
+    #[serde(default, rename = "-futs_prdy_hgpr")]
+    pub futs_prdy_hgpr: String,
+    /// 전일 최저가
+    #[serde(default, rename = "-futs_prdy_lwpr")]
+    pub futs_prdy_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "-futs_askp")]
+    pub futs_askp: String,
+    /// 매수호가
+    #[serde(default, rename = "-futs_bidp")]
+    pub futs_bidp: String,
+    /// 베이시스
+    #[serde(default, rename = "-basis")]
+    pub basis: String,
+    /// KOSPI200 지수
+    #[serde(default, rename = "-kospi200_nmix")]
+    pub kospi200_nmix: String,
+    /// KOSPI200 전일 대비
+    #[serde(default, rename = "-kospi200_prdy_vrss")]
+    pub kospi200_prdy_vrss: String,
+    /// KOSPI200 전일 대비율
+    #[serde(default, rename = "-kospi200_prdy_ctrt")]
+    pub kospi200_prdy_ctrt: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "-kospi200_prdy_vrss_sign")]
+    pub kospi200_prdy_vrss_sign: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "-hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "-otst_stpl_qty_icdc")]
+    pub otst_stpl_qty_icdc: String,
+    /// 당일 체결강도
+    #[serde(default, rename = "-tday_rltv")]
+    pub tday_rltv: String,
+    /// HTS 이론가
+    #[serde(default, rename = "-hts_thpr")]
+    pub hts_thpr: String,
+    /// 괴리율
+    #[serde(default, rename = "-dprt")]
+    pub dprt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션기간별시세(일/주/월/년)[v1_국내선물-008]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceOutput2Item {
+    /// 영업 일자
+    #[serde(default, rename = "-stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 현재가
+    #[serde(default, rename = "-futs_prpr")]
+    pub futs_prpr: String,
+    /// 시가
+    #[serde(default, rename = "-futs_oprc")]
+    pub futs_oprc: String,
+    /// 최고가
+    #[serde(default, rename = "-futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 최저가
+    #[serde(default, rename = "-futs_lwpr")]
+    pub futs_lwpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "-acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "-acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 변경 여부
+    #[serde(default, rename = "-mod_yn")]
+    pub mod_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션기간별시세(일/주/월/년)[v1_국내선물-008]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 분봉조회[v1_국내선물-012]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceOutput1Item {
+    /// 선물 전일 대비
+    #[serde(default, rename = "futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// 선물 전일 종가
+    #[serde(default, rename = "futs_prdy_clpr")]
+    pub futs_prdy_clpr: String,
+    /// 전일 지수
+    #[serde(default, rename = "prdy_nmix")]
+    pub prdy_nmix: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "futs_shrn_iscd")]
+    pub futs_shrn_iscd: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 선물 상한가
+    #[serde(default, rename = "futs_mxpr")]
+    pub futs_mxpr: String,
+    /// 선물 하한가
+    #[serde(default, rename = "futs_llam")]
+    pub futs_llam: String,
+    /// 선물 시가2
+    #[serde(default, rename = "futs_oprc")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "futs_lwpr")]
+    pub futs_lwpr: String,
+    /// 선물 전일 시가
+    #[serde(default, rename = "futs_prdy_oprc")]
+    pub futs_prdy_oprc: String,
+    /// 선물 전일 최고가
+    #[serde(default, rename = "futs_prdy_hgpr")]
+    pub futs_prdy_hgpr: String,
+    /// 선물 전일 최저가
+    #[serde(default, rename = "futs_prdy_lwpr")]
+    pub futs_prdy_lwpr: String,
+    /// 선물 매도호가
+    #[serde(default, rename = "futs_askp")]
+    pub futs_askp: String,
+    /// 선물 매수호가
+    #[serde(default, rename = "futs_bidp")]
+    pub futs_bidp: String,
+    /// 베이시스
+    #[serde(default, rename = "basis")]
+    pub basis: String,
+    /// KOSPI200 지수
+    #[serde(default, rename = "kospi200_nmix")]
+    pub kospi200_nmix: String,
+    /// KOSPI200 전일 대비
+    #[serde(default, rename = "kospi200_prdy_vrss")]
+    pub kospi200_prdy_vrss: String,
+    /// KOSPI200 전일 대비율
+    #[serde(default, rename = "kospi200_prdy_ctrt")]
+    pub kospi200_prdy_ctrt: String,
+    /// KOSPI200 전일 대비 부호
+    #[serde(default, rename = "kospi200_prdy_vrss_sign")]
+    pub kospi200_prdy_vrss_sign: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "otst_stpl_qty_icdc")]
+    pub otst_stpl_qty_icdc: String,
+    /// 당일 체결강도
+    #[serde(default, rename = "tday_rltv")]
+    pub tday_rltv: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 분봉조회[v1_국내선물-012]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 선물 시가2
+    #[serde(default, rename = "futs_oprc")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "futs_lwpr")]
+    pub futs_lwpr: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 분봉조회[v1_국내선물-012]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내옵션전광판_옵션월물리스트[국내선물-020]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardOptionListOutput1Item {
+    /// 만기 년월 코드
+    #[serde(default, rename = "mtrt_yymm_code")]
+    pub mtrt_yymm_code: String,
+    /// 만기 년월
+    #[serde(default, rename = "mtrt_yymm")]
+    pub mtrt_yymm: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내옵션전광판_옵션월물리스트[국내선물-020]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardOptionListResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsDisplayBoardOptionListOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsDisplayBoardOptionListResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내선물 기초자산 시세[국내선물-021]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardTopOutput1Item {
+    /// 기초자산 현재가
+    #[serde(default, rename = "unas_prpr")]
+    pub unas_prpr: String,
+    /// 기초자산 전일 대비
+    #[serde(default, rename = "unas_prdy_vrss")]
+    pub unas_prdy_vrss: String,
+    /// 기초자산 전일 대비 부호
+    #[serde(default, rename = "unas_prdy_vrss_sign")]
+    pub unas_prdy_vrss_sign: String,
+    /// 기초자산 전일 대비율
+    #[serde(default, rename = "unas_prdy_ctrt")]
+    pub unas_prdy_ctrt: String,
+    /// 기초자산 누적 거래량
+    #[serde(default, rename = "unas_acml_vol")]
+    pub unas_acml_vol: Decimal,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내선물 기초자산 시세[국내선물-021]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardTopOutput2Item {
+    /// HTS 잔존 일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내선물 기초자산 시세[국내선물-021]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardTopResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsDisplayBoardTopOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsDisplayBoardTopOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsDisplayBoardTopResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내옵션전광판_콜풋[국내선물-022]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardCallputOutput1Item {
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 환산 현재가
+    #[serde(default, rename = "unch_prpr")]
+    pub unch_prpr: String,
+    /// 옵션 단축 종목코드
+    #[serde(default, rename = "optn_shrn_iscd")]
+    pub optn_shrn_iscd: String,
+    /// 옵션 현재가
+    #[serde(default, rename = "optn_prpr")]
+    pub optn_prpr: String,
+    /// 옵션 전일 대비
+    #[serde(default, rename = "optn_prdy_vrss")]
+    pub optn_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 옵션 전일 대비율
+    #[serde(default, rename = "optn_prdy_ctrt")]
+    pub optn_prdy_ctrt: String,
+    /// 옵션 매수호가
+    #[serde(default, rename = "optn_bidp")]
+    pub optn_bidp: String,
+    /// 옵션 매도호가
+    #[serde(default, rename = "optn_askp")]
+    pub optn_askp: String,
+    /// 시간가치 값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 지수 기준가
+    #[serde(default, rename = "nmix_sdpr")]
+    pub nmix_sdpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매도 잔량
+    #[serde(default, rename = "seln_rsqn")]
+    pub seln_rsqn: String,
+    /// 매수2 잔량
+    #[serde(default, rename = "shnu_rsqn")]
+    pub shnu_rsqn: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "otst_stpl_qty_icdc")]
+    pub otst_stpl_qty_icdc: String,
+    /// 델타 값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 내재가치 값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 괴리도
+    #[serde(default, rename = "esdg")]
+    pub esdg: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 역사적 변동성
+    #[serde(default, rename = "hist_vltl")]
+    pub hist_vltl: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 옵션 시가2
+    #[serde(default, rename = "optn_oprc")]
+    pub optn_oprc: String,
+    /// 옵션 최고가
+    #[serde(default, rename = "optn_hgpr")]
+    pub optn_hgpr: String,
+    /// 옵션 최저가
+    #[serde(default, rename = "optn_lwpr")]
+    pub optn_lwpr: String,
+    /// 옵션 상한가
+    #[serde(default, rename = "optn_mxpr")]
+    pub optn_mxpr: String,
+    /// 옵션 하한가
+    #[serde(default, rename = "optn_llam")]
+    pub optn_llam: String,
+    /// ATM 구분 명
+    #[serde(default, rename = "atm_cls_name")]
+    pub atm_cls_name: String,
+    /// 직전 대비 증감
+    #[serde(default, rename = "rgbf_vrss_icdc")]
+    pub rgbf_vrss_icdc: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 선물예상체결가
+    #[serde(default, rename = "futs_antc_cnpr")]
+    pub futs_antc_cnpr: String,
+    /// 선물예상체결대비
+    #[serde(default, rename = "futs_antc_cntg_vrss")]
+    pub futs_antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내옵션전광판_콜풋[국내선물-022]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardCallputOutput2Item {
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 환산 현재가
+    #[serde(default, rename = "unch_prpr")]
+    pub unch_prpr: String,
+    /// 옵션 단축 종목코드
+    #[serde(default, rename = "optn_shrn_iscd")]
+    pub optn_shrn_iscd: String,
+    /// 옵션 현재가
+    #[serde(default, rename = "optn_prpr")]
+    pub optn_prpr: String,
+    /// 옵션 전일 대비
+    #[serde(default, rename = "optn_prdy_vrss")]
+    pub optn_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 옵션 전일 대비율
+    #[serde(default, rename = "optn_prdy_ctrt")]
+    pub optn_prdy_ctrt: String,
+    /// 옵션 매수호가
+    #[serde(default, rename = "optn_bidp")]
+    pub optn_bidp: String,
+    /// 옵션 매도호가
+    #[serde(default, rename = "optn_askp")]
+    pub optn_askp: String,
+    /// 시간가치 값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 지수 기준가
+    #[serde(default, rename = "nmix_sdpr")]
+    pub nmix_sdpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매도 잔량
+    #[serde(default, rename = "seln_rsqn")]
+    pub seln_rsqn: String,
+    /// 매수2 잔량
+    #[serde(default, rename = "shnu_rsqn")]
+    pub shnu_rsqn: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "otst_stpl_qty_icdc")]
+    pub otst_stpl_qty_icdc: String,
+    /// 델타 값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 내재가치 값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 괴리도
+    #[serde(default, rename = "esdg")]
+    pub esdg: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 역사적 변동성
+    #[serde(default, rename = "hist_vltl")]
+    pub hist_vltl: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 옵션 시가2
+    #[serde(default, rename = "optn_oprc")]
+    pub optn_oprc: String,
+    /// 옵션 최고가
+    #[serde(default, rename = "optn_hgpr")]
+    pub optn_hgpr: String,
+    /// 옵션 최저가
+    #[serde(default, rename = "optn_lwpr")]
+    pub optn_lwpr: String,
+    /// 옵션 상한가
+    #[serde(default, rename = "optn_mxpr")]
+    pub optn_mxpr: String,
+    /// 옵션 하한가
+    #[serde(default, rename = "optn_llam")]
+    pub optn_llam: String,
+    /// ATM 구분 명
+    #[serde(default, rename = "atm_cls_name")]
+    pub atm_cls_name: String,
+    /// 직전 대비 증감
+    #[serde(default, rename = "rgbf_vrss_icdc")]
+    pub rgbf_vrss_icdc: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 선물예상체결가
+    #[serde(default, rename = "futs_antc_cnpr")]
+    pub futs_antc_cnpr: String,
+    /// 선물예상체결대비
+    #[serde(default, rename = "futs_antc_cntg_vrss")]
+    pub futs_antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내옵션전광판_콜풋[국내선물-022]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardCallputResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsDisplayBoardCallputOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsDisplayBoardCallputOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsDisplayBoardCallputResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내옵션전광판_선물[국내선물-023]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardFuturesOutput1Item {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "futs_shrn_iscd")]
+    pub futs_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 선물 현재가
+    #[serde(default, rename = "futs_prpr")]
+    pub futs_prpr: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "futs_prdy_vrss")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "futs_prdy_ctrt")]
+    pub futs_prdy_ctrt: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 선물 매도호가
+    #[serde(default, rename = "futs_askp")]
+    pub futs_askp: String,
+    /// 선물 매수호가
+    #[serde(default, rename = "futs_bidp")]
+    pub futs_bidp: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "hts_otst_stpl_qty")]
+    pub hts_otst_stpl_qty: Decimal,
+    /// 선물 최고가
+    #[serde(default, rename = "futs_hgpr")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "futs_lwpr")]
+    pub futs_lwpr: String,
+    /// HTS 잔존 일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 선물예상체결가
+    #[serde(default, rename = "futs_antc_cnpr")]
+    pub futs_antc_cnpr: String,
+    /// 선물예상체결대비
+    #[serde(default, rename = "futs_antc_cntg_vrss")]
+    pub futs_antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내옵션전광판_선물[국내선물-023]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsDisplayBoardFuturesResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsDisplayBoardFuturesOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsDisplayBoardFuturesResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [선물옵션 일중예상체결추이[국내선물-018]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsExpPriceTrendOutput1Item {
+    /// 영업 시간
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "futs_antc_cnpr")]
+    pub futs_antc_cnpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "futs_antc_cntg_vrss")]
+    pub futs_antc_cntg_vrss: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "futs_sdpr")]
+    pub futs_sdpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 일중예상체결추이[국내선물-018]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticFutureoptionV1QuotationsExpPriceTrendOutput2Item {
+    /// 주식체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 선물예상체결가
+    #[serde(default, rename = "futs_antc_cnpr")]
+    pub futs_antc_cnpr: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 선물예상체결대비
+    #[serde(default, rename = "futs_antc_cntg_vrss")]
+    pub futs_antc_cntg_vrss: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [선물옵션 일중예상체결추이[국내선물-018]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticFutureoptionV1QuotationsExpPriceTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticFutureoptionV1QuotationsExpPriceTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticFutureoptionV1QuotationsExpPriceTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticFutureoptionV1QuotationsExpPriceTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
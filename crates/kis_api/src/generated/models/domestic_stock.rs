@@ -0,0 +1,17236 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [주식주문(현금)[v1_국내주식-001]] 요청 구조체
+/// [국내주식-001 v1] 주식주문(현금)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderCashRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도유형 (매도주문 시) (String, 선택)
+    #[serde(rename = "SLL_TYPE")]
+    pub sll_type: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 조건가격 (String, 선택)
+    #[serde(rename = "CNDT_PRIC")]
+    pub cndt_pric: String,
+    /// 거래소ID구분코드 (String, 선택)
+    #[serde(rename = "EXCG_ID_DVSN_CD")]
+    pub excg_id_dvsn_cd: String,
+}
+
+/// [주식주문(신용)[v1_국내주식-002]] 요청 구조체
+/// [국내주식-002 v1] 주식주문(신용)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderCreditRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도유형 (String, 선택)
+    #[serde(rename = "SLL_TYPE")]
+    pub sll_type: String,
+    /// 신용유형 (String, 필수)
+    #[serde(rename = "CRDT_TYPE")]
+    pub crdt_type: String,
+    /// 대출일자 (String, 필수)
+    #[serde(rename = "LOAN_DT")]
+    pub loan_dt: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 예약주문여부 (String, 선택)
+    #[serde(rename = "RSVN_ORD_YN")]
+    pub rsvn_ord_yn: String,
+    /// 비상주문여부 (String, 선택)
+    #[serde(rename = "EMGC_ORD_YN")]
+    pub emgc_ord_yn: String,
+    /// 프로그램매매구분 (String, 선택)
+    #[serde(rename = "PGTR_DVSN")]
+    pub pgtr_dvsn: String,
+    /// 운용사지정주문번호 (String, 선택)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 대량거래협상상세번호 (String, 선택)
+    #[serde(rename = "LQTY_TR_NGTN_DTL_NO")]
+    pub lqty_tr_ngtn_dtl_no: String,
+    /// 대량거래협정번호 (String, 선택)
+    #[serde(rename = "LQTY_TR_AGMT_NO")]
+    pub lqty_tr_agmt_no: String,
+    /// 대량거래협상자Id (String, 선택)
+    #[serde(rename = "LQTY_TR_NGTN_ID")]
+    pub lqty_tr_ngtn_id: String,
+    /// LP주문여부 (String, 선택)
+    #[serde(rename = "LP_ORD_YN")]
+    pub lp_ord_yn: String,
+    /// 매체주문번호 (String, 선택)
+    #[serde(rename = "MDIA_ODNO")]
+    pub mdia_odno: String,
+    /// 주문서버구분코드 (String, 선택)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 프로그램호가신고구분코드 (String, 선택)
+    #[serde(rename = "PGM_NMPR_STMT_DVSN_CD")]
+    pub pgm_nmpr_stmt_dvsn_cd: String,
+    /// 반대매매선정사유코드 (String, 선택)
+    #[serde(rename = "CVRG_SLCT_RSON_CD")]
+    pub cvrg_slct_rson_cd: String,
+    /// 반대매매순번 (String, 선택)
+    #[serde(rename = "CVRG_SEQ")]
+    pub cvrg_seq: String,
+    /// 거래소ID구분코드 (String, 선택)
+    #[serde(rename = "EXCG_ID_DVSN_CD")]
+    pub excg_id_dvsn_cd: String,
+    /// 조건가격 (String, 선택)
+    #[serde(rename = "CNDT_PRIC")]
+    pub cndt_pric: String,
+}
+
+/// [주식주문(정정취소)[v1_국내주식-003]] 요청 구조체
+/// [국내주식-003 v1] 주식주문(정정취소)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 한국거래소전송주문조직번호 (String, 필수)
+    #[serde(rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 잔량전부주문여부 (String, 필수)
+    #[serde(rename = "QTY_ALL_ORD_YN")]
+    pub qty_all_ord_yn: String,
+    /// 조건가격 (String, 선택)
+    #[serde(rename = "CNDT_PRIC")]
+    pub cndt_pric: String,
+    /// 거래소ID구분코드 (String, 선택)
+    #[serde(rename = "EXCG_ID_DVSN_CD")]
+    pub excg_id_dvsn_cd: String,
+}
+
+/// [주식정정취소가능주문조회[v1_국내주식-004]] 요청 구조체
+/// [국내주식-004 v1] 주식정정취소가능주문조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 조회구분1 (String, 필수)
+    #[serde(rename = "INQR_DVSN_1")]
+    pub inqr_dvsn_1: String,
+    /// 조회구분2 (String, 필수)
+    #[serde(rename = "INQR_DVSN_2")]
+    pub inqr_dvsn_2: String,
+}
+
+/// [주식일별주문체결조회[v1_국내주식-005]] 요청 구조체
+/// [국내주식-005 v1] 주식일별주문체결조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireDailyCcldRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 상품번호 (String, 선택)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문채번지점번호 (String, 필수)
+    #[serde(rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: String,
+    /// 주문번호 (String, 선택)
+    #[serde(rename = "ODNO")]
+    pub odno: String,
+    /// 체결구분 (String, 필수)
+    #[serde(rename = "CCLD_DVSN")]
+    pub ccld_dvsn: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 조회구분1 (String, 필수)
+    #[serde(rename = "INQR_DVSN_1")]
+    pub inqr_dvsn_1: String,
+    /// 조회구분3 (String, 필수)
+    #[serde(rename = "INQR_DVSN_3")]
+    pub inqr_dvsn_3: String,
+    /// 거래소ID구분코드 (String, 필수)
+    #[serde(rename = "EXCG_ID_DVSN_CD")]
+    pub excg_id_dvsn_cd: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [주식잔고조회[v1_국내주식-006]] 요청 구조체
+/// [국내주식-006 v1] 주식잔고조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시간외단일가, 거래소여부 (String, 필수)
+    #[serde(rename = "AFHR_FLPR_YN")]
+    pub afhr_flpr_yn: String,
+    /// 오프라인여부 (String, 선택)
+    #[serde(rename = "OFL_YN")]
+    pub ofl_yn: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 단가구분 (String, 필수)
+    #[serde(rename = "UNPR_DVSN")]
+    pub unpr_dvsn: String,
+    /// 펀드결제분포함여부 (String, 필수)
+    #[serde(rename = "FUND_STTL_ICLD_YN")]
+    pub fund_sttl_icld_yn: String,
+    /// 융자금액자동상환여부 (String, 필수)
+    #[serde(rename = "FNCG_AMT_AUTO_RDPT_YN")]
+    pub fncg_amt_auto_rdpt_yn: String,
+    /// 처리구분 (String, 필수)
+    #[serde(rename = "PRCS_DVSN")]
+    pub prcs_dvsn: String,
+    /// 연속조회검색조건100 (String, 선택)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 선택)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [매수가능조회[v1_국내주식-007]] 요청 구조체
+/// [국내주식-007 v1] 매수가능조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// CMA평가금액포함여부 (String, 필수)
+    #[serde(rename = "CMA_EVLU_AMT_ICLD_YN")]
+    pub cma_evlu_amt_icld_yn: String,
+    /// 해외포함여부 (String, 필수)
+    #[serde(rename = "OVRS_ICLD_YN")]
+    pub ovrs_icld_yn: String,
+}
+
+/// [매도가능수량조회 [국내주식-165]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblSellRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종목번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+}
+
+/// [신용매수가능조회[v1_국내주식-042]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireCreditPsamountRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 신용유형 (String, 필수)
+    #[serde(rename = "CRDT_TYPE")]
+    pub crdt_type: String,
+    /// CMA평가금액포함여부 (String, 필수)
+    #[serde(rename = "CMA_EVLU_AMT_ICLD_YN")]
+    pub cma_evlu_amt_icld_yn: String,
+    /// 해외포함여부 (String, 필수)
+    #[serde(rename = "OVRS_ICLD_YN")]
+    pub ovrs_icld_yn: String,
+}
+
+/// [주식예약주문[v1_국내주식-017]] 요청 구조체
+/// [국내주식-017 v1] 주식예약주문
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종목코드(6자리) (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문구분코드 (String, 필수)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+    /// 주문대상잔고구분코드 (String, 필수)
+    #[serde(rename = "ORD_OBJT_CBLC_DVSN_CD")]
+    pub ord_objt_cblc_dvsn_cd: String,
+    /// 대출일자 (String, 선택)
+    #[serde(rename = "LOAN_DT")]
+    pub loan_dt: String,
+    /// 예약주문종료일자 (String, 선택)
+    #[serde(rename = "RSVN_ORD_END_DT")]
+    pub rsvn_ord_end_dt: String,
+    /// 대여일자 (String, 선택)
+    #[serde(rename = "LDNG_DT")]
+    pub ldng_dt: String,
+}
+
+/// [주식예약주문정정취소[v1_국내주식-018,019]] 요청 구조체
+/// [국내주식-018,019 v1] 주식예약주문정정취소
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종목코드(6자리) (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문구분코드 (String, 필수)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+    /// 주문대상잔고구분코드 (String, 필수)
+    #[serde(rename = "ORD_OBJT_CBLC_DVSN_CD")]
+    pub ord_objt_cblc_dvsn_cd: String,
+    /// 대출일자 (String, 선택)
+    #[serde(rename = "LOAN_DT")]
+    pub loan_dt: String,
+    /// 예약주문종료일자 (String, 선택)
+    #[serde(rename = "RSVN_ORD_END_DT")]
+    pub rsvn_ord_end_dt: String,
+    /// 연락전화번호 (String, 선택)
+    #[serde(rename = "CTAL_TLNO")]
+    pub ctal_tlno: String,
+    /// 예약주문순번 (String, 필수)
+    #[serde(rename = "RSVN_ORD_SEQ")]
+    pub rsvn_ord_seq: String,
+    /// 예약주문조직번호 (String, 선택)
+    #[serde(rename = "RSVN_ORD_ORGNO")]
+    pub rsvn_ord_orgno: String,
+    /// 예약주문주문일자 (String, 선택)
+    #[serde(rename = "RSVN_ORD_ORD_DT")]
+    pub rsvn_ord_ord_dt: String,
+}
+
+/// [주식예약주문조회[v1_국내주식-020]] 요청 구조체
+/// [국내주식-020 v1] 주식예약주문조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvCcnlRequest {
+    /// 예약주문시작일자 (String, 필수)
+    #[serde(rename = "RSVN_ORD_ORD_DT")]
+    pub rsvn_ord_ord_dt: String,
+    /// 예약주문종료일자 (String, 필수)
+    #[serde(rename = "RSVN_ORD_END_DT")]
+    pub rsvn_ord_end_dt: String,
+    /// 예약주문순번 (String, 필수)
+    #[serde(rename = "RSVN_ORD_SEQ")]
+    pub rsvn_ord_seq: String,
+    /// 단말매체종류코드 (String, 필수)
+    #[serde(rename = "TMNL_MDIA_KIND_CD")]
+    pub tmnl_mdia_kind_cd: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 처리구분코드 (String, 필수)
+    #[serde(rename = "PRCS_DVSN_CD")]
+    pub prcs_dvsn_cd: String,
+    /// 취소여부 (String, 필수)
+    #[serde(rename = "CNCL_YN")]
+    pub cncl_yn: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [퇴직연금 체결기준잔고[v1_국내주식-032]] 요청 구조체
+/// 퇴직연금 체결기준잔고[v1_국내주식-032]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquirePresentBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 사용자구분코드 (String, 필수)
+    #[serde(rename = "USER_DVSN_CD")]
+    pub user_dvsn_cd: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 처리구분코드 (String, 선택)
+    #[serde(rename = "PRCS_DVSN_CD")]
+    pub prcs_dvsn_cd: String,
+}
+
+/// [퇴직연금 미체결내역[v1_국내주식-033]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireDailyCcldRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 사용자구분코드 (String, 필수)
+    #[serde(rename = "USER_DVSN_CD")]
+    pub user_dvsn_cd: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 조회구분3 (String, 필수)
+    #[serde(rename = "INQR_DVSN_3")]
+    pub inqr_dvsn_3: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [퇴직연금 매수가능조회[v1_국내주식-034]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquirePsblOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 적립금구분코드 (String, 필수)
+    #[serde(rename = "ACCA_DVSN_CD")]
+    pub acca_dvsn_cd: String,
+    /// CMA평가금액포함여부 (String, 필수)
+    #[serde(rename = "CMA_EVLU_AMT_ICLD_YN")]
+    pub cma_evlu_amt_icld_yn: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 주문단가 (String, 필수)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+}
+
+/// [퇴직연금 예수금조회[v1_국내주식-035]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireDepositRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 적립금구분코드 (String, 필수)
+    #[serde(rename = "ACCA_DVSN_CD")]
+    pub acca_dvsn_cd: String,
+}
+
+/// [퇴직연금 잔고조회[v1_국내주식-036]] 요청 구조체
+/// 퇴직연금 잔고조회[v1_국내주식-036]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 적립금구분코드 (String, 필수)
+    #[serde(rename = "ACCA_DVSN_CD")]
+    pub acca_dvsn_cd: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [주식잔고조회_실현손익[v1_국내주식-041]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceRlzPlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시간외단일가여부 (String, 필수)
+    #[serde(rename = "AFHR_FLPR_YN")]
+    pub afhr_flpr_yn: String,
+    /// 오프라인여부 (String, 필수)
+    #[serde(rename = "OFL_YN")]
+    pub ofl_yn: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 단가구분 (String, 필수)
+    #[serde(rename = "UNPR_DVSN")]
+    pub unpr_dvsn: String,
+    /// 펀드결제포함여부 (String, 필수)
+    #[serde(rename = "FUND_STTL_ICLD_YN")]
+    pub fund_sttl_icld_yn: String,
+    /// 융자금액자동상환여부 (String, 필수)
+    #[serde(rename = "FNCG_AMT_AUTO_RDPT_YN")]
+    pub fncg_amt_auto_rdpt_yn: String,
+    /// PRCS_DVSN (String, 필수)
+    #[serde(rename = "PRCS_DVSN")]
+    pub prcs_dvsn: String,
+    /// 비용포함여부 (String, 필수)
+    #[serde(rename = "COST_ICLD_YN")]
+    pub cost_icld_yn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [투자계좌자산현황조회[v1_국내주식-048]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireAccountBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회구분1 (String, 필수)
+    #[serde(rename = "INQR_DVSN_1")]
+    pub inqr_dvsn_1: String,
+    /// 기준가이전일자적용여부 (String, 필수)
+    #[serde(rename = "BSPR_BF_DT_APLY_YN")]
+    pub bspr_bf_dt_aply_yn: String,
+}
+
+/// [기간별손익일별합산조회[v1_국내주식-052]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodProfitRequest {
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 정렬구분 (String, 필수)
+    #[serde(rename = "SORT_DVSN")]
+    pub sort_dvsn: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 잔고구분 (String, 필수)
+    #[serde(rename = "CBLC_DVSN")]
+    pub cblc_dvsn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+}
+
+/// [기간별매매손익현황조회[v1_국내주식-060]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodTradeProfitRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 정렬구분 (String, 필수)
+    #[serde(rename = "SORT_DVSN")]
+    pub sort_dvsn: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 잔고구분 (String, 필수)
+    #[serde(rename = "CBLC_DVSN")]
+    pub cblc_dvsn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+}
+
+/// [주식통합증거금 현황 [국내주식-191]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingIntgrMarginRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// CMA평가금액포함여부 (String, 필수)
+    #[serde(rename = "CMA_EVLU_AMT_ICLD_YN")]
+    pub cma_evlu_amt_icld_yn: String,
+    /// 원화외화구분코드 (String, 필수)
+    #[serde(rename = "WCRC_FRCR_DVSN_CD")]
+    pub wcrc_frcr_dvsn_cd: String,
+    /// 선도환계약외화구분코드 (String, 필수)
+    #[serde(rename = "FWEX_CTRT_FRCR_DVSN_CD")]
+    pub fwex_ctrt_frcr_dvsn_cd: String,
+}
+
+/// [기간별계좌권리현황조회 [국내주식-211]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPeriodRightsRequest {
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 고객실명확인번호25 (String, 필수)
+    #[serde(rename = "CUST_RNCNO25")]
+    pub cust_rncno25: String,
+    /// 홈넷ID (String, 필수)
+    #[serde(rename = "HMID")]
+    pub hmid: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 권리유형코드 (String, 필수)
+    #[serde(rename = "RGHT_TYPE_CD")]
+    pub rght_type_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+}
+
+/// [주식현재가 시세[v1_국내주식-008]] 요청 구조체
+/// [국내주식-008 v1] 주식현재가 시세
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquirePriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 시세2[v1_국내주식-054]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquirePrice2Request {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 체결[v1_국내주식-009]] 요청 구조체
+/// [국내주식-009 v1] 주식현재가 체결
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireCcnlRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 일자별[v1_국내주식-010]] 요청 구조체
+/// [국내주식-010 v1] 주식현재가 일자별
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyPriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 기간 분류 코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+    /// 수정주가 원주가 가격 (String, 필수)
+    #[serde(rename = "FID_ORG_ADJ_PRC")]
+    pub fid_org_adj_prc: String,
+}
+
+/// [주식현재가 호가/예상체결[v1_국내주식-011]] 요청 구조체
+/// [국내주식-011 v1] 주식현재가 호가 예상체결
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireAskingPriceExpCcnRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 투자자[v1_국내주식-012]] 요청 구조체
+/// [국내주식-012 v1] 주식현재가 투자자
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 회원사[v1_국내주식-013]] 요청 구조체
+/// [국내주식-013 v1] 주식현재가 회원사
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireMemberRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식기간별시세(일/주/월/년)[v1_국내주식-016]] 요청 구조체
+/// [국내주식-016 v1] 국내주식기간별시세(일/주/월/년)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyItemchartpriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 날짜 1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력 날짜 2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 기간분류코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+    /// 수정주가 원주가 가격 여부 (String, 필수)
+    #[serde(rename = "FID_ORG_ADJ_PRC")]
+    pub fid_org_adj_prc: String,
+}
+
+/// [주식당일분봉조회[v1_국내주식-022]] 요청 구조체
+/// 주식당일분봉조회[v1_국내주식-022]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemchartpriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 과거 데이터 포함 여부  (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+    /// 기타 구분 코드 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+}
+
+/// [주식일별분봉조회 [국내주식-213]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeDailychartpriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 과거 데이터 포함 여부  (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+    /// 허봉 포함 여부 (String, 선택)
+    #[serde(rename = "FID_FAKE_TICK_INCU_YN")]
+    pub fid_fake_tick_incu_yn: String,
+}
+
+/// [주식현재가 당일시간대별체결[v1_국내주식-023]] 요청 구조체
+/// 주식현재가 당일시간대별체결[v1_국내주식-023]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemconclusionRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+}
+
+/// [주식현재가 시간외일자별주가[v1_국내주식-026]] 요청 구조체
+/// 주식현재가 시간외일자별주가[v1_국내주식-026]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyOvertimepriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [주식현재가 시간외시간별체결[v1_국내주식-025]] 요청 구조체
+/// 주식현재가 시간외시간별체결[v1_국내주식-025]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeOvertimeconclusionRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시간 구분 코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+}
+
+/// [국내주식 시간외현재가[국내주식-076]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireOvertimePriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식 시간외호가[국내주식-077]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireOvertimeAskingPriceRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 장마감 예상체결가[국내주식-120]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpClosingPriceRequest {
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+}
+
+/// [ELW 현재가 시세[v1_국내주식-014]] 요청 구조체
+/// ELW 현재가 시세
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireElwPriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내업종 현재지수[v1_국내주식-063]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexPriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내업종 일자별지수[v1_국내주식-065]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexDailyPriceRequest {
+    /// FID 기간 분류 코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+}
+
+/// [국내업종 시간별지수(초)[국내주식-064]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexTickpriceRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내업종 시간별지수(분)[국내주식-119]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexTimepriceRequest {
+    /// ?입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [업종 분봉조회[v1_국내주식-045]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeIndexchartpriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 기타 구분 코드 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// FID 과거 데이터 포함 여부 (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+}
+
+/// [국내주식업종기간별시세(일/주/월/년)[v1_국내주식-021]] 요청 구조체
+/// [국내주식-021 v1] 업종기간별시세(일/주/월/년)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyIndexchartpriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 업종 상세코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조회 시작일자 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 조회 종료일자 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// ' 기간분류코드' (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+}
+
+/// [국내업종 구분별전체시세[v1_국내주식-066]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexCategoryPriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// FID 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// FID 소속 구분 코드 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+}
+
+/// [국내주식 예상체결지수 추이[국내주식-121]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpIndexTrendRequest {
+    /// 장운영 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MKOP_CLS_CODE")]
+    pub fid_mkop_cls_code: String,
+    /// 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 예상체결 전체지수[국내주식-122]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpTotalIndexRequest {
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "fid_mrkt_cls_code")]
+    pub fid_mrkt_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 장운영 구분 코드 (String, 필수)
+    #[serde(rename = "fid_mkop_cls_code")]
+    pub fid_mkop_cls_code: String,
+}
+
+/// [변동성완화장치(VI) 현황 [v1_국내주식-055]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireViStatusRequest {
+    /// FID 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// FID 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// FID 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// FID 대상 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// FID 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [금리 종합(국내채권/금리) [국내주식-155]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompInterestRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE1")]
+    pub fid_div_cls_code1: String,
+}
+
+/// [종합 시황/공시(제목) [국내주식-141]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsNewsTitleRequest {
+    /// 뉴스 제공 업체 코드 (String, 필수)
+    #[serde(rename = "FID_NEWS_OFER_ENTP_CODE")]
+    pub fid_news_ofer_entp_code: String,
+    /// 조건 시장 구분 코드  (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 제목 내용 (String, 필수)
+    #[serde(rename = "FID_TITL_CNTT")]
+    pub fid_titl_cntt: String,
+    /// 입력 날짜 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력 시간 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력 일련번호 (String, 필수)
+    #[serde(rename = "FID_INPUT_SRNO")]
+    pub fid_input_srno: String,
+}
+
+/// [국내휴장일조회[국내주식-040]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsChkHolidayRequest {
+    /// 기준일자 (String, 필수)
+    #[serde(rename = "BASS_DT")]
+    pub bass_dt: String,
+    /// 연속조회키 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK")]
+    pub ctx_area_nk: String,
+    /// 연속조회검색조건 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK")]
+    pub ctx_area_fk: String,
+}
+
+/// [상품기본조회[v1_국내주식-029]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsSearchInfoRequest {
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+}
+
+/// [주식기본조회[v1_국내주식-067]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsSearchStockInfoRequest {
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+}
+
+/// [국내주식 대차대조표[v1_국내주식-078]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceBalanceSheetRequest {
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식 손익계산서[v1_국내주식-079]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceIncomeStatementRequest {
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식 재무비율[v1_국내주식-080]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceFinancialRatioRequest {
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식 수익성비율[v1_국내주식-081]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceProfitRatioRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 기타주요비율[v1_국내주식-082]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceOtherMajorRatiosRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 안정성비율[v1_국내주식-083]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceStabilityRatioRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 성장성비율[v1_국내주식-085]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceGrowthRatioRequest {
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [국내주식 당사 신용가능종목[국내주식-111]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCreditByCompanyRequest {
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 선택 여부 (String, 필수)
+    #[serde(rename = "fid_slct_yn")]
+    pub fid_slct_yn: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [예탁원정보(배당일정)[국내주식-145]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoDividendRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "GB1")]
+    pub gb1: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// 고배당여부 (String, 필수)
+    #[serde(rename = "HIGH_GB")]
+    pub high_gb: String,
+}
+
+/// [예탁원정보(주식매수청구일정)[국내주식-146]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPurreqRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [예탁원정보(합병/분할일정)[국내주식-147]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoMergerSplitRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [예탁원정보(액면교체일정)[국내주식-148]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoRevSplitRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 시장구분 (String, 필수)
+    #[serde(rename = "MARKET_GB")]
+    pub market_gb: String,
+}
+
+/// [예탁원정보(자본감소일정)[국내주식-149]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoCapDcrsRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [예탁원정보(상장정보일정)[국내주식-150]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoListInfoRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [예탁원정보(공모주청약일정)[국내주식-151]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPubOfferRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+}
+
+/// [예탁원정보(실권주일정)[국내주식-152]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoForfeitRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [예탁원정보(의무예치일정)[국내주식-153]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoMandDepositRequest {
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [예탁원정보(유상증자일정) [국내주식-143]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPaidinCapinRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "GB1")]
+    pub gb1: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [예탁원정보(무상증자일정) [국내주식-144]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoBonusIssueRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [예탁원정보(주주총회일정) [국내주식-154]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoSharehldMeetRequest {
+    /// CTS (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+    /// 조회일자From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 조회일자To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsEstimatePerformRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SHT_CD")]
+    pub sht_cd: String,
+}
+
+/// [당사 대주가능 종목 [국내주식-195]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsLendableByCompanyRequest {
+    /// 거래소구분코드 (String, 필수)
+    #[serde(rename = "EXCG_DVSN_CD")]
+    pub excg_dvsn_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 당사대주가능여부 (String, 필수)
+    #[serde(rename = "THCO_STLN_PSBL_YN")]
+    pub thco_stln_psbl_yn: String,
+    /// 조회구분1 (String, 필수)
+    #[serde(rename = "INQR_DVSN_1")]
+    pub inqr_dvsn_1: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [국내주식 종목투자의견 [국내주식-188]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestOpinionRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [국내주식 증권사별 투자의견 [국내주식-189]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestOpbysecRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [종목조건검색 목록조회[국내주식-038]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPsearchTitleRequest {
+    /// 사용자 HTS ID (String, 필수)
+    #[serde(rename = "user_id")]
+    pub user_id: String,
+}
+
+/// [종목조건검색조회 [국내주식-039]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPsearchResultRequest {
+    /// 사용자 HTS ID (String, 필수)
+    #[serde(rename = "user_id")]
+    pub user_id: String,
+    /// 사용자조건 키값 (String, 필수)
+    #[serde(rename = "seq")]
+    pub seq: String,
+}
+
+/// [관심종목 그룹조회 [국내주식-204]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockGrouplistRequest {
+    /// 관심종목구분코드                 (String, 필수)
+    #[serde(rename = "TYPE")]
+    pub r#type: String,
+    /// FID 기타 구분 코드  (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+    /// 사용자 ID                 (String, 필수)
+    #[serde(rename = "USER_ID")]
+    pub user_id: String,
+}
+
+/// [관심종목(멀티종목) 시세조회 [국내주식-205]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockMultpriceRequest {
+    /// 조건 시장 분류 코드1 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_1")]
+    pub fid_cond_mrkt_div_code_1: String,
+    /// 입력 종목코드1 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_1")]
+    pub fid_input_iscd_1: String,
+    /// 조건 시장 분류 코드2 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_2")]
+    pub fid_cond_mrkt_div_code_2: String,
+    /// 입력 종목코드2 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 조건 시장 분류 코드3 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_3")]
+    pub fid_cond_mrkt_div_code_3: String,
+    /// 입력 종목코드3 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_3")]
+    pub fid_input_iscd_3: String,
+    /// 조건 시장 분류 코드4 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_4")]
+    pub fid_cond_mrkt_div_code_4: String,
+    /// 입력 종목코드4 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_4")]
+    pub fid_input_iscd_4: String,
+    /// 조건 시장 분류 코드5 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_5")]
+    pub fid_cond_mrkt_div_code_5: String,
+    /// 입력 종목코드5 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_5")]
+    pub fid_input_iscd_5: String,
+    /// 조건 시장 분류 코드6 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_6")]
+    pub fid_cond_mrkt_div_code_6: String,
+    /// 입력 종목코드6 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_6")]
+    pub fid_input_iscd_6: String,
+    /// 조건 시장 분류 코드7 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_7")]
+    pub fid_cond_mrkt_div_code_7: String,
+    /// 입력 종목코드7 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_7")]
+    pub fid_input_iscd_7: String,
+    /// 조건 시장 분류 코드8 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_8")]
+    pub fid_cond_mrkt_div_code_8: String,
+    /// 입력 종목코드8 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_8")]
+    pub fid_input_iscd_8: String,
+    /// 조건 시장 분류 코드9 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_9")]
+    pub fid_cond_mrkt_div_code_9: String,
+    /// 입력 종목코드9 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_9")]
+    pub fid_input_iscd_9: String,
+    /// 조건 시장 분류 코드10 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_10")]
+    pub fid_cond_mrkt_div_code_10: String,
+    /// 입력 종목코드10 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_10")]
+    pub fid_input_iscd_10: String,
+    /// 조건 시장 분류 코드11 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_11")]
+    pub fid_cond_mrkt_div_code_11: String,
+    /// 입력 종목코드11 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_11")]
+    pub fid_input_iscd_11: String,
+    /// 조건 시장 분류 코드12 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_12")]
+    pub fid_cond_mrkt_div_code_12: String,
+    /// 입력 종목코드12 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_12")]
+    pub fid_input_iscd_12: String,
+    /// 조건 시장 분류 코드13 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_13")]
+    pub fid_cond_mrkt_div_code_13: String,
+    /// 입력 종목코드13 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_13")]
+    pub fid_input_iscd_13: String,
+    /// 조건 시장 분류 코드14 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_14")]
+    pub fid_cond_mrkt_div_code_14: String,
+    /// 입력 종목코드14 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_14")]
+    pub fid_input_iscd_14: String,
+    /// 조건 시장 분류 코드15 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_15")]
+    pub fid_cond_mrkt_div_code_15: String,
+    /// 입력 종목코드15 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_15")]
+    pub fid_input_iscd_15: String,
+    /// 조건 시장 분류 코드16 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_16")]
+    pub fid_cond_mrkt_div_code_16: String,
+    /// 입력 종목코드16 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_16")]
+    pub fid_input_iscd_16: String,
+    /// 조건 시장 분류 코드17 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_17")]
+    pub fid_cond_mrkt_div_code_17: String,
+    /// 입력 종목코드17 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_17")]
+    pub fid_input_iscd_17: String,
+    /// 조건 시장 분류 코드18 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_18")]
+    pub fid_cond_mrkt_div_code_18: String,
+    ///  입력 종목코드18 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_18")]
+    pub fid_input_iscd_18: String,
+    /// 조건 시장 분류 코드19 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_19")]
+    pub fid_cond_mrkt_div_code_19: String,
+    /// 입력 종목코드19 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_19")]
+    pub fid_input_iscd_19: String,
+    /// 조건 시장 분류 코드20 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_20")]
+    pub fid_cond_mrkt_div_code_20: String,
+    /// 입력 종목코드20 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_20")]
+    pub fid_input_iscd_20: String,
+    /// 조건 시장 분류 코드21 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_21")]
+    pub fid_cond_mrkt_div_code_21: String,
+    /// 입력 종목코드21 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_21")]
+    pub fid_input_iscd_21: String,
+    /// 조건 시장 분류 코드22 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_22")]
+    pub fid_cond_mrkt_div_code_22: String,
+    /// 입력 종목코드22 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_22")]
+    pub fid_input_iscd_22: String,
+    /// 조건 시장 분류 코드23 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_23")]
+    pub fid_cond_mrkt_div_code_23: String,
+    /// 입력 종목코드23 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_23")]
+    pub fid_input_iscd_23: String,
+    /// 조건 시장 분류 코드24 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_24")]
+    pub fid_cond_mrkt_div_code_24: String,
+    /// 입력 종목코드24 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_24")]
+    pub fid_input_iscd_24: String,
+    /// 조건 시장 분류 코드25 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_25")]
+    pub fid_cond_mrkt_div_code_25: String,
+    /// 입력 종목코드25 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_25")]
+    pub fid_input_iscd_25: String,
+    /// 조건 시장 분류 코드26 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_26")]
+    pub fid_cond_mrkt_div_code_26: String,
+    /// 입력 종목코드26 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_26")]
+    pub fid_input_iscd_26: String,
+    /// 조건 시장 분류 코드27 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_27")]
+    pub fid_cond_mrkt_div_code_27: String,
+    /// 입력 종목코드27 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_27")]
+    pub fid_input_iscd_27: String,
+    /// 조건 시장 분류 코드28 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_28")]
+    pub fid_cond_mrkt_div_code_28: String,
+    /// 입력 종목코드28 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_28")]
+    pub fid_input_iscd_28: String,
+    /// 조건 시장 분류 코드29 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_29")]
+    pub fid_cond_mrkt_div_code_29: String,
+    /// 입력 종목코드29 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_29")]
+    pub fid_input_iscd_29: String,
+    /// 조건 시장 분류 코드30 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE_30")]
+    pub fid_cond_mrkt_div_code_30: String,
+    /// 입력 종목코드30 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_30")]
+    pub fid_input_iscd_30: String,
+}
+
+/// [관심종목 그룹별 종목조회 [국내주식-203]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockStocklistByGroupRequest {
+    /// 관심종목구분코드                 (String, 필수)
+    #[serde(rename = "TYPE")]
+    pub r#type: String,
+    /// 사용자 ID                 (String, 필수)
+    #[serde(rename = "USER_ID")]
+    pub user_id: String,
+    /// 데이터 순위            (String, 필수)
+    #[serde(rename = "DATA_RANK")]
+    pub data_rank: String,
+    /// 관심 그룹 코드        (String, 필수)
+    #[serde(rename = "INTER_GRP_CODE")]
+    pub inter_grp_code: String,
+    /// 관심 그룹 명       (String, 필수)
+    #[serde(rename = "INTER_GRP_NAME")]
+    pub inter_grp_name: String,
+    /// HTS 한글 종목명      (String, 필수)
+    #[serde(rename = "HTS_KOR_ISNM")]
+    pub hts_kor_isnm: String,
+    /// 체결 구분 코드          (String, 필수)
+    #[serde(rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 기타 구분 코드  (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+}
+
+/// [국내기관_외국인 매매종목가집계[국내주식-037]] 요청 구조체
+/// 국내기관_외국인 매매종목가집계[국내주식-037]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsForeignInstitutionTotalRequest {
+    /// 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 기타 구분  정렬 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+}
+
+/// [외국계 매매종목 가집계 [국내주식-161]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeEstimateRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 순위정렬구분코드2 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE_2")]
+    pub fid_rank_sort_cls_code_2: String,
+}
+
+/// [종목별 투자자매매동향(일별)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorTradeByStockDailyRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 수정주가 원주가 가격 (String, 필수)
+    #[serde(rename = "FID_ORG_ADJ_PRC")]
+    pub fid_org_adj_prc: String,
+    /// 기타 구분 코드 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+}
+
+/// [시장별 투자자매매동향(시세)[v1_국내주식-074]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorTimeByMarketRequest {
+    /// 시장구분 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 업종구분 (String, 필수)
+    #[serde(rename = "fid_input_iscd_2")]
+    pub fid_input_iscd_2: String,
+}
+
+/// [시장별 투자자매매동향(일별) [국내주식-075]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorDailyByMarketRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_1")]
+    pub fid_input_iscd_1: String,
+    /// 입력 날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 하위 분류코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+}
+
+/// [종목별 외국계 순매수추이 [국내주식-164]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemPchsTrendRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 시장구분코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [회원사 실시간 매매동향(틱) [국내주식-163]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeTrendRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 회원사코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 시장구분코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 거래량 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+}
+
+/// [주식현재가 회원사 종목매매동향 [국내주식-197]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireMemberDailyRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 회원사코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 구간구분코드 (String, 필수)
+    #[serde(rename = "FID_SCTN_CLS_CODE")]
+    pub fid_sctn_cls_code: String,
+}
+
+/// [종목별 프로그램매매추이(체결)[v1_국내주식-044]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [종목별 프로그램매매추이(일별) [국내주식-113]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockDailyRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+}
+
+/// [종목별 외인기관 추정가집계[v1_국내주식-046]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorTrendEstimateRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+}
+
+/// [종목별일별매수매도체결량 [v1_국내주식-056]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyTradeVolumeRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// FID 입력 날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// FID 기간 분류 코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+}
+
+/// [프로그램매매 종합현황(시간) [국내주식-114]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompProgramTradeTodayRequest {
+    /// 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 구간 구분 코드 (String, 필수)
+    #[serde(rename = "FID_SCTN_CLS_CODE")]
+    pub fid_sctn_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시장 분류코드1 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE1")]
+    pub fid_cond_mrkt_div_code1: String,
+    /// 입력 시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+}
+
+/// [프로그램매매 종합현황(일별)[국내주식-115]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompProgramTradeDailyRequest {
+    /// 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 검색시작일 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 검색종료일 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [프로그램매매 투자자매매동향(당일) [국내주식-116]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorProgramTradeTodayRequest {
+    /// 거래소 구분 코드 (String, 필수)
+    #[serde(rename = "EXCH_DIV_CLS_CODE")]
+    pub exch_div_cls_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "MRKT_DIV_CLS_CODE")]
+    pub mrkt_div_cls_code: String,
+}
+
+/// [국내주식 신용잔고 일별추이[국내주식-110]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyCreditBalanceRequest {
+    /// 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 결제일자 (String, 필수)
+    #[serde(rename = "fid_input_date_1")]
+    pub fid_input_date_1: String,
+}
+
+/// [국내주식 예상체결가 추이[국내주식-118]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpPriceTrendRequest {
+    /// 장운영 구분 코드 (String, 필수)
+    #[serde(rename = "fid_mkop_cls_code")]
+    pub fid_mkop_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내주식 공매도 일별추이[국내주식-134]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyShortSaleRequest {
+    /// 입력 날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+}
+
+/// [국내주식 시간외예상체결등락률 [국내주식-140]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeExpTransFluctRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 입력 거래량 (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+}
+
+/// [국내주식 체결금액별 매매비중 [국내주식-192]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsTradprtByamtRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [국내 증시자금 종합 [국내주식-193]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsMktfundsRequest {
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+}
+
+/// [종목별 일별 대차거래추이 [국내주식-135]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyLoanTransRequest {
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "MRKT_DIV_CLS_CODE")]
+    pub mrkt_div_cls_code: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE")]
+    pub start_date: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "END_DATE")]
+    pub end_date: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [국내주식 상하한가 포착 [국내주식-190]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCaptureUplowpriceRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 상하한가 구분코드 (String, 필수)
+    #[serde(rename = "FID_PRC_CLS_CODE")]
+    pub fid_prc_cls_code: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 대상구분코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// 대상제외구분코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량수 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+}
+
+/// [국내주식 매물대/거래비중 [국내주식-196]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPbarTratioRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+}
+
+/// [거래량순위[v1_국내주식-047]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsVolumeRankRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+}
+
+/// [국내주식 등락률 순위[v1_국내주식-088]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingFluctuationRequest {
+    /// 등락 비율2 (String, 필수)
+    #[serde(rename = "fid_rsfl_rate2")]
+    pub fid_rsfl_rate2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력 수1 (String, 필수)
+    #[serde(rename = "fid_input_cnt_1")]
+    pub fid_input_cnt_1: String,
+    /// 가격 구분 코드 (String, 필수)
+    #[serde(rename = "fid_prc_cls_code")]
+    pub fid_prc_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 등락 비율1 (String, 필수)
+    #[serde(rename = "fid_rsfl_rate1")]
+    pub fid_rsfl_rate1: String,
+}
+
+/// [국내주식 호가잔량 순위[국내주식-089]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingQuoteBalanceRequest {
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+}
+
+/// [국내주식 수익자산지표 순위[v1_국내주식-090]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingProfitAssetIndexRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 입력 옵션1 (String, 필수)
+    #[serde(rename = "fid_input_option_1")]
+    pub fid_input_option_1: String,
+    /// 입력 옵션2 (String, 필수)
+    #[serde(rename = "fid_input_option_2")]
+    pub fid_input_option_2: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "fid_blng_cls_code")]
+    pub fid_blng_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [국내주식 시가총액 상위[v1_국내주식-091]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingMarketCapRequest {
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+}
+
+/// [국내주식 재무비율 순위[v1_국내주식-092]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingFinanceRatioRequest {
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 입력 옵션1 (String, 필수)
+    #[serde(rename = "fid_input_option_1")]
+    pub fid_input_option_1: String,
+    /// 입력 옵션2 (String, 필수)
+    #[serde(rename = "fid_input_option_2")]
+    pub fid_input_option_2: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "fid_blng_cls_code")]
+    pub fid_blng_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [국내주식 시간외잔량 순위[v1_국내주식-093]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingAfterHourBalanceRequest {
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+}
+
+/// [국내주식 우선주/괴리율 상위[v1_국내주식-094]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingPreferDisparateRatioRequest {
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+}
+
+/// [국내주식 이격도 순위[v1_국내주식-095]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingDisparityRequest {
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 시간 구분 코드 (String, 필수)
+    #[serde(rename = "fid_hour_cls_code")]
+    pub fid_hour_cls_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+}
+
+/// [국내주식 시장가치 순위[v1_국내주식-096]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingMarketValueRequest {
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 입력 옵션1 (String, 필수)
+    #[serde(rename = "fid_input_option_1")]
+    pub fid_input_option_1: String,
+    /// 입력 옵션2 (String, 필수)
+    #[serde(rename = "fid_input_option_2")]
+    pub fid_input_option_2: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "fid_blng_cls_code")]
+    pub fid_blng_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [국내주식 체결강도 상위[v1_국내주식-101]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingVolumePowerRequest {
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+}
+
+/// [국내주식 관심종목등록 상위[v1_국내주식-102]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingTopInterestStockRequest {
+    /// 입력 필수값2 (String, 필수)
+    #[serde(rename = "fid_input_iscd_2")]
+    pub fid_input_iscd_2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 업종 코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "fid_input_price_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 순위 입력값 (String, 필수)
+    #[serde(rename = "fid_input_cnt_1")]
+    pub fid_input_cnt_1: String,
+}
+
+/// [국내주식 예상체결 상승/하락상위[v1_국내주식-103]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingExpTransUpdownRequest {
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 적용 범위 가격1 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_1")]
+    pub fid_aply_rang_prc_1: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+    /// 거래대금 (String, 필수)
+    #[serde(rename = "fid_pbmn")]
+    pub fid_pbmn: String,
+    /// 소속 구분 코드 (String, 필수)
+    #[serde(rename = "fid_blng_cls_code")]
+    pub fid_blng_cls_code: String,
+    /// 장운영 구분 코드 (String, 필수)
+    #[serde(rename = "fid_mkop_cls_code")]
+    pub fid_mkop_cls_code: String,
+}
+
+/// [국내주식 당사매매종목 상위[v1_국내주식-104]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingTradedByCompanyRequest {
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력 날짜1 (String, 필수)
+    #[serde(rename = "fid_input_date_1")]
+    pub fid_input_date_1: String,
+    /// 입력 날짜2 (String, 필수)
+    #[serde(rename = "fid_input_date_2")]
+    pub fid_input_date_2: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 적용 범위 거래량 (String, 필수)
+    #[serde(rename = "fid_aply_rang_vol")]
+    pub fid_aply_rang_vol: Decimal,
+    /// 적용 범위 가격2 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_2")]
+    pub fid_aply_rang_prc_2: String,
+    /// 적용 범위 가격1 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_1")]
+    pub fid_aply_rang_prc_1: String,
+}
+
+/// [국내주식 신고/신저근접종목 상위[v1_국내주식-105]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingNearNewHighlowRequest {
+    /// 적용 범위 거래량 (String, 필수)
+    #[serde(rename = "fid_aply_rang_vol")]
+    pub fid_aply_rang_vol: Decimal,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 수1 (String, 필수)
+    #[serde(rename = "fid_input_cnt_1")]
+    pub fid_input_cnt_1: String,
+    /// 입력 수2 (String, 필수)
+    #[serde(rename = "fid_input_cnt_2")]
+    pub fid_input_cnt_2: String,
+    /// 가격 구분 코드 (String, 필수)
+    #[serde(rename = "fid_prc_cls_code")]
+    pub fid_prc_cls_code: String,
+    ///  입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 적용 범위 가격1 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_1")]
+    pub fid_aply_rang_prc_1: String,
+    /// 적용 범위 가격2 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_2")]
+    pub fid_aply_rang_prc_2: String,
+}
+
+/// [국내주식 배당률 상위[국내주식-106]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingDividendRateRequest {
+    /// CTS_AREA (String, 필수)
+    #[serde(rename = "CTS_AREA")]
+    pub cts_area: String,
+    /// KOSPI (String, 필수)
+    #[serde(rename = "GB1")]
+    pub gb1: String,
+    /// 업종구분 (String, 필수)
+    #[serde(rename = "UPJONG")]
+    pub upjong: String,
+    /// 종목선택 (String, 필수)
+    #[serde(rename = "GB2")]
+    pub gb2: String,
+    /// 배당구분 (String, 필수)
+    #[serde(rename = "GB3")]
+    pub gb3: String,
+    /// 기준일From (String, 필수)
+    #[serde(rename = "F_DT")]
+    pub f_dt: String,
+    /// 기준일To (String, 필수)
+    #[serde(rename = "T_DT")]
+    pub t_dt: String,
+    /// 결산/중간배당 (String, 필수)
+    #[serde(rename = "GB4")]
+    pub gb4: String,
+}
+
+/// [국내주식 대량체결건수 상위[국내주식-107]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingBulkTransNumRequest {
+    /// 적용 범위 가격2 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_2")]
+    pub fid_aply_rang_prc_2: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_scr_div_code")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "fid_rank_sort_cls_code")]
+    pub fid_rank_sort_cls_code: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "fid_div_cls_code")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "fid_input_price_1")]
+    pub fid_input_price_1: String,
+    /// 적용 범위 가격1 (String, 필수)
+    #[serde(rename = "fid_aply_rang_prc_1")]
+    pub fid_aply_rang_prc_1: String,
+    /// 입력 종목코드2 (String, 필수)
+    #[serde(rename = "fid_input_iscd_2")]
+    pub fid_input_iscd_2: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_exls_cls_code")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "fid_trgt_cls_code")]
+    pub fid_trgt_cls_code: String,
+    ///  거래량 수 (String, 필수)
+    #[serde(rename = "fid_vol_cnt")]
+    pub fid_vol_cnt: i64,
+}
+
+/// [국내주식 신용잔고 상위[국내주식-109]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingCreditBalanceRequest {
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 증가율기간 (String, 필수)
+    #[serde(rename = "FID_OPTION")]
+    pub fid_option: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+}
+
+/// [국내주식 공매도 상위종목[국내주식-133]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingShortSaleRequest {
+    /// FID 적용 범위 거래량 (String, 필수)
+    #[serde(rename = "FID_APLY_RANG_VOL")]
+    pub fid_aply_rang_vol: String,
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조회구분 (일/월) (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+    /// 조회가간(일수 (String, 필수)
+    #[serde(rename = "FID_INPUT_CNT_1")]
+    pub fid_input_cnt_1: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+    /// FID 대상 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// FID 적용 범위 가격1 (String, 필수)
+    #[serde(rename = "FID_APLY_RANG_PRC_1")]
+    pub fid_aply_rang_prc_1: String,
+    /// FID 적용 범위 가격2 (String, 필수)
+    #[serde(rename = "FID_APLY_RANG_PRC_2")]
+    pub fid_aply_rang_prc_2: String,
+}
+
+/// [국내주식 시간외등락율순위 [국내주식-138]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeFluctuationRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 시장 구분 코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 분류 구분 코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [국내주식 시간외거래량순위 [국내주식-139]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeVolumeRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건 화면 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 순위 정렬 구분 코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력 가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력 가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량 수 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+    /// 대상 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// 대상 제외 구분 코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+}
+
+/// [주식주문(현금)[v1_국내주식-001]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderCashOutputItem {
+    /// 계좌관리점코드
+    #[serde(default, rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 주문시간
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식주문(현금)[v1_국내주식-001]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderCashResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderCashOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderCashResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식주문(신용)[v1_국내주식-002]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderCreditOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "krx_fwdg_ord_orgno")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 주문시간
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식주문(신용)[v1_국내주식-002]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderCreditResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderCreditOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderCreditResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식주문(정정취소)[v1_국내주식-003]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderRvsecnclOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "krx_fwdg_ord_orgno")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식주문(정정취소)[v1_국내주식-003]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식정정취소가능주문조회[v1_국내주식-004]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblRvsecnclOutputItem {
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 주문구분명
+    #[serde(default, rename = "ord_dvsn_name")]
+    pub ord_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 정정취소구분명
+    #[serde(default, rename = "rvse_cncl_dvsn_name")]
+    pub rvse_cncl_dvsn_name: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 주문단가
+    #[serde(default, rename = "ord_unpr")]
+    pub ord_unpr: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 가능수량
+    #[serde(default, rename = "psbl_qty")]
+    pub psbl_qty: Decimal,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문구분코드
+    #[serde(default, rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: String,
+    /// 운용사지정주문번호
+    #[serde(default, rename = "mgco_aptm_odno")]
+    pub mgco_aptm_odno: String,
+    /// 거래소구분코드
+    #[serde(default, rename = "excg_dvsn_cd")]
+    pub excg_dvsn_cd: String,
+    /// 거래소ID구분코드
+    #[serde(default, rename = "excg_id_dvsn_cd")]
+    pub excg_id_dvsn_cd: String,
+    /// 거래소ID구분명
+    #[serde(default, rename = "excg_id_dvsn_name")]
+    pub excg_id_dvsn_name: String,
+    /// 스톱지정가조건가격
+    #[serde(default, rename = "stpm_cndt_pric")]
+    pub stpm_cndt_pric: Decimal,
+    /// 스톱지정가효력발생여부
+    #[serde(default, rename = "stpm_efct_occr_yn")]
+    pub stpm_efct_occr_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식정정취소가능주문조회[v1_국내주식-004]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquirePsblRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<DomesticStockV1TradingInquirePsblRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquirePsblRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식일별주문체결조회[v1_국내주식-005]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireDailyCcldOutput1Item {
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 주문구분명
+    #[serde(default, rename = "ord_dvsn_name")]
+    pub ord_dvsn_name: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매도매수구분코드명
+    #[serde(default, rename = "sll_buy_dvsn_cd_name")]
+    pub sll_buy_dvsn_cd_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 주문단가
+    #[serde(default, rename = "ord_unpr")]
+    pub ord_unpr: Decimal,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 평균가
+    #[serde(default, rename = "avg_prvs")]
+    pub avg_prvs: String,
+    /// 취소여부
+    #[serde(default, rename = "cncl_yn")]
+    pub cncl_yn: String,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 주문자사번
+    #[serde(default, rename = "ordr_empno")]
+    pub ordr_empno: String,
+    /// 주문구분코드
+    #[serde(default, rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: String,
+    /// 취소확인수량
+    #[serde(default, rename = "cnc_cfrm_qty")]
+    pub cnc_cfrm_qty: Decimal,
+    /// 잔여수량
+    #[serde(default, rename = "rmn_qty")]
+    pub rmn_qty: Decimal,
+    /// 거부수량
+    #[serde(default, rename = "rjct_qty")]
+    pub rjct_qty: Decimal,
+    /// 체결조건명
+    #[serde(default, rename = "ccld_cndt_name")]
+    pub ccld_cndt_name: String,
+    /// 조회IP주소
+    #[serde(default, rename = "inqr_ip_addr")]
+    pub inqr_ip_addr: String,
+    /// 전산주문표주문접수구분코드
+    #[serde(default, rename = "cpbc_ordp_ord_rcit_dvsn_cd")]
+    pub cpbc_ordp_ord_rcit_dvsn_cd: String,
+    /// 전산주문표통보방법구분코드
+    #[serde(default, rename = "cpbc_ordp_infm_mthd_dvsn_cd")]
+    pub cpbc_ordp_infm_mthd_dvsn_cd: String,
+    /// 통보시각
+    #[serde(default, rename = "infm_tmd")]
+    pub infm_tmd: String,
+    /// 연락전화번호
+    #[serde(default, rename = "ctac_tlno")]
+    pub ctac_tlno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 거래소구분코드
+    #[serde(default, rename = "excg_dvsn_cd")]
+    pub excg_dvsn_cd: String,
+    /// 전산주문표자료구분코드
+    #[serde(default, rename = "cpbc_ordp_mtrl_dvsn_cd")]
+    pub cpbc_ordp_mtrl_dvsn_cd: String,
+    /// 주문조직번호
+    #[serde(default, rename = "ord_orgno")]
+    pub ord_orgno: String,
+    /// 예약주문종료일자
+    #[serde(default, rename = "rsvn_ord_end_dt")]
+    pub rsvn_ord_end_dt: String,
+    /// 거래소ID구분코드
+    #[serde(default, rename = "excg_id_dvsn_Cd")]
+    pub excg_id_dvsn_cd: String,
+    /// 스톱지정가조건가격
+    #[serde(default, rename = "stpm_cndt_pric")]
+    pub stpm_cndt_pric: Decimal,
+    /// 스톱지정가효력발생상세시각
+    #[serde(default, rename = "stpm_efct_occr_dtmd")]
+    pub stpm_efct_occr_dtmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식일별주문체결조회[v1_국내주식-005]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireDailyCcldOutput2Item {
+    /// 총주문수량
+    #[serde(default, rename = "tot_ord_qty")]
+    pub tot_ord_qty: Decimal,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "prsm_tlex_smtl")]
+    pub prsm_tlex_smtl: String,
+    /// 추정제비용합계
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식일별주문체결조회[v1_국내주식-005]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquireDailyCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquireDailyCcldOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquireDailyCcldOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquireDailyCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식잔고조회[v1_국내주식-006]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 전일매수수량
+    #[serde(default, rename = "bfdy_buy_qty")]
+    pub bfdy_buy_qty: Decimal,
+    /// 전일매도수량
+    #[serde(default, rename = "bfdy_sll_qty")]
+    pub bfdy_sll_qty: Decimal,
+    /// 금일매수수량
+    #[serde(default, rename = "thdt_buyqty")]
+    pub thdt_buyqty: String,
+    /// 금일매도수량
+    #[serde(default, rename = "thdt_sll_qty")]
+    pub thdt_sll_qty: Decimal,
+    /// 보유수량
+    #[serde(default, rename = "hldg_qty")]
+    pub hldg_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 현재가
+    #[serde(default, rename = "prpr")]
+    pub prpr: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 평가손익율
+    #[serde(default, rename = "evlu_pfls_rt")]
+    pub evlu_pfls_rt: Decimal,
+    /// 평가수익율
+    #[serde(default, rename = "evlu_erng_rt")]
+    pub evlu_erng_rt: Decimal,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 대출금액
+    #[serde(default, rename = "loan_amt")]
+    pub loan_amt: Decimal,
+    /// 대주매각대금
+    #[serde(default, rename = "stln_slng_chgs")]
+    pub stln_slng_chgs: String,
+    /// 만기일자
+    #[serde(default, rename = "expd_dt")]
+    pub expd_dt: String,
+    /// 등락율
+    #[serde(default, rename = "fltt_rt")]
+    pub fltt_rt: Decimal,
+    /// 전일대비증감
+    #[serde(default, rename = "bfdy_cprs_icdc")]
+    pub bfdy_cprs_icdc: String,
+    /// 종목증거금율명
+    #[serde(default, rename = "item_mgna_rt_name")]
+    pub item_mgna_rt_name: String,
+    /// 보증금율명
+    #[serde(default, rename = "grta_rt_name")]
+    pub grta_rt_name: String,
+    /// 대용가격
+    #[serde(default, rename = "sbst_pric")]
+    pub sbst_pric: Decimal,
+    /// 주식대출단가
+    #[serde(default, rename = "stck_loan_unpr")]
+    pub stck_loan_unpr: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식잔고조회[v1_국내주식-006]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceOutput2Item {
+    /// 예수금총금액
+    #[serde(default, rename = "dnca_tot_amt")]
+    pub dnca_tot_amt: Decimal,
+    /// 익일정산금액
+    #[serde(default, rename = "nxdy_excc_amt")]
+    pub nxdy_excc_amt: Decimal,
+    /// 가수도정산금액
+    #[serde(default, rename = "prvs_rcdl_excc_amt")]
+    pub prvs_rcdl_excc_amt: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 전일매수금액
+    #[serde(default, rename = "bfdy_buy_amt")]
+    pub bfdy_buy_amt: Decimal,
+    /// 금일매수금액
+    #[serde(default, rename = "thdt_buy_amt")]
+    pub thdt_buy_amt: Decimal,
+    /// 익일자동상환금액
+    #[serde(default, rename = "nxdy_auto_rdpt_amt")]
+    pub nxdy_auto_rdpt_amt: Decimal,
+    /// 전일매도금액
+    #[serde(default, rename = "bfdy_sll_amt")]
+    pub bfdy_sll_amt: Decimal,
+    /// 금일매도금액
+    #[serde(default, rename = "thdt_sll_amt")]
+    pub thdt_sll_amt: Decimal,
+    /// D+2자동상환금액
+    #[serde(default, rename = "d2_auto_rdpt_amt")]
+    pub d2_auto_rdpt_amt: Decimal,
+    /// 전일제비용금액
+    #[serde(default, rename = "bfdy_tlex_amt")]
+    pub bfdy_tlex_amt: Decimal,
+    /// 금일제비용금액
+    #[serde(default, rename = "thdt_tlex_amt")]
+    pub thdt_tlex_amt: Decimal,
+    /// 총대출금액
+    #[serde(default, rename = "tot_loan_amt")]
+    pub tot_loan_amt: Decimal,
+    /// 유가평가금액
+    #[serde(default, rename = "scts_evlu_amt")]
+    pub scts_evlu_amt: Decimal,
+    /// 총평가금액
+    #[serde(default, rename = "tot_evlu_amt")]
+    pub tot_evlu_amt: Decimal,
+    /// 순자산금액
+    #[serde(default, rename = "nass_amt")]
+    pub nass_amt: Decimal,
+    /// 융자금자동상환여부
+    #[serde(default, rename = "fncg_gld_auto_rdpt_yn")]
+    pub fncg_gld_auto_rdpt_yn: String,
+    /// 매입금액합계금액
+    #[serde(default, rename = "pchs_amt_smtl_amt")]
+    pub pchs_amt_smtl_amt: Decimal,
+    /// 평가금액합계금액
+    #[serde(default, rename = "evlu_amt_smtl_amt")]
+    pub evlu_amt_smtl_amt: Decimal,
+    /// 평가손익합계금액
+    #[serde(default, rename = "evlu_pfls_smtl_amt")]
+    pub evlu_pfls_smtl_amt: Decimal,
+    /// 총대주매각대금
+    #[serde(default, rename = "tot_stln_slng_chgs")]
+    pub tot_stln_slng_chgs: String,
+    /// 전일총자산평가금액
+    #[serde(default, rename = "bfdy_tot_asst_evlu_amt")]
+    pub bfdy_tot_asst_evlu_amt: Decimal,
+    /// 자산증감액
+    #[serde(default, rename = "asst_icdc_amt")]
+    pub asst_icdc_amt: Decimal,
+    /// 자산증감수익율
+    #[serde(default, rename = "asst_icdc_erng_rt")]
+    pub asst_icdc_erng_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식잔고조회[v1_국내주식-006]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquireBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건100
+    #[serde(default, rename = "ctx_area_fk100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100
+    #[serde(default, rename = "ctx_area_nk100")]
+    pub ctx_area_nk100: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquireBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquireBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquireBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [매수가능조회[v1_국내주식-007]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblOrderOutputItem {
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 재사용가능금액
+    #[serde(default, rename = "ruse_psbl_amt")]
+    pub ruse_psbl_amt: Decimal,
+    /// 펀드환매대금
+    #[serde(default, rename = "fund_rpch_chgs")]
+    pub fund_rpch_chgs: String,
+    /// 가능수량계산단가
+    #[serde(default, rename = "psbl_qty_calc_unpr")]
+    pub psbl_qty_calc_unpr: Decimal,
+    /// 미수없는매수금액
+    #[serde(default, rename = "nrcvb_buy_amt")]
+    pub nrcvb_buy_amt: Decimal,
+    /// 미수없는매수수량
+    #[serde(default, rename = "nrcvb_buy_qty")]
+    pub nrcvb_buy_qty: Decimal,
+    /// 최대매수금액
+    #[serde(default, rename = "max_buy_amt")]
+    pub max_buy_amt: Decimal,
+    /// 최대매수수량
+    #[serde(default, rename = "max_buy_qty")]
+    pub max_buy_qty: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 해외재사용금액원화
+    #[serde(default, rename = "ovrs_re_use_amt_wcrc")]
+    pub ovrs_re_use_amt_wcrc: String,
+    /// 주문가능외화금액원화
+    #[serde(default, rename = "ord_psbl_frcr_amt_wcrc")]
+    pub ord_psbl_frcr_amt_wcrc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [매수가능조회[v1_국내주식-007]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquirePsblOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingInquirePsblOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquirePsblOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [매도가능수량조회 [국내주식-165]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePsblSellOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매수수량
+    #[serde(default, rename = "buy_qty")]
+    pub buy_qty: Decimal,
+    /// 매도수량
+    #[serde(default, rename = "sll_qty")]
+    pub sll_qty: Decimal,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 비저축수량
+    #[serde(default, rename = "nsvg_qty")]
+    pub nsvg_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 현재가
+    #[serde(default, rename = "now_pric")]
+    pub now_pric: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 평가손익율
+    #[serde(default, rename = "evlu_pfls_rt")]
+    pub evlu_pfls_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [매도가능수량조회 [국내주식-165]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquirePsblSellResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquirePsblSellOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquirePsblSellResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [신용매수가능조회[v1_국내주식-042]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireCreditPsamountOutputItem {
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 주문가능대용
+    #[serde(default, rename = "ord_psbl_sbst")]
+    pub ord_psbl_sbst: String,
+    /// 재사용가능금액
+    #[serde(default, rename = "ruse_psbl_amt")]
+    pub ruse_psbl_amt: Decimal,
+    /// 펀드환매대금
+    #[serde(default, rename = "fund_rpch_chgs")]
+    pub fund_rpch_chgs: String,
+    /// 가능수량계산단가
+    #[serde(default, rename = "psbl_qty_calc_unpr")]
+    pub psbl_qty_calc_unpr: Decimal,
+    /// 미수없는매수금액
+    #[serde(default, rename = "nrcvb_buy_amt")]
+    pub nrcvb_buy_amt: Decimal,
+    /// 미수없는매수수량
+    #[serde(default, rename = "nrcvb_buy_qty")]
+    pub nrcvb_buy_qty: Decimal,
+    /// 최대매수금액
+    #[serde(default, rename = "max_buy_amt")]
+    pub max_buy_amt: Decimal,
+    /// 최대매수수량
+    #[serde(default, rename = "max_buy_qty")]
+    pub max_buy_qty: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 해외재사용금액원화
+    #[serde(default, rename = "ovrs_re_use_amt_wcrc")]
+    pub ovrs_re_use_amt_wcrc: String,
+    /// 주문가능외화금액원화
+    #[serde(default, rename = "ord_psbl_frcr_amt_wcrc")]
+    pub ord_psbl_frcr_amt_wcrc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [신용매수가능조회[v1_국내주식-042]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquireCreditPsamountResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingInquireCreditPsamountOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquireCreditPsamountResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식예약주문[v1_국내주식-017]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvOutputItem {
+    /// 예약주문 순번
+    #[serde(default, rename = "rsvn_ord_seq")]
+    pub rsvn_ord_seq: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식예약주문[v1_국내주식-017]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderResvResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg", alias = "msg1")]
+    pub msg: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderResvOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderResvResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식예약주문정정취소[v1_국내주식-018,019]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvRvsecnclOutputItem {
+    /// 정상처리여부
+    #[serde(default, rename = "nrml_prcs_yn")]
+    pub nrml_prcs_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식예약주문정정취소[v1_국내주식-018,019]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderResvRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg", alias = "msg1")]
+    pub msg: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderResvRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderResvRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식예약주문조회[v1_국내주식-020]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingOrderResvCcnlOutputItem {
+    /// 예약주문 순번
+    #[serde(default, rename = "rsvn_ord_seq")]
+    pub rsvn_ord_seq: String,
+    /// 예약주문주문일자
+    #[serde(default, rename = "rsvn_ord_ord_dt")]
+    pub rsvn_ord_ord_dt: String,
+    /// 예약주문접수일자
+    #[serde(default, rename = "rsvn_ord_rcit_dt")]
+    pub rsvn_ord_rcit_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 주문구분코드
+    #[serde(default, rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: String,
+    /// 주문예약수량
+    #[serde(default, rename = "ord_rsvn_qty")]
+    pub ord_rsvn_qty: Decimal,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 취소주문일자
+    #[serde(default, rename = "cncl_ord_dt")]
+    pub cncl_ord_dt: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 연락전화번호
+    #[serde(default, rename = "ctac_tlno")]
+    pub ctac_tlno: String,
+    /// 거부사유2
+    #[serde(default, rename = "rjct_rson2")]
+    pub rjct_rson2: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 예약주문접수시각
+    #[serde(default, rename = "rsvn_ord_rcit_tmd")]
+    pub rsvn_ord_rcit_tmd: String,
+    /// 한글종목단축명
+    #[serde(default, rename = "kor_item_shtn_name")]
+    pub kor_item_shtn_name: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문예약단가
+    #[serde(default, rename = "ord_rsvn_unpr")]
+    pub ord_rsvn_unpr: Decimal,
+    /// 총체결금액
+    #[serde(default, rename = "tot_ccld_amt")]
+    pub tot_ccld_amt: Decimal,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 취소접수시각
+    #[serde(default, rename = "cncl_rcit_tmd")]
+    pub cncl_rcit_tmd: String,
+    /// 처리결과
+    #[serde(default, rename = "prcs_rslt")]
+    pub prcs_rslt: String,
+    /// 주문구분명
+    #[serde(default, rename = "ord_dvsn_name")]
+    pub ord_dvsn_name: String,
+    /// 단말매체종류코드
+    #[serde(default, rename = "tmnl_mdia_kind_cd")]
+    pub tmnl_mdia_kind_cd: String,
+    /// 예약종료일자
+    #[serde(default, rename = "rsvn_end_dt")]
+    pub rsvn_end_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식예약주문조회[v1_국내주식-020]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingOrderResvCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingOrderResvCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingOrderResvCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [퇴직연금 체결기준잔고[v1_국내주식-032]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquirePresentBalanceOutput1Item {
+    /// 잔고구분
+    #[serde(default, rename = "cblc_dvsn")]
+    pub cblc_dvsn: String,
+    /// 잔고구분명
+    #[serde(default, rename = "cblc_dvsn_name")]
+    pub cblc_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 보유수량
+    #[serde(default, rename = "hldg_qty")]
+    pub hldg_qty: Decimal,
+    /// 매도가능수량
+    #[serde(default, rename = "slpsb_qty")]
+    pub slpsb_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 평가손익율
+    #[serde(default, rename = "evlu_pfls_rt")]
+    pub evlu_pfls_rt: Decimal,
+    /// 현재가
+    #[serde(default, rename = "prpr")]
+    pub prpr: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 잔고비중
+    #[serde(default, rename = "cblc_weit")]
+    pub cblc_weit: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 체결기준잔고[v1_국내주식-032]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquirePresentBalanceOutput2Item {
+    /// 매입금액합계금액
+    #[serde(default, rename = "pchs_amt_smtl_amt")]
+    pub pchs_amt_smtl_amt: Decimal,
+    /// 평가금액합계금액
+    #[serde(default, rename = "evlu_amt_smtl_amt")]
+    pub evlu_amt_smtl_amt: Decimal,
+    /// 평가손익합계금액
+    #[serde(default, rename = "evlu_pfls_smtl_amt")]
+    pub evlu_pfls_smtl_amt: Decimal,
+    /// 매매손익합계
+    #[serde(default, rename = "trad_pfls_smtl")]
+    pub trad_pfls_smtl: String,
+    /// 당일총손익금액
+    #[serde(default, rename = "thdt_tot_pfls_amt")]
+    pub thdt_tot_pfls_amt: Decimal,
+    /// 수익률
+    #[serde(default, rename = "pftrt")]
+    pub pftrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 체결기준잔고[v1_국내주식-032]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPensionInquirePresentBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingPensionInquirePresentBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingPensionInquirePresentBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPensionInquirePresentBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [퇴직연금 미체결내역[v1_국내주식-033]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireDailyCcldOutputItem {
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 주문단가
+    #[serde(default, rename = "ord_unpr")]
+    pub ord_unpr: Decimal,
+    /// 주문수량
+    #[serde(default, rename = "ord_qty")]
+    pub ord_qty: Decimal,
+    /// 총체결수량
+    #[serde(default, rename = "tot_ccld_qty")]
+    pub tot_ccld_qty: Decimal,
+    /// 미체결수량
+    #[serde(default, rename = "nccs_qty")]
+    pub nccs_qty: Decimal,
+    /// 주문구분코드
+    #[serde(default, rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: String,
+    /// 주문구분명
+    #[serde(default, rename = "ord_dvsn_name")]
+    pub ord_dvsn_name: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 대상고객구분명
+    #[serde(default, rename = "objt_cust_dvsn_name")]
+    pub objt_cust_dvsn_name: String,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 스톱지정가조건가격
+    #[serde(default, rename = "stpm_cndt_pric")]
+    pub stpm_cndt_pric: Decimal,
+    /// 스톱지정가효력발생상세시각
+    #[serde(default, rename = "stpm_efct_occr_dtmd")]
+    pub stpm_efct_occr_dtmd: String,
+    /// 스톱지정가효력발생여부
+    #[serde(default, rename = "stpm_efct_occr_yn")]
+    pub stpm_efct_occr_yn: String,
+    /// 거래소ID구분코드
+    #[serde(default, rename = "excg_id_dvsn_cd")]
+    pub excg_id_dvsn_cd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 미체결내역[v1_국내주식-033]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPensionInquireDailyCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingPensionInquireDailyCcldOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPensionInquireDailyCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [퇴직연금 매수가능조회[v1_국내주식-034]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquirePsblOrderOutputItem {
+    /// 주문가능현금
+    #[serde(default, rename = "ord_psbl_cash")]
+    pub ord_psbl_cash: String,
+    /// 재사용가능금액
+    #[serde(default, rename = "ruse_psbl_amt")]
+    pub ruse_psbl_amt: Decimal,
+    /// 가능수량계산단가
+    #[serde(default, rename = "psbl_qty_calc_unpr")]
+    pub psbl_qty_calc_unpr: Decimal,
+    /// 최대매수금액
+    #[serde(default, rename = "max_buy_amt")]
+    pub max_buy_amt: Decimal,
+    /// 최대매수수량
+    #[serde(default, rename = "max_buy_qty")]
+    pub max_buy_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 매수가능조회[v1_국내주식-034]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPensionInquirePsblOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingPensionInquirePsblOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPensionInquirePsblOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [퇴직연금 예수금조회[v1_국내주식-035]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireDepositOutputItem {
+    /// 예수금총액
+    #[serde(default, rename = "dnca_tota")]
+    pub dnca_tota: String,
+    /// 익일정산액
+    #[serde(default, rename = "nxdy_excc_amt")]
+    pub nxdy_excc_amt: Decimal,
+    /// 익일결제금액
+    #[serde(default, rename = "nxdy_sttl_amt")]
+    pub nxdy_sttl_amt: Decimal,
+    /// 2익일결제금액
+    #[serde(default, rename = "nx2_day_sttl_amt")]
+    pub nx2_day_sttl_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 예수금조회[v1_국내주식-035]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPensionInquireDepositResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingPensionInquireDepositOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPensionInquireDepositResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [퇴직연금 잔고조회[v1_국내주식-036]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireBalanceOutput1Item {
+    /// 잔고구분명
+    #[serde(default, rename = "cblc_dvsn_name")]
+    pub cblc_dvsn_name: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 종목구분명
+    #[serde(default, rename = "item_dvsn_name")]
+    pub item_dvsn_name: String,
+    /// 금일매수수량
+    #[serde(default, rename = "thdt_buyqty")]
+    pub thdt_buyqty: String,
+    /// 금일매도수량
+    #[serde(default, rename = "thdt_sll_qty")]
+    pub thdt_sll_qty: Decimal,
+    /// 보유수량
+    #[serde(default, rename = "hldg_qty")]
+    pub hldg_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 현재가
+    #[serde(default, rename = "prpr")]
+    pub prpr: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 평가수익율
+    #[serde(default, rename = "evlu_erng_rt")]
+    pub evlu_erng_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 잔고조회[v1_국내주식-036]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPensionInquireBalanceOutput2Item {
+    /// 예수금총금액
+    #[serde(default, rename = "dnca_tot_amt")]
+    pub dnca_tot_amt: Decimal,
+    /// 익일정산금액
+    #[serde(default, rename = "nxdy_excc_amt")]
+    pub nxdy_excc_amt: Decimal,
+    /// 가수도정산금액
+    #[serde(default, rename = "prvs_rcdl_excc_amt")]
+    pub prvs_rcdl_excc_amt: Decimal,
+    /// 금일매수금액
+    #[serde(default, rename = "thdt_buy_amt")]
+    pub thdt_buy_amt: Decimal,
+    /// 금일매도금액
+    #[serde(default, rename = "thdt_sll_amt")]
+    pub thdt_sll_amt: Decimal,
+    /// 금일제비용금액
+    #[serde(default, rename = "thdt_tlex_amt")]
+    pub thdt_tlex_amt: Decimal,
+    /// 유가평가금액
+    #[serde(default, rename = "scts_evlu_amt")]
+    pub scts_evlu_amt: Decimal,
+    /// 총평가금액
+    #[serde(default, rename = "tot_evlu_amt")]
+    pub tot_evlu_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [퇴직연금 잔고조회[v1_국내주식-036]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPensionInquireBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingPensionInquireBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingPensionInquireBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPensionInquireBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식잔고조회_실현손익[v1_국내주식-041]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceRlzPlOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 전일매수수량
+    #[serde(default, rename = "bfdy_buy_qty")]
+    pub bfdy_buy_qty: Decimal,
+    /// 전일매도수량
+    #[serde(default, rename = "bfdy_sll_qty")]
+    pub bfdy_sll_qty: Decimal,
+    /// 금일매수수량
+    #[serde(default, rename = "thdt_buyqty")]
+    pub thdt_buyqty: String,
+    /// 금일매도수량
+    #[serde(default, rename = "thdt_sll_qty")]
+    pub thdt_sll_qty: Decimal,
+    /// 보유수량
+    #[serde(default, rename = "hldg_qty")]
+    pub hldg_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 현재가
+    #[serde(default, rename = "prpr")]
+    pub prpr: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 평가손익율
+    #[serde(default, rename = "evlu_pfls_rt")]
+    pub evlu_pfls_rt: Decimal,
+    /// 평가수익율
+    #[serde(default, rename = "evlu_erng_rt")]
+    pub evlu_erng_rt: Decimal,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 대출금액
+    #[serde(default, rename = "loan_amt")]
+    pub loan_amt: Decimal,
+    /// 대주매각대금
+    #[serde(default, rename = "stln_slng_chgs")]
+    pub stln_slng_chgs: String,
+    /// 만기일자
+    #[serde(default, rename = "expd_dt")]
+    pub expd_dt: String,
+    /// 주식대출단가
+    #[serde(default, rename = "stck_loan_unpr")]
+    pub stck_loan_unpr: Decimal,
+    /// 전일대비증감
+    #[serde(default, rename = "bfdy_cprs_icdc")]
+    pub bfdy_cprs_icdc: String,
+    /// 등락율
+    #[serde(default, rename = "fltt_rt")]
+    pub fltt_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식잔고조회_실현손익[v1_국내주식-041]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireBalanceRlzPlOutput2Item {
+    /// 예수금총금액
+    #[serde(default, rename = "dnca_tot_amt")]
+    pub dnca_tot_amt: Decimal,
+    /// 익일정산금액
+    #[serde(default, rename = "nxdy_excc_amt")]
+    pub nxdy_excc_amt: Decimal,
+    /// 가수도정산금액
+    #[serde(default, rename = "prvs_rcdl_excc_amt")]
+    pub prvs_rcdl_excc_amt: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 전일매수금액
+    #[serde(default, rename = "bfdy_buy_amt")]
+    pub bfdy_buy_amt: Decimal,
+    /// 금일매수금액
+    #[serde(default, rename = "thdt_buy_amt")]
+    pub thdt_buy_amt: Decimal,
+    /// 익일자동상환금액
+    #[serde(default, rename = "nxdy_auto_rdpt_amt")]
+    pub nxdy_auto_rdpt_amt: Decimal,
+    /// 전일매도금액
+    #[serde(default, rename = "bfdy_sll_amt")]
+    pub bfdy_sll_amt: Decimal,
+    /// 금일매도금액
+    #[serde(default, rename = "thdt_sll_amt")]
+    pub thdt_sll_amt: Decimal,
+    /// D+2자동상환금액
+    #[serde(default, rename = "d2_auto_rdpt_amt")]
+    pub d2_auto_rdpt_amt: Decimal,
+    /// 전일제비용금액
+    #[serde(default, rename = "bfdy_tlex_amt")]
+    pub bfdy_tlex_amt: Decimal,
+    /// 금일제비용금액
+    #[serde(default, rename = "thdt_tlex_amt")]
+    pub thdt_tlex_amt: Decimal,
+    /// 총대출금액
+    #[serde(default, rename = "tot_loan_amt")]
+    pub tot_loan_amt: Decimal,
+    /// 유가평가금액
+    #[serde(default, rename = "scts_evlu_amt")]
+    pub scts_evlu_amt: Decimal,
+    /// 총평가금액
+    #[serde(default, rename = "tot_evlu_amt")]
+    pub tot_evlu_amt: Decimal,
+    /// 순자산금액
+    #[serde(default, rename = "nass_amt")]
+    pub nass_amt: Decimal,
+    /// 융자금자동상환여부
+    #[serde(default, rename = "fncg_gld_auto_rdpt_yn")]
+    pub fncg_gld_auto_rdpt_yn: String,
+    /// 매입금액합계금액
+    #[serde(default, rename = "pchs_amt_smtl_amt")]
+    pub pchs_amt_smtl_amt: Decimal,
+    /// 평가금액합계금액
+    #[serde(default, rename = "evlu_amt_smtl_amt")]
+    pub evlu_amt_smtl_amt: Decimal,
+    /// 평가손익합계금액
+    #[serde(default, rename = "evlu_pfls_smtl_amt")]
+    pub evlu_pfls_smtl_amt: Decimal,
+    /// 총대주매각대금
+    #[serde(default, rename = "tot_stln_slng_chgs")]
+    pub tot_stln_slng_chgs: String,
+    /// 전일총자산평가금액
+    #[serde(default, rename = "bfdy_tot_asst_evlu_amt")]
+    pub bfdy_tot_asst_evlu_amt: Decimal,
+    /// 자산증감액
+    #[serde(default, rename = "asst_icdc_amt")]
+    pub asst_icdc_amt: Decimal,
+    /// 자산증감수익율
+    #[serde(default, rename = "asst_icdc_erng_rt")]
+    pub asst_icdc_erng_rt: Decimal,
+    /// 실현손익
+    #[serde(default, rename = "rlzt_pfls")]
+    pub rlzt_pfls: String,
+    /// 실현수익율
+    #[serde(default, rename = "rlzt_erng_rt")]
+    pub rlzt_erng_rt: Decimal,
+    /// 실평가손익
+    #[serde(default, rename = "real_evlu_pfls")]
+    pub real_evlu_pfls: String,
+    /// 실평가손익수익율
+    #[serde(default, rename = "real_evlu_pfls_erng_rt")]
+    pub real_evlu_pfls_erng_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식잔고조회_실현손익[v1_국내주식-041]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquireBalanceRlzPlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquireBalanceRlzPlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquireBalanceRlzPlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquireBalanceRlzPlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [투자계좌자산현황조회[v1_국내주식-048]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireAccountBalanceOutput1Item {
+    /// 매입금액
+    #[serde(default, rename = "pchs_amt")]
+    pub pchs_amt: Decimal,
+    /// 평가금액
+    #[serde(default, rename = "evlu_amt")]
+    pub evlu_amt: Decimal,
+    /// 평가손익금액
+    #[serde(default, rename = "evlu_pfls_amt")]
+    pub evlu_pfls_amt: Decimal,
+    /// 신용대출금액
+    #[serde(default, rename = "crdt_lnd_amt")]
+    pub crdt_lnd_amt: Decimal,
+    /// 실제순자산금액
+    #[serde(default, rename = "real_nass_amt")]
+    pub real_nass_amt: Decimal,
+    /// 전체비중율
+    #[serde(default, rename = "whol_weit_rt")]
+    pub whol_weit_rt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [투자계좌자산현황조회[v1_국내주식-048]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquireAccountBalanceOutput2Item {
+    /// 매입금액합계
+    #[serde(default, rename = "pchs_amt_smtl")]
+    pub pchs_amt_smtl: String,
+    /// 순자산총금액
+    #[serde(default, rename = "nass_tot_amt")]
+    pub nass_tot_amt: Decimal,
+    /// 대출금액합계
+    #[serde(default, rename = "loan_amt_smtl")]
+    pub loan_amt_smtl: String,
+    /// 평가손익금액합계
+    #[serde(default, rename = "evlu_pfls_amt_smtl")]
+    pub evlu_pfls_amt_smtl: String,
+    /// 평가금액합계
+    #[serde(default, rename = "evlu_amt_smtl")]
+    pub evlu_amt_smtl: String,
+    /// 총자산금액
+    #[serde(default, rename = "tot_asst_amt")]
+    pub tot_asst_amt: Decimal,
+    /// 총대출금액총융자대출금액
+    #[serde(default, rename = "tot_lnda_tot_ulst_lnda")]
+    pub tot_lnda_tot_ulst_lnda: String,
+    /// CMA자동대출금액
+    #[serde(default, rename = "cma_auto_loan_amt")]
+    pub cma_auto_loan_amt: Decimal,
+    /// 총담보대출금액
+    #[serde(default, rename = "tot_mgln_amt")]
+    pub tot_mgln_amt: Decimal,
+    /// 대주평가금액
+    #[serde(default, rename = "stln_evlu_amt")]
+    pub stln_evlu_amt: Decimal,
+    /// 신용융자금액
+    #[serde(default, rename = "crdt_fncg_amt")]
+    pub crdt_fncg_amt: Decimal,
+    /// OCL_APL대출금액
+    #[serde(default, rename = "ocl_apl_loan_amt")]
+    pub ocl_apl_loan_amt: Decimal,
+    /// 질권설정금액
+    #[serde(default, rename = "pldg_stup_amt")]
+    pub pldg_stup_amt: Decimal,
+    /// 외화평가총액
+    #[serde(default, rename = "frcr_evlu_tota")]
+    pub frcr_evlu_tota: String,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 예수금액
+    #[serde(default, rename = "dncl_amt")]
+    pub dncl_amt: Decimal,
+    /// 총대용금액
+    #[serde(default, rename = "tot_sbst_amt")]
+    pub tot_sbst_amt: Decimal,
+    /// 당일미수금액
+    #[serde(default, rename = "thdt_rcvb_amt")]
+    pub thdt_rcvb_amt: Decimal,
+    /// 해외주식평가금액1
+    #[serde(default, rename = "ovrs_stck_evlu_amt1")]
+    pub ovrs_stck_evlu_amt1: Decimal,
+    /// 해외채권평가금액
+    #[serde(default, rename = "ovrs_bond_evlu_amt")]
+    pub ovrs_bond_evlu_amt: Decimal,
+    /// MMFCMA담보대출금액
+    #[serde(default, rename = "mmf_cma_mgge_loan_amt")]
+    pub mmf_cma_mgge_loan_amt: Decimal,
+    /// 청약예수금액
+    #[serde(default, rename = "sbsc_dncl_amt")]
+    pub sbsc_dncl_amt: Decimal,
+    /// 공모주청약자금대출사용금액
+    #[serde(default, rename = "pbst_sbsc_fnds_loan_use_amt")]
+    pub pbst_sbsc_fnds_loan_use_amt: Decimal,
+    /// 기업신용공여대출금액
+    #[serde(default, rename = "etpr_crdt_grnt_loan_amt")]
+    pub etpr_crdt_grnt_loan_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [투자계좌자산현황조회[v1_국내주식-048]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquireAccountBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquireAccountBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquireAccountBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquireAccountBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [기간별손익일별합산조회[v1_국내주식-052]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodProfitOutput1Item {
+    /// 매매일자
+    #[serde(default, rename = "trad_dt")]
+    pub trad_dt: String,
+    /// 매수금액
+    #[serde(default, rename = "buy_amt")]
+    pub buy_amt: Decimal,
+    /// 매도금액
+    #[serde(default, rename = "sll_amt")]
+    pub sll_amt: Decimal,
+    /// 실현손익
+    #[serde(default, rename = "rlzt_pfls")]
+    pub rlzt_pfls: String,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 대출이자
+    #[serde(default, rename = "loan_int")]
+    pub loan_int: String,
+    /// 제세금
+    #[serde(default, rename = "tl_tax")]
+    pub tl_tax: String,
+    /// 손익률
+    #[serde(default, rename = "pfls_rt")]
+    pub pfls_rt: Decimal,
+    /// 매도수량1
+    #[serde(default, rename = "sll_qty1")]
+    pub sll_qty1: String,
+    /// 매수수량1
+    #[serde(default, rename = "buy_qty1")]
+    pub buy_qty1: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [기간별손익일별합산조회[v1_국내주식-052]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodProfitOutput2Item {
+    /// 매도수량합계
+    #[serde(default, rename = "sll_qty_smtl")]
+    pub sll_qty_smtl: String,
+    /// 매도거래금액합계
+    #[serde(default, rename = "sll_tr_amt_smtl")]
+    pub sll_tr_amt_smtl: String,
+    /// 매도수수료합계
+    #[serde(default, rename = "sll_fee_smtl")]
+    pub sll_fee_smtl: String,
+    /// 매도제세금합계
+    #[serde(default, rename = "sll_tltx_smtl")]
+    pub sll_tltx_smtl: String,
+    /// 매도정산금액합계
+    #[serde(default, rename = "sll_excc_amt_smtl")]
+    pub sll_excc_amt_smtl: String,
+    /// 매수수량합계
+    #[serde(default, rename = "buy_qty_smtl")]
+    pub buy_qty_smtl: String,
+    /// 매수거래금액합계
+    #[serde(default, rename = "buy_tr_amt_smtl")]
+    pub buy_tr_amt_smtl: String,
+    /// 매수수수료합계
+    #[serde(default, rename = "buy_fee_smtl")]
+    pub buy_fee_smtl: String,
+    /// 매수제세금합계
+    #[serde(default, rename = "buy_tax_smtl")]
+    pub buy_tax_smtl: String,
+    /// 매수정산금액합계
+    #[serde(default, rename = "buy_excc_amt_smtl")]
+    pub buy_excc_amt_smtl: String,
+    /// 총수량
+    #[serde(default, rename = "tot_qty")]
+    pub tot_qty: Decimal,
+    /// 총거래금액
+    #[serde(default, rename = "tot_tr_amt")]
+    pub tot_tr_amt: Decimal,
+    /// 총수수료
+    #[serde(default, rename = "tot_fee")]
+    pub tot_fee: String,
+    /// 총제세금
+    #[serde(default, rename = "tot_tltx")]
+    pub tot_tltx: String,
+    /// 총정산금액
+    #[serde(default, rename = "tot_excc_amt")]
+    pub tot_excc_amt: Decimal,
+    /// 총실현손익
+    #[serde(default, rename = "tot_rlzt_pfls")]
+    pub tot_rlzt_pfls: String,
+    /// 대출이자
+    #[serde(default, rename = "loan_int")]
+    pub loan_int: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [기간별손익일별합산조회[v1_국내주식-052]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquirePeriodProfitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquirePeriodProfitOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquirePeriodProfitOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquirePeriodProfitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [기간별매매손익현황조회[v1_국내주식-060]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodTradeProfitOutput1Item {
+    /// 매매일자
+    #[serde(default, rename = "trad_dt")]
+    pub trad_dt: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 보유수량
+    #[serde(default, rename = "hldg_qty")]
+    pub hldg_qty: Decimal,
+    /// 매입단가
+    #[serde(default, rename = "pchs_unpr")]
+    pub pchs_unpr: Decimal,
+    /// 매수수량
+    #[serde(default, rename = "buy_qty")]
+    pub buy_qty: Decimal,
+    /// 매수금액
+    #[serde(default, rename = "buy_amt")]
+    pub buy_amt: Decimal,
+    /// 매도가격
+    #[serde(default, rename = "sll_pric")]
+    pub sll_pric: Decimal,
+    /// 매도수량
+    #[serde(default, rename = "sll_qty")]
+    pub sll_qty: Decimal,
+    /// 매도금액
+    #[serde(default, rename = "sll_amt")]
+    pub sll_amt: Decimal,
+    /// 실현손익
+    #[serde(default, rename = "rlzt_pfls")]
+    pub rlzt_pfls: String,
+    /// 손익률
+    #[serde(default, rename = "pfls_rt")]
+    pub pfls_rt: Decimal,
+    /// 수수료
+    #[serde(default, rename = "fee")]
+    pub fee: String,
+    /// 제세금
+    #[serde(default, rename = "tl_tax")]
+    pub tl_tax: String,
+    /// 대출이자
+    #[serde(default, rename = "loan_int")]
+    pub loan_int: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [기간별매매손익현황조회[v1_국내주식-060]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingInquirePeriodTradeProfitOutput2Item {
+    /// 매도수량합계
+    #[serde(default, rename = "sll_qty_smtl")]
+    pub sll_qty_smtl: String,
+    /// 매도거래금액합계
+    #[serde(default, rename = "sll_tr_amt_smtl")]
+    pub sll_tr_amt_smtl: String,
+    /// 매도수수료합계
+    #[serde(default, rename = "sll_fee_smtl")]
+    pub sll_fee_smtl: String,
+    /// 매도제세금합계
+    #[serde(default, rename = "sll_tltx_smtl")]
+    pub sll_tltx_smtl: String,
+    /// 매도정산금액합계
+    #[serde(default, rename = "sll_excc_amt_smtl")]
+    pub sll_excc_amt_smtl: String,
+    /// 매수수량합계
+    #[serde(default, rename = "buyqty_smtl")]
+    pub buyqty_smtl: String,
+    /// 매수거래금액합계
+    #[serde(default, rename = "buy_tr_amt_smtl")]
+    pub buy_tr_amt_smtl: String,
+    /// 매수수수료합계
+    #[serde(default, rename = "buy_fee_smtl")]
+    pub buy_fee_smtl: String,
+    /// 매수제세금합계
+    #[serde(default, rename = "buy_tax_smtl")]
+    pub buy_tax_smtl: String,
+    /// 매수정산금액합계
+    #[serde(default, rename = "buy_excc_amt_smtl")]
+    pub buy_excc_amt_smtl: String,
+    /// 총수량
+    #[serde(default, rename = "tot_qty")]
+    pub tot_qty: Decimal,
+    /// 총거래금액
+    #[serde(default, rename = "tot_tr_amt")]
+    pub tot_tr_amt: Decimal,
+    /// 총수수료
+    #[serde(default, rename = "tot_fee")]
+    pub tot_fee: String,
+    /// 총제세금
+    #[serde(default, rename = "tot_tltx")]
+    pub tot_tltx: String,
+    /// 총정산금액
+    #[serde(default, rename = "tot_excc_amt")]
+    pub tot_excc_amt: Decimal,
+    /// 총실현손익
+    #[serde(default, rename = "tot_rlzt_pfls")]
+    pub tot_rlzt_pfls: String,
+    /// 대출이자
+    #[serde(default, rename = "loan_int")]
+    pub loan_int: String,
+    /// 총수익률
+    #[serde(default, rename = "tot_pftrt")]
+    pub tot_pftrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [기간별매매손익현황조회[v1_국내주식-060]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingInquirePeriodTradeProfitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회키100
+    #[serde(default, rename = "ctx_area_nk100")]
+    pub ctx_area_nk100: String,
+    /// 연속조회검색조건100
+    #[serde(default, rename = "ctx_area_fk100")]
+    pub ctx_area_fk100: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingInquirePeriodTradeProfitOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1TradingInquirePeriodTradeProfitOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingInquirePeriodTradeProfitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식통합증거금 현황 [국내주식-191]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingIntgrMarginOutputItem {
+    /// 계좌증거금율
+    #[serde(default, rename = "acmga_rt")]
+    pub acmga_rt: Decimal,
+    /// 계좌증거금100퍼센트지정사유
+    #[serde(default, rename = "acmga_pct100_aptm_rson")]
+    pub acmga_pct100_aptm_rson: String,
+    /// 주식현금대상금액
+    #[serde(default, rename = "stck_cash_objt_amt")]
+    pub stck_cash_objt_amt: Decimal,
+    /// 주식대용대상금액
+    #[serde(default, rename = "stck_sbst_objt_amt")]
+    pub stck_sbst_objt_amt: Decimal,
+    /// 주식평가대상금액
+    #[serde(default, rename = "stck_evlu_objt_amt")]
+    pub stck_evlu_objt_amt: Decimal,
+    /// 주식재사용가능대상금액
+    #[serde(default, rename = "stck_ruse_psbl_objt_amt")]
+    pub stck_ruse_psbl_objt_amt: Decimal,
+    /// 주식펀드환매대금대상금액
+    #[serde(default, rename = "stck_fund_rpch_chgs_objt_amt")]
+    pub stck_fund_rpch_chgs_objt_amt: Decimal,
+    /// 주식융자상환금대상금액
+    #[serde(default, rename = "stck_fncg_rdpt_objt_atm")]
+    pub stck_fncg_rdpt_objt_atm: String,
+    /// 채권재사용가능대상금액
+    #[serde(default, rename = "bond_ruse_psbl_objt_amt")]
+    pub bond_ruse_psbl_objt_amt: Decimal,
+    /// 주식현금사용금액
+    #[serde(default, rename = "stck_cash_use_amt")]
+    pub stck_cash_use_amt: Decimal,
+    /// 주식대용사용금액
+    #[serde(default, rename = "stck_sbst_use_amt")]
+    pub stck_sbst_use_amt: Decimal,
+    /// 주식평가사용금액
+    #[serde(default, rename = "stck_evlu_use_amt")]
+    pub stck_evlu_use_amt: Decimal,
+    /// 주식재사용가능금사용금액
+    #[serde(default, rename = "stck_ruse_psbl_amt_use_amt")]
+    pub stck_ruse_psbl_amt_use_amt: Decimal,
+    /// 주식펀드환매대금사용금액
+    #[serde(default, rename = "stck_fund_rpch_chgs_use_amt")]
+    pub stck_fund_rpch_chgs_use_amt: Decimal,
+    /// 주식융자상환금사용금액
+    #[serde(default, rename = "stck_fncg_rdpt_amt_use_amt")]
+    pub stck_fncg_rdpt_amt_use_amt: Decimal,
+    /// 채권재사용가능금사용금액
+    #[serde(default, rename = "bond_ruse_psbl_amt_use_amt")]
+    pub bond_ruse_psbl_amt_use_amt: Decimal,
+    /// 주식현금주문가능금액
+    #[serde(default, rename = "stck_cash_ord_psbl_amt")]
+    pub stck_cash_ord_psbl_amt: Decimal,
+    /// 주식대용주문가능금액
+    #[serde(default, rename = "stck_sbst_ord_psbl_amt")]
+    pub stck_sbst_ord_psbl_amt: Decimal,
+    /// 주식평가주문가능금액
+    #[serde(default, rename = "stck_evlu_ord_psbl_amt")]
+    pub stck_evlu_ord_psbl_amt: Decimal,
+    /// 주식재사용가능주문가능금액
+    #[serde(default, rename = "stck_ruse_psbl_ord_psbl_amt")]
+    pub stck_ruse_psbl_ord_psbl_amt: Decimal,
+    /// 주식펀드환매주문가능금액
+    #[serde(default, rename = "stck_fund_rpch_ord_psbl_amt")]
+    pub stck_fund_rpch_ord_psbl_amt: Decimal,
+    /// 채권재사용가능주문가능금액
+    #[serde(default, rename = "bond_ruse_psbl_ord_psbl_amt")]
+    pub bond_ruse_psbl_ord_psbl_amt: Decimal,
+    /// 미수금액
+    #[serde(default, rename = "rcvb_amt")]
+    pub rcvb_amt: Decimal,
+    /// 주식대출보증금재사용가능금액
+    #[serde(default, rename = "stck_loan_grta_ruse_psbl_amt")]
+    pub stck_loan_grta_ruse_psbl_amt: Decimal,
+    /// 주식현금20최대주문가능금액
+    #[serde(default, rename = "stck_cash20_max_ord_psbl_amt")]
+    pub stck_cash20_max_ord_psbl_amt: Decimal,
+    /// 주식현금30최대주문가능금액
+    #[serde(default, rename = "stck_cash30_max_ord_psbl_amt")]
+    pub stck_cash30_max_ord_psbl_amt: Decimal,
+    /// 주식현금40최대주문가능금액
+    #[serde(default, rename = "stck_cash40_max_ord_psbl_amt")]
+    pub stck_cash40_max_ord_psbl_amt: Decimal,
+    /// 주식현금50최대주문가능금액
+    #[serde(default, rename = "stck_cash50_max_ord_psbl_amt")]
+    pub stck_cash50_max_ord_psbl_amt: Decimal,
+    /// 주식현금60최대주문가능금액
+    #[serde(default, rename = "stck_cash60_max_ord_psbl_amt")]
+    pub stck_cash60_max_ord_psbl_amt: Decimal,
+    /// 주식현금100최대주문가능금액
+    #[serde(default, rename = "stck_cash100_max_ord_psbl_amt")]
+    pub stck_cash100_max_ord_psbl_amt: Decimal,
+    /// 주식재사용불가100최대주문가능
+    #[serde(default, rename = "stck_rsip100_max_ord_psbl_amt")]
+    pub stck_rsip100_max_ord_psbl_amt: Decimal,
+    /// 채권최대주문가능금액
+    #[serde(default, rename = "bond_max_ord_psbl_amt")]
+    pub bond_max_ord_psbl_amt: Decimal,
+    /// 주식융자45최대주문가능금액
+    #[serde(default, rename = "stck_fncg45_max_ord_psbl_amt")]
+    pub stck_fncg45_max_ord_psbl_amt: Decimal,
+    /// 주식융자50최대주문가능금액
+    #[serde(default, rename = "stck_fncg50_max_ord_psbl_amt")]
+    pub stck_fncg50_max_ord_psbl_amt: Decimal,
+    /// 주식융자60최대주문가능금액
+    #[serde(default, rename = "stck_fncg60_max_ord_psbl_amt")]
+    pub stck_fncg60_max_ord_psbl_amt: Decimal,
+    /// 주식융자70최대주문가능금액
+    #[serde(default, rename = "stck_fncg70_max_ord_psbl_amt")]
+    pub stck_fncg70_max_ord_psbl_amt: Decimal,
+    /// 주식대주최대주문가능금액
+    #[serde(default, rename = "stck_stln_max_ord_psbl_amt")]
+    pub stck_stln_max_ord_psbl_amt: Decimal,
+    /// 한도금액
+    #[serde(default, rename = "lmt_amt")]
+    pub lmt_amt: Decimal,
+    /// 해외주식통합증거금구분명
+    #[serde(default, rename = "ovrs_stck_itgr_mgna_dvsn_name")]
+    pub ovrs_stck_itgr_mgna_dvsn_name: String,
+    /// 미화대상금액
+    #[serde(default, rename = "usd_objt_amt")]
+    pub usd_objt_amt: Decimal,
+    /// 미화사용금액
+    #[serde(default, rename = "usd_use_amt")]
+    pub usd_use_amt: Decimal,
+    /// 미화주문가능금액
+    #[serde(default, rename = "usd_ord_psbl_amt")]
+    pub usd_ord_psbl_amt: Decimal,
+    /// 홍콩달러대상금액
+    #[serde(default, rename = "hkd_objt_amt")]
+    pub hkd_objt_amt: Decimal,
+    /// 홍콩달러사용금액
+    #[serde(default, rename = "hkd_use_amt")]
+    pub hkd_use_amt: Decimal,
+    /// 홍콩달러주문가능금액
+    #[serde(default, rename = "hkd_ord_psbl_amt")]
+    pub hkd_ord_psbl_amt: Decimal,
+    /// 엔화대상금액
+    #[serde(default, rename = "jpy_objt_amt")]
+    pub jpy_objt_amt: Decimal,
+    /// 엔화사용금액
+    #[serde(default, rename = "jpy_use_amt")]
+    pub jpy_use_amt: Decimal,
+    /// 엔화주문가능금액
+    #[serde(default, rename = "jpy_ord_psbl_amt")]
+    pub jpy_ord_psbl_amt: Decimal,
+    /// 위안화대상금액
+    #[serde(default, rename = "cny_objt_amt")]
+    pub cny_objt_amt: Decimal,
+    /// 위안화사용금액
+    #[serde(default, rename = "cny_use_amt")]
+    pub cny_use_amt: Decimal,
+    /// 위안화주문가능금액
+    #[serde(default, rename = "cny_ord_psbl_amt")]
+    pub cny_ord_psbl_amt: Decimal,
+    /// 미화재사용대상금액
+    #[serde(default, rename = "usd_ruse_objt_amt")]
+    pub usd_ruse_objt_amt: Decimal,
+    /// 미화재사용금액
+    #[serde(default, rename = "usd_ruse_amt")]
+    pub usd_ruse_amt: Decimal,
+    /// 미화재사용주문가능금액
+    #[serde(default, rename = "usd_ruse_ord_psbl_amt")]
+    pub usd_ruse_ord_psbl_amt: Decimal,
+    /// 홍콩달러재사용대상금액
+    #[serde(default, rename = "hkd_ruse_objt_amt")]
+    pub hkd_ruse_objt_amt: Decimal,
+    /// 홍콩달러재사용금액
+    #[serde(default, rename = "hkd_ruse_amt")]
+    pub hkd_ruse_amt: Decimal,
+    /// 홍콩달러재사용주문가능금액
+    #[serde(default, rename = "hkd_ruse_ord_psbl_amt")]
+    pub hkd_ruse_ord_psbl_amt: Decimal,
+    /// 엔화재사용대상금액
+    #[serde(default, rename = "jpy_ruse_objt_amt")]
+    pub jpy_ruse_objt_amt: Decimal,
+    /// 엔화재사용금액
+    #[serde(default, rename = "jpy_ruse_amt")]
+    pub jpy_ruse_amt: Decimal,
+    /// 엔화재사용주문가능금액
+    #[serde(default, rename = "jpy_ruse_ord_psbl_amt")]
+    pub jpy_ruse_ord_psbl_amt: Decimal,
+    /// 위안화재사용대상금액
+    #[serde(default, rename = "cny_ruse_objt_amt")]
+    pub cny_ruse_objt_amt: Decimal,
+    /// 위안화재사용금액
+    #[serde(default, rename = "cny_ruse_amt")]
+    pub cny_ruse_amt: Decimal,
+    /// 위안화재사용주문가능금액
+    #[serde(default, rename = "cny_ruse_ord_psbl_amt")]
+    pub cny_ruse_ord_psbl_amt: Decimal,
+    /// 미화일반주문가능금액
+    #[serde(default, rename = "usd_gnrl_ord_psbl_amt")]
+    pub usd_gnrl_ord_psbl_amt: Decimal,
+    /// 미화통합주문가능금액
+    #[serde(default, rename = "usd_itgr_ord_psbl_amt")]
+    pub usd_itgr_ord_psbl_amt: Decimal,
+    /// 홍콩달러일반주문가능금액
+    #[serde(default, rename = "hkd_gnrl_ord_psbl_amt")]
+    pub hkd_gnrl_ord_psbl_amt: Decimal,
+    /// 홍콩달러통합주문가능금액
+    #[serde(default, rename = "hkd_itgr_ord_psbl_amt")]
+    pub hkd_itgr_ord_psbl_amt: Decimal,
+    /// 엔화일반주문가능금액
+    #[serde(default, rename = "jpy_gnrl_ord_psbl_amt")]
+    pub jpy_gnrl_ord_psbl_amt: Decimal,
+    /// 엔화통합주문가능금액
+    #[serde(default, rename = "jpy_itgr_ord_psbl_amt")]
+    pub jpy_itgr_ord_psbl_amt: Decimal,
+    /// 위안화일반주문가능금액
+    #[serde(default, rename = "cny_gnrl_ord_psbl_amt")]
+    pub cny_gnrl_ord_psbl_amt: Decimal,
+    /// 위안화통합주문가능금액
+    #[serde(default, rename = "cny_itgr_ord_psbl_amt")]
+    pub cny_itgr_ord_psbl_amt: Decimal,
+    /// 주식통합현금20주문가능금액
+    #[serde(default, rename = "stck_itgr_cash20_ord_psbl_amt")]
+    pub stck_itgr_cash20_ord_psbl_amt: Decimal,
+    /// 주식통합현금30주문가능금액
+    #[serde(default, rename = "stck_itgr_cash30_ord_psbl_amt")]
+    pub stck_itgr_cash30_ord_psbl_amt: Decimal,
+    /// 주식통합현금40주문가능금액
+    #[serde(default, rename = "stck_itgr_cash40_ord_psbl_amt")]
+    pub stck_itgr_cash40_ord_psbl_amt: Decimal,
+    /// 주식통합현금50주문가능금액
+    #[serde(default, rename = "stck_itgr_cash50_ord_psbl_amt")]
+    pub stck_itgr_cash50_ord_psbl_amt: Decimal,
+    /// 주식통합현금60주문가능금액
+    #[serde(default, rename = "stck_itgr_cash60_ord_psbl_amt")]
+    pub stck_itgr_cash60_ord_psbl_amt: Decimal,
+    /// 주식통합현금100주문가능금액
+    #[serde(default, rename = "stck_itgr_cash100_ord_psbl_amt")]
+    pub stck_itgr_cash100_ord_psbl_amt: Decimal,
+    /// 주식통합100주문가능금액
+    #[serde(default, rename = "stck_itgr_100_ord_psbl_amt")]
+    pub stck_itgr_100_ord_psbl_amt: Decimal,
+    /// 주식통합융자45주문가능금액
+    #[serde(default, rename = "stck_itgr_fncg45_ord_psbl_amt")]
+    pub stck_itgr_fncg45_ord_psbl_amt: Decimal,
+    /// 주식통합융자50주문가능금액
+    #[serde(default, rename = "stck_itgr_fncg50_ord_psbl_amt")]
+    pub stck_itgr_fncg50_ord_psbl_amt: Decimal,
+    /// 주식통합융자60주문가능금액
+    #[serde(default, rename = "stck_itgr_fncg60_ord_psbl_amt")]
+    pub stck_itgr_fncg60_ord_psbl_amt: Decimal,
+    /// 주식통합융자70주문가능금액
+    #[serde(default, rename = "stck_itgr_fncg70_ord_psbl_amt")]
+    pub stck_itgr_fncg70_ord_psbl_amt: Decimal,
+    /// 주식통합대주주문가능금액
+    #[serde(default, rename = "stck_itgr_stln_ord_psbl_amt")]
+    pub stck_itgr_stln_ord_psbl_amt: Decimal,
+    /// 채권통합주문가능금액
+    #[serde(default, rename = "bond_itgr_ord_psbl_amt")]
+    pub bond_itgr_ord_psbl_amt: Decimal,
+    /// 주식현금해외사용금액
+    #[serde(default, rename = "stck_cash_ovrs_use_amt")]
+    pub stck_cash_ovrs_use_amt: Decimal,
+    /// 주식대용해외사용금액
+    #[serde(default, rename = "stck_sbst_ovrs_use_amt")]
+    pub stck_sbst_ovrs_use_amt: Decimal,
+    /// 주식평가해외사용금액
+    #[serde(default, rename = "stck_evlu_ovrs_use_amt")]
+    pub stck_evlu_ovrs_use_amt: Decimal,
+    /// 주식재사용금액해외사용금액
+    #[serde(default, rename = "stck_re_use_amt_ovrs_use_amt")]
+    pub stck_re_use_amt_ovrs_use_amt: Decimal,
+    /// 주식펀드환매해외사용금액
+    #[serde(default, rename = "stck_fund_rpch_ovrs_use_amt")]
+    pub stck_fund_rpch_ovrs_use_amt: Decimal,
+    /// 주식융자상환해외사용금액
+    #[serde(default, rename = "stck_fncg_rdpt_ovrs_use_amt")]
+    pub stck_fncg_rdpt_ovrs_use_amt: Decimal,
+    /// 채권재사용해외사용금액
+    #[serde(default, rename = "bond_re_use_ovrs_use_amt")]
+    pub bond_re_use_ovrs_use_amt: Decimal,
+    /// 미화타시장사용금액
+    #[serde(default, rename = "usd_oth_mket_use_amt")]
+    pub usd_oth_mket_use_amt: Decimal,
+    /// 엔화타시장사용금액
+    #[serde(default, rename = "jpy_oth_mket_use_amt")]
+    pub jpy_oth_mket_use_amt: Decimal,
+    /// 위안화타시장사용금액
+    #[serde(default, rename = "cny_oth_mket_use_amt")]
+    pub cny_oth_mket_use_amt: Decimal,
+    /// 홍콩달러타시장사용금액
+    #[serde(default, rename = "hkd_oth_mket_use_amt")]
+    pub hkd_oth_mket_use_amt: Decimal,
+    /// 미화재사용타시장사용금액
+    #[serde(default, rename = "usd_re_use_oth_mket_use_amt")]
+    pub usd_re_use_oth_mket_use_amt: Decimal,
+    /// 엔화재사용타시장사용금액
+    #[serde(default, rename = "jpy_re_use_oth_mket_use_amt")]
+    pub jpy_re_use_oth_mket_use_amt: Decimal,
+    /// 위안화재사용타시장사용금액
+    #[serde(default, rename = "cny_re_use_oth_mket_use_amt")]
+    pub cny_re_use_oth_mket_use_amt: Decimal,
+    /// 홍콩달러재사용타시장사용금액
+    #[serde(default, rename = "hkd_re_use_oth_mket_use_amt")]
+    pub hkd_re_use_oth_mket_use_amt: Decimal,
+    /// 홍콩위안화재사용금액
+    #[serde(default, rename = "hgkg_cny_re_use_amt")]
+    pub hgkg_cny_re_use_amt: Decimal,
+    /// 미국달러최초고시환율
+    #[serde(default, rename = "usd_frst_bltn_exrt")]
+    pub usd_frst_bltn_exrt: String,
+    /// 홍콩달러최초고시환율
+    #[serde(default, rename = "hkd_frst_bltn_exrt")]
+    pub hkd_frst_bltn_exrt: String,
+    /// 일본엔화최초고시환율
+    #[serde(default, rename = "jpy_frst_bltn_exrt")]
+    pub jpy_frst_bltn_exrt: String,
+    /// 중국위안화최초고시환율
+    #[serde(default, rename = "cny_frst_bltn_exrt")]
+    pub cny_frst_bltn_exrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식통합증거금 현황 [국내주식-191]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingIntgrMarginResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1TradingIntgrMarginOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingIntgrMarginResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [기간별계좌권리현황조회 [국내주식-211]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1TradingPeriodRightsOutput1Item {
+    /// 계좌번호10
+    #[serde(default, rename = "acno10")]
+    pub acno10: String,
+    /// 권리유형코드
+    #[serde(default, rename = "rght_type_cd")]
+    pub rght_type_cd: String,
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 권리잔고유형코드
+    #[serde(default, rename = "rght_cblc_type_cd")]
+    pub rght_cblc_type_cd: String,
+    /// 대표상품번호
+    #[serde(default, rename = "rptt_pdno")]
+    pub rptt_pdno: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 잔고수량
+    #[serde(default, rename = "cblc_qty")]
+    pub cblc_qty: Decimal,
+    /// 최종배정수량
+    #[serde(default, rename = "last_alct_qty")]
+    pub last_alct_qty: Decimal,
+    /// 초과배정수량
+    #[serde(default, rename = "excs_alct_qty")]
+    pub excs_alct_qty: Decimal,
+    /// 총배정수량
+    #[serde(default, rename = "tot_alct_qty")]
+    pub tot_alct_qty: Decimal,
+    /// 최종단수주수량
+    #[serde(default, rename = "last_ftsk_qty")]
+    pub last_ftsk_qty: Decimal,
+    /// 최종배정금액
+    #[serde(default, rename = "last_alct_amt")]
+    pub last_alct_amt: Decimal,
+    /// 최종단수주대금
+    #[serde(default, rename = "last_ftsk_chgs")]
+    pub last_ftsk_chgs: String,
+    /// 상환원금
+    #[serde(default, rename = "rdpt_prca")]
+    pub rdpt_prca: String,
+    /// 지연이자금액
+    #[serde(default, rename = "dlay_int_amt")]
+    pub dlay_int_amt: Decimal,
+    /// 상장일자
+    #[serde(default, rename = "lstg_dt")]
+    pub lstg_dt: String,
+    /// 청약종료일자
+    #[serde(default, rename = "sbsc_end_dt")]
+    pub sbsc_end_dt: String,
+    /// 현금지급일자
+    #[serde(default, rename = "cash_dfrm_dt")]
+    pub cash_dfrm_dt: String,
+    /// 신청수량
+    #[serde(default, rename = "rqst_qty")]
+    pub rqst_qty: Decimal,
+    /// 신청금액
+    #[serde(default, rename = "rqst_amt")]
+    pub rqst_amt: Decimal,
+    /// 신청일자
+    #[serde(default, rename = "rqst_dt")]
+    pub rqst_dt: String,
+    /// 환불일자
+    #[serde(default, rename = "rfnd_dt")]
+    pub rfnd_dt: String,
+    /// 환불금액
+    #[serde(default, rename = "rfnd_amt")]
+    pub rfnd_amt: Decimal,
+    /// 상장주수
+    #[serde(default, rename = "lstg_stqt")]
+    pub lstg_stqt: String,
+    /// 세금금액
+    #[serde(default, rename = "tax_amt")]
+    pub tax_amt: Decimal,
+    /// 청약단가
+    #[serde(default, rename = "sbsc_unpr")]
+    pub sbsc_unpr: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [기간별계좌권리현황조회 [국내주식-211]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1TradingPeriodRightsResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1TradingPeriodRightsOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1TradingPeriodRightsResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 시세[v1_국내주식-008]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquirePriceOutputItem {
+    /// 종목 상태 구분 코드
+    #[serde(default, rename = "iscd_stat_cls_code")]
+    pub iscd_stat_cls_code: String,
+    /// 증거금 비율
+    #[serde(default, rename = "marg_rate")]
+    pub marg_rate: Decimal,
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 신 고가 저가 구분 코드
+    #[serde(default, rename = "new_hgpr_lwpr_cls_code")]
+    pub new_hgpr_lwpr_cls_code: String,
+    /// 업종 한글 종목명
+    #[serde(default, rename = "bstp_kor_isnm")]
+    pub bstp_kor_isnm: String,
+    /// 임시 정지 여부
+    #[serde(default, rename = "temp_stop_yn")]
+    pub temp_stop_yn: String,
+    /// 시가 범위 연장 여부
+    #[serde(default, rename = "oprc_rang_cont_yn")]
+    pub oprc_rang_cont_yn: String,
+    /// 종가 범위 연장 여부
+    #[serde(default, rename = "clpr_rang_cont_yn")]
+    pub clpr_rang_cont_yn: String,
+    /// 신용 가능 여부
+    #[serde(default, rename = "crdt_able_yn")]
+    pub crdt_able_yn: String,
+    /// 보증금 비율 구분 코드
+    #[serde(default, rename = "grmn_rate_cls_code")]
+    pub grmn_rate_cls_code: String,
+    /// ELW 발행 여부
+    #[serde(default, rename = "elw_pblc_yn")]
+    pub elw_pblc_yn: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 대비 거래량 비율
+    #[serde(default, rename = "prdy_vrss_vol_rate")]
+    pub prdy_vrss_vol_rate: Decimal,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 주식 상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// 주식 하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 가중 평균 주식 가격
+    #[serde(default, rename = "wghn_avrg_stck_prc")]
+    pub wghn_avrg_stck_prc: String,
+    /// HTS 외국인 소진율
+    #[serde(default, rename = "hts_frgn_ehrt")]
+    pub hts_frgn_ehrt: String,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 프로그램매매 순매수 수량
+    #[serde(default, rename = "pgtr_ntby_qty")]
+    pub pgtr_ntby_qty: Decimal,
+    /// 피벗 2차 디저항 가격
+    #[serde(default, rename = "pvt_scnd_dmrs_prc")]
+    pub pvt_scnd_dmrs_prc: String,
+    /// 피벗 1차 디저항 가격
+    #[serde(default, rename = "pvt_frst_dmrs_prc")]
+    pub pvt_frst_dmrs_prc: String,
+    /// 피벗 포인트 값
+    #[serde(default, rename = "pvt_pont_val")]
+    pub pvt_pont_val: Decimal,
+    /// 피벗 1차 디지지 가격
+    #[serde(default, rename = "pvt_frst_dmsp_prc")]
+    pub pvt_frst_dmsp_prc: String,
+    /// 피벗 2차 디지지 가격
+    #[serde(default, rename = "pvt_scnd_dmsp_prc")]
+    pub pvt_scnd_dmsp_prc: String,
+    /// 디저항 값
+    #[serde(default, rename = "dmrs_val")]
+    pub dmrs_val: Decimal,
+    /// 디지지 값
+    #[serde(default, rename = "dmsp_val")]
+    pub dmsp_val: Decimal,
+    /// 자본금
+    #[serde(default, rename = "cpfn")]
+    pub cpfn: String,
+    /// 제한 폭 가격
+    #[serde(default, rename = "rstc_wdth_prc")]
+    pub rstc_wdth_prc: String,
+    /// 주식 액면가
+    #[serde(default, rename = "stck_fcam")]
+    pub stck_fcam: String,
+    /// 주식 대용가
+    #[serde(default, rename = "stck_sspr")]
+    pub stck_sspr: String,
+    /// 호가단위
+    #[serde(default, rename = "aspr_unit")]
+    pub aspr_unit: String,
+    /// HTS 매매 수량 단위 값
+    #[serde(default, rename = "hts_deal_qty_unit_val")]
+    pub hts_deal_qty_unit_val: Decimal,
+    /// 상장 주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// HTS 시가총액
+    #[serde(default, rename = "hts_avls")]
+    pub hts_avls: String,
+    /// PER
+    #[serde(default, rename = "per")]
+    pub per: String,
+    /// PBR
+    #[serde(default, rename = "pbr")]
+    pub pbr: String,
+    /// 결산 월
+    #[serde(default, rename = "stac_month")]
+    pub stac_month: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "vol_tnrt")]
+    pub vol_tnrt: String,
+    /// EPS
+    #[serde(default, rename = "eps")]
+    pub eps: String,
+    /// BPS
+    #[serde(default, rename = "bps")]
+    pub bps: String,
+    /// 250일 최고가
+    #[serde(default, rename = "d250_hgpr")]
+    pub d250_hgpr: String,
+    /// 250일 최고가 일자
+    #[serde(default, rename = "d250_hgpr_date")]
+    pub d250_hgpr_date: String,
+    /// 250일 최고가 대비 현재가 비율
+    #[serde(default, rename = "d250_hgpr_vrss_prpr_rate")]
+    pub d250_hgpr_vrss_prpr_rate: Decimal,
+    /// 250일 최저가
+    #[serde(default, rename = "d250_lwpr")]
+    pub d250_lwpr: String,
+    /// 250일 최저가 일자
+    #[serde(default, rename = "d250_lwpr_date")]
+    pub d250_lwpr_date: String,
+    /// 250일 최저가 대비 현재가 비율
+    #[serde(default, rename = "d250_lwpr_vrss_prpr_rate")]
+    pub d250_lwpr_vrss_prpr_rate: Decimal,
+    /// 주식 연중 최고가
+    #[serde(default, rename = "stck_dryy_hgpr")]
+    pub stck_dryy_hgpr: String,
+    /// 연중 최고가 대비 현재가 비율
+    #[serde(default, rename = "dryy_hgpr_vrss_prpr_rate")]
+    pub dryy_hgpr_vrss_prpr_rate: Decimal,
+    /// 연중 최고가 일자
+    #[serde(default, rename = "dryy_hgpr_date")]
+    pub dryy_hgpr_date: String,
+    /// 주식 연중 최저가
+    #[serde(default, rename = "stck_dryy_lwpr")]
+    pub stck_dryy_lwpr: String,
+    /// 연중 최저가 대비 현재가 비율
+    #[serde(default, rename = "dryy_lwpr_vrss_prpr_rate")]
+    pub dryy_lwpr_vrss_prpr_rate: Decimal,
+    /// 연중 최저가 일자
+    #[serde(default, rename = "dryy_lwpr_date")]
+    pub dryy_lwpr_date: String,
+    /// 52주일 최고가
+    #[serde(default, rename = "w52_hgpr")]
+    pub w52_hgpr: String,
+    /// 52주일 최고가 대비 현재가 대비
+    #[serde(default, rename = "w52_hgpr_vrss_prpr_ctrt")]
+    pub w52_hgpr_vrss_prpr_ctrt: String,
+    /// 52주일 최고가 일자
+    #[serde(default, rename = "w52_hgpr_date")]
+    pub w52_hgpr_date: String,
+    /// 52주일 최저가
+    #[serde(default, rename = "w52_lwpr")]
+    pub w52_lwpr: String,
+    /// 52주일 최저가 대비 현재가 대비
+    #[serde(default, rename = "w52_lwpr_vrss_prpr_ctrt")]
+    pub w52_lwpr_vrss_prpr_ctrt: String,
+    /// 52주일 최저가 일자
+    #[serde(default, rename = "w52_lwpr_date")]
+    pub w52_lwpr_date: String,
+    /// 전체 융자 잔고 비율
+    #[serde(default, rename = "whol_loan_rmnd_rate")]
+    pub whol_loan_rmnd_rate: Decimal,
+    /// 공매도가능여부
+    #[serde(default, rename = "ssts_yn")]
+    pub ssts_yn: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 액면가 통화명
+    #[serde(default, rename = "fcam_cnnm")]
+    pub fcam_cnnm: String,
+    /// 자본금 통화명
+    #[serde(default, rename = "cpfn_cnnm")]
+    pub cpfn_cnnm: String,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 외국인 보유 수량
+    #[serde(default, rename = "frgn_hldn_qty")]
+    pub frgn_hldn_qty: Decimal,
+    /// VI적용구분코드
+    #[serde(default, rename = "vi_cls_code")]
+    pub vi_cls_code: String,
+    /// 시간외단일가VI적용구분코드
+    #[serde(default, rename = "ovtm_vi_cls_code")]
+    pub ovtm_vi_cls_code: String,
+    /// 최종 공매도 체결 수량
+    #[serde(default, rename = "last_ssts_cntg_qty")]
+    pub last_ssts_cntg_qty: Decimal,
+    /// 투자유의여부
+    #[serde(default, rename = "invt_caful_yn")]
+    pub invt_caful_yn: String,
+    /// 시장경고코드
+    #[serde(default, rename = "mrkt_warn_cls_code")]
+    pub mrkt_warn_cls_code: String,
+    /// 단기과열여부
+    #[serde(default, rename = "short_over_yn")]
+    pub short_over_yn: String,
+    /// 정리매매여부
+    #[serde(default, rename = "sltr_yn")]
+    pub sltr_yn: String,
+    /// 관리종목여부
+    #[serde(default, rename = "mang_issu_cls_code")]
+    pub mang_issu_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시세[v1_국내주식-008]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquirePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquirePriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquirePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 시세2[v1_국내주식-054]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquirePrice2OutputItem {
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 신 고가 저가 구분 코드
+    #[serde(default, rename = "new_hgpr_lwpr_cls_code")]
+    pub new_hgpr_lwpr_cls_code: String,
+    /// 상하한가 구분 코드
+    #[serde(default, rename = "mxpr_llam_cls_code")]
+    pub mxpr_llam_cls_code: String,
+    /// 신용 가능 여부
+    #[serde(default, rename = "crdt_able_yn")]
+    pub crdt_able_yn: String,
+    /// 주식 상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// ELW 발행 여부
+    #[serde(default, rename = "elw_pblc_yn")]
+    pub elw_pblc_yn: String,
+    /// 전일 종가 대비 시가2 비율
+    #[serde(default, rename = "prdy_clpr_vrss_oprc_rate")]
+    pub prdy_clpr_vrss_oprc_rate: Decimal,
+    /// 신용 비율
+    #[serde(default, rename = "crdt_rate")]
+    pub crdt_rate: Decimal,
+    /// 증거금 비율
+    #[serde(default, rename = "marg_rate")]
+    pub marg_rate: Decimal,
+    /// 최저가 대비 현재가
+    #[serde(default, rename = "lwpr_vrss_prpr")]
+    pub lwpr_vrss_prpr: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "lwpr_vrss_prpr_sign")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 전일 종가 대비 최저가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_lwpr_rate")]
+    pub prdy_clpr_vrss_lwpr_rate: Decimal,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 최고가 대비 현재가
+    #[serde(default, rename = "hgpr_vrss_prpr")]
+    pub hgpr_vrss_prpr: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "hgpr_vrss_prpr_sign")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 전일 종가 대비 최고가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_hgpr_rate")]
+    pub prdy_clpr_vrss_hgpr_rate: Decimal,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 시가2 대비 현재가
+    #[serde(default, rename = "oprc_vrss_prpr")]
+    pub oprc_vrss_prpr: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "oprc_vrss_prpr_sign")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 관리 종목 여부
+    #[serde(default, rename = "mang_issu_yn")]
+    pub mang_issu_yn: String,
+    /// 동시호가배분처리코드
+    #[serde(default, rename = "divi_app_cls_code")]
+    pub divi_app_cls_code: String,
+    /// 단기과열여부
+    #[serde(default, rename = "short_over_yn")]
+    pub short_over_yn: String,
+    /// 시장경고코드
+    #[serde(default, rename = "mrkt_warn_cls_code")]
+    pub mrkt_warn_cls_code: String,
+    /// 투자유의여부
+    #[serde(default, rename = "invt_caful_yn")]
+    pub invt_caful_yn: String,
+    /// 이상급등여부
+    #[serde(default, rename = "stange_runup_yn")]
+    pub stange_runup_yn: String,
+    /// 공매도과열 여부
+    #[serde(default, rename = "ssts_hot_yn")]
+    pub ssts_hot_yn: String,
+    /// 저유동성 종목 여부
+    #[serde(default, rename = "low_current_yn")]
+    pub low_current_yn: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "vi_cls_code")]
+    pub vi_cls_code: String,
+    /// 단기과열구분코드
+    #[serde(default, rename = "short_over_cls_code")]
+    pub short_over_cls_code: String,
+    /// 주식 하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 신규 상장 구분 명
+    #[serde(default, rename = "new_lstn_cls_name")]
+    pub new_lstn_cls_name: String,
+    /// 임의 매매 구분 명
+    #[serde(default, rename = "vlnt_deal_cls_name")]
+    pub vlnt_deal_cls_name: String,
+    /// 락 구분 이름
+    #[serde(default, rename = "flng_cls_name")]
+    pub flng_cls_name: String,
+    /// 재평가 종목 사유 명
+    #[serde(default, rename = "revl_issu_reas_name")]
+    pub revl_issu_reas_name: String,
+    /// 시장 경고 구분 명
+    #[serde(default, rename = "mrkt_warn_cls_name")]
+    pub mrkt_warn_cls_name: String,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 불성실 공시 여부
+    #[serde(default, rename = "insn_pbnt_yn")]
+    pub insn_pbnt_yn: String,
+    /// 액면가 변경 구분 명
+    #[serde(default, rename = "fcam_mod_cls_name")]
+    pub fcam_mod_cls_name: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 대비 거래량 비율
+    #[serde(default, rename = "prdy_vrss_vol_rate")]
+    pub prdy_vrss_vol_rate: Decimal,
+    /// 업종 한글 종목명
+    #[serde(default, rename = "bstp_kor_isnm")]
+    pub bstp_kor_isnm: String,
+    /// 정리매매 여부
+    #[serde(default, rename = "sltr_yn")]
+    pub sltr_yn: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "trht_yn")]
+    pub trht_yn: String,
+    /// 시가 범위 연장 여부
+    #[serde(default, rename = "oprc_rang_cont_yn")]
+    pub oprc_rang_cont_yn: String,
+    /// 임의 종료 구분 코드
+    #[serde(default, rename = "vlnt_fin_cls_code")]
+    pub vlnt_fin_cls_code: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시세2[v1_국내주식-054]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquirePrice2Response {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquirePrice2OutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquirePrice2Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 체결[v1_국내주식-009]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireCcnlOutputItem {
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 당일 체결강도
+    #[serde(default, rename = "tday_rltv")]
+    pub tday_rltv: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 체결[v1_국내주식-009]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 일자별[v1_국내주식-010]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyPriceOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 대비 거래량 비율
+    #[serde(default, rename = "prdy_vrss_vol_rate")]
+    pub prdy_vrss_vol_rate: Decimal,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// HTS 외국인 소진율
+    #[serde(default, rename = "hts_frgn_ehrt")]
+    pub hts_frgn_ehrt: String,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 락 구분 코드
+    #[serde(default, rename = "flng_cls_code")]
+    pub flng_cls_code: String,
+    /// 누적 분할 비율
+    #[serde(default, rename = "acml_prtt_rate")]
+    pub acml_prtt_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 일자별[v1_국내주식-010]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireDailyPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireDailyPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireDailyPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 호가/예상체결[v1_국내주식-011]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireAskingPriceExpCcnOutput1Item {
+    /// 호가 접수 시간
+    #[serde(default, rename = "aspr_acpt_hour")]
+    pub aspr_acpt_hour: String,
+    /// 매도호가1
+    #[serde(default, rename = "askp1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "askp2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "askp3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "askp4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "askp5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "askp6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "askp7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "askp8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "askp9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "askp10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "bidp1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "bidp2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "bidp3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "bidp4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "bidp5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "bidp6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "bidp7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "bidp8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "bidp9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "bidp10")]
+    pub bidp10: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "askp_rsqn1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "askp_rsqn2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "askp_rsqn3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "askp_rsqn4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "askp_rsqn5")]
+    pub askp_rsqn5: String,
+    /// 매도호가 잔량6
+    #[serde(default, rename = "askp_rsqn6")]
+    pub askp_rsqn6: String,
+    /// 매도호가 잔량7
+    #[serde(default, rename = "askp_rsqn7")]
+    pub askp_rsqn7: String,
+    /// 매도호가 잔량8
+    #[serde(default, rename = "askp_rsqn8")]
+    pub askp_rsqn8: String,
+    /// 매도호가 잔량9
+    #[serde(default, rename = "askp_rsqn9")]
+    pub askp_rsqn9: String,
+    /// 매도호가 잔량10
+    #[serde(default, rename = "askp_rsqn10")]
+    pub askp_rsqn10: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "bidp_rsqn1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "bidp_rsqn2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "bidp_rsqn3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "bidp_rsqn4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "bidp_rsqn5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가 잔량6
+    #[serde(default, rename = "bidp_rsqn6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가 잔량7
+    #[serde(default, rename = "bidp_rsqn7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가 잔량8
+    #[serde(default, rename = "bidp_rsqn8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가 잔량9
+    #[serde(default, rename = "bidp_rsqn9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가 잔량10
+    #[serde(default, rename = "bidp_rsqn10")]
+    pub bidp_rsqn10: String,
+    /// 매도호가 잔량 증감1
+    #[serde(default, rename = "askp_rsqn_icdc1")]
+    pub askp_rsqn_icdc1: String,
+    /// 매도호가 잔량 증감2
+    #[serde(default, rename = "askp_rsqn_icdc2")]
+    pub askp_rsqn_icdc2: String,
+    /// 매도호가 잔량 증감3
+    #[serde(default, rename = "askp_rsqn_icdc3")]
+    pub askp_rsqn_icdc3: String,
+    /// 매도호가 잔량 증감4
+    #[serde(default, rename = "askp_rsqn_icdc4")]
+    pub askp_rsqn_icdc4: String,
+    /// 매도호가 잔량 증감5
+    #[serde(default, rename = "askp_rsqn_icdc5")]
+    pub askp_rsqn_icdc5: String,
+    /// 매도호가 잔량 증감6
+    #[serde(default, rename = "askp_rsqn_icdc6")]
+    pub askp_rsqn_icdc6: String,
+    /// 매도호가 잔량 증감7
+    #[serde(default, rename = "askp_rsqn_icdc7")]
+    pub askp_rsqn_icdc7: String,
+    /// 매도호가 잔량 증감8
+    #[serde(default, rename = "askp_rsqn_icdc8")]
+    pub askp_rsqn_icdc8: String,
+    /// 매도호가 잔량 증감9
+    #[serde(default, rename = "askp_rsqn_icdc9")]
+    pub askp_rsqn_icdc9: String,
+    /// 매도호가 잔량 증감10
+    #[serde(default, rename = "askp_rsqn_icdc10")]
+    pub askp_rsqn_icdc10: String,
+    /// 매수호가 잔량 증감1
+    #[serde(default, rename = "bidp_rsqn_icdc1")]
+    pub bidp_rsqn_icdc1: String,
+    /// 매수호가 잔량 증감2
+    #[serde(default, rename = "bidp_rsqn_icdc2")]
+    pub bidp_rsqn_icdc2: String,
+    /// 매수호가 잔량 증감3
+    #[serde(default, rename = "bidp_rsqn_icdc3")]
+    pub bidp_rsqn_icdc3: String,
+    /// 매수호가 잔량 증감4
+    #[serde(default, rename = "bidp_rsqn_icdc4")]
+    pub bidp_rsqn_icdc4: String,
+    /// 매수호가 잔량 증감5
+    #[serde(default, rename = "bidp_rsqn_icdc5")]
+    pub bidp_rsqn_icdc5: String,
+    /// 매수호가 잔량 증감6
+    #[serde(default, rename = "bidp_rsqn_icdc6")]
+    pub bidp_rsqn_icdc6: String,
+    /// 매수호가 잔량 증감7
+    #[serde(default, rename = "bidp_rsqn_icdc7")]
+    pub bidp_rsqn_icdc7: String,
+    /// 매수호가 잔량 증감8
+    #[serde(default, rename = "bidp_rsqn_icdc8")]
+    pub bidp_rsqn_icdc8: String,
+    /// 매수호가 잔량 증감9
+    #[serde(default, rename = "bidp_rsqn_icdc9")]
+    pub bidp_rsqn_icdc9: String,
+    /// 매수호가 잔량 증감10
+    #[serde(default, rename = "bidp_rsqn_icdc10")]
+    pub bidp_rsqn_icdc10: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "total_askp_rsqn_icdc")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "total_bidp_rsqn_icdc")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외 총 매도호가 증감
+    #[serde(default, rename = "ovtm_total_askp_icdc")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외 총 매수호가 증감
+    #[serde(default, rename = "ovtm_total_bidp_icdc")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "ovtm_total_askp_rsqn")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "ovtm_total_bidp_rsqn")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 순매수 호가 잔량
+    #[serde(default, rename = "ntby_aspr_rsqn")]
+    pub ntby_aspr_rsqn: String,
+    /// 신 장운영 구분 코드
+    #[serde(default, rename = "new_mkop_cls_code")]
+    pub new_mkop_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 호가/예상체결[v1_국내주식-011]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireAskingPriceExpCcnOutput2Item {
+    /// 예상 장운영 구분 코드
+    #[serde(default, rename = "antc_mkop_cls_code")]
+    pub antc_mkop_cls_code: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 예상 체결가
+    #[serde(default, rename = "antc_cnpr")]
+    pub antc_cnpr: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 대비
+    #[serde(default, rename = "antc_cntg_vrss")]
+    pub antc_cntg_vrss: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 예상 거래량
+    #[serde(default, rename = "antc_vol")]
+    pub antc_vol: Decimal,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "vi_cls_code")]
+    pub vi_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 호가/예상체결[v1_국내주식-011]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireAskingPriceExpCcnResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireAskingPriceExpCcnOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireAskingPriceExpCcnOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireAskingPriceExpCcnResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 투자자[v1_국내주식-012]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 개인 순매수 수량
+    #[serde(default, rename = "prsn_ntby_qty")]
+    pub prsn_ntby_qty: Decimal,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 기관계 순매수 수량
+    #[serde(default, rename = "orgn_ntby_qty")]
+    pub orgn_ntby_qty: Decimal,
+    /// 개인 순매수 거래 대금
+    #[serde(default, rename = "prsn_ntby_tr_pbmn")]
+    pub prsn_ntby_tr_pbmn: String,
+    /// 외국인 순매수 거래 대금
+    #[serde(default, rename = "frgn_ntby_tr_pbmn")]
+    pub frgn_ntby_tr_pbmn: String,
+    /// 기관계 순매수 거래 대금
+    #[serde(default, rename = "orgn_ntby_tr_pbmn")]
+    pub orgn_ntby_tr_pbmn: String,
+    /// 개인 매수2 거래량
+    #[serde(default, rename = "prsn_shnu_vol")]
+    pub prsn_shnu_vol: Decimal,
+    /// 외국인 매수2 거래량
+    #[serde(default, rename = "frgn_shnu_vol")]
+    pub frgn_shnu_vol: Decimal,
+    /// 기관계 매수2 거래량
+    #[serde(default, rename = "orgn_shnu_vol")]
+    pub orgn_shnu_vol: Decimal,
+    /// 개인 매수2 거래 대금
+    #[serde(default, rename = "prsn_shnu_tr_pbmn")]
+    pub prsn_shnu_tr_pbmn: String,
+    /// 외국인 매수2 거래 대금
+    #[serde(default, rename = "frgn_shnu_tr_pbmn")]
+    pub frgn_shnu_tr_pbmn: String,
+    /// 기관계 매수2 거래 대금
+    #[serde(default, rename = "orgn_shnu_tr_pbmn")]
+    pub orgn_shnu_tr_pbmn: String,
+    /// 개인 매도 거래량
+    #[serde(default, rename = "prsn_seln_vol")]
+    pub prsn_seln_vol: Decimal,
+    /// 외국인 매도 거래량
+    #[serde(default, rename = "frgn_seln_vol")]
+    pub frgn_seln_vol: Decimal,
+    /// 기관계 매도 거래량
+    #[serde(default, rename = "orgn_seln_vol")]
+    pub orgn_seln_vol: Decimal,
+    /// 개인 매도 거래 대금
+    #[serde(default, rename = "prsn_seln_tr_pbmn")]
+    pub prsn_seln_tr_pbmn: String,
+    /// 외국인 매도 거래 대금
+    #[serde(default, rename = "frgn_seln_tr_pbmn")]
+    pub frgn_seln_tr_pbmn: String,
+    /// 기관계 매도 거래 대금
+    #[serde(default, rename = "orgn_seln_tr_pbmn")]
+    pub orgn_seln_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 투자자[v1_국내주식-012]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireInvestorResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireInvestorOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireInvestorResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 회원사[v1_국내주식-013]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireMemberOutputItem {
+    /// 매도 회원사 번호1
+    #[serde(default, rename = "seln_mbcr_no1")]
+    pub seln_mbcr_no1: String,
+    /// 매도 회원사 번호2
+    #[serde(default, rename = "seln_mbcr_no2")]
+    pub seln_mbcr_no2: String,
+    /// 매도 회원사 번호3
+    #[serde(default, rename = "seln_mbcr_no3")]
+    pub seln_mbcr_no3: String,
+    /// 매도 회원사 번호4
+    #[serde(default, rename = "seln_mbcr_no4")]
+    pub seln_mbcr_no4: String,
+    /// 매도 회원사 번호5
+    #[serde(default, rename = "seln_mbcr_no5")]
+    pub seln_mbcr_no5: String,
+    /// 매도 회원사 명1
+    #[serde(default, rename = "seln_mbcr_name1")]
+    pub seln_mbcr_name1: String,
+    /// 매도 회원사 명2
+    #[serde(default, rename = "seln_mbcr_name2")]
+    pub seln_mbcr_name2: String,
+    /// 매도 회원사 명3
+    #[serde(default, rename = "seln_mbcr_name3")]
+    pub seln_mbcr_name3: String,
+    /// 매도 회원사 명4
+    #[serde(default, rename = "seln_mbcr_name4")]
+    pub seln_mbcr_name4: String,
+    /// 매도 회원사 명5
+    #[serde(default, rename = "seln_mbcr_name5")]
+    pub seln_mbcr_name5: String,
+    /// 총 매도 수량1
+    #[serde(default, rename = "total_seln_qty1")]
+    pub total_seln_qty1: String,
+    /// 총 매도 수량2
+    #[serde(default, rename = "total_seln_qty2")]
+    pub total_seln_qty2: String,
+    /// 총 매도 수량3
+    #[serde(default, rename = "total_seln_qty3")]
+    pub total_seln_qty3: String,
+    /// 총 매도 수량4
+    #[serde(default, rename = "total_seln_qty4")]
+    pub total_seln_qty4: String,
+    /// 총 매도 수량5
+    #[serde(default, rename = "total_seln_qty5")]
+    pub total_seln_qty5: String,
+    /// 매도 회원사 비중1
+    #[serde(default, rename = "seln_mbcr_rlim1")]
+    pub seln_mbcr_rlim1: String,
+    /// 매도 회원사 비중2
+    #[serde(default, rename = "seln_mbcr_rlim2")]
+    pub seln_mbcr_rlim2: String,
+    /// 매도 회원사 비중3
+    #[serde(default, rename = "seln_mbcr_rlim3")]
+    pub seln_mbcr_rlim3: String,
+    /// 매도 회원사 비중4
+    #[serde(default, rename = "seln_mbcr_rlim4")]
+    pub seln_mbcr_rlim4: String,
+    /// 매도 회원사 비중5
+    #[serde(default, rename = "seln_mbcr_rlim5")]
+    pub seln_mbcr_rlim5: String,
+    /// 매도 수량 증감1
+    #[serde(default, rename = "seln_qty_icdc1")]
+    pub seln_qty_icdc1: String,
+    /// 매도 수량 증감2
+    #[serde(default, rename = "seln_qty_icdc2")]
+    pub seln_qty_icdc2: String,
+    /// 매도 수량 증감3
+    #[serde(default, rename = "seln_qty_icdc3")]
+    pub seln_qty_icdc3: String,
+    /// 매도 수량 증감4
+    #[serde(default, rename = "seln_qty_icdc4")]
+    pub seln_qty_icdc4: String,
+    /// 매도 수량 증감5
+    #[serde(default, rename = "seln_qty_icdc5")]
+    pub seln_qty_icdc5: String,
+    /// 매수2 회원사 번호1
+    #[serde(default, rename = "shnu_mbcr_no1")]
+    pub shnu_mbcr_no1: String,
+    /// 매수2 회원사 번호2
+    #[serde(default, rename = "shnu_mbcr_no2")]
+    pub shnu_mbcr_no2: String,
+    /// 매수2 회원사 번호3
+    #[serde(default, rename = "shnu_mbcr_no3")]
+    pub shnu_mbcr_no3: String,
+    /// 매수2 회원사 번호4
+    #[serde(default, rename = "shnu_mbcr_no4")]
+    pub shnu_mbcr_no4: String,
+    /// 매수2 회원사 번호5
+    #[serde(default, rename = "shnu_mbcr_no5")]
+    pub shnu_mbcr_no5: String,
+    /// 매수2 회원사 명1
+    #[serde(default, rename = "shnu_mbcr_name1")]
+    pub shnu_mbcr_name1: String,
+    /// 매수2 회원사 명2
+    #[serde(default, rename = "shnu_mbcr_name2")]
+    pub shnu_mbcr_name2: String,
+    /// 매수2 회원사 명3
+    #[serde(default, rename = "shnu_mbcr_name3")]
+    pub shnu_mbcr_name3: String,
+    /// 매수2 회원사 명4
+    #[serde(default, rename = "shnu_mbcr_name4")]
+    pub shnu_mbcr_name4: String,
+    /// 매수2 회원사 명5
+    #[serde(default, rename = "shnu_mbcr_name5")]
+    pub shnu_mbcr_name5: String,
+    /// 총 매수2 수량1
+    #[serde(default, rename = "total_shnu_qty1")]
+    pub total_shnu_qty1: String,
+    /// 총 매수2 수량2
+    #[serde(default, rename = "total_shnu_qty2")]
+    pub total_shnu_qty2: String,
+    /// 총 매수2 수량3
+    #[serde(default, rename = "total_shnu_qty3")]
+    pub total_shnu_qty3: String,
+    /// 총 매수2 수량4
+    #[serde(default, rename = "total_shnu_qty4")]
+    pub total_shnu_qty4: String,
+    /// 총 매수2 수량5
+    #[serde(default, rename = "total_shnu_qty5")]
+    pub total_shnu_qty5: String,
+    /// 매수2 회원사 비중1
+    #[serde(default, rename = "shnu_mbcr_rlim1")]
+    pub shnu_mbcr_rlim1: String,
+    /// 매수2 회원사 비중2
+    #[serde(default, rename = "shnu_mbcr_rlim2")]
+    pub shnu_mbcr_rlim2: String,
+    /// 매수2 회원사 비중3
+    #[serde(default, rename = "shnu_mbcr_rlim3")]
+    pub shnu_mbcr_rlim3: String,
+    /// 매수2 회원사 비중4
+    #[serde(default, rename = "shnu_mbcr_rlim4")]
+    pub shnu_mbcr_rlim4: String,
+    /// 매수2 회원사 비중5
+    #[serde(default, rename = "shnu_mbcr_rlim5")]
+    pub shnu_mbcr_rlim5: String,
+    /// 매수2 수량 증감1
+    #[serde(default, rename = "shnu_qty_icdc1")]
+    pub shnu_qty_icdc1: String,
+    /// 매수2 수량 증감2
+    #[serde(default, rename = "shnu_qty_icdc2")]
+    pub shnu_qty_icdc2: String,
+    /// 매수2 수량 증감3
+    #[serde(default, rename = "shnu_qty_icdc3")]
+    pub shnu_qty_icdc3: String,
+    /// 매수2 수량 증감4
+    #[serde(default, rename = "shnu_qty_icdc4")]
+    pub shnu_qty_icdc4: String,
+    /// 매수2 수량 증감5
+    #[serde(default, rename = "shnu_qty_icdc5")]
+    pub shnu_qty_icdc5: String,
+    /// 외국계 총 매도 수량
+    #[serde(default, rename = "glob_total_seln_qty")]
+    pub glob_total_seln_qty: Decimal,
+    /// 외국계 매도 비중
+    #[serde(default, rename = "glob_seln_rlim")]
+    pub glob_seln_rlim: String,
+    /// 외국계 순매수 수량
+    #[serde(default, rename = "glob_ntby_qty")]
+    pub glob_ntby_qty: Decimal,
+    /// 외국계 총 매수2 수량
+    #[serde(default, rename = "glob_total_shnu_qty")]
+    pub glob_total_shnu_qty: Decimal,
+    /// 외국계 매수2 비중
+    #[serde(default, rename = "glob_shnu_rlim")]
+    pub glob_shnu_rlim: String,
+    /// 매도 회원사 외국계 여부1
+    #[serde(default, rename = "seln_mbcr_glob_yn_1")]
+    pub seln_mbcr_glob_yn_1: String,
+    /// 매도 회원사 외국계 여부2
+    #[serde(default, rename = "seln_mbcr_glob_yn_2")]
+    pub seln_mbcr_glob_yn_2: String,
+    /// 매도 회원사 외국계 여부3
+    #[serde(default, rename = "seln_mbcr_glob_yn_3")]
+    pub seln_mbcr_glob_yn_3: String,
+    /// 매도 회원사 외국계 여부4
+    #[serde(default, rename = "seln_mbcr_glob_yn_4")]
+    pub seln_mbcr_glob_yn_4: String,
+    /// 매도 회원사 외국계 여부5
+    #[serde(default, rename = "seln_mbcr_glob_yn_5")]
+    pub seln_mbcr_glob_yn_5: String,
+    /// 매수2 회원사 외국계 여부1
+    #[serde(default, rename = "shnu_mbcr_glob_yn_1")]
+    pub shnu_mbcr_glob_yn_1: String,
+    /// 매수2 회원사 외국계 여부2
+    #[serde(default, rename = "shnu_mbcr_glob_yn_2")]
+    pub shnu_mbcr_glob_yn_2: String,
+    /// 매수2 회원사 외국계 여부3
+    #[serde(default, rename = "shnu_mbcr_glob_yn_3")]
+    pub shnu_mbcr_glob_yn_3: String,
+    /// 매수2 회원사 외국계 여부4
+    #[serde(default, rename = "shnu_mbcr_glob_yn_4")]
+    pub shnu_mbcr_glob_yn_4: String,
+    /// 매수2 회원사 외국계 여부5
+    #[serde(default, rename = "shnu_mbcr_glob_yn_5")]
+    pub shnu_mbcr_glob_yn_5: String,
+    /// 외국계 총 매도 수량 증감
+    #[serde(default, rename = "glob_total_seln_qty_icdc")]
+    pub glob_total_seln_qty_icdc: String,
+    /// 외국계 총 매수2 수량 증감
+    #[serde(default, rename = "glob_total_shnu_qty_icdc")]
+    pub glob_total_shnu_qty_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 회원사[v1_국내주식-013]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireMemberResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireMemberOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireMemberResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식기간별시세(일/주/월/년)[v1_국내주식-016]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyItemchartpriceOutput1Item {
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 주식 상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// 주식 하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 주식 전일 시가
+    #[serde(default, rename = "stck_prdy_oprc")]
+    pub stck_prdy_oprc: String,
+    /// 주식 전일 최고가
+    #[serde(default, rename = "stck_prdy_hgpr")]
+    pub stck_prdy_hgpr: String,
+    /// 주식 전일 최저가
+    #[serde(default, rename = "stck_prdy_lwpr")]
+    pub stck_prdy_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 전일 대비 거래량
+    #[serde(default, rename = "prdy_vrss_vol")]
+    pub prdy_vrss_vol: Decimal,
+    /// 거래량 회전율
+    #[serde(default, rename = "vol_tnrt")]
+    pub vol_tnrt: String,
+    /// 주식 액면가
+    #[serde(default, rename = "stck_fcam")]
+    pub stck_fcam: String,
+    /// 상장 주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 자본금
+    #[serde(default, rename = "cpfn")]
+    pub cpfn: String,
+    /// HTS 시가총액
+    #[serde(default, rename = "hts_avls")]
+    pub hts_avls: String,
+    /// PER
+    #[serde(default, rename = "per")]
+    pub per: String,
+    /// EPS
+    #[serde(default, rename = "eps")]
+    pub eps: String,
+    /// PBR
+    #[serde(default, rename = "pbr")]
+    pub pbr: String,
+    /// 전체 융자 잔고 비율
+    #[serde(default, rename = "itewhol_loan_rmnd_ratem")]
+    pub itewhol_loan_rmnd_ratem: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식기간별시세(일/주/월/년)[v1_국내주식-016]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyItemchartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 락 구분 코드
+    #[serde(default, rename = "flng_cls_code")]
+    pub flng_cls_code: String,
+    /// 분할 비율
+    #[serde(default, rename = "prtt_rate")]
+    pub prtt_rate: Decimal,
+    /// 변경 여부
+    #[serde(default, rename = "mod_yn")]
+    pub mod_yn: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 재평가사유코드
+    #[serde(default, rename = "revl_issu_reas")]
+    pub revl_issu_reas: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식기간별시세(일/주/월/년)[v1_국내주식-016]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireDailyItemchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireDailyItemchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireDailyItemchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireDailyItemchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식당일분봉조회[v1_국내주식-022]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemchartpriceOutput1Item {
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 전일대비 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식당일분봉조회[v1_국내주식-022]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemchartpriceOutput2Item {
+    /// 주식 영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 주식 시가
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적 거래대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식당일분봉조회[v1_국내주식-022]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireTimeItemchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireTimeItemchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireTimeItemchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireTimeItemchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식일별분봉조회 [국내주식-213]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeDailychartpriceOutput1Item {
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식일별분봉조회 [국내주식-213]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeDailychartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식일별분봉조회 [국내주식-213]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireTimeDailychartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireTimeDailychartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireTimeDailychartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireTimeDailychartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 당일시간대별체결[v1_국내주식-023]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemconclusionOutput1Item {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 당일시간대별체결[v1_국내주식-023]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeItemconclusionOutput2Item {
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_pbpr")]
+    pub stck_pbpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 당일 체결강도
+    #[serde(default, rename = "tday_rltv")]
+    pub tday_rltv: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결량
+    #[serde(default, rename = "cnqn")]
+    pub cnqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 당일시간대별체결[v1_국내주식-023]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireTimeItemconclusionResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireTimeItemconclusionOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireTimeItemconclusionOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireTimeItemconclusionResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 시간외일자별주가[v1_국내주식-026]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyOvertimepriceOutput1Item {
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 시간외 단일가 거래 대금
+    #[serde(default, rename = "ovtm_untp_tr_pbmn")]
+    pub ovtm_untp_tr_pbmn: String,
+    /// 시간외 단일가 상한가
+    #[serde(default, rename = "ovtm_untp_mxpr")]
+    pub ovtm_untp_mxpr: String,
+    /// 시간외 단일가 하한가
+    #[serde(default, rename = "ovtm_untp_llam")]
+    pub ovtm_untp_llam: String,
+    /// 시간외 단일가 시가2
+    #[serde(default, rename = "ovtm_untp_oprc")]
+    pub ovtm_untp_oprc: String,
+    /// 시간외 단일가 최고가
+    #[serde(default, rename = "ovtm_untp_hgpr")]
+    pub ovtm_untp_hgpr: String,
+    /// 시간외 단일가 최저가
+    #[serde(default, rename = "ovtm_untp_lwpr")]
+    pub ovtm_untp_lwpr: String,
+    /// 시간외 단일가 예상 체결가
+    #[serde(default, rename = "ovtm_untp_antc_cnpr")]
+    pub ovtm_untp_antc_cnpr: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss")]
+    pub ovtm_untp_antc_cntg_vrss: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss_sign")]
+    pub ovtm_untp_antc_cntg_vrss_sign: String,
+    /// 시간외 단일가 예상 체결 대비율
+    #[serde(default, rename = "ovtm_untp_antc_cntg_ctrt")]
+    pub ovtm_untp_antc_cntg_ctrt: String,
+    /// 시간외 단일가 예상 거래량
+    #[serde(default, rename = "ovtm_untp_antc_vol")]
+    pub ovtm_untp_antc_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시간외일자별주가[v1_국내주식-026]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyOvertimepriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 시간외 단일가 거래대금
+    #[serde(default, rename = "ovtm_untp_tr_pbmn")]
+    pub ovtm_untp_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시간외일자별주가[v1_국내주식-026]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireDailyOvertimepriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireDailyOvertimepriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireDailyOvertimepriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireDailyOvertimepriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 시간외시간별체결[v1_국내주식-025]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeOvertimeconclusionOutput1Item {
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 시간외 단일가 거래 대금
+    #[serde(default, rename = "ovtm_untp_tr_pbmn")]
+    pub ovtm_untp_tr_pbmn: String,
+    /// 시간외 단일가 상한가
+    #[serde(default, rename = "ovtm_untp_mxpr")]
+    pub ovtm_untp_mxpr: String,
+    /// 시간외 단일가 하한가
+    #[serde(default, rename = "ovtm_untp_llam")]
+    pub ovtm_untp_llam: String,
+    /// 시간외 단일가 시가2
+    #[serde(default, rename = "ovtm_untp_oprc")]
+    pub ovtm_untp_oprc: String,
+    /// 시간외 단일가 최고가
+    #[serde(default, rename = "ovtm_untp_hgpr")]
+    pub ovtm_untp_hgpr: String,
+    /// 시간외 단일가 최저가
+    #[serde(default, rename = "ovtm_untp_lwpr")]
+    pub ovtm_untp_lwpr: String,
+    /// 시간외 단일가 예상 체결가
+    #[serde(default, rename = "ovtm_untp_antc_cnpr")]
+    pub ovtm_untp_antc_cnpr: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss")]
+    pub ovtm_untp_antc_cntg_vrss: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss_sign")]
+    pub ovtm_untp_antc_cntg_vrss_sign: String,
+    /// 시간외 단일가 예상 체결 대비율
+    #[serde(default, rename = "ovtm_untp_antc_cntg_ctrt")]
+    pub ovtm_untp_antc_cntg_ctrt: String,
+    /// 시간외 단일가 예상 거래량
+    #[serde(default, rename = "ovtm_untp_antc_vol")]
+    pub ovtm_untp_antc_vol: Decimal,
+    /// 상한 부호
+    #[serde(default, rename = "uplm_sign")]
+    pub uplm_sign: String,
+    /// 하한 부호
+    #[serde(default, rename = "lslm_sign")]
+    pub lslm_sign: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시간외시간별체결[v1_국내주식-025]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeOvertimeconclusionOutput2Item {
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 시간외시간별체결[v1_국내주식-025]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireTimeOvertimeconclusionResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireTimeOvertimeconclusionOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireTimeOvertimeconclusionOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireTimeOvertimeconclusionResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외현재가[국내주식-076]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireOvertimePriceOutputItem {
+    /// 업종 한글 종목명
+    #[serde(default, rename = "bstp_kor_isnm")]
+    pub bstp_kor_isnm: String,
+    /// 관리 종목 구분 명
+    #[serde(default, rename = "mang_issu_cls_name")]
+    pub mang_issu_cls_name: String,
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 시간외 단일가 거래 대금
+    #[serde(default, rename = "ovtm_untp_tr_pbmn")]
+    pub ovtm_untp_tr_pbmn: String,
+    /// 시간외 단일가 상한가
+    #[serde(default, rename = "ovtm_untp_mxpr")]
+    pub ovtm_untp_mxpr: String,
+    /// 시간외 단일가 하한가
+    #[serde(default, rename = "ovtm_untp_llam")]
+    pub ovtm_untp_llam: String,
+    /// 시간외 단일가 시가2
+    #[serde(default, rename = "ovtm_untp_oprc")]
+    pub ovtm_untp_oprc: String,
+    /// 시간외 단일가 최고가
+    #[serde(default, rename = "ovtm_untp_hgpr")]
+    pub ovtm_untp_hgpr: String,
+    /// 시간외 단일가 최저가
+    #[serde(default, rename = "ovtm_untp_lwpr")]
+    pub ovtm_untp_lwpr: String,
+    /// 증거금 비율
+    #[serde(default, rename = "marg_rate")]
+    pub marg_rate: Decimal,
+    /// 시간외 단일가 예상 체결가
+    #[serde(default, rename = "ovtm_untp_antc_cnpr")]
+    pub ovtm_untp_antc_cnpr: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss")]
+    pub ovtm_untp_antc_cntg_vrss: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss_sign")]
+    pub ovtm_untp_antc_cntg_vrss_sign: String,
+    /// 시간외 단일가 예상 체결 대비율
+    #[serde(default, rename = "ovtm_untp_antc_cntg_ctrt")]
+    pub ovtm_untp_antc_cntg_ctrt: String,
+    /// 시간외 단일가 예상 체결량
+    #[serde(default, rename = "ovtm_untp_antc_cnqn")]
+    pub ovtm_untp_antc_cnqn: String,
+    /// 신용 가능 여부
+    #[serde(default, rename = "crdt_able_yn")]
+    pub crdt_able_yn: String,
+    /// 신규 상장 구분 명
+    #[serde(default, rename = "new_lstn_cls_name")]
+    pub new_lstn_cls_name: String,
+    /// 정리매매 여부
+    #[serde(default, rename = "sltr_yn")]
+    pub sltr_yn: String,
+    /// 관리 종목 여부
+    #[serde(default, rename = "mang_issu_yn")]
+    pub mang_issu_yn: String,
+    /// 시장 경고 구분 코드
+    #[serde(default, rename = "mrkt_warn_cls_code")]
+    pub mrkt_warn_cls_code: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "trht_yn")]
+    pub trht_yn: String,
+    /// 임의 매매 구분 명
+    #[serde(default, rename = "vlnt_deal_cls_name")]
+    pub vlnt_deal_cls_name: String,
+    /// 시간외 단일가 기준가
+    #[serde(default, rename = "ovtm_untp_sdpr")]
+    pub ovtm_untp_sdpr: String,
+    /// 시장 경구 구분 명
+    #[serde(default, rename = "mrkt_warn_cls_name")]
+    pub mrkt_warn_cls_name: String,
+    /// 재평가 종목 사유 명
+    #[serde(default, rename = "revl_issu_reas_name")]
+    pub revl_issu_reas_name: String,
+    /// 불성실 공시 여부
+    #[serde(default, rename = "insn_pbnt_yn")]
+    pub insn_pbnt_yn: String,
+    /// 락 구분 이름
+    #[serde(default, rename = "flng_cls_name")]
+    pub flng_cls_name: String,
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 시간외단일가VI적용구분코드
+    #[serde(default, rename = "ovtm_vi_cls_code")]
+    pub ovtm_vi_cls_code: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외현재가[국내주식-076]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireOvertimePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireOvertimePriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireOvertimePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외호가[국내주식-077]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireOvertimeAskingPriceOutput1Item {
+    /// 시간외 단일가 최종 시간
+    #[serde(default, rename = "ovtm_untp_last_hour")]
+    pub ovtm_untp_last_hour: String,
+    /// 시간외 단일가 매도호가1
+    #[serde(default, rename = "ovtm_untp_askp1")]
+    pub ovtm_untp_askp1: String,
+    /// 시간외 단일가 매도호가2
+    #[serde(default, rename = "ovtm_untp_askp2")]
+    pub ovtm_untp_askp2: String,
+    /// 시간외 단일가 매도호가3
+    #[serde(default, rename = "ovtm_untp_askp3")]
+    pub ovtm_untp_askp3: String,
+    /// 시간외 단일가 매도호가4
+    #[serde(default, rename = "ovtm_untp_askp4")]
+    pub ovtm_untp_askp4: String,
+    /// 시간외 단일가 매도호가5
+    #[serde(default, rename = "ovtm_untp_askp5")]
+    pub ovtm_untp_askp5: String,
+    /// 시간외 단일가 매도호가6
+    #[serde(default, rename = "ovtm_untp_askp6")]
+    pub ovtm_untp_askp6: String,
+    /// 시간외 단일가 매도호가7
+    #[serde(default, rename = "ovtm_untp_askp7")]
+    pub ovtm_untp_askp7: String,
+    /// 시간외 단일가 매도호가8
+    #[serde(default, rename = "ovtm_untp_askp8")]
+    pub ovtm_untp_askp8: String,
+    /// 시간외 단일가 매도호가9
+    #[serde(default, rename = "ovtm_untp_askp9")]
+    pub ovtm_untp_askp9: String,
+    /// 시간외 단일가 매도호가10
+    #[serde(default, rename = "ovtm_untp_askp10")]
+    pub ovtm_untp_askp10: String,
+    /// 시간외 단일가 매수호가1
+    #[serde(default, rename = "ovtm_untp_bidp1")]
+    pub ovtm_untp_bidp1: String,
+    /// 시간외 단일가 매수호가2
+    #[serde(default, rename = "ovtm_untp_bidp2")]
+    pub ovtm_untp_bidp2: String,
+    /// 시간외 단일가 매수호가3
+    #[serde(default, rename = "ovtm_untp_bidp3")]
+    pub ovtm_untp_bidp3: String,
+    /// 시간외 단일가 매수호가4
+    #[serde(default, rename = "ovtm_untp_bidp4")]
+    pub ovtm_untp_bidp4: String,
+    /// 시간외 단일가 매수호가5
+    #[serde(default, rename = "ovtm_untp_bidp5")]
+    pub ovtm_untp_bidp5: String,
+    /// 시간외 단일가 매수호가6
+    #[serde(default, rename = "ovtm_untp_bidp6")]
+    pub ovtm_untp_bidp6: String,
+    /// 시간외 단일가 매수호가7
+    #[serde(default, rename = "ovtm_untp_bidp7")]
+    pub ovtm_untp_bidp7: String,
+    /// 시간외 단일가 매수호가8
+    #[serde(default, rename = "ovtm_untp_bidp8")]
+    pub ovtm_untp_bidp8: String,
+    /// 시간외 단일가 매수호가9
+    #[serde(default, rename = "ovtm_untp_bidp9")]
+    pub ovtm_untp_bidp9: String,
+    /// 시간외 단일가 매수호가10
+    #[serde(default, rename = "ovtm_untp_bidp10")]
+    pub ovtm_untp_bidp10: String,
+    /// 시간외 단일가 매도호가 증감1
+    #[serde(default, rename = "ovtm_untp_askp_icdc1")]
+    pub ovtm_untp_askp_icdc1: String,
+    /// 시간외 단일가 매도호가 증감2
+    #[serde(default, rename = "ovtm_untp_askp_icdc2")]
+    pub ovtm_untp_askp_icdc2: String,
+    /// 시간외 단일가 매도호가 증감3
+    #[serde(default, rename = "ovtm_untp_askp_icdc3")]
+    pub ovtm_untp_askp_icdc3: String,
+    /// 시간외 단일가 매도호가 증감4
+    #[serde(default, rename = "ovtm_untp_askp_icdc4")]
+    pub ovtm_untp_askp_icdc4: String,
+    /// 시간외 단일가 매도호가 증감5
+    #[serde(default, rename = "ovtm_untp_askp_icdc5")]
+    pub ovtm_untp_askp_icdc5: String,
+    /// 시간외 단일가 매도호가 증감6
+    #[serde(default, rename = "ovtm_untp_askp_icdc6")]
+    pub ovtm_untp_askp_icdc6: String,
+    /// 시간외 단일가 매도호가 증감7
+    #[serde(default, rename = "ovtm_untp_askp_icdc7")]
+    pub ovtm_untp_askp_icdc7: String,
+    /// 시간외 단일가 매도호가 증감8
+    #[serde(default, rename = "ovtm_untp_askp_icdc8")]
+    pub ovtm_untp_askp_icdc8: String,
+    /// 시간외 단일가 매도호가 증감9
+    #[serde(default, rename = "ovtm_untp_askp_icdc9")]
+    pub ovtm_untp_askp_icdc9: String,
+    /// 시간외 단일가 매도호가 증감10
+    #[serde(default, rename = "ovtm_untp_askp_icdc10")]
+    pub ovtm_untp_askp_icdc10: String,
+    /// 시간외 단일가 매수호가 증감1
+    #[serde(default, rename = "ovtm_untp_bidp_icdc1")]
+    pub ovtm_untp_bidp_icdc1: String,
+    /// 시간외 단일가 매수호가 증감2
+    #[serde(default, rename = "ovtm_untp_bidp_icdc2")]
+    pub ovtm_untp_bidp_icdc2: String,
+    /// 시간외 단일가 매수호가 증감3
+    #[serde(default, rename = "ovtm_untp_bidp_icdc3")]
+    pub ovtm_untp_bidp_icdc3: String,
+    /// 시간외 단일가 매수호가 증감4
+    #[serde(default, rename = "ovtm_untp_bidp_icdc4")]
+    pub ovtm_untp_bidp_icdc4: String,
+    /// 시간외 단일가 매수호가 증감5
+    #[serde(default, rename = "ovtm_untp_bidp_icdc5")]
+    pub ovtm_untp_bidp_icdc5: String,
+    /// 시간외 단일가 매수호가 증감6
+    #[serde(default, rename = "ovtm_untp_bidp_icdc6")]
+    pub ovtm_untp_bidp_icdc6: String,
+    /// 시간외 단일가 매수호가 증감7
+    #[serde(default, rename = "ovtm_untp_bidp_icdc7")]
+    pub ovtm_untp_bidp_icdc7: String,
+    /// 시간외 단일가 매수호가 증감8
+    #[serde(default, rename = "ovtm_untp_bidp_icdc8")]
+    pub ovtm_untp_bidp_icdc8: String,
+    /// 시간외 단일가 매수호가 증감9
+    #[serde(default, rename = "ovtm_untp_bidp_icdc9")]
+    pub ovtm_untp_bidp_icdc9: String,
+    /// 시간외 단일가 매수호가 증감10
+    #[serde(default, rename = "ovtm_untp_bidp_icdc10")]
+    pub ovtm_untp_bidp_icdc10: String,
+    /// 시간외 단일가 매도호가 잔량1
+    #[serde(default, rename = "ovtm_untp_askp_rsqn1")]
+    pub ovtm_untp_askp_rsqn1: String,
+    /// 시간외 단일가 매도호가 잔량2
+    #[serde(default, rename = "ovtm_untp_askp_rsqn2")]
+    pub ovtm_untp_askp_rsqn2: String,
+    /// 시간외 단일가 매도호가 잔량3
+    #[serde(default, rename = "ovtm_untp_askp_rsqn3")]
+    pub ovtm_untp_askp_rsqn3: String,
+    /// 시간외 단일가 매도호가 잔량4
+    #[serde(default, rename = "ovtm_untp_askp_rsqn4")]
+    pub ovtm_untp_askp_rsqn4: String,
+    /// 시간외 단일가 매도호가 잔량5
+    #[serde(default, rename = "ovtm_untp_askp_rsqn5")]
+    pub ovtm_untp_askp_rsqn5: String,
+    /// 시간외 단일가 매도호가 잔량6
+    #[serde(default, rename = "ovtm_untp_askp_rsqn6")]
+    pub ovtm_untp_askp_rsqn6: String,
+    /// 시간외 단일가 매도호가 잔량7
+    #[serde(default, rename = "ovtm_untp_askp_rsqn7")]
+    pub ovtm_untp_askp_rsqn7: String,
+    /// 시간외 단일가 매도호가 잔량8
+    #[serde(default, rename = "ovtm_untp_askp_rsqn8")]
+    pub ovtm_untp_askp_rsqn8: String,
+    /// 시간외 단일가 매도호가 잔량9
+    #[serde(default, rename = "ovtm_untp_askp_rsqn9")]
+    pub ovtm_untp_askp_rsqn9: String,
+    /// 시간외 단일가 매도호가 잔량10
+    #[serde(default, rename = "ovtm_untp_askp_rsqn10")]
+    pub ovtm_untp_askp_rsqn10: String,
+    /// 시간외 단일가 매수호가 잔량1
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn1")]
+    pub ovtm_untp_bidp_rsqn1: String,
+    /// 시간외 단일가 매수호가 잔량2
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn2")]
+    pub ovtm_untp_bidp_rsqn2: String,
+    /// 시간외 단일가 매수호가 잔량3
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn3")]
+    pub ovtm_untp_bidp_rsqn3: String,
+    /// 시간외 단일가 매수호가 잔량4
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn4")]
+    pub ovtm_untp_bidp_rsqn4: String,
+    /// 시간외 단일가 매수호가 잔량5
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn5")]
+    pub ovtm_untp_bidp_rsqn5: String,
+    /// 시간외 단일가 매수호가 잔량6
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn6")]
+    pub ovtm_untp_bidp_rsqn6: String,
+    /// 시간외 단일가 매수호가 잔량7
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn7")]
+    pub ovtm_untp_bidp_rsqn7: String,
+    /// 시간외 단일가 매수호가 잔량8
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn8")]
+    pub ovtm_untp_bidp_rsqn8: String,
+    /// 시간외 단일가 매수호가 잔량9
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn9")]
+    pub ovtm_untp_bidp_rsqn9: String,
+    /// 시간외 단일가 매수호가 잔량10
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn10")]
+    pub ovtm_untp_bidp_rsqn10: String,
+    /// 시간외 단일가 총 매도호가 잔량
+    #[serde(default, rename = "ovtm_untp_total_askp_rsqn")]
+    pub ovtm_untp_total_askp_rsqn: String,
+    /// 시간외 단일가 총 매수호가 잔량
+    #[serde(default, rename = "ovtm_untp_total_bidp_rsqn")]
+    pub ovtm_untp_total_bidp_rsqn: String,
+    /// 시간외 단일가 총 매도호가 잔량
+    #[serde(default, rename = "ovtm_untp_total_askp_rsqn_icdc")]
+    pub ovtm_untp_total_askp_rsqn_icdc: String,
+    /// 시간외 단일가 총 매수호가 잔량
+    #[serde(default, rename = "ovtm_untp_total_bidp_rsqn_icdc")]
+    pub ovtm_untp_total_bidp_rsqn_icdc: String,
+    /// 시간외 단일가 순매수 호가 잔량
+    #[serde(default, rename = "ovtm_untp_ntby_bidp_rsqn")]
+    pub ovtm_untp_ntby_bidp_rsqn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "total_askp_rsqn_icdc")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "total_bidp_rsqn_icdc")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "ovtm_total_askp_rsqn")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "ovtm_total_bidp_rsqn")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 시간외 총 매도호가 증감
+    #[serde(default, rename = "ovtm_total_askp_icdc")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외 총 매수호가 증감
+    #[serde(default, rename = "ovtm_total_bidp_icdc")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외호가[국내주식-077]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireOvertimeAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireOvertimeAskingPriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireOvertimeAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 장마감 예상체결가[국내주식-120]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpClosingPriceOutput1Item {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 기준가 대비 현재가
+    #[serde(default, rename = "sdpr_vrss_prpr")]
+    pub sdpr_vrss_prpr: String,
+    /// 기준가 대비 현재가 비율
+    #[serde(default, rename = "sdpr_vrss_prpr_rate")]
+    pub sdpr_vrss_prpr_rate: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 장마감 예상체결가[국내주식-120]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsExpClosingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsExpClosingPriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsExpClosingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 현재가 시세[v1_국내주식-014]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireElwPriceOutput1Item {
+    /// ELW 단축 종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// ELW 현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 대비 거래량 비율
+    #[serde(default, rename = "prdy_vrss_vol_rate")]
+    pub prdy_vrss_vol_rate: Decimal,
+    /// 기초자산 단축 종목코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// 기초자산 종목명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 기초자산 현재가
+    #[serde(default, rename = "unas_prpr")]
+    pub unas_prpr: String,
+    /// 기초자산 전일 대비
+    #[serde(default, rename = "unas_prdy_vrss")]
+    pub unas_prdy_vrss: String,
+    /// 기초자산 전일 대비 부호
+    #[serde(default, rename = "unas_prdy_vrss_sign")]
+    pub unas_prdy_vrss_sign: String,
+    /// 기초자산 전일 대비율
+    #[serde(default, rename = "unas_prdy_ctrt")]
+    pub unas_prdy_ctrt: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "vol_tnrt")]
+    pub vol_tnrt: String,
+    /// ELW 시가2
+    #[serde(default, rename = "elw_oprc")]
+    pub elw_oprc: String,
+    /// ELW 최고가
+    #[serde(default, rename = "elw_hgpr")]
+    pub elw_hgpr: String,
+    /// ELW 최저가
+    #[serde(default, rename = "elw_lwpr")]
+    pub elw_lwpr: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// HTS 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// ATM 구분 명
+    #[serde(default, rename = "atm_cls_name")]
+    pub atm_cls_name: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 피벗 2차 디저항 가격
+    #[serde(default, rename = "pvt_scnd_dmrs_prc")]
+    pub pvt_scnd_dmrs_prc: String,
+    /// 피벗 1차 디저항 가격
+    #[serde(default, rename = "pvt_frst_dmrs_prc")]
+    pub pvt_frst_dmrs_prc: String,
+    /// 피벗 포인트 값
+    #[serde(default, rename = "pvt_pont_val")]
+    pub pvt_pont_val: Decimal,
+    /// 피벗 1차 디지지 가격
+    #[serde(default, rename = "pvt_frst_dmsp_prc")]
+    pub pvt_frst_dmsp_prc: String,
+    /// 피벗 2차 디지지 가격
+    #[serde(default, rename = "pvt_scnd_dmsp_prc")]
+    pub pvt_scnd_dmsp_prc: String,
+    /// 디지지 값
+    #[serde(default, rename = "dmsp_val")]
+    pub dmsp_val: Decimal,
+    /// 디저항 값
+    #[serde(default, rename = "dmrs_val")]
+    pub dmrs_val: Decimal,
+    /// ELW 기준가
+    #[serde(default, rename = "elw_sdpr")]
+    pub elw_sdpr: String,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 틱환산가
+    #[serde(default, rename = "tick_conv_prc")]
+    pub tick_conv_prc: String,
+    /// 투자 유의 내용
+    #[serde(default, rename = "invt_epmd_cntt")]
+    pub invt_epmd_cntt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 현재가 시세[v1_국내주식-014]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireElwPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireElwPriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireElwPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내업종 현재지수[v1_국내주식-063]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexPriceOutputItem {
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 전일 거래 대금
+    #[serde(default, rename = "prdy_tr_pbmn")]
+    pub prdy_tr_pbmn: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 전일 지수 대비 지수 시가2
+    #[serde(default, rename = "prdy_nmix_vrss_nmix_oprc")]
+    pub prdy_nmix_vrss_nmix_oprc: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "oprc_vrss_prpr_sign")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 업종 지수 시가2 전일 대비율
+    #[serde(default, rename = "bstp_nmix_oprc_prdy_ctrt")]
+    pub bstp_nmix_oprc_prdy_ctrt: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 전일 지수 대비 지수 최고가
+    #[serde(default, rename = "prdy_nmix_vrss_nmix_hgpr")]
+    pub prdy_nmix_vrss_nmix_hgpr: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "hgpr_vrss_prpr_sign")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 업종 지수 최고가 전일 대비율
+    #[serde(default, rename = "bstp_nmix_hgpr_prdy_ctrt")]
+    pub bstp_nmix_hgpr_prdy_ctrt: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 전일 종가 대비 최저가
+    #[serde(default, rename = "prdy_clpr_vrss_lwpr")]
+    pub prdy_clpr_vrss_lwpr: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "lwpr_vrss_prpr_sign")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 전일 종가 대비 최저가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_lwpr_rate")]
+    pub prdy_clpr_vrss_lwpr_rate: Decimal,
+    /// 상승 종목 수
+    #[serde(
+        default,
+        rename = "ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ascn_issu_cnt: i64,
+    /// 상한 종목 수
+    #[serde(
+        default,
+        rename = "uplm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub uplm_issu_cnt: i64,
+    /// 보합 종목 수
+    #[serde(
+        default,
+        rename = "stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub stnr_issu_cnt: i64,
+    /// 하락 종목 수
+    #[serde(
+        default,
+        rename = "down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub down_issu_cnt: i64,
+    /// 하한 종목 수
+    #[serde(
+        default,
+        rename = "lslm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub lslm_issu_cnt: i64,
+    /// 연중업종지수최고가
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr")]
+    pub dryy_bstp_nmix_hgpr: String,
+    /// 연중 최고가 대비 현재가 비율
+    #[serde(default, rename = "dryy_hgpr_vrss_prpr_rate")]
+    pub dryy_hgpr_vrss_prpr_rate: Decimal,
+    /// 연중업종지수최고가일자
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr_date")]
+    pub dryy_bstp_nmix_hgpr_date: String,
+    /// 연중업종지수최저가
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr")]
+    pub dryy_bstp_nmix_lwpr: String,
+    /// 연중 최저가 대비 현재가 비율
+    #[serde(default, rename = "dryy_lwpr_vrss_prpr_rate")]
+    pub dryy_lwpr_vrss_prpr_rate: Decimal,
+    /// 연중업종지수최저가일자
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr_date")]
+    pub dryy_bstp_nmix_lwpr_date: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 매도 잔량 비율
+    #[serde(default, rename = "seln_rsqn_rate")]
+    pub seln_rsqn_rate: Decimal,
+    /// 매수2 잔량 비율
+    #[serde(default, rename = "shnu_rsqn_rate")]
+    pub shnu_rsqn_rate: Decimal,
+    /// 순매수 잔량
+    #[serde(default, rename = "ntby_rsqn")]
+    pub ntby_rsqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 현재지수[v1_국내주식-063]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireIndexPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireIndexPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireIndexPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내업종 일자별지수[v1_국내주식-065]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexDailyPriceOutput1Item {
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 상승 종목 수
+    #[serde(
+        default,
+        rename = "ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ascn_issu_cnt: i64,
+    /// 하락 종목 수
+    #[serde(
+        default,
+        rename = "down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub down_issu_cnt: i64,
+    /// 보합 종목 수
+    #[serde(
+        default,
+        rename = "stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub stnr_issu_cnt: i64,
+    /// 상한 종목 수
+    #[serde(
+        default,
+        rename = "uplm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub uplm_issu_cnt: i64,
+    /// 하한 종목 수
+    #[serde(
+        default,
+        rename = "lslm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub lslm_issu_cnt: i64,
+    /// 전일 거래 대금
+    #[serde(default, rename = "prdy_tr_pbmn")]
+    pub prdy_tr_pbmn: String,
+    /// 연중업종지수최고가일자
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr_date")]
+    pub dryy_bstp_nmix_hgpr_date: String,
+    /// 연중업종지수최고가
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr")]
+    pub dryy_bstp_nmix_hgpr: String,
+    /// 연중업종지수최저가
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr")]
+    pub dryy_bstp_nmix_lwpr: String,
+    /// 연중업종지수최저가일자
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr_date")]
+    pub dryy_bstp_nmix_lwpr_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 일자별지수[v1_국내주식-065]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexDailyPriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 누적 거래량 비중
+    #[serde(default, rename = "acml_vol_rlim")]
+    pub acml_vol_rlim: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 투자 신 심리도
+    #[serde(default, rename = "invt_new_psdg")]
+    pub invt_new_psdg: String,
+    /// 20일 이격도
+    #[serde(default, rename = "d20_dsrt")]
+    pub d20_dsrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 일자별지수[v1_국내주식-065]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireIndexDailyPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireIndexDailyPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireIndexDailyPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireIndexDailyPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내업종 시간별지수(초)[국내주식-064]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexTickpriceOutputItem {
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 시간별지수(초)[국내주식-064]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireIndexTickpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireIndexTickpriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireIndexTickpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내업종 시간별지수(분)[국내주식-119]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexTimepriceOutputItem {
+    /// 영업 시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 시간별지수(분)[국내주식-119]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireIndexTimepriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireIndexTimepriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireIndexTimepriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [업종 분봉조회[v1_국내주식-045]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeIndexchartpriceOutput1Item {
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 전일 지수
+    #[serde(default, rename = "prdy_nmix")]
+    pub prdy_nmix: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 선물 전일 시가
+    #[serde(default, rename = "futs_prdy_oprc")]
+    pub futs_prdy_oprc: String,
+    /// 선물 전일 최고가
+    #[serde(default, rename = "futs_prdy_hgpr")]
+    pub futs_prdy_hgpr: String,
+    /// 선물 전일 최저가
+    #[serde(default, rename = "futs_prdy_lwpr")]
+    pub futs_prdy_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [업종 분봉조회[v1_국내주식-045]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireTimeIndexchartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [업종 분봉조회[v1_국내주식-045]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireTimeIndexchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireTimeIndexchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireTimeIndexchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireTimeIndexchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식업종기간별시세(일/주/월/년)[v1_국내주식-021]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyIndexchartpriceOutput1Item {
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 전일 지수
+    #[serde(default, rename = "prdy_nmix")]
+    pub prdy_nmix: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 선물 전일 시가
+    #[serde(default, rename = "futs_prdy_oprc")]
+    pub futs_prdy_oprc: String,
+    /// 선물 전일 최고가
+    #[serde(default, rename = "futs_prdy_hgpr")]
+    pub futs_prdy_hgpr: String,
+    /// 선물 전일 최저가
+    #[serde(default, rename = "futs_prdy_lwpr")]
+    pub futs_prdy_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식업종기간별시세(일/주/월/년)[v1_국내주식-021]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyIndexchartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 변경 여부
+    #[serde(default, rename = "mod_yn")]
+    pub mod_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식업종기간별시세(일/주/월/년)[v1_국내주식-021]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireDailyIndexchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireDailyIndexchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireDailyIndexchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireDailyIndexchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내업종 구분별전체시세[v1_국내주식-066]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexCategoryPriceOutput1Item {
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 상승 종목 수
+    #[serde(
+        default,
+        rename = "ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ascn_issu_cnt: i64,
+    /// 하락 종목 수
+    #[serde(
+        default,
+        rename = "down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub down_issu_cnt: i64,
+    /// 보합 종목 수
+    #[serde(
+        default,
+        rename = "stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub stnr_issu_cnt: i64,
+    /// 상한 종목 수
+    #[serde(
+        default,
+        rename = "uplm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub uplm_issu_cnt: i64,
+    /// 하한 종목 수
+    #[serde(
+        default,
+        rename = "lslm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub lslm_issu_cnt: i64,
+    /// 전일 거래 대금
+    #[serde(default, rename = "prdy_tr_pbmn")]
+    pub prdy_tr_pbmn: String,
+    /// 연중업종지수최고가일자
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr_date")]
+    pub dryy_bstp_nmix_hgpr_date: String,
+    /// 연중업종지수최고가
+    #[serde(default, rename = "dryy_bstp_nmix_hgpr")]
+    pub dryy_bstp_nmix_hgpr: String,
+    /// 연중업종지수최저가
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr")]
+    pub dryy_bstp_nmix_lwpr: String,
+    /// 연중업종지수최저가일자
+    #[serde(default, rename = "dryy_bstp_nmix_lwpr_date")]
+    pub dryy_bstp_nmix_lwpr_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 구분별전체시세[v1_국내주식-066]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireIndexCategoryPriceOutput2Item {
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 누적 거래량 비중
+    #[serde(default, rename = "acml_vol_rlim")]
+    pub acml_vol_rlim: String,
+    /// 누적 거래 대금 비중
+    #[serde(default, rename = "acml_tr_pbmn_rlim")]
+    pub acml_tr_pbmn_rlim: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내업종 구분별전체시세[v1_국내주식-066]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireIndexCategoryPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireIndexCategoryPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireIndexCategoryPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireIndexCategoryPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 예상체결지수 추이[국내주식-121]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpIndexTrendOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 주식 현재가
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 전일 대비율
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 기준가 대비 현재가
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결지수 추이[국내주식-121]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsExpIndexTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsExpIndexTrendOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsExpIndexTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 예상체결 전체지수[국내주식-122]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpTotalIndexOutput1Item {
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 상승 종목 수
+    #[serde(
+        default,
+        rename = "ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ascn_issu_cnt: i64,
+    /// 하락 종목 수
+    #[serde(
+        default,
+        rename = "down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub down_issu_cnt: i64,
+    /// 보합 종목 수
+    #[serde(
+        default,
+        rename = "stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub stnr_issu_cnt: i64,
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결 전체지수[국내주식-122]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpTotalIndexOutput2Item {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 지수 기준가
+    #[serde(default, rename = "nmix_sdpr")]
+    pub nmix_sdpr: String,
+    /// 상승 종목 수
+    #[serde(
+        default,
+        rename = "ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ascn_issu_cnt: i64,
+    /// 보합 종목 수
+    #[serde(
+        default,
+        rename = "stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub stnr_issu_cnt: i64,
+    /// 하락 종목 수
+    #[serde(
+        default,
+        rename = "down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub down_issu_cnt: i64,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결 전체지수[국내주식-122]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsExpTotalIndexResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsExpTotalIndexOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsExpTotalIndexOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsExpTotalIndexResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [변동성완화장치(VI) 현황 [v1_국내주식-055]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireViStatusOutputItem {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// VI발동상태
+    #[serde(default, rename = "vi_cls_code")]
+    pub vi_cls_code: String,
+    /// 영업 일자
+    #[serde(default, rename = "bsop_date")]
+    pub bsop_date: String,
+    /// VI발동시간
+    #[serde(default, rename = "cntg_vi_hour")]
+    pub cntg_vi_hour: String,
+    /// VI해제시간
+    #[serde(default, rename = "vi_cncl_hour")]
+    pub vi_cncl_hour: String,
+    /// VI종류코드
+    #[serde(default, rename = "vi_kind_code")]
+    pub vi_kind_code: String,
+    /// VI발동가격
+    #[serde(default, rename = "vi_prc")]
+    pub vi_prc: String,
+    /// 정적VI발동기준가격
+    #[serde(default, rename = "vi_stnd_prc")]
+    pub vi_stnd_prc: String,
+    /// 정적VI발동괴리율
+    #[serde(default, rename = "vi_dprt")]
+    pub vi_dprt: String,
+    /// 동적VI발동기준가격
+    #[serde(default, rename = "vi_dmc_stnd_prc")]
+    pub vi_dmc_stnd_prc: String,
+    /// 동적VI발동괴리율
+    #[serde(default, rename = "vi_dmc_dprt")]
+    pub vi_dmc_dprt: String,
+    /// VI발동횟수
+    #[serde(default, rename = "vi_count")]
+    pub vi_count: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [변동성완화장치(VI) 현황 [v1_국내주식-055]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireViStatusResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireViStatusOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireViStatusResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [금리 종합(국내채권/금리) [국내주식-155]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompInterestOutput1Item {
+    /// 자료코드
+    #[serde(default, rename = "bcdt_code")]
+    pub bcdt_code: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 채권금리현재가
+    #[serde(default, rename = "bond_mnrt_prpr")]
+    pub bond_mnrt_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 채권금리전일대비
+    #[serde(default, rename = "bond_mnrt_prdy_vrss")]
+    pub bond_mnrt_prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [금리 종합(국내채권/금리) [국내주식-155]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompInterestOutput2Item {
+    /// 자료코드
+    #[serde(default, rename = "bcdt_code")]
+    pub bcdt_code: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 채권금리현재가
+    #[serde(default, rename = "bond_mnrt_prpr")]
+    pub bond_mnrt_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 채권금리전일대비
+    #[serde(default, rename = "bond_mnrt_prdy_vrss")]
+    pub bond_mnrt_prdy_vrss: String,
+    /// 업종지수전일대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [금리 종합(국내채권/금리) [국내주식-155]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsCompInterestResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsCompInterestOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsCompInterestOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsCompInterestResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종합 시황/공시(제목) [국내주식-141]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsNewsTitleOutputItem {
+    /// 내용 조회용 일련번호
+    #[serde(default, rename = "cntt_usiq_srno")]
+    pub cntt_usiq_srno: String,
+    /// 뉴스 제공 업체 코드
+    #[serde(default, rename = "news_ofer_entp_code")]
+    pub news_ofer_entp_code: String,
+    /// 작성일자
+    #[serde(default, rename = "data_dt")]
+    pub data_dt: String,
+    /// 작성시간
+    #[serde(default, rename = "data_tm")]
+    pub data_tm: String,
+    /// HTS 공시 제목 내용
+    #[serde(default, rename = "hts_pbnt_titl_cntt")]
+    pub hts_pbnt_titl_cntt: String,
+    /// 뉴스 대구분
+    #[serde(default, rename = "news_lrdv_code")]
+    pub news_lrdv_code: String,
+    /// 자료원
+    #[serde(default, rename = "dorg")]
+    pub dorg: String,
+    /// 종목 코드1
+    #[serde(default, rename = "iscd1")]
+    pub iscd1: String,
+    /// 종목 코드2
+    #[serde(default, rename = "iscd2")]
+    pub iscd2: String,
+    /// 종목 코드3
+    #[serde(default, rename = "iscd3")]
+    pub iscd3: String,
+    /// 종목 코드4
+    #[serde(default, rename = "iscd4")]
+    pub iscd4: String,
+    /// 종목 코드5
+    #[serde(default, rename = "iscd5")]
+    pub iscd5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종합 시황/공시(제목) [국내주식-141]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsNewsTitleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsNewsTitleOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsNewsTitleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내휴장일조회[국내주식-040]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsChkHolidayOutputItem {
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 요일구분코드
+    #[serde(default, rename = "wday_dvsn_cd")]
+    pub wday_dvsn_cd: String,
+    /// 영업일여부
+    #[serde(default, rename = "bzdy_yn")]
+    pub bzdy_yn: String,
+    /// 거래일여부
+    #[serde(default, rename = "tr_day_yn")]
+    pub tr_day_yn: String,
+    /// 개장일여부
+    #[serde(default, rename = "opnd_yn")]
+    pub opnd_yn: String,
+    /// 결제일여부
+    #[serde(default, rename = "sttl_day_yn")]
+    pub sttl_day_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내휴장일조회[국내주식-040]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsChkHolidayResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<DomesticStockV1QuotationsChkHolidayOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsChkHolidayResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내선물 영업일조회 [국내주식-160]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsMarketTimeOutput1Item {
+    /// 영업일1
+    #[serde(default, rename = "date1")]
+    pub date1: String,
+    /// 영업일2
+    #[serde(default, rename = "date2")]
+    pub date2: String,
+    /// 영업일3
+    #[serde(default, rename = "date3")]
+    pub date3: String,
+    /// 영업일4
+    #[serde(default, rename = "date4")]
+    pub date4: String,
+    /// 영업일5
+    #[serde(default, rename = "date5")]
+    pub date5: String,
+    /// 오늘일자
+    #[serde(default, rename = "today")]
+    pub today: String,
+    /// 현재시간
+    #[serde(default, rename = "time")]
+    pub time: String,
+    /// 장시작시간
+    #[serde(default, rename = "s_time")]
+    pub s_time: String,
+    /// 장마감시간
+    #[serde(default, rename = "e_time")]
+    pub e_time: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내선물 영업일조회 [국내주식-160]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsMarketTimeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsMarketTimeOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsMarketTimeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [상품기본조회[v1_국내주식-029]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsSearchInfoOutputItem {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품명120
+    #[serde(default, rename = "prdt_name120")]
+    pub prdt_name120: String,
+    /// 상품약어명
+    #[serde(default, rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: String,
+    /// 상품영문명
+    #[serde(default, rename = "prdt_eng_name")]
+    pub prdt_eng_name: String,
+    /// 상품영문명120
+    #[serde(default, rename = "prdt_eng_name120")]
+    pub prdt_eng_name120: String,
+    /// 상품영문약어명
+    #[serde(default, rename = "prdt_eng_abrv_name")]
+    pub prdt_eng_abrv_name: String,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 단축상품번호
+    #[serde(default, rename = "shtn_pdno")]
+    pub shtn_pdno: String,
+    /// 상품판매상태코드
+    #[serde(default, rename = "prdt_sale_stat_cd")]
+    pub prdt_sale_stat_cd: String,
+    /// 상품위험등급코드
+    #[serde(default, rename = "prdt_risk_grad_cd")]
+    pub prdt_risk_grad_cd: String,
+    /// 상품분류코드
+    #[serde(default, rename = "prdt_clsf_cd")]
+    pub prdt_clsf_cd: String,
+    /// 상품분류명
+    #[serde(default, rename = "prdt_clsf_name")]
+    pub prdt_clsf_name: String,
+    /// 판매시작일자
+    #[serde(default, rename = "sale_strt_dt")]
+    pub sale_strt_dt: String,
+    /// 판매종료일자
+    #[serde(default, rename = "sale_end_dt")]
+    pub sale_end_dt: String,
+    /// 랩어카운트자산유형코드
+    #[serde(default, rename = "wrap_asst_type_cd")]
+    pub wrap_asst_type_cd: String,
+    /// 투자상품유형코드
+    #[serde(default, rename = "ivst_prdt_type_cd")]
+    pub ivst_prdt_type_cd: String,
+    /// 투자상품유형코드명
+    #[serde(default, rename = "ivst_prdt_type_cd_name")]
+    pub ivst_prdt_type_cd_name: String,
+    /// 최초등록일자
+    #[serde(default, rename = "frst_erlm_dt")]
+    pub frst_erlm_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [상품기본조회[v1_국내주식-029]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsSearchInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsSearchInfoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsSearchInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식기본조회[v1_국내주식-067]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsSearchStockInfoOutputItem {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 시장ID코드
+    #[serde(default, rename = "mket_id_cd")]
+    pub mket_id_cd: String,
+    /// 증권그룹ID코드
+    #[serde(default, rename = "scty_grp_id_cd")]
+    pub scty_grp_id_cd: String,
+    /// 거래소구분코드
+    #[serde(default, rename = "excg_dvsn_cd")]
+    pub excg_dvsn_cd: String,
+    /// 결산월일
+    #[serde(default, rename = "setl_mmdd")]
+    pub setl_mmdd: String,
+    /// 상장주수
+    #[serde(default, rename = "lstg_stqt")]
+    pub lstg_stqt: String,
+    /// 상장자본금액
+    #[serde(default, rename = "lstg_cptl_amt")]
+    pub lstg_cptl_amt: Decimal,
+    /// 자본금
+    #[serde(default, rename = "cpta")]
+    pub cpta: String,
+    /// 액면가
+    #[serde(default, rename = "papr")]
+    pub papr: String,
+    /// 발행가격
+    #[serde(default, rename = "issu_pric")]
+    pub issu_pric: Decimal,
+    /// 코스피200종목여부
+    #[serde(default, rename = "kospi200_item_yn")]
+    pub kospi200_item_yn: String,
+    /// 유가증권시장상장일자
+    #[serde(default, rename = "scts_mket_lstg_dt")]
+    pub scts_mket_lstg_dt: String,
+    /// 유가증권시장상장폐지일자
+    #[serde(default, rename = "scts_mket_lstg_abol_dt")]
+    pub scts_mket_lstg_abol_dt: String,
+    /// 코스닥시장상장일자
+    #[serde(default, rename = "kosdaq_mket_lstg_dt")]
+    pub kosdaq_mket_lstg_dt: String,
+    /// 코스닥시장상장폐지일자
+    #[serde(default, rename = "kosdaq_mket_lstg_abol_dt")]
+    pub kosdaq_mket_lstg_abol_dt: String,
+    /// 프리보드시장상장일자
+    #[serde(default, rename = "frbd_mket_lstg_dt")]
+    pub frbd_mket_lstg_dt: String,
+    /// 프리보드시장상장폐지일자
+    #[serde(default, rename = "frbd_mket_lstg_abol_dt")]
+    pub frbd_mket_lstg_abol_dt: String,
+    /// 리츠종류코드
+    #[serde(default, rename = "reits_kind_cd")]
+    pub reits_kind_cd: String,
+    /// ETF구분코드
+    #[serde(default, rename = "etf_dvsn_cd")]
+    pub etf_dvsn_cd: String,
+    /// 유전펀드여부
+    #[serde(default, rename = "oilf_fund_yn")]
+    pub oilf_fund_yn: String,
+    /// 지수업종대분류코드
+    #[serde(default, rename = "idx_bztp_lcls_cd")]
+    pub idx_bztp_lcls_cd: String,
+    /// 지수업종중분류코드
+    #[serde(default, rename = "idx_bztp_mcls_cd")]
+    pub idx_bztp_mcls_cd: String,
+    /// 지수업종소분류코드
+    #[serde(default, rename = "idx_bztp_scls_cd")]
+    pub idx_bztp_scls_cd: String,
+    /// 주식종류코드
+    #[serde(default, rename = "stck_kind_cd")]
+    pub stck_kind_cd: String,
+    /// 뮤추얼펀드개시일자
+    #[serde(default, rename = "mfnd_opng_dt")]
+    pub mfnd_opng_dt: String,
+    /// 뮤추얼펀드종료일자
+    #[serde(default, rename = "mfnd_end_dt")]
+    pub mfnd_end_dt: String,
+    /// 예탁등록취소일자
+    #[serde(default, rename = "dpsi_erlm_cncl_dt")]
+    pub dpsi_erlm_cncl_dt: String,
+    /// ETFCU수량
+    #[serde(default, rename = "etf_cu_qty")]
+    pub etf_cu_qty: Decimal,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품명120
+    #[serde(default, rename = "prdt_name120")]
+    pub prdt_name120: String,
+    /// 상품약어명
+    #[serde(default, rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: String,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 상품영문명
+    #[serde(default, rename = "prdt_eng_name")]
+    pub prdt_eng_name: String,
+    /// 상품영문명120
+    #[serde(default, rename = "prdt_eng_name120")]
+    pub prdt_eng_name120: String,
+    /// 상품영문약어명
+    #[serde(default, rename = "prdt_eng_abrv_name")]
+    pub prdt_eng_abrv_name: String,
+    /// 예탁지정등록여부
+    #[serde(default, rename = "dpsi_aptm_erlm_yn")]
+    pub dpsi_aptm_erlm_yn: String,
+    /// ETF과세유형코드
+    #[serde(default, rename = "etf_txtn_type_cd")]
+    pub etf_txtn_type_cd: String,
+    /// ETF유형코드
+    #[serde(default, rename = "etf_type_cd")]
+    pub etf_type_cd: String,
+    /// 상장폐지일자
+    #[serde(default, rename = "lstg_abol_dt")]
+    pub lstg_abol_dt: String,
+    /// 신주구주구분코드
+    #[serde(default, rename = "nwst_odst_dvsn_cd")]
+    pub nwst_odst_dvsn_cd: String,
+    /// 대용가격
+    #[serde(default, rename = "sbst_pric")]
+    pub sbst_pric: Decimal,
+    /// 당사대용가격
+    #[serde(default, rename = "thco_sbst_pric")]
+    pub thco_sbst_pric: Decimal,
+    /// 당사대용가격변경일자
+    #[serde(default, rename = "thco_sbst_pric_chng_dt")]
+    pub thco_sbst_pric_chng_dt: String,
+    /// 거래정지여부
+    #[serde(default, rename = "tr_stop_yn")]
+    pub tr_stop_yn: String,
+    /// 관리종목여부
+    #[serde(default, rename = "admn_item_yn")]
+    pub admn_item_yn: String,
+    /// 당일종가
+    #[serde(default, rename = "thdt_clpr")]
+    pub thdt_clpr: String,
+    /// 전일종가
+    #[serde(default, rename = "bfdy_clpr")]
+    pub bfdy_clpr: String,
+    /// 종가변경일자
+    #[serde(default, rename = "clpr_chng_dt")]
+    pub clpr_chng_dt: String,
+    /// 표준산업분류코드
+    #[serde(default, rename = "std_idst_clsf_cd")]
+    pub std_idst_clsf_cd: String,
+    /// 표준산업분류코드명
+    #[serde(default, rename = "std_idst_clsf_cd_name")]
+    pub std_idst_clsf_cd_name: String,
+    /// 지수업종대분류코드명
+    #[serde(default, rename = "idx_bztp_lcls_cd_name")]
+    pub idx_bztp_lcls_cd_name: String,
+    /// 지수업종중분류코드명
+    #[serde(default, rename = "idx_bztp_mcls_cd_name")]
+    pub idx_bztp_mcls_cd_name: String,
+    /// 지수업종소분류코드명
+    #[serde(default, rename = "idx_bztp_scls_cd_name")]
+    pub idx_bztp_scls_cd_name: String,
+    /// OCR번호
+    #[serde(default, rename = "ocr_no")]
+    pub ocr_no: String,
+    /// 크라우드펀딩종목여부
+    #[serde(default, rename = "crfd_item_yn")]
+    pub crfd_item_yn: String,
+    /// 전자증권여부
+    #[serde(default, rename = "elec_scty_yn")]
+    pub elec_scty_yn: String,
+    /// 발행기관코드
+    #[serde(default, rename = "issu_istt_cd")]
+    pub issu_istt_cd: String,
+    /// ETF추적수익율배수
+    #[serde(default, rename = "etf_chas_erng_rt_dbnb")]
+    pub etf_chas_erng_rt_dbnb: String,
+    /// ETFETN투자유의종목여부
+    #[serde(default, rename = "etf_etn_ivst_heed_item_yn")]
+    pub etf_etn_ivst_heed_item_yn: String,
+    /// 대주이자율구분코드
+    #[serde(default, rename = "stln_int_rt_dvsn_cd")]
+    pub stln_int_rt_dvsn_cd: String,
+    /// 외국인개인한도비율
+    #[serde(default, rename = "frnr_psnl_lmt_rt")]
+    pub frnr_psnl_lmt_rt: Decimal,
+    /// 상장신청인발행기관코드
+    #[serde(default, rename = "lstg_rqsr_issu_istt_cd")]
+    pub lstg_rqsr_issu_istt_cd: String,
+    /// 상장신청인종목코드
+    #[serde(default, rename = "lstg_rqsr_item_cd")]
+    pub lstg_rqsr_item_cd: String,
+    /// 신탁기관발행기관코드
+    #[serde(default, rename = "trst_istt_issu_istt_cd")]
+    pub trst_istt_issu_istt_cd: String,
+    /// NXT 거래종목여부
+    #[serde(default, rename = "cptt_trad_tr_psbl_yn")]
+    pub cptt_trad_tr_psbl_yn: String,
+    /// NXT 거래정지여부
+    #[serde(default, rename = "nxt_tr_stop_yn")]
+    pub nxt_tr_stop_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식기본조회[v1_국내주식-067]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsSearchStockInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsSearchStockInfoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsSearchStockInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 대차대조표[v1_국내주식-078]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceBalanceSheetOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 유동자산
+    #[serde(default, rename = "cras")]
+    pub cras: String,
+    /// 고정자산
+    #[serde(default, rename = "fxas")]
+    pub fxas: String,
+    /// 자산총계
+    #[serde(default, rename = "total_aset")]
+    pub total_aset: String,
+    /// 유동부채
+    #[serde(default, rename = "flow_lblt")]
+    pub flow_lblt: String,
+    /// 고정부채
+    #[serde(default, rename = "fix_lblt")]
+    pub fix_lblt: String,
+    /// 부채총계
+    #[serde(default, rename = "total_lblt")]
+    pub total_lblt: String,
+    /// 자본금
+    #[serde(default, rename = "cpfn")]
+    pub cpfn: String,
+    /// 자본 잉여금
+    #[serde(default, rename = "cfp_surp")]
+    pub cfp_surp: String,
+    /// 이익 잉여금
+    #[serde(default, rename = "prfi_surp")]
+    pub prfi_surp: String,
+    /// 자본총계
+    #[serde(default, rename = "total_cptl")]
+    pub total_cptl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 대차대조표[v1_국내주식-078]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceBalanceSheetResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceBalanceSheetOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceBalanceSheetResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 손익계산서[v1_국내주식-079]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceIncomeStatementOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 매출액
+    #[serde(default, rename = "sale_account")]
+    pub sale_account: String,
+    /// 매출 원가
+    #[serde(default, rename = "sale_cost")]
+    pub sale_cost: String,
+    /// 매출 총 이익
+    #[serde(default, rename = "sale_totl_prfi")]
+    pub sale_totl_prfi: String,
+    /// 감가상각비
+    #[serde(default, rename = "depr_cost")]
+    pub depr_cost: String,
+    /// 판매 및 관리비
+    #[serde(default, rename = "sell_mang")]
+    pub sell_mang: String,
+    /// 영업 이익
+    #[serde(default, rename = "bsop_prti")]
+    pub bsop_prti: String,
+    /// 영업 외 수익
+    #[serde(default, rename = "bsop_non_ernn")]
+    pub bsop_non_ernn: String,
+    /// 영업 외 비용
+    #[serde(default, rename = "bsop_non_expn")]
+    pub bsop_non_expn: String,
+    /// 경상 이익
+    #[serde(default, rename = "op_prfi")]
+    pub op_prfi: String,
+    /// 특별 이익
+    #[serde(default, rename = "spec_prfi")]
+    pub spec_prfi: String,
+    /// 특별 손실
+    #[serde(default, rename = "spec_loss")]
+    pub spec_loss: String,
+    /// 당기순이익
+    #[serde(default, rename = "thtr_ntin")]
+    pub thtr_ntin: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 손익계산서[v1_국내주식-079]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceIncomeStatementResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceIncomeStatementOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceIncomeStatementResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 재무비율[v1_국내주식-080]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceFinancialRatioOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 매출액 증가율
+    #[serde(default, rename = "grs")]
+    pub grs: String,
+    /// 영업 이익 증가율
+    #[serde(default, rename = "bsop_prfi_inrt")]
+    pub bsop_prfi_inrt: String,
+    /// 순이익 증가율
+    #[serde(default, rename = "ntin_inrt")]
+    pub ntin_inrt: String,
+    /// ROE 값
+    #[serde(default, rename = "roe_val")]
+    pub roe_val: Decimal,
+    /// EPS
+    #[serde(default, rename = "eps")]
+    pub eps: String,
+    /// 주당매출액
+    #[serde(default, rename = "sps")]
+    pub sps: String,
+    /// BPS
+    #[serde(default, rename = "bps")]
+    pub bps: String,
+    /// 유보 비율
+    #[serde(default, rename = "rsrv_rate")]
+    pub rsrv_rate: Decimal,
+    /// 부채 비율
+    #[serde(default, rename = "lblt_rate")]
+    pub lblt_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 재무비율[v1_국내주식-080]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceFinancialRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceFinancialRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceFinancialRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 수익성비율[v1_국내주식-081]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceProfitRatioOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 총자본 순이익율
+    #[serde(default, rename = "cptl_ntin_rate")]
+    pub cptl_ntin_rate: Decimal,
+    /// 자기자본 순이익율
+    #[serde(default, rename = "self_cptl_ntin_inrt")]
+    pub self_cptl_ntin_inrt: String,
+    /// 매출액 순이익율
+    #[serde(default, rename = "sale_ntin_rate")]
+    pub sale_ntin_rate: Decimal,
+    /// 매출액 총이익율
+    #[serde(default, rename = "sale_totl_rate")]
+    pub sale_totl_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 수익성비율[v1_국내주식-081]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceProfitRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceProfitRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceProfitRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 기타주요비율[v1_국내주식-082]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceOtherMajorRatiosOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 배당 성향
+    #[serde(default, rename = "payout_rate")]
+    pub payout_rate: Decimal,
+    /// EVA
+    #[serde(default, rename = "eva")]
+    pub eva: String,
+    /// EBITDA
+    #[serde(default, rename = "ebitda")]
+    pub ebitda: String,
+    /// EV_EBITDA
+    #[serde(default, rename = "ev_ebitda")]
+    pub ev_ebitda: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 기타주요비율[v1_국내주식-082]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceOtherMajorRatiosResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceOtherMajorRatiosOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceOtherMajorRatiosResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 안정성비율[v1_국내주식-083]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceStabilityRatioOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 부채 비율
+    #[serde(default, rename = "lblt_rate")]
+    pub lblt_rate: Decimal,
+    /// 차입금 의존도
+    #[serde(default, rename = "bram_depn")]
+    pub bram_depn: String,
+    /// 유동 비율
+    #[serde(default, rename = "crnt_rate")]
+    pub crnt_rate: Decimal,
+    /// 당좌 비율
+    #[serde(default, rename = "quck_rate")]
+    pub quck_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 안정성비율[v1_국내주식-083]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceStabilityRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceStabilityRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceStabilityRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 성장성비율[v1_국내주식-085]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1FinanceGrowthRatioOutputItem {
+    /// 결산 년월
+    #[serde(default, rename = "stac_yymm")]
+    pub stac_yymm: String,
+    /// 매출액 증가율
+    #[serde(default, rename = "grs")]
+    pub grs: String,
+    /// 영업 이익 증가율
+    #[serde(default, rename = "bsop_prfi_inrt")]
+    pub bsop_prfi_inrt: String,
+    /// 자기자본 증가율
+    #[serde(default, rename = "equt_inrt")]
+    pub equt_inrt: String,
+    /// 총자산 증가율
+    #[serde(default, rename = "totl_aset_inrt")]
+    pub totl_aset_inrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 성장성비율[v1_국내주식-085]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1FinanceGrowthRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1FinanceGrowthRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1FinanceGrowthRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 당사 신용가능종목[국내주식-111]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCreditByCompanyOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 신용 비율
+    #[serde(default, rename = "crdt_rate")]
+    pub crdt_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 당사 신용가능종목[국내주식-111]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsCreditByCompanyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsCreditByCompanyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsCreditByCompanyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(배당일정)[국내주식-145]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoDividendOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 배당종류
+    #[serde(default, rename = "divi_kind")]
+    pub divi_kind: String,
+    /// 액면가
+    #[serde(default, rename = "face_val")]
+    pub face_val: Decimal,
+    /// 현금배당금
+    #[serde(default, rename = "per_sto_divi_amt")]
+    pub per_sto_divi_amt: Decimal,
+    /// 현금배당률(%)
+    #[serde(default, rename = "divi_rate")]
+    pub divi_rate: Decimal,
+    /// 주식배당률(%)
+    #[serde(default, rename = "stk_divi_rate")]
+    pub stk_divi_rate: Decimal,
+    /// 배당금지급일
+    #[serde(default, rename = "divi_pay_dt")]
+    pub divi_pay_dt: String,
+    /// 주식배당지급일
+    #[serde(default, rename = "stk_div_pay_dt")]
+    pub stk_div_pay_dt: String,
+    /// 단주대금지급일
+    #[serde(default, rename = "odd_pay_dt")]
+    pub odd_pay_dt: String,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 고배당종목여부
+    #[serde(default, rename = "high_divi_gb")]
+    pub high_divi_gb: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(배당일정)[국내주식-145]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoDividendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoDividendOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoDividendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(주식매수청구일정)[국내주식-146]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPurreqOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 반대의사접수시한
+    #[serde(default, rename = "opp_opi_rcpt_term")]
+    pub opp_opi_rcpt_term: String,
+    /// 매수청구접수시한
+    #[serde(default, rename = "buy_req_rcpt_term")]
+    pub buy_req_rcpt_term: String,
+    ///  매수청구가격
+    #[serde(default, rename = "buy_req_price")]
+    pub buy_req_price: String,
+    /// 매수대금지급일
+    #[serde(default, rename = "buy_amt_pay_dt")]
+    pub buy_amt_pay_dt: String,
+    /// 주총일
+    #[serde(default, rename = "get_meet_dt")]
+    pub get_meet_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(주식매수청구일정)[국내주식-146]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoPurreqResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoPurreqOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoPurreqResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(합병/분할일정)[국내주식-147]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoMergerSplitOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 피합병(피분할)회사코드
+    #[serde(default, rename = "opp_cust_cd")]
+    pub opp_cust_cd: String,
+    /// 피합병(피분할)회사명
+    #[serde(default, rename = "opp_cust_nm")]
+    pub opp_cust_nm: String,
+    /// 합병(분할)회사코드
+    #[serde(default, rename = "cust_cd")]
+    pub cust_cd: String,
+    /// 합병(분할)회사명
+    #[serde(default, rename = "cust_nm")]
+    pub cust_nm: String,
+    /// 합병사유
+    #[serde(default, rename = "merge_type")]
+    pub merge_type: String,
+    /// 비율
+    #[serde(default, rename = "merge_rate")]
+    pub merge_rate: Decimal,
+    /// 매매거래정지기간
+    #[serde(default, rename = "td_stop_dt")]
+    pub td_stop_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 단주대금지급일
+    #[serde(default, rename = "odd_amt_pay_dt")]
+    pub odd_amt_pay_dt: String,
+    /// 발행주식
+    #[serde(default, rename = "tot_issue_stk_qty")]
+    pub tot_issue_stk_qty: Decimal,
+    /// 발행할주식
+    #[serde(default, rename = "issue_stk_qty")]
+    pub issue_stk_qty: Decimal,
+    /// 연번
+    #[serde(default, rename = "seq")]
+    pub seq: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(합병/분할일정)[국내주식-147]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoMergerSplitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoMergerSplitOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoMergerSplitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(액면교체일정)[국내주식-148]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoRevSplitOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 변경전액면가
+    #[serde(default, rename = "inter_bf_face_amt")]
+    pub inter_bf_face_amt: Decimal,
+    /// 변경후액면가
+    #[serde(default, rename = "inter_af_face_amt")]
+    pub inter_af_face_amt: Decimal,
+    /// 매매거래정지기간
+    #[serde(default, rename = "td_stop_dt")]
+    pub td_stop_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(액면교체일정)[국내주식-148]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoRevSplitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoRevSplitOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoRevSplitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(자본감소일정)[국내주식-149]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoCapDcrsOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 감자구분
+    #[serde(default, rename = "reduce_cap_type")]
+    pub reduce_cap_type: String,
+    /// 감자배정율
+    #[serde(default, rename = "reduce_cap_rate")]
+    pub reduce_cap_rate: Decimal,
+    /// 계산방법
+    #[serde(default, rename = "comp_way")]
+    pub comp_way: String,
+    /// 매매거래정지기간
+    #[serde(default, rename = "td_stop_dt")]
+    pub td_stop_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(자본감소일정)[국내주식-149]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoCapDcrsResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoCapDcrsOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoCapDcrsResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(상장정보일정)[국내주식-150]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoListInfoOutput1Item {
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 사유
+    #[serde(default, rename = "issue_type")]
+    pub issue_type: String,
+    /// 상장주식수
+    #[serde(default, rename = "issue_stk_qty")]
+    pub issue_stk_qty: Decimal,
+    /// 총발행주식수
+    #[serde(default, rename = "tot_issue_stk_qty")]
+    pub tot_issue_stk_qty: Decimal,
+    /// 발행가
+    #[serde(default, rename = "issue_price")]
+    pub issue_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(상장정보일정)[국내주식-150]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoListInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoListInfoOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoListInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(공모주청약일정)[국내주식-151]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPubOfferOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 공모가
+    #[serde(default, rename = "fix_subscr_pri")]
+    pub fix_subscr_pri: String,
+    /// 액면가
+    #[serde(default, rename = "face_value")]
+    pub face_value: String,
+    /// 청약기간
+    #[serde(default, rename = "subscr_dt")]
+    pub subscr_dt: String,
+    /// 납입일
+    #[serde(default, rename = "pay_dt")]
+    pub pay_dt: String,
+    /// 환불일
+    #[serde(default, rename = "refund_dt")]
+    pub refund_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 주간사
+    #[serde(default, rename = "lead_mgr")]
+    pub lead_mgr: String,
+    /// 공모전자본금
+    #[serde(default, rename = "pub_bf_cap")]
+    pub pub_bf_cap: String,
+    /// 공모후자본금
+    #[serde(default, rename = "pub_af_cap")]
+    pub pub_af_cap: String,
+    /// 당사배정물량
+    #[serde(default, rename = "assign_stk_qty")]
+    pub assign_stk_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(공모주청약일정)[국내주식-151]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoPubOfferResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoPubOfferOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoPubOfferResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(실권주일정)[국내주식-152]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoForfeitOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 청약일
+    #[serde(default, rename = "subscr_dt")]
+    pub subscr_dt: String,
+    /// 공모가
+    #[serde(default, rename = "subscr_price")]
+    pub subscr_price: String,
+    /// 공모주식수
+    #[serde(default, rename = "subscr_stk_qty")]
+    pub subscr_stk_qty: Decimal,
+    /// 환불일
+    #[serde(default, rename = "refund_dt")]
+    pub refund_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_dt")]
+    pub list_dt: String,
+    /// 주간사
+    #[serde(default, rename = "lead_mgr")]
+    pub lead_mgr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(실권주일정)[국내주식-152]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoForfeitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoForfeitOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoForfeitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(의무예치일정)[국내주식-153]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoMandDepositOutput1Item {
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 주식수
+    #[serde(default, rename = "stk_qty")]
+    pub stk_qty: Decimal,
+    /// 예치일
+    #[serde(default, rename = "depo_date")]
+    pub depo_date: String,
+    /// 사유
+    #[serde(default, rename = "depo_reason")]
+    pub depo_reason: String,
+    /// 총발행주식수대비비율(%)
+    #[serde(default, rename = "tot_issue_qty_per_rate")]
+    pub tot_issue_qty_per_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(의무예치일정)[국내주식-153]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoMandDepositResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoMandDepositOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoMandDepositResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(유상증자일정) [국내주식-143]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoPaidinCapinOutputItem {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 발행주식
+    #[serde(default, rename = "tot_issue_stk_qty")]
+    pub tot_issue_stk_qty: Decimal,
+    /// 발행할주식
+    #[serde(default, rename = "issue_stk_qty")]
+    pub issue_stk_qty: Decimal,
+    /// 확정배정율
+    #[serde(default, rename = "fix_rate")]
+    pub fix_rate: Decimal,
+    /// 할인율
+    #[serde(default, rename = "disc_rate")]
+    pub disc_rate: Decimal,
+    /// 발행예정가
+    #[serde(default, rename = "fix_price")]
+    pub fix_price: String,
+    /// 권리락일
+    #[serde(default, rename = "right_dt")]
+    pub right_dt: String,
+    /// 청약기간
+    #[serde(default, rename = "sub_term_ft")]
+    pub sub_term_ft: String,
+    /// 청약기간
+    #[serde(default, rename = "sub_term")]
+    pub sub_term: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_date")]
+    pub list_date: String,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(유상증자일정) [국내주식-143]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoPaidinCapinResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1KsdinfoPaidinCapinOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoPaidinCapinResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(무상증자일정) [국내주식-144]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoBonusIssueOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 확정배정율
+    #[serde(default, rename = "fix_rate")]
+    pub fix_rate: Decimal,
+    /// 단주기준가
+    #[serde(default, rename = "odd_rec_price")]
+    pub odd_rec_price: String,
+    /// 권리락일
+    #[serde(default, rename = "right_dt")]
+    pub right_dt: String,
+    /// 단주대금지급일
+    #[serde(default, rename = "odd_pay_dt")]
+    pub odd_pay_dt: String,
+    /// 상장/등록일
+    #[serde(default, rename = "list_date")]
+    pub list_date: String,
+    /// 발행주식
+    #[serde(default, rename = "tot_issue_stk_qty")]
+    pub tot_issue_stk_qty: Decimal,
+    /// 발행할주식
+    #[serde(default, rename = "issue_stk_qty")]
+    pub issue_stk_qty: Decimal,
+    /// 주식종류
+    #[serde(default, rename = "stk_kind")]
+    pub stk_kind: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(무상증자일정) [국내주식-144]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoBonusIssueResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoBonusIssueOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoBonusIssueResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [예탁원정보(주주총회일정) [국내주식-154]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1KsdinfoSharehldMeetOutput1Item {
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 주총일자
+    #[serde(default, rename = "gen_meet_dt")]
+    pub gen_meet_dt: String,
+    /// 주총사유
+    #[serde(default, rename = "gen_meet_type")]
+    pub gen_meet_type: String,
+    /// 주총의안
+    #[serde(default, rename = "agenda")]
+    pub agenda: String,
+    /// 의결권주식총수
+    #[serde(default, rename = "vote_tot_qty")]
+    pub vote_tot_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [예탁원정보(주주총회일정) [국내주식-154]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1KsdinfoSharehldMeetResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1KsdinfoSharehldMeetOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1KsdinfoSharehldMeetResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsEstimatePerformOutput1Item {
+    /// ELW단축종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "item_kor_nm")]
+    pub item_kor_nm: String,
+    /// ELW현재가
+    #[serde(default, rename = "name1")]
+    pub name1: String,
+    /// 전일대비
+    #[serde(default, rename = "name2")]
+    pub name2: String,
+    /// 전일대비부호
+    #[serde(default, rename = "estdate")]
+    pub estdate: String,
+    /// 전일대비율
+    #[serde(default, rename = "rcmd_name")]
+    pub rcmd_name: String,
+    /// 누적거래량
+    #[serde(default, rename = "capital")]
+    pub capital: String,
+    /// 행사가
+    #[serde(default, rename = "forn_item_lmtrt")]
+    pub forn_item_lmtrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsEstimatePerformOutput2Item {
+    /// DATA1
+    #[serde(default, rename = "data1")]
+    pub data1: String,
+    /// DATA2
+    #[serde(default, rename = "data2")]
+    pub data2: String,
+    /// DATA3
+    #[serde(default, rename = "data3")]
+    pub data3: String,
+    /// DATA4
+    #[serde(default, rename = "data4")]
+    pub data4: String,
+    /// DATA5
+    #[serde(default, rename = "data5")]
+    pub data5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsEstimatePerformOutput3Item {
+    /// DATA1
+    #[serde(default, rename = "data1")]
+    pub data1: String,
+    /// DATA2
+    #[serde(default, rename = "data2")]
+    pub data2: String,
+    /// DATA3
+    #[serde(default, rename = "data3")]
+    pub data3: String,
+    /// DATA4
+    #[serde(default, rename = "data4")]
+    pub data4: String,
+    /// DATA5
+    #[serde(default, rename = "data5")]
+    pub data5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] output4 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsEstimatePerformOutput4Item {
+    /// 결산년월
+    #[serde(default, rename = "dt")]
+    pub dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 종목추정실적 [국내주식-187]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsEstimatePerformResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsEstimatePerformOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsEstimatePerformOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<DomesticStockV1QuotationsEstimatePerformOutput3Item>,
+    #[serde(default)]
+    pub output4: Vec<DomesticStockV1QuotationsEstimatePerformOutput4Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsEstimatePerformResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        for item in &self.output4 {
+            fields.extend(item.extra.keys().map(|k| format!("output4.{k}")));
+        }
+        fields
+    }
+}
+
+/// [당사 대주가능 종목 [국내주식-195]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsLendableByCompanyOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 액면가
+    #[serde(default, rename = "papr")]
+    pub papr: String,
+    /// 전일종가
+    #[serde(default, rename = "bfdy_clpr")]
+    pub bfdy_clpr: String,
+    /// 대용가
+    #[serde(default, rename = "sbst_prvs")]
+    pub sbst_prvs: String,
+    /// 거래정지구분명
+    #[serde(default, rename = "tr_stop_dvsn_name")]
+    pub tr_stop_dvsn_name: String,
+    /// 가능여부명
+    #[serde(default, rename = "psbl_yn_name")]
+    pub psbl_yn_name: String,
+    /// 한도수량1
+    #[serde(default, rename = "lmt_qty1")]
+    pub lmt_qty1: String,
+    /// 사용수량1
+    #[serde(default, rename = "use_qty1")]
+    pub use_qty1: String,
+    /// 매매가능수량2
+    #[serde(default, rename = "trad_psbl_qty2")]
+    pub trad_psbl_qty2: String,
+    /// 권리유형코드
+    #[serde(default, rename = "rght_type_cd")]
+    pub rght_type_cd: String,
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 가능여부
+    #[serde(default, rename = "psbl_yn")]
+    pub psbl_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [당사 대주가능 종목 [국내주식-195]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsLendableByCompanyOutput2Item {
+    /// 총설정한도수량
+    #[serde(default, rename = "tot_stup_lmt_qty")]
+    pub tot_stup_lmt_qty: Decimal,
+    /// 지점한도수량
+    #[serde(default, rename = "brch_lmt_qty")]
+    pub brch_lmt_qty: Decimal,
+    /// 신청가능수량
+    #[serde(default, rename = "rqst_psbl_qty")]
+    pub rqst_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [당사 대주가능 종목 [국내주식-195]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsLendableByCompanyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsLendableByCompanyOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsLendableByCompanyOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsLendableByCompanyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 종목투자의견 [국내주식-188]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestOpinionOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 투자의견
+    #[serde(default, rename = "invt_opnn")]
+    pub invt_opnn: String,
+    /// 투자의견구분코드
+    #[serde(default, rename = "invt_opnn_cls_code")]
+    pub invt_opnn_cls_code: String,
+    /// 직전투자의견
+    #[serde(default, rename = "rgbf_invt_opnn")]
+    pub rgbf_invt_opnn: String,
+    /// 직전투자의견구분코드
+    #[serde(default, rename = "rgbf_invt_opnn_cls_code")]
+    pub rgbf_invt_opnn_cls_code: String,
+    /// 회원사명
+    #[serde(default, rename = "mbcr_name")]
+    pub mbcr_name: String,
+    /// HTS목표가격
+    #[serde(default, rename = "hts_goal_prc")]
+    pub hts_goal_prc: String,
+    /// 주식전일종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 주식N일괴리도
+    #[serde(default, rename = "stck_nday_esdg")]
+    pub stck_nday_esdg: String,
+    /// N일괴리율
+    #[serde(default, rename = "nday_dprt")]
+    pub nday_dprt: String,
+    /// 주식선물괴리도
+    #[serde(default, rename = "stft_esdg")]
+    pub stft_esdg: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 종목투자의견 [국내주식-188]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInvestOpinionResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInvestOpinionOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInvestOpinionResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 증권사별 투자의견 [국내주식-189]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestOpbysecOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식단축종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 투자의견
+    #[serde(default, rename = "invt_opnn")]
+    pub invt_opnn: String,
+    /// 투자의견구분코드
+    #[serde(default, rename = "invt_opnn_cls_code")]
+    pub invt_opnn_cls_code: String,
+    /// 직전투자의견
+    #[serde(default, rename = "rgbf_invt_opnn")]
+    pub rgbf_invt_opnn: String,
+    /// 직전투자의견구분코드
+    #[serde(default, rename = "rgbf_invt_opnn_cls_code")]
+    pub rgbf_invt_opnn_cls_code: String,
+    /// 회원사명
+    #[serde(default, rename = "mbcr_name")]
+    pub mbcr_name: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// HTS목표가격
+    #[serde(default, rename = "hts_goal_prc")]
+    pub hts_goal_prc: String,
+    /// 주식전일종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 주식선물괴리도
+    #[serde(default, rename = "stft_esdg")]
+    pub stft_esdg: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 증권사별 투자의견 [국내주식-189]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInvestOpbysecResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInvestOpbysecOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInvestOpbysecResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목조건검색 목록조회[국내주식-038]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPsearchTitleOutput2Item {
+    /// HTS ID
+    #[serde(default, rename = "user_id")]
+    pub user_id: String,
+    /// 조건키값
+    #[serde(default, rename = "seq")]
+    pub seq: String,
+    /// 그룹명
+    #[serde(default, rename = "grp_nm")]
+    pub grp_nm: String,
+    /// 조건명
+    #[serde(default, rename = "condition_nm")]
+    pub condition_nm: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목조건검색 목록조회[국내주식-038]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsPsearchTitleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsPsearchTitleOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsPsearchTitleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목조건검색조회 [국내주식-039]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPsearchResultOutput2Item {
+    /// 종목코드
+    #[serde(default, rename = "code")]
+    pub code: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 전일대비부호
+    #[serde(default, rename = "daebi")]
+    pub daebi: String,
+    /// 현재가
+    #[serde(default, rename = "price")]
+    pub price: Decimal,
+    /// 등락율
+    #[serde(default, rename = "chgrate")]
+    pub chgrate: String,
+    /// 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 거래대금
+    #[serde(default, rename = "trade_amt")]
+    pub trade_amt: Decimal,
+    /// 전일대비
+    #[serde(default, rename = "change")]
+    pub change: String,
+    /// 체결강도
+    #[serde(default, rename = "cttr")]
+    pub cttr: String,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// 52주최고가
+    #[serde(default, rename = "high52")]
+    pub high52: String,
+    /// 52주최저가
+    #[serde(default, rename = "low52")]
+    pub low52: String,
+    /// 예상체결가
+    #[serde(default, rename = "expprice")]
+    pub expprice: String,
+    /// 예상대비
+    #[serde(default, rename = "expchange")]
+    pub expchange: String,
+    /// 예상등락률
+    #[serde(default, rename = "expchggrate")]
+    pub expchggrate: String,
+    /// 예상체결수량
+    #[serde(default, rename = "expcvol")]
+    pub expcvol: String,
+    /// 전일거래량대비율
+    #[serde(default, rename = "chgrate2")]
+    pub chgrate2: String,
+    /// 예상대비부호
+    #[serde(default, rename = "expdaebi")]
+    pub expdaebi: String,
+    /// 기준가
+    #[serde(default, rename = "recprice")]
+    pub recprice: String,
+    /// 상한가
+    #[serde(default, rename = "uplmtprice")]
+    pub uplmtprice: String,
+    /// 하한가
+    #[serde(default, rename = "dnlmtprice")]
+    pub dnlmtprice: String,
+    /// 시가총액
+    #[serde(default, rename = "stotprice")]
+    pub stotprice: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목조건검색조회 [국내주식-039]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsPsearchResultResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsPsearchResultOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsPsearchResultResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [관심종목 그룹조회 [국내주식-204]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockGrouplistOutput2Item {
+    /// 일자
+    #[serde(default, rename = "date")]
+    pub date: String,
+    /// 전송 시간
+    #[serde(default, rename = "trnm_hour")]
+    pub trnm_hour: String,
+    /// 데이터 순위     
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 관심 그룹 코드
+    #[serde(default, rename = "inter_grp_code")]
+    pub inter_grp_code: String,
+    /// 관심 그룹 명
+    #[serde(default, rename = "inter_grp_name")]
+    pub inter_grp_name: String,
+    /// 요청 개수
+    #[serde(
+        default,
+        rename = "ask_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ask_cnt: i64,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [관심종목 그룹조회 [국내주식-204]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsIntstockGrouplistResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsIntstockGrouplistOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsIntstockGrouplistResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [관심종목(멀티종목) 시세조회 [국내주식-205]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockMultpriceOutputItem {
+    /// 코스피 코스닥 구분 명
+    #[serde(default, rename = "kospi_kosdaq_cls_name")]
+    pub kospi_kosdaq_cls_name: String,
+    /// 시장 조치 구분 명
+    #[serde(default, rename = "mrkt_trtm_cls_name")]
+    pub mrkt_trtm_cls_name: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "hour_cls_code")]
+    pub hour_cls_code: String,
+    /// 관심 단축 종목코드
+    #[serde(default, rename = "inter_shrn_iscd")]
+    pub inter_shrn_iscd: String,
+    /// 관심 한글 종목명
+    #[serde(default, rename = "inter_kor_isnm")]
+    pub inter_kor_isnm: String,
+    /// 관심2 현재가
+    #[serde(default, rename = "inter2_prpr")]
+    pub inter2_prpr: String,
+    /// 관심2 전일 대비
+    #[serde(default, rename = "inter2_prdy_vrss")]
+    pub inter2_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 관심2 시가
+    #[serde(default, rename = "inter2_oprc")]
+    pub inter2_oprc: String,
+    /// 관심2 고가
+    #[serde(default, rename = "inter2_hgpr")]
+    pub inter2_hgpr: String,
+    /// 관심2 저가
+    #[serde(default, rename = "inter2_lwpr")]
+    pub inter2_lwpr: String,
+    /// 관심2 하한가
+    #[serde(default, rename = "inter2_llam")]
+    pub inter2_llam: String,
+    /// 관심2 상한가
+    #[serde(default, rename = "inter2_mxpr")]
+    pub inter2_mxpr: String,
+    /// 관심2 매도호가
+    #[serde(default, rename = "inter2_askp")]
+    pub inter2_askp: String,
+    /// 관심2 매수호가
+    #[serde(default, rename = "inter2_bidp")]
+    pub inter2_bidp: String,
+    /// 매도 잔량
+    #[serde(default, rename = "seln_rsqn")]
+    pub seln_rsqn: String,
+    /// 매수2 잔량
+    #[serde(default, rename = "shnu_rsqn")]
+    pub shnu_rsqn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 관심2 전일 종가
+    #[serde(default, rename = "inter2_prdy_clpr")]
+    pub inter2_prdy_clpr: String,
+    /// 시가 대비 최고가 비율
+    #[serde(default, rename = "oprc_vrss_hgpr_rate")]
+    pub oprc_vrss_hgpr_rate: Decimal,
+    /// 관심 예상 체결 대비
+    #[serde(default, rename = "intr_antc_cntg_vrss")]
+    pub intr_antc_cntg_vrss: String,
+    /// 관심 예상 체결 대비 부호
+    #[serde(default, rename = "intr_antc_cntg_vrss_sign")]
+    pub intr_antc_cntg_vrss_sign: String,
+    /// 관심 예상 체결 전일 대비율
+    #[serde(default, rename = "intr_antc_cntg_prdy_ctrt")]
+    pub intr_antc_cntg_prdy_ctrt: String,
+    /// 관심 예상 거래량
+    #[serde(default, rename = "intr_antc_vol")]
+    pub intr_antc_vol: Decimal,
+    /// 관심2 기준가
+    #[serde(default, rename = "inter2_sdpr")]
+    pub inter2_sdpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [관심종목(멀티종목) 시세조회 [국내주식-205]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsIntstockMultpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsIntstockMultpriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsIntstockMultpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [관심종목 그룹별 종목조회 [국내주식-203]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockStocklistByGroupOutput1Item {
+    /// 데이터 순위     
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 관심 그룹 명
+    #[serde(default, rename = "inter_grp_name")]
+    pub inter_grp_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [관심종목 그룹별 종목조회 [국내주식-203]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsIntstockStocklistByGroupOutput2Item {
+    /// FID 시장 구분 코드
+    #[serde(default, rename = "fid_mrkt_cls_code")]
+    pub fid_mrkt_cls_code: String,
+    /// 데이터 순위          
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 거래소코드               
+    #[serde(default, rename = "exch_code")]
+    pub exch_code: String,
+    /// 종목코드               
+    #[serde(default, rename = "jong_code")]
+    pub jong_code: String,
+    /// 생상 코드              
+    #[serde(default, rename = "color_code")]
+    pub color_code: String,
+    /// 메모                     
+    #[serde(default, rename = "memo")]
+    pub memo: String,
+    /// HTS 한글 종목명    
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 기준일 순매수 수량
+    #[serde(default, rename = "fxdt_ntby_qty")]
+    pub fxdt_ntby_qty: Decimal,
+    /// 체결단가             
+    #[serde(default, rename = "cntg_unpr")]
+    pub cntg_unpr: Decimal,
+    /// 체결 구분 코드        
+    #[serde(default, rename = "cntg_cls_code")]
+    pub cntg_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [관심종목 그룹별 종목조회 [국내주식-203]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsIntstockStocklistByGroupResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsIntstockStocklistByGroupOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsIntstockStocklistByGroupOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsIntstockStocklistByGroupResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내기관_외국인 매매종목가집계[국내주식-037]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsForeignInstitutionTotalOutputItem {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 순매수 수량
+    #[serde(default, rename = "ntby_qty")]
+    pub ntby_qty: Decimal,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 기관계 순매수 수량
+    #[serde(default, rename = "orgn_ntby_qty")]
+    pub orgn_ntby_qty: Decimal,
+    /// 투자신탁 순매수 수량
+    #[serde(default, rename = "ivtr_ntby_qty")]
+    pub ivtr_ntby_qty: Decimal,
+    /// 은행 순매수 수량
+    #[serde(default, rename = "bank_ntby_qty")]
+    pub bank_ntby_qty: Decimal,
+    /// 보험 순매수 수량
+    #[serde(default, rename = "insu_ntby_qty")]
+    pub insu_ntby_qty: Decimal,
+    /// 종금 순매수 수량
+    #[serde(default, rename = "mrbn_ntby_qty")]
+    pub mrbn_ntby_qty: Decimal,
+    /// 기금 순매수 수량
+    #[serde(default, rename = "fund_ntby_qty")]
+    pub fund_ntby_qty: Decimal,
+    /// 기타 단체 순매수 거래량
+    #[serde(default, rename = "etc_orgt_ntby_vol")]
+    pub etc_orgt_ntby_vol: Decimal,
+    /// 기타 법인 순매수 거래량
+    #[serde(default, rename = "etc_corp_ntby_vol")]
+    pub etc_corp_ntby_vol: Decimal,
+    /// 외국인 순매수 거래 대금
+    #[serde(default, rename = "frgn_ntby_tr_pbmn")]
+    pub frgn_ntby_tr_pbmn: String,
+    /// 기관계 순매수 거래 대금
+    #[serde(default, rename = "orgn_ntby_tr_pbmn")]
+    pub orgn_ntby_tr_pbmn: String,
+    /// 투자신탁 순매수 거래 대금
+    #[serde(default, rename = "ivtr_ntby_tr_pbmn")]
+    pub ivtr_ntby_tr_pbmn: String,
+    /// 은행 순매수 거래 대금
+    #[serde(default, rename = "bank_ntby_tr_pbmn")]
+    pub bank_ntby_tr_pbmn: String,
+    /// 보험 순매수 거래 대금
+    #[serde(default, rename = "insu_ntby_tr_pbmn")]
+    pub insu_ntby_tr_pbmn: String,
+    /// 종금 순매수 거래 대금
+    #[serde(default, rename = "mrbn_ntby_tr_pbmn")]
+    pub mrbn_ntby_tr_pbmn: String,
+    /// 기금 순매수 거래 대금
+    #[serde(default, rename = "fund_ntby_tr_pbmn")]
+    pub fund_ntby_tr_pbmn: String,
+    /// 기타 단체 순매수 거래 대금
+    #[serde(default, rename = "etc_orgt_ntby_tr_pbmn")]
+    pub etc_orgt_ntby_tr_pbmn: String,
+    /// 기타 법인 순매수 거래 대금
+    #[serde(default, rename = "etc_corp_ntby_tr_pbmn")]
+    pub etc_corp_ntby_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내기관_외국인 매매종목가집계[국내주식-037]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsForeignInstitutionTotalResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsForeignInstitutionTotalOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsForeignInstitutionTotalResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [외국계 매매종목 가집계 [국내주식-161]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeEstimateOutputItem {
+    /// 주식단축종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 외국계순매도수량
+    #[serde(default, rename = "glob_ntsl_qty")]
+    pub glob_ntsl_qty: Decimal,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 외국계총매도수량
+    #[serde(default, rename = "glob_total_seln_qty")]
+    pub glob_total_seln_qty: Decimal,
+    /// 외국계총매수2수량
+    #[serde(default, rename = "glob_total_shnu_qty")]
+    pub glob_total_shnu_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [외국계 매매종목 가집계 [국내주식-161]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeEstimateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsFrgnmemTradeEstimateOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsFrgnmemTradeEstimateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 투자자매매동향(일별)] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorTradeByStockDailyOutput1Item {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 투자자매매동향(일별)] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorTradeByStockDailyOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 외국인 등록 순매수 수량
+    #[serde(default, rename = "frgn_reg_ntby_qty")]
+    pub frgn_reg_ntby_qty: Decimal,
+    /// 외국인 비등록 순매수 수량
+    #[serde(default, rename = "frgn_nreg_ntby_qty")]
+    pub frgn_nreg_ntby_qty: Decimal,
+    /// 개인 순매수 수량
+    #[serde(default, rename = "prsn_ntby_qty")]
+    pub prsn_ntby_qty: Decimal,
+    /// 기관계 순매수 수량
+    #[serde(default, rename = "orgn_ntby_qty")]
+    pub orgn_ntby_qty: Decimal,
+    /// 증권 순매수 수량
+    #[serde(default, rename = "scrt_ntby_qty")]
+    pub scrt_ntby_qty: Decimal,
+    /// 투자신탁 순매수 수량
+    #[serde(default, rename = "ivtr_ntby_qty")]
+    pub ivtr_ntby_qty: Decimal,
+    /// 사모 펀드 순매수 거래량
+    #[serde(default, rename = "pe_fund_ntby_vol")]
+    pub pe_fund_ntby_vol: Decimal,
+    /// 은행 순매수 수량
+    #[serde(default, rename = "bank_ntby_qty")]
+    pub bank_ntby_qty: Decimal,
+    /// 보험 순매수 수량
+    #[serde(default, rename = "insu_ntby_qty")]
+    pub insu_ntby_qty: Decimal,
+    /// 종금 순매수 수량
+    #[serde(default, rename = "mrbn_ntby_qty")]
+    pub mrbn_ntby_qty: Decimal,
+    /// 기금 순매수 수량
+    #[serde(default, rename = "fund_ntby_qty")]
+    pub fund_ntby_qty: Decimal,
+    /// 기타 순매수 수량
+    #[serde(default, rename = "etc_ntby_qty")]
+    pub etc_ntby_qty: Decimal,
+    /// 기타 법인 순매수 거래량
+    #[serde(default, rename = "etc_corp_ntby_vol")]
+    pub etc_corp_ntby_vol: Decimal,
+    /// 기타 단체 순매수 거래량
+    #[serde(default, rename = "etc_orgt_ntby_vol")]
+    pub etc_orgt_ntby_vol: Decimal,
+    /// 외국인 등록 순매수 대금
+    #[serde(default, rename = "frgn_reg_ntby_pbmn")]
+    pub frgn_reg_ntby_pbmn: String,
+    /// 외국인 순매수 거래 대금
+    #[serde(default, rename = "frgn_ntby_tr_pbmn")]
+    pub frgn_ntby_tr_pbmn: String,
+    /// 외국인 비등록 순매수 대금
+    #[serde(default, rename = "frgn_nreg_ntby_pbmn")]
+    pub frgn_nreg_ntby_pbmn: String,
+    /// 개인 순매수 거래 대금
+    #[serde(default, rename = "prsn_ntby_tr_pbmn")]
+    pub prsn_ntby_tr_pbmn: String,
+    /// 기관계 순매수 거래 대금
+    #[serde(default, rename = "orgn_ntby_tr_pbmn")]
+    pub orgn_ntby_tr_pbmn: String,
+    /// 증권 순매수 거래 대금
+    #[serde(default, rename = "scrt_ntby_tr_pbmn")]
+    pub scrt_ntby_tr_pbmn: String,
+    /// 사모 펀드 순매수 거래 대금
+    #[serde(default, rename = "pe_fund_ntby_tr_pbmn")]
+    pub pe_fund_ntby_tr_pbmn: String,
+    /// 투자신탁 순매수 거래 대금
+    #[serde(default, rename = "ivtr_ntby_tr_pbmn")]
+    pub ivtr_ntby_tr_pbmn: String,
+    /// 은행 순매수 거래 대금
+    #[serde(default, rename = "bank_ntby_tr_pbmn")]
+    pub bank_ntby_tr_pbmn: String,
+    /// 보험 순매수 거래 대금
+    #[serde(default, rename = "insu_ntby_tr_pbmn")]
+    pub insu_ntby_tr_pbmn: String,
+    /// 종금 순매수 거래 대금
+    #[serde(default, rename = "mrbn_ntby_tr_pbmn")]
+    pub mrbn_ntby_tr_pbmn: String,
+    /// 기금 순매수 거래 대금
+    #[serde(default, rename = "fund_ntby_tr_pbmn")]
+    pub fund_ntby_tr_pbmn: String,
+    /// 기타 순매수 거래 대금
+    #[serde(default, rename = "etc_ntby_tr_pbmn")]
+    pub etc_ntby_tr_pbmn: String,
+    /// 기타 법인 순매수 거래 대금
+    #[serde(default, rename = "etc_corp_ntby_tr_pbmn")]
+    pub etc_corp_ntby_tr_pbmn: String,
+    /// 기타 단체 순매수 거래 대금
+    #[serde(default, rename = "etc_orgt_ntby_tr_pbmn")]
+    pub etc_orgt_ntby_tr_pbmn: String,
+    /// 외국인 매도 거래량
+    #[serde(default, rename = "frgn_seln_vol")]
+    pub frgn_seln_vol: Decimal,
+    /// 외국인 매수2 거래량
+    #[serde(default, rename = "frgn_shnu_vol")]
+    pub frgn_shnu_vol: Decimal,
+    /// 외국인 매도 거래 대금
+    #[serde(default, rename = "frgn_seln_tr_pbmn")]
+    pub frgn_seln_tr_pbmn: String,
+    /// 외국인 매수2 거래 대금
+    #[serde(default, rename = "frgn_shnu_tr_pbmn")]
+    pub frgn_shnu_tr_pbmn: String,
+    /// 외국인 등록 매도 수량
+    #[serde(default, rename = "frgn_reg_askp_qty")]
+    pub frgn_reg_askp_qty: Decimal,
+    /// 외국인 등록 매수 수량
+    #[serde(default, rename = "frgn_reg_bidp_qty")]
+    pub frgn_reg_bidp_qty: Decimal,
+    /// 외국인 등록 매도 대금
+    #[serde(default, rename = "frgn_reg_askp_pbmn")]
+    pub frgn_reg_askp_pbmn: String,
+    /// 외국인 등록 매수 대금
+    #[serde(default, rename = "frgn_reg_bidp_pbmn")]
+    pub frgn_reg_bidp_pbmn: String,
+    /// 외국인 비등록 매도 수량
+    #[serde(default, rename = "frgn_nreg_askp_qty")]
+    pub frgn_nreg_askp_qty: Decimal,
+    /// 외국인 비등록 매수 수량
+    #[serde(default, rename = "frgn_nreg_bidp_qty")]
+    pub frgn_nreg_bidp_qty: Decimal,
+    /// 외국인 비등록 매도 대금
+    #[serde(default, rename = "frgn_nreg_askp_pbmn")]
+    pub frgn_nreg_askp_pbmn: String,
+    /// 외국인 비등록 매수 대금
+    #[serde(default, rename = "frgn_nreg_bidp_pbmn")]
+    pub frgn_nreg_bidp_pbmn: String,
+    /// 개인 매도 거래량
+    #[serde(default, rename = "prsn_seln_vol")]
+    pub prsn_seln_vol: Decimal,
+    /// 개인 매수2 거래량
+    #[serde(default, rename = "prsn_shnu_vol")]
+    pub prsn_shnu_vol: Decimal,
+    /// 개인 매도 거래 대금
+    #[serde(default, rename = "prsn_seln_tr_pbmn")]
+    pub prsn_seln_tr_pbmn: String,
+    /// 개인 매수2 거래 대금
+    #[serde(default, rename = "prsn_shnu_tr_pbmn")]
+    pub prsn_shnu_tr_pbmn: String,
+    /// 기관계 매도 거래량
+    #[serde(default, rename = "orgn_seln_vol")]
+    pub orgn_seln_vol: Decimal,
+    /// 기관계 매수2 거래량
+    #[serde(default, rename = "orgn_shnu_vol")]
+    pub orgn_shnu_vol: Decimal,
+    /// 기관계 매도 거래 대금
+    #[serde(default, rename = "orgn_seln_tr_pbmn")]
+    pub orgn_seln_tr_pbmn: String,
+    /// 기관계 매수2 거래 대금
+    #[serde(default, rename = "orgn_shnu_tr_pbmn")]
+    pub orgn_shnu_tr_pbmn: String,
+    /// 증권 매도 거래량
+    #[serde(default, rename = "scrt_seln_vol")]
+    pub scrt_seln_vol: Decimal,
+    /// 증권 매수2 거래량
+    #[serde(default, rename = "scrt_shnu_vol")]
+    pub scrt_shnu_vol: Decimal,
+    /// 증권 매도 거래 대금
+    #[serde(default, rename = "scrt_seln_tr_pbmn")]
+    pub scrt_seln_tr_pbmn: String,
+    /// 증권 매수2 거래 대금
+    #[serde(default, rename = "scrt_shnu_tr_pbmn")]
+    pub scrt_shnu_tr_pbmn: String,
+    /// 투자신탁 매도 거래량
+    #[serde(default, rename = "ivtr_seln_vol")]
+    pub ivtr_seln_vol: Decimal,
+    /// 투자신탁 매수2 거래량
+    #[serde(default, rename = "ivtr_shnu_vol")]
+    pub ivtr_shnu_vol: Decimal,
+    /// 투자신탁 매도 거래 대금
+    #[serde(default, rename = "ivtr_seln_tr_pbmn")]
+    pub ivtr_seln_tr_pbmn: String,
+    /// 투자신탁 매수2 거래 대금
+    #[serde(default, rename = "ivtr_shnu_tr_pbmn")]
+    pub ivtr_shnu_tr_pbmn: String,
+    /// 사모 펀드 매도 거래 대금
+    #[serde(default, rename = "pe_fund_seln_tr_pbmn")]
+    pub pe_fund_seln_tr_pbmn: String,
+    /// 사모 펀드 매도 거래량
+    #[serde(default, rename = "pe_fund_seln_vol")]
+    pub pe_fund_seln_vol: Decimal,
+    /// 사모 펀드 매수2 거래 대금
+    #[serde(default, rename = "pe_fund_shnu_tr_pbmn")]
+    pub pe_fund_shnu_tr_pbmn: String,
+    /// 사모 펀드 매수2 거래량
+    #[serde(default, rename = "pe_fund_shnu_vol")]
+    pub pe_fund_shnu_vol: Decimal,
+    /// 은행 매도 거래량
+    #[serde(default, rename = "bank_seln_vol")]
+    pub bank_seln_vol: Decimal,
+    /// 은행 매수2 거래량
+    #[serde(default, rename = "bank_shnu_vol")]
+    pub bank_shnu_vol: Decimal,
+    /// 은행 매도 거래 대금
+    #[serde(default, rename = "bank_seln_tr_pbmn")]
+    pub bank_seln_tr_pbmn: String,
+    /// 은행 매수2 거래 대금
+    #[serde(default, rename = "bank_shnu_tr_pbmn")]
+    pub bank_shnu_tr_pbmn: String,
+    /// 보험 매도 거래량
+    #[serde(default, rename = "insu_seln_vol")]
+    pub insu_seln_vol: Decimal,
+    /// 보험 매수2 거래량
+    #[serde(default, rename = "insu_shnu_vol")]
+    pub insu_shnu_vol: Decimal,
+    /// 보험 매도 거래 대금
+    #[serde(default, rename = "insu_seln_tr_pbmn")]
+    pub insu_seln_tr_pbmn: String,
+    /// 보험 매수2 거래 대금
+    #[serde(default, rename = "insu_shnu_tr_pbmn")]
+    pub insu_shnu_tr_pbmn: String,
+    /// 종금 매도 거래량
+    #[serde(default, rename = "mrbn_seln_vol")]
+    pub mrbn_seln_vol: Decimal,
+    /// 종금 매수2 거래량
+    #[serde(default, rename = "mrbn_shnu_vol")]
+    pub mrbn_shnu_vol: Decimal,
+    /// 종금 매도 거래 대금
+    #[serde(default, rename = "mrbn_seln_tr_pbmn")]
+    pub mrbn_seln_tr_pbmn: String,
+    /// 종금 매수2 거래 대금
+    #[serde(default, rename = "mrbn_shnu_tr_pbmn")]
+    pub mrbn_shnu_tr_pbmn: String,
+    /// 기금 매도 거래량
+    #[serde(default, rename = "fund_seln_vol")]
+    pub fund_seln_vol: Decimal,
+    /// 기금 매수2 거래량
+    #[serde(default, rename = "fund_shnu_vol")]
+    pub fund_shnu_vol: Decimal,
+    /// 기금 매도 거래 대금
+    #[serde(default, rename = "fund_seln_tr_pbmn")]
+    pub fund_seln_tr_pbmn: String,
+    /// 기금 매수2 거래 대금
+    #[serde(default, rename = "fund_shnu_tr_pbmn")]
+    pub fund_shnu_tr_pbmn: String,
+    /// 기타 매도 거래량
+    #[serde(default, rename = "etc_seln_vol")]
+    pub etc_seln_vol: Decimal,
+    /// 기타 매수2 거래량
+    #[serde(default, rename = "etc_shnu_vol")]
+    pub etc_shnu_vol: Decimal,
+    /// 기타 매도 거래 대금
+    #[serde(default, rename = "etc_seln_tr_pbmn")]
+    pub etc_seln_tr_pbmn: String,
+    /// 기타 매수2 거래 대금
+    #[serde(default, rename = "etc_shnu_tr_pbmn")]
+    pub etc_shnu_tr_pbmn: String,
+    /// 기타 단체 매도 거래량
+    #[serde(default, rename = "etc_orgt_seln_vol")]
+    pub etc_orgt_seln_vol: Decimal,
+    /// 기타 단체 매수2 거래량
+    #[serde(default, rename = "etc_orgt_shnu_vol")]
+    pub etc_orgt_shnu_vol: Decimal,
+    /// 기타 단체 매도 거래 대금
+    #[serde(default, rename = "etc_orgt_seln_tr_pbmn")]
+    pub etc_orgt_seln_tr_pbmn: String,
+    /// 기타 단체 매수2 거래 대금
+    #[serde(default, rename = "etc_orgt_shnu_tr_pbmn")]
+    pub etc_orgt_shnu_tr_pbmn: String,
+    /// 기타 법인 매도 거래량
+    #[serde(default, rename = "etc_corp_seln_vol")]
+    pub etc_corp_seln_vol: Decimal,
+    /// 기타 법인 매수2 거래량
+    #[serde(default, rename = "etc_corp_shnu_vol")]
+    pub etc_corp_shnu_vol: Decimal,
+    /// 기타 법인 매도 거래 대금
+    #[serde(default, rename = "etc_corp_seln_tr_pbmn")]
+    pub etc_corp_seln_tr_pbmn: String,
+    /// 기타 법인 매수2 거래 대금
+    #[serde(default, rename = "etc_corp_shnu_tr_pbmn")]
+    pub etc_corp_shnu_tr_pbmn: String,
+    /// BOLD 여부
+    #[serde(default, rename = "bold_yn")]
+    pub bold_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 투자자매매동향(일별)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInvestorTradeByStockDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInvestorTradeByStockDailyOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInvestorTradeByStockDailyOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInvestorTradeByStockDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [시장별 투자자매매동향(시세)[v1_국내주식-074]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorTimeByMarketOutputItem {
+    /// 외국인 매도 거래량
+    #[serde(default, rename = "frgn_seln_vol")]
+    pub frgn_seln_vol: Decimal,
+    /// 외국인 매수2 거래량
+    #[serde(default, rename = "frgn_shnu_vol")]
+    pub frgn_shnu_vol: Decimal,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 외국인 매도 거래 대금
+    #[serde(default, rename = "frgn_seln_tr_pbmn")]
+    pub frgn_seln_tr_pbmn: String,
+    /// 외국인 매수2 거래 대금
+    #[serde(default, rename = "frgn_shnu_tr_pbmn")]
+    pub frgn_shnu_tr_pbmn: String,
+    /// 외국인 순매수 거래 대금
+    #[serde(default, rename = "frgn_ntby_tr_pbmn")]
+    pub frgn_ntby_tr_pbmn: String,
+    /// 개인 매도 거래량
+    #[serde(default, rename = "prsn_seln_vol")]
+    pub prsn_seln_vol: Decimal,
+    /// 개인 매수2 거래량
+    #[serde(default, rename = "prsn_shnu_vol")]
+    pub prsn_shnu_vol: Decimal,
+    /// 개인 순매수 수량
+    #[serde(default, rename = "prsn_ntby_qty")]
+    pub prsn_ntby_qty: Decimal,
+    /// 개인 매도 거래 대금
+    #[serde(default, rename = "prsn_seln_tr_pbmn")]
+    pub prsn_seln_tr_pbmn: String,
+    /// 개인 매수2 거래 대금
+    #[serde(default, rename = "prsn_shnu_tr_pbmn")]
+    pub prsn_shnu_tr_pbmn: String,
+    /// 개인 순매수 거래 대금
+    #[serde(default, rename = "prsn_ntby_tr_pbmn")]
+    pub prsn_ntby_tr_pbmn: String,
+    /// 기관계 매도 거래량
+    #[serde(default, rename = "orgn_seln_vol")]
+    pub orgn_seln_vol: Decimal,
+    /// 기관계 매수2 거래량
+    #[serde(default, rename = "orgn_shnu_vol")]
+    pub orgn_shnu_vol: Decimal,
+    /// 기관계 순매수 수량
+    #[serde(default, rename = "orgn_ntby_qty")]
+    pub orgn_ntby_qty: Decimal,
+    /// 기관계 매도 거래 대금
+    #[serde(default, rename = "orgn_seln_tr_pbmn")]
+    pub orgn_seln_tr_pbmn: String,
+    /// 기관계 매수2 거래 대금
+    #[serde(default, rename = "orgn_shnu_tr_pbmn")]
+    pub orgn_shnu_tr_pbmn: String,
+    /// 기관계 순매수 거래 대금
+    #[serde(default, rename = "orgn_ntby_tr_pbmn")]
+    pub orgn_ntby_tr_pbmn: String,
+    /// 증권 매도 거래량
+    #[serde(default, rename = "scrt_seln_vol")]
+    pub scrt_seln_vol: Decimal,
+    /// 증권 매수2 거래량
+    #[serde(default, rename = "scrt_shnu_vol")]
+    pub scrt_shnu_vol: Decimal,
+    /// 증권 순매수 수량
+    #[serde(default, rename = "scrt_ntby_qty")]
+    pub scrt_ntby_qty: Decimal,
+    /// 증권 매도 거래 대금
+    #[serde(default, rename = "scrt_seln_tr_pbmn")]
+    pub scrt_seln_tr_pbmn: String,
+    /// 증권 매수2 거래 대금
+    #[serde(default, rename = "scrt_shnu_tr_pbmn")]
+    pub scrt_shnu_tr_pbmn: String,
+    /// 증권 순매수 거래 대금
+    #[serde(default, rename = "scrt_ntby_tr_pbmn")]
+    pub scrt_ntby_tr_pbmn: String,
+    /// 투자신탁 매도 거래량
+    #[serde(default, rename = "ivtr_seln_vol")]
+    pub ivtr_seln_vol: Decimal,
+    /// 투자신탁 매수2 거래량
+    #[serde(default, rename = "ivtr_shnu_vol")]
+    pub ivtr_shnu_vol: Decimal,
+    /// 투자신탁 순매수 수량
+    #[serde(default, rename = "ivtr_ntby_qty")]
+    pub ivtr_ntby_qty: Decimal,
+    /// 투자신탁 매도 거래 대금
+    #[serde(default, rename = "ivtr_seln_tr_pbmn")]
+    pub ivtr_seln_tr_pbmn: String,
+    /// 투자신탁 매수2 거래 대금
+    #[serde(default, rename = "ivtr_shnu_tr_pbmn")]
+    pub ivtr_shnu_tr_pbmn: String,
+    /// 투자신탁 순매수 거래 대금
+    #[serde(default, rename = "ivtr_ntby_tr_pbmn")]
+    pub ivtr_ntby_tr_pbmn: String,
+    /// 사모 펀드 매도 거래 대금
+    #[serde(default, rename = "pe_fund_seln_tr_pbmn")]
+    pub pe_fund_seln_tr_pbmn: String,
+    /// 사모 펀드 매도 거래량
+    #[serde(default, rename = "pe_fund_seln_vol")]
+    pub pe_fund_seln_vol: Decimal,
+    /// 사모 펀드 순매수 거래량
+    #[serde(default, rename = "pe_fund_ntby_vol")]
+    pub pe_fund_ntby_vol: Decimal,
+    /// 사모 펀드 매수2 거래 대금
+    #[serde(default, rename = "pe_fund_shnu_tr_pbmn")]
+    pub pe_fund_shnu_tr_pbmn: String,
+    /// 사모 펀드 매수2 거래량
+    #[serde(default, rename = "pe_fund_shnu_vol")]
+    pub pe_fund_shnu_vol: Decimal,
+    /// 사모 펀드 순매수 거래 대금
+    #[serde(default, rename = "pe_fund_ntby_tr_pbmn")]
+    pub pe_fund_ntby_tr_pbmn: String,
+    /// 은행 매도 거래량
+    #[serde(default, rename = "bank_seln_vol")]
+    pub bank_seln_vol: Decimal,
+    /// 은행 매수2 거래량
+    #[serde(default, rename = "bank_shnu_vol")]
+    pub bank_shnu_vol: Decimal,
+    /// 은행 순매수 수량
+    #[serde(default, rename = "bank_ntby_qty")]
+    pub bank_ntby_qty: Decimal,
+    /// 은행 매도 거래 대금
+    #[serde(default, rename = "bank_seln_tr_pbmn")]
+    pub bank_seln_tr_pbmn: String,
+    /// 은행 매수2 거래 대금
+    #[serde(default, rename = "bank_shnu_tr_pbmn")]
+    pub bank_shnu_tr_pbmn: String,
+    /// 은행 순매수 거래 대금
+    #[serde(default, rename = "bank_ntby_tr_pbmn")]
+    pub bank_ntby_tr_pbmn: String,
+    /// 보험 매도 거래량
+    #[serde(default, rename = "insu_seln_vol")]
+    pub insu_seln_vol: Decimal,
+    /// 보험 매수2 거래량
+    #[serde(default, rename = "insu_shnu_vol")]
+    pub insu_shnu_vol: Decimal,
+    /// 보험 순매수 수량
+    #[serde(default, rename = "insu_ntby_qty")]
+    pub insu_ntby_qty: Decimal,
+    /// 보험 매도 거래 대금
+    #[serde(default, rename = "insu_seln_tr_pbmn")]
+    pub insu_seln_tr_pbmn: String,
+    /// 보험 매수2 거래 대금
+    #[serde(default, rename = "insu_shnu_tr_pbmn")]
+    pub insu_shnu_tr_pbmn: String,
+    /// 보험 순매수 거래 대금
+    #[serde(default, rename = "insu_ntby_tr_pbmn")]
+    pub insu_ntby_tr_pbmn: String,
+    /// 종금 매도 거래량
+    #[serde(default, rename = "mrbn_seln_vol")]
+    pub mrbn_seln_vol: Decimal,
+    /// 종금 매수2 거래량
+    #[serde(default, rename = "mrbn_shnu_vol")]
+    pub mrbn_shnu_vol: Decimal,
+    /// 종금 순매수 수량
+    #[serde(default, rename = "mrbn_ntby_qty")]
+    pub mrbn_ntby_qty: Decimal,
+    /// 종금 매도 거래 대금
+    #[serde(default, rename = "mrbn_seln_tr_pbmn")]
+    pub mrbn_seln_tr_pbmn: String,
+    /// 종금 매수2 거래 대금
+    #[serde(default, rename = "mrbn_shnu_tr_pbmn")]
+    pub mrbn_shnu_tr_pbmn: String,
+    /// 종금 순매수 거래 대금
+    #[serde(default, rename = "mrbn_ntby_tr_pbmn")]
+    pub mrbn_ntby_tr_pbmn: String,
+    /// 기금 매도 거래량
+    #[serde(default, rename = "fund_seln_vol")]
+    pub fund_seln_vol: Decimal,
+    /// 기금 매수2 거래량
+    #[serde(default, rename = "fund_shnu_vol")]
+    pub fund_shnu_vol: Decimal,
+    /// 기금 순매수 수량
+    #[serde(default, rename = "fund_ntby_qty")]
+    pub fund_ntby_qty: Decimal,
+    /// 기금 매도 거래 대금
+    #[serde(default, rename = "fund_seln_tr_pbmn")]
+    pub fund_seln_tr_pbmn: String,
+    /// 기금 매수2 거래 대금
+    #[serde(default, rename = "fund_shnu_tr_pbmn")]
+    pub fund_shnu_tr_pbmn: String,
+    /// 기금 순매수 거래 대금
+    #[serde(default, rename = "fund_ntby_tr_pbmn")]
+    pub fund_ntby_tr_pbmn: String,
+    /// 기타 단체 매도 거래량
+    #[serde(default, rename = "etc_orgt_seln_vol")]
+    pub etc_orgt_seln_vol: Decimal,
+    /// 기타 단체 매수2 거래량
+    #[serde(default, rename = "etc_orgt_shnu_vol")]
+    pub etc_orgt_shnu_vol: Decimal,
+    /// 기타 단체 순매수 거래량
+    #[serde(default, rename = "etc_orgt_ntby_vol")]
+    pub etc_orgt_ntby_vol: Decimal,
+    /// 기타 단체 매도 거래 대금
+    #[serde(default, rename = "etc_orgt_seln_tr_pbmn")]
+    pub etc_orgt_seln_tr_pbmn: String,
+    /// 기타 단체 매수2 거래 대금
+    #[serde(default, rename = "etc_orgt_shnu_tr_pbmn")]
+    pub etc_orgt_shnu_tr_pbmn: String,
+    /// 기타 단체 순매수 거래 대금
+    #[serde(default, rename = "etc_orgt_ntby_tr_pbmn")]
+    pub etc_orgt_ntby_tr_pbmn: String,
+    /// 기타 법인 매도 거래량
+    #[serde(default, rename = "etc_corp_seln_vol")]
+    pub etc_corp_seln_vol: Decimal,
+    /// 기타 법인 매수2 거래량
+    #[serde(default, rename = "etc_corp_shnu_vol")]
+    pub etc_corp_shnu_vol: Decimal,
+    /// 기타 법인 순매수 거래량
+    #[serde(default, rename = "etc_corp_ntby_vol")]
+    pub etc_corp_ntby_vol: Decimal,
+    /// 기타 법인 매도 거래 대금
+    #[serde(default, rename = "etc_corp_seln_tr_pbmn")]
+    pub etc_corp_seln_tr_pbmn: String,
+    /// 기타 법인 매수2 거래 대금
+    #[serde(default, rename = "etc_corp_shnu_tr_pbmn")]
+    pub etc_corp_shnu_tr_pbmn: String,
+    /// 기타 법인 순매수 거래 대금
+    #[serde(default, rename = "etc_corp_ntby_tr_pbmn")]
+    pub etc_corp_ntby_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [시장별 투자자매매동향(시세)[v1_국내주식-074]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireInvestorTimeByMarketResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireInvestorTimeByMarketOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireInvestorTimeByMarketResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [시장별 투자자매매동향(일별) [국내주식-075]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireInvestorDailyByMarketOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비율
+    #[serde(default, rename = "bstp_nmix_prdy_ctrt")]
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 업종 지수 시가2
+    #[serde(default, rename = "bstp_nmix_oprc")]
+    pub bstp_nmix_oprc: String,
+    /// 업종 지수 최고가
+    #[serde(default, rename = "bstp_nmix_hgpr")]
+    pub bstp_nmix_hgpr: String,
+    /// 업종 지수 최저가
+    #[serde(default, rename = "bstp_nmix_lwpr")]
+    pub bstp_nmix_lwpr: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 외국인 순매수 수량
+    #[serde(default, rename = "frgn_ntby_qty")]
+    pub frgn_ntby_qty: Decimal,
+    /// 외국인 등록 순매수 수량
+    #[serde(default, rename = "frgn_reg_ntby_qty")]
+    pub frgn_reg_ntby_qty: Decimal,
+    /// 외국인 비등록 순매수 수량
+    #[serde(default, rename = "frgn_nreg_ntby_qty")]
+    pub frgn_nreg_ntby_qty: Decimal,
+    /// 개인 순매수 수량
+    #[serde(default, rename = "prsn_ntby_qty")]
+    pub prsn_ntby_qty: Decimal,
+    /// 기관계 순매수 수량
+    #[serde(default, rename = "orgn_ntby_qty")]
+    pub orgn_ntby_qty: Decimal,
+    /// 증권 순매수 수량
+    #[serde(default, rename = "scrt_ntby_qty")]
+    pub scrt_ntby_qty: Decimal,
+    /// 투자신탁 순매수 수량
+    #[serde(default, rename = "ivtr_ntby_qty")]
+    pub ivtr_ntby_qty: Decimal,
+    /// 사모 펀드 순매수 거래량
+    #[serde(default, rename = "pe_fund_ntby_vol")]
+    pub pe_fund_ntby_vol: Decimal,
+    /// 은행 순매수 수량
+    #[serde(default, rename = "bank_ntby_qty")]
+    pub bank_ntby_qty: Decimal,
+    /// 보험 순매수 수량
+    #[serde(default, rename = "insu_ntby_qty")]
+    pub insu_ntby_qty: Decimal,
+    /// 종금 순매수 수량
+    #[serde(default, rename = "mrbn_ntby_qty")]
+    pub mrbn_ntby_qty: Decimal,
+    /// 기금 순매수 수량
+    #[serde(default, rename = "fund_ntby_qty")]
+    pub fund_ntby_qty: Decimal,
+    /// 기타 순매수 수량
+    #[serde(default, rename = "etc_ntby_qty")]
+    pub etc_ntby_qty: Decimal,
+    /// 기타 단체 순매수 거래량
+    #[serde(default, rename = "etc_orgt_ntby_vol")]
+    pub etc_orgt_ntby_vol: Decimal,
+    /// 기타 법인 순매수 거래량
+    #[serde(default, rename = "etc_corp_ntby_vol")]
+    pub etc_corp_ntby_vol: Decimal,
+    /// 외국인 순매수 거래 대금
+    #[serde(default, rename = "frgn_ntby_tr_pbmn")]
+    pub frgn_ntby_tr_pbmn: String,
+    /// 외국인 등록 순매수 대금
+    #[serde(default, rename = "frgn_reg_ntby_pbmn")]
+    pub frgn_reg_ntby_pbmn: String,
+    /// 외국인 비등록 순매수 대금
+    #[serde(default, rename = "frgn_nreg_ntby_pbmn")]
+    pub frgn_nreg_ntby_pbmn: String,
+    /// 개인 순매수 거래 대금
+    #[serde(default, rename = "prsn_ntby_tr_pbmn")]
+    pub prsn_ntby_tr_pbmn: String,
+    /// 기관계 순매수 거래 대금
+    #[serde(default, rename = "orgn_ntby_tr_pbmn")]
+    pub orgn_ntby_tr_pbmn: String,
+    /// 증권 순매수 거래 대금
+    #[serde(default, rename = "scrt_ntby_tr_pbmn")]
+    pub scrt_ntby_tr_pbmn: String,
+    /// 투자신탁 순매수 거래 대금
+    #[serde(default, rename = "ivtr_ntby_tr_pbmn")]
+    pub ivtr_ntby_tr_pbmn: String,
+    /// 사모 펀드 순매수 거래 대금
+    #[serde(default, rename = "pe_fund_ntby_tr_pbmn")]
+    pub pe_fund_ntby_tr_pbmn: String,
+    /// 은행 순매수 거래 대금
+    #[serde(default, rename = "bank_ntby_tr_pbmn")]
+    pub bank_ntby_tr_pbmn: String,
+    /// 보험 순매수 거래 대금
+    #[serde(default, rename = "insu_ntby_tr_pbmn")]
+    pub insu_ntby_tr_pbmn: String,
+    /// 종금 순매수 거래 대금
+    #[serde(default, rename = "mrbn_ntby_tr_pbmn")]
+    pub mrbn_ntby_tr_pbmn: String,
+    /// 기금 순매수 거래 대금
+    #[serde(default, rename = "fund_ntby_tr_pbmn")]
+    pub fund_ntby_tr_pbmn: String,
+    /// 기타 순매수 거래 대금
+    #[serde(default, rename = "etc_ntby_tr_pbmn")]
+    pub etc_ntby_tr_pbmn: String,
+    /// 기타 단체 순매수 거래 대금
+    #[serde(default, rename = "etc_orgt_ntby_tr_pbmn")]
+    pub etc_orgt_ntby_tr_pbmn: String,
+    /// 기타 법인 순매수 거래 대금
+    #[serde(default, rename = "etc_corp_ntby_tr_pbmn")]
+    pub etc_corp_ntby_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [시장별 투자자매매동향(일별) [국내주식-075]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireInvestorDailyByMarketResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireInvestorDailyByMarketOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireInvestorDailyByMarketResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 외국계 순매수추이 [국내주식-164]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemPchsTrendOutputItem {
+    /// 영업시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 외국인매도거래량
+    #[serde(default, rename = "frgn_seln_vol")]
+    pub frgn_seln_vol: Decimal,
+    /// 외국인매수2거래량
+    #[serde(default, rename = "frgn_shnu_vol")]
+    pub frgn_shnu_vol: Decimal,
+    /// 외국계순매수수량
+    #[serde(default, rename = "glob_ntby_qty")]
+    pub glob_ntby_qty: Decimal,
+    /// 외국인순매수수량증감
+    #[serde(default, rename = "frgn_ntby_qty_icdc")]
+    pub frgn_ntby_qty_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 외국계 순매수추이 [국내주식-164]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsFrgnmemPchsTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsFrgnmemPchsTrendOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsFrgnmemPchsTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [회원사 실시간 매매동향(틱) [국내주식-163]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeTrendOutput1Item {
+    /// 총매도수량
+    #[serde(default, rename = "total_seln_qty")]
+    pub total_seln_qty: Decimal,
+    /// 총매수2수량
+    #[serde(default, rename = "total_shnu_qty")]
+    pub total_shnu_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [회원사 실시간 매매동향(틱) [국내주식-163]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeTrendOutput2Item {
+    /// 영업시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 회원사명
+    #[serde(default, rename = "mbcr_name")]
+    pub mbcr_name: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 체결거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적순매수수량
+    #[serde(default, rename = "acml_ntby_qty")]
+    pub acml_ntby_qty: Decimal,
+    /// 외국계순매수수량
+    #[serde(default, rename = "glob_ntby_qty")]
+    pub glob_ntby_qty: Decimal,
+    /// 외국인순매수수량증감
+    #[serde(default, rename = "frgn_ntby_qty_icdc")]
+    pub frgn_ntby_qty_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [회원사 실시간 매매동향(틱) [국내주식-163]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsFrgnmemTradeTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsFrgnmemTradeTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsFrgnmemTradeTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsFrgnmemTradeTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [주식현재가 회원사 종목매매동향 [국내주식-197]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireMemberDailyOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 총매도수량
+    #[serde(default, rename = "total_seln_qty")]
+    pub total_seln_qty: Decimal,
+    /// 총매수2수량
+    #[serde(default, rename = "total_shnu_qty")]
+    pub total_shnu_qty: Decimal,
+    /// 순매수수량
+    #[serde(default, rename = "ntby_qty")]
+    pub ntby_qty: Decimal,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [주식현재가 회원사 종목매매동향 [국내주식-197]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireMemberDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsInquireMemberDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireMemberDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 프로그램매매추이(체결)[v1_국내주식-044]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockOutputItem {
+    /// 영업 시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전체 합계 매도 거래량
+    #[serde(default, rename = "whol_smtn_seln_vol")]
+    pub whol_smtn_seln_vol: Decimal,
+    /// 전체 합계 매수2 거래량
+    #[serde(default, rename = "whol_smtn_shnu_vol")]
+    pub whol_smtn_shnu_vol: Decimal,
+    /// 전체 합계 순매수 수량
+    #[serde(default, rename = "whol_smtn_ntby_qty")]
+    pub whol_smtn_ntby_qty: Decimal,
+    /// 전체 합계 매도 거래 대금
+    #[serde(default, rename = "whol_smtn_seln_tr_pbmn")]
+    pub whol_smtn_seln_tr_pbmn: String,
+    /// 전체 합계 매수2 거래 대금
+    #[serde(default, rename = "whol_smtn_shnu_tr_pbmn")]
+    pub whol_smtn_shnu_tr_pbmn: String,
+    /// 전체 합계 순매수 거래 대금
+    #[serde(default, rename = "whol_smtn_ntby_tr_pbmn")]
+    pub whol_smtn_ntby_tr_pbmn: String,
+    /// 전체 순매수 거래량 증감
+    #[serde(default, rename = "whol_ntby_vol_icdc")]
+    pub whol_ntby_vol_icdc: String,
+    /// 전체 순매수 거래 대금 증감
+    #[serde(default, rename = "whol_ntby_tr_pbmn_icdc")]
+    pub whol_ntby_tr_pbmn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 프로그램매매추이(체결)[v1_국내주식-044]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsProgramTradeByStockOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsProgramTradeByStockResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 프로그램매매추이(일별) [국내주식-113]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockDailyOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 전체 합계 매도 거래량
+    #[serde(default, rename = "whol_smtn_seln_vol")]
+    pub whol_smtn_seln_vol: Decimal,
+    /// 전체 합계 매수2 거래량
+    #[serde(default, rename = "whol_smtn_shnu_vol")]
+    pub whol_smtn_shnu_vol: Decimal,
+    /// 전체 합계 순매수 수량
+    #[serde(default, rename = "whol_smtn_ntby_qty")]
+    pub whol_smtn_ntby_qty: Decimal,
+    /// 전체 합계 매도 거래 대금
+    #[serde(default, rename = "whol_smtn_seln_tr_pbmn")]
+    pub whol_smtn_seln_tr_pbmn: String,
+    /// 전체 합계 매수2 거래 대금
+    #[serde(default, rename = "whol_smtn_shnu_tr_pbmn")]
+    pub whol_smtn_shnu_tr_pbmn: String,
+    /// 전체 합계 순매수 거래 대금
+    #[serde(default, rename = "whol_smtn_ntby_tr_pbmn")]
+    pub whol_smtn_ntby_tr_pbmn: String,
+    /// 전체 순매수 거래량 증감
+    #[serde(default, rename = "whol_ntby_vol_icdc")]
+    pub whol_ntby_vol_icdc: String,
+    /// 전체 순매수 거래 대금 증감2
+    #[serde(default, rename = "whol_ntby_tr_pbmn_icdc2")]
+    pub whol_ntby_tr_pbmn_icdc2: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 프로그램매매추이(일별) [국내주식-113]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsProgramTradeByStockDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsProgramTradeByStockDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsProgramTradeByStockDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 외인기관 추정가집계[v1_국내주식-046]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorTrendEstimateOutput2Item {
+    /// 입력구분
+    #[serde(default, rename = "bsop_hour_gb")]
+    pub bsop_hour_gb: String,
+    /// 외국인수량(가집계)
+    #[serde(default, rename = "frgn_fake_ntby_qty")]
+    pub frgn_fake_ntby_qty: Decimal,
+    /// 기관수량(가집계)
+    #[serde(default, rename = "orgn_fake_ntby_qty")]
+    pub orgn_fake_ntby_qty: Decimal,
+    /// 합산수량(가집계)
+    #[serde(default, rename = "sum_fake_ntby_qty")]
+    pub sum_fake_ntby_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 외인기관 추정가집계[v1_국내주식-046]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInvestorTrendEstimateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInvestorTrendEstimateOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInvestorTrendEstimateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별일별매수매도체결량 [v1_국내주식-056]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyTradeVolumeOutput1Item {
+    /// 매수 체결량 합계
+    #[serde(default, rename = "shnu_cnqn_smtn")]
+    pub shnu_cnqn_smtn: String,
+    /// 매도 체결량 합계
+    #[serde(default, rename = "seln_cnqn_smtn")]
+    pub seln_cnqn_smtn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별일별매수매도체결량 [v1_국내주식-056]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInquireDailyTradeVolumeOutput2Item {
+    /// 거래상태정보
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "total_seln_qty")]
+    pub total_seln_qty: Decimal,
+    /// 총 매수 수량
+    #[serde(default, rename = "total_shnu_qty")]
+    pub total_shnu_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별일별매수매도체결량 [v1_국내주식-056]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInquireDailyTradeVolumeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInquireDailyTradeVolumeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsInquireDailyTradeVolumeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInquireDailyTradeVolumeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [프로그램매매 종합현황(시간) [국내주식-114]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompProgramTradeTodayOutput1Item {
+    /// 영업 시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 차익 합계 매도 거래 대금
+    #[serde(default, rename = "arbt_smtn_seln_tr_pbmn")]
+    pub arbt_smtn_seln_tr_pbmn: String,
+    /// 차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "arbt_smtm_seln_tr_pbmn_rate")]
+    pub arbt_smtm_seln_tr_pbmn_rate: Decimal,
+    /// 차익 합계 매수2 거래 대금
+    #[serde(default, rename = "arbt_smtn_shnu_tr_pbmn")]
+    pub arbt_smtn_shnu_tr_pbmn: String,
+    /// 차익합계매수거래대금비율
+    #[serde(default, rename = "arbt_smtm_shun_tr_pbmn_rate")]
+    pub arbt_smtm_shun_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 매도 거래 대금
+    #[serde(default, rename = "nabt_smtn_seln_tr_pbmn")]
+    pub nabt_smtn_seln_tr_pbmn: String,
+    /// 비차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "nabt_smtm_seln_tr_pbmn_rate")]
+    pub nabt_smtm_seln_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 매수2 거래 대금
+    #[serde(default, rename = "nabt_smtn_shnu_tr_pbmn")]
+    pub nabt_smtn_shnu_tr_pbmn: String,
+    /// 비차익합계매수거래대금비율
+    #[serde(default, rename = "nabt_smtm_shun_tr_pbmn_rate")]
+    pub nabt_smtm_shun_tr_pbmn_rate: Decimal,
+    /// 차익 합계 순매수 거래 대금
+    #[serde(default, rename = "arbt_smtn_ntby_tr_pbmn")]
+    pub arbt_smtn_ntby_tr_pbmn: String,
+    /// 차익 합계 순매수 거래대금 비율
+    #[serde(default, rename = "arbt_smtm_ntby_tr_pbmn_rate")]
+    pub arbt_smtm_ntby_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 순매수 거래 대금
+    #[serde(default, rename = "nabt_smtn_ntby_tr_pbmn")]
+    pub nabt_smtn_ntby_tr_pbmn: String,
+    /// 비차익 합계 순매수 거래대금 비
+    #[serde(default, rename = "nabt_smtm_ntby_tr_pbmn_rate")]
+    pub nabt_smtm_ntby_tr_pbmn_rate: Decimal,
+    /// 전체 합계 순매수 거래 대금
+    #[serde(default, rename = "whol_smtn_ntby_tr_pbmn")]
+    pub whol_smtn_ntby_tr_pbmn: String,
+    /// 전체 순매수 거래대금 비율
+    #[serde(default, rename = "whol_ntby_tr_pbmn_rate")]
+    pub whol_ntby_tr_pbmn_rate: Decimal,
+    /// 업종 지수 현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [프로그램매매 종합현황(시간) [국내주식-114]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsCompProgramTradeTodayResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsCompProgramTradeTodayOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsCompProgramTradeTodayResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [프로그램매매 종합현황(일별)[국내주식-115]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCompProgramTradeDailyOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 비차익 위탁 매도 거래 대금
+    #[serde(default, rename = "nabt_entm_seln_tr_pbmn")]
+    pub nabt_entm_seln_tr_pbmn: String,
+    /// 비차익 자기 매도 거래량
+    #[serde(default, rename = "nabt_onsl_seln_vol")]
+    pub nabt_onsl_seln_vol: Decimal,
+    /// 전체 자기 매도 거래 대금
+    #[serde(default, rename = "whol_onsl_seln_tr_pbmn")]
+    pub whol_onsl_seln_tr_pbmn: String,
+    /// 차익 합계 매수2 거래량
+    #[serde(default, rename = "arbt_smtn_shnu_vol")]
+    pub arbt_smtn_shnu_vol: Decimal,
+    /// 비차익 합계 매수2 거래 대금
+    #[serde(default, rename = "nabt_smtn_shnu_tr_pbmn")]
+    pub nabt_smtn_shnu_tr_pbmn: String,
+    /// 차익 위탁 순매수 수량
+    #[serde(default, rename = "arbt_entm_ntby_qty")]
+    pub arbt_entm_ntby_qty: Decimal,
+    /// 비차익 위탁 순매수 거래 대금
+    #[serde(default, rename = "nabt_entm_ntby_tr_pbmn")]
+    pub nabt_entm_ntby_tr_pbmn: String,
+    /// 차익 위탁 매도 거래량
+    #[serde(default, rename = "arbt_entm_seln_vol")]
+    pub arbt_entm_seln_vol: Decimal,
+    /// 비차익 위탁 매도 거래량 비율
+    #[serde(default, rename = "nabt_entm_seln_vol_rate")]
+    pub nabt_entm_seln_vol_rate: Decimal,
+    /// 비차익 자기 매도 거래량 비율
+    #[serde(default, rename = "nabt_onsl_seln_vol_rate")]
+    pub nabt_onsl_seln_vol_rate: Decimal,
+    /// 전체 자기 매도 거래 대금 비율
+    #[serde(default, rename = "whol_onsl_seln_tr_pbmn_rate")]
+    pub whol_onsl_seln_tr_pbmn_rate: Decimal,
+    /// 차익 합계 매수 거래량 비율
+    #[serde(default, rename = "arbt_smtm_shun_vol_rate")]
+    pub arbt_smtm_shun_vol_rate: Decimal,
+    /// 비차익 합계 매수 거래대금 비율
+    #[serde(default, rename = "nabt_smtm_shun_tr_pbmn_rate")]
+    pub nabt_smtm_shun_tr_pbmn_rate: Decimal,
+    /// 차익 위탁 순매수 수량 비율
+    #[serde(default, rename = "arbt_entm_ntby_qty_rate")]
+    pub arbt_entm_ntby_qty_rate: Decimal,
+    /// 비차익 위탁 순매수 거래 대금
+    #[serde(default, rename = "nabt_entm_ntby_tr_pbmn_rate")]
+    pub nabt_entm_ntby_tr_pbmn_rate: Decimal,
+    /// 차익 위탁 매도 거래량 비율
+    #[serde(default, rename = "arbt_entm_seln_vol_rate")]
+    pub arbt_entm_seln_vol_rate: Decimal,
+    /// 비차익 위탁 매도 거래 대금 비
+    #[serde(default, rename = "nabt_entm_seln_tr_pbmn_rate")]
+    pub nabt_entm_seln_tr_pbmn_rate: Decimal,
+    /// 비차익 자기 매도 거래 대금
+    #[serde(default, rename = "nabt_onsl_seln_tr_pbmn")]
+    pub nabt_onsl_seln_tr_pbmn: String,
+    /// 전체 합계 매도 거래량
+    #[serde(default, rename = "whol_smtn_seln_vol")]
+    pub whol_smtn_seln_vol: Decimal,
+    /// 차익 합계 매수2 거래 대금
+    #[serde(default, rename = "arbt_smtn_shnu_tr_pbmn")]
+    pub arbt_smtn_shnu_tr_pbmn: String,
+    /// 전체 위탁 매수2 거래량
+    #[serde(default, rename = "whol_entm_shnu_vol")]
+    pub whol_entm_shnu_vol: Decimal,
+    /// 차익 위탁 순매수 거래 대금
+    #[serde(default, rename = "arbt_entm_ntby_tr_pbmn")]
+    pub arbt_entm_ntby_tr_pbmn: String,
+    /// 비차익 자기 순매수 수량
+    #[serde(default, rename = "nabt_onsl_ntby_qty")]
+    pub nabt_onsl_ntby_qty: Decimal,
+    /// 차익 위탁 매도 거래 대금
+    #[serde(default, rename = "arbt_entm_seln_tr_pbmn")]
+    pub arbt_entm_seln_tr_pbmn: String,
+    /// 비차익 자기 매도 거래 대금 비
+    #[serde(default, rename = "nabt_onsl_seln_tr_pbmn_rate")]
+    pub nabt_onsl_seln_tr_pbmn_rate: Decimal,
+    /// 전체 매도 거래량 비율
+    #[serde(default, rename = "whol_seln_vol_rate")]
+    pub whol_seln_vol_rate: Decimal,
+    /// 차익 합계 매수 거래대금 비율
+    #[serde(default, rename = "arbt_smtm_shun_tr_pbmn_rate")]
+    pub arbt_smtm_shun_tr_pbmn_rate: Decimal,
+    /// 전체 위탁 매수 거래량 비율
+    #[serde(default, rename = "whol_entm_shnu_vol_rate")]
+    pub whol_entm_shnu_vol_rate: Decimal,
+    /// 차익 위탁 순매수 거래 대금 비
+    #[serde(default, rename = "arbt_entm_ntby_tr_pbmn_rate")]
+    pub arbt_entm_ntby_tr_pbmn_rate: Decimal,
+    /// 비차익 자기 순매수 수량 비율
+    #[serde(default, rename = "nabt_onsl_ntby_qty_rate")]
+    pub nabt_onsl_ntby_qty_rate: Decimal,
+    /// 차익 위탁 매도 거래 대금 비율
+    #[serde(default, rename = "arbt_entm_seln_tr_pbmn_rate")]
+    pub arbt_entm_seln_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 매도 거래량
+    #[serde(default, rename = "nabt_smtn_seln_vol")]
+    pub nabt_smtn_seln_vol: Decimal,
+    /// 전체 합계 매도 거래 대금
+    #[serde(default, rename = "whol_smtn_seln_tr_pbmn")]
+    pub whol_smtn_seln_tr_pbmn: String,
+    /// 비차익 위탁 매수2 거래량
+    #[serde(default, rename = "nabt_entm_shnu_vol")]
+    pub nabt_entm_shnu_vol: Decimal,
+    /// 전체 위탁 매수2 거래 대금
+    #[serde(default, rename = "whol_entm_shnu_tr_pbmn")]
+    pub whol_entm_shnu_tr_pbmn: String,
+    /// 차익 자기 순매수 수량
+    #[serde(default, rename = "arbt_onsl_ntby_qty")]
+    pub arbt_onsl_ntby_qty: Decimal,
+    /// 비차익 자기 순매수 거래 대금
+    #[serde(default, rename = "nabt_onsl_ntby_tr_pbmn")]
+    pub nabt_onsl_ntby_tr_pbmn: String,
+    /// 차익 자기 매도 거래 대금
+    #[serde(default, rename = "arbt_onsl_seln_tr_pbmn")]
+    pub arbt_onsl_seln_tr_pbmn: String,
+    /// 비차익 합계 매도 거래량 비율
+    #[serde(default, rename = "nabt_smtm_seln_vol_rate")]
+    pub nabt_smtm_seln_vol_rate: Decimal,
+    /// 전체 매도 거래대금 비율
+    #[serde(default, rename = "whol_seln_tr_pbmn_rate")]
+    pub whol_seln_tr_pbmn_rate: Decimal,
+    /// 비차익 위탁 매수 거래량 비율
+    #[serde(default, rename = "nabt_entm_shnu_vol_rate")]
+    pub nabt_entm_shnu_vol_rate: Decimal,
+    /// 전체 위탁 매수 거래 대금 비율
+    #[serde(default, rename = "whol_entm_shnu_tr_pbmn_rate")]
+    pub whol_entm_shnu_tr_pbmn_rate: Decimal,
+    /// 차익 자기 순매수 수량 비율
+    #[serde(default, rename = "arbt_onsl_ntby_qty_rate")]
+    pub arbt_onsl_ntby_qty_rate: Decimal,
+    /// 비차익 자기 순매수 거래 대금
+    #[serde(default, rename = "nabt_onsl_ntby_tr_pbmn_rate")]
+    pub nabt_onsl_ntby_tr_pbmn_rate: Decimal,
+    /// 차익 자기 매도 거래 대금 비율
+    #[serde(default, rename = "arbt_onsl_seln_tr_pbmn_rate")]
+    pub arbt_onsl_seln_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 매도 거래 대금
+    #[serde(default, rename = "nabt_smtn_seln_tr_pbmn")]
+    pub nabt_smtn_seln_tr_pbmn: String,
+    /// 차익 위탁 매수2 거래량
+    #[serde(default, rename = "arbt_entm_shnu_vol")]
+    pub arbt_entm_shnu_vol: Decimal,
+    /// 비차익 위탁 매수2 거래 대금
+    #[serde(default, rename = "nabt_entm_shnu_tr_pbmn")]
+    pub nabt_entm_shnu_tr_pbmn: String,
+    /// 전체 자기 매수2 거래량
+    #[serde(default, rename = "whol_onsl_shnu_vol")]
+    pub whol_onsl_shnu_vol: Decimal,
+    /// 차익 자기 순매수 거래 대금
+    #[serde(default, rename = "arbt_onsl_ntby_tr_pbmn")]
+    pub arbt_onsl_ntby_tr_pbmn: String,
+    /// 비차익 합계 순매수 수량
+    #[serde(default, rename = "nabt_smtn_ntby_qty")]
+    pub nabt_smtn_ntby_qty: Decimal,
+    /// 차익 자기 매도 거래량
+    #[serde(default, rename = "arbt_onsl_seln_vol")]
+    pub arbt_onsl_seln_vol: Decimal,
+    /// 비차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "nabt_smtm_seln_tr_pbmn_rate")]
+    pub nabt_smtm_seln_tr_pbmn_rate: Decimal,
+    /// 차익 위탁 매수 거래량 비율
+    #[serde(default, rename = "arbt_entm_shnu_vol_rate")]
+    pub arbt_entm_shnu_vol_rate: Decimal,
+    /// 비차익 위탁 매수 거래 대금 비
+    #[serde(default, rename = "nabt_entm_shnu_tr_pbmn_rate")]
+    pub nabt_entm_shnu_tr_pbmn_rate: Decimal,
+    /// 전체 자기 매수2 거래 대금
+    #[serde(default, rename = "whol_onsl_shnu_tr_pbmn")]
+    pub whol_onsl_shnu_tr_pbmn: String,
+    /// 차익 자기 순매수 거래 대금 비
+    #[serde(default, rename = "arbt_onsl_ntby_tr_pbmn_rate")]
+    pub arbt_onsl_ntby_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 순매수 수량 비율
+    #[serde(default, rename = "nabt_smtm_ntby_qty_rate")]
+    pub nabt_smtm_ntby_qty_rate: Decimal,
+    /// 차익 자기 매도 거래량 비율
+    #[serde(default, rename = "arbt_onsl_seln_vol_rate")]
+    pub arbt_onsl_seln_vol_rate: Decimal,
+    /// 전체 위탁 매도 거래량
+    #[serde(default, rename = "whol_entm_seln_vol")]
+    pub whol_entm_seln_vol: Decimal,
+    /// 차익 위탁 매수2 거래 대금
+    #[serde(default, rename = "arbt_entm_shnu_tr_pbmn")]
+    pub arbt_entm_shnu_tr_pbmn: String,
+    /// 비차익 자기 매수2 거래량
+    #[serde(default, rename = "nabt_onsl_shnu_vol")]
+    pub nabt_onsl_shnu_vol: Decimal,
+    /// 전체 자기 매수 거래 대금 비율
+    #[serde(default, rename = "whol_onsl_shnu_tr_pbmn_rate")]
+    pub whol_onsl_shnu_tr_pbmn_rate: Decimal,
+    /// 차익 합계 순매수 수량
+    #[serde(default, rename = "arbt_smtn_ntby_qty")]
+    pub arbt_smtn_ntby_qty: Decimal,
+    /// 비차익 합계 순매수 거래 대금
+    #[serde(default, rename = "nabt_smtn_ntby_tr_pbmn")]
+    pub nabt_smtn_ntby_tr_pbmn: String,
+    /// 차익 합계 매도 거래량
+    #[serde(default, rename = "arbt_smtn_seln_vol")]
+    pub arbt_smtn_seln_vol: Decimal,
+    /// 전체 위탁 매도 거래 대금
+    #[serde(default, rename = "whol_entm_seln_tr_pbmn")]
+    pub whol_entm_seln_tr_pbmn: String,
+    /// 차익 위탁 매수 거래 대금 비율
+    #[serde(default, rename = "arbt_entm_shnu_tr_pbmn_rate")]
+    pub arbt_entm_shnu_tr_pbmn_rate: Decimal,
+    /// 비차익 자기 매수 거래량 비율
+    #[serde(default, rename = "nabt_onsl_shnu_vol_rate")]
+    pub nabt_onsl_shnu_vol_rate: Decimal,
+    /// 전체 자기 매수 거래량 비율
+    #[serde(default, rename = "whol_onsl_shnu_vol_rate")]
+    pub whol_onsl_shnu_vol_rate: Decimal,
+    /// 차익 합계 순매수 수량 비율
+    #[serde(default, rename = "arbt_smtm_ntby_qty_rate")]
+    pub arbt_smtm_ntby_qty_rate: Decimal,
+    /// 비차익 합계 순매수 거래대금 비
+    #[serde(default, rename = "nabt_smtm_ntby_tr_pbmn_rate")]
+    pub nabt_smtm_ntby_tr_pbmn_rate: Decimal,
+    /// 차익 합계 매도 거래량 비율
+    #[serde(default, rename = "arbt_smtm_seln_vol_rate")]
+    pub arbt_smtm_seln_vol_rate: Decimal,
+    /// 전체 위탁 매도 거래량 비율
+    #[serde(default, rename = "whol_entm_seln_vol_rate")]
+    pub whol_entm_seln_vol_rate: Decimal,
+    /// 차익 자기 매수2 거래량
+    #[serde(default, rename = "arbt_onsl_shnu_vol")]
+    pub arbt_onsl_shnu_vol: Decimal,
+    /// 비차익 자기 매수2 거래 대금
+    #[serde(default, rename = "nabt_onsl_shnu_tr_pbmn")]
+    pub nabt_onsl_shnu_tr_pbmn: String,
+    /// 전체 합계 매수2 거래량
+    #[serde(default, rename = "whol_smtn_shnu_vol")]
+    pub whol_smtn_shnu_vol: Decimal,
+    /// 차익 합계 순매수 거래 대금
+    #[serde(default, rename = "arbt_smtn_ntby_tr_pbmn")]
+    pub arbt_smtn_ntby_tr_pbmn: String,
+    /// 전체 위탁 순매수 수량
+    #[serde(default, rename = "whol_entm_ntby_qty")]
+    pub whol_entm_ntby_qty: Decimal,
+    /// 차익 합계 매도 거래 대금
+    #[serde(default, rename = "arbt_smtn_seln_tr_pbmn")]
+    pub arbt_smtn_seln_tr_pbmn: String,
+    /// 전체 위탁 매도 거래 대금 비율
+    #[serde(default, rename = "whol_entm_seln_tr_pbmn_rate")]
+    pub whol_entm_seln_tr_pbmn_rate: Decimal,
+    /// 차익 자기 매수 거래량 비율
+    #[serde(default, rename = "arbt_onsl_shnu_vol_rate")]
+    pub arbt_onsl_shnu_vol_rate: Decimal,
+    /// 비차익 자기 매수 거래 대금 비
+    #[serde(default, rename = "nabt_onsl_shnu_tr_pbmn_rate")]
+    pub nabt_onsl_shnu_tr_pbmn_rate: Decimal,
+    /// 전체 매수 거래량 비율
+    #[serde(default, rename = "whol_shun_vol_rate")]
+    pub whol_shun_vol_rate: Decimal,
+    /// 차익 합계 순매수 거래대금 비율
+    #[serde(default, rename = "arbt_smtm_ntby_tr_pbmn_rate")]
+    pub arbt_smtm_ntby_tr_pbmn_rate: Decimal,
+    /// 전체 위탁 순매수 수량 비율
+    #[serde(default, rename = "whol_entm_ntby_qty_rate")]
+    pub whol_entm_ntby_qty_rate: Decimal,
+    /// 차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "arbt_smtm_seln_tr_pbmn_rate")]
+    pub arbt_smtm_seln_tr_pbmn_rate: Decimal,
+    /// 전체 자기 매도 거래량
+    #[serde(default, rename = "whol_onsl_seln_vol")]
+    pub whol_onsl_seln_vol: Decimal,
+    /// 차익 자기 매수2 거래 대금
+    #[serde(default, rename = "arbt_onsl_shnu_tr_pbmn")]
+    pub arbt_onsl_shnu_tr_pbmn: String,
+    /// 비차익 합계 매수2 거래량
+    #[serde(default, rename = "nabt_smtn_shnu_vol")]
+    pub nabt_smtn_shnu_vol: Decimal,
+    /// 전체 합계 매수2 거래 대금
+    #[serde(default, rename = "whol_smtn_shnu_tr_pbmn")]
+    pub whol_smtn_shnu_tr_pbmn: String,
+    /// 비차익 위탁 순매수 수량
+    #[serde(default, rename = "nabt_entm_ntby_qty")]
+    pub nabt_entm_ntby_qty: Decimal,
+    /// 전체 위탁 순매수 거래 대금
+    #[serde(default, rename = "whol_entm_ntby_tr_pbmn")]
+    pub whol_entm_ntby_tr_pbmn: String,
+    /// 비차익 위탁 매도 거래량
+    #[serde(default, rename = "nabt_entm_seln_vol")]
+    pub nabt_entm_seln_vol: Decimal,
+    /// 전체 자기 매도 거래량 비율
+    #[serde(default, rename = "whol_onsl_seln_vol_rate")]
+    pub whol_onsl_seln_vol_rate: Decimal,
+    /// 차익 자기 매수 거래 대금 비율
+    #[serde(default, rename = "arbt_onsl_shnu_tr_pbmn_rate")]
+    pub arbt_onsl_shnu_tr_pbmn_rate: Decimal,
+    /// 비차익 합계 매수 거래량 비율
+    #[serde(default, rename = "nabt_smtm_shun_vol_rate")]
+    pub nabt_smtm_shun_vol_rate: Decimal,
+    /// 전체 매수 거래대금 비율
+    #[serde(default, rename = "whol_shun_tr_pbmn_rate")]
+    pub whol_shun_tr_pbmn_rate: Decimal,
+    /// 비차익 위탁 순매수 수량 비율
+    #[serde(default, rename = "nabt_entm_ntby_qty_rate")]
+    pub nabt_entm_ntby_qty_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [프로그램매매 종합현황(일별)[국내주식-115]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsCompProgramTradeDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsCompProgramTradeDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsCompProgramTradeDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [프로그램매매 투자자매매동향(당일) [국내주식-116]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsInvestorProgramTradeTodayOutput1Item {
+    /// 투자자코드
+    #[serde(default, rename = "invr_cls_code")]
+    pub invr_cls_code: String,
+    /// 전체매도수량
+    #[serde(default, rename = "all_seln_qty")]
+    pub all_seln_qty: Decimal,
+    /// 전체매도대금
+    #[serde(default, rename = "all_seln_amt")]
+    pub all_seln_amt: Decimal,
+    /// 투자자 구분 명
+    #[serde(default, rename = "invr_cls_name")]
+    pub invr_cls_name: String,
+    /// 전체매수수량
+    #[serde(default, rename = "all_shnu_qty")]
+    pub all_shnu_qty: Decimal,
+    /// 전체매수대금
+    #[serde(default, rename = "all_shnu_amt")]
+    pub all_shnu_amt: Decimal,
+    /// 전체순매수대금
+    #[serde(default, rename = "all_ntby_amt")]
+    pub all_ntby_amt: Decimal,
+    /// 차익매도수량
+    #[serde(default, rename = "arbt_seln_qty")]
+    pub arbt_seln_qty: Decimal,
+    /// 전체순매수수량
+    #[serde(default, rename = "all_ntby_qty")]
+    pub all_ntby_qty: Decimal,
+    /// 차익매수수량
+    #[serde(default, rename = "arbt_shnu_qty")]
+    pub arbt_shnu_qty: Decimal,
+    /// 차익순매수수량
+    #[serde(default, rename = "arbt_ntby_qty")]
+    pub arbt_ntby_qty: Decimal,
+    /// 차익매도대금
+    #[serde(default, rename = "arbt_seln_amt")]
+    pub arbt_seln_amt: Decimal,
+    /// 차익매수대금
+    #[serde(default, rename = "arbt_shnu_amt")]
+    pub arbt_shnu_amt: Decimal,
+    /// 차익순매수대금
+    #[serde(default, rename = "arbt_ntby_amt")]
+    pub arbt_ntby_amt: Decimal,
+    /// 비차익매도수량
+    #[serde(default, rename = "nabt_seln_qty")]
+    pub nabt_seln_qty: Decimal,
+    /// 비차익매수수량
+    #[serde(default, rename = "nabt_shnu_qty")]
+    pub nabt_shnu_qty: Decimal,
+    /// 비차익순매수수량
+    #[serde(default, rename = "nabt_ntby_qty")]
+    pub nabt_ntby_qty: Decimal,
+    /// 비차익매도대금
+    #[serde(default, rename = "nabt_seln_amt")]
+    pub nabt_seln_amt: Decimal,
+    /// 비차익매수대금
+    #[serde(default, rename = "nabt_shnu_amt")]
+    pub nabt_shnu_amt: Decimal,
+    /// 비차익순매수대금
+    #[serde(default, rename = "nabt_ntby_amt")]
+    pub nabt_ntby_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [프로그램매매 투자자매매동향(당일) [국내주식-116]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsInvestorProgramTradeTodayResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsInvestorProgramTradeTodayOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsInvestorProgramTradeTodayResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 신용잔고 일별추이[국내주식-110]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyCreditBalanceOutputItem {
+    /// 매매 일자
+    #[serde(default, rename = "deal_date")]
+    pub deal_date: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 결제 일자
+    #[serde(default, rename = "stlm_date")]
+    pub stlm_date: String,
+    /// 전체 융자 신규 주수
+    #[serde(default, rename = "whol_loan_new_stcn")]
+    pub whol_loan_new_stcn: String,
+    /// 전체 융자 상환 주수
+    #[serde(default, rename = "whol_loan_rdmp_stcn")]
+    pub whol_loan_rdmp_stcn: String,
+    /// 전체 융자 잔고 주수
+    #[serde(default, rename = "whol_loan_rmnd_stcn")]
+    pub whol_loan_rmnd_stcn: String,
+    /// 전체 융자 신규 금액
+    #[serde(default, rename = "whol_loan_new_amt")]
+    pub whol_loan_new_amt: Decimal,
+    /// 전체 융자 상환 금액
+    #[serde(default, rename = "whol_loan_rdmp_amt")]
+    pub whol_loan_rdmp_amt: Decimal,
+    /// 전체 융자 잔고 금액
+    #[serde(default, rename = "whol_loan_rmnd_amt")]
+    pub whol_loan_rmnd_amt: Decimal,
+    /// 전체 융자 잔고 비율
+    #[serde(default, rename = "whol_loan_rmnd_rate")]
+    pub whol_loan_rmnd_rate: Decimal,
+    /// 전체 융자 공여율
+    #[serde(default, rename = "whol_loan_gvrt")]
+    pub whol_loan_gvrt: String,
+    /// 전체 대주 신규 주수
+    #[serde(default, rename = "whol_stln_new_stcn")]
+    pub whol_stln_new_stcn: String,
+    /// 전체 대주 상환 주수
+    #[serde(default, rename = "whol_stln_rdmp_stcn")]
+    pub whol_stln_rdmp_stcn: String,
+    /// 전체 대주 잔고 주수
+    #[serde(default, rename = "whol_stln_rmnd_stcn")]
+    pub whol_stln_rmnd_stcn: String,
+    /// 전체 대주 신규 금액
+    #[serde(default, rename = "whol_stln_new_amt")]
+    pub whol_stln_new_amt: Decimal,
+    /// 전체 대주 상환 금액
+    #[serde(default, rename = "whol_stln_rdmp_amt")]
+    pub whol_stln_rdmp_amt: Decimal,
+    /// 전체 대주 잔고 금액
+    #[serde(default, rename = "whol_stln_rmnd_amt")]
+    pub whol_stln_rmnd_amt: Decimal,
+    /// 전체 대주 잔고 비율
+    #[serde(default, rename = "whol_stln_rmnd_rate")]
+    pub whol_stln_rmnd_rate: Decimal,
+    /// 전체 대주 공여율
+    #[serde(default, rename = "whol_stln_gvrt")]
+    pub whol_stln_gvrt: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 신용잔고 일별추이[국내주식-110]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsDailyCreditBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsDailyCreditBalanceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsDailyCreditBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 예상체결가 추이[국내주식-118]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpPriceTrendOutput1Item {
+    /// 대표 시장 한글 명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 예상 체결가
+    #[serde(default, rename = "antc_cnpr")]
+    pub antc_cnpr: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "antc_cntg_vrss_sign")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 대비
+    #[serde(default, rename = "antc_cntg_vrss")]
+    pub antc_cntg_vrss: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "antc_cntg_prdy_ctrt")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 예상 거래량
+    #[serde(default, rename = "antc_vol")]
+    pub antc_vol: Decimal,
+    /// 예상 거래대금
+    #[serde(default, rename = "antc_tr_pbmn")]
+    pub antc_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결가 추이[국내주식-118]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsExpPriceTrendOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결가 추이[국내주식-118]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsExpPriceTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsExpPriceTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsExpPriceTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsExpPriceTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 공매도 일별추이[국내주식-134]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyShortSaleOutput1Item {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 공매도 일별추이[국내주식-134]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyShortSaleOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 기준 거래량 합계
+    #[serde(default, rename = "stnd_vol_smtn")]
+    pub stnd_vol_smtn: String,
+    /// 공매도 체결 수량
+    #[serde(default, rename = "ssts_cntg_qty")]
+    pub ssts_cntg_qty: Decimal,
+    /// 공매도 거래량 비중
+    #[serde(default, rename = "ssts_vol_rlim")]
+    pub ssts_vol_rlim: String,
+    /// 누적 공매도 체결 수량
+    #[serde(default, rename = "acml_ssts_cntg_qty")]
+    pub acml_ssts_cntg_qty: Decimal,
+    /// 누적 공매도 체결 수량 비중
+    #[serde(default, rename = "acml_ssts_cntg_qty_rlim")]
+    pub acml_ssts_cntg_qty_rlim: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 기준 거래대금 합계
+    #[serde(default, rename = "stnd_tr_pbmn_smtn")]
+    pub stnd_tr_pbmn_smtn: String,
+    /// 공매도 거래 대금
+    #[serde(default, rename = "ssts_tr_pbmn")]
+    pub ssts_tr_pbmn: String,
+    /// 공매도 거래대금 비중
+    #[serde(default, rename = "ssts_tr_pbmn_rlim")]
+    pub ssts_tr_pbmn_rlim: String,
+    /// 누적 공매도 거래 대금
+    #[serde(default, rename = "acml_ssts_tr_pbmn")]
+    pub acml_ssts_tr_pbmn: String,
+    /// 누적 공매도 거래 대금 비중
+    #[serde(default, rename = "acml_ssts_tr_pbmn_rlim")]
+    pub acml_ssts_tr_pbmn_rlim: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 평균가격
+    #[serde(default, rename = "avrg_prc")]
+    pub avrg_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 공매도 일별추이[국내주식-134]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsDailyShortSaleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsDailyShortSaleOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsDailyShortSaleOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsDailyShortSaleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외예상체결등락률 [국내주식-140]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeExpTransFluctOutputItem {
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 종목 상태 구분 코드
+    #[serde(default, rename = "iscd_stat_cls_code")]
+    pub iscd_stat_cls_code: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 시간외 단일가 예상 체결가
+    #[serde(default, rename = "ovtm_untp_antc_cnpr")]
+    pub ovtm_untp_antc_cnpr: String,
+    ///  시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrss")]
+    pub ovtm_untp_antc_cntg_vrss: String,
+    /// 시간외 단일가 예상 체결 대비
+    #[serde(default, rename = "ovtm_untp_antc_cntg_vrsssign")]
+    pub ovtm_untp_antc_cntg_vrsssign: String,
+    /// 시간외 단일가 예상 체결 대비율
+    #[serde(default, rename = "ovtm_untp_antc_cntg_ctrt")]
+    pub ovtm_untp_antc_cntg_ctrt: String,
+    /// 시간외 단일가 매도호가 잔량1
+    #[serde(default, rename = "ovtm_untp_askp_rsqn1")]
+    pub ovtm_untp_askp_rsqn1: String,
+    /// 시간외 단일가 매수호가 잔량1
+    #[serde(default, rename = "ovtm_untp_bidp_rsqn1")]
+    pub ovtm_untp_bidp_rsqn1: String,
+    /// 시간외 단일가 예상 체결량
+    #[serde(default, rename = "ovtm_untp_antc_cnqn")]
+    pub ovtm_untp_antc_cnqn: String,
+    /// 장중 거래량
+    #[serde(default, rename = "itmt_vol")]
+    pub itmt_vol: Decimal,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외예상체결등락률 [국내주식-140]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingOvertimeExpTransFluctResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingOvertimeExpTransFluctOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingOvertimeExpTransFluctResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 체결금액별 매매비중 [국내주식-192]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsTradprtByamtOutputItem {
+    /// 가격명
+    #[serde(default, rename = "prpr_name")]
+    pub prpr_name: String,
+    /// 합계 평균가격
+    #[serde(default, rename = "smtn_avrg_prpr")]
+    pub smtn_avrg_prpr: String,
+    /// 합계 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 합계 순매수비율
+    #[serde(default, rename = "whol_ntby_qty_rate")]
+    pub whol_ntby_qty_rate: Decimal,
+    /// 합계 순매수건수
+    #[serde(default, rename = "ntby_cntg_csnu")]
+    pub ntby_cntg_csnu: String,
+    /// 매도 거래량
+    #[serde(default, rename = "seln_cnqn_smtn")]
+    pub seln_cnqn_smtn: String,
+    /// 매도 거래량비율
+    #[serde(default, rename = "whol_seln_vol_rate")]
+    pub whol_seln_vol_rate: Decimal,
+    /// 매도 건수
+    #[serde(default, rename = "seln_cntg_csnu")]
+    pub seln_cntg_csnu: String,
+    /// 매수 거래량
+    #[serde(default, rename = "shnu_cnqn_smtn")]
+    pub shnu_cnqn_smtn: String,
+    /// 매수 거래량비율
+    #[serde(default, rename = "whol_shun_vol_rate")]
+    pub whol_shun_vol_rate: Decimal,
+    /// 매수 건수
+    #[serde(default, rename = "shnu_cntg_csnu")]
+    pub shnu_cntg_csnu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 체결금액별 매매비중 [국내주식-192]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsTradprtByamtResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsTradprtByamtOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsTradprtByamtResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내 증시자금 종합 [국내주식-193]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsMktfundsOutputItem {
+    /// 영업일자
+    #[serde(default, rename = "bsop_date")]
+    pub bsop_date: String,
+    /// 업종지수현재가
+    #[serde(default, rename = "bstp_nmix_prpr")]
+    pub bstp_nmix_prpr: String,
+    /// 업종지수전일대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// HTS시가총액
+    #[serde(default, rename = "hts_avls")]
+    pub hts_avls: String,
+    /// 고객예탁금금액
+    #[serde(default, rename = "cust_dpmn_amt")]
+    pub cust_dpmn_amt: Decimal,
+    /// 고객예탁금금액전일대비
+    #[serde(default, rename = "cust_dpmn_amt_prdy_vrss")]
+    pub cust_dpmn_amt_prdy_vrss: String,
+    /// 금액회전율
+    #[serde(default, rename = "amt_tnrt")]
+    pub amt_tnrt: String,
+    /// 미수금액
+    #[serde(default, rename = "uncl_amt")]
+    pub uncl_amt: Decimal,
+    /// 신용융자잔고
+    #[serde(default, rename = "crdt_loan_rmnd")]
+    pub crdt_loan_rmnd: String,
+    /// 선물예수금금액
+    #[serde(default, rename = "futs_tfam_amt")]
+    pub futs_tfam_amt: Decimal,
+    /// 주식형금액
+    #[serde(default, rename = "sttp_amt")]
+    pub sttp_amt: Decimal,
+    /// 혼합형금액
+    #[serde(default, rename = "mxtp_amt")]
+    pub mxtp_amt: Decimal,
+    /// 채권형금액
+    #[serde(default, rename = "bntp_amt")]
+    pub bntp_amt: Decimal,
+    /// MMF금액
+    #[serde(default, rename = "mmf_amt")]
+    pub mmf_amt: Decimal,
+    /// 담보대출잔고금액
+    #[serde(default, rename = "secu_lend_amt")]
+    pub secu_lend_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내 증시자금 종합 [국내주식-193]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsMktfundsResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsMktfundsOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsMktfundsResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [종목별 일별 대차거래추이 [국내주식-135]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsDailyLoanTransOutput1Item {
+    /// 일자
+    #[serde(default, rename = "bsop_date")]
+    pub bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 당일 증가 주수 (체결)
+    #[serde(default, rename = "new_stcn")]
+    pub new_stcn: String,
+    /// 당일 감소 주수 (상환)
+    #[serde(default, rename = "rdmp_stcn")]
+    pub rdmp_stcn: String,
+    /// 대차거래 증감
+    #[serde(default, rename = "prdy_rmnd_vrss")]
+    pub prdy_rmnd_vrss: String,
+    /// 당일 잔고 주수
+    #[serde(default, rename = "rmnd_stcn")]
+    pub rmnd_stcn: String,
+    /// 당일 잔고 금액
+    #[serde(default, rename = "rmnd_amt")]
+    pub rmnd_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [종목별 일별 대차거래추이 [국내주식-135]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsDailyLoanTransResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsDailyLoanTransOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsDailyLoanTransResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 상하한가 포착 [국내주식-190]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsCaptureUplowpriceOutputItem {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 총매도호가잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "askp_rsqn1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "bidp_rsqn1")]
+    pub bidp_rsqn1: String,
+    /// 전일거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 매도체결량
+    #[serde(default, rename = "seln_cnqn")]
+    pub seln_cnqn: String,
+    /// 매수2체결량
+    #[serde(default, rename = "shnu_cnqn")]
+    pub shnu_cnqn: String,
+    /// 주식하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 주식상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// 전일대비거래량비율
+    #[serde(default, rename = "prdy_vrss_vol_rate")]
+    pub prdy_vrss_vol_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 상하한가 포착 [국내주식-190]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsCaptureUplowpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1QuotationsCaptureUplowpriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsCaptureUplowpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 매물대/거래비중 [국내주식-196]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPbarTratioOutput1Item {
+    /// 대표시장한글명
+    #[serde(default, rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: String,
+    /// 주식단축종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 가중평균주식가격
+    #[serde(default, rename = "wghn_avrg_stck_prc")]
+    pub wghn_avrg_stck_prc: String,
+    /// 상장주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 매물대/거래비중 [국내주식-196]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsPbarTratioOutput2Item {
+    /// 데이터순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 주식현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 체결거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 누적거래량비중
+    #[serde(default, rename = "acml_vol_rlim")]
+    pub acml_vol_rlim: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 매물대/거래비중 [국내주식-196]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsPbarTratioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1QuotationsPbarTratioOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1QuotationsPbarTratioOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsPbarTratioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [거래량순위[v1_국내주식-047]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1QuotationsVolumeRankOutputItem {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 상장 주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 평균 거래량
+    #[serde(default, rename = "avrg_vol")]
+    pub avrg_vol: Decimal,
+    /// N일전종가대비현재가대비율
+    #[serde(default, rename = "n_befr_clpr_vrss_prpr_rate")]
+    pub n_befr_clpr_vrss_prpr_rate: Decimal,
+    /// 거래량증가율
+    #[serde(default, rename = "vol_inrt")]
+    pub vol_inrt: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "vol_tnrt")]
+    pub vol_tnrt: String,
+    /// N일 거래량 회전율
+    #[serde(default, rename = "nday_vol_tnrt")]
+    pub nday_vol_tnrt: String,
+    /// 평균 거래 대금
+    #[serde(default, rename = "avrg_tr_pbmn")]
+    pub avrg_tr_pbmn: String,
+    /// 거래대금회전율
+    #[serde(default, rename = "tr_pbmn_tnrt")]
+    pub tr_pbmn_tnrt: String,
+    /// N일 거래대금 회전율
+    #[serde(default, rename = "nday_tr_pbmn_tnrt")]
+    pub nday_tr_pbmn_tnrt: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [거래량순위[v1_국내주식-047]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1QuotationsVolumeRankResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<DomesticStockV1QuotationsVolumeRankOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1QuotationsVolumeRankResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 등락률 순위[v1_국내주식-088]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingFluctuationOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "hgpr_hour")]
+    pub hgpr_hour: String,
+    /// 누적 최고가 일자
+    #[serde(default, rename = "acml_hgpr_date")]
+    pub acml_hgpr_date: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "lwpr_hour")]
+    pub lwpr_hour: String,
+    /// 누적 최저가 일자
+    #[serde(default, rename = "acml_lwpr_date")]
+    pub acml_lwpr_date: String,
+    /// 최저가 대비 현재가 비율
+    #[serde(default, rename = "lwpr_vrss_prpr_rate")]
+    pub lwpr_vrss_prpr_rate: Decimal,
+    /// 지정 일자 종가 대비 현재가 비
+    #[serde(default, rename = "dsgt_date_clpr_vrss_prpr_rate")]
+    pub dsgt_date_clpr_vrss_prpr_rate: Decimal,
+    /// 연속 상승 일수
+    #[serde(default, rename = "cnnt_ascn_dynu")]
+    pub cnnt_ascn_dynu: String,
+    /// 최고가 대비 현재가 비율
+    #[serde(default, rename = "hgpr_vrss_prpr_rate")]
+    pub hgpr_vrss_prpr_rate: Decimal,
+    /// 연속 하락 일수
+    #[serde(default, rename = "cnnt_down_dynu")]
+    pub cnnt_down_dynu: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "oprc_vrss_prpr_sign")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가2 대비 현재가
+    #[serde(default, rename = "oprc_vrss_prpr")]
+    pub oprc_vrss_prpr: String,
+    /// 시가2 대비 현재가 비율
+    #[serde(default, rename = "oprc_vrss_prpr_rate")]
+    pub oprc_vrss_prpr_rate: Decimal,
+    /// 기간 등락
+    #[serde(default, rename = "prd_rsfl")]
+    pub prd_rsfl: String,
+    /// 기간 등락 비율
+    #[serde(default, rename = "prd_rsfl_rate")]
+    pub prd_rsfl_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 등락률 순위[v1_국내주식-088]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingFluctuationResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingFluctuationOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingFluctuationResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 호가잔량 순위[국내주식-089]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingQuoteBalanceOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 총 순 매수호가 잔량
+    #[serde(default, rename = "total_ntsl_bidp_rsqn")]
+    pub total_ntsl_bidp_rsqn: String,
+    /// 매수 잔량 비율
+    #[serde(default, rename = "shnu_rsqn_rate")]
+    pub shnu_rsqn_rate: Decimal,
+    /// 매도 잔량 비율
+    #[serde(default, rename = "seln_rsqn_rate")]
+    pub seln_rsqn_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 호가잔량 순위[국내주식-089]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingQuoteBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingQuoteBalanceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingQuoteBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 수익자산지표 순위[v1_국내주식-090]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingProfitAssetIndexOutputItem {
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매출 총 이익
+    #[serde(default, rename = "sale_totl_prfi")]
+    pub sale_totl_prfi: String,
+    /// 영업 이익
+    #[serde(default, rename = "bsop_prti")]
+    pub bsop_prti: String,
+    /// 경상 이익
+    #[serde(default, rename = "op_prfi")]
+    pub op_prfi: String,
+    /// 당기순이익
+    #[serde(default, rename = "thtr_ntin")]
+    pub thtr_ntin: String,
+    /// 자산총계
+    #[serde(default, rename = "total_aset")]
+    pub total_aset: String,
+    /// 부채총계
+    #[serde(default, rename = "total_lblt")]
+    pub total_lblt: String,
+    /// 자본총계
+    #[serde(default, rename = "total_cptl")]
+    pub total_cptl: String,
+    /// 결산 월
+    #[serde(default, rename = "stac_month")]
+    pub stac_month: String,
+    /// 결산 월 구분 코드
+    #[serde(default, rename = "stac_month_cls_code")]
+    pub stac_month_cls_code: String,
+    /// 조회 건수
+    #[serde(default, rename = "iqry_csnu")]
+    pub iqry_csnu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 수익자산지표 순위[v1_국내주식-090]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingProfitAssetIndexResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingProfitAssetIndexOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingProfitAssetIndexResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시가총액 상위[v1_국내주식-091]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingMarketCapOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 상장 주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 시가 총액
+    #[serde(default, rename = "stck_avls")]
+    pub stck_avls: String,
+    /// 시장 전체 시가총액 비중
+    #[serde(default, rename = "mrkt_whol_avls_rlim")]
+    pub mrkt_whol_avls_rlim: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시가총액 상위[v1_국내주식-091]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingMarketCapResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingMarketCapOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingMarketCapResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 재무비율 순위[v1_국내주식-092]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingFinanceRatioOutputItem {
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 총자본경상이익율
+    #[serde(default, rename = "cptl_op_prfi")]
+    pub cptl_op_prfi: String,
+    /// 총자본 순이익율
+    #[serde(default, rename = "cptl_ntin_rate")]
+    pub cptl_ntin_rate: Decimal,
+    /// 매출액 총이익율
+    #[serde(default, rename = "sale_totl_rate")]
+    pub sale_totl_rate: Decimal,
+    /// 매출액 순이익율
+    #[serde(default, rename = "sale_ntin_rate")]
+    pub sale_ntin_rate: Decimal,
+    /// 자기자본비율
+    #[serde(default, rename = "bis")]
+    pub bis: String,
+    /// 부채 비율
+    #[serde(default, rename = "lblt_rate")]
+    pub lblt_rate: Decimal,
+    /// 차입금 의존도
+    #[serde(default, rename = "bram_depn")]
+    pub bram_depn: String,
+    /// 유보 비율
+    #[serde(default, rename = "rsrv_rate")]
+    pub rsrv_rate: Decimal,
+    /// 매출액 증가율
+    #[serde(default, rename = "grs")]
+    pub grs: String,
+    /// 경상 이익 증가율
+    #[serde(default, rename = "op_prfi_inrt")]
+    pub op_prfi_inrt: String,
+    /// 영업 이익 증가율
+    #[serde(default, rename = "bsop_prfi_inrt")]
+    pub bsop_prfi_inrt: String,
+    /// 순이익 증가율
+    #[serde(default, rename = "ntin_inrt")]
+    pub ntin_inrt: String,
+    /// 자기자본 증가율
+    #[serde(default, rename = "equt_inrt")]
+    pub equt_inrt: String,
+    /// 총자본회전율
+    #[serde(default, rename = "cptl_tnrt")]
+    pub cptl_tnrt: String,
+    /// 매출 채권 회전율
+    #[serde(default, rename = "sale_bond_tnrt")]
+    pub sale_bond_tnrt: String,
+    /// 총자산 증가율
+    #[serde(default, rename = "totl_aset_inrt")]
+    pub totl_aset_inrt: String,
+    /// 결산 월
+    #[serde(default, rename = "stac_month")]
+    pub stac_month: String,
+    /// 결산 월 구분 코드
+    #[serde(default, rename = "stac_month_cls_code")]
+    pub stac_month_cls_code: String,
+    /// 조회 건수
+    #[serde(default, rename = "iqry_csnu")]
+    pub iqry_csnu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 재무비율 순위[v1_국내주식-092]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingFinanceRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingFinanceRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingFinanceRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외잔량 순위[v1_국내주식-093]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingAfterHourBalanceOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "ovtm_total_askp_rsqn")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "ovtm_total_bidp_rsqn")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 장개시전 시간외종가 거래량
+    #[serde(default, rename = "mkob_otcp_vol")]
+    pub mkob_otcp_vol: Decimal,
+    /// 장종료후 시간외종가 거래량
+    #[serde(default, rename = "mkfa_otcp_vol")]
+    pub mkfa_otcp_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외잔량 순위[v1_국내주식-093]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingAfterHourBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingAfterHourBalanceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingAfterHourBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 우선주/괴리율 상위[v1_국내주식-094]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingPreferDisparateRatioOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 우선주 종목코드
+    #[serde(default, rename = "prst_iscd")]
+    pub prst_iscd: String,
+    /// 우선주 한글 종목명
+    #[serde(default, rename = "prst_kor_isnm")]
+    pub prst_kor_isnm: String,
+    /// 우선주 현재가
+    #[serde(default, rename = "prst_prpr")]
+    pub prst_prpr: String,
+    /// 우선주 전일대비
+    #[serde(default, rename = "prst_prdy_vrss")]
+    pub prst_prdy_vrss: String,
+    /// 우선주 전일 대비 부호
+    #[serde(default, rename = "prst_prdy_vrss_sign")]
+    pub prst_prdy_vrss_sign: String,
+    /// 우선주 누적 거래량
+    #[serde(default, rename = "prst_acml_vol")]
+    pub prst_acml_vol: Decimal,
+    /// 차이 현재가
+    #[serde(default, rename = "diff_prpr")]
+    pub diff_prpr: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 우선주 전일 대비율
+    #[serde(default, rename = "prst_prdy_ctrt")]
+    pub prst_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 우선주/괴리율 상위[v1_국내주식-094]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingPreferDisparateRatioResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingPreferDisparateRatioOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingPreferDisparateRatioResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 이격도 순위[v1_국내주식-095]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingDisparityOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 5일 이격도
+    #[serde(default, rename = "d5_dsrt")]
+    pub d5_dsrt: String,
+    /// 10일 이격도
+    #[serde(default, rename = "d10_dsrt")]
+    pub d10_dsrt: String,
+    /// 20일 이격도
+    #[serde(default, rename = "d20_dsrt")]
+    pub d20_dsrt: String,
+    /// 60일 이격도
+    #[serde(default, rename = "d60_dsrt")]
+    pub d60_dsrt: String,
+    /// 120일 이격도
+    #[serde(default, rename = "d120_dsrt")]
+    pub d120_dsrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 이격도 순위[v1_국내주식-095]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingDisparityResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingDisparityOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingDisparityResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시장가치 순위[v1_국내주식-096]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingMarketValueOutputItem {
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// PER
+    #[serde(default, rename = "per")]
+    pub per: String,
+    /// PBR
+    #[serde(default, rename = "pbr")]
+    pub pbr: String,
+    /// PCR
+    #[serde(default, rename = "pcr")]
+    pub pcr: String,
+    /// PSR
+    #[serde(default, rename = "psr")]
+    pub psr: String,
+    /// EPS
+    #[serde(default, rename = "eps")]
+    pub eps: String,
+    /// EVA
+    #[serde(default, rename = "eva")]
+    pub eva: String,
+    /// EBITDA
+    #[serde(default, rename = "ebitda")]
+    pub ebitda: String,
+    /// PV DIV EBITDA
+    #[serde(default, rename = "pv_div_ebitda")]
+    pub pv_div_ebitda: String,
+    /// EBITDA DIV 금융비용
+    #[serde(default, rename = "ebitda_div_fnnc_expn")]
+    pub ebitda_div_fnnc_expn: String,
+    /// 결산 월
+    #[serde(default, rename = "stac_month")]
+    pub stac_month: String,
+    /// 결산 월 구분 코드
+    #[serde(default, rename = "stac_month_cls_code")]
+    pub stac_month_cls_code: String,
+    /// 조회 건수
+    #[serde(default, rename = "iqry_csnu")]
+    pub iqry_csnu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시장가치 순위[v1_국내주식-096]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingMarketValueResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingMarketValueOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingMarketValueResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 체결강도 상위[v1_국내주식-101]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingVolumePowerOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 당일 체결강도
+    #[serde(default, rename = "tday_rltv")]
+    pub tday_rltv: String,
+    /// 매도 체결량 합계
+    #[serde(default, rename = "seln_cnqn_smtn")]
+    pub seln_cnqn_smtn: String,
+    /// 매수2 체결량 합계
+    #[serde(default, rename = "shnu_cnqn_smtn")]
+    pub shnu_cnqn_smtn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 체결강도 상위[v1_국내주식-101]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingVolumePowerResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingVolumePowerOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingVolumePowerResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 관심종목등록 상위[v1_국내주식-102]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingTopInterestStockOutputItem {
+    /// 시장 분류 구분 명
+    #[serde(default, rename = "mrkt_div_cls_name")]
+    pub mrkt_div_cls_name: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 관심 종목 등록 건수
+    #[serde(default, rename = "inter_issu_reg_csnu")]
+    pub inter_issu_reg_csnu: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 관심종목등록 상위[v1_국내주식-102]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingTopInterestStockResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingTopInterestStockOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingTopInterestStockResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 예상체결 상승/하락상위[v1_국내주식-103]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingExpTransUpdownOutputItem {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 매도 잔량
+    #[serde(default, rename = "seln_rsqn")]
+    pub seln_rsqn: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매수2 잔량
+    #[serde(default, rename = "shnu_rsqn")]
+    pub shnu_rsqn: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 체결 거래대금
+    #[serde(default, rename = "antc_tr_pbmn")]
+    pub antc_tr_pbmn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 예상체결 상승/하락상위[v1_국내주식-103]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingExpTransUpdownResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingExpTransUpdownOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingExpTransUpdownResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 당사매매종목 상위[v1_국내주식-104]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingTradedByCompanyOutputItem {
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 매도 체결량 합계
+    #[serde(default, rename = "seln_cnqn_smtn")]
+    pub seln_cnqn_smtn: String,
+    /// 매수2 체결량 합계
+    #[serde(default, rename = "shnu_cnqn_smtn")]
+    pub shnu_cnqn_smtn: String,
+    /// 순매수 체결량
+    #[serde(default, rename = "ntby_cnqn")]
+    pub ntby_cnqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 당사매매종목 상위[v1_국내주식-104]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingTradedByCompanyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingTradedByCompanyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingTradedByCompanyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 신고/신저근접종목 상위[v1_국내주식-105]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingNearNewHighlowOutputItem {
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "askp_rsqn1")]
+    pub askp_rsqn1: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "bidp_rsqn1")]
+    pub bidp_rsqn1: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 신 최고가
+    #[serde(default, rename = "new_hgpr")]
+    pub new_hgpr: String,
+    /// 고가 근접 비율
+    #[serde(default, rename = "hprc_near_rate")]
+    pub hprc_near_rate: Decimal,
+    /// 신 최저가
+    #[serde(default, rename = "new_lwpr")]
+    pub new_lwpr: String,
+    /// 저가 근접 비율
+    #[serde(default, rename = "lwpr_near_rate")]
+    pub lwpr_near_rate: Decimal,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 신고/신저근접종목 상위[v1_국내주식-105]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingNearNewHighlowResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingNearNewHighlowOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingNearNewHighlowResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 배당률 상위[국내주식-106]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingDividendRateOutput1Item {
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 종목코드
+    #[serde(default, rename = "sht_cd")]
+    pub sht_cd: String,
+    /// 종목명
+    #[serde(default, rename = "isin_name")]
+    pub isin_name: String,
+    /// 기준일
+    #[serde(default, rename = "record_date")]
+    pub record_date: String,
+    /// 현금/주식배당금
+    #[serde(default, rename = "per_sto_divi_amt")]
+    pub per_sto_divi_amt: Decimal,
+    /// 현금/주식배당률(%)
+    #[serde(default, rename = "divi_rate")]
+    pub divi_rate: Decimal,
+    /// 배당종류
+    #[serde(default, rename = "divi_kind")]
+    pub divi_kind: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 배당률 상위[국내주식-106]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingDividendRateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1RankingDividendRateOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingDividendRateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 대량체결건수 상위[국내주식-107]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingBulkTransNumOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 데이터 순위
+    #[serde(default, rename = "data_rank")]
+    pub data_rank: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매수2 체결 건수
+    #[serde(default, rename = "shnu_cntg_csnu")]
+    pub shnu_cntg_csnu: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "seln_cntg_csnu")]
+    pub seln_cntg_csnu: String,
+    /// 순매수 체결량
+    #[serde(default, rename = "ntby_cnqn")]
+    pub ntby_cnqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 대량체결건수 상위[국내주식-107]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingBulkTransNumResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingBulkTransNumOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingBulkTransNumResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 신용잔고 상위[국내주식-109]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingCreditBalanceOutput1Item {
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 기준 일자1
+    #[serde(default, rename = "stnd_date1")]
+    pub stnd_date1: String,
+    /// 기준 일자2
+    #[serde(default, rename = "stnd_date2")]
+    pub stnd_date2: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 신용잔고 상위[국내주식-109]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingCreditBalanceOutput2Item {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전체 융자 잔고 주수
+    #[serde(default, rename = "whol_loan_rmnd_stcn")]
+    pub whol_loan_rmnd_stcn: String,
+    /// 전체 융자 잔고 금액
+    #[serde(default, rename = "whol_loan_rmnd_amt")]
+    pub whol_loan_rmnd_amt: Decimal,
+    /// 전체 융자 잔고 비율
+    #[serde(default, rename = "whol_loan_rmnd_rate")]
+    pub whol_loan_rmnd_rate: Decimal,
+    /// 전체 대주 잔고 주수
+    #[serde(default, rename = "whol_stln_rmnd_stcn")]
+    pub whol_stln_rmnd_stcn: String,
+    /// 전체 대주 잔고 금액
+    #[serde(default, rename = "whol_stln_rmnd_amt")]
+    pub whol_stln_rmnd_amt: Decimal,
+    /// 전체 대주 잔고 비율
+    #[serde(default, rename = "whol_stln_rmnd_rate")]
+    pub whol_stln_rmnd_rate: Decimal,
+    /// N일 대비 융자 잔고 증가율
+    #[serde(default, rename = "nday_vrss_loan_rmnd_inrt")]
+    pub nday_vrss_loan_rmnd_inrt: String,
+    /// N일 대비 대주 잔고 증가율
+    #[serde(default, rename = "nday_vrss_stln_rmnd_inrt")]
+    pub nday_vrss_stln_rmnd_inrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 신용잔고 상위[국내주식-109]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingCreditBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1RankingCreditBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1RankingCreditBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingCreditBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 공매도 상위종목[국내주식-133]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingShortSaleOutputItem {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 공매도 체결 수량
+    #[serde(default, rename = "ssts_cntg_qty")]
+    pub ssts_cntg_qty: Decimal,
+    /// 공매도 거래량 비중
+    #[serde(default, rename = "ssts_vol_rlim")]
+    pub ssts_vol_rlim: String,
+    /// 공매도 거래 대금
+    #[serde(default, rename = "ssts_tr_pbmn")]
+    pub ssts_tr_pbmn: String,
+    /// 공매도 거래대금 비중
+    #[serde(default, rename = "ssts_tr_pbmn_rlim")]
+    pub ssts_tr_pbmn_rlim: String,
+    /// 기준 일자1
+    #[serde(default, rename = "stnd_date1")]
+    pub stnd_date1: String,
+    /// 기준 일자2
+    #[serde(default, rename = "stnd_date2")]
+    pub stnd_date2: String,
+    /// 평균가격
+    #[serde(default, rename = "avrg_prc")]
+    pub avrg_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 공매도 상위종목[국내주식-133]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingShortSaleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<DomesticStockV1RankingShortSaleOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingShortSaleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외등락율순위 [국내주식-138]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeFluctuationOutput1Item {
+    /// 시간외 단일가 상한 종목 수             
+    #[serde(
+        default,
+        rename = "ovtm_untp_uplm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ovtm_untp_uplm_issu_cnt: i64,
+    /// 시간외 단일가 상승 종목 수         
+    #[serde(
+        default,
+        rename = "ovtm_untp_ascn_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ovtm_untp_ascn_issu_cnt: i64,
+    /// 시간외 단일가 보합 종목 수      
+    #[serde(
+        default,
+        rename = "ovtm_untp_stnr_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ovtm_untp_stnr_issu_cnt: i64,
+    /// 시간외 단일가 하한 종목 수          
+    #[serde(
+        default,
+        rename = "ovtm_untp_lslm_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ovtm_untp_lslm_issu_cnt: i64,
+    /// 시간외 단일가 하락 종목 수              
+    #[serde(
+        default,
+        rename = "ovtm_untp_down_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ovtm_untp_down_issu_cnt: i64,
+    /// 시간외 단일가 누적 거래량  
+    #[serde(default, rename = "ovtm_untp_acml_vol")]
+    pub ovtm_untp_acml_vol: Decimal,
+    ///  시간외 단일가 누적 거래대금
+    #[serde(default, rename = "ovtm_untp_acml_tr_pbmn")]
+    pub ovtm_untp_acml_tr_pbmn: String,
+    /// 시간외 단일가 거래소 거래량
+    #[serde(default, rename = "ovtm_untp_exch_vol")]
+    pub ovtm_untp_exch_vol: Decimal,
+    /// 시간외 단일가 거래소 거래대금
+    #[serde(default, rename = "ovtm_untp_exch_tr_pbmn")]
+    pub ovtm_untp_exch_tr_pbmn: String,
+    /// 시간외 단일가 KOSDAQ 거래량              
+    #[serde(default, rename = "ovtm_untp_kosdaq_vol")]
+    pub ovtm_untp_kosdaq_vol: Decimal,
+    /// 시간외 단일가 KOSDAQ 거래대금         
+    #[serde(default, rename = "ovtm_untp_kosdaq_tr_pbmn")]
+    pub ovtm_untp_kosdaq_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외등락율순위 [국내주식-138]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeFluctuationOutput2Item {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 매도호가1       
+    #[serde(default, rename = "ovtm_untp_askp1")]
+    pub ovtm_untp_askp1: String,
+    /// 시간외 단일가 매도 잔량       
+    #[serde(default, rename = "ovtm_untp_seln_rsqn")]
+    pub ovtm_untp_seln_rsqn: String,
+    /// 시간외 단일가 매수호가1       
+    #[serde(default, rename = "ovtm_untp_bidp1")]
+    pub ovtm_untp_bidp1: String,
+    /// 시간외 단일가 매수 잔량       
+    #[serde(default, rename = "ovtm_untp_shnu_rsqn")]
+    pub ovtm_untp_shnu_rsqn: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 시간외 대비 누적 거래량 비중  
+    #[serde(default, rename = "ovtm_vrss_acml_vol_rlim")]
+    pub ovtm_vrss_acml_vol_rlim: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외등락율순위 [국내주식-138]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingOvertimeFluctuationResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1RankingOvertimeFluctuationOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1RankingOvertimeFluctuationOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingOvertimeFluctuationResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [국내주식 시간외거래량순위 [국내주식-139]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeVolumeOutput1Item {
+    /// 시간외 단일가 거래소 거래량
+    #[serde(default, rename = "ovtm_untp_exch_vol")]
+    pub ovtm_untp_exch_vol: Decimal,
+    /// 시간외 단일가 거래소 거래대금
+    #[serde(default, rename = "ovtm_untp_exch_tr_pbmn")]
+    pub ovtm_untp_exch_tr_pbmn: String,
+    /// 시간외 단일가 KOSDAQ 거래량
+    #[serde(default, rename = "ovtm_untp_kosdaq_vol")]
+    pub ovtm_untp_kosdaq_vol: Decimal,
+    /// 시간외 단일가 KOSDAQ 거래대금
+    #[serde(default, rename = "ovtm_untp_kosdaq_tr_pbmn")]
+    pub ovtm_untp_kosdaq_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외거래량순위 [국내주식-139]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingOvertimeVolumeOutput2Item {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 시간외 단일가 현재가
+    #[serde(default, rename = "ovtm_untp_prpr")]
+    pub ovtm_untp_prpr: String,
+    /// 시간외 단일가 전일 대비
+    #[serde(default, rename = "ovtm_untp_prdy_vrss")]
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 단일가 전일 대비 부호
+    #[serde(default, rename = "ovtm_untp_prdy_vrss_sign")]
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 단일가 전일 대비율
+    #[serde(default, rename = "ovtm_untp_prdy_ctrt")]
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 단일가 매도 잔량
+    #[serde(default, rename = "ovtm_untp_seln_rsqn")]
+    pub ovtm_untp_seln_rsqn: String,
+    /// 시간외 단일가 매수 잔량
+    #[serde(default, rename = "ovtm_untp_shnu_rsqn")]
+    pub ovtm_untp_shnu_rsqn: String,
+    /// 시간외 단일가 거래량
+    #[serde(default, rename = "ovtm_untp_vol")]
+    pub ovtm_untp_vol: Decimal,
+    /// 시간외 대비 누적 거래량 비중
+    #[serde(default, rename = "ovtm_vrss_acml_vol_rlim")]
+    pub ovtm_vrss_acml_vol_rlim: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [국내주식 시간외거래량순위 [국내주식-139]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingOvertimeVolumeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1RankingOvertimeVolumeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<DomesticStockV1RankingOvertimeVolumeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingOvertimeVolumeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [HTS조회상위20종목 [국내주식-214]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct DomesticStockV1RankingHtsTopViewOutput1Item {
+    /// 시장구분
+    #[serde(default, rename = "mrkt_div_cls_code")]
+    pub mrkt_div_cls_code: String,
+    /// 종목코드
+    #[serde(default, rename = "mksc_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [HTS조회상위20종목 [국내주식-214]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomesticStockV1RankingHtsTopViewResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<DomesticStockV1RankingHtsTopViewOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for DomesticStockV1RankingHtsTopViewResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
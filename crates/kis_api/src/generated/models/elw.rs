@@ -0,0 +1,2593 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [ELW 신규상장종목 [국내주식-181]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsNewlyListedRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 입력종목코드2 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 결재방법 (String, 필수)
+    #[serde(rename = "FID_BLNC_CLS_CODE")]
+    pub fid_blnc_cls_code: String,
+}
+
+/// [ELW 민감도 순위[국내주식-170]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingSensitivityRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 콜풋구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 가격(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 가격(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// 거래량(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 잔존일수(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_1")]
+    pub fid_input_rmnn_dynu_1: String,
+    /// 조회기준일 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 결재방법 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+}
+
+/// [ELW 기초자산별 종목시세 [국내주식-186]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsUdrlAssetPriceRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 시장구분코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 거래량수 (String, 필수)
+    #[serde(rename = "FID_VOL_CNT")]
+    pub fid_vol_cnt: String,
+    /// 대상제외구분코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_EXLS_CLS_CODE")]
+    pub fid_trgt_exls_cls_code: String,
+    /// 입력가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 입력가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 입력거래량1 (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// 입력거래량2 (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 입력잔존일수1 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_1")]
+    pub fid_input_rmnn_dynu_1: String,
+    /// 입력잔존일수2 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_2")]
+    pub fid_input_rmnn_dynu_2: String,
+    /// 옵션 (String, 필수)
+    #[serde(rename = "FID_OPTION")]
+    pub fid_option: String,
+    /// 입력옵션1 (String, 필수)
+    #[serde(rename = "FID_INPUT_OPTION_1")]
+    pub fid_input_option_1: String,
+    /// 입력옵션2 (String, 필수)
+    #[serde(rename = "FID_INPUT_OPTION_2")]
+    pub fid_input_option_2: String,
+}
+
+/// [ELW 종목검색 [국내주식-166]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsCondSearchRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력수1 (String, 필수)
+    #[serde(rename = "FID_INPUT_CNT_1")]
+    pub fid_input_cnt_1: String,
+    /// 순위정렬구분코드2 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE_2")]
+    pub fid_rank_sort_cls_code_2: String,
+    /// 입력수2 (String, 필수)
+    #[serde(rename = "FID_INPUT_CNT_2")]
+    pub fid_input_cnt_2: String,
+    /// 순위정렬구분코드3 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE_3")]
+    pub fid_rank_sort_cls_code_3: String,
+    /// 입력수3 (String, 필수)
+    #[serde(rename = "FID_INPUT_CNT_3")]
+    pub fid_input_cnt_3: String,
+    /// 대상구분코드 (String, 필수)
+    #[serde(rename = "FID_TRGT_CLS_CODE")]
+    pub fid_trgt_cls_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 시장구분코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 입력종목코드2 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 기타구분코드 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+    /// 입력잔존일수1 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_1")]
+    pub fid_input_rmnn_dynu_1: String,
+    /// 입력잔존일수2 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_2")]
+    pub fid_input_rmnn_dynu_2: String,
+    /// 현재가수1 (String, 필수)
+    #[serde(rename = "FID_PRPR_CNT1")]
+    pub fid_prpr_cnt1: String,
+    /// 현재가수2 (String, 필수)
+    #[serde(rename = "FID_PRPR_CNT2")]
+    pub fid_prpr_cnt2: String,
+    /// 등락비율1 (String, 필수)
+    #[serde(rename = "FID_RSFL_RATE1")]
+    pub fid_rsfl_rate1: String,
+    /// 등락비율2 (String, 필수)
+    #[serde(rename = "FID_RSFL_RATE2")]
+    pub fid_rsfl_rate2: String,
+    /// 거래량1 (String, 필수)
+    #[serde(rename = "FID_VOL1")]
+    pub fid_vol1: String,
+    /// 거래량2 (String, 필수)
+    #[serde(rename = "FID_VOL2")]
+    pub fid_vol2: String,
+    /// 적용범위가격1 (String, 필수)
+    #[serde(rename = "FID_APLY_RANG_PRC_1")]
+    pub fid_aply_rang_prc_1: String,
+    /// 적용범위가격2 (String, 필수)
+    #[serde(rename = "FID_APLY_RANG_PRC_2")]
+    pub fid_aply_rang_prc_2: String,
+    /// 레버리지값1 (String, 필수)
+    #[serde(rename = "FID_LVRG_VAL1")]
+    pub fid_lvrg_val1: String,
+    /// 레버리지값2 (String, 필수)
+    #[serde(rename = "FID_LVRG_VAL2")]
+    pub fid_lvrg_val2: String,
+    /// 거래량3 (String, 필수)
+    #[serde(rename = "FID_VOL3")]
+    pub fid_vol3: String,
+    /// 거래량4 (String, 필수)
+    #[serde(rename = "FID_VOL4")]
+    pub fid_vol4: String,
+    /// 내재변동성1 (String, 필수)
+    #[serde(rename = "FID_INTS_VLTL1")]
+    pub fid_ints_vltl1: String,
+    /// 내재변동성2 (String, 필수)
+    #[serde(rename = "FID_INTS_VLTL2")]
+    pub fid_ints_vltl2: String,
+    /// 프리미엄값1 (String, 필수)
+    #[serde(rename = "FID_PRMM_VAL1")]
+    pub fid_prmm_val1: String,
+    /// 프리미엄값2 (String, 필수)
+    #[serde(rename = "FID_PRMM_VAL2")]
+    pub fid_prmm_val2: String,
+    /// 기어링1 (String, 필수)
+    #[serde(rename = "FID_GEAR1")]
+    pub fid_gear1: String,
+    /// 기어링2 (String, 필수)
+    #[serde(rename = "FID_GEAR2")]
+    pub fid_gear2: String,
+    /// 손익분기비율1 (String, 필수)
+    #[serde(rename = "FID_PRLS_QRYR_RATE1")]
+    pub fid_prls_qryr_rate1: String,
+    /// 손익분기비율2 (String, 필수)
+    #[serde(rename = "FID_PRLS_QRYR_RATE2")]
+    pub fid_prls_qryr_rate2: String,
+    /// 델타1 (String, 필수)
+    #[serde(rename = "FID_DELTA1")]
+    pub fid_delta1: String,
+    /// 델타2 (String, 필수)
+    #[serde(rename = "FID_DELTA2")]
+    pub fid_delta2: String,
+    /// 행사가1 (String, 필수)
+    #[serde(rename = "FID_ACPR1")]
+    pub fid_acpr1: String,
+    /// 행사가2 (String, 필수)
+    #[serde(rename = "FID_ACPR2")]
+    pub fid_acpr2: String,
+    /// 주식전환비율1 (String, 필수)
+    #[serde(rename = "FID_STCK_CNVR_RATE1")]
+    pub fid_stck_cnvr_rate1: String,
+    /// 주식전환비율2 (String, 필수)
+    #[serde(rename = "FID_STCK_CNVR_RATE2")]
+    pub fid_stck_cnvr_rate2: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 패리티1 (String, 필수)
+    #[serde(rename = "FID_PRIT1")]
+    pub fid_prit1: String,
+    /// 패리티2 (String, 필수)
+    #[serde(rename = "FID_PRIT2")]
+    pub fid_prit2: String,
+    /// 자본지지점1 (String, 필수)
+    #[serde(rename = "FID_CFP1")]
+    pub fid_cfp1: String,
+    /// 자본지지점2 (String, 필수)
+    #[serde(rename = "FID_CFP2")]
+    pub fid_cfp2: String,
+    /// 지수가격1 (String, 필수)
+    #[serde(rename = "FID_INPUT_NMIX_PRICE_1")]
+    pub fid_input_nmix_price_1: String,
+    /// 지수가격2 (String, 필수)
+    #[serde(rename = "FID_INPUT_NMIX_PRICE_2")]
+    pub fid_input_nmix_price_2: String,
+    /// E기어링값1 (String, 필수)
+    #[serde(rename = "FID_EGEA_VAL1")]
+    pub fid_egea_val1: String,
+    /// E기어링값2 (String, 필수)
+    #[serde(rename = "FID_EGEA_VAL2")]
+    pub fid_egea_val2: String,
+    /// 배당수익율 (String, 필수)
+    #[serde(rename = "FID_INPUT_DVDN_ERT")]
+    pub fid_input_dvdn_ert: String,
+    /// 역사적변동성 (String, 필수)
+    #[serde(rename = "FID_INPUT_HIST_VLTL")]
+    pub fid_input_hist_vltl: String,
+    /// 세타1 (String, 필수)
+    #[serde(rename = "FID_THETA1")]
+    pub fid_theta1: String,
+    /// 세타2 (String, 필수)
+    #[serde(rename = "FID_THETA2")]
+    pub fid_theta2: String,
+}
+
+/// [ELW 당일급변종목[국내주식-171]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingQuickChangeRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 발행사 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시장구분코드 (String, 필수)
+    #[serde(rename = "FID_MRKT_CLS_CODE")]
+    pub fid_mrkt_cls_code: String,
+    /// 가격(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 가격(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// 거래량(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 시간구분코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+    /// 입력 일 또는 분 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 기준시간(분 선택 시) (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_2")]
+    pub fid_input_hour_2: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 결재방법 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+}
+
+/// [ELW 기초자산 목록조회 [국내주식-185]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsUdrlAssetListRequest {
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 비교대상종목조회 [국내주식-183]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsCompareStocksRequest {
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW LP매매추이 [국내주식-182]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsLpTradeTrendRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 투자지표추이(체결) [국내주식-172]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendCcnlRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 투자지표추이(분별) [국내주식-174]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendMinuteRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시간구분코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+    /// 과거데이터 포함 여부 (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+}
+
+/// [ELW 투자지표추이(일별) [국내주식-173]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendDailyRequest {
+    /// 시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 종콕코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 변동성 추이(틱) [국내주식-180]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendTickRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 변동성추이(체결) [국내주식-177]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendCcnlRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 변동성 추이(일별) [국내주식-178]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendDailyRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 민감도 추이(체결) [국내주식-175]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsSensitivityTrendCcnlRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 변동성 추이(분별) [국내주식-179]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendMinuteRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시간구분코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+    /// 과거데이터 포함 여부 (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+}
+
+/// [ELW 민감도 추이(일별) [국내주식-176]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsSensitivityTrendDailyRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [ELW 만기예정/만기종목 [국내주식-184]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsExpirationStocksRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// 분류구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 기타구분코드 (String, 필수)
+    #[serde(rename = "FID_ETC_CLS_CODE")]
+    pub fid_etc_cls_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 발행회사코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 결제방법 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+    /// 입력옵션1 (String, 필수)
+    #[serde(rename = "FID_INPUT_OPTION_1")]
+    pub fid_input_option_1: String,
+}
+
+/// [ELW 지표순위[국내주식-169]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingIndicatorRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 발행사 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 콜풋구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 가격(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 가격(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// 거래량(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 결재방법 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+}
+
+/// [ELW 상승률순위[국내주식-167]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingUpdownRateRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 상승율/하락율 구분 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_1")]
+    pub fid_input_rmnn_dynu_1: String,
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 상승율/하락율 구분 (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [ELW 거래량순위[국내주식-168]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingVolumeRankRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+    /// 기초자산입력종목코드 (String, 필수)
+    #[serde(rename = "FID_UNAS_INPUT_ISCD")]
+    pub fid_unas_input_iscd: String,
+    /// 발행사 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 입력잔존일수 (String, 필수)
+    #[serde(rename = "FID_INPUT_RMNN_DYNU_1")]
+    pub fid_input_rmnn_dynu_1: String,
+    /// 콜풋구분코드 (String, 필수)
+    #[serde(rename = "FID_DIV_CLS_CODE")]
+    pub fid_div_cls_code: String,
+    /// 가격(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_1")]
+    pub fid_input_price_1: String,
+    /// 가격(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_PRICE_2")]
+    pub fid_input_price_2: String,
+    /// 거래량(이상) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_1")]
+    pub fid_input_vol_1: String,
+    /// 거래량(이하) (String, 필수)
+    #[serde(rename = "FID_INPUT_VOL_2")]
+    pub fid_input_vol_2: String,
+    /// 조회기준일 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 소속구분코드 (String, 필수)
+    #[serde(rename = "FID_BLNG_CLS_CODE")]
+    pub fid_blng_cls_code: String,
+    /// LP발행사 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD_2")]
+    pub fid_input_iscd_2: String,
+    /// 만기일-최종거래일조회 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [ELW 신규상장종목 [국내주식-181]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsNewlyListedOutputItem {
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// 기초자산종목명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 발행회사명
+    #[serde(default, rename = "pblc_co_name")]
+    pub pblc_co_name: String,
+    /// 상장주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 주식최종거래일자
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// 조기종료발생기준가격
+    #[serde(default, rename = "elw_ko_barrier")]
+    pub elw_ko_barrier: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 신규상장종목 [국내주식-181]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsNewlyListedResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsNewlyListedOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsNewlyListedResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 민감도 순위[국내주식-170]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingSensitivityOutputItem {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// HTS이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 90일역사적변동성
+    #[serde(default, rename = "d90_hist_vltl")]
+    pub d90_hist_vltl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 민감도 순위[국내주식-170]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1RankingSensitivityResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1RankingSensitivityOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1RankingSensitivityResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 기초자산별 종목시세 [국내주식-186]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsUdrlAssetPriceOutputItem {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 손익분기주가가격
+    #[serde(default, rename = "prls_qryr_stpr_prc")]
+    pub prls_qryr_stpr_prc: String,
+    /// HTS잔존일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// LP보유량
+    #[serde(default, rename = "lp_hvol")]
+    pub lp_hvol: String,
+    /// LP비중
+    #[serde(default, rename = "lp_rlim")]
+    pub lp_rlim: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 손익분기비율
+    #[serde(default, rename = "prls_qryr_rate")]
+    pub prls_qryr_rate: Decimal,
+    /// 자본지지점
+    #[serde(default, rename = "cfp")]
+    pub cfp: String,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// HTS이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// 주식최종거래일자
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// LP순매도량
+    #[serde(default, rename = "lp_ntby_qty")]
+    pub lp_ntby_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 기초자산별 종목시세 [국내주식-186]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsUdrlAssetPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsUdrlAssetPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsUdrlAssetPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 종목검색 [국내주식-166]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsCondSearchOutput1Item {
+    /// 채권단축종목코드
+    #[serde(default, rename = "bond_shrn_iscd")]
+    pub bond_shrn_iscd: String,
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 권리유형명
+    #[serde(default, rename = "rght_type_name")]
+    pub rght_type_name: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// 주식최종거래일자
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// HTS잔존일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 기초자산종목명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 기초자산현재가
+    #[serde(default, rename = "unas_prpr")]
+    pub unas_prpr: String,
+    /// 기초자산전일대비
+    #[serde(default, rename = "unas_prdy_vrss")]
+    pub unas_prdy_vrss: String,
+    /// 기초자산전일대비부호
+    #[serde(default, rename = "unas_prdy_vrss_sign")]
+    pub unas_prdy_vrss_sign: String,
+    /// 기초자산전일대비율
+    #[serde(default, rename = "unas_prdy_ctrt")]
+    pub unas_prdy_ctrt: String,
+    /// 기초자산누적거래량
+    #[serde(default, rename = "unas_acml_vol")]
+    pub unas_acml_vol: Decimal,
+    /// MONEYNESS
+    #[serde(default, rename = "moneyness")]
+    pub moneyness: String,
+    /// ATM구분명
+    #[serde(default, rename = "atm_cls_name")]
+    pub atm_cls_name: String,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 손익분기비율
+    #[serde(default, rename = "prls_qryr_rate")]
+    pub prls_qryr_rate: Decimal,
+    /// 자본지지점
+    #[serde(default, rename = "cfp")]
+    pub cfp: String,
+    /// 상장주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 발행회사명
+    #[serde(default, rename = "pblc_co_name")]
+    pub pblc_co_name: String,
+    /// LP회원사명
+    #[serde(default, rename = "lp_mbcr_name")]
+    pub lp_mbcr_name: String,
+    /// LP보유비율
+    #[serde(default, rename = "lp_hldn_rate")]
+    pub lp_hldn_rate: Decimal,
+    /// ELW권리형태
+    #[serde(default, rename = "elw_rght_form")]
+    pub elw_rght_form: String,
+    /// 조기종료발생기준가격
+    #[serde(default, rename = "elw_ko_barrier")]
+    pub elw_ko_barrier: String,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 기초자산단축종목코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// 만기일자
+    #[serde(default, rename = "mtrt_date")]
+    pub mtrt_date: String,
+    /// 프리미엄값
+    #[serde(default, rename = "prmm_val")]
+    pub prmm_val: Decimal,
+    /// 주식LP종료일자
+    #[serde(default, rename = "stck_lp_fin_date")]
+    pub stck_lp_fin_date: String,
+    /// 틱환산가
+    #[serde(default, rename = "tick_conv_prc")]
+    pub tick_conv_prc: String,
+    /// 손익분기주가가격
+    #[serde(default, rename = "prls_qryr_stpr_prc")]
+    pub prls_qryr_stpr_prc: String,
+    /// LP보유량
+    #[serde(default, rename = "lp_hvol")]
+    pub lp_hvol: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 종목검색 [국내주식-166]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsCondSearchResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<ElwV1QuotationsCondSearchOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsCondSearchResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 당일급변종목[국내주식-171]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingQuickChangeOutputItem {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 기준값
+    #[serde(default, rename = "stnd_val")]
+    pub stnd_val: Decimal,
+    /// 기준값대비
+    #[serde(default, rename = "stnd_val_vrss")]
+    pub stnd_val_vrss: String,
+    /// 기준값대비율
+    #[serde(default, rename = "stnd_val_ctrt")]
+    pub stnd_val_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 당일급변종목[국내주식-171]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1RankingQuickChangeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1RankingQuickChangeOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1RankingQuickChangeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 기초자산 목록조회 [국내주식-185]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsUdrlAssetListOutputItem {
+    /// 기초자산단축종목코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// 기초자산종목명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 기초자산현재가
+    #[serde(default, rename = "unas_prpr")]
+    pub unas_prpr: String,
+    /// 기초자산전일대비
+    #[serde(default, rename = "unas_prdy_vrss")]
+    pub unas_prdy_vrss: String,
+    /// 기초자산전일대비부호
+    #[serde(default, rename = "unas_prdy_vrss_sign")]
+    pub unas_prdy_vrss_sign: String,
+    /// 기초자산전일대비율
+    #[serde(default, rename = "unas_prdy_ctrt")]
+    pub unas_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 기초자산 목록조회 [국내주식-185]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsUdrlAssetListResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsUdrlAssetListOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsUdrlAssetListResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 비교대상종목조회 [국내주식-183]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsCompareStocksOutputItem {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 비교대상종목조회 [국내주식-183]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsCompareStocksResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsCompareStocksOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsCompareStocksResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW LP매매추이 [국내주식-182]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsLpTradeTrendOutput1Item {
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 손익분기비율
+    #[serde(default, rename = "prls_qryr_rate")]
+    pub prls_qryr_rate: Decimal,
+    /// 자본지지점
+    #[serde(default, rename = "cfp")]
+    pub cfp: String,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 조기종료발생기준가격
+    #[serde(default, rename = "elw_ko_barrier")]
+    pub elw_ko_barrier: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW LP매매추이 [국내주식-182]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsLpTradeTrendOutput2Item {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// LP매도수량
+    #[serde(default, rename = "lp_seln_qty")]
+    pub lp_seln_qty: Decimal,
+    /// LP매도평균단가
+    #[serde(default, rename = "lp_seln_avrg_unpr")]
+    pub lp_seln_avrg_unpr: Decimal,
+    /// LP매수수량
+    #[serde(default, rename = "lp_shnu_qty")]
+    pub lp_shnu_qty: Decimal,
+    /// LP매수평균단가
+    #[serde(default, rename = "lp_shnu_avrg_unpr")]
+    pub lp_shnu_avrg_unpr: Decimal,
+    /// LP보유량
+    #[serde(default, rename = "lp_hvol")]
+    pub lp_hvol: String,
+    /// LP보유비율
+    #[serde(default, rename = "lp_hldn_rate")]
+    pub lp_hldn_rate: Decimal,
+    /// 개인매매수량
+    #[serde(default, rename = "prsn_deal_qty")]
+    pub prsn_deal_qty: Decimal,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW LP매매추이 [국내주식-182]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsLpTradeTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<ElwV1QuotationsLpTradeTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<ElwV1QuotationsLpTradeTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsLpTradeTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 투자지표추이(체결) [국내주식-172]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendCcnlOutputItem {
+    /// 주식체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 투자지표추이(체결) [국내주식-172]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsIndicatorTrendCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsIndicatorTrendCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsIndicatorTrendCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 투자지표추이(분별) [국내주식-174]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendMinuteOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// ELW시가2
+    #[serde(default, rename = "elw_oprc")]
+    pub elw_oprc: String,
+    /// ELW최고가
+    #[serde(default, rename = "elw_hgpr")]
+    pub elw_hgpr: String,
+    /// ELW최저가
+    #[serde(default, rename = "elw_lwpr")]
+    pub elw_lwpr: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 프리미엄값
+    #[serde(default, rename = "prmm_val")]
+    pub prmm_val: Decimal,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 투자지표추이(분별) [국내주식-174]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsIndicatorTrendMinuteResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsIndicatorTrendMinuteOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsIndicatorTrendMinuteResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 투자지표추이(일별) [국내주식-173]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsIndicatorTrendDailyOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 기어링
+    #[serde(default, rename = "gear")]
+    pub gear: String,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// ELW시가2
+    #[serde(default, rename = "elw_oprc")]
+    pub elw_oprc: String,
+    /// ELW최고가
+    #[serde(default, rename = "elw_hgpr")]
+    pub elw_hgpr: String,
+    /// ELW최저가
+    #[serde(default, rename = "elw_lwpr")]
+    pub elw_lwpr: String,
+    /// 접근도
+    #[serde(default, rename = "apprch_rate")]
+    pub apprch_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 투자지표추이(일별) [국내주식-173]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsIndicatorTrendDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsIndicatorTrendDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsIndicatorTrendDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 변동성 추이(틱) [국내주식-180]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendTickOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "bsop_date")]
+    pub bsop_date: String,
+    /// ELW현재가
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 전일대비
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 변동성 추이(틱) [국내주식-180]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsVolatilityTrendTickResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsVolatilityTrendTickOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsVolatilityTrendTickResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 변동성추이(체결) [국내주식-177]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendCcnlOutputItem {
+    /// 주식체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 매수호가
+    #[serde(default, rename = "bidp")]
+    pub bidp: String,
+    /// 매도호가
+    #[serde(default, rename = "askp")]
+    pub askp: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 변동성추이(체결) [국내주식-177]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsVolatilityTrendCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsVolatilityTrendCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsVolatilityTrendCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 변동성 추이(일별) [국내주식-178]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendDailyOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// ELW 현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// elw 시가2
+    #[serde(default, rename = "elw_oprc")]
+    pub elw_oprc: String,
+    /// elw 최고가
+    #[serde(default, rename = "elw_hgpr")]
+    pub elw_hgpr: String,
+    /// elw 최저가
+    #[serde(default, rename = "elw_lwpr")]
+    pub elw_lwpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 10일 역사적 변동성
+    #[serde(default, rename = "d10_hist_vltl")]
+    pub d10_hist_vltl: String,
+    /// 20일 역사적 변동성
+    #[serde(default, rename = "d20_hist_vltl")]
+    pub d20_hist_vltl: String,
+    /// 30일 역사적 변동성
+    #[serde(default, rename = "d30_hist_vltl")]
+    pub d30_hist_vltl: String,
+    /// 60일 역사적 변동성
+    #[serde(default, rename = "d60_hist_vltl")]
+    pub d60_hist_vltl: String,
+    /// 90일 역사적 변동성
+    #[serde(default, rename = "d90_hist_vltl")]
+    pub d90_hist_vltl: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 변동성 추이(일별) [국내주식-178]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsVolatilityTrendDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsVolatilityTrendDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsVolatilityTrendDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 민감도 추이(체결) [국내주식-175]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsSensitivityTrendCcnlOutputItem {
+    /// 주식체결시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// hts 이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 델타 값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 민감도 추이(체결) [국내주식-175]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsSensitivityTrendCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsSensitivityTrendCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsSensitivityTrendCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 변동성 추이(분별) [국내주식-179]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsVolatilityTrendMinuteOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// ELW 시가2
+    #[serde(default, rename = "elw_oprc")]
+    pub elw_oprc: String,
+    /// ELW 최고가
+    #[serde(default, rename = "elw_hgpr")]
+    pub elw_hgpr: String,
+    /// ELW 최저가
+    #[serde(default, rename = "elw_lwpr")]
+    pub elw_lwpr: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 역사적 변동성
+    #[serde(default, rename = "hist_vltl")]
+    pub hist_vltl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 변동성 추이(분별) [국내주식-179]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsVolatilityTrendMinuteResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsVolatilityTrendMinuteOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsVolatilityTrendMinuteResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 민감도 추이(일별) [국내주식-176]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsSensitivityTrendDailyOutputItem {
+    /// 주식영업일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// HTS이론가
+    #[serde(default, rename = "hts_thpr")]
+    pub hts_thpr: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 감마
+    #[serde(default, rename = "gama")]
+    pub gama: String,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 베가
+    #[serde(default, rename = "vega")]
+    pub vega: String,
+    /// 로우
+    #[serde(default, rename = "rho")]
+    pub rho: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 민감도 추이(일별) [국내주식-176]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsSensitivityTrendDailyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1QuotationsSensitivityTrendDailyOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsSensitivityTrendDailyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 만기예정/만기종목 [국내주식-184]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1QuotationsExpirationStocksOutput1Item {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// 기초자산종목명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 기초자산현재가
+    #[serde(default, rename = "unas_prpr")]
+    pub unas_prpr: String,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// 주식최종거래일자
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// 총상환금액
+    #[serde(default, rename = "total_rdmp_amt")]
+    pub total_rdmp_amt: Decimal,
+    /// 상환금액
+    #[serde(default, rename = "rdmp_amt")]
+    pub rdmp_amt: Decimal,
+    /// 상장주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// LP보유량
+    #[serde(default, rename = "lp_hvol")]
+    pub lp_hvol: String,
+    /// 확정지급2가격
+    #[serde(default, rename = "ccls_paym_prc")]
+    pub ccls_paym_prc: String,
+    /// 만기평가금액
+    #[serde(default, rename = "mtrt_vltn_amt")]
+    pub mtrt_vltn_amt: Decimal,
+    /// 행사2기간종료일자
+    #[serde(default, rename = "evnt_prd_fin_date")]
+    pub evnt_prd_fin_date: String,
+    /// 결제일자
+    #[serde(default, rename = "stlm_date")]
+    pub stlm_date: String,
+    /// 발행가격
+    #[serde(default, rename = "pblc_prc")]
+    pub pblc_prc: String,
+    /// 기초자산단축종목코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// 표준종목코드
+    #[serde(default, rename = "stnd_iscd")]
+    pub stnd_iscd: String,
+    /// 상환청구금액
+    #[serde(default, rename = "rdmp_ask_amt")]
+    pub rdmp_ask_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 만기예정/만기종목 [국내주식-184]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1QuotationsExpirationStocksResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<ElwV1QuotationsExpirationStocksOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1QuotationsExpirationStocksResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 지표순위[국내주식-169]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingIndicatorOutput1Item {
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 조기종료발생기준가격
+    #[serde(default, rename = "elw_ko_barrier")]
+    pub elw_ko_barrier: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 지표순위[국내주식-169]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1RankingIndicatorResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<ElwV1RankingIndicatorOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1RankingIndicatorResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 상승률순위[국내주식-167]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingUpdownRateOutputItem {
+    /// HTS한글종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 주식기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 기준가대비현재가부호
+    #[serde(default, rename = "sdpr_vrss_prpr_sign")]
+    pub sdpr_vrss_prpr_sign: String,
+    /// 기준가대비현재가
+    #[serde(default, rename = "sdpr_vrss_prpr")]
+    pub sdpr_vrss_prpr: String,
+    /// 기준가대비현재가비율
+    #[serde(default, rename = "sdpr_vrss_prpr_rate")]
+    pub sdpr_vrss_prpr_rate: Decimal,
+    /// 주식시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "oprc_vrss_prpr_sign")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가2대비현재가
+    #[serde(default, rename = "oprc_vrss_prpr")]
+    pub oprc_vrss_prpr: String,
+    /// 시가2대비현재가비율
+    #[serde(default, rename = "oprc_vrss_prpr_rate")]
+    pub oprc_vrss_prpr_rate: Decimal,
+    /// 주식최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 기간등락부호
+    #[serde(default, rename = "prd_rsfl_sign")]
+    pub prd_rsfl_sign: String,
+    /// 기간등락
+    #[serde(default, rename = "prd_rsfl")]
+    pub prd_rsfl: String,
+    /// 기간등락비율
+    #[serde(default, rename = "prd_rsfl_rate")]
+    pub prd_rsfl_rate: Decimal,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// HTS잔존일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// 기초자산명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 기초자산코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// LP보유비율
+    #[serde(default, rename = "lp_hldn_rate")]
+    pub lp_hldn_rate: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 손익분기주가가격
+    #[serde(default, rename = "prls_qryr_stpr_prc")]
+    pub prls_qryr_stpr_prc: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 손익분기비율
+    #[serde(default, rename = "prls_qryr_rate")]
+    pub prls_qryr_rate: Decimal,
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// 주식최종거래일자
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 상승률순위[국내주식-167]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1RankingUpdownRateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1RankingUpdownRateOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1RankingUpdownRateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ELW 거래량순위[국내주식-168]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct ElwV1RankingVolumeRankOutputItem {
+    /// ELW한글종목명
+    #[serde(default, rename = "elw_kor_isnm")]
+    pub elw_kor_isnm: String,
+    /// ELW단축종목코드
+    #[serde(default, rename = "elw_shrn_iscd")]
+    pub elw_shrn_iscd: String,
+    /// ELW현재가
+    #[serde(default, rename = "elw_prpr")]
+    pub elw_prpr: String,
+    /// 전일대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 상장주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 누적거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// N전일거래량
+    #[serde(default, rename = "n_prdy_vol")]
+    pub n_prdy_vol: Decimal,
+    /// N전일거래량대비
+    #[serde(default, rename = "n_prdy_vol_vrss")]
+    pub n_prdy_vol_vrss: String,
+    /// 거래량증가율
+    #[serde(default, rename = "vol_inrt")]
+    pub vol_inrt: String,
+    /// 거래량회전율
+    #[serde(default, rename = "vol_tnrt")]
+    pub vol_tnrt: String,
+    /// N일거래량회전율
+    #[serde(default, rename = "nday_vol_tnrt")]
+    pub nday_vol_tnrt: String,
+    /// 누적거래대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// N전일거래대금
+    #[serde(default, rename = "n_prdy_tr_pbmn")]
+    pub n_prdy_tr_pbmn: String,
+    /// N전일거래대금대비
+    #[serde(default, rename = "n_prdy_tr_pbmn_vrss")]
+    pub n_prdy_tr_pbmn_vrss: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "total_askp_rsqn")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "total_bidp_rsqn")]
+    pub total_bidp_rsqn: String,
+    /// 순매도잔량
+    #[serde(default, rename = "ntsl_rsqn")]
+    pub ntsl_rsqn: String,
+    /// 순매수잔량
+    #[serde(default, rename = "ntby_rsqn")]
+    pub ntby_rsqn: String,
+    /// 매도잔량비율
+    #[serde(default, rename = "seln_rsqn_rate")]
+    pub seln_rsqn_rate: Decimal,
+    /// 매수2잔량비율
+    #[serde(default, rename = "shnu_rsqn_rate")]
+    pub shnu_rsqn_rate: Decimal,
+    /// 주식전환비율
+    #[serde(default, rename = "stck_cnvr_rate")]
+    pub stck_cnvr_rate: Decimal,
+    /// HTS잔존일수
+    #[serde(default, rename = "hts_rmnn_dynu")]
+    pub hts_rmnn_dynu: String,
+    /// 내재가치값
+    #[serde(default, rename = "invl_val")]
+    pub invl_val: Decimal,
+    /// 시간가치값
+    #[serde(default, rename = "tmvl_val")]
+    pub tmvl_val: Decimal,
+    /// 행사가
+    #[serde(default, rename = "acpr")]
+    pub acpr: String,
+    /// LP회원사명
+    #[serde(default, rename = "lp_mbcr_name")]
+    pub lp_mbcr_name: String,
+    /// 기초자산명
+    #[serde(default, rename = "unas_isnm")]
+    pub unas_isnm: String,
+    /// 최종거래일
+    #[serde(default, rename = "stck_last_tr_date")]
+    pub stck_last_tr_date: String,
+    /// 기초자산코드
+    #[serde(default, rename = "unas_shrn_iscd")]
+    pub unas_shrn_iscd: String,
+    /// 전일거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// LP보유비율
+    #[serde(default, rename = "lp_hldn_rate")]
+    pub lp_hldn_rate: Decimal,
+    /// 패리티
+    #[serde(default, rename = "prit")]
+    pub prit: String,
+    /// 손익분기주가가격
+    #[serde(default, rename = "prls_qryr_stpr_prc")]
+    pub prls_qryr_stpr_prc: String,
+    /// 델타값
+    #[serde(default, rename = "delta_val")]
+    pub delta_val: Decimal,
+    /// 세타
+    #[serde(default, rename = "theta")]
+    pub theta: String,
+    /// 손익분기비율
+    #[serde(default, rename = "prls_qryr_rate")]
+    pub prls_qryr_rate: Decimal,
+    /// 주식상장일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "hts_ints_vltl")]
+    pub hts_ints_vltl: String,
+    /// 레버리지값
+    #[serde(default, rename = "lvrg_val")]
+    pub lvrg_val: Decimal,
+    /// LP순매도량
+    #[serde(default, rename = "lp_ntby_qty")]
+    pub lp_ntby_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ELW 거래량순위[국내주식-168]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ElwV1RankingVolumeRankResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<ElwV1RankingVolumeRankOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for ElwV1RankingVolumeRankResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
@@ -0,0 +1,727 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [ETF/ETN 현재가[v1_국내주식-068]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsInquirePriceRequest {
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+}
+
+/// [ETF 구성종목시세[국내주식-073]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsInquireComponentStockPriceRequest {
+    /// 조건시장분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+}
+
+/// [NAV 비교추이(종목)[v1_국내주식-069]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonTrendRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+}
+
+/// [NAV 비교추이(일)[v1_국내주식-071]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonDailyTrendRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "fid_input_date_1")]
+    pub fid_input_date_1: String,
+    /// FID 입력 날짜2 (String, 필수)
+    #[serde(rename = "fid_input_date_2")]
+    pub fid_input_date_2: String,
+}
+
+/// [NAV 비교추이(분)[v1_국내주식-070]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonTimeTrendRequest {
+    /// FID 시간 구분 코드 (String, 필수)
+    #[serde(rename = "fid_hour_cls_code")]
+    pub fid_hour_cls_code: String,
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "fid_cond_mrkt_div_code")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "fid_input_iscd")]
+    pub fid_input_iscd: String,
+}
+
+/// [ETF/ETN 현재가[v1_국내주식-068]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsInquirePriceOutputItem {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 주식 상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// 주식 하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 전일 종가 대비 시가2 비율
+    #[serde(default, rename = "prdy_clpr_vrss_oprc_rate")]
+    pub prdy_clpr_vrss_oprc_rate: Decimal,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 전일 종가 대비 최고가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_hgpr_rate")]
+    pub prdy_clpr_vrss_hgpr_rate: Decimal,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 전일 종가 대비 최저가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_lwpr_rate")]
+    pub prdy_clpr_vrss_lwpr_rate: Decimal,
+    /// 전일 최종 NAV
+    #[serde(default, rename = "prdy_last_nav")]
+    pub prdy_last_nav: String,
+    /// NAV
+    #[serde(default, rename = "nav")]
+    pub nav: String,
+    /// NAV 전일 대비
+    #[serde(default, rename = "nav_prdy_vrss")]
+    pub nav_prdy_vrss: String,
+    /// NAV 전일 대비 부호
+    #[serde(default, rename = "nav_prdy_vrss_sign")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일 대비율
+    #[serde(default, rename = "nav_prdy_ctrt")]
+    pub nav_prdy_ctrt: String,
+    /// 추적 오차율
+    #[serde(default, rename = "trc_errt")]
+    pub trc_errt: String,
+    /// 주식 기준가
+    #[serde(default, rename = "stck_sdpr")]
+    pub stck_sdpr: String,
+    /// 주식 대용가
+    #[serde(default, rename = "stck_sspr")]
+    pub stck_sspr: String,
+    /// 지수 대비율
+    #[serde(default, rename = "nmix_ctrt")]
+    pub nmix_ctrt: String,
+    /// ETF 유통 주수
+    #[serde(default, rename = "etf_crcl_stcn")]
+    pub etf_crcl_stcn: String,
+    /// ETF 순자산 총액
+    #[serde(default, rename = "etf_ntas_ttam")]
+    pub etf_ntas_ttam: String,
+    /// ETF 외화 순자산 총액
+    #[serde(default, rename = "etf_frcr_ntas_ttam")]
+    pub etf_frcr_ntas_ttam: String,
+    /// 외국인 한도 비율
+    #[serde(default, rename = "frgn_limt_rate")]
+    pub frgn_limt_rate: Decimal,
+    /// 외국인 주문 가능 수량
+    #[serde(default, rename = "frgn_oder_able_qty")]
+    pub frgn_oder_able_qty: Decimal,
+    /// ETF CU 단위 증권 수
+    #[serde(
+        default,
+        rename = "etf_cu_unit_scrt_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub etf_cu_unit_scrt_cnt: i64,
+    /// ETF 구성 종목 수
+    #[serde(
+        default,
+        rename = "etf_cnfg_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub etf_cnfg_issu_cnt: i64,
+    /// ETF 배당 주기
+    #[serde(default, rename = "etf_dvdn_cycl")]
+    pub etf_dvdn_cycl: String,
+    /// 통화 코드
+    #[serde(default, rename = "crcd")]
+    pub crcd: String,
+    /// ETF 유통 순자산 총액
+    #[serde(default, rename = "etf_crcl_ntas_ttam")]
+    pub etf_crcl_ntas_ttam: String,
+    /// ETF 외화 유통 순자산 총액
+    #[serde(default, rename = "etf_frcr_crcl_ntas_ttam")]
+    pub etf_frcr_crcl_ntas_ttam: String,
+    /// ETF 외화 최종 순자산 가치 값
+    #[serde(default, rename = "etf_frcr_last_ntas_wrth_val")]
+    pub etf_frcr_last_ntas_wrth_val: Decimal,
+    /// LP 주문 가능 구분 코드
+    #[serde(default, rename = "lp_oder_able_cls_code")]
+    pub lp_oder_able_cls_code: String,
+    /// 주식 연중 최고가
+    #[serde(default, rename = "stck_dryy_hgpr")]
+    pub stck_dryy_hgpr: String,
+    /// 연중 최고가 대비 현재가 비율
+    #[serde(default, rename = "dryy_hgpr_vrss_prpr_rate")]
+    pub dryy_hgpr_vrss_prpr_rate: Decimal,
+    /// 연중 최고가 일자
+    #[serde(default, rename = "dryy_hgpr_date")]
+    pub dryy_hgpr_date: String,
+    /// 주식 연중 최저가
+    #[serde(default, rename = "stck_dryy_lwpr")]
+    pub stck_dryy_lwpr: String,
+    /// 연중 최저가 대비 현재가 비율
+    #[serde(default, rename = "dryy_lwpr_vrss_prpr_rate")]
+    pub dryy_lwpr_vrss_prpr_rate: Decimal,
+    /// 연중 최저가 일자
+    #[serde(default, rename = "dryy_lwpr_date")]
+    pub dryy_lwpr_date: String,
+    /// 업종 한글 종목명
+    #[serde(default, rename = "bstp_kor_isnm")]
+    pub bstp_kor_isnm: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "vi_cls_code")]
+    pub vi_cls_code: String,
+    /// 상장 주수
+    #[serde(default, rename = "lstn_stcn")]
+    pub lstn_stcn: String,
+    /// 외국인 보유 수량
+    #[serde(default, rename = "frgn_hldn_qty")]
+    pub frgn_hldn_qty: Decimal,
+    /// 외국인 보유 수량 비율
+    #[serde(default, rename = "frgn_hldn_qty_rate")]
+    pub frgn_hldn_qty_rate: Decimal,
+    /// ETF 추적 수익률 배수
+    #[serde(default, rename = "etf_trc_ert_mltp")]
+    pub etf_trc_ert_mltp: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 회원사 명
+    #[serde(default, rename = "mbcr_name")]
+    pub mbcr_name: String,
+    /// 주식 상장 일자
+    #[serde(default, rename = "stck_lstn_date")]
+    pub stck_lstn_date: String,
+    /// 만기 일자
+    #[serde(default, rename = "mtrt_date")]
+    pub mtrt_date: String,
+    /// 분배금형태코드
+    #[serde(default, rename = "shrg_type_code")]
+    pub shrg_type_code: String,
+    /// LP 보유 비율
+    #[serde(default, rename = "lp_hldn_rate")]
+    pub lp_hldn_rate: Decimal,
+    /// ETF대상지수업종코드
+    #[serde(default, rename = "etf_trgt_nmix_bstp_code")]
+    pub etf_trgt_nmix_bstp_code: String,
+    /// ETF 분류 명
+    #[serde(default, rename = "etf_div_name")]
+    pub etf_div_name: String,
+    /// ETF 대표 업종 한글 종목명
+    #[serde(default, rename = "etf_rprs_bstp_kor_isnm")]
+    pub etf_rprs_bstp_kor_isnm: String,
+    /// ETN LP 보유량
+    #[serde(default, rename = "lp_hldn_vol")]
+    pub lp_hldn_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ETF/ETN 현재가[v1_국내주식-068]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EtfetnV1QuotationsInquirePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<EtfetnV1QuotationsInquirePriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for EtfetnV1QuotationsInquirePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [ETF 구성종목시세[국내주식-073]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsInquireComponentStockPriceOutput1Item {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// ETF구성종목시가총액
+    #[serde(default, rename = "etf_cnfg_issu_avls")]
+    pub etf_cnfg_issu_avls: String,
+    /// NAV
+    #[serde(default, rename = "nav")]
+    pub nav: String,
+    /// NAV 전일 대비 부호
+    #[serde(default, rename = "nav_prdy_vrss_sign")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일 대비
+    #[serde(default, rename = "nav_prdy_vrss")]
+    pub nav_prdy_vrss: String,
+    /// NAV 전일 대비율
+    #[serde(default, rename = "nav_prdy_ctrt")]
+    pub nav_prdy_ctrt: String,
+    /// ETF 순자산 총액
+    #[serde(default, rename = "etf_ntas_ttam")]
+    pub etf_ntas_ttam: String,
+    /// NAV전일종가
+    #[serde(default, rename = "prdy_clpr_nav")]
+    pub prdy_clpr_nav: String,
+    /// NAV시가
+    #[serde(default, rename = "oprc_nav")]
+    pub oprc_nav: String,
+    /// NAV고가
+    #[serde(default, rename = "hprc_nav")]
+    pub hprc_nav: String,
+    /// NAV저가
+    #[serde(default, rename = "lprc_nav")]
+    pub lprc_nav: String,
+    /// ETF CU 단위 증권 수
+    #[serde(
+        default,
+        rename = "etf_cu_unit_scrt_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub etf_cu_unit_scrt_cnt: i64,
+    /// ETF 구성 종목 수
+    #[serde(
+        default,
+        rename = "etf_cnfg_issu_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub etf_cnfg_issu_cnt: i64,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ETF 구성종목시세[국내주식-073]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsInquireComponentStockPriceOutput2Item {
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 당일 등락 비율
+    #[serde(default, rename = "tday_rsfl_rate")]
+    pub tday_rsfl_rate: Decimal,
+    /// 전일 대비 거래량
+    #[serde(default, rename = "prdy_vrss_vol")]
+    pub prdy_vrss_vol: Decimal,
+    /// 거래대금회전율
+    #[serde(default, rename = "tr_pbmn_tnrt")]
+    pub tr_pbmn_tnrt: String,
+    /// HTS 시가총액
+    #[serde(default, rename = "hts_avls")]
+    pub hts_avls: String,
+    /// ETF구성종목시가총액
+    #[serde(default, rename = "etf_cnfg_issu_avls")]
+    pub etf_cnfg_issu_avls: String,
+    /// ETF구성종목비중
+    #[serde(default, rename = "etf_cnfg_issu_rlim")]
+    pub etf_cnfg_issu_rlim: String,
+    /// ETF구성종목내평가금액
+    #[serde(default, rename = "etf_vltn_amt")]
+    pub etf_vltn_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [ETF 구성종목시세[국내주식-073]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EtfetnV1QuotationsInquireComponentStockPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<EtfetnV1QuotationsInquireComponentStockPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<EtfetnV1QuotationsInquireComponentStockPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for EtfetnV1QuotationsInquireComponentStockPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [NAV 비교추이(종목)[v1_국내주식-069]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonTrendOutput1Item {
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 주식 전일 종가
+    #[serde(default, rename = "stck_prdy_clpr")]
+    pub stck_prdy_clpr: String,
+    /// 주식 시가2
+    #[serde(default, rename = "stck_oprc")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "stck_hgpr")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "stck_lwpr")]
+    pub stck_lwpr: String,
+    /// 주식 상한가
+    #[serde(default, rename = "stck_mxpr")]
+    pub stck_mxpr: String,
+    /// 주식 하한가
+    #[serde(default, rename = "stck_llam")]
+    pub stck_llam: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [NAV 비교추이(종목)[v1_국내주식-069]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonTrendOutput2Item {
+    /// NAV
+    #[serde(default, rename = "nav")]
+    pub nav: String,
+    /// NAV 전일 대비 부호
+    #[serde(default, rename = "nav_prdy_vrss_sign")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일 대비
+    #[serde(default, rename = "nav_prdy_vrss")]
+    pub nav_prdy_vrss: String,
+    /// NAV 전일 대비율
+    #[serde(default, rename = "nav_prdy_ctrt")]
+    pub nav_prdy_ctrt: String,
+    /// NAV전일종가
+    #[serde(default, rename = "prdy_clpr_nav")]
+    pub prdy_clpr_nav: String,
+    /// NAV시가
+    #[serde(default, rename = "oprc_nav")]
+    pub oprc_nav: String,
+    /// NAV고가
+    #[serde(default, rename = "hprc_nav")]
+    pub hprc_nav: String,
+    /// NAV저가
+    #[serde(default, rename = "lprc_nav")]
+    pub lprc_nav: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [NAV 비교추이(종목)[v1_국내주식-069]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EtfetnV1QuotationsNavComparisonTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<EtfetnV1QuotationsNavComparisonTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<EtfetnV1QuotationsNavComparisonTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for EtfetnV1QuotationsNavComparisonTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [NAV 비교추이(일)[v1_국내주식-071]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonDailyTrendOutputItem {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 종가
+    #[serde(default, rename = "stck_clpr")]
+    pub stck_clpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// NAV 대비 현재가
+    #[serde(default, rename = "nav_vrss_prpr")]
+    pub nav_vrss_prpr: String,
+    /// NAV
+    #[serde(default, rename = "nav")]
+    pub nav: String,
+    /// NAV 전일 대비 부호
+    #[serde(default, rename = "nav_prdy_vrss_sign")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일 대비
+    #[serde(default, rename = "nav_prdy_vrss")]
+    pub nav_prdy_vrss: String,
+    /// NAV 전일 대비율
+    #[serde(default, rename = "nav_prdy_ctrt")]
+    pub nav_prdy_ctrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [NAV 비교추이(일)[v1_국내주식-071]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EtfetnV1QuotationsNavComparisonDailyTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<EtfetnV1QuotationsNavComparisonDailyTrendOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for EtfetnV1QuotationsNavComparisonDailyTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [NAV 비교추이(분)[v1_국내주식-070]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct EtfetnV1QuotationsNavComparisonTimeTrendOutputItem {
+    /// 영업 시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// NAV
+    #[serde(default, rename = "nav")]
+    pub nav: String,
+    /// NAV 전일 대비 부호
+    #[serde(default, rename = "nav_prdy_vrss_sign")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일 대비
+    #[serde(default, rename = "nav_prdy_vrss")]
+    pub nav_prdy_vrss: String,
+    /// NAV 전일 대비율
+    #[serde(default, rename = "nav_prdy_ctrt")]
+    pub nav_prdy_ctrt: String,
+    /// NAV 대비 현재가
+    #[serde(default, rename = "nav_vrss_prpr")]
+    pub nav_vrss_prpr: String,
+    /// 괴리율
+    #[serde(default, rename = "dprt")]
+    pub dprt: String,
+    /// 주식 현재가
+    #[serde(default, rename = "stck_prpr")]
+    pub stck_prpr: String,
+    /// 전일 대비
+    #[serde(default, rename = "prdy_vrss")]
+    pub prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [NAV 비교추이(분)[v1_국내주식-070]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EtfetnV1QuotationsNavComparisonTimeTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<EtfetnV1QuotationsNavComparisonTimeTrendOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for EtfetnV1QuotationsNavComparisonTimeTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
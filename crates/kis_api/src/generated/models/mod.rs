@@ -0,0 +1,36 @@
+// This file is generated from kis-openapi.yaml. Do not edit manually.
+
+mod common;
+pub use common::*;
+#[cfg(feature = "domestic-stock")]
+mod domestic_stock;
+#[cfg(feature = "domestic-stock")]
+pub use domestic_stock::*;
+#[cfg(feature = "domestic-futureoption")]
+mod domestic_futureoption;
+#[cfg(feature = "domestic-futureoption")]
+pub use domestic_futureoption::*;
+#[cfg(feature = "overseas-stock")]
+mod overseas_stock;
+#[cfg(feature = "overseas-stock")]
+pub use overseas_stock::*;
+#[cfg(feature = "overseas-futureoption")]
+mod overseas_futureoption;
+#[cfg(feature = "overseas-futureoption")]
+pub use overseas_futureoption::*;
+#[cfg(feature = "bond")]
+mod bond;
+#[cfg(feature = "bond")]
+pub use bond::*;
+#[cfg(feature = "elw")]
+mod elw;
+#[cfg(feature = "elw")]
+pub use elw::*;
+#[cfg(feature = "etfetn")]
+mod etfetn;
+#[cfg(feature = "etfetn")]
+pub use etfetn::*;
+#[cfg(feature = "websocket")]
+mod websocket;
+#[cfg(feature = "websocket")]
+pub use websocket::*;
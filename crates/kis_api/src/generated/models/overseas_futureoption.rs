@@ -0,0 +1,4061 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [해외선물옵션 주문 [v1_해외선물-001]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외선물FX상품번호 (String, 필수)
+    #[serde(rename = "OVRS_FUTR_FX_PDNO")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// FM청산미결제체결일자 (String, 선택)
+    #[serde(rename = "FM_LQD_USTL_CCLD_DT")]
+    pub fm_lqd_ustl_ccld_dt: String,
+    /// FM청산미결제체결번호 (String, 선택)
+    #[serde(rename = "FM_LQD_USTL_CCNO")]
+    pub fm_lqd_ustl_ccno: String,
+    /// 가격구분코드 (String, 필수)
+    #[serde(rename = "PRIC_DVSN_CD")]
+    pub pric_dvsn_cd: String,
+    /// FMLIMIT주문가격 (String, 필수)
+    #[serde(rename = "FM_LIMIT_ORD_PRIC")]
+    pub fm_limit_ord_pric: String,
+    /// FMSTOP주문가격 (String, 필수)
+    #[serde(rename = "FM_STOP_ORD_PRIC")]
+    pub fm_stop_ord_pric: String,
+    /// FM주문수량 (String, 필수)
+    #[serde(rename = "FM_ORD_QTY")]
+    pub fm_ord_qty: String,
+    /// FM청산LIMIT주문가격 (String, 선택)
+    #[serde(rename = "FM_LQD_LMT_ORD_PRIC")]
+    pub fm_lqd_lmt_ord_pric: String,
+    /// FM청산STOP주문가격 (String, 선택)
+    #[serde(rename = "FM_LQD_STOP_ORD_PRIC")]
+    pub fm_lqd_stop_ord_pric: String,
+    /// 체결조건코드 (String, 필수)
+    #[serde(rename = "CCLD_CNDT_CD")]
+    pub ccld_cndt_cd: String,
+    /// 복합주문구분코드 (String, 필수)
+    #[serde(rename = "CPLX_ORD_DVSN_CD")]
+    pub cplx_ord_dvsn_cd: String,
+    /// 행사예약주문여부 (String, 필수)
+    #[serde(rename = "ECIS_RSVN_ORD_YN")]
+    pub ecis_rsvn_ord_yn: String,
+    /// FM_HEDGE주문화면여부 (String, 필수)
+    #[serde(rename = "FM_HDGE_ORD_SCRN_YN")]
+    pub fm_hdge_ord_scrn_yn: String,
+}
+
+/// [해외선물옵션 정정취소주문 [v1_해외선물-002, 003]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingOrderRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 원주문일자 (String, 필수)
+    #[serde(rename = "ORGN_ORD_DT")]
+    pub orgn_ord_dt: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// FMLIMIT주문가격 (String, 선택)
+    #[serde(rename = "FM_LIMIT_ORD_PRIC")]
+    pub fm_limit_ord_pric: String,
+    /// FMSTOP주문가격 (String, 선택)
+    #[serde(rename = "FM_STOP_ORD_PRIC")]
+    pub fm_stop_ord_pric: String,
+    /// FM청산LIMIT주문가격 (String, 선택)
+    #[serde(rename = "FM_LQD_LMT_ORD_PRIC")]
+    pub fm_lqd_lmt_ord_pric: String,
+    /// FM청산STOP주문가격 (String, 선택)
+    #[serde(rename = "FM_LQD_STOP_ORD_PRIC")]
+    pub fm_lqd_stop_ord_pric: String,
+    /// FM_HEDGE주문화면여부 (String, 필수)
+    #[serde(rename = "FM_HDGE_ORD_SCRN_YN")]
+    pub fm_hdge_ord_scrn_yn: String,
+    /// FM시장가전환여부 (String, 선택)
+    #[serde(rename = "FM_MKPR_CVSN_YN")]
+    pub fm_mkpr_cvsn_yn: String,
+}
+
+/// [해외선물옵션 당일주문내역조회 [v1_해외선물-004]] 요청 구조체
+/// 해외선물옵션 당일주문내역조회 [v1_해외선물-004]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireCcldRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 선물옵션구분 (String, 필수)
+    #[serde(rename = "FUOP_DVSN")]
+    pub fuop_dvsn: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외선물옵션 미결제내역조회(잔고) [v1_해외선물-005]] 요청 구조체
+/// 해외선물옵션 미결제내역조회(잔고) [v1_해외선물-005]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireUnpdRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 선물옵션구분 (String, 필수)
+    #[serde(rename = "FUOP_DVSN")]
+    pub fuop_dvsn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [해외선물옵션 주문가능조회 [v1_해외선물-006]] 요청 구조체
+/// 해외선물옵션 주문가능조회 [v1_해외선물-006]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePsamountRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외선물FX상품번호 (String, 필수)
+    #[serde(rename = "OVRS_FUTR_FX_PDNO")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// FM주문가격 (String, 필수)
+    #[serde(rename = "FM_ORD_PRIC")]
+    pub fm_ord_pric: String,
+    /// 행사예약주문여부 (String, 필수)
+    #[serde(rename = "ECIS_RSVN_ORD_YN")]
+    pub ecis_rsvn_ord_yn: String,
+}
+
+/// [해외선물옵션 기간계좌손익 일별[해외선물-010]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodCcldRequest {
+    /// 조회기간FROM일자 (String, 필수)
+    #[serde(rename = "INQR_TERM_FROM_DT")]
+    pub inqr_term_from_dt: String,
+    /// 조회기간TO일자 (String, 필수)
+    #[serde(rename = "INQR_TERM_TO_DT")]
+    pub inqr_term_to_dt: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 전체환산여부 (String, 필수)
+    #[serde(rename = "WHOL_TRSL_YN")]
+    pub whol_trsl_yn: String,
+    /// 선물옵션구분 (String, 필수)
+    #[serde(rename = "FUOP_DVSN")]
+    pub fuop_dvsn: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외선물옵션 일별 체결내역[해외선물-011]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDailyCcldRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시작일자 (String, 필수)
+    #[serde(rename = "STRT_DT")]
+    pub strt_dt: String,
+    /// 종료일자 (String, 필수)
+    #[serde(rename = "END_DT")]
+    pub end_dt: String,
+    /// 선물옵션구분코드 (String, 필수)
+    #[serde(rename = "FUOP_DVSN_CD")]
+    pub fuop_dvsn_cd: String,
+    /// FM상품군코드 (String, 필수)
+    #[serde(rename = "FM_PDGR_CD")]
+    pub fm_pdgr_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// FM종목합산여부 (String, 필수)
+    #[serde(rename = "FM_ITEM_FTNG_YN")]
+    pub fm_item_ftng_yn: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외선물옵션 예수금현황[해외선물-012]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDepositRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 조회일자 (String, 필수)
+    #[serde(rename = "INQR_DT")]
+    pub inqr_dt: String,
+}
+
+/// [해외선물옵션 일별 주문내역[해외선물-013]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDailyOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 시작일자 (String, 필수)
+    #[serde(rename = "STRT_DT")]
+    pub strt_dt: String,
+    /// 종료일자 (String, 필수)
+    #[serde(rename = "END_DT")]
+    pub end_dt: String,
+    /// FM상품군코드 (String, 필수)
+    #[serde(rename = "FM_PDGR_CD")]
+    pub fm_pdgr_cd: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 선물옵션구분 (String, 필수)
+    #[serde(rename = "FUOP_DVSN")]
+    pub fuop_dvsn: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외선물옵션 기간계좌거래내역[해외선물-014]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodTransRequest {
+    /// 조회기간FROM일자 (String, 필수)
+    #[serde(rename = "INQR_TERM_FROM_DT")]
+    pub inqr_term_from_dt: String,
+    /// 조회기간TO일자 (String, 필수)
+    #[serde(rename = "INQR_TERM_TO_DT")]
+    pub inqr_term_to_dt: String,
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 계좌거래유형코드 (String, 필수)
+    #[serde(rename = "ACNT_TR_TYPE_CD")]
+    pub acnt_tr_type_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+    /// 비밀번호체크여부 (String, 필수)
+    #[serde(rename = "PWD_CHK_YN")]
+    pub pwd_chk_yn: String,
+}
+
+/// [해외선물옵션 증거금상세 [해외선물-032]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingMarginDetailRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 조회일자 (String, 필수)
+    #[serde(rename = "INQR_DT")]
+    pub inqr_dt: String,
+}
+
+/// [해외선물종목현재가 [v1_해외선물-009]] 요청 구조체
+/// 해외선물종목현재가 [v1_해외선물-009]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquirePriceRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외선물종목상세 [v1_해외선물-008]] 요청 구조체
+/// 해외선물종목상세 [v1_해외선물-008]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsStockDetailRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외선물 호가 [해외선물-031]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireAskingPriceRequest {
+    /// 종목명 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외선물 분봉조회[해외선물-016]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외선물 체결추이(틱)[해외선물-019]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsTickCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외선물 체결추이(주간)[해외선물-017]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsWeeklyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외선물 체결추이(일간)[해외선물-018]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsDailyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외선물 체결추이(월간)[해외선물-020]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsMonthlyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외선물 상품기본정보 [해외선물-023]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsSearchContractDetailRequest {
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 품목종류 (String, 필수)
+    #[serde(rename = "SRS_CD_01")]
+    pub srs_cd_01: String,
+    /// 품목종류… (String, 필수)
+    #[serde(rename = "SRS_CD_02…")]
+    pub srs_cd_02: String,
+    /// 품목종류 (String, 필수)
+    #[serde(rename = "SRS_CD_32")]
+    pub srs_cd_32: String,
+}
+
+/// [해외선물 미결제추이 [해외선물-029]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInvestorUnpdTrendRequest {
+    /// 상품 (String, 필수)
+    #[serde(rename = "PROD_ISCD")]
+    pub prod_iscd: String,
+    /// 일자 (String, 필수)
+    #[serde(rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 구분 (String, 필수)
+    #[serde(rename = "UPMU_GUBUN")]
+    pub upmu_gubun: String,
+    /// CTS_KEY (String, 필수)
+    #[serde(rename = "CTS_KEY")]
+    pub cts_key: String,
+}
+
+/// [해외옵션종목현재가 [해외선물-035]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptPriceRequest {
+    /// 종목명 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외옵션종목상세 [해외선물-034]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptDetailRequest {
+    /// 종목명 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외옵션 호가 [해외선물-033]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptAskingPriceRequest {
+    /// 종목명 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+}
+
+/// [해외옵션 분봉조회 [해외선물-040]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외옵션 체결추이(틱) [해외선물-038]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptTickCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외옵션 체결추이(일간) [해외선물-037]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptDailyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외옵션 체결추이(주간) [해외선물-036]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptWeeklyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외옵션 체결추이(월간) [해외선물-039]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptMonthlyCcnlRequest {
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SRS_CD")]
+    pub srs_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCH_CD")]
+    pub exch_cd: String,
+    /// 조회시작일시 (String, 필수)
+    #[serde(rename = "START_DATE_TIME")]
+    pub start_date_time: String,
+    /// 조회종료일시 (String, 필수)
+    #[serde(rename = "CLOSE_DATE_TIME")]
+    pub close_date_time: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "QRY_TP")]
+    pub qry_tp: String,
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 묶음개수 (String, 필수)
+    #[serde(rename = "QRY_GAP")]
+    pub qry_gap: String,
+    /// 이전조회KEY (String, 필수)
+    #[serde(rename = "INDEX_KEY")]
+    pub index_key: String,
+}
+
+/// [해외옵션 상품기본정보 [해외선물-041]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsSearchOptDetailRequest {
+    /// 요청개수 (String, 필수)
+    #[serde(rename = "QRY_CNT")]
+    pub qry_cnt: String,
+    /// 종목코드1 (String, 필수)
+    #[serde(rename = "SRS_CD_01")]
+    pub srs_cd_01: String,
+    /// 종목코드2 (String, 필수)
+    #[serde(rename = "SRS_CD_02...")]
+    pub srs_cd_02: String,
+    /// 종목코드30 (String, 필수)
+    #[serde(rename = "SRS_CD_30")]
+    pub srs_cd_30: String,
+}
+
+/// [해외선물옵션 장운영시간 [해외선물-030]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsMarketTimeRequest {
+    /// FM상품군코드 (String, 필수)
+    #[serde(rename = "FM_PDGR_CD")]
+    pub fm_pdgr_cd: String,
+    /// FM클래스코드 (String, 필수)
+    #[serde(rename = "FM_CLAS_CD")]
+    pub fm_clas_cd: String,
+    /// FM거래소코드 (String, 필수)
+    #[serde(rename = "FM_EXCG_CD")]
+    pub fm_excg_cd: String,
+    /// 옵션여부 (String, 필수)
+    #[serde(rename = "OPT_YN")]
+    pub opt_yn: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+}
+
+/// [해외선물옵션 주문 [v1_해외선물-001]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingOrderOutputItem {
+    /// 주문일자
+    #[serde(default, rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 주문 [v1_해외선물-001]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 정정취소주문 [v1_해외선물-002, 003]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingOrderRvsecnclOutputItem {
+    /// 주문일자
+    #[serde(default, rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 정정취소주문 [v1_해외선물-002, 003]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 당일주문내역조회 [v1_해외선물-004]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireCcldOutputItem {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문일자
+    #[serde(default, rename = "orgn_ord_dt")]
+    pub orgn_ord_dt: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 접수구분코드
+    #[serde(default, rename = "rcit_dvsn_cd")]
+    pub rcit_dvsn_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매매전략구분코드
+    #[serde(default, rename = "trad_stgy_dvsn_cd")]
+    pub trad_stgy_dvsn_cd: String,
+    /// 기준가격유형코드
+    #[serde(default, rename = "bass_pric_type_cd")]
+    pub bass_pric_type_cd: String,
+    /// 주문상태코드
+    #[serde(default, rename = "ord_stat_cd")]
+    pub ord_stat_cd: String,
+    /// FM주문수량
+    #[serde(default, rename = "fm_ord_qty")]
+    pub fm_ord_qty: Decimal,
+    /// FM주문가격
+    #[serde(default, rename = "fm_ord_pric")]
+    pub fm_ord_pric: Decimal,
+    /// FMSTOP주문가격
+    #[serde(default, rename = "fm_stop_ord_pric")]
+    pub fm_stop_ord_pric: Decimal,
+    /// 예약구분
+    #[serde(default, rename = "rsvn_dvsn")]
+    pub rsvn_dvsn: String,
+    /// FM체결수량
+    #[serde(default, rename = "fm_ccld_qty")]
+    pub fm_ccld_qty: Decimal,
+    /// FM체결가격
+    #[serde(default, rename = "fm_ccld_pric")]
+    pub fm_ccld_pric: Decimal,
+    /// FM주문잔여수량
+    #[serde(default, rename = "fm_ord_rmn_qty")]
+    pub fm_ord_rmn_qty: Decimal,
+    /// 주문그룹명
+    #[serde(default, rename = "ord_grp_name")]
+    pub ord_grp_name: String,
+    /// 등록상세일시
+    #[serde(default, rename = "erlm_dtl_dtime")]
+    pub erlm_dtl_dtime: String,
+    /// 체결상세일시
+    #[serde(default, rename = "ccld_dtl_dtime")]
+    pub ccld_dtl_dtime: String,
+    /// 주문직원번호
+    #[serde(default, rename = "ord_stfno")]
+    pub ord_stfno: String,
+    /// 비고1
+    #[serde(default, rename = "rmks1")]
+    pub rmks1: String,
+    /// 신규청산구분코드
+    #[serde(default, rename = "new_lqd_dvsn_cd")]
+    pub new_lqd_dvsn_cd: String,
+    /// FM청산LIMIT주문가격
+    #[serde(default, rename = "fm_lqd_lmt_ord_pric")]
+    pub fm_lqd_lmt_ord_pric: Decimal,
+    /// FM청산STOP가격
+    #[serde(default, rename = "fm_lqd_stop_pric")]
+    pub fm_lqd_stop_pric: Decimal,
+    /// 체결조건코드
+    #[serde(default, rename = "ccld_cndt_cd")]
+    pub ccld_cndt_cd: String,
+    /// 게시유효일자
+    #[serde(default, rename = "noti_vald_dt")]
+    pub noti_vald_dt: String,
+    /// 계좌유형코드
+    #[serde(default, rename = "acnt_type_cd")]
+    pub acnt_type_cd: String,
+    /// 선물옵션구분
+    #[serde(default, rename = "fuop_dvsn")]
+    pub fuop_dvsn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 당일주문내역조회 [v1_해외선물-004]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquireCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquireCcldOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquireCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 미결제내역조회(잔고) [v1_해외선물-005]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireUnpdOutputItem {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// FM미결제수량
+    #[serde(default, rename = "fm_ustl_qty")]
+    pub fm_ustl_qty: Decimal,
+    /// FM체결평균가격
+    #[serde(default, rename = "fm_ccld_avg_pric")]
+    pub fm_ccld_avg_pric: Decimal,
+    /// FM현재가격
+    #[serde(default, rename = "fm_now_pric")]
+    pub fm_now_pric: Decimal,
+    /// FM평가손익금액
+    #[serde(default, rename = "fm_evlu_pfls_amt")]
+    pub fm_evlu_pfls_amt: Decimal,
+    /// FM옵션평가금액
+    #[serde(default, rename = "fm_opt_evlu_amt")]
+    pub fm_opt_evlu_amt: Decimal,
+    /// FM옵션평가손익금액
+    #[serde(default, rename = "fm_otp_evlu_pfls_amt")]
+    pub fm_otp_evlu_pfls_amt: Decimal,
+    /// 선물옵션구분
+    #[serde(default, rename = "fuop_dvsn")]
+    pub fuop_dvsn: String,
+    /// 행사예약주문여부
+    #[serde(default, rename = "ecis_rsvn_ord_yn")]
+    pub ecis_rsvn_ord_yn: String,
+    /// FM청산가능수량
+    #[serde(default, rename = "fm_lqd_psbl_qty")]
+    pub fm_lqd_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 미결제내역조회(잔고) [v1_해외선물-005]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquireUnpdResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquireUnpdOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquireUnpdResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 주문가능조회 [v1_해외선물-006]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePsamountOutputItem {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// FM미결제수량
+    #[serde(default, rename = "fm_ustl_qty")]
+    pub fm_ustl_qty: Decimal,
+    /// FM청산가능수량
+    #[serde(default, rename = "fm_lqd_psbl_qty")]
+    pub fm_lqd_psbl_qty: Decimal,
+    /// FM신규주문가능수량
+    #[serde(default, rename = "fm_new_ord_psbl_qty")]
+    pub fm_new_ord_psbl_qty: Decimal,
+    /// FM총주문가능수량
+    #[serde(default, rename = "fm_tot_ord_psbl_qty")]
+    pub fm_tot_ord_psbl_qty: Decimal,
+    /// FM시장가총주문가능수량
+    #[serde(default, rename = "fm_mkpr_tot_ord_psbl_qty")]
+    pub fm_mkpr_tot_ord_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 주문가능조회 [v1_해외선물-006]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquirePsamountResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquirePsamountOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquirePsamountResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 기간계좌손익 일별[해외선물-010]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodCcldOutput1Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// FM매수수량
+    #[serde(default, rename = "fm_buy_qty")]
+    pub fm_buy_qty: Decimal,
+    /// FM매도수량
+    #[serde(default, rename = "fm_sll_qty")]
+    pub fm_sll_qty: Decimal,
+    /// FM청산손익금액
+    #[serde(default, rename = "fm_lqd_pfls_amt")]
+    pub fm_lqd_pfls_amt: Decimal,
+    /// FM수수료
+    #[serde(default, rename = "fm_fee")]
+    pub fm_fee: String,
+    /// FM순손익금액
+    #[serde(default, rename = "fm_net_pfls_amt")]
+    pub fm_net_pfls_amt: Decimal,
+    /// FM미결제매수수량
+    #[serde(default, rename = "fm_ustl_buy_qty")]
+    pub fm_ustl_buy_qty: Decimal,
+    /// FM미결제매도수량
+    #[serde(default, rename = "fm_ustl_sll_qty")]
+    pub fm_ustl_sll_qty: Decimal,
+    /// FM미결제평가손익금액
+    #[serde(default, rename = "fm_ustl_evlu_pfls_amt")]
+    pub fm_ustl_evlu_pfls_amt: Decimal,
+    /// FM미결제평가손익금액2
+    #[serde(default, rename = "fm_ustl_evlu_pfls_amt2")]
+    pub fm_ustl_evlu_pfls_amt2: Decimal,
+    /// FM미결제평가손익증감금액
+    #[serde(default, rename = "fm_ustl_evlu_pfls_icdc_amt")]
+    pub fm_ustl_evlu_pfls_icdc_amt: Decimal,
+    /// FM미결제약정금액
+    #[serde(default, rename = "fm_ustl_agrm_amt")]
+    pub fm_ustl_agrm_amt: Decimal,
+    /// FM옵션청산금액
+    #[serde(default, rename = "fm_opt_lqd_amt")]
+    pub fm_opt_lqd_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 기간계좌손익 일별[해외선물-010]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodCcldOutput2Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// FM매수수량
+    #[serde(default, rename = "fm_buy_qty")]
+    pub fm_buy_qty: Decimal,
+    /// FM매도수량
+    #[serde(default, rename = "fm_sll_qty")]
+    pub fm_sll_qty: Decimal,
+    /// FM청산손익금액
+    #[serde(default, rename = "fm_lqd_pfls_amt")]
+    pub fm_lqd_pfls_amt: Decimal,
+    /// FM수수료
+    #[serde(default, rename = "fm_fee")]
+    pub fm_fee: String,
+    /// FM순손익금액
+    #[serde(default, rename = "fm_net_pfls_amt")]
+    pub fm_net_pfls_amt: Decimal,
+    /// FM미결제매수수량
+    #[serde(default, rename = "fm_ustl_buy_qty")]
+    pub fm_ustl_buy_qty: Decimal,
+    /// FM미결제매도수량
+    #[serde(default, rename = "fm_ustl_sll_qty")]
+    pub fm_ustl_sll_qty: Decimal,
+    /// FM미결제평가손익금액
+    #[serde(default, rename = "fm_ustl_evlu_pfls_amt")]
+    pub fm_ustl_evlu_pfls_amt: Decimal,
+    /// FM미결제평가손익금액2
+    #[serde(default, rename = "fm_ustl_evlu_pfls_amt2")]
+    pub fm_ustl_evlu_pfls_amt2: Decimal,
+    /// FM미결제평가손익증감금액
+    #[serde(default, rename = "fm_ustl_evlu_pfls_icdc_amt")]
+    pub fm_ustl_evlu_pfls_icdc_amt: Decimal,
+    /// FM체결평균가격
+    #[serde(default, rename = "fm_ccld_avg_pric")]
+    pub fm_ccld_avg_pric: Decimal,
+    /// FM미결제약정금액
+    #[serde(default, rename = "fm_ustl_agrm_amt")]
+    pub fm_ustl_agrm_amt: Decimal,
+    /// FM옵션청산금액
+    #[serde(default, rename = "fm_opt_lqd_amt")]
+    pub fm_opt_lqd_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 기간계좌손익 일별[해외선물-010]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1TradingInquirePeriodCcldOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1TradingInquirePeriodCcldOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquirePeriodCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 일별 체결내역[해외선물-011]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDailyCcldOutput2Item {
+    /// FM총체결수량
+    #[serde(default, rename = "fm_tot_ccld_qty")]
+    pub fm_tot_ccld_qty: Decimal,
+    /// FM총선물약정금액
+    #[serde(default, rename = "fm_tot_futr_agrm_amt")]
+    pub fm_tot_futr_agrm_amt: Decimal,
+    /// FM총옵션약정금액
+    #[serde(default, rename = "fm_tot_opt_agrm_amt")]
+    pub fm_tot_opt_agrm_amt: Decimal,
+    /// FM수수료합계
+    #[serde(default, rename = "fm_fee_smtl")]
+    pub fm_fee_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 일별 체결내역[해외선물-011]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDailyCcldOutput1Item {
+    /// 일자
+    #[serde(default, rename = "dt")]
+    pub dt: String,
+    /// 체결번호
+    #[serde(default, rename = "ccno")]
+    pub ccno: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// FM체결수량
+    #[serde(default, rename = "fm_ccld_qty")]
+    pub fm_ccld_qty: Decimal,
+    /// FM체결금액
+    #[serde(default, rename = "fm_ccld_amt")]
+    pub fm_ccld_amt: Decimal,
+    /// FM선물체결금액
+    #[serde(default, rename = "fm_futr_ccld_amt")]
+    pub fm_futr_ccld_amt: Decimal,
+    /// FM옵션체결금액
+    #[serde(default, rename = "fm_opt_ccld_amt")]
+    pub fm_opt_ccld_amt: Decimal,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// FM수수료
+    #[serde(default, rename = "fm_fee")]
+    pub fm_fee: String,
+    /// FM선물순약정금액
+    #[serde(default, rename = "fm_futr_pure_agrm_amt")]
+    pub fm_futr_pure_agrm_amt: Decimal,
+    /// FM옵션순약정금액
+    #[serde(default, rename = "fm_opt_pure_agrm_amt")]
+    pub fm_opt_pure_agrm_amt: Decimal,
+    /// 체결상세일시
+    #[serde(default, rename = "ccld_dtl_dtime")]
+    pub ccld_dtl_dtime: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 주문매체구분명
+    #[serde(default, rename = "ord_mdia_dvsn_name")]
+    pub ord_mdia_dvsn_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 일별 체결내역[해외선물-011]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquireDailyCcldResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1TradingInquireDailyCcldOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1TradingInquireDailyCcldOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquireDailyCcldResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 예수금현황[해외선물-012]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDepositOutputItem {
+    /// FM익일예수금액
+    #[serde(default, rename = "fm_nxdy_dncl_amt")]
+    pub fm_nxdy_dncl_amt: Decimal,
+    /// FM총자산평가금액
+    #[serde(default, rename = "fm_tot_asst_evlu_amt")]
+    pub fm_tot_asst_evlu_amt: Decimal,
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 응답일자
+    #[serde(default, rename = "resp_dt")]
+    pub resp_dt: String,
+    /// FM예수금잔액
+    #[serde(default, rename = "fm_dnca_rmnd")]
+    pub fm_dnca_rmnd: String,
+    /// FM청산손익금액
+    #[serde(default, rename = "fm_lqd_pfls_amt")]
+    pub fm_lqd_pfls_amt: Decimal,
+    /// FM수수료
+    #[serde(default, rename = "fm_fee")]
+    pub fm_fee: String,
+    /// FM선물옵션평가손익금액
+    #[serde(default, rename = "fm_fuop_evlu_pfls_amt")]
+    pub fm_fuop_evlu_pfls_amt: Decimal,
+    /// FM미수금액
+    #[serde(default, rename = "fm_rcvb_amt")]
+    pub fm_rcvb_amt: Decimal,
+    /// FM위탁증거금액
+    #[serde(default, rename = "fm_brkg_mgn_amt")]
+    pub fm_brkg_mgn_amt: Decimal,
+    /// FM유지증거금액
+    #[serde(default, rename = "fm_mntn_mgn_amt")]
+    pub fm_mntn_mgn_amt: Decimal,
+    /// FM추가증거금액
+    #[serde(default, rename = "fm_add_mgn_amt")]
+    pub fm_add_mgn_amt: Decimal,
+    /// FM위험율
+    #[serde(default, rename = "fm_risk_rt")]
+    pub fm_risk_rt: Decimal,
+    /// FM주문가능금액
+    #[serde(default, rename = "fm_ord_psbl_amt")]
+    pub fm_ord_psbl_amt: Decimal,
+    /// FM출금가능금액
+    #[serde(default, rename = "fm_drwg_psbl_amt")]
+    pub fm_drwg_psbl_amt: Decimal,
+    /// FM환전요청금액
+    #[serde(default, rename = "fm_echm_rqrm_amt")]
+    pub fm_echm_rqrm_amt: Decimal,
+    /// FM출금예정금액
+    #[serde(default, rename = "fm_drwg_prar_amt")]
+    pub fm_drwg_prar_amt: Decimal,
+    /// FM옵션거래대금
+    #[serde(default, rename = "fm_opt_tr_chgs")]
+    pub fm_opt_tr_chgs: String,
+    /// FM옵션포함자산평가금액
+    #[serde(default, rename = "fm_opt_icld_asst_evlu_amt")]
+    pub fm_opt_icld_asst_evlu_amt: Decimal,
+    /// FM옵션평가금액
+    #[serde(default, rename = "fm_opt_evlu_amt")]
+    pub fm_opt_evlu_amt: Decimal,
+    /// FM통화대용금액
+    #[serde(default, rename = "fm_crcy_sbst_amt")]
+    pub fm_crcy_sbst_amt: Decimal,
+    /// FM통화대용사용금액
+    #[serde(default, rename = "fm_crcy_sbst_use_amt")]
+    pub fm_crcy_sbst_use_amt: Decimal,
+    /// FM통화대용설정금액
+    #[serde(default, rename = "fm_crcy_sbst_stup_amt")]
+    pub fm_crcy_sbst_stup_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 예수금현황[해외선물-012]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquireDepositResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquireDepositOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquireDepositResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 일별 주문내역[해외선물-013]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquireDailyOrderOutputItem {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 일자
+    #[serde(default, rename = "dt")]
+    pub dt: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문일자
+    #[serde(default, rename = "orgn_ord_dt")]
+    pub orgn_ord_dt: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 해외선물FX상품번호
+    #[serde(default, rename = "ovrs_futr_fx_pdno")]
+    pub ovrs_futr_fx_pdno: String,
+    /// 정정취소구분코드
+    #[serde(default, rename = "rvse_cncl_dvsn_cd")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 복합주문구분코드
+    #[serde(default, rename = "cplx_ord_dvsn_cd")]
+    pub cplx_ord_dvsn_cd: String,
+    /// 가격구분코드
+    #[serde(default, rename = "pric_dvsn_cd")]
+    pub pric_dvsn_cd: String,
+    /// 접수구분코드
+    #[serde(default, rename = "rcit_dvsn_cd")]
+    pub rcit_dvsn_cd: String,
+    /// FM주문수량
+    #[serde(default, rename = "fm_ord_qty")]
+    pub fm_ord_qty: Decimal,
+    /// FM주문가격
+    #[serde(default, rename = "fm_ord_pric")]
+    pub fm_ord_pric: Decimal,
+    /// FMSTOP주문가격
+    #[serde(default, rename = "fm_stop_ord_pric")]
+    pub fm_stop_ord_pric: Decimal,
+    /// 행사예약주문여부
+    #[serde(default, rename = "ecis_rsvn_ord_yn")]
+    pub ecis_rsvn_ord_yn: String,
+    /// FM체결수량
+    #[serde(default, rename = "fm_ccld_qty")]
+    pub fm_ccld_qty: Decimal,
+    /// FM체결가격
+    #[serde(default, rename = "fm_ccld_pric")]
+    pub fm_ccld_pric: Decimal,
+    /// FM주문잔여수량
+    #[serde(default, rename = "fm_ord_rmn_qty")]
+    pub fm_ord_rmn_qty: Decimal,
+    /// 주문그룹명
+    #[serde(default, rename = "ord_grp_name")]
+    pub ord_grp_name: String,
+    /// 접수상세일시
+    #[serde(default, rename = "rcit_dtl_dtime")]
+    pub rcit_dtl_dtime: String,
+    /// 체결상세일시
+    #[serde(default, rename = "ccld_dtl_dtime")]
+    pub ccld_dtl_dtime: String,
+    /// 주문자사원번호
+    #[serde(default, rename = "ordr_emp_no")]
+    pub ordr_emp_no: String,
+    /// 거부사유명
+    #[serde(default, rename = "rjct_rson_name")]
+    pub rjct_rson_name: String,
+    /// 체결조건코드
+    #[serde(default, rename = "ccld_cndt_cd")]
+    pub ccld_cndt_cd: String,
+    /// 매매종료일자
+    #[serde(default, rename = "trad_end_dt")]
+    pub trad_end_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 일별 주문내역[해외선물-013]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquireDailyOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquireDailyOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquireDailyOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 기간계좌거래내역[해외선물-014]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodTransOutputItem {
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// FM원장출납순번
+    #[serde(default, rename = "fm_ldgr_inog_seq")]
+    pub fm_ldgr_inog_seq: String,
+    /// 계좌거래유형명
+    #[serde(default, rename = "acnt_tr_type_name")]
+    pub acnt_tr_type_name: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 거래항목명
+    #[serde(default, rename = "tr_itm_name")]
+    pub tr_itm_name: String,
+    /// FM입출금액
+    #[serde(default, rename = "fm_iofw_amt")]
+    pub fm_iofw_amt: Decimal,
+    /// FM수수료
+    #[serde(default, rename = "fm_fee")]
+    pub fm_fee: String,
+    /// FM세금금액
+    #[serde(default, rename = "fm_tax_amt")]
+    pub fm_tax_amt: Decimal,
+    /// FM결제금액
+    #[serde(default, rename = "fm_sttl_amt")]
+    pub fm_sttl_amt: Decimal,
+    /// FM이전예수금액
+    #[serde(default, rename = "fm_bf_dncl_amt")]
+    pub fm_bf_dncl_amt: Decimal,
+    /// FM예수금액
+    #[serde(default, rename = "fm_dncl_amt")]
+    pub fm_dncl_amt: Decimal,
+    /// FM미수발생금액
+    #[serde(default, rename = "fm_rcvb_occr_amt")]
+    pub fm_rcvb_occr_amt: Decimal,
+    /// FM미수변제금액
+    #[serde(default, rename = "fm_rcvb_pybk_amt")]
+    pub fm_rcvb_pybk_amt: Decimal,
+    /// 연체이자변제금액
+    #[serde(default, rename = "ovdu_int_pybk_amt")]
+    pub ovdu_int_pybk_amt: Decimal,
+    /// 비고내용
+    #[serde(default, rename = "rmks_text")]
+    pub rmks_text: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 기간계좌거래내역[해외선물-014]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingInquirePeriodTransResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingInquirePeriodTransOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingInquirePeriodTransResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 증거금상세 [해외선물-032]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1TradingMarginDetailOutputItem {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 응답일자
+    #[serde(default, rename = "resp_dt")]
+    pub resp_dt: String,
+    /// 계좌순위험증거금적용여부
+    #[serde(default, rename = "acnt_net_risk_mgna_aply_yn")]
+    pub acnt_net_risk_mgna_aply_yn: String,
+    /// FM주문가능금액
+    #[serde(default, rename = "fm_ord_psbl_amt")]
+    pub fm_ord_psbl_amt: Decimal,
+    /// FM추가증거금액
+    #[serde(default, rename = "fm_add_mgn_amt")]
+    pub fm_add_mgn_amt: Decimal,
+    /// FM위탁증거금액
+    #[serde(default, rename = "fm_brkg_mgn_amt")]
+    pub fm_brkg_mgn_amt: Decimal,
+    /// FM정산위탁증거금액
+    #[serde(default, rename = "fm_excc_brkg_mgn_amt")]
+    pub fm_excc_brkg_mgn_amt: Decimal,
+    /// FM미결제증거금액
+    #[serde(default, rename = "fm_ustl_mgn_amt")]
+    pub fm_ustl_mgn_amt: Decimal,
+    /// FM유지증거금액
+    #[serde(default, rename = "fm_mntn_mgn_amt")]
+    pub fm_mntn_mgn_amt: Decimal,
+    /// FM주문증거금액
+    #[serde(default, rename = "fm_ord_mgn_amt")]
+    pub fm_ord_mgn_amt: Decimal,
+    /// FM선물주문증거금액
+    #[serde(default, rename = "fm_futr_ord_mgn_amt")]
+    pub fm_futr_ord_mgn_amt: Decimal,
+    /// FM옵션매수주문금액
+    #[serde(default, rename = "fm_opt_buy_ord_amt")]
+    pub fm_opt_buy_ord_amt: Decimal,
+    /// FM옵션매도주문증거금액
+    #[serde(default, rename = "fm_opt_sll_ord_mgn_amt")]
+    pub fm_opt_sll_ord_mgn_amt: Decimal,
+    /// FM옵션매수주문증거금액
+    #[serde(default, rename = "fm_opt_buy_ord_mgn_amt")]
+    pub fm_opt_buy_ord_mgn_amt: Decimal,
+    /// FM행사예약증거금액
+    #[serde(default, rename = "fm_ecis_rsvn_mgn_amt")]
+    pub fm_ecis_rsvn_mgn_amt: Decimal,
+    /// FMSPAN위탁증거금액
+    #[serde(default, rename = "fm_span_brkg_mgn_amt")]
+    pub fm_span_brkg_mgn_amt: Decimal,
+    /// FMSPAN가격변동증거금액
+    #[serde(default, rename = "fm_span_pric_altr_mgn_amt")]
+    pub fm_span_pric_altr_mgn_amt: Decimal,
+    /// FMSPAN기간스프레드증거금액
+    #[serde(default, rename = "fm_span_term_sprd_mgn_amt")]
+    pub fm_span_term_sprd_mgn_amt: Decimal,
+    /// FMSPAN옵션가격증거금액
+    #[serde(default, rename = "fm_span_buy_opt_min_mgn_amt")]
+    pub fm_span_buy_opt_min_mgn_amt: Decimal,
+    /// FMSPAN옵션최소증거금액
+    #[serde(default, rename = "fm_span_opt_min_mgn_amt")]
+    pub fm_span_opt_min_mgn_amt: Decimal,
+    /// FMSPAN총위험증거금액
+    #[serde(default, rename = "fm_span_tot_risk_mgn_amt")]
+    pub fm_span_tot_risk_mgn_amt: Decimal,
+    /// FMSPAN유지증거금액
+    #[serde(default, rename = "fm_span_mntn_mgn_amt")]
+    pub fm_span_mntn_mgn_amt: Decimal,
+    /// FMSPAN유지가격변동증거금액
+    #[serde(default, rename = "fm_span_mntn_pric_altr_mgn_amt")]
+    pub fm_span_mntn_pric_altr_mgn_amt: Decimal,
+    /// FMSPAN유지기간스프레드증거금액
+    #[serde(default, rename = "fm_span_mntn_term_sprd_mgn_amt")]
+    pub fm_span_mntn_term_sprd_mgn_amt: Decimal,
+    /// FMSPAN유지옵션가격증거금액
+    #[serde(default, rename = "fm_span_mntn_opt_pric_mgn_amt")]
+    pub fm_span_mntn_opt_pric_mgn_amt: Decimal,
+    /// FMSPAN유지옵션최소증거금액
+    #[serde(default, rename = "fm_span_mntn_opt_min_mgn_amt")]
+    pub fm_span_mntn_opt_min_mgn_amt: Decimal,
+    /// FMSPAN유지총위험증거금액
+    #[serde(default, rename = "fm_span_mntn_tot_risk_mgn_amt")]
+    pub fm_span_mntn_tot_risk_mgn_amt: Decimal,
+    /// FMEUREX위탁증거금액
+    #[serde(default, rename = "fm_eurx_brkg_mgn_amt")]
+    pub fm_eurx_brkg_mgn_amt: Decimal,
+    /// FMEUREX가격변동증거금액
+    #[serde(default, rename = "fm_eurx_pric_altr_mgn_amt")]
+    pub fm_eurx_pric_altr_mgn_amt: Decimal,
+    /// FMEUREX기간스프레드증거금액
+    #[serde(default, rename = "fm_eurx_term_sprd_mgn_amt")]
+    pub fm_eurx_term_sprd_mgn_amt: Decimal,
+    /// FMEUREX옵션가격증거금액
+    #[serde(default, rename = "fm_eurx_opt_pric_mgn_amt")]
+    pub fm_eurx_opt_pric_mgn_amt: Decimal,
+    /// FMEUREX매수옵션최소증거금액
+    #[serde(default, rename = "fm_eurx_buy_opt_min_mgn_amt")]
+    pub fm_eurx_buy_opt_min_mgn_amt: Decimal,
+    /// FMEUREX총위험증거금액
+    #[serde(default, rename = "fm_eurx_tot_risk_mgn_amt")]
+    pub fm_eurx_tot_risk_mgn_amt: Decimal,
+    /// FMEUREX유지증거금액
+    #[serde(default, rename = "fm_eurx_mntn_mgn_amt")]
+    pub fm_eurx_mntn_mgn_amt: Decimal,
+    /// FMEUREX유지가격변동증거금액
+    #[serde(default, rename = "fm_eurx_mntn_pric_altr_mgn_amt")]
+    pub fm_eurx_mntn_pric_altr_mgn_amt: Decimal,
+    /// FMEUREX기간스프레드증거금액
+    #[serde(default, rename = "fm_eurx_mntn_term_sprd_mgn_amt")]
+    pub fm_eurx_mntn_term_sprd_mgn_amt: Decimal,
+    /// FMEUREX유지옵션가격증거금액
+    #[serde(default, rename = "fm_eurx_mntn_opt_pric_mgn_amt")]
+    pub fm_eurx_mntn_opt_pric_mgn_amt: Decimal,
+    /// FMEUREX유지총위험증거금액
+    #[serde(default, rename = "fm_eurx_mntn_tot_risk_mgn_amt")]
+    pub fm_eurx_mntn_tot_risk_mgn_amt: Decimal,
+    /// FM일반위탁증거금액
+    #[serde(default, rename = "fm_gnrl_brkg_mgn_amt")]
+    pub fm_gnrl_brkg_mgn_amt: Decimal,
+    /// FM선물미결제증거금액
+    #[serde(default, rename = "fm_futr_ustl_mgn_amt")]
+    pub fm_futr_ustl_mgn_amt: Decimal,
+    /// FM매도옵션미결제증거금액
+    #[serde(default, rename = "fm_sll_opt_ustl_mgn_amt")]
+    pub fm_sll_opt_ustl_mgn_amt: Decimal,
+    /// FM매수옵션미결제증거금액
+    #[serde(default, rename = "fm_buy_opt_ustl_mgn_amt")]
+    pub fm_buy_opt_ustl_mgn_amt: Decimal,
+    /// FM스프레드미결제증거금액
+    #[serde(default, rename = "fm_sprd_ustl_mgn_amt")]
+    pub fm_sprd_ustl_mgn_amt: Decimal,
+    /// FMAVG할인증거금액
+    #[serde(default, rename = "fm_avg_dsct_mgn_amt")]
+    pub fm_avg_dsct_mgn_amt: Decimal,
+    /// FM일반유지증거금액
+    #[serde(default, rename = "fm_gnrl_mntn_mgn_amt")]
+    pub fm_gnrl_mntn_mgn_amt: Decimal,
+    /// FM선물유지증거금액
+    #[serde(default, rename = "fm_futr_mntn_mgn_amt")]
+    pub fm_futr_mntn_mgn_amt: Decimal,
+    /// FM옵션유지증거금액
+    #[serde(default, rename = "fm_opt_mntn_mgn_amt")]
+    pub fm_opt_mntn_mgn_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 증거금상세 [해외선물-032]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1TradingMarginDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1TradingMarginDetailOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1TradingMarginDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물종목현재가 [v1_해외선물-009]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquirePriceOutput1Item {
+    /// 최종처리일자
+    #[serde(default, rename = "proc_date")]
+    pub proc_date: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 최종처리시각
+    #[serde(default, rename = "proc_time")]
+    pub proc_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 현재가
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 매수1수량
+    #[serde(default, rename = "bid_qntt")]
+    pub bid_qntt: String,
+    /// 매수1호가
+    #[serde(default, rename = "bid_price")]
+    pub bid_price: String,
+    /// 매도1수량
+    #[serde(default, rename = "ask_qntt")]
+    pub ask_qntt: String,
+    /// 매도1호가
+    #[serde(default, rename = "ask_price")]
+    pub ask_price: String,
+    /// 전일종가
+    #[serde(default, rename = "prev_price")]
+    pub prev_price: String,
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 체결량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 총매도잔량
+    #[serde(default, rename = "tot_ask_qntt")]
+    pub tot_ask_qntt: String,
+    /// 총매수잔량
+    #[serde(default, rename = "tot_bid_qntt")]
+    pub tot_bid_qntt: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_size")]
+    pub tick_size: String,
+    /// 장개시일자
+    #[serde(default, rename = "open_date")]
+    pub open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "open_time")]
+    pub open_time: String,
+    /// 장종료일자
+    #[serde(default, rename = "close_date")]
+    pub close_date: String,
+    /// 장종료시각
+    #[serde(default, rename = "close_time")]
+    pub close_time: String,
+    /// 영업일자
+    #[serde(default, rename = "sbsnsdate")]
+    pub sbsnsdate: String,
+    /// 정산가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물종목현재가 [v1_해외선물-009]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsInquirePriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsInquirePriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsInquirePriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물종목상세 [v1_해외선물-008]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsStockDetailOutput1Item {
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_sz")]
+    pub tick_sz: String,
+    /// 가격표시진법
+    #[serde(default, rename = "disp_digit")]
+    pub disp_digit: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 정산일
+    #[serde(default, rename = "sttl_date")]
+    pub sttl_date: String,
+    /// 전일종가
+    #[serde(default, rename = "prev_price")]
+    pub prev_price: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 품목종류
+    #[serde(default, rename = "clas_cd")]
+    pub clas_cd: String,
+    /// 틱가치
+    #[serde(default, rename = "tick_val")]
+    pub tick_val: Decimal,
+    /// 장개시일자
+    #[serde(default, rename = "mrkt_open_date")]
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "mrkt_open_time")]
+    pub mrkt_open_time: String,
+    /// 장마감일자
+    #[serde(default, rename = "mrkt_close_date")]
+    pub mrkt_close_date: String,
+    /// 장마감시각
+    #[serde(default, rename = "mrkt_close_time")]
+    pub mrkt_close_time: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 매매여부
+    #[serde(default, rename = "stat_tp")]
+    pub stat_tp: String,
+    /// 계약크기
+    #[serde(default, rename = "ctrt_size")]
+    pub ctrt_size: String,
+    /// 최종결제구분
+    #[serde(default, rename = "stl_tp")]
+    pub stl_tp: String,
+    /// 최초식별일
+    #[serde(default, rename = "frst_noti_date")]
+    pub frst_noti_date: String,
+    /// 스프레드 종목 #1
+    #[serde(default, rename = "sprd_srs_cd1")]
+    pub sprd_srs_cd1: String,
+    /// 스프레드 종목 #2
+    #[serde(default, rename = "sprd_srs_cd2")]
+    pub sprd_srs_cd2: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물종목상세 [v1_해외선물-008]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsStockDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsStockDetailOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsStockDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 호가 [해외선물-031]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireAskingPriceOutput1Item {
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "lowp_rice")]
+    pub lowp_rice: String,
+    /// 현재가
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 전일종가
+    #[serde(default, rename = "prev_price")]
+    pub prev_price: String,
+    /// 거래량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비가
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 호가수신일자
+    #[serde(default, rename = "quot_date")]
+    pub quot_date: String,
+    /// 호가수신시각
+    #[serde(default, rename = "quot_time")]
+    pub quot_time: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 호가 [해외선물-031]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireAskingPriceOutput2Item {
+    /// 매수수량
+    #[serde(default, rename = "bid_qntt")]
+    pub bid_qntt: String,
+    /// 매수번호
+    #[serde(default, rename = "bid_num")]
+    pub bid_num: String,
+    /// 매수호가
+    #[serde(default, rename = "bid_price")]
+    pub bid_price: String,
+    /// 매도수량
+    #[serde(default, rename = "ask_qntt")]
+    pub ask_qntt: String,
+    /// 매도번호
+    #[serde(default, rename = "ask_num")]
+    pub ask_num: String,
+    /// 매도호가
+    #[serde(default, rename = "ask_price")]
+    pub ask_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 호가 [해외선물-031]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsInquireAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsInquireAskingPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsInquireAskingPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsInquireAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 분봉조회[해외선물-016]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceOutput2Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 분봉조회[해외선물-016]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceOutput1Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시각
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 분봉조회[해외선물-016]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 체결추이(틱)[해외선물-019]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsTickCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "tret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub tret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(틱)[해외선물-019]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsTickCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시각
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(틱)[해외선물-019]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsTickCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsTickCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsTickCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsTickCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 체결추이(주간)[해외선물-017]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsWeeklyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(주간)[해외선물-017]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsWeeklyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시각
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(주간)[해외선물-017]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsWeeklyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsWeeklyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsWeeklyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsWeeklyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 체결추이(일간)[해외선물-018]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsDailyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "tret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub tret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(일간)[해외선물-018]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsDailyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시각
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(일간)[해외선물-018]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsDailyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsDailyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsDailyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsDailyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 체결추이(월간)[해외선물-020]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsMonthlyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "tret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub tret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(월간)[해외선물-020]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsMonthlyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시각
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 체결추이(월간)[해외선물-020]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsMonthlyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsMonthlyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsMonthlyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsMonthlyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 상품기본정보 [해외선물-023]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsSearchContractDetailOutput2Item {
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 품목종류
+    #[serde(default, rename = "clas_cd")]
+    pub clas_cd: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 정산가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 정산일
+    #[serde(default, rename = "sttl_date")]
+    pub sttl_date: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 가격표시진법
+    #[serde(default, rename = "disp_digit")]
+    pub disp_digit: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_sz")]
+    pub tick_sz: String,
+    /// 틱가치
+    #[serde(default, rename = "tick_val")]
+    pub tick_val: Decimal,
+    /// 장개시일자
+    #[serde(default, rename = "mrkt_open_date")]
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "mrkt_open_time")]
+    pub mrkt_open_time: String,
+    /// 장마감일자
+    #[serde(default, rename = "mrkt_close_date")]
+    pub mrkt_close_date: String,
+    /// 장마감시각
+    #[serde(default, rename = "mrkt_close_time")]
+    pub mrkt_close_time: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 매매여부
+    #[serde(default, rename = "stat_tp")]
+    pub stat_tp: String,
+    /// 계약크기
+    #[serde(default, rename = "ctrt_size")]
+    pub ctrt_size: String,
+    /// 최종결제구분
+    #[serde(default, rename = "stl_tp")]
+    pub stl_tp: String,
+    /// 최초식별일
+    #[serde(default, rename = "frst_noti_date")]
+    pub frst_noti_date: String,
+    /// 서브거래소코드
+    #[serde(default, rename = "sub_exch_nm")]
+    pub sub_exch_nm: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 상품기본정보 [해외선물-023]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsSearchContractDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsSearchContractDetailOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsSearchContractDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물 미결제추이 [해외선물-029]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInvestorUnpdTrendOutput1Item {
+    /// 응답레코드카운트
+    #[serde(
+        default,
+        rename = "row_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub row_cnt: i64,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 미결제추이 [해외선물-029]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInvestorUnpdTrendOutput2Item {
+    /// 상품
+    #[serde(default, rename = "prod_iscd")]
+    pub prod_iscd: String,
+    /// CFTC코드
+    #[serde(default, rename = "cftc_iscd")]
+    pub cftc_iscd: String,
+    /// 일자
+    #[serde(default, rename = "bsop_date")]
+    pub bsop_date: String,
+    /// 매수투기
+    #[serde(default, rename = "bidp_spec")]
+    pub bidp_spec: String,
+    /// 매도투기
+    #[serde(default, rename = "askp_spec")]
+    pub askp_spec: String,
+    /// 스프레드투기
+    #[serde(default, rename = "spread_spec")]
+    pub spread_spec: String,
+    /// 매수헤지
+    #[serde(default, rename = "bidp_hedge")]
+    pub bidp_hedge: String,
+    /// 매도헤지
+    #[serde(default, rename = "askp_hedge")]
+    pub askp_hedge: String,
+    /// 미결제합계
+    #[serde(default, rename = "hts_otst_smtn")]
+    pub hts_otst_smtn: String,
+    /// 매수누락
+    #[serde(default, rename = "bidp_missing")]
+    pub bidp_missing: String,
+    /// 매도누락
+    #[serde(default, rename = "askp_missing")]
+    pub askp_missing: String,
+    /// 매수투기고객
+    #[serde(default, rename = "bidp_spec_cust")]
+    pub bidp_spec_cust: String,
+    /// 매도투기고객
+    #[serde(default, rename = "askp_spec_cust")]
+    pub askp_spec_cust: String,
+    /// 스프레드투기고객
+    #[serde(default, rename = "spread_spec_cust")]
+    pub spread_spec_cust: String,
+    /// 매수헤지고객
+    #[serde(default, rename = "bidp_hedge_cust")]
+    pub bidp_hedge_cust: String,
+    /// 매도헤지고객
+    #[serde(default, rename = "askp_hedge_cust")]
+    pub askp_hedge_cust: String,
+    /// 고객합계
+    #[serde(default, rename = "cust_smtn")]
+    pub cust_smtn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물 미결제추이 [해외선물-029]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsInvestorUnpdTrendResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsInvestorUnpdTrendOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsInvestorUnpdTrendOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsInvestorUnpdTrendResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션종목현재가 [해외선물-035]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptPriceOutput1Item {
+    /// 최종처리일자
+    #[serde(default, rename = "proc_date")]
+    pub proc_date: String,
+    /// 최종처리시각
+    #[serde(default, rename = "proc_time")]
+    pub proc_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 현재가
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 매수1수량
+    #[serde(default, rename = "bid_qntt")]
+    pub bid_qntt: String,
+    /// 매수1호가
+    #[serde(default, rename = "bid_price")]
+    pub bid_price: String,
+    /// 매도1수량
+    #[serde(default, rename = "ask_qntt")]
+    pub ask_qntt: String,
+    /// 매도1호가
+    #[serde(default, rename = "ask_price")]
+    pub ask_price: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 체결량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 총매도잔량
+    #[serde(default, rename = "tot_ask_qntt")]
+    pub tot_ask_qntt: String,
+    /// 총매수잔량
+    #[serde(default, rename = "tot_bid_qntt")]
+    pub tot_bid_qntt: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_size")]
+    pub tick_size: String,
+    /// 장개시일자
+    #[serde(default, rename = "open_date")]
+    pub open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "open_time")]
+    pub open_time: String,
+    /// 장종료일자
+    #[serde(default, rename = "close_date")]
+    pub close_date: String,
+    /// 장종료시각
+    #[serde(default, rename = "close_time")]
+    pub close_time: String,
+    /// 영업일자
+    #[serde(default, rename = "sbsnsdate")]
+    pub sbsnsdate: String,
+    /// 정산가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션종목현재가 [해외선물-035]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptPriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션종목상세 [해외선물-034]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptDetailOutput1Item {
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 품목종류
+    #[serde(default, rename = "clas_cd")]
+    pub clas_cd: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 전일종가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 정산일
+    #[serde(default, rename = "sttl_date")]
+    pub sttl_date: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 가격표시진법
+    #[serde(default, rename = "disp_digit")]
+    pub disp_digit: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_sz")]
+    pub tick_sz: String,
+    /// 틱가치
+    #[serde(default, rename = "tick_val")]
+    pub tick_val: Decimal,
+    /// 장개시일자
+    #[serde(default, rename = "mrkt_open_date")]
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "mrkt_open_time")]
+    pub mrkt_open_time: String,
+    /// 장마감일자
+    #[serde(default, rename = "mrkt_close_date")]
+    pub mrkt_close_date: String,
+    /// 장마감시각
+    #[serde(default, rename = "mrkt_close_time")]
+    pub mrkt_close_time: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 매매여부
+    #[serde(default, rename = "stat_tp")]
+    pub stat_tp: String,
+    /// 계약크기
+    #[serde(default, rename = "ctrt_size")]
+    pub ctrt_size: String,
+    /// 최종결제구분
+    #[serde(default, rename = "stl_tp")]
+    pub stl_tp: String,
+    /// 최초식별일
+    #[serde(default, rename = "frst_noti_date")]
+    pub frst_noti_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션종목상세 [해외선물-034]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptDetailOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 호가 [해외선물-033]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptAskingPriceOutput1Item {
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "lowp_rice")]
+    pub lowp_rice: String,
+    /// 현재가
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 정산가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 거래량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비가
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 호가수신일자
+    #[serde(default, rename = "quot_date")]
+    pub quot_date: String,
+    /// 호가수신시각
+    #[serde(default, rename = "quot_time")]
+    pub quot_time: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 호가 [해외선물-033]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptAskingPriceOutput2Item {
+    /// 매수수량
+    #[serde(default, rename = "bid_qntt")]
+    pub bid_qntt: String,
+    /// 매수번호
+    #[serde(default, rename = "bid_num")]
+    pub bid_num: String,
+    /// 매수호가
+    #[serde(default, rename = "bid_price")]
+    pub bid_price: String,
+    /// 매도수량
+    #[serde(default, rename = "ask_qntt")]
+    pub ask_qntt: String,
+    /// 매도번호
+    #[serde(default, rename = "ask_num")]
+    pub ask_num: String,
+    /// 매도호가
+    #[serde(default, rename = "ask_price")]
+    pub ask_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 호가 [해외선물-033]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptAskingPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsOptAskingPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 분봉조회 [해외선물-040]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceOutput2Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 분봉조회 [해외선물-040]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceOutput1Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시간
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 분봉조회 [해외선물-040]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 체결추이(틱) [해외선물-038]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptTickCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(틱) [해외선물-038]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptTickCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시간
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(틱) [해외선물-038]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptTickCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptTickCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsOptTickCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptTickCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 체결추이(일간) [해외선물-037]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptDailyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(일간) [해외선물-037]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptDailyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시간
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(일간) [해외선물-037]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptDailyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptDailyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsOptDailyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptDailyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 체결추이(주간) [해외선물-036]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptWeeklyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(주간) [해외선물-036]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptWeeklyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시간
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(주간) [해외선물-036]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptWeeklyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptWeeklyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsOptWeeklyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptWeeklyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 체결추이(월간) [해외선물-039]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptMonthlyCcnlOutput1Item {
+    /// 자료개수
+    #[serde(
+        default,
+        rename = "ret_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub ret_cnt: i64,
+    /// N틱최종개수
+    #[serde(
+        default,
+        rename = "last_n_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub last_n_cnt: i64,
+    /// 이전조회KEY
+    #[serde(default, rename = "index_key")]
+    pub index_key: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(월간) [해외선물-039]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsOptMonthlyCcnlOutput2Item {
+    /// 일자
+    #[serde(default, rename = "data_date")]
+    pub data_date: String,
+    /// 시간
+    #[serde(default, rename = "data_time")]
+    pub data_time: String,
+    /// 시가
+    #[serde(default, rename = "open_price")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "high_price")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "low_price")]
+    pub low_price: String,
+    /// 체결가격
+    #[serde(default, rename = "last_price")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "last_qntt")]
+    pub last_qntt: String,
+    /// 누적거래수량
+    #[serde(default, rename = "vol")]
+    pub vol: String,
+    /// 전일대비구분
+    #[serde(default, rename = "prev_diff_flag")]
+    pub prev_diff_flag: String,
+    /// 전일대비가격
+    #[serde(default, rename = "prev_diff_price")]
+    pub prev_diff_price: String,
+    /// 전일대비율
+    #[serde(default, rename = "prev_diff_rate")]
+    pub prev_diff_rate: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 체결추이(월간) [해외선물-039]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsOptMonthlyCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasFutureoptionV1QuotationsOptMonthlyCcnlOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsOptMonthlyCcnlOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsOptMonthlyCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외옵션 상품기본정보 [해외선물-041]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsSearchOptDetailOutput2Item {
+    /// 거래소코드
+    #[serde(default, rename = "exch_cd")]
+    pub exch_cd: String,
+    /// 품목종류
+    #[serde(default, rename = "clas_cd")]
+    pub clas_cd: String,
+    /// 거래통화
+    #[serde(default, rename = "crc_cd")]
+    pub crc_cd: String,
+    /// 정산가
+    #[serde(default, rename = "sttl_price")]
+    pub sttl_price: String,
+    /// 정산일
+    #[serde(default, rename = "sttl_date")]
+    pub sttl_date: String,
+    /// 증거금
+    #[serde(default, rename = "trst_mgn")]
+    pub trst_mgn: String,
+    /// 가격표시진법
+    #[serde(default, rename = "disp_digit")]
+    pub disp_digit: String,
+    /// 틱사이즈
+    #[serde(default, rename = "tick_sz")]
+    pub tick_sz: String,
+    /// 틱가치
+    #[serde(default, rename = "tick_val")]
+    pub tick_val: Decimal,
+    /// 장개시일자
+    #[serde(default, rename = "mrkt_open_date")]
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "mrkt_open_time")]
+    pub mrkt_open_time: String,
+    /// 장마감일자
+    #[serde(default, rename = "mrkt_close_date")]
+    pub mrkt_close_date: String,
+    /// 장마감시각
+    #[serde(default, rename = "mrkt_close_time")]
+    pub mrkt_close_time: String,
+    /// 상장일
+    #[serde(default, rename = "trd_fr_date")]
+    pub trd_fr_date: String,
+    /// 만기일
+    #[serde(default, rename = "expr_date")]
+    pub expr_date: String,
+    /// 최종거래일
+    #[serde(default, rename = "trd_to_date")]
+    pub trd_to_date: String,
+    /// 잔존일수
+    #[serde(
+        default,
+        rename = "remn_cnt",
+        deserialize_with = "crate::models::i64_from_str"
+    )]
+    pub remn_cnt: i64,
+    /// 매매여부
+    #[serde(default, rename = "stat_tp")]
+    pub stat_tp: String,
+    /// 계약크기
+    #[serde(default, rename = "ctrt_size")]
+    pub ctrt_size: String,
+    /// 최종결제구분
+    #[serde(default, rename = "stl_tp")]
+    pub stl_tp: String,
+    /// 최초식별일
+    #[serde(default, rename = "frst_noti_date")]
+    pub frst_noti_date: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외옵션 상품기본정보 [해외선물-041]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsSearchOptDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasFutureoptionV1QuotationsSearchOptDetailOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsSearchOptDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외선물옵션 장운영시간 [해외선물-030]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasFutureoptionV1QuotationsMarketTimeOutputItem {
+    /// FM상품군코드
+    #[serde(default, rename = "fm_pdgr_cd")]
+    pub fm_pdgr_cd: String,
+    /// FM상품군명
+    #[serde(default, rename = "fm_pdgr_name")]
+    pub fm_pdgr_name: String,
+    /// FM거래소코드
+    #[serde(default, rename = "fm_excg_cd")]
+    pub fm_excg_cd: String,
+    /// FM거래소명
+    #[serde(default, rename = "fm_excg_name")]
+    pub fm_excg_name: String,
+    /// 선물옵션구분명
+    #[serde(default, rename = "fuop_dvsn_name")]
+    pub fuop_dvsn_name: String,
+    /// FM클래스코드
+    #[serde(default, rename = "fm_clas_cd")]
+    pub fm_clas_cd: String,
+    /// FM클래스명
+    #[serde(default, rename = "fm_clas_name")]
+    pub fm_clas_name: String,
+    /// 오전장운영시작시각
+    #[serde(default, rename = "am_mkmn_strt_tmd")]
+    pub am_mkmn_strt_tmd: String,
+    /// 오전장운영종료시각
+    #[serde(default, rename = "am_mkmn_end_tmd")]
+    pub am_mkmn_end_tmd: String,
+    /// 오후장운영시작시각
+    #[serde(default, rename = "pm_mkmn_strt_tmd")]
+    pub pm_mkmn_strt_tmd: String,
+    /// 오후장운영종료시각
+    #[serde(default, rename = "pm_mkmn_end_tmd")]
+    pub pm_mkmn_end_tmd: String,
+    /// 장운영익일시작시각
+    #[serde(default, rename = "mkmn_nxdy_strt_tmd")]
+    pub mkmn_nxdy_strt_tmd: String,
+    /// 장운영익일종료시각
+    #[serde(default, rename = "mkmn_nxdy_end_tmd")]
+    pub mkmn_nxdy_end_tmd: String,
+    /// 기본시장시작시각
+    #[serde(default, rename = "base_mket_strt_tmd")]
+    pub base_mket_strt_tmd: String,
+    /// 기본시장종료시각
+    #[serde(default, rename = "base_mket_end_tmd")]
+    pub base_mket_end_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외선물옵션 장운영시간 [해외선물-030]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasFutureoptionV1QuotationsMarketTimeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasFutureoptionV1QuotationsMarketTimeOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasFutureoptionV1QuotationsMarketTimeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
@@ -0,0 +1,6560 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [해외주식 주문[v1_해외주식-001]] 요청 구조체
+/// [해외주식-001 v1] 해외주식 주문
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 해외주문단가 (String, 필수)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 연락전화번호 (String, 선택)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+    /// 운용사지정주문번호 (String, 선택)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 판매유형 (String, 선택)
+    #[serde(rename = "SLL_TYPE")]
+    pub sll_type: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 시작시간 (String, 선택)
+    #[serde(rename = "START_TIME")]
+    pub start_time: String,
+    /// 종료시간 (String, 선택)
+    #[serde(rename = "END_TIME")]
+    pub end_time: String,
+    /// 알고리즘주문시간구분코드 (String, 선택)
+    #[serde(rename = "ALGO_ORD_TMD_DVSN_CD")]
+    pub algo_ord_tmd_dvsn_cd: String,
+}
+
+/// [해외주식 정정취소주문[v1_해외주식-003]] 요청 구조체
+/// [해외주식-003 v1] 해외주식 정정취소주문
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 해외주문단가 (String, 필수)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 운용사지정주문번호 (String, 선택)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 선택)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+}
+
+/// [해외주식 예약주문접수[v1_해외주식-002]] 요청 구조체
+/// [해외주식-002 v1] 해외주식 예약주문접수
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 매도매수구분코드 (String, 선택)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// FT주문수량 (String, 필수)
+    #[serde(rename = "FT_ORD_QTY")]
+    pub ft_ord_qty: String,
+    /// FT주문단가3 (String, 필수)
+    #[serde(rename = "FT_ORD_UNPR3")]
+    pub ft_ord_unpr3: String,
+    /// 주문서버구분코드 (String, 선택)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 예약주문접수일자 (String, 선택)
+    #[serde(rename = "RSVN_ORD_RCIT_DT")]
+    pub rsvn_ord_rcit_dt: String,
+    /// 주문구분 (String, 선택)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 해외예약주문번호 (String, 선택)
+    #[serde(rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: String,
+    /// 알고리즘주문시간구분코드 (String, 선택)
+    #[serde(rename = "ALGO_ORD_TMD_DVSN_CD")]
+    pub algo_ord_tmd_dvsn_cd: String,
+}
+
+/// [해외주식 예약주문접수취소[v1_해외주식-004]] 요청 구조체
+/// [해외주식-004 v1] 해외주식 예약주문접수취소
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvCcnlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외주문접수일자 (String, 필수)
+    #[serde(rename = "RSYN_ORD_RCIT_DT")]
+    pub rsyn_ord_rcit_dt: String,
+    /// 해외예약주문번호 (String, 필수)
+    #[serde(rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: String,
+}
+
+/// [해외주식 매수가능금액조회[v1_해외주식-014]] 요청 구조체
+/// [v1_해외주식-014]해외주식 매수가능금액조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePsamountRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 해외주문단가 (String, 필수)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "ITEM_CD")]
+    pub item_cd: String,
+}
+
+/// [해외주식 미체결내역[v1_해외주식-005]] 요청 구조체
+/// [해외주식-005 v1] 해외주식 미체결내역
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireNccsRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 정렬순서 (String, 필수)
+    #[serde(rename = "SORT_SQN")]
+    pub sort_sqn: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외주식 잔고[v1_해외주식-006]] 요청 구조체
+/// [해외주식-006 v1] 해외주식 잔고
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 거래통화코드 (String, 필수)
+    #[serde(rename = "TR_CRCY_CD")]
+    pub tr_crcy_cd: String,
+    /// 연속조회검색조건200 (String, 선택)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 선택)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외주식 주문체결내역[v1_해외주식-007]] 요청 구조체
+/// [해외주식-007 v1] 해외주식 주문체결내역
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireCcnlRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문시작일자 (String, 필수)
+    #[serde(rename = "ORD_STRT_DT")]
+    pub ord_strt_dt: String,
+    /// 주문종료일자 (String, 필수)
+    #[serde(rename = "ORD_END_DT")]
+    pub ord_end_dt: String,
+    /// 매도매수구분 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN")]
+    pub sll_buy_dvsn: String,
+    /// 체결미체결구분 (String, 필수)
+    #[serde(rename = "CCLD_NCCS_DVSN")]
+    pub ccld_nccs_dvsn: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 정렬순서 (String, 필수)
+    #[serde(rename = "SORT_SQN")]
+    pub sort_sqn: String,
+    /// 주문일자 (String, 필수)
+    #[serde(rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문채번지점번호 (String, 필수)
+    #[serde(rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: String,
+    /// 주문번호 (String, 필수)
+    #[serde(rename = "ODNO")]
+    pub odno: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+}
+
+/// [해외주식 체결기준현재잔고[v1_해외주식-008]] 요청 구조체
+/// [해외주식-008 v1] 해외주식 체결기준현재잔고
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePresentBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 원화외화구분코드 (String, 필수)
+    #[serde(rename = "WCRC_FRCR_DVSN_CD")]
+    pub wcrc_frcr_dvsn_cd: String,
+    /// 국가코드 (String, 필수)
+    #[serde(rename = "NATN_CD")]
+    pub natn_cd: String,
+    /// 거래시장코드 (String, 필수)
+    #[serde(rename = "TR_MKET_CD")]
+    pub tr_mket_cd: String,
+    /// 조회구분코드 (String, 필수)
+    #[serde(rename = "INQR_DVSN_CD")]
+    pub inqr_dvsn_cd: String,
+}
+
+/// [해외주식 예약주문조회[v1_해외주식-013]] 요청 구조체
+/// [v1_해외주식-013]해외주식 예약주문조회
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvListRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 조회구분코드 (String, 필수)
+    #[serde(rename = "INQR_DVSN_CD")]
+    pub inqr_dvsn_cd: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외주식 결제기준잔고 [해외주식-064]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePaymtStdrBalanceRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 기준일자 (String, 필수)
+    #[serde(rename = "BASS_DT")]
+    pub bass_dt: String,
+    /// 원화외화구분코드 (String, 필수)
+    #[serde(rename = "WCRC_FRCR_DVSN_CD")]
+    pub wcrc_frcr_dvsn_cd: String,
+    /// 조회구분코드 (String, 필수)
+    #[serde(rename = "INQR_DVSN_CD")]
+    pub inqr_dvsn_cd: String,
+}
+
+/// [해외주식 일별거래내역 [해외주식-063]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodTransRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 등록시작일자 (String, 필수)
+    #[serde(rename = "ERLM_STRT_DT")]
+    pub erlm_strt_dt: String,
+    /// 등록종료일자 (String, 필수)
+    #[serde(rename = "ERLM_END_DT")]
+    pub erlm_end_dt: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도매수구분코드 (String, 필수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 대출구분코드 (String, 필수)
+    #[serde(rename = "LOAN_DVSN_CD")]
+    pub loan_dvsn_cd: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [해외주식 기간손익[v1_해외주식-032]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodProfitRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 국가코드 (String, 필수)
+    #[serde(rename = "NATN_CD")]
+    pub natn_cd: String,
+    /// 통화코드 (String, 필수)
+    #[serde(rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 원화외화구분코드 (String, 필수)
+    #[serde(rename = "WCRC_FRCR_DVSN_CD")]
+    pub wcrc_frcr_dvsn_cd: String,
+    /// 연속조회검색조건200 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+}
+
+/// [해외증거금 통화별조회 [해외주식-035]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingForeignMarginRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드  (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+}
+
+/// [해외주식 미국주간주문[v1_해외주식-026]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingDaytimeOrderRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 해외주문단가 (String, 필수)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 연락전화번호 (String, 선택)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+    /// 운용사지정주문번호 (String, 선택)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+    /// 주문구분 (String, 필수)
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+}
+
+/// [해외주식 미국주간정정취소[v1_해외주식-027]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingDaytimeOrderRvsecnclRequest {
+    /// 종합계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 해외거래소코드 (String, 필수)
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 원주문번호 (String, 필수)
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 정정취소구분코드 (String, 필수)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 주문수량 (String, 필수)
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 해외주문단가 (String, 필수)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 연락전화번호 (String, 필수)
+    #[serde(rename = "CTAC_TLNO")]
+    pub ctac_tlno: String,
+    /// 운용사지정주문번호 (String, 필수)
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드 (String, 필수)
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+}
+
+/// [해외주식 지정가주문번호조회  [해외주식-071]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingAlgoOrdnoRequest {
+    /// 거래일자 (String, 필수)
+    #[serde(rename = "TRAD_DT")]
+    pub trad_dt: String,
+    /// 계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 연속조회키200 (String, 선택)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+    /// 연속조회조건200 (String, 선택)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+}
+
+/// [해외주식 지정가체결내역조회 [해외주식-070]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireAlgoCcnlRequest {
+    /// 계좌번호 (String, 필수)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (String, 필수)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 주문일자 (String, 필수)
+    #[serde(rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문채번지점번호 (String, 선택)
+    #[serde(rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: String,
+    /// 주문번호 (String, 필수)
+    #[serde(rename = "ODNO")]
+    pub odno: String,
+    /// 집계포함여부 (String, 선택)
+    #[serde(rename = "TTLZ_ICLD_YN")]
+    pub ttlz_icld_yn: String,
+    /// 연속조회키200 (String, 선택)
+    #[serde(rename = "CTX_AREA_NK200")]
+    pub ctx_area_nk200: String,
+    /// 연속조회조건200 (String, 선택)
+    #[serde(rename = "CTX_AREA_FK200")]
+    pub ctx_area_fk200: String,
+}
+
+/// [해외주식 현재가상세[v1_해외주식-029]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPriceDetailRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소명 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+}
+
+/// [해외주식 현재가 호가 [해외주식-033]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireAskingPriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+}
+
+/// [해외주식 현재체결가[v1_해외주식-009]] 요청 구조체
+/// [해외주식-009 v1] 해외주식 현재체결가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+}
+
+/// [해외주식 체결추이[해외주식-037]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireCcnlRequest {
+    /// 거래소명 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 당일전일구분 (String, 필수)
+    #[serde(rename = "TDAY")]
+    pub tday: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+}
+
+/// [해외주식분봉조회[v1_해외주식-030]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeItemchartpriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+    /// 분갭 (String, 필수)
+    #[serde(rename = "NMIN")]
+    pub nmin: String,
+    /// 전일포함여부 (String, 필수)
+    #[serde(rename = "PINC")]
+    pub pinc: String,
+    /// 다음여부 (String, 필수)
+    #[serde(rename = "NEXT")]
+    pub next: String,
+    /// 요청갯수 (String, 필수)
+    #[serde(rename = "NREC")]
+    pub nrec: String,
+    /// 미체결채움구분 (String, 필수)
+    #[serde(rename = "FILL")]
+    pub fill: String,
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+}
+
+/// [해외지수분봉조회[v1_해외주식-031]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeIndexchartpriceRequest {
+    /// 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 시간 구분 코드 (String, 필수)
+    #[serde(rename = "FID_HOUR_CLS_CODE")]
+    pub fid_hour_cls_code: String,
+    /// 과거 데이터 포함 여부 (String, 필수)
+    #[serde(rename = "FID_PW_DATA_INCU_YN")]
+    pub fid_pw_data_incu_yn: String,
+}
+
+/// [해외주식 기간별시세[v1_해외주식-010]] 요청 구조체
+/// [해외주식-010 v1] 해외주식 기간별시세
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsDailypriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+    /// 일/주/월구분 (String, 필수)
+    #[serde(rename = "GUBN")]
+    pub gubn: String,
+    /// 조회기준일자 (String, 필수)
+    #[serde(rename = "BYMD")]
+    pub bymd: String,
+    /// 수정주가반영여부 (String, 필수)
+    #[serde(rename = "MODP")]
+    pub modp: String,
+    /// NEXT KEY BUFF (String, 선택)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+}
+
+/// [해외주식 종목/지수/환율기간별시세(일/주/월/년)[v1_해외주식-012]] 요청 구조체
+/// [v1_해외주식-012] 해외지수/환율기간별시세(일/주/월/년)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireDailyChartpriceRequest {
+    /// FID 조건 시장 분류 코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
+    pub fid_cond_mrkt_div_code: String,
+    /// FID 입력 종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// FID 입력 날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// FID 입력 날짜2 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_2")]
+    pub fid_input_date_2: String,
+    /// FID 기간 분류 코드 (String, 필수)
+    #[serde(rename = "FID_PERIOD_DIV_CODE")]
+    pub fid_period_div_code: String,
+}
+
+/// [해외주식조건검색[v1_해외주식-015]] 요청 구조체
+/// 해외주식조건검색[v1_해외주식-015]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireSearchRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 현재가선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_PRICECUR")]
+    pub co_yn_pricecur: String,
+    /// 현재가시작범위가 (String, 선택)
+    #[serde(rename = "CO_ST_PRICECUR")]
+    pub co_st_pricecur: String,
+    /// 현재가끝범위가 (String, 선택)
+    #[serde(rename = "CO_EN_PRICECUR")]
+    pub co_en_pricecur: String,
+    /// 등락율선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_RATE")]
+    pub co_yn_rate: String,
+    /// 등락율시작율 (String, 선택)
+    #[serde(rename = "CO_ST_RATE")]
+    pub co_st_rate: String,
+    /// 등락율끝율 (String, 선택)
+    #[serde(rename = "CO_EN_RATE")]
+    pub co_en_rate: String,
+    /// 시가총액선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_VALX")]
+    pub co_yn_valx: String,
+    /// 시가총액시작액 (String, 선택)
+    #[serde(rename = "CO_ST_VALX")]
+    pub co_st_valx: String,
+    /// 시가총액끝액 (String, 선택)
+    #[serde(rename = "CO_EN_VALX")]
+    pub co_en_valx: String,
+    /// 발행주식수선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_SHAR")]
+    pub co_yn_shar: String,
+    /// 발행주식시작수 (String, 선택)
+    #[serde(rename = "CO_ST_SHAR")]
+    pub co_st_shar: String,
+    /// 발행주식끝수 (String, 선택)
+    #[serde(rename = "CO_EN_SHAR")]
+    pub co_en_shar: String,
+    /// 거래량선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_VOLUME")]
+    pub co_yn_volume: String,
+    /// 거래량시작량 (String, 선택)
+    #[serde(rename = "CO_ST_VOLUME")]
+    pub co_st_volume: String,
+    /// 거래량끝량 (String, 선택)
+    #[serde(rename = "CO_EN_VOLUME")]
+    pub co_en_volume: String,
+    /// 거래대금선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_AMT")]
+    pub co_yn_amt: String,
+    /// 거래대금시작금 (String, 선택)
+    #[serde(rename = "CO_ST_AMT")]
+    pub co_st_amt: String,
+    /// 거래대금끝금 (String, 선택)
+    #[serde(rename = "CO_EN_AMT")]
+    pub co_en_amt: String,
+    /// EPS선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_EPS")]
+    pub co_yn_eps: String,
+    /// EPS시작 (String, 선택)
+    #[serde(rename = "CO_ST_EPS")]
+    pub co_st_eps: String,
+    /// EPS끝 (String, 선택)
+    #[serde(rename = "CO_EN_EPS")]
+    pub co_en_eps: String,
+    /// PER선택조건 (String, 선택)
+    #[serde(rename = "CO_YN_PER")]
+    pub co_yn_per: String,
+    /// PER시작 (String, 선택)
+    #[serde(rename = "CO_ST_PER")]
+    pub co_st_per: String,
+    /// PER끝 (String, 선택)
+    #[serde(rename = "CO_EN_PER")]
+    pub co_en_per: String,
+    /// NEXT KEY BUFF (String, 선택)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+}
+
+/// [해외결제일자조회[해외주식-017]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1QuotationsCountriesHolidayRequest {
+    /// 기준일자 (String, 필수)
+    #[serde(rename = "TRAD_DT")]
+    pub trad_dt: String,
+    /// 연속조회키 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK")]
+    pub ctx_area_nk: String,
+    /// 연속조회검색조건 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK")]
+    pub ctx_area_fk: String,
+}
+
+/// [해외주식 상품기본정보[v1_해외주식-034]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsSearchInfoRequest {
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+}
+
+/// [해외주식 업종별시세[해외주식-048]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryThemeRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 업종코드 (String, 필수)
+    #[serde(rename = "ICOD")]
+    pub icod: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 업종별코드조회[해외주식-049]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryPriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+}
+
+/// [해외주식 복수종목 시세조회] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsMultpriceRequest {
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 종목요청개수 (String, 필수)
+    #[serde(rename = "NREC")]
+    pub nrec: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD_01 ~ 10")]
+    pub excd_01_10: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB_01 ~ 10")]
+    pub symb_01_10: String,
+}
+
+/// [해외주식 가격급등락[해외주식-038]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingPriceFluctRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 급등/급락구분 (String, 필수)
+    #[serde(rename = "GUBN")]
+    pub gubn: String,
+    /// N분전콤보값 (String, 필수)
+    #[serde(rename = "MINX")]
+    pub minx: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 거래량급증[해외주식-039]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumeSurgeRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N분전콤보값 (String, 필수)
+    #[serde(rename = "MINX")]
+    pub minx: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 매수체결강도상위[해외주식-040]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumePowerRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 상승율/하락율[해외주식-041]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingUpdownRateRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 상승율/하락율 구분 (String, 필수)
+    #[serde(rename = "GUBN")]
+    pub gubn: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 신고/신저가[해외주식-042]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingNewHighlowRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 신고/신저 구분 (String, 필수)
+    #[serde(rename = "GUBN")]
+    pub gubn: String,
+    /// 일시돌파/돌파 구분 (String, 필수)
+    #[serde(rename = "GUBN2")]
+    pub gubn2: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 거래량순위[해외주식-043]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeVolRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 현재가 필터범위 1 (String, 필수)
+    #[serde(rename = "PRC1")]
+    pub prc1: String,
+    /// 현재가 필터범위 2 (String, 필수)
+    #[serde(rename = "PRC2")]
+    pub prc2: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 거래대금순위[해외주식-044]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradePbmnRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+    /// 현재가 필터범위 1 (String, 필수)
+    #[serde(rename = "PRC1")]
+    pub prc1: String,
+    /// 현재가 필터범위 2 (String, 필수)
+    #[serde(rename = "PRC2")]
+    pub prc2: String,
+}
+
+/// [해외주식 거래증가율순위[해외주식-045]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeGrowthRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 거래회전율순위[해외주식-046]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeTurnoverRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// N일자값 (String, 필수)
+    #[serde(rename = "NDAY")]
+    pub nday: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 시가총액순위[해외주식-047]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingMarketCapRequest {
+    /// NEXT KEY BUFF (String, 필수)
+    #[serde(rename = "KEYB")]
+    pub keyb: String,
+    /// 사용자권한정보 (String, 필수)
+    #[serde(rename = "AUTH")]
+    pub auth: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCD")]
+    pub excd: String,
+    /// 거래량조건 (String, 필수)
+    #[serde(rename = "VOL_RANG")]
+    pub vol_rang: String,
+}
+
+/// [해외주식 기간별권리조회 [해외주식-052]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPeriodRightsRequest {
+    /// 권리유형코드 (String, 필수)
+    #[serde(rename = "RGHT_TYPE_CD")]
+    pub rght_type_cd: String,
+    /// 조회구분코드 (String, 필수)
+    #[serde(rename = "INQR_DVSN_CD")]
+    pub inqr_dvsn_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 연속조회키50 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK50")]
+    pub ctx_area_nk50: String,
+    /// 연속조회검색조건50 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK50")]
+    pub ctx_area_fk50: String,
+}
+
+/// [해외뉴스종합(제목) [해외주식-053]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsNewsTitleRequest {
+    /// 뉴스구분 (String, 필수)
+    #[serde(rename = "INFO_GB")]
+    pub info_gb: String,
+    /// 중분류 (String, 필수)
+    #[serde(rename = "CLASS_CD")]
+    pub class_cd: String,
+    /// 국가코드 (String, 필수)
+    #[serde(rename = "NATION_CD")]
+    pub nation_cd: String,
+    /// 거래소코드 (String, 필수)
+    #[serde(rename = "EXCHANGE_CD")]
+    pub exchange_cd: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+    /// 조회일자 (String, 필수)
+    #[serde(rename = "DATA_DT")]
+    pub data_dt: String,
+    /// 조회시간 (String, 필수)
+    #[serde(rename = "DATA_TM")]
+    pub data_tm: String,
+    /// 다음키 (String, 필수)
+    #[serde(rename = "CTS")]
+    pub cts: String,
+}
+
+/// [해외주식 권리종합 [해외주식-050]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsRightsByIceRequest {
+    /// 국가코드 (String, 필수)
+    #[serde(rename = "NCOD")]
+    pub ncod: String,
+    /// 심볼 (String, 필수)
+    #[serde(rename = "SYMB")]
+    pub symb: String,
+    /// 일자 시작일 (String, 필수)
+    #[serde(rename = "ST_YMD")]
+    pub st_ymd: String,
+    /// 일자 종료일 (String, 필수)
+    #[serde(rename = "ED_YMD")]
+    pub ed_ymd: String,
+}
+
+/// [당사 해외주식담보대출 가능 종목 [해외주식-051]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsColableByCompanyRequest {
+    /// 상품번호 (String, 필수)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 (String, 필수)
+    #[serde(rename = "PRDT_TYPE_CD")]
+    pub prdt_type_cd: String,
+    /// 조회시작일자 (String, 필수)
+    #[serde(rename = "INQR_STRT_DT")]
+    pub inqr_strt_dt: String,
+    /// 조회종료일자 (String, 필수)
+    #[serde(rename = "INQR_END_DT")]
+    pub inqr_end_dt: String,
+    /// 조회구분 (String, 필수)
+    #[serde(rename = "INQR_DVSN")]
+    pub inqr_dvsn: String,
+    /// 국가코드 (String, 필수)
+    #[serde(rename = "NATN_CD")]
+    pub natn_cd: String,
+    /// 조회순서구분 (String, 필수)
+    #[serde(rename = "INQR_SQN_DVSN")]
+    pub inqr_sqn_dvsn: String,
+    /// 비율구분코드 (String, 필수)
+    #[serde(rename = "RT_DVSN_CD")]
+    pub rt_dvsn_cd: String,
+    /// 비율 (String, 필수)
+    #[serde(rename = "RT")]
+    pub rt: String,
+    /// 대출가능여부 (String, 필수)
+    #[serde(rename = "LOAN_PSBL_YN")]
+    pub loan_psbl_yn: String,
+    /// 연속조회검색조건100 (String, 필수)
+    #[serde(rename = "CTX_AREA_FK100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100 (String, 필수)
+    #[serde(rename = "CTX_AREA_NK100")]
+    pub ctx_area_nk100: String,
+}
+
+/// [해외속보(제목) [해외주식-055]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsBrknewsTitleRequest {
+    /// 뉴스제공업체코드 (String, 필수)
+    #[serde(rename = "FID_NEWS_OFER_ENTP_CODE")]
+    pub fid_news_ofer_entp_code: String,
+    /// 조건시장구분코드 (String, 필수)
+    #[serde(rename = "FID_COND_MRKT_CLS_CODE")]
+    pub fid_cond_mrkt_cls_code: String,
+    /// 입력종목코드 (String, 필수)
+    #[serde(rename = "FID_INPUT_ISCD")]
+    pub fid_input_iscd: String,
+    /// 제목내용 (String, 필수)
+    #[serde(rename = "FID_TITL_CNTT")]
+    pub fid_titl_cntt: String,
+    /// 입력날짜1 (String, 필수)
+    #[serde(rename = "FID_INPUT_DATE_1")]
+    pub fid_input_date_1: String,
+    /// 입력시간1 (String, 필수)
+    #[serde(rename = "FID_INPUT_HOUR_1")]
+    pub fid_input_hour_1: String,
+    /// 순위정렬구분코드 (String, 필수)
+    #[serde(rename = "FID_RANK_SORT_CLS_CODE")]
+    pub fid_rank_sort_cls_code: String,
+    /// 입력일련번호 (String, 필수)
+    #[serde(rename = "FID_INPUT_SRNO")]
+    pub fid_input_srno: String,
+    /// 조건화면분류코드 (String, 필수)
+    #[serde(rename = "FID_COND_SCR_DIV_CODE")]
+    pub fid_cond_scr_div_code: String,
+}
+
+/// [해외주식 주문[v1_해외주식-001]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 주문[v1_해외주식-001]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 정정취소주문[v1_해외주식-003]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderRvsecnclOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 정정취소주문[v1_해외주식-003]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 예약주문접수[v1_해외주식-002]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 예약주문접수일자
+    #[serde(default, rename = "RSVN_ORD_RCIT_DT")]
+    pub rsvn_ord_rcit_dt: String,
+    /// 해외예약주문번호
+    #[serde(default, rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 예약주문접수[v1_해외주식-002]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingOrderResvResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingOrderResvOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingOrderResvResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 예약주문접수취소[v1_해외주식-004]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvCcnlOutputItem {
+    /// 해외예약주문번호
+    #[serde(default, rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 예약주문접수취소[v1_해외주식-004]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingOrderResvCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingOrderResvCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingOrderResvCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 매수가능금액조회[v1_해외주식-014]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePsamountOutputItem {
+    /// 거래통화코드
+    #[serde(default, rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: String,
+    /// 주문가능외화금액
+    #[serde(default, rename = "ord_psbl_frcr_amt")]
+    pub ord_psbl_frcr_amt: Decimal,
+    /// 매도재사용가능금액
+    #[serde(default, rename = "sll_ruse_psbl_amt")]
+    pub sll_ruse_psbl_amt: Decimal,
+    /// 해외주문가능금액
+    #[serde(default, rename = "ovrs_ord_psbl_amt")]
+    pub ovrs_ord_psbl_amt: Decimal,
+    /// 최대주문가능수량
+    #[serde(default, rename = "max_ord_psbl_qty")]
+    pub max_ord_psbl_qty: Decimal,
+    /// 환전이후주문가능금액
+    #[serde(default, rename = "echm_af_ord_psbl_amt")]
+    pub echm_af_ord_psbl_amt: Decimal,
+    /// 환전이후주문가능수량
+    #[serde(default, rename = "echm_af_ord_psbl_qty")]
+    pub echm_af_ord_psbl_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 환율
+    #[serde(default, rename = "exrt")]
+    pub exrt: String,
+    /// 외화주문가능금액1
+    #[serde(default, rename = "frcr_ord_psbl_amt1")]
+    pub frcr_ord_psbl_amt1: Decimal,
+    /// 해외최대주문가능수량
+    #[serde(default, rename = "ovrs_max_ord_psbl_qty")]
+    pub ovrs_max_ord_psbl_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 매수가능금액조회[v1_해외주식-014]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquirePsamountResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingInquirePsamountOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquirePsamountResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 미체결내역[v1_해외주식-005]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireNccsOutputItem {
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매도매수구분코드명
+    #[serde(default, rename = "sll_buy_dvsn_cd_name")]
+    pub sll_buy_dvsn_cd_name: String,
+    /// 정정취소구분코드
+    #[serde(default, rename = "rvse_cncl_dvsn_cd")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 정정취소구분코드명
+    #[serde(default, rename = "rvse_cncl_dvsn_cd_name")]
+    pub rvse_cncl_dvsn_cd_name: String,
+    /// 거부사유
+    #[serde(default, rename = "rjct_rson")]
+    pub rjct_rson: String,
+    /// 거부사유명
+    #[serde(default, rename = "rjct_rson_name")]
+    pub rjct_rson_name: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 거래통화코드
+    #[serde(default, rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: String,
+    /// 국가코드
+    #[serde(default, rename = "natn_cd")]
+    pub natn_cd: String,
+    /// 국가한글명
+    #[serde(default, rename = "natn_kor_name")]
+    pub natn_kor_name: String,
+    /// FT주문수량
+    #[serde(default, rename = "ft_ord_qty")]
+    pub ft_ord_qty: Decimal,
+    /// FT체결수량
+    #[serde(default, rename = "ft_ccld_qty")]
+    pub ft_ccld_qty: Decimal,
+    /// 미체결수량
+    #[serde(default, rename = "nccs_qty")]
+    pub nccs_qty: Decimal,
+    /// FT주문단가3
+    #[serde(default, rename = "ft_ord_unpr3")]
+    pub ft_ord_unpr3: Decimal,
+    /// FT체결단가3
+    #[serde(default, rename = "ft_ccld_unpr3")]
+    pub ft_ccld_unpr3: Decimal,
+    /// FT체결금액3
+    #[serde(default, rename = "ft_ccld_amt3")]
+    pub ft_ccld_amt3: Decimal,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 처리상태명
+    #[serde(default, rename = "prcs_stat_name")]
+    pub prcs_stat_name: String,
+    /// 대출유형코드
+    #[serde(default, rename = "loan_type_cd")]
+    pub loan_type_cd: String,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 미국애프터마켓연장신청여부
+    #[serde(default, rename = "usa_amk_exts_rqst_yn")]
+    pub usa_amk_exts_rqst_yn: String,
+    /// 분할매수속성명
+    #[serde(default, rename = "splt_buy_attr_name")]
+    pub splt_buy_attr_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 미체결내역[v1_해외주식-005]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquireNccsResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<OverseasStockV1TradingInquireNccsOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquireNccsResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 잔고[v1_해외주식-006]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireBalanceOutput1Item {
+    /// 종합계좌번호
+    #[serde(default, rename = "cano")]
+    pub cano: String,
+    /// 계좌상품코드
+    #[serde(default, rename = "acnt_prdt_cd")]
+    pub acnt_prdt_cd: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 해외상품번호
+    #[serde(default, rename = "ovrs_pdno")]
+    pub ovrs_pdno: String,
+    /// 해외종목명
+    #[serde(default, rename = "ovrs_item_name")]
+    pub ovrs_item_name: String,
+    /// 외화평가손익금액
+    #[serde(default, rename = "frcr_evlu_pfls_amt")]
+    pub frcr_evlu_pfls_amt: Decimal,
+    /// 평가손익율
+    #[serde(default, rename = "evlu_pfls_rt")]
+    pub evlu_pfls_rt: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 해외잔고수량
+    #[serde(default, rename = "ovrs_cblc_qty")]
+    pub ovrs_cblc_qty: Decimal,
+    /// 주문가능수량
+    #[serde(default, rename = "ord_psbl_qty")]
+    pub ord_psbl_qty: Decimal,
+    /// 외화매입금액1
+    #[serde(default, rename = "frcr_pchs_amt1")]
+    pub frcr_pchs_amt1: Decimal,
+    /// 해외주식평가금액
+    #[serde(default, rename = "ovrs_stck_evlu_amt")]
+    pub ovrs_stck_evlu_amt: Decimal,
+    /// 현재가격2
+    #[serde(default, rename = "now_pric2")]
+    pub now_pric2: String,
+    /// 거래통화코드
+    #[serde(default, rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 대출유형코드
+    #[serde(default, rename = "loan_type_cd")]
+    pub loan_type_cd: String,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 만기일자
+    #[serde(default, rename = "expd_dt")]
+    pub expd_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 잔고[v1_해외주식-006]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireBalanceOutput2Item {
+    /// 외화매입금액1
+    #[serde(default, rename = "frcr_pchs_amt1")]
+    pub frcr_pchs_amt1: Decimal,
+    /// 해외실현손익금액
+    #[serde(default, rename = "ovrs_rlzt_pfls_amt")]
+    pub ovrs_rlzt_pfls_amt: Decimal,
+    /// 해외총손익
+    #[serde(default, rename = "ovrs_tot_pfls")]
+    pub ovrs_tot_pfls: String,
+    /// 실현수익율
+    #[serde(default, rename = "rlzt_erng_rt")]
+    pub rlzt_erng_rt: Decimal,
+    /// 총평가손익금액
+    #[serde(default, rename = "tot_evlu_pfls_amt")]
+    pub tot_evlu_pfls_amt: Decimal,
+    /// 총수익률
+    #[serde(default, rename = "tot_pftrt")]
+    pub tot_pftrt: String,
+    /// 외화매수금액합계1
+    #[serde(default, rename = "frcr_buy_amt_smtl1")]
+    pub frcr_buy_amt_smtl1: String,
+    /// 해외실현손익금액2
+    #[serde(default, rename = "ovrs_rlzt_pfls_amt2")]
+    pub ovrs_rlzt_pfls_amt2: Decimal,
+    /// 외화매수금액합계2
+    #[serde(default, rename = "frcr_buy_amt_smtl2")]
+    pub frcr_buy_amt_smtl2: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 잔고[v1_해외주식-006]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquireBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1TradingInquireBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1TradingInquireBalanceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquireBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 주문체결내역[v1_해외주식-007]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireCcnlOutputItem {
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 원주문번호
+    #[serde(default, rename = "orgn_odno")]
+    pub orgn_odno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매도매수구분코드명
+    #[serde(default, rename = "sll_buy_dvsn_cd_name")]
+    pub sll_buy_dvsn_cd_name: String,
+    /// 정정취소구분
+    #[serde(default, rename = "rvse_cncl_dvsn")]
+    pub rvse_cncl_dvsn: String,
+    /// 정정취소구분명
+    #[serde(default, rename = "rvse_cncl_dvsn_name")]
+    pub rvse_cncl_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// FT주문수량
+    #[serde(default, rename = "ft_ord_qty")]
+    pub ft_ord_qty: Decimal,
+    /// FT주문단가3
+    #[serde(default, rename = "ft_ord_unpr3")]
+    pub ft_ord_unpr3: Decimal,
+    /// FT체결수량
+    #[serde(default, rename = "ft_ccld_qty")]
+    pub ft_ccld_qty: Decimal,
+    /// FT체결단가3
+    #[serde(default, rename = "ft_ccld_unpr3")]
+    pub ft_ccld_unpr3: Decimal,
+    /// FT체결금액3
+    #[serde(default, rename = "ft_ccld_amt3")]
+    pub ft_ccld_amt3: Decimal,
+    /// 미체결수량
+    #[serde(default, rename = "nccs_qty")]
+    pub nccs_qty: Decimal,
+    /// 처리상태명
+    #[serde(default, rename = "prcs_stat_name")]
+    pub prcs_stat_name: String,
+    /// 거부사유
+    #[serde(default, rename = "rjct_rson")]
+    pub rjct_rson: String,
+    /// 거부사유명
+    #[serde(default, rename = "rjct_rson_name")]
+    pub rjct_rson_name: String,
+    /// 주문시각
+    #[serde(default, rename = "ord_tmd")]
+    pub ord_tmd: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 거래국가
+    #[serde(default, rename = "tr_natn")]
+    pub tr_natn: String,
+    /// 거래국가명
+    #[serde(default, rename = "tr_natn_name")]
+    pub tr_natn_name: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 거래통화코드
+    #[serde(default, rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: String,
+    /// 국내주문일자
+    #[serde(default, rename = "dmst_ord_dt")]
+    pub dmst_ord_dt: String,
+    /// 당사주문시각
+    #[serde(default, rename = "thco_ord_tmd")]
+    pub thco_ord_tmd: String,
+    /// 대출유형코드
+    #[serde(default, rename = "loan_type_cd")]
+    pub loan_type_cd: String,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 매체구분명
+    #[serde(default, rename = "mdia_dvsn_name")]
+    pub mdia_dvsn_name: String,
+    /// 미국애프터마켓연장신청여부
+    #[serde(default, rename = "usa_amk_exts_rqst_yn")]
+    pub usa_amk_exts_rqst_yn: String,
+    /// 분할매수/매도속성명
+    #[serde(default, rename = "splt_buy_attr_name")]
+    pub splt_buy_attr_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 주문체결내역[v1_해외주식-007]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquireCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<OverseasStockV1TradingInquireCcnlOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquireCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 체결기준현재잔고[v1_해외주식-008]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePresentBalanceOutput1Item {
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 잔고수량13
+    #[serde(default, rename = "cblc_qty13")]
+    pub cblc_qty13: String,
+    /// 당일매수체결수량1
+    #[serde(default, rename = "thdt_buy_ccld_qty1")]
+    pub thdt_buy_ccld_qty1: String,
+    /// 당일매도체결수량1
+    #[serde(default, rename = "thdt_sll_ccld_qty1")]
+    pub thdt_sll_ccld_qty1: String,
+    /// 체결수량합계1
+    #[serde(default, rename = "ccld_qty_smtl1")]
+    pub ccld_qty_smtl1: String,
+    /// 주문가능수량1
+    #[serde(default, rename = "ord_psbl_qty1")]
+    pub ord_psbl_qty1: String,
+    /// 외화매입금액
+    #[serde(default, rename = "frcr_pchs_amt")]
+    pub frcr_pchs_amt: Decimal,
+    /// 외화평가금액2
+    #[serde(default, rename = "frcr_evlu_amt2")]
+    pub frcr_evlu_amt2: Decimal,
+    /// 평가손익금액2
+    #[serde(default, rename = "evlu_pfls_amt2")]
+    pub evlu_pfls_amt2: Decimal,
+    /// 평가손익율1
+    #[serde(default, rename = "evlu_pfls_rt1")]
+    pub evlu_pfls_rt1: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 기준환율
+    #[serde(default, rename = "bass_exrt")]
+    pub bass_exrt: String,
+    /// 매수통화코드
+    #[serde(default, rename = "buy_crcy_cd")]
+    pub buy_crcy_cd: String,
+    /// 해외현재가격1
+    #[serde(default, rename = "ovrs_now_pric1")]
+    pub ovrs_now_pric1: String,
+    /// 평균단가3
+    #[serde(default, rename = "avg_unpr3")]
+    pub avg_unpr3: Decimal,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 국가한글명
+    #[serde(default, rename = "natn_kor_name")]
+    pub natn_kor_name: String,
+    /// 매입잔액원화금액
+    #[serde(default, rename = "pchs_rmnd_wcrc_amt")]
+    pub pchs_rmnd_wcrc_amt: Decimal,
+    /// 당일매수체결외화금액
+    #[serde(default, rename = "thdt_buy_ccld_frcr_amt")]
+    pub thdt_buy_ccld_frcr_amt: Decimal,
+    /// 당일매도체결외화금액
+    #[serde(default, rename = "thdt_sll_ccld_frcr_amt")]
+    pub thdt_sll_ccld_frcr_amt: Decimal,
+    /// 단위금액
+    #[serde(default, rename = "unit_amt")]
+    pub unit_amt: Decimal,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 유가증권구분명
+    #[serde(default, rename = "scts_dvsn_name")]
+    pub scts_dvsn_name: String,
+    /// 대출잔액
+    #[serde(default, rename = "loan_rmnd")]
+    pub loan_rmnd: String,
+    /// 대출일자
+    #[serde(default, rename = "loan_dt")]
+    pub loan_dt: String,
+    /// 대출만기일자
+    #[serde(default, rename = "loan_expd_dt")]
+    pub loan_expd_dt: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 종목연동거래소코드
+    #[serde(default, rename = "item_lnkg_excg_cd")]
+    pub item_lnkg_excg_cd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 체결기준현재잔고[v1_해외주식-008]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePresentBalanceOutput2Item {
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 통화코드명
+    #[serde(default, rename = "crcy_cd_name")]
+    pub crcy_cd_name: String,
+    /// 외화매수금액합계
+    #[serde(default, rename = "frcr_buy_amt_smtl")]
+    pub frcr_buy_amt_smtl: String,
+    /// 외화매도금액합계
+    #[serde(default, rename = "frcr_sll_amt_smtl")]
+    pub frcr_sll_amt_smtl: String,
+    /// 외화예수금액2
+    #[serde(default, rename = "frcr_dncl_amt_2")]
+    pub frcr_dncl_amt_2: String,
+    /// 최초고시환율
+    #[serde(default, rename = "frst_bltn_exrt")]
+    pub frst_bltn_exrt: String,
+    /// 외화매수증거금액
+    #[serde(default, rename = "frcr_buy_mgn_amt")]
+    pub frcr_buy_mgn_amt: Decimal,
+    /// 외화기타증거금
+    #[serde(default, rename = "frcr_etc_mgna")]
+    pub frcr_etc_mgna: String,
+    /// 외화출금가능금액1
+    #[serde(default, rename = "frcr_drwg_psbl_amt_1")]
+    pub frcr_drwg_psbl_amt_1: String,
+    /// 출금가능원화금액
+    #[serde(default, rename = "frcr_evlu_amt2")]
+    pub frcr_evlu_amt2: Decimal,
+    /// 현지보관통화여부
+    #[serde(default, rename = "acpl_cstd_crcy_yn")]
+    pub acpl_cstd_crcy_yn: String,
+    /// 익일외화출금가능금액
+    #[serde(default, rename = "nxdy_frcr_drwg_psbl_amt")]
+    pub nxdy_frcr_drwg_psbl_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 체결기준현재잔고[v1_해외주식-008]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePresentBalanceOutput3Item {
+    /// 매입금액합계
+    #[serde(default, rename = "pchs_amt_smtl")]
+    pub pchs_amt_smtl: String,
+    /// 평가금액합계
+    #[serde(default, rename = "evlu_amt_smtl")]
+    pub evlu_amt_smtl: String,
+    /// 평가손익금액합계
+    #[serde(default, rename = "evlu_pfls_amt_smtl")]
+    pub evlu_pfls_amt_smtl: String,
+    /// 예수금액
+    #[serde(default, rename = "dncl_amt")]
+    pub dncl_amt: Decimal,
+    /// CMA평가금액
+    #[serde(default, rename = "cma_evlu_amt")]
+    pub cma_evlu_amt: Decimal,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// 기타증거금
+    #[serde(default, rename = "etc_mgna")]
+    pub etc_mgna: String,
+    /// 인출가능총금액
+    #[serde(default, rename = "wdrw_psbl_tot_amt")]
+    pub wdrw_psbl_tot_amt: Decimal,
+    /// 외화평가총액
+    #[serde(default, rename = "frcr_evlu_tota")]
+    pub frcr_evlu_tota: String,
+    /// 평가수익율1
+    #[serde(default, rename = "evlu_erng_rt1")]
+    pub evlu_erng_rt1: String,
+    /// 매입금액합계금액
+    #[serde(default, rename = "pchs_amt_smtl_amt")]
+    pub pchs_amt_smtl_amt: Decimal,
+    /// 평가금액합계금액
+    #[serde(default, rename = "evlu_amt_smtl_amt")]
+    pub evlu_amt_smtl_amt: Decimal,
+    /// 총평가손익금액
+    #[serde(default, rename = "tot_evlu_pfls_amt")]
+    pub tot_evlu_pfls_amt: Decimal,
+    /// 총자산금액
+    #[serde(default, rename = "tot_asst_amt")]
+    pub tot_asst_amt: Decimal,
+    /// 매수증거금액
+    #[serde(default, rename = "buy_mgn_amt")]
+    pub buy_mgn_amt: Decimal,
+    /// 증거금총액
+    #[serde(default, rename = "mgna_tota")]
+    pub mgna_tota: String,
+    /// 외화사용가능금액
+    #[serde(default, rename = "frcr_use_psbl_amt")]
+    pub frcr_use_psbl_amt: Decimal,
+    /// 미결제매도금액합계
+    #[serde(default, rename = "ustl_sll_amt_smtl")]
+    pub ustl_sll_amt_smtl: String,
+    /// 미결제매수금액합계
+    #[serde(default, rename = "ustl_buy_amt_smtl")]
+    pub ustl_buy_amt_smtl: String,
+    /// 총외화잔고합계
+    #[serde(default, rename = "tot_frcr_cblc_smtl")]
+    pub tot_frcr_cblc_smtl: String,
+    /// 총대출금액
+    #[serde(default, rename = "tot_loan_amt")]
+    pub tot_loan_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 체결기준현재잔고[v1_해외주식-008]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquirePresentBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1TradingInquirePresentBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1TradingInquirePresentBalanceOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<OverseasStockV1TradingInquirePresentBalanceOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquirePresentBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 예약주문조회[v1_해외주식-013]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingOrderResvListOutputItem {
+    /// 취소여부
+    #[serde(default, rename = "cncl_yn")]
+    pub cncl_yn: String,
+    /// 예약주문접수일자
+    #[serde(default, rename = "rsvn_ord_rcit_dt")]
+    pub rsvn_ord_rcit_dt: String,
+    /// 해외예약주문번호
+    #[serde(default, rename = "ovrs_rsvn_odno")]
+    pub ovrs_rsvn_odno: String,
+    /// 주문일자
+    #[serde(default, rename = "ord_dt")]
+    pub ord_dt: String,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매도매수구분명
+    #[serde(default, rename = "sll_buy_dvsn_cd_name")]
+    pub sll_buy_dvsn_cd_name: String,
+    /// 해외예약주문상태코드
+    #[serde(default, rename = "ovrs_rsvn_ord_stat_cd")]
+    pub ovrs_rsvn_ord_stat_cd: String,
+    /// 해외예약주문상태코드명
+    #[serde(default, rename = "ovrs_rsvn_ord_stat_cd_name")]
+    pub ovrs_rsvn_ord_stat_cd_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 주문접수시각
+    #[serde(default, rename = "ord_rcit_tmd")]
+    pub ord_rcit_tmd: String,
+    /// 주문전송시각
+    #[serde(default, rename = "ord_fwdg_tmd")]
+    pub ord_fwdg_tmd: String,
+    /// 거래구분명
+    #[serde(default, rename = "tr_dvsn_name")]
+    pub tr_dvsn_name: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 주문직원번호
+    #[serde(default, rename = "ord_stfno")]
+    pub ord_stfno: String,
+    /// FT주문수량
+    #[serde(default, rename = "ft_ord_qty")]
+    pub ft_ord_qty: Decimal,
+    /// FT주문단가3
+    #[serde(default, rename = "ft_ord_unpr3")]
+    pub ft_ord_unpr3: Decimal,
+    /// FT체결수량
+    #[serde(default, rename = "ft_ccld_qty")]
+    pub ft_ccld_qty: Decimal,
+    /// 미처리사유내용
+    #[serde(default, rename = "nprc_rson_text")]
+    pub nprc_rson_text: String,
+    /// 분할매수속성명
+    #[serde(default, rename = "splt_buy_attr_name")]
+    pub splt_buy_attr_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 예약주문조회[v1_해외주식-013]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingOrderResvListResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<OverseasStockV1TradingOrderResvListOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingOrderResvListResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 결제기준잔고 [해외주식-064]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePaymtStdrBalanceOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 잔고수량13
+    #[serde(default, rename = "cblc_qty13")]
+    pub cblc_qty13: String,
+    /// 주문가능수량1
+    #[serde(default, rename = "ord_psbl_qty1")]
+    pub ord_psbl_qty1: String,
+    /// 평균단가3
+    #[serde(default, rename = "avg_unpr3")]
+    pub avg_unpr3: Decimal,
+    /// 해외현재가격1
+    #[serde(default, rename = "ovrs_now_pric1")]
+    pub ovrs_now_pric1: String,
+    /// 외화매입금액
+    #[serde(default, rename = "frcr_pchs_amt")]
+    pub frcr_pchs_amt: Decimal,
+    /// 외화평가금액2
+    #[serde(default, rename = "frcr_evlu_amt2")]
+    pub frcr_evlu_amt2: Decimal,
+    /// 평가손익금액2
+    #[serde(default, rename = "evlu_pfls_amt2")]
+    pub evlu_pfls_amt2: Decimal,
+    /// 기준환율
+    #[serde(default, rename = "bass_exrt")]
+    pub bass_exrt: String,
+    /// 조작상세일시
+    #[serde(default, rename = "oprt_dtl_dtime")]
+    pub oprt_dtl_dtime: String,
+    /// 매수통화코드
+    #[serde(default, rename = "buy_crcy_cd")]
+    pub buy_crcy_cd: String,
+    /// 당일매도체결수량1
+    #[serde(default, rename = "thdt_sll_ccld_qty1")]
+    pub thdt_sll_ccld_qty1: String,
+    /// 당일매수체결수량1
+    #[serde(default, rename = "thdt_buy_ccld_qty1")]
+    pub thdt_buy_ccld_qty1: String,
+    /// 평가손익율1
+    #[serde(default, rename = "evlu_pfls_rt1")]
+    pub evlu_pfls_rt1: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 국가한글명
+    #[serde(default, rename = "natn_kor_name")]
+    pub natn_kor_name: String,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 담보수량
+    #[serde(default, rename = "mgge_qty")]
+    pub mgge_qty: Decimal,
+    /// 대출잔액
+    #[serde(default, rename = "loan_rmnd")]
+    pub loan_rmnd: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 유가증권구분명
+    #[serde(default, rename = "scts_dvsn_name")]
+    pub scts_dvsn_name: String,
+    /// 대여잔고수량
+    #[serde(default, rename = "ldng_cblc_qty")]
+    pub ldng_cblc_qty: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 결제기준잔고 [해외주식-064]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePaymtStdrBalanceOutput2Item {
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 통화코드명
+    #[serde(default, rename = "crcy_cd_name")]
+    pub crcy_cd_name: String,
+    /// 외화예수금액2
+    #[serde(default, rename = "frcr_dncl_amt_2")]
+    pub frcr_dncl_amt_2: String,
+    /// 최초고시환율
+    #[serde(default, rename = "frst_bltn_exrt")]
+    pub frst_bltn_exrt: String,
+    /// 외화평가금액2
+    #[serde(default, rename = "frcr_evlu_amt2")]
+    pub frcr_evlu_amt2: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 결제기준잔고 [해외주식-064]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePaymtStdrBalanceOutput3Item {
+    /// 매입금액합계금액
+    #[serde(default, rename = "pchs_amt_smtl_amt")]
+    pub pchs_amt_smtl_amt: Decimal,
+    /// 총평가손익금액
+    #[serde(default, rename = "tot_evlu_pfls_amt")]
+    pub tot_evlu_pfls_amt: Decimal,
+    /// 평가수익율1
+    #[serde(default, rename = "evlu_erng_rt1")]
+    pub evlu_erng_rt1: String,
+    /// 총예수금액
+    #[serde(default, rename = "tot_dncl_amt")]
+    pub tot_dncl_amt: Decimal,
+    /// 원화평가금액합계
+    #[serde(default, rename = "wcrc_evlu_amt_smtl")]
+    pub wcrc_evlu_amt_smtl: String,
+    /// 총자산금액2
+    #[serde(default, rename = "tot_asst_amt2")]
+    pub tot_asst_amt2: Decimal,
+    /// 외화잔고원화평가금액합계
+    #[serde(default, rename = "frcr_cblc_wcrc_evlu_amt_smtl")]
+    pub frcr_cblc_wcrc_evlu_amt_smtl: String,
+    /// 총대출금액
+    #[serde(default, rename = "tot_loan_amt")]
+    pub tot_loan_amt: Decimal,
+    /// 총대여평가금액
+    #[serde(default, rename = "tot_ldng_evlu_amt")]
+    pub tot_ldng_evlu_amt: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 결제기준잔고 [해외주식-064]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquirePaymtStdrBalanceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1TradingInquirePaymtStdrBalanceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1TradingInquirePaymtStdrBalanceOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<OverseasStockV1TradingInquirePaymtStdrBalanceOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquirePaymtStdrBalanceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 일별거래내역 [해외주식-063]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodTransOutput1Item {
+    /// 매매일자
+    #[serde(default, rename = "trad_dt")]
+    pub trad_dt: String,
+    /// 결제일자
+    #[serde(default, rename = "sttl_dt")]
+    pub sttl_dt: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: String,
+    /// 매도매수구분명
+    #[serde(default, rename = "sll_buy_dvsn_name")]
+    pub sll_buy_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 해외종목명
+    #[serde(default, rename = "ovrs_item_name")]
+    pub ovrs_item_name: String,
+    /// 체결수량
+    #[serde(default, rename = "ccld_qty")]
+    pub ccld_qty: Decimal,
+    /// 금액단위체결수량
+    #[serde(default, rename = "amt_unit_ccld_qty")]
+    pub amt_unit_ccld_qty: Decimal,
+    /// FT체결단가2
+    #[serde(default, rename = "ft_ccld_unpr2")]
+    pub ft_ccld_unpr2: Decimal,
+    /// 해외주식체결단가
+    #[serde(default, rename = "ovrs_stck_ccld_unpr")]
+    pub ovrs_stck_ccld_unpr: Decimal,
+    /// 거래외화금액2
+    #[serde(default, rename = "tr_frcr_amt2")]
+    pub tr_frcr_amt2: Decimal,
+    /// 거래금액
+    #[serde(default, rename = "tr_amt")]
+    pub tr_amt: Decimal,
+    /// 외화정산금액1
+    #[serde(default, rename = "frcr_excc_amt_1")]
+    pub frcr_excc_amt_1: String,
+    /// 원화정산금액
+    #[serde(default, rename = "wcrc_excc_amt")]
+    pub wcrc_excc_amt: Decimal,
+    /// 국내외화수수료1
+    #[serde(default, rename = "dmst_frcr_fee1")]
+    pub dmst_frcr_fee1: String,
+    /// 외화수수료1
+    #[serde(default, rename = "frcr_fee1")]
+    pub frcr_fee1: String,
+    /// 국내원화수수료
+    #[serde(default, rename = "dmst_wcrc_fee")]
+    pub dmst_wcrc_fee: String,
+    /// 해외원화수수료
+    #[serde(default, rename = "ovrs_wcrc_fee")]
+    pub ovrs_wcrc_fee: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 등록환율
+    #[serde(default, rename = "erlm_exrt")]
+    pub erlm_exrt: String,
+    /// 대출구분코드
+    #[serde(default, rename = "loan_dvsn_cd")]
+    pub loan_dvsn_cd: String,
+    /// 대출구분명
+    #[serde(default, rename = "loan_dvsn_name")]
+    pub loan_dvsn_name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 일별거래내역 [해외주식-063]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodTransOutput2Item {
+    /// 외화매수금액합계
+    #[serde(default, rename = "frcr_buy_amt_smtl")]
+    pub frcr_buy_amt_smtl: String,
+    /// 외화매도금액합계
+    #[serde(default, rename = "frcr_sll_amt_smtl")]
+    pub frcr_sll_amt_smtl: String,
+    /// 국내수수료합계
+    #[serde(default, rename = "dmst_fee_smtl")]
+    pub dmst_fee_smtl: String,
+    /// 해외수수료합계
+    #[serde(default, rename = "ovrs_fee_smtl")]
+    pub ovrs_fee_smtl: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 일별거래내역 [해외주식-063]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquirePeriodTransResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건100
+    #[serde(default, rename = "ctx_area_fk100")]
+    pub ctx_area_fk100: String,
+    /// 연속조회키100
+    #[serde(default, rename = "ctx_area_nk100")]
+    pub ctx_area_nk100: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1TradingInquirePeriodTransOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1TradingInquirePeriodTransOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquirePeriodTransResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 기간손익[v1_해외주식-032]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodProfitOutput1Item {
+    /// 매매일
+    #[serde(default, rename = "trad_day")]
+    pub trad_day: String,
+    /// 해외상품번호
+    #[serde(default, rename = "ovrs_pdno")]
+    pub ovrs_pdno: String,
+    /// 해외종목명
+    #[serde(default, rename = "ovrs_item_name")]
+    pub ovrs_item_name: String,
+    /// 매도청산수량
+    #[serde(default, rename = "slcl_qty")]
+    pub slcl_qty: Decimal,
+    /// 매입평균가격
+    #[serde(default, rename = "pchs_avg_pric")]
+    pub pchs_avg_pric: Decimal,
+    /// 외화매입금액1
+    #[serde(default, rename = "frcr_pchs_amt1")]
+    pub frcr_pchs_amt1: Decimal,
+    /// 평균매도단가
+    #[serde(default, rename = "avg_sll_unpr")]
+    pub avg_sll_unpr: Decimal,
+    /// 외화매도금액합계1
+    #[serde(default, rename = "frcr_sll_amt_smtl1")]
+    pub frcr_sll_amt_smtl1: String,
+    /// 주식매도제비용
+    #[serde(default, rename = "stck_sll_tlex")]
+    pub stck_sll_tlex: String,
+    /// 해외실현손익금액
+    #[serde(default, rename = "ovrs_rlzt_pfls_amt")]
+    pub ovrs_rlzt_pfls_amt: Decimal,
+    /// 수익률
+    #[serde(default, rename = "pftrt")]
+    pub pftrt: String,
+    /// 환율
+    #[serde(default, rename = "exrt")]
+    pub exrt: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 최초고시환율
+    #[serde(default, rename = "frst_bltn_exrt")]
+    pub frst_bltn_exrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 기간손익[v1_해외주식-032]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquirePeriodProfitOutput2Item {
+    /// 주식매도금액합계
+    #[serde(default, rename = "stck_sll_amt_smtl")]
+    pub stck_sll_amt_smtl: String,
+    /// 주식매수금액합계
+    #[serde(default, rename = "stck_buy_amt_smtl")]
+    pub stck_buy_amt_smtl: String,
+    /// 합계수수료1
+    #[serde(default, rename = "smtl_fee1")]
+    pub smtl_fee1: String,
+    /// 정산지급금액
+    #[serde(default, rename = "excc_dfrm_amt")]
+    pub excc_dfrm_amt: Decimal,
+    /// 해외실현손익총금액
+    #[serde(default, rename = "ovrs_rlzt_pfls_tot_amt")]
+    pub ovrs_rlzt_pfls_tot_amt: Decimal,
+    /// 총수익률
+    #[serde(default, rename = "tot_pftrt")]
+    pub tot_pftrt: String,
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 환율
+    #[serde(default, rename = "exrt")]
+    pub exrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 기간손익[v1_해외주식-032]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquirePeriodProfitResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1TradingInquirePeriodProfitOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1TradingInquirePeriodProfitOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquirePeriodProfitResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외증거금 통화별조회 [해외주식-035]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingForeignMarginOutputItem {
+    /// 국가명           
+    #[serde(default, rename = "natn_name")]
+    pub natn_name: String,
+    /// 통화코드          
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 외화예수금액       
+    #[serde(default, rename = "frcr_dncl_amt1")]
+    pub frcr_dncl_amt1: Decimal,
+    /// 미결제매수금액      
+    #[serde(default, rename = "ustl_buy_amt")]
+    pub ustl_buy_amt: Decimal,
+    /// 미결제매도금액      
+    #[serde(default, rename = "ustl_sll_amt")]
+    pub ustl_sll_amt: Decimal,
+    /// 외화미수금액       
+    #[serde(default, rename = "frcr_rcvb_amt")]
+    pub frcr_rcvb_amt: Decimal,
+    /// 외화증거금액       
+    #[serde(default, rename = "frcr_mgn_amt")]
+    pub frcr_mgn_amt: Decimal,
+    /// 외화일반주문가능금액  
+    #[serde(default, rename = "frcr_gnrl_ord_psbl_amt")]
+    pub frcr_gnrl_ord_psbl_amt: Decimal,
+    /// 외화주문가능금액    
+    #[serde(default, rename = "frcr_ord_psbl_amt1")]
+    pub frcr_ord_psbl_amt1: Decimal,
+    /// 통합주문가능금액    
+    #[serde(default, rename = "itgr_ord_psbl_amt")]
+    pub itgr_ord_psbl_amt: Decimal,
+    /// 기준환율          
+    #[serde(default, rename = "bass_exrt")]
+    pub bass_exrt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외증거금 통화별조회 [해외주식-035]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingForeignMarginResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingForeignMarginOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingForeignMarginResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 미국주간주문[v1_해외주식-026]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingDaytimeOrderOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 미국주간주문[v1_해외주식-026]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingDaytimeOrderResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingDaytimeOrderOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingDaytimeOrderResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 미국주간정정취소[v1_해외주식-027]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingDaytimeOrderRvsecnclOutputItem {
+    /// 한국거래소전송주문조직번호
+    #[serde(default, rename = "KRX_FWDG_ORD_ORGNO")]
+    pub krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 미국주간정정취소[v1_해외주식-027]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingDaytimeOrderRvsecnclResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingDaytimeOrderRvsecnclOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingDaytimeOrderRvsecnclResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 지정가주문번호조회  [해외주식-071]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingAlgoOrdnoOutputItem {
+    /// 주문번호
+    #[serde(default, rename = "odno")]
+    pub odno: String,
+    /// 매매구분명
+    #[serde(default, rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 종목명
+    #[serde(default, rename = "item_name")]
+    pub item_name: String,
+    /// FT주문수량
+    #[serde(default, rename = "ft_ord_qty")]
+    pub ft_ord_qty: Decimal,
+    /// FT주문단가
+    #[serde(default, rename = "ft_ord_unpr3")]
+    pub ft_ord_unpr3: Decimal,
+    /// 분할매수속성명
+    #[serde(default, rename = "splt_buy_attr_name")]
+    pub splt_buy_attr_name: String,
+    /// FT체결수량
+    #[serde(default, rename = "ft_ccld_qty")]
+    pub ft_ccld_qty: Decimal,
+    /// 주문채번지점번호
+    #[serde(default, rename = "ord_gno_brno")]
+    pub ord_gno_brno: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 지정가주문번호조회  [해외주식-071]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingAlgoOrdnoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 연속조회검색조건200
+    #[serde(default, rename = "ctx_area_fk200")]
+    pub ctx_area_fk200: String,
+    /// 연속조회키200
+    #[serde(default, rename = "ctx_area_nk200")]
+    pub ctx_area_nk200: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<OverseasStockV1TradingAlgoOrdnoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingAlgoOrdnoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 지정가체결내역조회 [해외주식-070]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireAlgoCcnlOutputItem {
+    /// 체결순번
+    #[serde(default, rename = "CCLD_SEQ")]
+    pub ccld_seq: String,
+    /// 체결시간
+    #[serde(default, rename = "CCLD_BTWN")]
+    pub ccld_btwn: String,
+    /// 상품번호
+    #[serde(default, rename = "PDNO")]
+    pub pdno: String,
+    /// 종목명
+    #[serde(default, rename = "ITEM_NAME")]
+    pub item_name: String,
+    /// FT체결수량
+    #[serde(default, rename = "FT_CCLD_QTY")]
+    pub ft_ccld_qty: String,
+    /// FT체결단가
+    #[serde(default, rename = "FT_CCLD_UNPR3")]
+    pub ft_ccld_unpr3: String,
+    /// FT체결금액
+    #[serde(default, rename = "FT_CCLD_AMT3")]
+    pub ft_ccld_amt3: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 지정가체결내역조회 [해외주식-070]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1TradingInquireAlgoCcnlOutput3Item {
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 매매구분명
+    #[serde(default, rename = "TRAD_DVSN_NAME")]
+    pub trad_dvsn_name: String,
+    /// 상품번호
+    #[serde(default, rename = "PDNO")]
+    pub pdno: String,
+    /// 종목명
+    #[serde(default, rename = "ITEM_NAME")]
+    pub item_name: String,
+    /// FT주문수량
+    #[serde(default, rename = "FT_ORD_QTY")]
+    pub ft_ord_qty: String,
+    /// FT주문단가
+    #[serde(default, rename = "FT_ORD_UNPR3")]
+    pub ft_ord_unpr3: String,
+    /// 주문시각
+    #[serde(default, rename = "ORD_TMD")]
+    pub ord_tmd: String,
+    /// 분할매수속성명
+    #[serde(default, rename = "SPLT_BUY_ATTR_NAME")]
+    pub splt_buy_attr_name: String,
+    /// FT체결수량
+    #[serde(default, rename = "FT_CCLD_QTY")]
+    pub ft_ccld_qty: String,
+    /// 거래통화
+    #[serde(default, rename = "TR_CRCY")]
+    pub tr_crcy: String,
+    /// FT체결단가
+    #[serde(default, rename = "FT_CCLD_UNPR3")]
+    pub ft_ccld_unpr3: String,
+    /// FT체결금액
+    #[serde(default, rename = "FT_CCLD_AMT3")]
+    pub ft_ccld_amt3: String,
+    /// 체결건수
+    #[serde(default, rename = "CCLD_CNT")]
+    pub ccld_cnt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 지정가체결내역조회 [해외주식-070]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1TradingInquireAlgoCcnlResponse {
+    /// 성공 실패여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메시지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasStockV1TradingInquireAlgoCcnlOutputItem>,
+    #[serde(default)]
+    pub output3: Vec<OverseasStockV1TradingInquireAlgoCcnlOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1TradingInquireAlgoCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 현재가상세[v1_해외주식-029]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPriceDetailOutputItem {
+    /// 실시간조회종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 전일거래량
+    #[serde(default, rename = "pvol")]
+    pub pvol: String,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 전일종가
+    #[serde(default, rename = "base")]
+    pub base: String,
+    /// 시가총액
+    #[serde(default, rename = "tomv")]
+    pub tomv: String,
+    /// 전일거래대금
+    #[serde(default, rename = "pamt")]
+    pub pamt: String,
+    /// 상한가
+    #[serde(default, rename = "uplp")]
+    pub uplp: String,
+    /// 하한가
+    #[serde(default, rename = "dnlp")]
+    pub dnlp: String,
+    /// 52주최고가
+    #[serde(default, rename = "h52p")]
+    pub h52p: String,
+    /// 52주최고일자
+    #[serde(default, rename = "h52d")]
+    pub h52d: String,
+    /// 52주최저가
+    #[serde(default, rename = "l52p")]
+    pub l52p: String,
+    /// 52주최저일자
+    #[serde(default, rename = "l52d")]
+    pub l52d: String,
+    /// PER
+    #[serde(default, rename = "perx")]
+    pub perx: String,
+    /// PBR
+    #[serde(default, rename = "pbrx")]
+    pub pbrx: String,
+    /// EPS
+    #[serde(default, rename = "epsx")]
+    pub epsx: String,
+    /// BPS
+    #[serde(default, rename = "bpsx")]
+    pub bpsx: String,
+    /// 상장주수
+    #[serde(default, rename = "shar")]
+    pub shar: String,
+    /// 자본금
+    #[serde(default, rename = "mcap")]
+    pub mcap: String,
+    /// 통화
+    #[serde(default, rename = "curr")]
+    pub curr: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 매매단위
+    #[serde(default, rename = "vnit")]
+    pub vnit: String,
+    /// 원환산당일가격
+    #[serde(default, rename = "t_xprc")]
+    pub t_xprc: String,
+    /// 원환산당일대비
+    #[serde(default, rename = "t_xdif")]
+    pub t_xdif: String,
+    /// 원환산당일등락
+    #[serde(default, rename = "t_xrat")]
+    pub t_xrat: String,
+    /// 원환산전일가격
+    #[serde(default, rename = "p_xprc")]
+    pub p_xprc: String,
+    /// 원환산전일대비
+    #[serde(default, rename = "p_xdif")]
+    pub p_xdif: String,
+    /// 원환산전일등락
+    #[serde(default, rename = "p_xrat")]
+    pub p_xrat: String,
+    /// 당일환율
+    #[serde(default, rename = "t_rate")]
+    pub t_rate: Decimal,
+    /// 전일환율
+    #[serde(default, rename = "p_rate")]
+    pub p_rate: Decimal,
+    /// 원환산당일기호
+    #[serde(default, rename = "t_xsgn")]
+    pub t_xsgn: String,
+    /// 원환산전일기호
+    #[serde(default, rename = "p_xsng")]
+    pub p_xsng: String,
+    /// 거래가능여부
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 호가단위
+    #[serde(default, rename = "e_hogau")]
+    pub e_hogau: String,
+    /// 업종(섹터)
+    #[serde(default, rename = "e_icod")]
+    pub e_icod: String,
+    /// 액면가
+    #[serde(default, rename = "e_parp")]
+    pub e_parp: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// ETP 분류명
+    #[serde(default, rename = "etyp_nm")]
+    pub etyp_nm: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 현재가상세[v1_해외주식-029]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsPriceDetailResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsPriceDetailOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsPriceDetailResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 현재가 호가 [해외주식-033]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireAskingPriceOutput1Item {
+    /// 실시간조회종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 통화
+    #[serde(default, rename = "curr")]
+    pub curr: String,
+    /// 전일종가
+    #[serde(default, rename = "base")]
+    pub base: String,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 호가일자
+    #[serde(default, rename = "dymd")]
+    pub dymd: String,
+    /// 호가시간
+    #[serde(default, rename = "dhms")]
+    pub dhms: String,
+    /// 매수호가총잔량
+    #[serde(default, rename = "bvol")]
+    pub bvol: String,
+    /// 매도호가총잔량
+    #[serde(default, rename = "avol")]
+    pub avol: String,
+    /// 매수호가총잔량대비
+    #[serde(default, rename = "bdvl")]
+    pub bdvl: String,
+    /// 매도호가총잔량대비
+    #[serde(default, rename = "advl")]
+    pub advl: String,
+    /// 종목코드
+    #[serde(default, rename = "code")]
+    pub code: String,
+    /// 시가율
+    #[serde(default, rename = "ropen")]
+    pub ropen: String,
+    /// 고가율
+    #[serde(default, rename = "rhigh")]
+    pub rhigh: String,
+    /// 저가율
+    #[serde(default, rename = "rlow")]
+    pub rlow: String,
+    /// 현재가율
+    #[serde(default, rename = "rclose")]
+    pub rclose: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 현재가 호가 [해외주식-033]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireAskingPriceOutput2Item {
+    /// 매수호가가격1
+    #[serde(default, rename = "pbid1")]
+    pub pbid1: String,
+    /// 매도호가가격1
+    #[serde(default, rename = "pask1")]
+    pub pask1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "vbid1")]
+    pub vbid1: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "vask1")]
+    pub vask1: String,
+    /// 매수호가대비1
+    #[serde(default, rename = "dbid1")]
+    pub dbid1: String,
+    /// 매도호가대비1
+    #[serde(default, rename = "dask1")]
+    pub dask1: String,
+    /// 매수호가가격2
+    #[serde(default, rename = "pbid2")]
+    pub pbid2: String,
+    /// 매도호가가격2
+    #[serde(default, rename = "pask2")]
+    pub pask2: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "vbid2")]
+    pub vbid2: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "vask2")]
+    pub vask2: String,
+    /// 매수호가대비2
+    #[serde(default, rename = "dbid2")]
+    pub dbid2: String,
+    /// 매도호가대비2
+    #[serde(default, rename = "dask2")]
+    pub dask2: String,
+    /// 매수호가가격3
+    #[serde(default, rename = "pbid3")]
+    pub pbid3: String,
+    /// 매도호가가격3
+    #[serde(default, rename = "pask3")]
+    pub pask3: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "vbid3")]
+    pub vbid3: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "vask3")]
+    pub vask3: String,
+    /// 매수호가대비3
+    #[serde(default, rename = "dbid3")]
+    pub dbid3: String,
+    /// 매도호가대비3
+    #[serde(default, rename = "dask3")]
+    pub dask3: String,
+    /// 매수호가가격4
+    #[serde(default, rename = "pbid4")]
+    pub pbid4: String,
+    /// 매도호가가격4
+    #[serde(default, rename = "pask4")]
+    pub pask4: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "vbid4")]
+    pub vbid4: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "vask4")]
+    pub vask4: String,
+    /// 매수호가대비4
+    #[serde(default, rename = "dbid4")]
+    pub dbid4: String,
+    /// 매도호가대비4
+    #[serde(default, rename = "dask4")]
+    pub dask4: String,
+    /// 매수호가가격5
+    #[serde(default, rename = "pbid5")]
+    pub pbid5: String,
+    /// 매도호가가격5
+    #[serde(default, rename = "pask5")]
+    pub pask5: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "vbid5")]
+    pub vbid5: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "vask5")]
+    pub vask5: String,
+    /// 매수호가대비5
+    #[serde(default, rename = "dbid5")]
+    pub dbid5: String,
+    /// 매도호가대비5
+    #[serde(default, rename = "dask5")]
+    pub dask5: String,
+    /// 매수호가가격6
+    #[serde(default, rename = "pbid6")]
+    pub pbid6: String,
+    /// 매도호가가격6
+    #[serde(default, rename = "pask6")]
+    pub pask6: String,
+    /// 매수호가잔량6
+    #[serde(default, rename = "vbid6")]
+    pub vbid6: String,
+    /// 매도호가잔량6
+    #[serde(default, rename = "vask6")]
+    pub vask6: String,
+    /// 매수호가대비6
+    #[serde(default, rename = "dbid6")]
+    pub dbid6: String,
+    /// 매도호가대비6
+    #[serde(default, rename = "dask6")]
+    pub dask6: String,
+    /// 매수호가가격7
+    #[serde(default, rename = "pbid7")]
+    pub pbid7: String,
+    /// 매도호가가격7
+    #[serde(default, rename = "pask7")]
+    pub pask7: String,
+    /// 매수호가잔량7
+    #[serde(default, rename = "vbid7")]
+    pub vbid7: String,
+    /// 매도호가잔량7
+    #[serde(default, rename = "vask7")]
+    pub vask7: String,
+    /// 매수호가대비7
+    #[serde(default, rename = "dbid7")]
+    pub dbid7: String,
+    /// 매도호가대비7
+    #[serde(default, rename = "dask7")]
+    pub dask7: String,
+    /// 매수호가가격8
+    #[serde(default, rename = "pbid8")]
+    pub pbid8: String,
+    /// 매도호가가격8
+    #[serde(default, rename = "pask8")]
+    pub pask8: String,
+    /// 매수호가잔량8
+    #[serde(default, rename = "vbid8")]
+    pub vbid8: String,
+    /// 매도호가잔량8
+    #[serde(default, rename = "vask8")]
+    pub vask8: String,
+    /// 매수호가대비8
+    #[serde(default, rename = "dbid8")]
+    pub dbid8: String,
+    /// 매도호가대비8
+    #[serde(default, rename = "dask8")]
+    pub dask8: String,
+    /// 매수호가가격9
+    #[serde(default, rename = "pbid9")]
+    pub pbid9: String,
+    /// 매도호가가격9
+    #[serde(default, rename = "pask9")]
+    pub pask9: String,
+    /// 매수호가잔량9
+    #[serde(default, rename = "vbid9")]
+    pub vbid9: String,
+    /// 매도호가잔량9
+    #[serde(default, rename = "vask9")]
+    pub vask9: String,
+    /// 매수호가대비9
+    #[serde(default, rename = "dbid9")]
+    pub dbid9: String,
+    /// 매도호가대비9
+    #[serde(default, rename = "dask9")]
+    pub dask9: String,
+    /// 매수호가가격10
+    #[serde(default, rename = "pbid10")]
+    pub pbid10: String,
+    /// 매도호가가격10
+    #[serde(default, rename = "pask10")]
+    pub pask10: String,
+    /// 매수호가잔량10
+    #[serde(default, rename = "vbid10")]
+    pub vbid10: String,
+    /// 매도호가잔량10
+    #[serde(default, rename = "vask10")]
+    pub vask10: String,
+    /// 매수호가대비10
+    #[serde(default, rename = "dbid10")]
+    pub dbid10: String,
+    /// 매도호가대비10
+    #[serde(default, rename = "dask10")]
+    pub dask10: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 현재가 호가 [해외주식-033]] output3 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireAskingPriceOutput3Item {
+    /// VCMStart시간
+    #[serde(default, rename = "vstm")]
+    pub vstm: String,
+    /// VCMEnd시간
+    #[serde(default, rename = "vetm")]
+    pub vetm: String,
+    /// CAS/VCM기준가
+    #[serde(default, rename = "csbp")]
+    pub csbp: String,
+    /// CAS/VCMHighprice
+    #[serde(default, rename = "cshi")]
+    pub cshi: String,
+    /// CAS/VCMLowprice
+    #[serde(default, rename = "cslo")]
+    pub cslo: String,
+    /// IEP
+    #[serde(default, rename = "iep")]
+    pub iep: String,
+    /// IEV
+    #[serde(default, rename = "iev")]
+    pub iev: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 현재가 호가 [해외주식-033]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireAskingPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireAskingPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireAskingPriceOutput2Item>,
+    #[serde(default)]
+    pub output3: Vec<OverseasPriceV1QuotationsInquireAskingPriceOutput3Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireAskingPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output3 {
+            fields.extend(item.extra.keys().map(|k| format!("output3.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 현재체결가[v1_해외주식-009]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPriceOutputItem {
+    /// 실시간조회종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 전일종가
+    #[serde(default, rename = "base")]
+    pub base: String,
+    /// 전일거래량
+    #[serde(default, rename = "pvol")]
+    pub pvol: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 대비기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// 매수가능여부
+    #[serde(default, rename = "ordy")]
+    pub ordy: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 현재체결가[v1_해외주식-009]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsPriceOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 체결추이[해외주식-037]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireCcnlOutput2Item {
+    /// 한국기준시간
+    #[serde(default, rename = "khms")]
+    pub khms: String,
+    /// 체결가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 체결량
+    #[serde(default, rename = "evol")]
+    pub evol: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 시장구분
+    #[serde(default, rename = "mtyp")]
+    pub mtyp: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 체결강도
+    #[serde(default, rename = "vpow")]
+    pub vpow: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 체결추이[해외주식-037]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireCcnlOutput1Item {
+    /// 실시간조회종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 소수점자리수
+    #[serde(default, rename = "ZDIV")]
+    pub zdiv: String,
+    ///   Record Count
+    #[serde(default, rename = "NREC")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 체결추이[해외주식-037]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireCcnlResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireCcnlOutput2Item>,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireCcnlOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireCcnlResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식분봉조회[v1_해외주식-030]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeItemchartpriceOutput1Item {
+    /// 실시간종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 장시작현지시간
+    #[serde(default, rename = "stim")]
+    pub stim: String,
+    /// 장종료현지시간
+    #[serde(default, rename = "etim")]
+    pub etim: String,
+    /// 장시작한국시간
+    #[serde(default, rename = "sktm")]
+    pub sktm: String,
+    /// 장종료한국시간
+    #[serde(default, rename = "ektm")]
+    pub ektm: String,
+    /// 다음가능여부
+    #[serde(default, rename = "next")]
+    pub next: String,
+    /// 추가데이타여부
+    #[serde(default, rename = "more")]
+    pub more: String,
+    /// 레코드갯수
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식분봉조회[v1_해외주식-030]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeItemchartpriceOutput2Item {
+    /// 현지영업일자
+    #[serde(default, rename = "tymd")]
+    pub tymd: String,
+    /// 현지기준일자
+    #[serde(default, rename = "xymd")]
+    pub xymd: String,
+    /// 현지기준시간
+    #[serde(default, rename = "xhms")]
+    pub xhms: String,
+    /// 한국기준일자
+    #[serde(default, rename = "kymd")]
+    pub kymd: String,
+    /// 한국기준시간
+    #[serde(default, rename = "khms")]
+    pub khms: String,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// 종가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 체결량
+    #[serde(default, rename = "evol")]
+    pub evol: String,
+    /// 체결대금
+    #[serde(default, rename = "eamt")]
+    pub eamt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식분봉조회[v1_해외주식-030]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireTimeItemchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireTimeItemchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외지수분봉조회[v1_해외주식-031]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeIndexchartpriceOutput1Item {
+    /// 해외 지수 전일 대비
+    #[serde(default, rename = "ovrs_nmix_prdy_vrss")]
+    pub ovrs_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 해외 지수 전일 종가
+    #[serde(default, rename = "ovrs_nmix_prdy_clpr")]
+    pub ovrs_nmix_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 해외 지수 현재가
+    #[serde(default, rename = "ovrs_nmix_prpr")]
+    pub ovrs_nmix_prpr: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 해외 상품 시가2
+    #[serde(default, rename = "ovrs_prod_oprc")]
+    pub ovrs_prod_oprc: String,
+    /// 해외 상품 최고가
+    #[serde(default, rename = "ovrs_prod_hgpr")]
+    pub ovrs_prod_hgpr: String,
+    /// 해외 상품 최저가
+    #[serde(default, rename = "ovrs_prod_lwpr")]
+    pub ovrs_prod_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외지수분봉조회[v1_해외주식-031]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireTimeIndexchartpriceOutput2Item {
+    /// 주식 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "stck_cntg_hour")]
+    pub stck_cntg_hour: String,
+    /// 옵션 현재가
+    #[serde(default, rename = "optn_prpr")]
+    pub optn_prpr: String,
+    /// 옵션 시가2
+    #[serde(default, rename = "optn_oprc")]
+    pub optn_oprc: String,
+    /// 옵션 최고가
+    #[serde(default, rename = "optn_hgpr")]
+    pub optn_hgpr: String,
+    /// 옵션 최저가
+    #[serde(default, rename = "optn_lwpr")]
+    pub optn_lwpr: String,
+    /// 체결 거래량
+    #[serde(default, rename = "cntg_vol")]
+    pub cntg_vol: Decimal,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외지수분봉조회[v1_해외주식-031]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireTimeIndexchartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireTimeIndexchartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireTimeIndexchartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireTimeIndexchartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 기간별시세[v1_해외주식-010]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsDailypriceOutput1Item {
+    /// 실시간조회종목코드
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 전일종가
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 기간별시세[v1_해외주식-010]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsDailypriceOutput2Item {
+    /// 일자(YYYYMMDD)
+    #[serde(default, rename = "xymd")]
+    pub xymd: String,
+    /// 종가
+    #[serde(default, rename = "clos")]
+    pub clos: String,
+    /// 대비기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 매수호가잔량
+    #[serde(default, rename = "vbid")]
+    pub vbid: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매도호가잔량
+    #[serde(default, rename = "vask")]
+    pub vask: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 기간별시세[v1_해외주식-010]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsDailypriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsDailypriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsDailypriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsDailypriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 종목/지수/환율기간별시세(일/주/월/년)[v1_해외주식-012]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireDailyChartpriceOutput1Item {
+    ///  전일 대비
+    #[serde(default, rename = "ovrs_nmix_prdy_vrss")]
+    pub ovrs_nmix_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 전일 종가
+    #[serde(default, rename = "ovrs_nmix_prdy_clpr")]
+    pub ovrs_nmix_prdy_clpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// HTS 한글 종목명
+    #[serde(default, rename = "hts_kor_isnm")]
+    pub hts_kor_isnm: String,
+    /// 현재가
+    #[serde(default, rename = "ovrs_nmix_prpr")]
+    pub ovrs_nmix_prpr: String,
+    /// 단축 종목코드
+    #[serde(default, rename = "stck_shrn_iscd")]
+    pub stck_shrn_iscd: String,
+    /// 전일 거래량
+    #[serde(default, rename = "prdy_vol")]
+    pub prdy_vol: Decimal,
+    /// 시가
+    #[serde(default, rename = "ovrs_prod_oprc")]
+    pub ovrs_prod_oprc: String,
+    /// 최고가
+    #[serde(default, rename = "ovrs_prod_hgpr")]
+    pub ovrs_prod_hgpr: String,
+    /// 최저가
+    #[serde(default, rename = "ovrs_prod_lwpr")]
+    pub ovrs_prod_lwpr: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 종목/지수/환율기간별시세(일/주/월/년)[v1_해외주식-012]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireDailyChartpriceOutput2Item {
+    /// 영업 일자
+    #[serde(default, rename = "stck_bsop_date")]
+    pub stck_bsop_date: String,
+    /// 현재가
+    #[serde(default, rename = "ovrs_nmix_prpr")]
+    pub ovrs_nmix_prpr: String,
+    /// 시가
+    #[serde(default, rename = "ovrs_nmix_oprc")]
+    pub ovrs_nmix_oprc: String,
+    /// 최고가
+    #[serde(default, rename = "ovrs_nmix_hgpr")]
+    pub ovrs_nmix_hgpr: String,
+    /// 최저가
+    #[serde(default, rename = "ovrs_nmix_lwpr")]
+    pub ovrs_nmix_lwpr: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: Decimal,
+    /// 변경 여부
+    #[serde(default, rename = "mod_yn")]
+    pub mod_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 종목/지수/환율기간별시세(일/주/월/년)[v1_해외주식-012]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireDailyChartpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireDailyChartpriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireDailyChartpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireDailyChartpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식조건검색[v1_해외주식-015]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireSearchOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// Record Count
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식조건검색[v1_해외주식-015]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsInquireSearchOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 발행주식
+    #[serde(default, rename = "shar")]
+    pub shar: String,
+    /// 시가총액
+    #[serde(default, rename = "valx")]
+    pub valx: String,
+    /// 저가
+    #[serde(default, rename = "plow")]
+    pub plow: String,
+    /// 고가
+    #[serde(default, rename = "phigh")]
+    pub phigh: String,
+    /// 시가
+    #[serde(default, rename = "popen")]
+    pub popen: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 거래대금
+    #[serde(default, rename = "avol")]
+    pub avol: String,
+    /// EPS
+    #[serde(default, rename = "eps")]
+    pub eps: String,
+    /// PER
+    #[serde(default, rename = "per")]
+    pub per: String,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식조건검색[v1_해외주식-015]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsInquireSearchResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsInquireSearchOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsInquireSearchOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsInquireSearchResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외결제일자조회[해외주식-017]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1QuotationsCountriesHolidayOutputItem {
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 거래국가코드
+    #[serde(default, rename = "tr_natn_cd")]
+    pub tr_natn_cd: String,
+    /// 거래국가명
+    #[serde(default, rename = "tr_natn_name")]
+    pub tr_natn_name: String,
+    /// 국가영문약어코드
+    #[serde(default, rename = "natn_eng_abrv_cd")]
+    pub natn_eng_abrv_cd: String,
+    /// 거래시장코드
+    #[serde(default, rename = "tr_mket_cd")]
+    pub tr_mket_cd: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 현지결제일자
+    #[serde(default, rename = "acpl_sttl_dt")]
+    pub acpl_sttl_dt: String,
+    /// 국내결제일자
+    #[serde(default, rename = "dmst_sttl_dt")]
+    pub dmst_sttl_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외결제일자조회[해외주식-017]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1QuotationsCountriesHolidayResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default, deserialize_with = "crate::models::vec_or_single")]
+    pub output: Vec<OverseasStockV1QuotationsCountriesHolidayOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1QuotationsCountriesHolidayResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 상품기본정보[v1_해외주식-034]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsSearchInfoOutputItem {
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 상품영문명
+    #[serde(default, rename = "prdt_eng_name")]
+    pub prdt_eng_name: String,
+    /// 국가코드
+    #[serde(default, rename = "natn_cd")]
+    pub natn_cd: String,
+    /// 국가명
+    #[serde(default, rename = "natn_name")]
+    pub natn_name: String,
+    /// 거래시장코드
+    #[serde(default, rename = "tr_mket_cd")]
+    pub tr_mket_cd: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 해외거래소명
+    #[serde(default, rename = "ovrs_excg_name")]
+    pub ovrs_excg_name: String,
+    /// 거래통화코드
+    #[serde(default, rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: String,
+    /// 해외액면가
+    #[serde(default, rename = "ovrs_papr")]
+    pub ovrs_papr: String,
+    /// 통화명
+    #[serde(default, rename = "crcy_name")]
+    pub crcy_name: String,
+    /// 해외주식구분코드
+    #[serde(default, rename = "ovrs_stck_dvsn_cd")]
+    pub ovrs_stck_dvsn_cd: String,
+    /// 상품분류코드
+    #[serde(default, rename = "prdt_clsf_cd")]
+    pub prdt_clsf_cd: String,
+    /// 상품분류명
+    #[serde(default, rename = "prdt_clsf_name")]
+    pub prdt_clsf_name: String,
+    /// 매도단위수량
+    #[serde(default, rename = "sll_unit_qty")]
+    pub sll_unit_qty: Decimal,
+    /// 매수단위수량
+    #[serde(default, rename = "buy_unit_qty")]
+    pub buy_unit_qty: Decimal,
+    /// 거래단위금액
+    #[serde(default, rename = "tr_unit_amt")]
+    pub tr_unit_amt: Decimal,
+    /// 상장주식수
+    #[serde(default, rename = "lstg_stck_num")]
+    pub lstg_stck_num: String,
+    /// 상장일자
+    #[serde(default, rename = "lstg_dt")]
+    pub lstg_dt: String,
+    /// 해외주식거래정지구분코드
+    #[serde(default, rename = "ovrs_stck_tr_stop_dvsn_cd")]
+    pub ovrs_stck_tr_stop_dvsn_cd: String,
+    /// 상장폐지종목여부
+    #[serde(default, rename = "lstg_abol_item_yn")]
+    pub lstg_abol_item_yn: String,
+    /// 해외주식상품그룹번호
+    #[serde(default, rename = "ovrs_stck_prdt_grp_no")]
+    pub ovrs_stck_prdt_grp_no: String,
+    /// 상장여부
+    #[serde(default, rename = "lstg_yn")]
+    pub lstg_yn: String,
+    /// 세금징수여부
+    #[serde(default, rename = "tax_levy_yn")]
+    pub tax_levy_yn: String,
+    /// 해외주식등록사유코드
+    #[serde(default, rename = "ovrs_stck_erlm_rosn_cd")]
+    pub ovrs_stck_erlm_rosn_cd: String,
+    /// 해외주식이력권리구분코드
+    #[serde(default, rename = "ovrs_stck_hist_rght_dvsn_cd")]
+    pub ovrs_stck_hist_rght_dvsn_cd: String,
+    /// 변경전상품번호
+    #[serde(default, rename = "chng_bf_pdno")]
+    pub chng_bf_pdno: String,
+    /// 상품유형코드2
+    #[serde(default, rename = "prdt_type_cd_2")]
+    pub prdt_type_cd_2: String,
+    /// 해외종목명
+    #[serde(default, rename = "ovrs_item_name")]
+    pub ovrs_item_name: String,
+    /// SEDOL번호
+    #[serde(default, rename = "sedol_no")]
+    pub sedol_no: String,
+    /// 블름버그티커내용
+    #[serde(default, rename = "blbg_tckr_text")]
+    pub blbg_tckr_text: String,
+    /// 해외주식ETF위험지표코드
+    #[serde(default, rename = "ovrs_stck_etf_risk_drtp_cd")]
+    pub ovrs_stck_etf_risk_drtp_cd: String,
+    /// ETP추적수익율배수
+    #[serde(default, rename = "etp_chas_erng_rt_dbnb")]
+    pub etp_chas_erng_rt_dbnb: String,
+    /// 기관용도ISIN코드
+    #[serde(default, rename = "istt_usge_isin_cd")]
+    pub istt_usge_isin_cd: String,
+    /// MINT서비스여부
+    #[serde(default, rename = "mint_svc_yn")]
+    pub mint_svc_yn: String,
+    /// MINT서비스여부변경일자
+    #[serde(default, rename = "mint_svc_yn_chng_dt")]
+    pub mint_svc_yn_chng_dt: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// LEI코드
+    #[serde(default, rename = "lei_cd")]
+    pub lei_cd: String,
+    /// 해외주식정지사유코드
+    #[serde(default, rename = "ovrs_stck_stop_rson_cd")]
+    pub ovrs_stck_stop_rson_cd: String,
+    /// 상장폐지일자
+    #[serde(default, rename = "lstg_abol_dt")]
+    pub lstg_abol_dt: String,
+    /// 미니스탁거래상태구분코드
+    #[serde(default, rename = "mini_stk_tr_stat_dvsn_cd")]
+    pub mini_stk_tr_stat_dvsn_cd: String,
+    /// MINT최초서비스등록일자
+    #[serde(default, rename = "mint_frst_svc_erlm_dt")]
+    pub mint_frst_svc_erlm_dt: String,
+    /// MINT소수점매매가능여부
+    #[serde(default, rename = "mint_dcpt_trad_psbl_yn")]
+    pub mint_dcpt_trad_psbl_yn: String,
+    /// MINT정수매매가능여부
+    #[serde(default, rename = "mint_fnum_trad_psbl_yn")]
+    pub mint_fnum_trad_psbl_yn: String,
+    /// MINT잔고전환불가여부
+    #[serde(default, rename = "mint_cblc_cvsn_ipsb_yn")]
+    pub mint_cblc_cvsn_ipsb_yn: String,
+    /// PTP종목여부
+    #[serde(default, rename = "ptp_item_yn")]
+    pub ptp_item_yn: String,
+    /// PTP종목양도세면제여부
+    #[serde(default, rename = "ptp_item_trfx_exmt_yn")]
+    pub ptp_item_trfx_exmt_yn: String,
+    /// PTP종목양도세면제시작일자
+    #[serde(default, rename = "ptp_item_trfx_exmt_strt_dt")]
+    pub ptp_item_trfx_exmt_strt_dt: String,
+    /// PTP종목양도세면제종료일자
+    #[serde(default, rename = "ptp_item_trfx_exmt_end_dt")]
+    pub ptp_item_trfx_exmt_end_dt: String,
+    /// 주간거래가능여부
+    #[serde(default, rename = "dtm_tr_psbl_yn")]
+    pub dtm_tr_psbl_yn: String,
+    /// 급등락정지제외여부
+    #[serde(default, rename = "sdrf_stop_ecls_yn")]
+    pub sdrf_stop_ecls_yn: String,
+    /// 급등락정지제외등록일자
+    #[serde(default, rename = "sdrf_stop_ecls_erlm_dt")]
+    pub sdrf_stop_ecls_erlm_dt: String,
+    /// 메모내용1
+    #[serde(default, rename = "memo_text1")]
+    pub memo_text1: String,
+    /// 해외현재가격1
+    #[serde(default, rename = "ovrs_now_pric1")]
+    pub ovrs_now_pric1: String,
+    /// 최종수신일시
+    #[serde(default, rename = "last_rcvg_dtime")]
+    pub last_rcvg_dtime: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 상품기본정보[v1_해외주식-034]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsSearchInfoResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsSearchInfoOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsSearchInfoResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 업종별시세[해외주식-048]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryThemeOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 업종별시세[해외주식-048]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryThemeOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도잔량
+    #[serde(default, rename = "vask")]
+    pub vask: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 매수잔량
+    #[serde(default, rename = "vbid")]
+    pub vbid: String,
+    /// 순위
+    #[serde(default, rename = "seqn")]
+    pub seqn: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 업종별시세[해외주식-048]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsIndustryThemeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메시지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsIndustryThemeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsIndustryThemeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsIndustryThemeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 업종별코드조회[해외주식-049]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryPriceOutput1Item {
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 업종별코드조회[해외주식-049]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsIndustryPriceOutput2Item {
+    /// 업종코드
+    #[serde(default, rename = "icod")]
+    pub icod: String,
+    /// 업종명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 업종별코드조회[해외주식-049]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsIndustryPriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsIndustryPriceOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsIndustryPriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsIndustryPriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 복수종목 시세조회] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsMultpriceOutputItem {
+    /// Output 개수
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 복수종목 시세조회] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsMultpriceOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "knam")]
+    pub knam: String,
+    /// 거래소명
+    #[serde(default, rename = "exnm")]
+    pub exnm: String,
+    /// 국가명
+    #[serde(default, rename = "nnam")]
+    pub nnam: String,
+    /// 실 지 휴 정 재
+    #[serde(default, rename = "stat1")]
+    pub stat1: String,
+    /// 실시간 지연15분 휴장 거래정지 거래재개
+    #[serde(default, rename = "stat2")]
+    pub stat2: String,
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// Last Price
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 대비기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 시가
+    #[serde(default, rename = "open")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "high")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "low")]
+    pub low: String,
+    /// Bid Price
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// Ask Price
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가잔량
+    #[serde(default, rename = "vbid")]
+    pub vbid: String,
+    /// 매도호가잔량
+    #[serde(default, rename = "vask")]
+    pub vask: String,
+    /// 매수호가총잔량
+    #[serde(default, rename = "bvol")]
+    pub bvol: String,
+    /// 매도호가총잔량
+    #[serde(default, rename = "avol")]
+    pub avol: String,
+    /// 체결량
+    #[serde(default, rename = "evol")]
+    pub evol: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// 체결강도
+    #[serde(default, rename = "powx")]
+    pub powx: String,
+    /// 현지기준시간(HHMMSS)
+    #[serde(default, rename = "xhms")]
+    pub xhms: String,
+    /// 한국기준시간(HHMMSS)
+    #[serde(default, rename = "khms")]
+    pub khms: String,
+    /// 통화코드
+    #[serde(default, rename = "curr")]
+    pub curr: String,
+    /// Base Price
+    #[serde(default, rename = "base")]
+    pub base: String,
+    /// Previous Volume
+    #[serde(default, rename = "pvol")]
+    pub pvol: String,
+    /// 전일거래대금
+    #[serde(default, rename = "pamt")]
+    pub pamt: String,
+    /// 전일시가
+    #[serde(default, rename = "popen")]
+    pub popen: String,
+    /// 전일고가
+    #[serde(default, rename = "phigh")]
+    pub phigh: String,
+    /// 전일저가
+    #[serde(default, rename = "plow")]
+    pub plow: String,
+    /// 상장주수
+    #[serde(default, rename = "shar")]
+    pub shar: String,
+    /// 자본금
+    #[serde(default, rename = "mcap")]
+    pub mcap: String,
+    /// 시가총액
+    #[serde(default, rename = "tomv")]
+    pub tomv: String,
+    /// 52주최고가
+    #[serde(default, rename = "h52p")]
+    pub h52p: String,
+    /// 52주최저가
+    #[serde(default, rename = "l52p")]
+    pub l52p: String,
+    /// 52주최고일자
+    #[serde(default, rename = "h52d")]
+    pub h52d: String,
+    /// 52주최저일자
+    #[serde(default, rename = "l52d")]
+    pub l52d: String,
+    /// High Anual Price
+    #[serde(default, rename = "hanp")]
+    pub hanp: String,
+    /// Low Anual Price
+    #[serde(default, rename = "lanp")]
+    pub lanp: String,
+    /// 연중최고일자
+    #[serde(default, rename = "hand")]
+    pub hand: String,
+    /// 연중최저일자
+    #[serde(default, rename = "land")]
+    pub land: String,
+    /// 매매단위
+    #[serde(default, rename = "bnit")]
+    pub bnit: String,
+    /// 원환산당일가격
+    #[serde(default, rename = "t_xprc")]
+    pub t_xprc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 복수종목 시세조회] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsMultpriceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsMultpriceOutputItem>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsMultpriceOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsMultpriceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 가격급등락[해외주식-038]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingPriceFluctOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 가격급등락[해외주식-038]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingPriceFluctOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "knam")]
+    pub knam: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 기준가격
+    #[serde(default, rename = "n_base")]
+    pub n_base: String,
+    /// 기준가격대비
+    #[serde(default, rename = "n_diff")]
+    pub n_diff: String,
+    /// 기준가격대비율
+    #[serde(default, rename = "n_rate")]
+    pub n_rate: Decimal,
+    /// 영문종목명
+    #[serde(default, rename = "enam")]
+    pub enam: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 가격급등락[해외주식-038]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingPriceFluctResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingPriceFluctOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingPriceFluctOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingPriceFluctResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 거래량급증[해외주식-039]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumeSurgeOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래량급증[해외주식-039]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumeSurgeOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "knam")]
+    pub knam: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 기준거래량
+    #[serde(default, rename = "n_tvol")]
+    pub n_tvol: String,
+    /// 증가량
+    #[serde(default, rename = "n_diff")]
+    pub n_diff: String,
+    /// 증가율
+    #[serde(default, rename = "n_rate")]
+    pub n_rate: Decimal,
+    /// 영문종목명
+    #[serde(default, rename = "enam")]
+    pub enam: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래량급증[해외주식-039]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingVolumeSurgeResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingVolumeSurgeOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingVolumeSurgeOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingVolumeSurgeResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 매수체결강도상위[해외주식-040]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumePowerOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 매수체결강도상위[해외주식-040]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingVolumePowerOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "knam")]
+    pub knam: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 당일체결강도
+    #[serde(default, rename = "tpow")]
+    pub tpow: String,
+    /// 체결강도
+    #[serde(default, rename = "powx")]
+    pub powx: String,
+    /// 영문종목명
+    #[serde(default, rename = "enam")]
+    pub enam: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 매수체결강도상위[해외주식-040]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingVolumePowerResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingVolumePowerOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingVolumePowerOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingVolumePowerResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 상승율/하락율[해외주식-041]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingUpdownRateOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재Count
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 상승율/하락율[해외주식-041]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingUpdownRateOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 기준가격
+    #[serde(default, rename = "n_base")]
+    pub n_base: String,
+    /// 기준가격대비
+    #[serde(default, rename = "n_diff")]
+    pub n_diff: String,
+    /// 기준가격대비율
+    #[serde(default, rename = "n_rate")]
+    pub n_rate: Decimal,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 상승율/하락율[해외주식-041]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingUpdownRateResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingUpdownRateOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingUpdownRateOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingUpdownRateResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 신고/신저가[해외주식-042]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingNewHighlowOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 신고/신저가[해외주식-042]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingNewHighlowOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 기준가
+    #[serde(default, rename = "n_base")]
+    pub n_base: String,
+    /// 기준가대비
+    #[serde(default, rename = "n_diff")]
+    pub n_diff: String,
+    /// 기준가대비율
+    #[serde(default, rename = "n_rate")]
+    pub n_rate: Decimal,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 신고/신저가[해외주식-042]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingNewHighlowResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingNewHighlowOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingNewHighlowOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingNewHighlowResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 거래량순위[해외주식-043]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeVolOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래량순위[해외주식-043]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeVolOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// 평균거래량
+    #[serde(default, rename = "a_tvol")]
+    pub a_tvol: String,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래량순위[해외주식-043]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingTradeVolResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingTradeVolOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingTradeVolOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingTradeVolResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 거래대금순위[해외주식-044]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradePbmnOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래대금순위[해외주식-044]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradePbmnOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "tamt")]
+    pub tamt: String,
+    /// 평균거래대금
+    #[serde(default, rename = "a_tamt")]
+    pub a_tamt: String,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래대금순위[해외주식-044]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingTradePbmnResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingTradePbmnOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingTradePbmnOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingTradePbmnResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 거래증가율순위[해외주식-045]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeGrowthOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래증가율순위[해외주식-045]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeGrowthOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 평균거래량
+    #[serde(default, rename = "n_tvol")]
+    pub n_tvol: String,
+    /// 증가율
+    #[serde(default, rename = "n_rate")]
+    pub n_rate: Decimal,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래증가율순위[해외주식-045]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingTradeGrowthResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메시지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingTradeGrowthOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingTradeGrowthOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingTradeGrowthResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 거래회전율순위[해외주식-046]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeTurnoverOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래회전율순위[해외주식-046]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingTradeTurnoverOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 매도호가
+    #[serde(default, rename = "pask")]
+    pub pask: String,
+    /// 매수호가
+    #[serde(default, rename = "pbid")]
+    pub pbid: String,
+    /// 평균거래량
+    #[serde(default, rename = "n_tvol")]
+    pub n_tvol: String,
+    /// 상장주식수
+    #[serde(default, rename = "shar")]
+    pub shar: String,
+    /// 회전율
+    #[serde(default, rename = "tover")]
+    pub tover: String,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 거래회전율순위[해외주식-046]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingTradeTurnoverResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingTradeTurnoverOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingTradeTurnoverOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingTradeTurnoverResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 시가총액순위[해외주식-047]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingMarketCapOutput1Item {
+    /// 소수점자리수
+    #[serde(default, rename = "zdiv")]
+    pub zdiv: String,
+    /// 거래상태정보
+    #[serde(default, rename = "stat")]
+    pub stat: String,
+    /// 현재조회종목수
+    #[serde(default, rename = "crec")]
+    pub crec: String,
+    /// 전체조회종목수
+    #[serde(default, rename = "trec")]
+    pub trec: String,
+    /// RecordCount
+    #[serde(default, rename = "nrec")]
+    pub nrec: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 시가총액순위[해외주식-047]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasStockV1RankingMarketCapOutput2Item {
+    /// 실시간조회심볼
+    #[serde(default, rename = "rsym")]
+    pub rsym: String,
+    /// 거래소코드
+    #[serde(default, rename = "excd")]
+    pub excd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "name")]
+    pub name: String,
+    /// 현재가
+    #[serde(default, rename = "last")]
+    pub last: String,
+    /// 기호
+    #[serde(default, rename = "sign")]
+    pub sign: String,
+    /// 대비
+    #[serde(default, rename = "diff")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "rate")]
+    pub rate: Decimal,
+    /// 거래량
+    #[serde(default, rename = "tvol")]
+    pub tvol: String,
+    /// 상장주식수
+    #[serde(default, rename = "shar")]
+    pub shar: String,
+    /// 시가총액
+    #[serde(default, rename = "tomv")]
+    pub tomv: String,
+    /// 비중
+    #[serde(default, rename = "grav")]
+    pub grav: String,
+    /// 순위
+    #[serde(default, rename = "rank")]
+    pub rank: String,
+    /// 영문종목명
+    #[serde(default, rename = "ename")]
+    pub ename: String,
+    /// 매매가능
+    #[serde(default, rename = "e_ordyn")]
+    pub e_ordyn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 시가총액순위[해외주식-047]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasStockV1RankingMarketCapResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasStockV1RankingMarketCapOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasStockV1RankingMarketCapOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasStockV1RankingMarketCapResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외주식 기간별권리조회 [해외주식-052]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsPeriodRightsOutputItem {
+    /// 기준일자
+    #[serde(default, rename = "bass_dt")]
+    pub bass_dt: String,
+    /// 권리유형코드
+    #[serde(default, rename = "rght_type_cd")]
+    pub rght_type_cd: String,
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 상품명
+    #[serde(default, rename = "prdt_name")]
+    pub prdt_name: String,
+    /// 상품유형코드
+    #[serde(default, rename = "prdt_type_cd")]
+    pub prdt_type_cd: String,
+    /// 표준상품번호
+    #[serde(default, rename = "std_pdno")]
+    pub std_pdno: String,
+    /// 현지기준일자
+    #[serde(default, rename = "acpl_bass_dt")]
+    pub acpl_bass_dt: String,
+    /// 청약시작일자
+    #[serde(default, rename = "sbsc_strt_dt")]
+    pub sbsc_strt_dt: String,
+    /// 청약종료일자
+    #[serde(default, rename = "sbsc_end_dt")]
+    pub sbsc_end_dt: String,
+    /// 현금배정비율
+    #[serde(default, rename = "cash_alct_rt")]
+    pub cash_alct_rt: Decimal,
+    /// 주식배정비율
+    #[serde(default, rename = "stck_alct_rt")]
+    pub stck_alct_rt: Decimal,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 통화코드2
+    #[serde(default, rename = "crcy_cd2")]
+    pub crcy_cd2: String,
+    /// 통화코드3
+    #[serde(default, rename = "crcy_cd3")]
+    pub crcy_cd3: String,
+    /// 통화코드4
+    #[serde(default, rename = "crcy_cd4")]
+    pub crcy_cd4: String,
+    /// 배정외화단가
+    #[serde(default, rename = "alct_frcr_unpr")]
+    pub alct_frcr_unpr: Decimal,
+    /// 주당배당외화금액2
+    #[serde(default, rename = "stkp_dvdn_frcr_amt2")]
+    pub stkp_dvdn_frcr_amt2: Decimal,
+    /// 주당배당외화금액3
+    #[serde(default, rename = "stkp_dvdn_frcr_amt3")]
+    pub stkp_dvdn_frcr_amt3: Decimal,
+    /// 주당배당외화금액4
+    #[serde(default, rename = "stkp_dvdn_frcr_amt4")]
+    pub stkp_dvdn_frcr_amt4: Decimal,
+    /// 확정여부
+    #[serde(default, rename = "dfnt_yn")]
+    pub dfnt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 기간별권리조회 [해외주식-052]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsPeriodRightsResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsPeriodRightsOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsPeriodRightsResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외뉴스종합(제목) [해외주식-053]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsNewsTitleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    /// 응답상세
+    #[serde(default, rename = "outblock1")]
+    pub outblock1: String,
+    /// 뉴스구분
+    #[serde(default, rename = "info_gb")]
+    pub info_gb: String,
+    /// 뉴스키
+    #[serde(default, rename = "news_key")]
+    pub news_key: String,
+    /// 조회일자
+    #[serde(default, rename = "data_dt")]
+    pub data_dt: String,
+    /// 조회시간
+    #[serde(default, rename = "data_tm")]
+    pub data_tm: String,
+    /// 중분류
+    #[serde(default, rename = "class_cd")]
+    pub class_cd: String,
+    /// 중분류명
+    #[serde(default, rename = "class_name")]
+    pub class_name: String,
+    /// 자료원
+    #[serde(default, rename = "source")]
+    pub source: String,
+    /// 국가코드
+    #[serde(default, rename = "nation_cd")]
+    pub nation_cd: String,
+    /// 거래소코드
+    #[serde(default, rename = "exchange_cd")]
+    pub exchange_cd: String,
+    /// 종목코드
+    #[serde(default, rename = "symb")]
+    pub symb: String,
+    /// 종목명
+    #[serde(default, rename = "symb_name")]
+    pub symb_name: String,
+    /// 제목
+    #[serde(default, rename = "title")]
+    pub title: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsNewsTitleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외주식 권리종합 [해외주식-050]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsRightsByIceOutput1Item {
+    /// ICE공시일
+    #[serde(default, rename = "anno_dt")]
+    pub anno_dt: String,
+    /// 권리유형
+    #[serde(default, rename = "ca_title")]
+    pub ca_title: String,
+    /// 배당락일
+    #[serde(default, rename = "div_lock_dt")]
+    pub div_lock_dt: String,
+    /// 지급일
+    #[serde(default, rename = "pay_dt")]
+    pub pay_dt: String,
+    /// 기준일
+    #[serde(default, rename = "record_dt")]
+    pub record_dt: String,
+    /// 효력일자
+    #[serde(default, rename = "validity_dt")]
+    pub validity_dt: String,
+    /// 현지지시마감일
+    #[serde(default, rename = "local_end_dt")]
+    pub local_end_dt: String,
+    /// 권리락일
+    #[serde(default, rename = "lock_dt")]
+    pub lock_dt: String,
+    /// 상장폐지일
+    #[serde(default, rename = "delist_dt")]
+    pub delist_dt: String,
+    /// 상환일자
+    #[serde(default, rename = "redempt_dt")]
+    pub redempt_dt: String,
+    /// 조기상환일자
+    #[serde(default, rename = "early_redempt_dt")]
+    pub early_redempt_dt: String,
+    /// 적용일
+    #[serde(default, rename = "effective_dt")]
+    pub effective_dt: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외주식 권리종합 [해외주식-050]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsRightsByIceResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsRightsByIceOutput1Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsRightsByIceResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        fields
+    }
+}
+
+/// [당사 해외주식담보대출 가능 종목 [해외주식-051]] output1 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsColableByCompanyOutput1Item {
+    /// 상품번호
+    #[serde(default, rename = "pdno")]
+    pub pdno: String,
+    /// 해외종목명
+    #[serde(default, rename = "ovrs_item_name")]
+    pub ovrs_item_name: String,
+    /// 대출비율
+    #[serde(default, rename = "loan_rt")]
+    pub loan_rt: Decimal,
+    /// 담보유지비율
+    #[serde(default, rename = "mgge_mntn_rt")]
+    pub mgge_mntn_rt: Decimal,
+    /// 담보확보비율
+    #[serde(default, rename = "mgge_ensu_rt")]
+    pub mgge_ensu_rt: Decimal,
+    /// 대출실행가능여부
+    #[serde(default, rename = "loan_exec_psbl_yn")]
+    pub loan_exec_psbl_yn: String,
+    /// 직원명
+    #[serde(default, rename = "stff_name")]
+    pub stff_name: String,
+    /// 등록일자
+    #[serde(default, rename = "erlm_dt")]
+    pub erlm_dt: String,
+    /// 거래시장명
+    #[serde(default, rename = "tr_mket_name")]
+    pub tr_mket_name: String,
+    /// 통화코드
+    #[serde(default, rename = "crcy_cd")]
+    pub crcy_cd: String,
+    /// 국가한글명
+    #[serde(default, rename = "natn_kor_name")]
+    pub natn_kor_name: String,
+    /// 해외거래소코드
+    #[serde(default, rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [당사 해외주식담보대출 가능 종목 [해외주식-051]] output2 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsColableByCompanyOutput2Item {
+    /// 대출가능종목수
+    #[serde(default, rename = "loan_psbl_item_num")]
+    pub loan_psbl_item_num: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [당사 해외주식담보대출 가능 종목 [해외주식-051]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsColableByCompanyResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output1: Vec<OverseasPriceV1QuotationsColableByCompanyOutput1Item>,
+    #[serde(default)]
+    pub output2: Vec<OverseasPriceV1QuotationsColableByCompanyOutput2Item>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsColableByCompanyResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        for item in &self.output1 {
+            fields.extend(item.extra.keys().map(|k| format!("output1.{k}")));
+        }
+        for item in &self.output2 {
+            fields.extend(item.extra.keys().map(|k| format!("output2.{k}")));
+        }
+        fields
+    }
+}
+
+/// [해외속보(제목) [해외주식-055]] output 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OverseasPriceV1QuotationsBrknewsTitleOutputItem {
+    /// 내용조회용일련번호
+    #[serde(default, rename = "cntt_usiq_srno")]
+    pub cntt_usiq_srno: String,
+    /// 뉴스제공업체코드
+    #[serde(default, rename = "news_ofer_entp_code")]
+    pub news_ofer_entp_code: String,
+    /// 작성일자
+    #[serde(default, rename = "data_dt")]
+    pub data_dt: String,
+    /// 작성시간
+    #[serde(default, rename = "data_tm")]
+    pub data_tm: String,
+    /// HTS공시제목내용
+    #[serde(default, rename = "hts_pbnt_titl_cntt")]
+    pub hts_pbnt_titl_cntt: String,
+    /// 뉴스대구분
+    #[serde(default, rename = "news_lrdv_code")]
+    pub news_lrdv_code: String,
+    /// 자료원
+    #[serde(default, rename = "dorg")]
+    pub dorg: String,
+    /// 종목코드1
+    #[serde(default, rename = "iscd1")]
+    pub iscd1: String,
+    /// 종목코드2
+    #[serde(default, rename = "iscd2")]
+    pub iscd2: String,
+    /// 종목코드3
+    #[serde(default, rename = "iscd3")]
+    pub iscd3: String,
+    /// 종목코드4
+    #[serde(default, rename = "iscd4")]
+    pub iscd4: String,
+    /// 종목코드5
+    #[serde(default, rename = "iscd5")]
+    pub iscd5: String,
+    /// 종목코드6
+    #[serde(default, rename = "iscd6")]
+    pub iscd6: String,
+    /// 종목코드7
+    #[serde(default, rename = "iscd7")]
+    pub iscd7: String,
+    /// 종목코드8
+    #[serde(default, rename = "iscd8")]
+    pub iscd8: String,
+    /// 종목코드9
+    #[serde(default, rename = "iscd9")]
+    pub iscd9: String,
+    /// 종목코드10
+    #[serde(default, rename = "iscd10")]
+    pub iscd10: String,
+    /// 한글종목명1
+    #[serde(default, rename = "kor_isnm1")]
+    pub kor_isnm1: String,
+    /// 한글종목명2
+    #[serde(default, rename = "kor_isnm2")]
+    pub kor_isnm2: String,
+    /// 한글종목명3
+    #[serde(default, rename = "kor_isnm3")]
+    pub kor_isnm3: String,
+    /// 한글종목명4
+    #[serde(default, rename = "kor_isnm4")]
+    pub kor_isnm4: String,
+    /// 한글종목명5
+    #[serde(default, rename = "kor_isnm5")]
+    pub kor_isnm5: String,
+    /// 한글종목명6
+    #[serde(default, rename = "kor_isnm6")]
+    pub kor_isnm6: String,
+    /// 한글종목명7
+    #[serde(default, rename = "kor_isnm7")]
+    pub kor_isnm7: String,
+    /// 한글종목명8
+    #[serde(default, rename = "kor_isnm8")]
+    pub kor_isnm8: String,
+    /// 한글종목명9
+    #[serde(default, rename = "kor_isnm9")]
+    pub kor_isnm9: String,
+    /// 한글종목명10
+    #[serde(default, rename = "kor_isnm10")]
+    pub kor_isnm10: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// [해외속보(제목) [해외주식-055]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OverseasPriceV1QuotationsBrknewsTitleResponse {
+    /// 성공 실패 여부
+    #[serde(default, rename = "rt_cd")]
+    pub rt_cd: String,
+    /// 응답코드
+    #[serde(default, rename = "msg_cd")]
+    pub msg_cd: String,
+    /// 응답메세지
+    #[serde(default, rename = "msg1")]
+    pub msg1: String,
+    #[serde(default)]
+    pub output: Option<OverseasPriceV1QuotationsBrknewsTitleOutputItem>,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for OverseasPriceV1QuotationsBrknewsTitleResponse {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        if let Some(item) = &self.output {
+            fields.extend(item.extra.keys().map(|k| format!("output.{k}")));
+        }
+        fields
+    }
+}
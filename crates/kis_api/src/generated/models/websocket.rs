@@ -0,0 +1,8803 @@
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::tabs_in_doc_comments,
+    clippy::doc_markdown
+)]
+use crate::models::UnknownFields;
+use serde::{Deserialize, Serialize};
+
+/// [국내주식 실시간체결가 (KRX) [실시간-003]] 요청 구조체
+/// [실시간-003] 국내주식 실시간체결가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간호가 (KRX) [실시간-004]] 요청 구조체
+/// [실시간-004] 국내주식 실시간호가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간체결통보 [실시간-005]] 요청 구조체
+/// [실시간-005] 국내주식 실시간체결통보
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stcni0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간예상체결 (KRX) [실시간-041]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간회원사 (KRX) [실시간-047]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stmbc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간프로그램매매 (KRX) [실시간-048]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stpgm0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 장운영정보 (KRX) [실시간-049]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stmko0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 시간외 실시간호가 (KRX) [실시간-025]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stoaa0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 시간외 실시간체결가 (KRX) [실시간-042]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stoup0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값  (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 시간외 실시간예상체결 (KRX) [실시간-024]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stoac0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내지수 실시간체결 [실시간-026]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0upcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내지수 실시간예상체결 [실시간-027]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0upanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내지수 실시간프로그램매매 [실시간-028]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0uppgm0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [ELW 실시간호가 [실시간-062]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ewasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [ELW 실시간체결가 [실시간-061]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ewcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [ELW 실시간예상체결 [실시간-063]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ewanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내ETF NAV추이 [실시간-051]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0stnav0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간체결가 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0uncnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간호가 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0unasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간예상체결 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0unanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간회원사 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0unmbc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간프로그램매매 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0unpgm0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 장운영정보 (통합)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0unmko0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간체결가 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간호가 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간예상체결 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간회원사 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxmbc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간프로그램매매 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxpgm0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 장운영정보 (NXT)] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0nxmko0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [지수선물 실시간호가[실시간-011]] 요청 구조체
+/// [실시간-011] 지수선물 실시간호가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ifasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [지수선물 실시간체결가[실시간-010]] 요청 구조체
+/// [실시간-010] 지수선물 실시간체결가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ifcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [지수옵션 실시간호가[실시간-015]] 요청 구조체
+/// [실시간-015] 지수옵션 실시간호가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ioasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [지수옵션  실시간체결가[실시간-014]] 요청 구조체
+/// [실시간-014] 지수옵션 실시간체결가
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0iocnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [선물옵션 실시간체결통보[실시간-012]] 요청 구조체
+/// [실시간-012] 지수선물옵션 실시간체결 통보
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0ifcni0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [상품선물 실시간호가[실시간-023]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0cfasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [상품선물 실시간체결가[실시간-022]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0cfcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식선물 실시간호가 [실시간-030]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zfasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식선물 실시간체결가 [실시간-029]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zfcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식선물 실시간예상체결 [실시간-031]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zfanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식옵션 실시간호가 [실시간-045]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zoasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식옵션 실시간체결가 [실시간-044]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zocnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [주식옵션 실시간예상체결 [실시간-046]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0zoanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간옵션 실시간호가 [실시간-033]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0euasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간옵션 실시간체결가 [실시간-032]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0eucnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간옵션실시간예상체결 [실시간-034]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0euanc0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간옵션실시간체결통보 [실시간-067]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0eucni0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간선물 실시간호가 [실시간-065]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0mfasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간선물 실시간종목체결 [실시간-064]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0mfcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [KRX야간선물 실시간체결통보 [실시간-066]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0mfcni0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외주식 실시간호가[실시간-021]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfsasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// R거래소명종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외주식 지연호가(아시아)[실시간-008]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfsasp1Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// D거래소명종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외주식 실시간지연체결가[실시간-007]] 요청 구조체
+/// [실시간-007] 해외주식 실시간지연체결통보
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfscnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// D거래소명종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외주식 실시간체결통보[실시간-009]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0gscni0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// HTSID (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외선물옵션 실시간체결가[실시간-017]] 요청 구조체
+/// 해외선물옵션체결[실시간-017]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfff020Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외선물옵션 실시간호가[실시간-018]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfff010Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 종목코드 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외선물옵션 실시간주문내역통보[실시간-019]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfff1c0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// HTSID (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [해외선물옵션 실시간체결내역통보[실시간-020]] 요청 구조체
+/// 해외선물옵션체결내역통보[실시간-020]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutHdfff2c0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// HTSID (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [일반채권 실시간체결가 [실시간-052]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0bjcnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [일반채권 실시간호가 [실시간-053]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0bjasp0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [채권지수 실시간체결가 [실시간-060]] 요청 구조체
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct TryitoutH0bicnt0Request {
+    /// 거래ID (String, 필수)
+    #[serde(rename = "tr_id")]
+    pub tr_id: String,
+    /// 구분값 (String, 필수)
+    #[serde(rename = "tr_key")]
+    pub tr_key: String,
+}
+
+/// [국내주식 실시간체결가 (KRX) [실시간-003]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stcnt0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중 평균 주식 가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 주식 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 체결 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CCLD_DVSN")]
+    pub ccld_dvsn: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업 일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신 장운영 구분 코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일 동시간 누적 거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일 동시간 누적 거래량 비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 정적VI발동기준가
+    #[serde(default, rename = "VI_STND_PRC")]
+    pub vi_stnd_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간호가 (KRX) [실시간-004]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stasp0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "ASKP10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "BIDP10")]
+    pub bidp10: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가 잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가 잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가 잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가 잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가 잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가 잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가 잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가 잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가 잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가 잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_RSQN")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_RSQN")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 예상 체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상 체결량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 예상 거래량
+    #[serde(default, rename = "ANTC_VOL")]
+    pub antc_vol: String,
+    /// 예상 체결 대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외 총 매도호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_ICDC")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외 총 매수호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_ICDC")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 주식 매매 구분 코드
+    #[serde(default, rename = "STCK_DEAL_CLS_CODE")]
+    pub stck_deal_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간체결통보 [실시간-005]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stcni0Response {
+    /// 고객 ID
+    #[serde(default, rename = "CUST_ID")]
+    pub cust_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACNT_NO")]
+    pub acnt_no: String,
+    /// 주문번호
+    #[serde(default, rename = "ODER_NO")]
+    pub oder_no: String,
+    /// 원주문번호
+    #[serde(default, rename = "OODER_NO")]
+    pub ooder_no: String,
+    /// 매도매수구분
+    #[serde(default, rename = "SELN_BYOV_CLS")]
+    pub seln_byov_cls: String,
+    /// 정정구분
+    #[serde(default, rename = "RCTF_CLS")]
+    pub rctf_cls: String,
+    /// 주문종류
+    #[serde(default, rename = "ODER_KIND")]
+    pub oder_kind: String,
+    /// 주문조건
+    #[serde(default, rename = "ODER_COND")]
+    pub oder_cond: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "STCK_SHRN_ISCD")]
+    pub stck_shrn_iscd: String,
+    /// 체결 수량
+    #[serde(default, rename = "CNTG_QTY")]
+    pub cntg_qty: String,
+    /// 체결단가
+    #[serde(default, rename = "CNTG_UNPR")]
+    pub cntg_unpr: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 거부여부
+    #[serde(default, rename = "RFUS_YN")]
+    pub rfus_yn: String,
+    /// 체결여부
+    #[serde(default, rename = "CNTG_YN")]
+    pub cntg_yn: String,
+    /// 접수여부
+    #[serde(default, rename = "ACPT_YN")]
+    pub acpt_yn: String,
+    /// 지점번호
+    #[serde(default, rename = "BRNC_NO")]
+    pub brnc_no: String,
+    /// 주문수량
+    #[serde(default, rename = "ODER_QTY")]
+    pub oder_qty: String,
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 호가조건가격
+    #[serde(default, rename = "ORD_COND_PRC")]
+    pub ord_cond_prc: String,
+    /// 주문거래소 구분
+    #[serde(default, rename = "ORD_EXG_GB")]
+    pub ord_exg_gb: String,
+    /// 실시간체결창 표시여부
+    #[serde(default, rename = "POPUP_YN")]
+    pub popup_yn: String,
+    /// 필러
+    #[serde(default, rename = "FILLER")]
+    pub filler: String,
+    /// 신용구분
+    #[serde(default, rename = "CRDT_CLS")]
+    pub crdt_cls: String,
+    /// 신용대출일자
+    #[serde(default, rename = "CRDT_LOAN_DATE")]
+    pub crdt_loan_date: String,
+    /// 체결종목명
+    #[serde(default, rename = "CNTG_ISNM40")]
+    pub cntg_isnm40: String,
+    /// 주문가격
+    #[serde(default, rename = "ODER_PRC")]
+    pub oder_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stcni0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간예상체결 (KRX) [실시간-041]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stanc0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비구분
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 등락율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간구분코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간회원사 (KRX) [실시간-047]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stmbc0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 매도2회원사명1
+    #[serde(default, rename = "SELN2_MBCR_NAME1")]
+    pub seln2_mbcr_name1: String,
+    /// 매도2회원사명2
+    #[serde(default, rename = "SELN2_MBCR_NAME2")]
+    pub seln2_mbcr_name2: String,
+    /// 매도2회원사명3
+    #[serde(default, rename = "SELN2_MBCR_NAME3")]
+    pub seln2_mbcr_name3: String,
+    /// 매도2회원사명4
+    #[serde(default, rename = "SELN2_MBCR_NAME4")]
+    pub seln2_mbcr_name4: String,
+    /// 매도2회원사명5
+    #[serde(default, rename = "SELN2_MBCR_NAME5")]
+    pub seln2_mbcr_name5: String,
+    /// 매수회원사명1
+    #[serde(default, rename = "BYOV_MBCR_NAME1")]
+    pub byov_mbcr_name1: String,
+    /// 매수회원사명2
+    #[serde(default, rename = "BYOV_MBCR_NAME2")]
+    pub byov_mbcr_name2: String,
+    /// 매수회원사명3
+    #[serde(default, rename = "BYOV_MBCR_NAME3")]
+    pub byov_mbcr_name3: String,
+    /// 매수회원사명4
+    #[serde(default, rename = "BYOV_MBCR_NAME4")]
+    pub byov_mbcr_name4: String,
+    /// 매수회원사명5
+    #[serde(default, rename = "BYOV_MBCR_NAME5")]
+    pub byov_mbcr_name5: String,
+    /// 총매도수량1
+    #[serde(default, rename = "TOTAL_SELN_QTY1")]
+    pub total_seln_qty1: String,
+    /// 총매도수량2
+    #[serde(default, rename = "TOTAL_SELN_QTY2")]
+    pub total_seln_qty2: String,
+    /// 총매도수량3
+    #[serde(default, rename = "TOTAL_SELN_QTY3")]
+    pub total_seln_qty3: String,
+    /// 총매도수량4
+    #[serde(default, rename = "TOTAL_SELN_QTY4")]
+    pub total_seln_qty4: String,
+    /// 총매도수량5
+    #[serde(default, rename = "TOTAL_SELN_QTY5")]
+    pub total_seln_qty5: String,
+    /// 총매수2수량1
+    #[serde(default, rename = "TOTAL_SHNU_QTY1")]
+    pub total_shnu_qty1: String,
+    /// 총매수2수량2
+    #[serde(default, rename = "TOTAL_SHNU_QTY2")]
+    pub total_shnu_qty2: String,
+    /// 총매수2수량3
+    #[serde(default, rename = "TOTAL_SHNU_QTY3")]
+    pub total_shnu_qty3: String,
+    /// 총매수2수량4
+    #[serde(default, rename = "TOTAL_SHNU_QTY4")]
+    pub total_shnu_qty4: String,
+    /// 총매수2수량5
+    #[serde(default, rename = "TOTAL_SHNU_QTY5")]
+    pub total_shnu_qty5: String,
+    /// 매도거래원구분1
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_1")]
+    pub seln_mbcr_glob_yn_1: String,
+    /// 매도거래원구분2
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_2")]
+    pub seln_mbcr_glob_yn_2: String,
+    /// 매도거래원구분3
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_3")]
+    pub seln_mbcr_glob_yn_3: String,
+    /// 매도거래원구분4
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_4")]
+    pub seln_mbcr_glob_yn_4: String,
+    /// 매도거래원구분5
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_5")]
+    pub seln_mbcr_glob_yn_5: String,
+    /// 매수거래원구분1
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_1")]
+    pub shnu_mbcr_glob_yn_1: String,
+    /// 매수거래원구분2
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_2")]
+    pub shnu_mbcr_glob_yn_2: String,
+    /// 매수거래원구분3
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_3")]
+    pub shnu_mbcr_glob_yn_3: String,
+    /// 매수거래원구분4
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_4")]
+    pub shnu_mbcr_glob_yn_4: String,
+    /// 매수거래원구분5
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_5")]
+    pub shnu_mbcr_glob_yn_5: String,
+    /// 매도거래원코드1
+    #[serde(default, rename = "SELN_MBCR_NO1")]
+    pub seln_mbcr_no1: String,
+    /// 매도거래원코드2
+    #[serde(default, rename = "SELN_MBCR_NO2")]
+    pub seln_mbcr_no2: String,
+    /// 매도거래원코드3
+    #[serde(default, rename = "SELN_MBCR_NO3")]
+    pub seln_mbcr_no3: String,
+    /// 매도거래원코드4
+    #[serde(default, rename = "SELN_MBCR_NO4")]
+    pub seln_mbcr_no4: String,
+    /// 매도거래원코드5
+    #[serde(default, rename = "SELN_MBCR_NO5")]
+    pub seln_mbcr_no5: String,
+    /// 매수거래원코드1
+    #[serde(default, rename = "SHNU_MBCR_NO1")]
+    pub shnu_mbcr_no1: String,
+    /// 매수거래원코드2
+    #[serde(default, rename = "SHNU_MBCR_NO2")]
+    pub shnu_mbcr_no2: String,
+    /// 매수거래원코드3
+    #[serde(default, rename = "SHNU_MBCR_NO3")]
+    pub shnu_mbcr_no3: String,
+    /// 매수거래원코드4
+    #[serde(default, rename = "SHNU_MBCR_NO4")]
+    pub shnu_mbcr_no4: String,
+    /// 매수거래원코드5
+    #[serde(default, rename = "SHNU_MBCR_NO5")]
+    pub shnu_mbcr_no5: String,
+    /// 매도회원사비중1
+    #[serde(default, rename = "SELN_MBCR_RLIM1")]
+    pub seln_mbcr_rlim1: String,
+    /// 매도회원사비중2
+    #[serde(default, rename = "SELN_MBCR_RLIM2")]
+    pub seln_mbcr_rlim2: String,
+    /// 매도회원사비중3
+    #[serde(default, rename = "SELN_MBCR_RLIM3")]
+    pub seln_mbcr_rlim3: String,
+    /// 매도회원사비중4
+    #[serde(default, rename = "SELN_MBCR_RLIM4")]
+    pub seln_mbcr_rlim4: String,
+    /// 매도회원사비중5
+    #[serde(default, rename = "SELN_MBCR_RLIM5")]
+    pub seln_mbcr_rlim5: String,
+    /// 매수2회원사비중1
+    #[serde(default, rename = "SHNU_MBCR_RLIM1")]
+    pub shnu_mbcr_rlim1: String,
+    /// 매수2회원사비중2
+    #[serde(default, rename = "SHNU_MBCR_RLIM2")]
+    pub shnu_mbcr_rlim2: String,
+    /// 매수2회원사비중3
+    #[serde(default, rename = "SHNU_MBCR_RLIM3")]
+    pub shnu_mbcr_rlim3: String,
+    /// 매수2회원사비중4
+    #[serde(default, rename = "SHNU_MBCR_RLIM4")]
+    pub shnu_mbcr_rlim4: String,
+    /// 매수2회원사비중5
+    #[serde(default, rename = "SHNU_MBCR_RLIM5")]
+    pub shnu_mbcr_rlim5: String,
+    /// 매도수량증감1
+    #[serde(default, rename = "SELN_QTY_ICDC1")]
+    pub seln_qty_icdc1: String,
+    /// 매도수량증감2
+    #[serde(default, rename = "SELN_QTY_ICDC2")]
+    pub seln_qty_icdc2: String,
+    /// 매도수량증감3
+    #[serde(default, rename = "SELN_QTY_ICDC3")]
+    pub seln_qty_icdc3: String,
+    /// 매도수량증감4
+    #[serde(default, rename = "SELN_QTY_ICDC4")]
+    pub seln_qty_icdc4: String,
+    /// 매도수량증감5
+    #[serde(default, rename = "SELN_QTY_ICDC5")]
+    pub seln_qty_icdc5: String,
+    /// 매수2수량증감1
+    #[serde(default, rename = "SHNU_QTY_ICDC1")]
+    pub shnu_qty_icdc1: String,
+    /// 매수2수량증감2
+    #[serde(default, rename = "SHNU_QTY_ICDC2")]
+    pub shnu_qty_icdc2: String,
+    /// 매수2수량증감3
+    #[serde(default, rename = "SHNU_QTY_ICDC3")]
+    pub shnu_qty_icdc3: String,
+    /// 매수2수량증감4
+    #[serde(default, rename = "SHNU_QTY_ICDC4")]
+    pub shnu_qty_icdc4: String,
+    /// 매수2수량증감5
+    #[serde(default, rename = "SHNU_QTY_ICDC5")]
+    pub shnu_qty_icdc5: String,
+    /// 외국계총매도수량
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY")]
+    pub glob_total_seln_qty: String,
+    /// 외국계총매수2수량
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY")]
+    pub glob_total_shnu_qty: String,
+    /// 외국계총매도수량증감
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY_ICDC")]
+    pub glob_total_seln_qty_icdc: String,
+    /// 외국계총매수2수량증감
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY_ICDC")]
+    pub glob_total_shnu_qty_icdc: String,
+    /// 외국계순매수수량
+    #[serde(default, rename = "GLOB_NTBY_QTY")]
+    pub glob_ntby_qty: String,
+    /// 외국계매도비중
+    #[serde(default, rename = "GLOB_SELN_RLIM")]
+    pub glob_seln_rlim: String,
+    /// 외국계매수2비중
+    #[serde(default, rename = "GLOB_SHNU_RLIM")]
+    pub glob_shnu_rlim: String,
+    /// 매도2영문회원사명1
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME1")]
+    pub seln2_mbcr_eng_name1: String,
+    /// 매도2영문회원사명2
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME2")]
+    pub seln2_mbcr_eng_name2: String,
+    /// 매도2영문회원사명3
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME3")]
+    pub seln2_mbcr_eng_name3: String,
+    /// 매도2영문회원사명4
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME4")]
+    pub seln2_mbcr_eng_name4: String,
+    /// 매도2영문회원사명5
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME5")]
+    pub seln2_mbcr_eng_name5: String,
+    /// 매수영문회원사명1
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME1")]
+    pub byov_mbcr_eng_name1: String,
+    /// 매수영문회원사명2
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME2")]
+    pub byov_mbcr_eng_name2: String,
+    /// 매수영문회원사명3
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME3")]
+    pub byov_mbcr_eng_name3: String,
+    /// 매수영문회원사명4
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME4")]
+    pub byov_mbcr_eng_name4: String,
+    /// 매수영문회원사명5
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME5")]
+    pub byov_mbcr_eng_name5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stmbc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (KRX) [실시간-048]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stpgm0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 매도체결량
+    #[serde(default, rename = "SELN_CNQN")]
+    pub seln_cnqn: String,
+    /// 매도거래대금
+    #[serde(default, rename = "SELN_TR_PBMN")]
+    pub seln_tr_pbmn: String,
+    /// 매수2체결량
+    #[serde(default, rename = "SHNU_CNQN")]
+    pub shnu_cnqn: String,
+    /// 매수2거래대금
+    #[serde(default, rename = "SHNU_TR_PBMN")]
+    pub shnu_tr_pbmn: String,
+    /// 순매수체결량
+    #[serde(default, rename = "NTBY_CNQN")]
+    pub ntby_cnqn: String,
+    /// 순매수거래대금
+    #[serde(default, rename = "NTBY_TR_PBMN")]
+    pub ntby_tr_pbmn: String,
+    /// 매도호가잔량
+    #[serde(default, rename = "SELN_RSQN")]
+    pub seln_rsqn: String,
+    /// 매수호가잔량
+    #[serde(default, rename = "SHNU_RSQN")]
+    pub shnu_rsqn: String,
+    /// 전체순매수호가잔량
+    #[serde(default, rename = "WHOL_NTBY_QTY")]
+    pub whol_ntby_qty: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stpgm0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 장운영정보 (KRX) [실시간-049]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stmko0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 거래정지사유내용
+    #[serde(default, rename = "TR_SUSP_REAS_CNTT")]
+    pub tr_susp_reas_cntt: String,
+    /// 장운영구분코드
+    #[serde(default, rename = "MKOP_CLS_CODE")]
+    pub mkop_cls_code: String,
+    /// 예상장운영구분코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 임의연장구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 동시호가배분처리구분코드
+    #[serde(default, rename = "DIVI_APP_CLS_CODE")]
+    pub divi_app_cls_code: String,
+    /// 종목상태구분코드
+    #[serde(default, rename = "ISCD_STAT_CLS_CODE")]
+    pub iscd_stat_cls_code: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "VI_CLS_CODE")]
+    pub vi_cls_code: String,
+    /// 시간외단일가VI적용구분코드
+    #[serde(default, rename = "OVTM_VI_CLS_CODE")]
+    pub ovtm_vi_cls_code: String,
+    /// 거래소구분코드
+    #[serde(default, rename = "EXCH_CLS_CODE")]
+    pub exch_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stmko0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 시간외 실시간호가 (KRX) [실시간-025]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stoaa0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 시간구분코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간외총매도호가잔량
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_RSQN")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외총매수호가잔량
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_RSQN")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 예상체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상체결량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 예상거래량
+    #[serde(default, rename = "ANTC_VOL")]
+    pub antc_vol: String,
+    /// 예상체결대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 총매도호가잔량증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외총매도호가증감
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_ICDC")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외총매수호가증감
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_ICDC")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stoaa0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 시간외 실시간체결가 (KRX) [실시간-042]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stoup0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비구분
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 등락율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stoup0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 시간외 실시간예상체결 (KRX) [실시간-024]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stoac0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비구분
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 등락율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stoac0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내지수 실시간체결 [실시간-026]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0upcnt0Response {
+    /// 업종 구분 코드
+    #[serde(default, rename = "bstp_cls_code")]
+    pub bstp_cls_code: String,
+    /// 영업 시간
+    #[serde(default, rename = "bsop_hour")]
+    pub bsop_hour: String,
+    /// 현재가 지수
+    #[serde(default, rename = "prpr_nmix")]
+    pub prpr_nmix: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "prdy_vrss_sign")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "bstp_nmix_prdy_vrss")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 누적 거래량
+    #[serde(default, rename = "acml_vol")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "acml_tr_pbmn")]
+    pub acml_tr_pbmn: String,
+    /// 건별 거래량
+    #[serde(default, rename = "pcas_vol")]
+    pub pcas_vol: String,
+    /// 건별 거래 대금
+    #[serde(default, rename = "pcas_tr_pbmn")]
+    pub pcas_tr_pbmn: String,
+    /// 전일 대비율
+    #[serde(default, rename = "prdy_ctrt")]
+    pub prdy_ctrt: String,
+    /// 시가 지수
+    #[serde(default, rename = "oprc_nmix")]
+    pub oprc_nmix: String,
+    /// 지수 최고가
+    #[serde(default, rename = "nmix_hgpr")]
+    pub nmix_hgpr: String,
+    /// 지수 최저가
+    #[serde(default, rename = "nmix_lwpr")]
+    pub nmix_lwpr: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "oprc_vrss_nmix_prpr")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 시가 대비 지수 부호
+    #[serde(default, rename = "oprc_vrss_nmix_sign")]
+    pub oprc_vrss_nmix_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "hgpr_vrss_nmix_prpr")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최고가 대비 지수 부호
+    #[serde(default, rename = "hgpr_vrss_nmix_sign")]
+    pub hgpr_vrss_nmix_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "lwpr_vrss_nmix_prpr")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 최저가 대비 지수 부호
+    #[serde(default, rename = "lwpr_vrss_nmix_sign")]
+    pub lwpr_vrss_nmix_sign: String,
+    /// 전일 종가 대비 시가2 비율
+    #[serde(default, rename = "prdy_clpr_vrss_oprc_rate")]
+    pub prdy_clpr_vrss_oprc_rate: String,
+    /// 전일 종가 대비 최고가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_hgpr_rate")]
+    pub prdy_clpr_vrss_hgpr_rate: String,
+    /// 전일 종가 대비 최저가 비율
+    #[serde(default, rename = "prdy_clpr_vrss_lwpr_rate")]
+    pub prdy_clpr_vrss_lwpr_rate: String,
+    /// 상한 종목 수
+    #[serde(default, rename = "uplm_issu_cnt")]
+    pub uplm_issu_cnt: String,
+    /// 상승 종목 수
+    #[serde(default, rename = "ascn_issu_cnt")]
+    pub ascn_issu_cnt: String,
+    ///   보합 종목 수
+    #[serde(default, rename = "stnr_issu_cnt")]
+    pub stnr_issu_cnt: String,
+    /// 하락 종목 수
+    #[serde(default, rename = "down_issu_cnt")]
+    pub down_issu_cnt: String,
+    ///   하한 종목 수
+    #[serde(default, rename = "lslm_issu_cnt")]
+    pub lslm_issu_cnt: String,
+    /// 기세 상승 종목수
+    #[serde(default, rename = "qtqt_ascn_issu_cnt")]
+    pub qtqt_ascn_issu_cnt: String,
+    /// 기세 하락 종목수
+    #[serde(default, rename = "qtqt_down_issu_cnt")]
+    pub qtqt_down_issu_cnt: String,
+    /// TICK대비
+    #[serde(default, rename = "tick_vrss")]
+    pub tick_vrss: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0upcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내지수 실시간예상체결 [실시간-027]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0upanc0Response {
+    /// 업종 구분 코드
+    #[serde(default, rename = "BSTP_CLS_CODE")]
+    pub bstp_cls_code: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 현재가 지수
+    #[serde(default, rename = "PRPR_NMIX")]
+    pub prpr_nmix: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 업종 지수 전일 대비
+    #[serde(default, rename = "BSTP_NMIX_PRDY_VRSS")]
+    pub bstp_nmix_prdy_vrss: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 건별 거래량
+    #[serde(default, rename = "PCAS_VOL")]
+    pub pcas_vol: String,
+    /// 건별 거래 대금
+    #[serde(default, rename = "PCAS_TR_PBMN")]
+    pub pcas_tr_pbmn: String,
+    /// 전일 대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 시가 지수
+    #[serde(default, rename = "OPRC_NMIX")]
+    pub oprc_nmix: String,
+    /// 지수 최고가
+    #[serde(default, rename = "NMIX_HGPR")]
+    pub nmix_hgpr: String,
+    /// 지수 최저가
+    #[serde(default, rename = "NMIX_LWPR")]
+    pub nmix_lwpr: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 시가 대비 지수 부호
+    #[serde(default, rename = "OPRC_VRSS_NMIX_SIGN")]
+    pub oprc_vrss_nmix_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최고가 대비 지수 부호
+    #[serde(default, rename = "HGPR_VRSS_NMIX_SIGN")]
+    pub hgpr_vrss_nmix_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 최저가 대비 지수 부호
+    #[serde(default, rename = "LWPR_VRSS_NMIX_SIGN")]
+    pub lwpr_vrss_nmix_sign: String,
+    /// 전일 종가 대비 시가2 비율
+    #[serde(default, rename = "PRDY_CLPR_VRSS_OPRC_RATE")]
+    pub prdy_clpr_vrss_oprc_rate: String,
+    /// 전일 종가 대비 최고가 비율
+    #[serde(default, rename = "PRDY_CLPR_VRSS_HGPR_RATE")]
+    pub prdy_clpr_vrss_hgpr_rate: String,
+    /// 전일 종가 대비 최저가 비율
+    #[serde(default, rename = "PRDY_CLPR_VRSS_LWPR_RATE")]
+    pub prdy_clpr_vrss_lwpr_rate: String,
+    /// 상한 종목 수
+    #[serde(default, rename = "UPLM_ISSU_CNT")]
+    pub uplm_issu_cnt: String,
+    /// 상승 종목 수
+    #[serde(default, rename = "ASCN_ISSU_CNT")]
+    pub ascn_issu_cnt: String,
+    /// 보합 종목 수
+    #[serde(default, rename = "STNR_ISSU_CNT")]
+    pub stnr_issu_cnt: String,
+    /// 하락 종목 수
+    #[serde(default, rename = "DOWN_ISSU_CNT")]
+    pub down_issu_cnt: String,
+    /// 하한 종목 수
+    #[serde(default, rename = "LSLM_ISSU_CNT")]
+    pub lslm_issu_cnt: String,
+    /// 기세 상승 종목수
+    #[serde(default, rename = "QTQT_ASCN_ISSU_CNT")]
+    pub qtqt_ascn_issu_cnt: String,
+    /// 기세 하락 종목수
+    #[serde(default, rename = "QTQT_DOWN_ISSU_CNT")]
+    pub qtqt_down_issu_cnt: String,
+    /// TICK대비
+    #[serde(default, rename = "TICK_VRSS")]
+    pub tick_vrss: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0upanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내지수 실시간프로그램매매 [실시간-028]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0uppgm0Response {
+    /// 업종 구분 코드
+    #[serde(default, rename = "BSTP_CLS_CODE")]
+    pub bstp_cls_code: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 차익 매도 위탁 체결량
+    #[serde(default, rename = "ARBT_SELN_ENTM_CNQN")]
+    pub arbt_seln_entm_cnqn: String,
+    /// 차익 매도 자기 체결량
+    #[serde(default, rename = "ARBT_SELN_ONSL_CNQN")]
+    pub arbt_seln_onsl_cnqn: String,
+    /// 차익 매수2 위탁 체결량
+    #[serde(default, rename = "ARBT_SHNU_ENTM_CNQN")]
+    pub arbt_shnu_entm_cnqn: String,
+    /// 차익 매수2 자기 체결량
+    #[serde(default, rename = "ARBT_SHNU_ONSL_CNQN")]
+    pub arbt_shnu_onsl_cnqn: String,
+    /// 비차익 매도 위탁 체결량
+    #[serde(default, rename = "NABT_SELN_ENTM_CNQN")]
+    pub nabt_seln_entm_cnqn: String,
+    /// 비차익 매도 자기 체결량
+    #[serde(default, rename = "NABT_SELN_ONSL_CNQN")]
+    pub nabt_seln_onsl_cnqn: String,
+    /// 비차익 매수2 위탁 체결량
+    #[serde(default, rename = "NABT_SHNU_ENTM_CNQN")]
+    pub nabt_shnu_entm_cnqn: String,
+    /// 비차익 매수2 자기 체결량
+    #[serde(default, rename = "NABT_SHNU_ONSL_CNQN")]
+    pub nabt_shnu_onsl_cnqn: String,
+    /// 차익 매도 위탁 체결 금액
+    #[serde(default, rename = "ARBT_SELN_ENTM_CNTG_AMT")]
+    pub arbt_seln_entm_cntg_amt: String,
+    /// 차익 매도 자기 체결 금액
+    #[serde(default, rename = "ARBT_SELN_ONSL_CNTG_AMT")]
+    pub arbt_seln_onsl_cntg_amt: String,
+    /// 차익 매수2 위탁 체결 금액
+    #[serde(default, rename = "ARBT_SHNU_ENTM_CNTG_AMT")]
+    pub arbt_shnu_entm_cntg_amt: String,
+    /// 차익 매수2 자기 체결 금액
+    #[serde(default, rename = "ARBT_SHNU_ONSL_CNTG_AMT")]
+    pub arbt_shnu_onsl_cntg_amt: String,
+    /// 비차익 매도 위탁 체결 금액
+    #[serde(default, rename = "NABT_SELN_ENTM_CNTG_AMT")]
+    pub nabt_seln_entm_cntg_amt: String,
+    /// 비차익 매도 자기 체결 금액
+    #[serde(default, rename = "NABT_SELN_ONSL_CNTG_AMT")]
+    pub nabt_seln_onsl_cntg_amt: String,
+    /// 비차익 매수2 위탁 체결 금액
+    #[serde(default, rename = "NABT_SHNU_ENTM_CNTG_AMT")]
+    pub nabt_shnu_entm_cntg_amt: String,
+    /// 비차익 매수2 자기 체결 금액
+    #[serde(default, rename = "NABT_SHNU_ONSL_CNTG_AMT")]
+    pub nabt_shnu_onsl_cntg_amt: String,
+    /// 차익 합계 매도 거래량
+    #[serde(default, rename = "ARBT_SMTN_SELN_VOL")]
+    pub arbt_smtn_seln_vol: String,
+    /// 차익 합계 매도 거래량 비율
+    #[serde(default, rename = "ARBT_SMTM_SELN_VOL_RATE")]
+    pub arbt_smtm_seln_vol_rate: String,
+    /// 차익 합계 매도 거래 대금
+    #[serde(default, rename = "ARBT_SMTN_SELN_TR_PBMN")]
+    pub arbt_smtn_seln_tr_pbmn: String,
+    /// 차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "ARBT_SMTM_SELN_TR_PBMN_RATE")]
+    pub arbt_smtm_seln_tr_pbmn_rate: String,
+    /// 차익 합계 매수2 거래량
+    #[serde(default, rename = "ARBT_SMTN_SHNU_VOL")]
+    pub arbt_smtn_shnu_vol: String,
+    /// 차익 합계 매수 거래량 비율
+    #[serde(default, rename = "ARBT_SMTM_SHNU_VOL_RATE")]
+    pub arbt_smtm_shnu_vol_rate: String,
+    /// 차익 합계 매수2 거래 대금
+    #[serde(default, rename = "ARBT_SMTN_SHNU_TR_PBMN")]
+    pub arbt_smtn_shnu_tr_pbmn: String,
+    /// 차익 합계 매수 거래대금 비율
+    #[serde(default, rename = "ARBT_SMTM_SHNU_TR_PBMN_RATE")]
+    pub arbt_smtm_shnu_tr_pbmn_rate: String,
+    /// 차익 합계 순매수 수량
+    #[serde(default, rename = "ARBT_SMTN_NTBY_QTY")]
+    pub arbt_smtn_ntby_qty: String,
+    /// 차익 합계 순매수 수량 비율
+    #[serde(default, rename = "ARBT_SMTM_NTBY_QTY_RATE")]
+    pub arbt_smtm_ntby_qty_rate: String,
+    /// 차익 합계 순매수 거래 대금
+    #[serde(default, rename = "ARBT_SMTN_NTBY_TR_PBMN")]
+    pub arbt_smtn_ntby_tr_pbmn: String,
+    /// 차익 합계 순매수 거래대금 비율
+    #[serde(default, rename = "ARBT_SMTM_NTBY_TR_PBMN_RATE")]
+    pub arbt_smtm_ntby_tr_pbmn_rate: String,
+    /// 비차익 합계 매도 거래량
+    #[serde(default, rename = "NABT_SMTN_SELN_VOL")]
+    pub nabt_smtn_seln_vol: String,
+    /// 비차익 합계 매도 거래량 비율
+    #[serde(default, rename = "NABT_SMTM_SELN_VOL_RATE")]
+    pub nabt_smtm_seln_vol_rate: String,
+    /// 비차익 합계 매도 거래 대금
+    #[serde(default, rename = "NABT_SMTN_SELN_TR_PBMN")]
+    pub nabt_smtn_seln_tr_pbmn: String,
+    /// 비차익 합계 매도 거래대금 비율
+    #[serde(default, rename = "NABT_SMTM_SELN_TR_PBMN_RATE")]
+    pub nabt_smtm_seln_tr_pbmn_rate: String,
+    /// 비차익 합계 매수2 거래량
+    #[serde(default, rename = "NABT_SMTN_SHNU_VOL")]
+    pub nabt_smtn_shnu_vol: String,
+    /// 비차익 합계 매수 거래량 비율
+    #[serde(default, rename = "NABT_SMTM_SHNU_VOL_RATE")]
+    pub nabt_smtm_shnu_vol_rate: String,
+    /// 비차익 합계 매수2 거래 대금
+    #[serde(default, rename = "NABT_SMTN_SHNU_TR_PBMN")]
+    pub nabt_smtn_shnu_tr_pbmn: String,
+    /// 비차익 합계 매수 거래대금 비율
+    #[serde(default, rename = "NABT_SMTM_SHNU_TR_PBMN_RATE")]
+    pub nabt_smtm_shnu_tr_pbmn_rate: String,
+    /// 비차익 합계 순매수 수량
+    #[serde(default, rename = "NABT_SMTN_NTBY_QTY")]
+    pub nabt_smtn_ntby_qty: String,
+    /// 비차익 합계 순매수 수량 비율
+    #[serde(default, rename = "NABT_SMTM_NTBY_QTY_RATE")]
+    pub nabt_smtm_ntby_qty_rate: String,
+    /// 비차익 합계 순매수 거래 대금
+    #[serde(default, rename = "NABT_SMTN_NTBY_TR_PBMN")]
+    pub nabt_smtn_ntby_tr_pbmn: String,
+    /// 비차익 합계 순매수 거래대금 비
+    #[serde(default, rename = "NABT_SMTM_NTBY_TR_PBMN_RATE")]
+    pub nabt_smtm_ntby_tr_pbmn_rate: String,
+    /// 전체 위탁 매도 거래량
+    #[serde(default, rename = "WHOL_ENTM_SELN_VOL")]
+    pub whol_entm_seln_vol: String,
+    /// 위탁 매도 거래량 비율
+    #[serde(default, rename = "ENTM_SELN_VOL_RATE")]
+    pub entm_seln_vol_rate: String,
+    /// 전체 위탁 매도 거래 대금
+    #[serde(default, rename = "WHOL_ENTM_SELN_TR_PBMN")]
+    pub whol_entm_seln_tr_pbmn: String,
+    /// 위탁 매도 거래대금 비율
+    #[serde(default, rename = "ENTM_SELN_TR_PBMN_RATE")]
+    pub entm_seln_tr_pbmn_rate: String,
+    /// 전체 위탁 매수2 거래량
+    #[serde(default, rename = "WHOL_ENTM_SHNU_VOL")]
+    pub whol_entm_shnu_vol: String,
+    /// 위탁 매수 거래량 비율
+    #[serde(default, rename = "ENTM_SHNU_VOL_RATE")]
+    pub entm_shnu_vol_rate: String,
+    /// 전체 위탁 매수2 거래 대금
+    #[serde(default, rename = "WHOL_ENTM_SHNU_TR_PBMN")]
+    pub whol_entm_shnu_tr_pbmn: String,
+    /// 위탁 매수 거래대금 비율
+    #[serde(default, rename = "ENTM_SHNU_TR_PBMN_RATE")]
+    pub entm_shnu_tr_pbmn_rate: String,
+    /// 전체 위탁 순매수 수량
+    #[serde(default, rename = "WHOL_ENTM_NTBY_QT")]
+    pub whol_entm_ntby_qt: String,
+    /// 위탁 순매수 수량 비율
+    #[serde(default, rename = "ENTM_NTBY_QTY_RAT")]
+    pub entm_ntby_qty_rat: String,
+    /// 전체 위탁 순매수 거래 대금
+    #[serde(default, rename = "WHOL_ENTM_NTBY_TR_PBMN")]
+    pub whol_entm_ntby_tr_pbmn: String,
+    /// 위탁 순매수 금액 비율
+    #[serde(default, rename = "ENTM_NTBY_TR_PBMN_RATE")]
+    pub entm_ntby_tr_pbmn_rate: String,
+    /// 전체 자기 매도 거래량
+    #[serde(default, rename = "WHOL_ONSL_SELN_VOL")]
+    pub whol_onsl_seln_vol: String,
+    /// 자기 매도 거래량 비율
+    #[serde(default, rename = "ONSL_SELN_VOL_RATE")]
+    pub onsl_seln_vol_rate: String,
+    /// 전체 자기 매도 거래 대금
+    #[serde(default, rename = "WHOL_ONSL_SELN_TR_PBMN")]
+    pub whol_onsl_seln_tr_pbmn: String,
+    /// 자기 매도 거래대금 비율
+    #[serde(default, rename = "ONSL_SELN_TR_PBMN_RATE")]
+    pub onsl_seln_tr_pbmn_rate: String,
+    /// 전체 자기 매수2 거래량
+    #[serde(default, rename = "WHOL_ONSL_SHNU_VOL")]
+    pub whol_onsl_shnu_vol: String,
+    /// 자기 매수 거래량 비율
+    #[serde(default, rename = "ONSL_SHNU_VOL_RATE")]
+    pub onsl_shnu_vol_rate: String,
+    /// 전체 자기 매수2 거래 대금
+    #[serde(default, rename = "WHOL_ONSL_SHNU_TR_PBMN")]
+    pub whol_onsl_shnu_tr_pbmn: String,
+    /// 자기 매수 거래대금 비율
+    #[serde(default, rename = "ONSL_SHNU_TR_PBMN_RATE")]
+    pub onsl_shnu_tr_pbmn_rate: String,
+    /// 전체 자기 순매수 수량
+    #[serde(default, rename = "WHOL_ONSL_NTBY_QTY")]
+    pub whol_onsl_ntby_qty: String,
+    /// 자기 순매수량 비율
+    #[serde(default, rename = "ONSL_NTBY_QTY_RATE")]
+    pub onsl_ntby_qty_rate: String,
+    /// 전체 자기 순매수 거래 대금
+    #[serde(default, rename = "WHOL_ONSL_NTBY_TR_PBMN")]
+    pub whol_onsl_ntby_tr_pbmn: String,
+    /// 자기 순매수 대금 비율
+    #[serde(default, rename = "ONSL_NTBY_TR_PBMN_RATE")]
+    pub onsl_ntby_tr_pbmn_rate: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "TOTAL_SELN_QTY")]
+    pub total_seln_qty: String,
+    /// 전체 매도 거래량 비율
+    #[serde(default, rename = "WHOL_SELN_VOL_RATE")]
+    pub whol_seln_vol_rate: String,
+    /// 총 매도 거래 대금
+    #[serde(default, rename = "TOTAL_SELN_TR_PBMN")]
+    pub total_seln_tr_pbmn: String,
+    /// 전체 매도 거래대금 비율
+    #[serde(default, rename = "WHOL_SELN_TR_PBMN_RATE")]
+    pub whol_seln_tr_pbmn_rate: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 전체 매수 거래량 비율
+    #[serde(default, rename = "WHOL_SHUN_VOL_RATE")]
+    pub whol_shun_vol_rate: String,
+    /// 총 매수2 거래 대금
+    #[serde(default, rename = "TOTAL_SHNU_TR_PBMN")]
+    pub total_shnu_tr_pbmn: String,
+    /// 전체 매수 거래대금 비율
+    #[serde(default, rename = "WHOL_SHUN_TR_PBMN_RATE")]
+    pub whol_shun_tr_pbmn_rate: String,
+    /// 전체 순매수 수량
+    #[serde(default, rename = "WHOL_NTBY_QTY")]
+    pub whol_ntby_qty: String,
+    /// 전체 합계 순매수 수량 비율
+    #[serde(default, rename = "WHOL_SMTM_NTBY_QTY_RATE")]
+    pub whol_smtm_ntby_qty_rate: String,
+    /// 전체 순매수 거래 대금
+    #[serde(default, rename = "WHOL_NTBY_TR_PBMN")]
+    pub whol_ntby_tr_pbmn: String,
+    /// 전체 순매수 거래대금 비율
+    #[serde(default, rename = "WHOL_NTBY_TR_PBMN_RATE")]
+    pub whol_ntby_tr_pbmn_rate: String,
+    /// 차익 위탁 순매수 수량
+    #[serde(default, rename = "ARBT_ENTM_NTBY_QTY")]
+    pub arbt_entm_ntby_qty: String,
+    /// 차익 위탁 순매수 거래 대금
+    #[serde(default, rename = "ARBT_ENTM_NTBY_TR_PBMN")]
+    pub arbt_entm_ntby_tr_pbmn: String,
+    /// 차익 자기 순매수 수량
+    #[serde(default, rename = "ARBT_ONSL_NTBY_QTY")]
+    pub arbt_onsl_ntby_qty: String,
+    /// 차익 자기 순매수 거래 대금
+    #[serde(default, rename = "ARBT_ONSL_NTBY_TR_PBMN")]
+    pub arbt_onsl_ntby_tr_pbmn: String,
+    /// 비차익 위탁 순매수 수량
+    #[serde(default, rename = "NABT_ENTM_NTBY_QTY")]
+    pub nabt_entm_ntby_qty: String,
+    /// 비차익 위탁 순매수 거래 대금
+    #[serde(default, rename = "NABT_ENTM_NTBY_TR_PBMN")]
+    pub nabt_entm_ntby_tr_pbmn: String,
+    /// 비차익 자기 순매수 수량
+    #[serde(default, rename = "NABT_ONSL_NTBY_QTY")]
+    pub nabt_onsl_ntby_qty: String,
+    /// 비차익 자기 순매수 거래 대금
+    #[serde(default, rename = "NABT_ONSL_NTBY_TR_PBMN")]
+    pub nabt_onsl_ntby_tr_pbmn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0uppgm0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [ELW 실시간호가 [실시간-062]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ewasp0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 시간구분코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "ASKP10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "BIDP10")]
+    pub bidp10: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 예상체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상체결량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// LP매도호가잔량1
+    #[serde(default, rename = "LP_ASKP_RSQN1")]
+    pub lp_askp_rsqn1: String,
+    /// LP매도호가잔량2
+    #[serde(default, rename = "LP_ASKP_RSQN2")]
+    pub lp_askp_rsqn2: String,
+    /// LP매도호가잔량3
+    #[serde(default, rename = "LP_ASKP_RSQN3")]
+    pub lp_askp_rsqn3: String,
+    /// LP매수호가잔량4
+    #[serde(default, rename = "LP_BIDP_RSQN4")]
+    pub lp_bidp_rsqn4: String,
+    /// LP매도호가잔량4
+    #[serde(default, rename = "LP_ASKP_RSQN4")]
+    pub lp_askp_rsqn4: String,
+    /// LP매수호가잔량5
+    #[serde(default, rename = "LP_BIDP_RSQN5")]
+    pub lp_bidp_rsqn5: String,
+    /// LP매도호가잔량5
+    #[serde(default, rename = "LP_ASKP_RSQN5")]
+    pub lp_askp_rsqn5: String,
+    /// LP매수호가잔량6
+    #[serde(default, rename = "LP_BIDP_RSQN6")]
+    pub lp_bidp_rsqn6: String,
+    /// LP매도호가잔량6
+    #[serde(default, rename = "LP_ASKP_RSQN6")]
+    pub lp_askp_rsqn6: String,
+    /// LP매수호가잔량7
+    #[serde(default, rename = "LP_BIDP_RSQN7")]
+    pub lp_bidp_rsqn7: String,
+    /// LP매도호가잔량7
+    #[serde(default, rename = "LP_ASKP_RSQN7")]
+    pub lp_askp_rsqn7: String,
+    /// LP매도호가잔량8
+    #[serde(default, rename = "LP_ASKP_RSQN8")]
+    pub lp_askp_rsqn8: String,
+    /// LP매수호가잔량8
+    #[serde(default, rename = "LP_BIDP_RSQN8")]
+    pub lp_bidp_rsqn8: String,
+    /// LP매도호가잔량9
+    #[serde(default, rename = "LP_ASKP_RSQN9")]
+    pub lp_askp_rsqn9: String,
+    /// LP매수호가잔량9
+    #[serde(default, rename = "LP_BIDP_RSQN9")]
+    pub lp_bidp_rsqn9: String,
+    /// LP매도호가잔량10
+    #[serde(default, rename = "LP_ASKP_RSQN10")]
+    pub lp_askp_rsqn10: String,
+    /// LP매수호가잔량10
+    #[serde(default, rename = "LP_BIDP_RSQN10")]
+    pub lp_bidp_rsqn10: String,
+    /// LP매수호가잔량1
+    #[serde(default, rename = "LP_BIDP_RSQN1")]
+    pub lp_bidp_rsqn1: String,
+    /// LP총매도호가잔량
+    #[serde(default, rename = "LP_TOTAL_ASKP_RSQN")]
+    pub lp_total_askp_rsqn: String,
+    /// LP매수호가잔량2
+    #[serde(default, rename = "LP_BIDP_RSQN2")]
+    pub lp_bidp_rsqn2: String,
+    /// LP총매수호가잔량
+    #[serde(default, rename = "LP_TOTAL_BIDP_RSQN")]
+    pub lp_total_bidp_rsqn: String,
+    /// LP매수호가잔량3
+    #[serde(default, rename = "LP_BIDP_RSQN3")]
+    pub lp_bidp_rsqn3: String,
+    /// 예상거래량
+    #[serde(default, rename = "ANTC_VOL")]
+    pub antc_vol: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ewasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [ELW 실시간체결가 [실시간-061]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ewcnt0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 주식시가2
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 체결거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분코드
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수2비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가2대비현재가
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비현재가
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비현재가
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간가치값
+    #[serde(default, rename = "TMVL_VAL")]
+    pub tmvl_val: String,
+    /// 패리티
+    #[serde(default, rename = "PRIT")]
+    pub prit: String,
+    /// 프리미엄값
+    #[serde(default, rename = "PRMM_VAL")]
+    pub prmm_val: String,
+    /// 기어링
+    #[serde(default, rename = "GEAR")]
+    pub gear: String,
+    /// 손익분기비율
+    #[serde(default, rename = "PRLS_QRYR_RATE")]
+    pub prls_qryr_rate: String,
+    /// 내재가치값
+    #[serde(default, rename = "INVL_VAL")]
+    pub invl_val: String,
+    /// 프리미엄비율
+    #[serde(default, rename = "PRMM_RATE")]
+    pub prmm_rate: String,
+    /// 자본지지점
+    #[serde(default, rename = "CFP")]
+    pub cfp: String,
+    /// 레버리지값
+    #[serde(default, rename = "LVRG_VAL")]
+    pub lvrg_val: String,
+    /// 델타
+    #[serde(default, rename = "DELTA")]
+    pub delta: String,
+    /// 감마
+    #[serde(default, rename = "GAMA")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "VEGA")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "THETA")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "RHO")]
+    pub rho: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "HTS_INTS_VLTL")]
+    pub hts_ints_vltl: String,
+    /// HTS이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 접근도
+    #[serde(default, rename = "APPRCH_RATE")]
+    pub apprch_rate: String,
+    /// LP보유량
+    #[serde(default, rename = "LP_HVOL")]
+    pub lp_hvol: String,
+    /// LP보유비율
+    #[serde(default, rename = "LP_HLDN_RATE")]
+    pub lp_hldn_rate: String,
+    /// LP순매도량
+    #[serde(default, rename = "LP_NTBY_QTY")]
+    pub lp_ntby_qty: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ewcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [ELW 실시간예상체결 [실시간-063]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ewanc0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 주식시가2
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 체결거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분코드
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수2비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가2대비현재가
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비현재가
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비현재가
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간가치값
+    #[serde(default, rename = "TMVL_VAL")]
+    pub tmvl_val: String,
+    /// 패리티
+    #[serde(default, rename = "PRIT")]
+    pub prit: String,
+    /// 프리미엄값
+    #[serde(default, rename = "PRMM_VAL")]
+    pub prmm_val: String,
+    /// 기어링
+    #[serde(default, rename = "GEAR")]
+    pub gear: String,
+    /// 손익분기비율
+    #[serde(default, rename = "PRLS_QRYR_RATE")]
+    pub prls_qryr_rate: String,
+    /// 내재가치값
+    #[serde(default, rename = "INVL_VAL")]
+    pub invl_val: String,
+    /// 프리미엄비율
+    #[serde(default, rename = "PRMM_RATE")]
+    pub prmm_rate: String,
+    /// 자본지지점
+    #[serde(default, rename = "CFP")]
+    pub cfp: String,
+    /// 레버리지값
+    #[serde(default, rename = "LVRG_VAL")]
+    pub lvrg_val: String,
+    /// 델타
+    #[serde(default, rename = "DELTA")]
+    pub delta: String,
+    /// 감마
+    #[serde(default, rename = "GAMA")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "VEGA")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "THETA")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "RHO")]
+    pub rho: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "HTS_INTS_VLTL")]
+    pub hts_ints_vltl: String,
+    /// HTS이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// LP보유량
+    #[serde(default, rename = "LP_HVOL")]
+    pub lp_hvol: String,
+    /// LP보유비율
+    #[serde(default, rename = "LP_HLDN_RATE")]
+    pub lp_hldn_rate: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ewanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내ETF NAV추이 [실시간-051]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0stnav0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// NAV
+    #[serde(default, rename = "NAV")]
+    pub nav: String,
+    /// NAV전일대비부호
+    #[serde(default, rename = "NAV_PRDY_VRSS_SIGN")]
+    pub nav_prdy_vrss_sign: String,
+    /// NAV전일대비
+    #[serde(default, rename = "NAV_PRDY_VRSS")]
+    pub nav_prdy_vrss: String,
+    /// NAV전일대비율
+    #[serde(default, rename = "NAV_PRDY_CTRT")]
+    pub nav_prdy_ctrt: String,
+    /// NAV시가
+    #[serde(default, rename = "OPRC_NAV")]
+    pub oprc_nav: String,
+    /// NAV고가
+    #[serde(default, rename = "HPRC_NAV")]
+    pub hprc_nav: String,
+    /// NAV저가
+    #[serde(default, rename = "LPRC_NAV")]
+    pub lprc_nav: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0stnav0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간체결가 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0uncnt0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중 평균 주식 가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 주식 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 체결 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업 일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신 장운영 구분 코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일 동시간 누적 거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일 동시간 누적 거래량 비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 정적VI발동기준가
+    #[serde(default, rename = "VI_STND_PRC")]
+    pub vi_stnd_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0uncnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간호가 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0unasp0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "ASKP10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "BIDP10")]
+    pub bidp10: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가 잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가 잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가 잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가 잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가 잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가 잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가 잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가 잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가 잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가 잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_RSQN")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_RSQN")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 예상 체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상 체결량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 예상 거래량
+    #[serde(default, rename = "ANTC_VOL")]
+    pub antc_vol: String,
+    /// 예상 체결 대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외 총 매도호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_ICDC")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외 총 매수호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_ICDC")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 주식 매매 구분 코드
+    #[serde(default, rename = "STCK_DEAL_CLS_CODE")]
+    pub stck_deal_cls_code: String,
+    /// KRX 중간가
+    #[serde(default, rename = "KMID_PRC")]
+    pub kmid_prc: String,
+    /// KRX 중간가잔량합계수량
+    #[serde(default, rename = "KMID_TOTAL_RSQN")]
+    pub kmid_total_rsqn: String,
+    /// KRX 중간가 매수매도 구분
+    #[serde(default, rename = "KMID_CLS_CODE")]
+    pub kmid_cls_code: String,
+    /// NXT 중간가
+    #[serde(default, rename = "NMID_PRC")]
+    pub nmid_prc: String,
+    /// NXT 중간가잔량합계수량
+    #[serde(default, rename = "NMID_TOTAL_RSQN")]
+    pub nmid_total_rsqn: String,
+    /// NXT 중간가 매수매도 구분
+    #[serde(default, rename = "NMID_CLS_CODE")]
+    pub nmid_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0unasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간예상체결 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0unanc0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비구분
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 등락율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간구분코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// VI 상태값
+    #[serde(default, rename = "VI_STND_PRC")]
+    pub vi_stnd_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0unanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간회원사 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0unmbc0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 매도2 회원사명1
+    #[serde(default, rename = "SELN2_MBCR_NAME1")]
+    pub seln2_mbcr_name1: String,
+    /// 매도2 회원사명2
+    #[serde(default, rename = "SELN2_MBCR_NAME2")]
+    pub seln2_mbcr_name2: String,
+    /// 매도2 회원사명3
+    #[serde(default, rename = "SELN2_MBCR_NAME3")]
+    pub seln2_mbcr_name3: String,
+    /// 매도2 회원사명4
+    #[serde(default, rename = "SELN2_MBCR_NAME4")]
+    pub seln2_mbcr_name4: String,
+    /// 매도2 회원사명5
+    #[serde(default, rename = "SELN2_MBCR_NAME5")]
+    pub seln2_mbcr_name5: String,
+    /// 매수 회원사명1
+    #[serde(default, rename = "BYOV_MBCR_NAME1")]
+    pub byov_mbcr_name1: String,
+    /// 매수 회원사명2
+    #[serde(default, rename = "BYOV_MBCR_NAME2")]
+    pub byov_mbcr_name2: String,
+    /// 매수 회원사명3
+    #[serde(default, rename = "BYOV_MBCR_NAME3")]
+    pub byov_mbcr_name3: String,
+    /// 매수 회원사명4
+    #[serde(default, rename = "BYOV_MBCR_NAME4")]
+    pub byov_mbcr_name4: String,
+    /// 매수 회원사명5
+    #[serde(default, rename = "BYOV_MBCR_NAME5")]
+    pub byov_mbcr_name5: String,
+    /// 총 매도 수량1
+    #[serde(default, rename = "TOTAL_SELN_QTY1")]
+    pub total_seln_qty1: String,
+    /// 총 매도 수량2
+    #[serde(default, rename = "TOTAL_SELN_QTY2")]
+    pub total_seln_qty2: String,
+    /// 총 매도 수량3
+    #[serde(default, rename = "TOTAL_SELN_QTY3")]
+    pub total_seln_qty3: String,
+    /// 총 매도 수량4
+    #[serde(default, rename = "TOTAL_SELN_QTY4")]
+    pub total_seln_qty4: String,
+    /// 총 매도 수량5
+    #[serde(default, rename = "TOTAL_SELN_QTY5")]
+    pub total_seln_qty5: String,
+    /// 총 매수2 수량1
+    #[serde(default, rename = "TOTAL_SHNU_QTY1")]
+    pub total_shnu_qty1: String,
+    /// 총 매수2 수량2
+    #[serde(default, rename = "TOTAL_SHNU_QTY2")]
+    pub total_shnu_qty2: String,
+    /// 총 매수2 수량3
+    #[serde(default, rename = "TOTAL_SHNU_QTY3")]
+    pub total_shnu_qty3: String,
+    /// 총 매수2 수량4
+    #[serde(default, rename = "TOTAL_SHNU_QTY4")]
+    pub total_shnu_qty4: String,
+    /// 총 매수2 수량5
+    #[serde(default, rename = "TOTAL_SHNU_QTY5")]
+    pub total_shnu_qty5: String,
+    /// 매도거래원구분1
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_1")]
+    pub seln_mbcr_glob_yn_1: String,
+    /// 매도거래원구분2
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_2")]
+    pub seln_mbcr_glob_yn_2: String,
+    /// 매도거래원구분3
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_3")]
+    pub seln_mbcr_glob_yn_3: String,
+    /// 매도거래원구분4
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_4")]
+    pub seln_mbcr_glob_yn_4: String,
+    /// 매도거래원구분5
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_5")]
+    pub seln_mbcr_glob_yn_5: String,
+    /// 매수거래원구분1
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_1")]
+    pub shnu_mbcr_glob_yn_1: String,
+    /// 매수거래원구분2
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_2")]
+    pub shnu_mbcr_glob_yn_2: String,
+    /// 매수거래원구분3
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_3")]
+    pub shnu_mbcr_glob_yn_3: String,
+    /// 매수거래원구분4
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_4")]
+    pub shnu_mbcr_glob_yn_4: String,
+    /// 매수거래원구분5
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_5")]
+    pub shnu_mbcr_glob_yn_5: String,
+    /// 매도거래원코드1
+    #[serde(default, rename = "SELN_MBCR_NO1")]
+    pub seln_mbcr_no1: String,
+    /// 매도거래원코드2
+    #[serde(default, rename = "SELN_MBCR_NO2")]
+    pub seln_mbcr_no2: String,
+    /// 매도거래원코드3
+    #[serde(default, rename = "SELN_MBCR_NO3")]
+    pub seln_mbcr_no3: String,
+    /// 매도거래원코드4
+    #[serde(default, rename = "SELN_MBCR_NO4")]
+    pub seln_mbcr_no4: String,
+    /// 매도거래원코드5
+    #[serde(default, rename = "SELN_MBCR_NO5")]
+    pub seln_mbcr_no5: String,
+    /// 매수거래원코드1
+    #[serde(default, rename = "SHNU_MBCR_NO1")]
+    pub shnu_mbcr_no1: String,
+    /// 매수거래원코드2
+    #[serde(default, rename = "SHNU_MBCR_NO2")]
+    pub shnu_mbcr_no2: String,
+    /// 매수거래원코드3
+    #[serde(default, rename = "SHNU_MBCR_NO3")]
+    pub shnu_mbcr_no3: String,
+    /// 매수거래원코드4
+    #[serde(default, rename = "SHNU_MBCR_NO4")]
+    pub shnu_mbcr_no4: String,
+    /// 매수거래원코드5
+    #[serde(default, rename = "SHNU_MBCR_NO5")]
+    pub shnu_mbcr_no5: String,
+    /// 매도 회원사 비중1
+    #[serde(default, rename = "SELN_MBCR_RLIM1")]
+    pub seln_mbcr_rlim1: String,
+    /// 매도 회원사 비중2
+    #[serde(default, rename = "SELN_MBCR_RLIM2")]
+    pub seln_mbcr_rlim2: String,
+    /// 매도 회원사 비중3
+    #[serde(default, rename = "SELN_MBCR_RLIM3")]
+    pub seln_mbcr_rlim3: String,
+    /// 매도 회원사 비중4
+    #[serde(default, rename = "SELN_MBCR_RLIM4")]
+    pub seln_mbcr_rlim4: String,
+    /// 매도 회원사 비중5
+    #[serde(default, rename = "SELN_MBCR_RLIM5")]
+    pub seln_mbcr_rlim5: String,
+    /// 매수2 회원사 비중1
+    #[serde(default, rename = "SHNU_MBCR_RLIM1")]
+    pub shnu_mbcr_rlim1: String,
+    /// 매수2 회원사 비중2
+    #[serde(default, rename = "SHNU_MBCR_RLIM2")]
+    pub shnu_mbcr_rlim2: String,
+    /// 매수2 회원사 비중3
+    #[serde(default, rename = "SHNU_MBCR_RLIM3")]
+    pub shnu_mbcr_rlim3: String,
+    /// 매수2 회원사 비중4
+    #[serde(default, rename = "SHNU_MBCR_RLIM4")]
+    pub shnu_mbcr_rlim4: String,
+    /// 매수2 회원사 비중5
+    #[serde(default, rename = "SHNU_MBCR_RLIM5")]
+    pub shnu_mbcr_rlim5: String,
+    /// 매도 수량 증감1
+    #[serde(default, rename = "SELN_QTY_ICDC1")]
+    pub seln_qty_icdc1: String,
+    /// 매도 수량 증감2
+    #[serde(default, rename = "SELN_QTY_ICDC2")]
+    pub seln_qty_icdc2: String,
+    /// 매도 수량 증감3
+    #[serde(default, rename = "SELN_QTY_ICDC3")]
+    pub seln_qty_icdc3: String,
+    /// 매도 수량 증감4
+    #[serde(default, rename = "SELN_QTY_ICDC4")]
+    pub seln_qty_icdc4: String,
+    /// 매도 수량 증감5
+    #[serde(default, rename = "SELN_QTY_ICDC5")]
+    pub seln_qty_icdc5: String,
+    /// 매수2 수량 증감1
+    #[serde(default, rename = "SHNU_QTY_ICDC1")]
+    pub shnu_qty_icdc1: String,
+    /// 매수2 수량 증감2
+    #[serde(default, rename = "SHNU_QTY_ICDC2")]
+    pub shnu_qty_icdc2: String,
+    /// 매수2 수량 증감3
+    #[serde(default, rename = "SHNU_QTY_ICDC3")]
+    pub shnu_qty_icdc3: String,
+    /// 매수2 수량 증감4
+    #[serde(default, rename = "SHNU_QTY_ICDC4")]
+    pub shnu_qty_icdc4: String,
+    /// 매수2 수량 증감5
+    #[serde(default, rename = "SHNU_QTY_ICDC5")]
+    pub shnu_qty_icdc5: String,
+    /// 외국계 총 매도 수량
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY")]
+    pub glob_total_seln_qty: String,
+    /// 외국계 총 매수2 수량
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY")]
+    pub glob_total_shnu_qty: String,
+    /// 외국계 총 매도 수량 증감
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY_ICDC")]
+    pub glob_total_seln_qty_icdc: String,
+    /// 외국계 총 매수2 수량 증감
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY_ICDC")]
+    pub glob_total_shnu_qty_icdc: String,
+    /// 외국계 순매수 수량
+    #[serde(default, rename = "GLOB_NTBY_QTY")]
+    pub glob_ntby_qty: String,
+    /// 외국계 매도 비중
+    #[serde(default, rename = "GLOB_SELN_RLIM")]
+    pub glob_seln_rlim: String,
+    /// 외국계 매수2 비중
+    #[serde(default, rename = "GLOB_SHNU_RLIM")]
+    pub glob_shnu_rlim: String,
+    /// 매도2 영문회원사명1
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME1")]
+    pub seln2_mbcr_eng_name1: String,
+    /// 매도2 영문회원사명2
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME2")]
+    pub seln2_mbcr_eng_name2: String,
+    /// 매도2 영문회원사명3
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME3")]
+    pub seln2_mbcr_eng_name3: String,
+    /// 매도2 영문회원사명4
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME4")]
+    pub seln2_mbcr_eng_name4: String,
+    /// 매도2 영문회원사명5
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME5")]
+    pub seln2_mbcr_eng_name5: String,
+    /// 매수 영문회원사명1
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME1")]
+    pub byov_mbcr_eng_name1: String,
+    /// 매수 영문회원사명2
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME2")]
+    pub byov_mbcr_eng_name2: String,
+    /// 매수 영문회원사명3
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME3")]
+    pub byov_mbcr_eng_name3: String,
+    /// 매수 영문회원사명4
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME4")]
+    pub byov_mbcr_eng_name4: String,
+    /// 매수 영문회원사명5
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME5")]
+    pub byov_mbcr_eng_name5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0unmbc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0unpgm0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 매도 체결량
+    #[serde(default, rename = "SELN_CNQN")]
+    pub seln_cnqn: String,
+    /// 매도 거래 대금
+    #[serde(default, rename = "SELN_TR_PBMN")]
+    pub seln_tr_pbmn: String,
+    /// 매수2 체결량
+    #[serde(default, rename = "SHNU_CNQN")]
+    pub shnu_cnqn: String,
+    /// 매수2 거래 대금
+    #[serde(default, rename = "SHNU_TR_PBMN")]
+    pub shnu_tr_pbmn: String,
+    /// 순매수 체결량
+    #[serde(default, rename = "NTBY_CNQN")]
+    pub ntby_cnqn: String,
+    /// 순매수 거래 대금
+    #[serde(default, rename = "NTBY_TR_PBMN")]
+    pub ntby_tr_pbmn: String,
+    /// 매도호가잔량
+    #[serde(default, rename = "SELN_RSQN")]
+    pub seln_rsqn: String,
+    /// 매수호가잔량
+    #[serde(default, rename = "SHNU_RSQN")]
+    pub shnu_rsqn: String,
+    /// 전체순매수호가잔량
+    #[serde(default, rename = "WHOL_NTBY_QTY")]
+    pub whol_ntby_qty: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0unpgm0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 장운영정보 (통합)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0unmko0Response {
+    /// 거래정지 여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 거래 정지 사유 내용
+    #[serde(default, rename = "TR_SUSP_REAS_CNTT")]
+    pub tr_susp_reas_cntt: String,
+    /// 장운영 구분 코드
+    #[serde(default, rename = "MKOP_CLS_CODE")]
+    pub mkop_cls_code: String,
+    /// 예상 장운영 구분 코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 임의연장구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 동시호가배분처리구분코드
+    #[serde(default, rename = "DIVI_APP_CLS_CODE")]
+    pub divi_app_cls_code: String,
+    /// 종목상태구분코드
+    #[serde(default, rename = "ISCD_STAT_CLS_CODE")]
+    pub iscd_stat_cls_code: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "VI_CLS_CODE")]
+    pub vi_cls_code: String,
+    /// 시간외단일가VI적용구분코드
+    #[serde(default, rename = "OVTM_VI_CLS_CODE")]
+    pub ovtm_vi_cls_code: String,
+    /// 거래소 구분코드
+    #[serde(default, rename = "EXCH_CLS_CODE")]
+    pub exch_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0unmko0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간체결가 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxcnt0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식 현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일 대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중 평균 주식 가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 주식 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식 최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식 최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 체결 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업 일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신 장운영 구분 코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량 회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일 동시간 누적 거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일 동시간 누적 거래량 비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 정적VI발동기준가
+    #[serde(default, rename = "VI_STND_PRC")]
+    pub vi_stnd_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간호가 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxasp0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 시간 구분 코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "ASKP10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "BIDP10")]
+    pub bidp10: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가 잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가 잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가 잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가 잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가 잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가 잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가 잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가 잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가 잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가 잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 시간외 총 매도호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_RSQN")]
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외 총 매수호가 잔량
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_RSQN")]
+    pub ovtm_total_bidp_rsqn: String,
+    /// 예상 체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상 체결량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 예상 거래량
+    #[serde(default, rename = "ANTC_VOL")]
+    pub antc_vol: String,
+    /// 예상 체결 대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상 체결 대비 부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상 체결 전일 대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외 총 매도호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_ASKP_ICDC")]
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외 총 매수호가 증감
+    #[serde(default, rename = "OVTM_TOTAL_BIDP_ICDC")]
+    pub ovtm_total_bidp_icdc: String,
+    /// 주식 매매 구분 코드
+    #[serde(default, rename = "STCK_DEAL_CLS_CODE")]
+    pub stck_deal_cls_code: String,
+    /// KRX 중간가
+    #[serde(default, rename = "KMID_PRC")]
+    pub kmid_prc: String,
+    /// KRX 중간가잔량합계수량
+    #[serde(default, rename = "KMID_TOTAL_RSQN")]
+    pub kmid_total_rsqn: String,
+    /// KRX 중간가 매수매도 구분
+    #[serde(default, rename = "KMID_CLS_CODE")]
+    pub kmid_cls_code: String,
+    /// NXT 중간가
+    #[serde(default, rename = "NMID_PRC")]
+    pub nmid_prc: String,
+    /// NXT 중간가잔량합계수량
+    #[serde(default, rename = "NMID_TOTAL_RSQN")]
+    pub nmid_total_rsqn: String,
+    /// NXT 중간가 매수매도 구분
+    #[serde(default, rename = "NMID_CLS_CODE")]
+    pub nmid_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간예상체결 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxanc0Response {
+    /// 유가증권단축종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비구분
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 등락율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    #[serde(default, rename = "WGHN_AVRG_STCK_PRC")]
+    pub wghn_avrg_stck_prc: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 매도호가
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 체결구분
+    #[serde(default, rename = "CNTG_CLS_CODE")]
+    pub cntg_cls_code: String,
+    /// 매수비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가대비구분
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자
+    #[serde(default, rename = "BSOP_DATE")]
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    #[serde(default, rename = "NEW_MKOP_CLS_CODE")]
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    #[serde(default, rename = "VOL_TNRT")]
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL")]
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    #[serde(default, rename = "PRDY_SMNS_HOUR_ACML_VOL_RATE")]
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간구분코드
+    #[serde(default, rename = "HOUR_CLS_CODE")]
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// VI 상태값
+    #[serde(default, rename = "VI_STND_PRC")]
+    pub vi_stnd_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간회원사 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxmbc0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 매도2 회원사명1
+    #[serde(default, rename = "SELN2_MBCR_NAME1")]
+    pub seln2_mbcr_name1: String,
+    /// 매도2 회원사명2
+    #[serde(default, rename = "SELN2_MBCR_NAME2")]
+    pub seln2_mbcr_name2: String,
+    /// 매도2 회원사명3
+    #[serde(default, rename = "SELN2_MBCR_NAME3")]
+    pub seln2_mbcr_name3: String,
+    /// 매도2 회원사명4
+    #[serde(default, rename = "SELN2_MBCR_NAME4")]
+    pub seln2_mbcr_name4: String,
+    /// 매도2 회원사명5
+    #[serde(default, rename = "SELN2_MBCR_NAME5")]
+    pub seln2_mbcr_name5: String,
+    /// 매수 회원사명1
+    #[serde(default, rename = "BYOV_MBCR_NAME1")]
+    pub byov_mbcr_name1: String,
+    /// 매수 회원사명2
+    #[serde(default, rename = "BYOV_MBCR_NAME2")]
+    pub byov_mbcr_name2: String,
+    /// 매수 회원사명3
+    #[serde(default, rename = "BYOV_MBCR_NAME3")]
+    pub byov_mbcr_name3: String,
+    /// 매수 회원사명4
+    #[serde(default, rename = "BYOV_MBCR_NAME4")]
+    pub byov_mbcr_name4: String,
+    /// 매수 회원사명5
+    #[serde(default, rename = "BYOV_MBCR_NAME5")]
+    pub byov_mbcr_name5: String,
+    /// 총 매도 수량1
+    #[serde(default, rename = "TOTAL_SELN_QTY1")]
+    pub total_seln_qty1: String,
+    /// 총 매도 수량2
+    #[serde(default, rename = "TOTAL_SELN_QTY2")]
+    pub total_seln_qty2: String,
+    /// 총 매도 수량3
+    #[serde(default, rename = "TOTAL_SELN_QTY3")]
+    pub total_seln_qty3: String,
+    /// 총 매도 수량4
+    #[serde(default, rename = "TOTAL_SELN_QTY4")]
+    pub total_seln_qty4: String,
+    /// 총 매도 수량5
+    #[serde(default, rename = "TOTAL_SELN_QTY5")]
+    pub total_seln_qty5: String,
+    /// 총 매수2 수량1
+    #[serde(default, rename = "TOTAL_SHNU_QTY1")]
+    pub total_shnu_qty1: String,
+    /// 총 매수2 수량2
+    #[serde(default, rename = "TOTAL_SHNU_QTY2")]
+    pub total_shnu_qty2: String,
+    /// 총 매수2 수량3
+    #[serde(default, rename = "TOTAL_SHNU_QTY3")]
+    pub total_shnu_qty3: String,
+    /// 총 매수2 수량4
+    #[serde(default, rename = "TOTAL_SHNU_QTY4")]
+    pub total_shnu_qty4: String,
+    /// 총 매수2 수량5
+    #[serde(default, rename = "TOTAL_SHNU_QTY5")]
+    pub total_shnu_qty5: String,
+    /// 매도거래원구분1
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_1")]
+    pub seln_mbcr_glob_yn_1: String,
+    /// 매도거래원구분2
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_2")]
+    pub seln_mbcr_glob_yn_2: String,
+    /// 매도거래원구분3
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_3")]
+    pub seln_mbcr_glob_yn_3: String,
+    /// 매도거래원구분4
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_4")]
+    pub seln_mbcr_glob_yn_4: String,
+    /// 매도거래원구분5
+    #[serde(default, rename = "SELN_MBCR_GLOB_YN_5")]
+    pub seln_mbcr_glob_yn_5: String,
+    /// 매수거래원구분1
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_1")]
+    pub shnu_mbcr_glob_yn_1: String,
+    /// 매수거래원구분2
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_2")]
+    pub shnu_mbcr_glob_yn_2: String,
+    /// 매수거래원구분3
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_3")]
+    pub shnu_mbcr_glob_yn_3: String,
+    /// 매수거래원구분4
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_4")]
+    pub shnu_mbcr_glob_yn_4: String,
+    /// 매수거래원구분5
+    #[serde(default, rename = "SHNU_MBCR_GLOB_YN_5")]
+    pub shnu_mbcr_glob_yn_5: String,
+    /// 매도거래원코드1
+    #[serde(default, rename = "SELN_MBCR_NO1")]
+    pub seln_mbcr_no1: String,
+    /// 매도거래원코드2
+    #[serde(default, rename = "SELN_MBCR_NO2")]
+    pub seln_mbcr_no2: String,
+    /// 매도거래원코드3
+    #[serde(default, rename = "SELN_MBCR_NO3")]
+    pub seln_mbcr_no3: String,
+    /// 매도거래원코드4
+    #[serde(default, rename = "SELN_MBCR_NO4")]
+    pub seln_mbcr_no4: String,
+    /// 매도거래원코드5
+    #[serde(default, rename = "SELN_MBCR_NO5")]
+    pub seln_mbcr_no5: String,
+    /// 매수거래원코드1
+    #[serde(default, rename = "SHNU_MBCR_NO1")]
+    pub shnu_mbcr_no1: String,
+    /// 매수거래원코드2
+    #[serde(default, rename = "SHNU_MBCR_NO2")]
+    pub shnu_mbcr_no2: String,
+    /// 매수거래원코드3
+    #[serde(default, rename = "SHNU_MBCR_NO3")]
+    pub shnu_mbcr_no3: String,
+    /// 매수거래원코드4
+    #[serde(default, rename = "SHNU_MBCR_NO4")]
+    pub shnu_mbcr_no4: String,
+    /// 매수거래원코드5
+    #[serde(default, rename = "SHNU_MBCR_NO5")]
+    pub shnu_mbcr_no5: String,
+    /// 매도 회원사 비중1
+    #[serde(default, rename = "SELN_MBCR_RLIM1")]
+    pub seln_mbcr_rlim1: String,
+    /// 매도 회원사 비중2
+    #[serde(default, rename = "SELN_MBCR_RLIM2")]
+    pub seln_mbcr_rlim2: String,
+    /// 매도 회원사 비중3
+    #[serde(default, rename = "SELN_MBCR_RLIM3")]
+    pub seln_mbcr_rlim3: String,
+    /// 매도 회원사 비중4
+    #[serde(default, rename = "SELN_MBCR_RLIM4")]
+    pub seln_mbcr_rlim4: String,
+    /// 매도 회원사 비중5
+    #[serde(default, rename = "SELN_MBCR_RLIM5")]
+    pub seln_mbcr_rlim5: String,
+    /// 매수2 회원사 비중1
+    #[serde(default, rename = "SHNU_MBCR_RLIM1")]
+    pub shnu_mbcr_rlim1: String,
+    /// 매수2 회원사 비중2
+    #[serde(default, rename = "SHNU_MBCR_RLIM2")]
+    pub shnu_mbcr_rlim2: String,
+    /// 매수2 회원사 비중3
+    #[serde(default, rename = "SHNU_MBCR_RLIM3")]
+    pub shnu_mbcr_rlim3: String,
+    /// 매수2 회원사 비중4
+    #[serde(default, rename = "SHNU_MBCR_RLIM4")]
+    pub shnu_mbcr_rlim4: String,
+    /// 매수2 회원사 비중5
+    #[serde(default, rename = "SHNU_MBCR_RLIM5")]
+    pub shnu_mbcr_rlim5: String,
+    /// 매도 수량 증감1
+    #[serde(default, rename = "SELN_QTY_ICDC1")]
+    pub seln_qty_icdc1: String,
+    /// 매도 수량 증감2
+    #[serde(default, rename = "SELN_QTY_ICDC2")]
+    pub seln_qty_icdc2: String,
+    /// 매도 수량 증감3
+    #[serde(default, rename = "SELN_QTY_ICDC3")]
+    pub seln_qty_icdc3: String,
+    /// 매도 수량 증감4
+    #[serde(default, rename = "SELN_QTY_ICDC4")]
+    pub seln_qty_icdc4: String,
+    /// 매도 수량 증감5
+    #[serde(default, rename = "SELN_QTY_ICDC5")]
+    pub seln_qty_icdc5: String,
+    /// 매수2 수량 증감1
+    #[serde(default, rename = "SHNU_QTY_ICDC1")]
+    pub shnu_qty_icdc1: String,
+    /// 매수2 수량 증감2
+    #[serde(default, rename = "SHNU_QTY_ICDC2")]
+    pub shnu_qty_icdc2: String,
+    /// 매수2 수량 증감3
+    #[serde(default, rename = "SHNU_QTY_ICDC3")]
+    pub shnu_qty_icdc3: String,
+    /// 매수2 수량 증감4
+    #[serde(default, rename = "SHNU_QTY_ICDC4")]
+    pub shnu_qty_icdc4: String,
+    /// 매수2 수량 증감5
+    #[serde(default, rename = "SHNU_QTY_ICDC5")]
+    pub shnu_qty_icdc5: String,
+    /// 외국계 총 매도 수량
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY")]
+    pub glob_total_seln_qty: String,
+    /// 외국계 총 매수2 수량
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY")]
+    pub glob_total_shnu_qty: String,
+    /// 외국계 총 매도 수량 증감
+    #[serde(default, rename = "GLOB_TOTAL_SELN_QTY_ICDC")]
+    pub glob_total_seln_qty_icdc: String,
+    /// 외국계 총 매수2 수량 증감
+    #[serde(default, rename = "GLOB_TOTAL_SHNU_QTY_ICDC")]
+    pub glob_total_shnu_qty_icdc: String,
+    /// 외국계 순매수 수량
+    #[serde(default, rename = "GLOB_NTBY_QTY")]
+    pub glob_ntby_qty: String,
+    /// 외국계 매도 비중
+    #[serde(default, rename = "GLOB_SELN_RLIM")]
+    pub glob_seln_rlim: String,
+    /// 외국계 매수2 비중
+    #[serde(default, rename = "GLOB_SHNU_RLIM")]
+    pub glob_shnu_rlim: String,
+    /// 매도2 영문회원사명1
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME1")]
+    pub seln2_mbcr_eng_name1: String,
+    /// 매도2 영문회원사명2
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME2")]
+    pub seln2_mbcr_eng_name2: String,
+    /// 매도2 영문회원사명3
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME3")]
+    pub seln2_mbcr_eng_name3: String,
+    /// 매도2 영문회원사명4
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME4")]
+    pub seln2_mbcr_eng_name4: String,
+    /// 매도2 영문회원사명5
+    #[serde(default, rename = "SELN2_MBCR_ENG_NAME5")]
+    pub seln2_mbcr_eng_name5: String,
+    /// 매수 영문회원사명1
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME1")]
+    pub byov_mbcr_eng_name1: String,
+    /// 매수 영문회원사명2
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME2")]
+    pub byov_mbcr_eng_name2: String,
+    /// 매수 영문회원사명3
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME3")]
+    pub byov_mbcr_eng_name3: String,
+    /// 매수 영문회원사명4
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME4")]
+    pub byov_mbcr_eng_name4: String,
+    /// 매수 영문회원사명5
+    #[serde(default, rename = "BYOV_MBCR_ENG_NAME5")]
+    pub byov_mbcr_eng_name5: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxmbc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxpgm0Response {
+    /// 유가증권 단축 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 매도 체결량
+    #[serde(default, rename = "SELN_CNQN")]
+    pub seln_cnqn: String,
+    /// 매도 거래 대금
+    #[serde(default, rename = "SELN_TR_PBMN")]
+    pub seln_tr_pbmn: String,
+    /// 매수2 체결량
+    #[serde(default, rename = "SHNU_CNQN")]
+    pub shnu_cnqn: String,
+    /// 매수2 거래 대금
+    #[serde(default, rename = "SHNU_TR_PBMN")]
+    pub shnu_tr_pbmn: String,
+    /// 순매수 체결량
+    #[serde(default, rename = "NTBY_CNQN")]
+    pub ntby_cnqn: String,
+    /// 순매수 거래 대금
+    #[serde(default, rename = "NTBY_TR_PBMN")]
+    pub ntby_tr_pbmn: String,
+    /// 매도호가잔량
+    #[serde(default, rename = "SELN_RSQN")]
+    pub seln_rsqn: String,
+    /// 매수호가잔량
+    #[serde(default, rename = "SHNU_RSQN")]
+    pub shnu_rsqn: String,
+    /// 전체순매수호가잔량
+    #[serde(default, rename = "WHOL_NTBY_QTY")]
+    pub whol_ntby_qty: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxpgm0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [국내주식 장운영정보 (NXT)] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0nxmko0Response {
+    /// 종목코드
+    #[serde(default, rename = "MKSC_SHRN_ISCD")]
+    pub mksc_shrn_iscd: String,
+    /// 거래정지 여부
+    #[serde(default, rename = "TRHT_YN")]
+    pub trht_yn: String,
+    /// 거래 정지 사유 내용
+    #[serde(default, rename = "TR_SUSP_REAS_CNTT")]
+    pub tr_susp_reas_cntt: String,
+    /// 장운영 구분 코드
+    #[serde(default, rename = "MKOP_CLS_CODE")]
+    pub mkop_cls_code: String,
+    /// 예상 장운영 구분 코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 임의연장구분코드
+    #[serde(default, rename = "MRKT_TRTM_CLS_CODE")]
+    pub mrkt_trtm_cls_code: String,
+    /// 동시호가배분처리구분코드
+    #[serde(default, rename = "DIVI_APP_CLS_CODE")]
+    pub divi_app_cls_code: String,
+    /// 종목상태구분코드
+    #[serde(default, rename = "ISCD_STAT_CLS_CODE")]
+    pub iscd_stat_cls_code: String,
+    /// VI적용구분코드
+    #[serde(default, rename = "VI_CLS_CODE")]
+    pub vi_cls_code: String,
+    /// 시간외단일가VI적용구분코드
+    #[serde(default, rename = "OVTM_VI_CLS_CODE")]
+    pub ovtm_vi_cls_code: String,
+    /// 거래소 구분코드
+    #[serde(default, rename = "EXCH_CLS_CODE")]
+    pub exch_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0nxmko0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [지수선물 실시간호가[실시간-011]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ifasp0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매도호가2
+    #[serde(default, rename = "FUTS_ASKP2")]
+    pub futs_askp2: String,
+    /// 선물 매도호가3
+    #[serde(default, rename = "FUTS_ASKP3")]
+    pub futs_askp3: String,
+    /// 선물 매도호가4
+    #[serde(default, rename = "FUTS_ASKP4")]
+    pub futs_askp4: String,
+    /// 선물 매도호가5
+    #[serde(default, rename = "FUTS_ASKP5")]
+    pub futs_askp5: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 선물 매수호가2
+    #[serde(default, rename = "FUTS_BIDP2")]
+    pub futs_bidp2: String,
+    /// 선물 매수호가3
+    #[serde(default, rename = "FUTS_BIDP3")]
+    pub futs_bidp3: String,
+    /// 선물 매수호가4
+    #[serde(default, rename = "FUTS_BIDP4")]
+    pub futs_bidp4: String,
+    /// 선물 매수호가5
+    #[serde(default, rename = "FUTS_BIDP5")]
+    pub futs_bidp5: String,
+    /// 매도호가 건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가 건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가 건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가 건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가 건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가 건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가 건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가 건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가 건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가 건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총 매도호가 건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총 매수호가 건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ifasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [지수선물 실시간체결가[실시간-010]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ifcnt0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "FUTS_PRDY_VRSS")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "FUTS_PRDY_CTRT")]
+    pub futs_prdy_ctrt: String,
+    /// 선물 현재가
+    #[serde(default, rename = "FUTS_PRPR")]
+    pub futs_prpr: String,
+    /// 선물 시가2
+    #[serde(default, rename = "FUTS_OPRC")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "FUTS_HGPR")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "FUTS_LWPR")]
+    pub futs_lwpr: String,
+    /// 최종 거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS 이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 시장 베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 근월물 약정가
+    #[serde(default, rename = "NMSC_FCTN_STPL_PRC")]
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물 약정가
+    #[serde(default, rename = "FMSC_FCTN_STPL_PRC")]
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드1
+    #[serde(default, rename = "SPEAD_PRC")]
+    pub spead_prc: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2 비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제 약정 직전 수량 증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론 베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 협의 대량 거래량
+    #[serde(default, rename = "DSCS_BLTR_ACML_QTY")]
+    pub dscs_bltr_acml_qty: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ifcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [지수옵션 실시간호가[실시간-015]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ioasp0Response {
+    /// 옵션 단축 종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션 매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션 매도호가2
+    #[serde(default, rename = "OPTN_ASKP2")]
+    pub optn_askp2: String,
+    /// 옵션 매도호가3
+    #[serde(default, rename = "OPTN_ASKP3")]
+    pub optn_askp3: String,
+    /// 옵션 매도호가4
+    #[serde(default, rename = "OPTN_ASKP4")]
+    pub optn_askp4: String,
+    /// 옵션 매도호가5
+    #[serde(default, rename = "OPTN_ASKP5")]
+    pub optn_askp5: String,
+    /// 옵션 매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 옵션 매수호가2
+    #[serde(default, rename = "OPTN_BIDP2")]
+    pub optn_bidp2: String,
+    /// 옵션 매수호가3
+    #[serde(default, rename = "OPTN_BIDP3")]
+    pub optn_bidp3: String,
+    /// 옵션 매수호가4
+    #[serde(default, rename = "OPTN_BIDP4")]
+    pub optn_bidp4: String,
+    /// 옵션 매수호가5
+    #[serde(default, rename = "OPTN_BIDP5")]
+    pub optn_bidp5: String,
+    /// 매도호가 건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가 건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가 건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가 건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가 건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가 건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가 건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가 건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가 건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가 건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총 매도호가 건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총 매수호가 건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ioasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [지수옵션  실시간체결가[실시간-014]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0iocnt0Response {
+    /// 옵션 단축 종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션 현재가
+    #[serde(default, rename = "OPTN_PRPR")]
+    pub optn_prpr: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 옵션 전일 대비
+    #[serde(default, rename = "OPTN_PRDY_VRSS")]
+    pub optn_prdy_vrss: String,
+    /// 전일 대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 옵션 시가2
+    #[serde(default, rename = "OPTN_OPRC")]
+    pub optn_oprc: String,
+    /// 옵션 최고가
+    #[serde(default, rename = "OPTN_HGPR")]
+    pub optn_hgpr: String,
+    /// 옵션 최저가
+    #[serde(default, rename = "OPTN_LWPR")]
+    pub optn_lwpr: String,
+    /// 최종 거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS 이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2 비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 프리미엄 값
+    #[serde(default, rename = "PRMM_VAL")]
+    pub prmm_val: String,
+    /// 내재가치 값
+    #[serde(default, rename = "INVL_VAL")]
+    pub invl_val: String,
+    /// 시간가치 값
+    #[serde(default, rename = "TMVL_VAL")]
+    pub tmvl_val: String,
+    /// 델타
+    #[serde(default, rename = "DELTA")]
+    pub delta: String,
+    /// 감마
+    #[serde(default, rename = "GAMA")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "VEGA")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "THETA")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "RHO")]
+    pub rho: String,
+    /// HTS 내재 변동성
+    #[serde(default, rename = "HTS_INTS_VLTL")]
+    pub hts_ints_vltl: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제 약정 직전 수량 증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론 베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 역사적변동성
+    #[serde(default, rename = "UNAS_HIST_VLTL")]
+    pub unas_hist_vltl: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 시장 베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 옵션 매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션 매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 평균 변동성
+    #[serde(default, rename = "AVRG_VLTL")]
+    pub avrg_vltl: String,
+    /// 협의대량누적 거래량
+    #[serde(default, rename = "DSCS_LRQN_VOL")]
+    pub dscs_lrqn_vol: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0iocnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [선물옵션 실시간체결통보[실시간-012]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0ifcni0Response {
+    /// 고객 ID
+    #[serde(default, rename = "CUST_ID")]
+    pub cust_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACNT_NO")]
+    pub acnt_no: String,
+    /// 주문번호
+    #[serde(default, rename = "ODER_NO")]
+    pub oder_no: String,
+    /// 원주문번호
+    #[serde(default, rename = "OODER_NO")]
+    pub ooder_no: String,
+    /// 매도매수구분
+    #[serde(default, rename = "SELN_BYOV_CLS")]
+    pub seln_byov_cls: String,
+    /// 정정구분
+    #[serde(default, rename = "RCTF_CLS")]
+    pub rctf_cls: String,
+    /// 주문종류2
+    #[serde(default, rename = "ODER_KIND2")]
+    pub oder_kind2: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "STCK_SHRN_ISCD")]
+    pub stck_shrn_iscd: String,
+    /// 체결 수량
+    #[serde(default, rename = "CNTG_QTY")]
+    pub cntg_qty: String,
+    /// 체결단가
+    #[serde(default, rename = "CNTG_UNPR")]
+    pub cntg_unpr: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 거부여부
+    #[serde(default, rename = "RFUS_YN")]
+    pub rfus_yn: String,
+    /// 체결여부
+    #[serde(default, rename = "CNTG_YN")]
+    pub cntg_yn: String,
+    /// 접수여부
+    #[serde(default, rename = "ACPT_YN")]
+    pub acpt_yn: String,
+    /// 지점번호
+    #[serde(default, rename = "BRNC_NO")]
+    pub brnc_no: String,
+    /// 주문수량
+    #[serde(default, rename = "ODER_QTY")]
+    pub oder_qty: String,
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 체결종목명
+    #[serde(default, rename = "CNTG_ISNM")]
+    pub cntg_isnm: String,
+    /// 주문조건
+    #[serde(default, rename = "ODER_COND")]
+    pub oder_cond: String,
+    /// 주문그룹ID
+    #[serde(default, rename = "ORD_GRP")]
+    pub ord_grp: String,
+    /// 주문그룹SEQ
+    #[serde(default, rename = "ORD_GRPSEQ")]
+    pub ord_grpseq: String,
+    /// 주문가격
+    #[serde(default, rename = "ORDER_PRC")]
+    pub order_prc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0ifcni0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [상품선물 실시간호가[실시간-023]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0cfasp0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매도호가2
+    #[serde(default, rename = "FUTS_ASKP2")]
+    pub futs_askp2: String,
+    /// 선물 매도호가3
+    #[serde(default, rename = "FUTS_ASKP3")]
+    pub futs_askp3: String,
+    /// 선물 매도호가4
+    #[serde(default, rename = "FUTS_ASKP4")]
+    pub futs_askp4: String,
+    /// 선물 매도호가5
+    #[serde(default, rename = "FUTS_ASKP5")]
+    pub futs_askp5: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 선물 매수호가2
+    #[serde(default, rename = "FUTS_BIDP2")]
+    pub futs_bidp2: String,
+    /// 선물 매수호가3
+    #[serde(default, rename = "FUTS_BIDP3")]
+    pub futs_bidp3: String,
+    /// 선물 매수호가4
+    #[serde(default, rename = "FUTS_BIDP4")]
+    pub futs_bidp4: String,
+    /// 선물 매수호가5
+    #[serde(default, rename = "FUTS_BIDP5")]
+    pub futs_bidp5: String,
+    /// 매도호가 건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가 건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가 건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가 건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가 건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가 건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가 건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가 건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가 건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가 건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총 매도호가 건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총 매수호가 건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0cfasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [상품선물 실시간체결가[실시간-022]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0cfcnt0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "FUTS_PRDY_VRSS")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "FUTS_PRDY_CTRT")]
+    pub futs_prdy_ctrt: String,
+    /// 선물 현재가
+    #[serde(default, rename = "FUTS_PRPR")]
+    pub futs_prpr: String,
+    /// 선물 시가2
+    #[serde(default, rename = "FUTS_OPRC")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "FUTS_HGPR")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "FUTS_LWPR")]
+    pub futs_lwpr: String,
+    /// 최종 거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS 이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 시장 베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 근월물 약정가
+    #[serde(default, rename = "NMSC_FCTN_STPL_PRC")]
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물 약정가
+    #[serde(default, rename = "FMSC_FCTN_STPL_PRC")]
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드1
+    #[serde(default, rename = "SPEAD_PRC")]
+    pub spead_prc: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2 비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제 약정 직전 수량 증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론 베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 협의 대량 거래량
+    #[serde(default, rename = "DSCS_BLTR_ACML_QTY")]
+    pub dscs_bltr_acml_qty: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0cfcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식선물 실시간호가 [실시간-030]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zfasp0Response {
+    /// 선물단축종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매도호가6
+    #[serde(default, rename = "ASKP6")]
+    pub askp6: String,
+    /// 매도호가7
+    #[serde(default, rename = "ASKP7")]
+    pub askp7: String,
+    /// 매도호가8
+    #[serde(default, rename = "ASKP8")]
+    pub askp8: String,
+    /// 매도호가9
+    #[serde(default, rename = "ASKP9")]
+    pub askp9: String,
+    /// 매도호가10
+    #[serde(default, rename = "ASKP10")]
+    pub askp10: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매수호가6
+    #[serde(default, rename = "BIDP6")]
+    pub bidp6: String,
+    /// 매수호가7
+    #[serde(default, rename = "BIDP7")]
+    pub bidp7: String,
+    /// 매수호가8
+    #[serde(default, rename = "BIDP8")]
+    pub bidp8: String,
+    /// 매수호가9
+    #[serde(default, rename = "BIDP9")]
+    pub bidp9: String,
+    /// 매수호가10
+    #[serde(default, rename = "BIDP10")]
+    pub bidp10: String,
+    /// 매도호가건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매도호가건수6
+    #[serde(default, rename = "ASKP_CSNU6")]
+    pub askp_csnu6: String,
+    /// 매도호가건수7
+    #[serde(default, rename = "ASKP_CSNU7")]
+    pub askp_csnu7: String,
+    /// 매도호가건수8
+    #[serde(default, rename = "ASKP_CSNU8")]
+    pub askp_csnu8: String,
+    /// 매도호가건수9
+    #[serde(default, rename = "ASKP_CSNU9")]
+    pub askp_csnu9: String,
+    /// 매도호가건수10
+    #[serde(default, rename = "ASKP_CSNU10")]
+    pub askp_csnu10: String,
+    /// 매수호가건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매수호가건수6
+    #[serde(default, rename = "BIDP_CSNU6")]
+    pub bidp_csnu6: String,
+    /// 매수호가건수7
+    #[serde(default, rename = "BIDP_CSNU7")]
+    pub bidp_csnu7: String,
+    /// 매수호가건수8
+    #[serde(default, rename = "BIDP_CSNU8")]
+    pub bidp_csnu8: String,
+    /// 매수호가건수9
+    #[serde(default, rename = "BIDP_CSNU9")]
+    pub bidp_csnu9: String,
+    /// 매수호가건수10
+    #[serde(default, rename = "BIDP_CSNU10")]
+    pub bidp_csnu10: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매도호가잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 매수호가잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 총매도호가건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총매수호가건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총매도호가잔량증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zfasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식선물 실시간체결가 [실시간-029]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zfcnt0Response {
+    /// 선물단축종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 주식현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 선물전일대비율
+    #[serde(default, rename = "FUTS_PRDY_CTRT")]
+    pub futs_prdy_ctrt: String,
+    /// 주식시가2
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 주식최고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 주식최저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 최종거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 시장베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 근월물약정가
+    #[serde(default, rename = "NMSC_FCTN_STPL_PRC")]
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물약정가
+    #[serde(default, rename = "FMSC_FCTN_STPL_PRC")]
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드1
+    #[serde(default, rename = "SPEAD_PRC")]
+    pub spead_prc: String,
+    /// HTS미결제약정수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가2대비현재가
+    #[serde(default, rename = "OPRC_VRSS_PRPR")]
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비현재가
+    #[serde(default, rename = "HGPR_VRSS_PRPR")]
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비현재가
+    #[serde(default, rename = "LWPR_VRSS_PRPR")]
+    pub lwpr_vrss_prpr: String,
+    /// 매수2비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zfcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식선물 실시간예상체결 [실시간-031]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zfanc0Response {
+    /// 선물단축종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 예상체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상체결대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 예상장운영구분코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 예상체결수량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zfanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식옵션 실시간호가 [실시간-045]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zoasp0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션매도호가2
+    #[serde(default, rename = "OPTN_ASKP2")]
+    pub optn_askp2: String,
+    /// 옵션매도호가3
+    #[serde(default, rename = "OPTN_ASKP3")]
+    pub optn_askp3: String,
+    /// 옵션매도호가4
+    #[serde(default, rename = "OPTN_ASKP4")]
+    pub optn_askp4: String,
+    /// 옵션매도호가5
+    #[serde(default, rename = "OPTN_ASKP5")]
+    pub optn_askp5: String,
+    /// 옵션매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 옵션매수호가2
+    #[serde(default, rename = "OPTN_BIDP2")]
+    pub optn_bidp2: String,
+    /// 옵션매수호가3
+    #[serde(default, rename = "OPTN_BIDP3")]
+    pub optn_bidp3: String,
+    /// 옵션매수호가4
+    #[serde(default, rename = "OPTN_BIDP4")]
+    pub optn_bidp4: String,
+    /// 옵션매수호가5
+    #[serde(default, rename = "OPTN_BIDP5")]
+    pub optn_bidp5: String,
+    /// 매도호가건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총매도호가건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총매수호가건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총매도호가잔량증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 옵션매도호가6
+    #[serde(default, rename = "OPTN_ASKP6")]
+    pub optn_askp6: String,
+    /// 옵션매도호가7
+    #[serde(default, rename = "OPTN_ASKP7")]
+    pub optn_askp7: String,
+    /// 옵션매도호가8
+    #[serde(default, rename = "OPTN_ASKP8")]
+    pub optn_askp8: String,
+    /// 옵션매도호가9
+    #[serde(default, rename = "OPTN_ASKP9")]
+    pub optn_askp9: String,
+    /// 옵션매도호가10
+    #[serde(default, rename = "OPTN_ASKP10")]
+    pub optn_askp10: String,
+    /// 옵션매수호가6
+    #[serde(default, rename = "OPTN_BIDP6")]
+    pub optn_bidp6: String,
+    /// 옵션매수호가7
+    #[serde(default, rename = "OPTN_BIDP7")]
+    pub optn_bidp7: String,
+    /// 옵션매수호가8
+    #[serde(default, rename = "OPTN_BIDP8")]
+    pub optn_bidp8: String,
+    /// 옵션매수호가9
+    #[serde(default, rename = "OPTN_BIDP9")]
+    pub optn_bidp9: String,
+    /// 옵션매수호가10
+    #[serde(default, rename = "OPTN_BIDP10")]
+    pub optn_bidp10: String,
+    /// 매도호가건수6
+    #[serde(default, rename = "ASKP_CSNU6")]
+    pub askp_csnu6: String,
+    /// 매도호가건수7
+    #[serde(default, rename = "ASKP_CSNU7")]
+    pub askp_csnu7: String,
+    /// 매도호가건수8
+    #[serde(default, rename = "ASKP_CSNU8")]
+    pub askp_csnu8: String,
+    /// 매도호가건수9
+    #[serde(default, rename = "ASKP_CSNU9")]
+    pub askp_csnu9: String,
+    /// 매도호가건수10
+    #[serde(default, rename = "ASKP_CSNU10")]
+    pub askp_csnu10: String,
+    /// 매수호가건수6
+    #[serde(default, rename = "BIDP_CSNU6")]
+    pub bidp_csnu6: String,
+    /// 매수호가건수7
+    #[serde(default, rename = "BIDP_CSNU7")]
+    pub bidp_csnu7: String,
+    /// 매수호가건수8
+    #[serde(default, rename = "BIDP_CSNU8")]
+    pub bidp_csnu8: String,
+    /// 매수호가건수9
+    #[serde(default, rename = "BIDP_CSNU9")]
+    pub bidp_csnu9: String,
+    /// 매수호가건수10
+    #[serde(default, rename = "BIDP_CSNU10")]
+    pub bidp_csnu10: String,
+    /// 매도호가잔량6
+    #[serde(default, rename = "ASKP_RSQN6")]
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    #[serde(default, rename = "ASKP_RSQN7")]
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    #[serde(default, rename = "ASKP_RSQN8")]
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    #[serde(default, rename = "ASKP_RSQN9")]
+    pub askp_rsqn9: String,
+    /// 매도호가잔량10
+    #[serde(default, rename = "ASKP_RSQN10")]
+    pub askp_rsqn10: String,
+    /// 매수호가잔량6
+    #[serde(default, rename = "BIDP_RSQN6")]
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    #[serde(default, rename = "BIDP_RSQN7")]
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    #[serde(default, rename = "BIDP_RSQN8")]
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    #[serde(default, rename = "BIDP_RSQN9")]
+    pub bidp_rsqn9: String,
+    /// 매수호가잔량10
+    #[serde(default, rename = "BIDP_RSQN10")]
+    pub bidp_rsqn10: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zoasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식옵션 실시간체결가 [실시간-044]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zocnt0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션현재가
+    #[serde(default, rename = "OPTN_PRPR")]
+    pub optn_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 옵션전일대비
+    #[serde(default, rename = "OPTN_PRDY_VRSS")]
+    pub optn_prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 옵션시가2
+    #[serde(default, rename = "OPTN_OPRC")]
+    pub optn_oprc: String,
+    /// 옵션최고가
+    #[serde(default, rename = "OPTN_HGPR")]
+    pub optn_hgpr: String,
+    /// 옵션최저가
+    #[serde(default, rename = "OPTN_LWPR")]
+    pub optn_lwpr: String,
+    /// 최종거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// HTS미결제약정수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비지수현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비지수현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비지수현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 프리미엄값
+    #[serde(default, rename = "PRMM_VAL")]
+    pub prmm_val: String,
+    /// 내재가치값
+    #[serde(default, rename = "INVL_VAL")]
+    pub invl_val: String,
+    /// 시간가치값
+    #[serde(default, rename = "TMVL_VAL")]
+    pub tmvl_val: String,
+    /// 델타
+    #[serde(default, rename = "DELTA")]
+    pub delta: String,
+    /// 감마
+    #[serde(default, rename = "GAMA")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "VEGA")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "THETA")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "RHO")]
+    pub rho: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "HTS_INTS_VLTL")]
+    pub hts_ints_vltl: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 역사적변동성
+    #[serde(default, rename = "UNAS_HIST_VLTL")]
+    pub unas_hist_vltl: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 시장베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 옵션매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zocnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [주식옵션 실시간예상체결 [실시간-046]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0zoanc0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 예상체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상체결대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 예상장운영구분코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0zoanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간옵션 실시간호가 [실시간-033]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0euasp0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션매도호가2
+    #[serde(default, rename = "OPTN_ASKP2")]
+    pub optn_askp2: String,
+    /// 옵션매도호가3
+    #[serde(default, rename = "OPTN_ASKP3")]
+    pub optn_askp3: String,
+    /// 옵션매도호가4
+    #[serde(default, rename = "OPTN_ASKP4")]
+    pub optn_askp4: String,
+    /// 옵션매도호가5
+    #[serde(default, rename = "OPTN_ASKP5")]
+    pub optn_askp5: String,
+    /// 옵션매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 옵션매수호가2
+    #[serde(default, rename = "OPTN_BIDP2")]
+    pub optn_bidp2: String,
+    /// 옵션매수호가3
+    #[serde(default, rename = "OPTN_BIDP3")]
+    pub optn_bidp3: String,
+    /// 옵션매수호가4
+    #[serde(default, rename = "OPTN_BIDP4")]
+    pub optn_bidp4: String,
+    /// 옵션매수호가5
+    #[serde(default, rename = "OPTN_BIDP5")]
+    pub optn_bidp5: String,
+    /// 매도호가건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총매도호가건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총매수호가건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총매도호가잔량증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0euasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간옵션 실시간체결가 [실시간-032]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0eucnt0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 옵션현재가
+    #[serde(default, rename = "OPTN_PRPR")]
+    pub optn_prpr: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 옵션전일대비
+    #[serde(default, rename = "OPTN_PRDY_VRSS")]
+    pub optn_prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 옵션시가2
+    #[serde(default, rename = "OPTN_OPRC")]
+    pub optn_oprc: String,
+    /// 옵션최고가
+    #[serde(default, rename = "OPTN_HGPR")]
+    pub optn_hgpr: String,
+    /// 옵션최저가
+    #[serde(default, rename = "OPTN_LWPR")]
+    pub optn_lwpr: String,
+    /// 최종거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적거래대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// HTS미결제약정수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2대비현재가부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비지수현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비지수현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비지수현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 프리미엄값
+    #[serde(default, rename = "PRMM_VAL")]
+    pub prmm_val: String,
+    /// 내재가치값
+    #[serde(default, rename = "INVL_VAL")]
+    pub invl_val: String,
+    /// 시간가치값
+    #[serde(default, rename = "TMVL_VAL")]
+    pub tmvl_val: String,
+    /// 델타
+    #[serde(default, rename = "DELTA")]
+    pub delta: String,
+    /// 감마
+    #[serde(default, rename = "GAMA")]
+    pub gama: String,
+    /// 베가
+    #[serde(default, rename = "VEGA")]
+    pub vega: String,
+    /// 세타
+    #[serde(default, rename = "THETA")]
+    pub theta: String,
+    /// 로우
+    #[serde(default, rename = "RHO")]
+    pub rho: String,
+    /// HTS내재변동성
+    #[serde(default, rename = "HTS_INTS_VLTL")]
+    pub hts_ints_vltl: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 역사적변동성
+    #[serde(default, rename = "UNAS_HIST_VLTL")]
+    pub unas_hist_vltl: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 시장베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 옵션매도호가1
+    #[serde(default, rename = "OPTN_ASKP1")]
+    pub optn_askp1: String,
+    /// 옵션매수호가1
+    #[serde(default, rename = "OPTN_BIDP1")]
+    pub optn_bidp1: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0eucnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간옵션실시간예상체결 [실시간-034]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0euanc0Response {
+    /// 옵션단축종목코드
+    #[serde(default, rename = "OPTN_SHRN_ISCD")]
+    pub optn_shrn_iscd: String,
+    /// 영업시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 예상체결가
+    #[serde(default, rename = "ANTC_CNPR")]
+    pub antc_cnpr: String,
+    /// 예상체결대비
+    #[serde(default, rename = "ANTC_CNTG_VRSS")]
+    pub antc_cntg_vrss: String,
+    /// 예상체결대비부호
+    #[serde(default, rename = "ANTC_CNTG_VRSS_SIGN")]
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결전일대비율
+    #[serde(default, rename = "ANTC_CNTG_PRDY_CTRT")]
+    pub antc_cntg_prdy_ctrt: String,
+    /// 예상장운영구분코드
+    #[serde(default, rename = "ANTC_MKOP_CLS_CODE")]
+    pub antc_mkop_cls_code: String,
+    /// 예상체결수량
+    #[serde(default, rename = "ANTC_CNQN")]
+    pub antc_cnqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0euanc0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간옵션실시간체결통보 [실시간-067]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0eucni0Response {
+    /// 고객 ID
+    #[serde(default, rename = "CUST_ID")]
+    pub cust_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACNT_NO")]
+    pub acnt_no: String,
+    /// 주문번호
+    #[serde(default, rename = "ODER_NO")]
+    pub oder_no: String,
+    /// 원주문번호
+    #[serde(default, rename = "OODER_NO")]
+    pub ooder_no: String,
+    /// 매도매수구분
+    #[serde(default, rename = "SELN_BYOV_CLS")]
+    pub seln_byov_cls: String,
+    /// 정정구분
+    #[serde(default, rename = "RCTF_CLS")]
+    pub rctf_cls: String,
+    /// 주문종류2
+    #[serde(default, rename = "ODER_KIND2")]
+    pub oder_kind2: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "STCK_SHRN_ISCD")]
+    pub stck_shrn_iscd: String,
+    /// 체결 수량
+    #[serde(default, rename = "CNTG_QTY")]
+    pub cntg_qty: String,
+    /// 체결단가
+    #[serde(default, rename = "CNTG_UNPR")]
+    pub cntg_unpr: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 거부여부
+    #[serde(default, rename = "RFUS_YN")]
+    pub rfus_yn: String,
+    /// 체결여부
+    #[serde(default, rename = "CNTG_YN")]
+    pub cntg_yn: String,
+    /// 접수여부
+    #[serde(default, rename = "ACPT_YN")]
+    pub acpt_yn: String,
+    /// 지점번호
+    #[serde(default, rename = "BRNC_NO")]
+    pub brnc_no: String,
+    /// 주문수량
+    #[serde(default, rename = "ODER_QTY")]
+    pub oder_qty: String,
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 체결종목명
+    #[serde(default, rename = "CNTG_ISNM")]
+    pub cntg_isnm: String,
+    /// 주문조건
+    #[serde(default, rename = "ODER_COND")]
+    pub oder_cond: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0eucni0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간선물 실시간호가 [실시간-065]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0mfasp0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매도호가2
+    #[serde(default, rename = "FUTS_ASKP2")]
+    pub futs_askp2: String,
+    /// 선물 매도호가3
+    #[serde(default, rename = "FUTS_ASKP3")]
+    pub futs_askp3: String,
+    /// 선물 매도호가4
+    #[serde(default, rename = "FUTS_ASKP4")]
+    pub futs_askp4: String,
+    /// 선물 매도호가5
+    #[serde(default, rename = "FUTS_ASKP5")]
+    pub futs_askp5: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 선물 매수호가2
+    #[serde(default, rename = "FUTS_BIDP2")]
+    pub futs_bidp2: String,
+    /// 선물 매수호가3
+    #[serde(default, rename = "FUTS_BIDP3")]
+    pub futs_bidp3: String,
+    /// 선물 매수호가4
+    #[serde(default, rename = "FUTS_BIDP4")]
+    pub futs_bidp4: String,
+    /// 선물 매수호가5
+    #[serde(default, rename = "FUTS_BIDP5")]
+    pub futs_bidp5: String,
+    /// 매도호가 건수1
+    #[serde(default, rename = "ASKP_CSNU1")]
+    pub askp_csnu1: String,
+    /// 매도호가 건수2
+    #[serde(default, rename = "ASKP_CSNU2")]
+    pub askp_csnu2: String,
+    /// 매도호가 건수3
+    #[serde(default, rename = "ASKP_CSNU3")]
+    pub askp_csnu3: String,
+    /// 매도호가 건수4
+    #[serde(default, rename = "ASKP_CSNU4")]
+    pub askp_csnu4: String,
+    /// 매도호가 건수5
+    #[serde(default, rename = "ASKP_CSNU5")]
+    pub askp_csnu5: String,
+    /// 매수호가 건수1
+    #[serde(default, rename = "BIDP_CSNU1")]
+    pub bidp_csnu1: String,
+    /// 매수호가 건수2
+    #[serde(default, rename = "BIDP_CSNU2")]
+    pub bidp_csnu2: String,
+    /// 매수호가 건수3
+    #[serde(default, rename = "BIDP_CSNU3")]
+    pub bidp_csnu3: String,
+    /// 매수호가 건수4
+    #[serde(default, rename = "BIDP_CSNU4")]
+    pub bidp_csnu4: String,
+    /// 매수호가 건수5
+    #[serde(default, rename = "BIDP_CSNU5")]
+    pub bidp_csnu5: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매도호가 잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매도호가 잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매도호가 잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매도호가 잔량5
+    #[serde(default, rename = "ASKP_RSQN5")]
+    pub askp_rsqn5: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매수호가 잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매수호가 잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매수호가 잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매수호가 잔량5
+    #[serde(default, rename = "BIDP_RSQN5")]
+    pub bidp_rsqn5: String,
+    /// 총 매도호가 건수
+    #[serde(default, rename = "TOTAL_ASKP_CSNU")]
+    pub total_askp_csnu: String,
+    /// 총 매수호가 건수
+    #[serde(default, rename = "TOTAL_BIDP_CSNU")]
+    pub total_bidp_csnu: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 총 매도호가 잔량 증감
+    #[serde(default, rename = "TOTAL_ASKP_RSQN_ICDC")]
+    pub total_askp_rsqn_icdc: String,
+    /// 총 매수호가 잔량 증감
+    #[serde(default, rename = "TOTAL_BIDP_RSQN_ICDC")]
+    pub total_bidp_rsqn_icdc: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0mfasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간선물 실시간종목체결 [실시간-064]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0mfcnt0Response {
+    /// 선물 단축 종목코드
+    #[serde(default, rename = "FUTS_SHRN_ISCD")]
+    pub futs_shrn_iscd: String,
+    /// 영업 시간
+    #[serde(default, rename = "BSOP_HOUR")]
+    pub bsop_hour: String,
+    /// 선물 전일 대비
+    #[serde(default, rename = "FUTS_PRDY_VRSS")]
+    pub futs_prdy_vrss: String,
+    /// 전일 대비 부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 선물 전일 대비율
+    #[serde(default, rename = "FUTS_PRDY_CTRT")]
+    pub futs_prdy_ctrt: String,
+    /// 선물 현재가
+    #[serde(default, rename = "FUTS_PRPR")]
+    pub futs_prpr: String,
+    /// 선물 시가2
+    #[serde(default, rename = "FUTS_OPRC")]
+    pub futs_oprc: String,
+    /// 선물 최고가
+    #[serde(default, rename = "FUTS_HGPR")]
+    pub futs_hgpr: String,
+    /// 선물 최저가
+    #[serde(default, rename = "FUTS_LWPR")]
+    pub futs_lwpr: String,
+    /// 최종 거래량
+    #[serde(default, rename = "LAST_CNQN")]
+    pub last_cnqn: String,
+    /// 누적 거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 누적 거래 대금
+    #[serde(default, rename = "ACML_TR_PBMN")]
+    pub acml_tr_pbmn: String,
+    /// HTS 이론가
+    #[serde(default, rename = "HTS_THPR")]
+    pub hts_thpr: String,
+    /// 시장 베이시스
+    #[serde(default, rename = "MRKT_BASIS")]
+    pub mrkt_basis: String,
+    /// 괴리율
+    #[serde(default, rename = "DPRT")]
+    pub dprt: String,
+    /// 근월물 약정가
+    #[serde(default, rename = "NMSC_FCTN_STPL_PRC")]
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물 약정가
+    #[serde(default, rename = "FMSC_FCTN_STPL_PRC")]
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드1
+    #[serde(default, rename = "SPEAD_PRC")]
+    pub spead_prc: String,
+    /// HTS 미결제 약정 수량
+    #[serde(default, rename = "HTS_OTST_STPL_QTY")]
+    pub hts_otst_stpl_qty: String,
+    /// 미결제 약정 수량 증감
+    #[serde(default, rename = "OTST_STPL_QTY_ICDC")]
+    pub otst_stpl_qty_icdc: String,
+    /// 시가 시간
+    #[serde(default, rename = "OPRC_HOUR")]
+    pub oprc_hour: String,
+    /// 시가2 대비 현재가 부호
+    #[serde(default, rename = "OPRC_VRSS_PRPR_SIGN")]
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가 대비 지수 현재가
+    #[serde(default, rename = "OPRC_VRSS_NMIX_PRPR")]
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가 시간
+    #[serde(default, rename = "HGPR_HOUR")]
+    pub hgpr_hour: String,
+    /// 최고가 대비 현재가 부호
+    #[serde(default, rename = "HGPR_VRSS_PRPR_SIGN")]
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가 대비 지수 현재가
+    #[serde(default, rename = "HGPR_VRSS_NMIX_PRPR")]
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가 시간
+    #[serde(default, rename = "LWPR_HOUR")]
+    pub lwpr_hour: String,
+    /// 최저가 대비 현재가 부호
+    #[serde(default, rename = "LWPR_VRSS_PRPR_SIGN")]
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가 대비 지수 현재가
+    #[serde(default, rename = "LWPR_VRSS_NMIX_PRPR")]
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수2 비율
+    #[serde(default, rename = "SHNU_RATE")]
+    pub shnu_rate: String,
+    /// 체결강도
+    #[serde(default, rename = "CTTR")]
+    pub cttr: String,
+    /// 괴리도
+    #[serde(default, rename = "ESDG")]
+    pub esdg: String,
+    /// 미결제 약정 직전 수량 증감
+    #[serde(default, rename = "OTST_STPL_RGBF_QTY_ICDC")]
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론 베이시스
+    #[serde(default, rename = "THPR_BASIS")]
+    pub thpr_basis: String,
+    /// 선물 매도호가1
+    #[serde(default, rename = "FUTS_ASKP1")]
+    pub futs_askp1: String,
+    /// 선물 매수호가1
+    #[serde(default, rename = "FUTS_BIDP1")]
+    pub futs_bidp1: String,
+    /// 매도호가 잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가 잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도 체결 건수
+    #[serde(default, rename = "SELN_CNTG_CSNU")]
+    pub seln_cntg_csnu: String,
+    /// 매수 체결 건수
+    #[serde(default, rename = "SHNU_CNTG_CSNU")]
+    pub shnu_cntg_csnu: String,
+    /// 순매수 체결 건수
+    #[serde(default, rename = "NTBY_CNTG_CSNU")]
+    pub ntby_cntg_csnu: String,
+    /// 총 매도 수량
+    #[serde(default, rename = "SELN_CNTG_SMTN")]
+    pub seln_cntg_smtn: String,
+    /// 총 매수 수량
+    #[serde(default, rename = "SHNU_CNTG_SMTN")]
+    pub shnu_cntg_smtn: String,
+    /// 총 매도호가 잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총 매수호가 잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 전일 거래량 대비 등락율
+    #[serde(default, rename = "PRDY_VOL_VRSS_ACML_VOL_RATE")]
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 실시간상한가
+    #[serde(default, rename = "DYNM_MXPR")]
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    #[serde(default, rename = "DYNM_LLAM")]
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    #[serde(default, rename = "DYNM_PRC_LIMT_YN")]
+    pub dynm_prc_limt_yn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0mfcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [KRX야간선물 실시간체결통보 [실시간-066]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0mfcni0Response {
+    /// 고객 ID
+    #[serde(default, rename = "CUST_ID")]
+    pub cust_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACNT_NO")]
+    pub acnt_no: String,
+    /// 주문번호
+    #[serde(default, rename = "ODER_NO")]
+    pub oder_no: String,
+    /// 원주문번호
+    #[serde(default, rename = "OODER_NO")]
+    pub ooder_no: String,
+    /// 매도매수구분
+    #[serde(default, rename = "SELN_BYOV_CLS")]
+    pub seln_byov_cls: String,
+    /// 정정구분
+    #[serde(default, rename = "RCTF_CLS")]
+    pub rctf_cls: String,
+    /// 주문종류2
+    #[serde(default, rename = "ODER_KIND2")]
+    pub oder_kind2: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "STCK_SHRN_ISCD")]
+    pub stck_shrn_iscd: String,
+    /// 체결 수량
+    #[serde(default, rename = "CNTG_QTY")]
+    pub cntg_qty: String,
+    /// 체결단가
+    #[serde(default, rename = "CNTG_UNPR")]
+    pub cntg_unpr: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 거부여부
+    #[serde(default, rename = "RFUS_YN")]
+    pub rfus_yn: String,
+    /// 체결여부
+    #[serde(default, rename = "CNTG_YN")]
+    pub cntg_yn: String,
+    /// 접수여부
+    #[serde(default, rename = "ACPT_YN")]
+    pub acpt_yn: String,
+    /// 지점번호
+    #[serde(default, rename = "BRNC_NO")]
+    pub brnc_no: String,
+    /// 주문수량
+    #[serde(default, rename = "ODER_QTY")]
+    pub oder_qty: String,
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 체결종목명
+    #[serde(default, rename = "CNTG_ISNM")]
+    pub cntg_isnm: String,
+    /// 주문조건
+    #[serde(default, rename = "ODER_COND")]
+    pub oder_cond: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0mfcni0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외주식 실시간호가[실시간-021]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfsasp0Response {
+    /// 실시간종목코드
+    #[serde(default, rename = "RSYM")]
+    pub rsym: String,
+    /// 종목코드
+    #[serde(default, rename = "SYMB")]
+    pub symb: String,
+    /// 소숫점자리수
+    #[serde(default, rename = "ZDIV")]
+    pub zdiv: String,
+    /// 현지일자
+    #[serde(default, rename = "XYMD")]
+    pub xymd: String,
+    /// 현지시간
+    #[serde(default, rename = "XHMS")]
+    pub xhms: String,
+    /// 한국일자
+    #[serde(default, rename = "KYMD")]
+    pub kymd: String,
+    /// 한국시간
+    #[serde(default, rename = "KHMS")]
+    pub khms: String,
+    /// 매수총잔량
+    #[serde(default, rename = "BVOL")]
+    pub bvol: String,
+    /// 매도총잔량
+    #[serde(default, rename = "AVOL")]
+    pub avol: String,
+    /// 매수총잔량대비
+    #[serde(default, rename = "BDVL")]
+    pub bdvl: String,
+    /// 매도총잔량대비
+    #[serde(default, rename = "ADVL")]
+    pub advl: String,
+    /// 매수호가1
+    #[serde(default, rename = "PBID1")]
+    pub pbid1: String,
+    /// 매도호가1
+    #[serde(default, rename = "PASK1")]
+    pub pask1: String,
+    /// 매수잔량1
+    #[serde(default, rename = "VBID1")]
+    pub vbid1: String,
+    /// 매도잔량1
+    #[serde(default, rename = "VASK1")]
+    pub vask1: String,
+    /// 매수잔량대비1
+    #[serde(default, rename = "DBID1")]
+    pub dbid1: String,
+    /// 매도잔량대비1
+    #[serde(default, rename = "DASK1")]
+    pub dask1: String,
+    /// 매수호가2
+    #[serde(default, rename = "PBID2")]
+    pub pbid2: String,
+    /// 매도호가2
+    #[serde(default, rename = "PASK2")]
+    pub pask2: String,
+    /// 매수잔량2
+    #[serde(default, rename = "VBID2")]
+    pub vbid2: String,
+    /// 매도잔량2
+    #[serde(default, rename = "VASK2")]
+    pub vask2: String,
+    /// 매수잔량대비2
+    #[serde(default, rename = "DBID2")]
+    pub dbid2: String,
+    /// 매도잔량대비2
+    #[serde(default, rename = "DASK2")]
+    pub dask2: String,
+    /// 매수호가3
+    #[serde(default, rename = "PBID3")]
+    pub pbid3: String,
+    /// 매도호가3
+    #[serde(default, rename = "PASK3")]
+    pub pask3: String,
+    /// 매수잔량3
+    #[serde(default, rename = "VBID3")]
+    pub vbid3: String,
+    /// 매도잔량3
+    #[serde(default, rename = "VASK3")]
+    pub vask3: String,
+    /// 매수잔량대비3
+    #[serde(default, rename = "DBID3")]
+    pub dbid3: String,
+    /// 매도잔량대비3
+    #[serde(default, rename = "DASK3")]
+    pub dask3: String,
+    /// 매수호가4
+    #[serde(default, rename = "PBID4")]
+    pub pbid4: String,
+    /// 매도호가4
+    #[serde(default, rename = "PASK4")]
+    pub pask4: String,
+    /// 매수잔량4
+    #[serde(default, rename = "VBID4")]
+    pub vbid4: String,
+    /// 매도잔량4
+    #[serde(default, rename = "VASK4")]
+    pub vask4: String,
+    /// 매수잔량대비4
+    #[serde(default, rename = "DBID4")]
+    pub dbid4: String,
+    /// 매도잔량대비4
+    #[serde(default, rename = "DASK4")]
+    pub dask4: String,
+    /// 매수호가5
+    #[serde(default, rename = "PBID5")]
+    pub pbid5: String,
+    /// 매도호가5
+    #[serde(default, rename = "PASK5")]
+    pub pask5: String,
+    /// 매수잔량5
+    #[serde(default, rename = "VBID5")]
+    pub vbid5: String,
+    /// 매도잔량5
+    #[serde(default, rename = "VASK5")]
+    pub vask5: String,
+    /// 매수잔량대비5
+    #[serde(default, rename = "DBID5")]
+    pub dbid5: String,
+    /// 매도잔량대비5
+    #[serde(default, rename = "DASK5")]
+    pub dask5: String,
+    /// 매수호가6
+    #[serde(default, rename = "PBID6")]
+    pub pbid6: String,
+    /// 매도호가6
+    #[serde(default, rename = "PASK6")]
+    pub pask6: String,
+    /// 매수잔량6
+    #[serde(default, rename = "VBID6")]
+    pub vbid6: String,
+    /// 매도잔량6
+    #[serde(default, rename = "VASK6")]
+    pub vask6: String,
+    /// 매수잔량대비6
+    #[serde(default, rename = "DBID6")]
+    pub dbid6: String,
+    /// 매도잔량대비6
+    #[serde(default, rename = "DASK6")]
+    pub dask6: String,
+    /// 매수호가7
+    #[serde(default, rename = "PBID7")]
+    pub pbid7: String,
+    /// 매도호가7
+    #[serde(default, rename = "PASK7")]
+    pub pask7: String,
+    /// 매수잔량7
+    #[serde(default, rename = "VBID7")]
+    pub vbid7: String,
+    /// 매도잔량7
+    #[serde(default, rename = "VASK7")]
+    pub vask7: String,
+    /// 매수잔량대비7
+    #[serde(default, rename = "DBID7")]
+    pub dbid7: String,
+    /// 매도잔량대비7
+    #[serde(default, rename = "DASK7")]
+    pub dask7: String,
+    /// 매수호가8
+    #[serde(default, rename = "PBID8")]
+    pub pbid8: String,
+    /// 매도호가8
+    #[serde(default, rename = "PASK8")]
+    pub pask8: String,
+    /// 매수잔량8
+    #[serde(default, rename = "VBID8")]
+    pub vbid8: String,
+    /// 매도잔량8
+    #[serde(default, rename = "VASK8")]
+    pub vask8: String,
+    /// 매수잔량대비8
+    #[serde(default, rename = "DBID8")]
+    pub dbid8: String,
+    /// 매도잔량대비8
+    #[serde(default, rename = "DASK8")]
+    pub dask8: String,
+    /// 매수호가9
+    #[serde(default, rename = "PBID9")]
+    pub pbid9: String,
+    /// 매도호가9
+    #[serde(default, rename = "PASK9")]
+    pub pask9: String,
+    /// 매수잔량9
+    #[serde(default, rename = "VBID9")]
+    pub vbid9: String,
+    /// 매도잔량9
+    #[serde(default, rename = "VASK9")]
+    pub vask9: String,
+    /// 매수잔량대비9
+    #[serde(default, rename = "DBID9")]
+    pub dbid9: String,
+    /// 매도잔량대비9
+    #[serde(default, rename = "DASK9")]
+    pub dask9: String,
+    /// 매수호가10
+    #[serde(default, rename = "PBID10")]
+    pub pbid10: String,
+    /// 매도호가10
+    #[serde(default, rename = "PASK10")]
+    pub pask10: String,
+    /// 매수잔량10
+    #[serde(default, rename = "VBID10")]
+    pub vbid10: String,
+    /// 매도잔량10
+    #[serde(default, rename = "VASK10")]
+    pub vask10: String,
+    /// 매수잔량대비10
+    #[serde(default, rename = "DBID10")]
+    pub dbid10: String,
+    /// 매도잔량대비10
+    #[serde(default, rename = "DASK10")]
+    pub dask10: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfsasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외주식 지연호가(아시아)[실시간-008]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfsasp1Response {
+    /// 실시간종목코드
+    #[serde(default, rename = "RSYM")]
+    pub rsym: String,
+    /// 종목코드
+    #[serde(default, rename = "SYMB")]
+    pub symb: String,
+    /// 소수점자리수
+    #[serde(default, rename = "ZDIV")]
+    pub zdiv: String,
+    /// 현지일자
+    #[serde(default, rename = "XYMD")]
+    pub xymd: String,
+    /// 현지시간
+    #[serde(default, rename = "XHMS")]
+    pub xhms: String,
+    /// 한국일자
+    #[serde(default, rename = "KYMD")]
+    pub kymd: String,
+    /// 한국시간
+    #[serde(default, rename = "KHMS")]
+    pub khms: String,
+    /// 매수총잔량
+    #[serde(default, rename = "BVOL")]
+    pub bvol: String,
+    /// 매도총잔량
+    #[serde(default, rename = "AVOL")]
+    pub avol: String,
+    /// 매수총잔량대비
+    #[serde(default, rename = "BDVL")]
+    pub bdvl: String,
+    /// 매도총잔량대비
+    #[serde(default, rename = "ADVL")]
+    pub advl: String,
+    /// 매수호가1
+    #[serde(default, rename = "PBID1")]
+    pub pbid1: String,
+    /// 매도호가1
+    #[serde(default, rename = "PASK1")]
+    pub pask1: String,
+    /// 매수잔량1
+    #[serde(default, rename = "VBID1")]
+    pub vbid1: String,
+    /// 매도잔량1
+    #[serde(default, rename = "VASK1")]
+    pub vask1: String,
+    /// 매수잔량대비1
+    #[serde(default, rename = "DBID1")]
+    pub dbid1: String,
+    /// 매도잔량대비1
+    #[serde(default, rename = "DASK1")]
+    pub dask1: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfsasp1Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외주식 실시간지연체결가[실시간-007]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfscnt0Response {
+    /// 실시간종목코드
+    #[serde(default, rename = "RSYM")]
+    pub rsym: String,
+    /// 종목코드
+    #[serde(default, rename = "SYMB")]
+    pub symb: String,
+    /// 수수점자리수
+    #[serde(default, rename = "ZDIV")]
+    pub zdiv: String,
+    /// 현지영업일자
+    #[serde(default, rename = "TYMD")]
+    pub tymd: String,
+    /// 현지일자
+    #[serde(default, rename = "XYMD")]
+    pub xymd: String,
+    /// 현지시간
+    #[serde(default, rename = "XHMS")]
+    pub xhms: String,
+    /// 한국일자
+    #[serde(default, rename = "KYMD")]
+    pub kymd: String,
+    /// 한국시간
+    #[serde(default, rename = "KHMS")]
+    pub khms: String,
+    /// 시가
+    #[serde(default, rename = "OPEN")]
+    pub open: String,
+    /// 고가
+    #[serde(default, rename = "HIGH")]
+    pub high: String,
+    /// 저가
+    #[serde(default, rename = "LOW")]
+    pub low: String,
+    /// 현재가
+    #[serde(default, rename = "LAST")]
+    pub last: String,
+    /// 대비구분
+    #[serde(default, rename = "SIGN")]
+    pub sign: String,
+    /// 전일대비
+    #[serde(default, rename = "DIFF")]
+    pub diff: String,
+    /// 등락율
+    #[serde(default, rename = "RATE")]
+    pub rate: String,
+    /// 매수호가
+    #[serde(default, rename = "PBID")]
+    pub pbid: String,
+    /// 매도호가
+    #[serde(default, rename = "PASK")]
+    pub pask: String,
+    /// 매수잔량
+    #[serde(default, rename = "VBID")]
+    pub vbid: String,
+    /// 매도잔량
+    #[serde(default, rename = "VASK")]
+    pub vask: String,
+    /// 체결량
+    #[serde(default, rename = "EVOL")]
+    pub evol: String,
+    /// 거래량
+    #[serde(default, rename = "TVOL")]
+    pub tvol: String,
+    /// 거래대금
+    #[serde(default, rename = "TAMT")]
+    pub tamt: String,
+    /// 매도체결량
+    #[serde(default, rename = "BIVL")]
+    pub bivl: String,
+    /// 매수체결량
+    #[serde(default, rename = "ASVL")]
+    pub asvl: String,
+    /// 체결강도
+    #[serde(default, rename = "STRN")]
+    pub strn: String,
+    /// 시장구분 1:장중,2:장전,3:장후
+    #[serde(default, rename = "MTYP")]
+    pub mtyp: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfscnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외주식 실시간체결통보[실시간-009]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0gscni0Response {
+    /// 고객 ID
+    #[serde(default, rename = "CUST_ID")]
+    pub cust_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACNT_NO")]
+    pub acnt_no: String,
+    /// 주문번호
+    #[serde(default, rename = "ODER_NO")]
+    pub oder_no: String,
+    /// 원주문번호
+    #[serde(default, rename = "OODER_NO")]
+    pub ooder_no: String,
+    /// 매도매수구분
+    #[serde(default, rename = "SELN_BYOV_CLS")]
+    pub seln_byov_cls: String,
+    /// 정정구분
+    #[serde(default, rename = "RCTF_CLS")]
+    pub rctf_cls: String,
+    /// 주문종류2
+    #[serde(default, rename = "ODER_KIND2")]
+    pub oder_kind2: String,
+    /// 주식 단축 종목코드
+    #[serde(default, rename = "STCK_SHRN_ISCD")]
+    pub stck_shrn_iscd: String,
+    /// 체결수량
+    #[serde(default, rename = "CNTG_QTY")]
+    pub cntg_qty: String,
+    /// 체결단가
+    #[serde(default, rename = "CNTG_UNPR")]
+    pub cntg_unpr: String,
+    /// 주식 체결 시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 거부여부
+    #[serde(default, rename = "RFUS_YN")]
+    pub rfus_yn: String,
+    /// 체결여부
+    #[serde(default, rename = "CNTG_YN")]
+    pub cntg_yn: String,
+    /// 접수여부
+    #[serde(default, rename = "ACPT_YN")]
+    pub acpt_yn: String,
+    /// 지점번호
+    #[serde(default, rename = "BRNC_NO")]
+    pub brnc_no: String,
+    /// 주문 수량
+    #[serde(default, rename = "ODER_QTY")]
+    pub oder_qty: String,
+    /// 계좌명
+    #[serde(default, rename = "ACNT_NAME")]
+    pub acnt_name: String,
+    /// 체결종목명
+    #[serde(default, rename = "CNTG_ISNM")]
+    pub cntg_isnm: String,
+    /// 해외종목구분
+    #[serde(default, rename = "ODER_COND")]
+    pub oder_cond: String,
+    /// 담보유형코드
+    #[serde(default, rename = "DEBT_GB")]
+    pub debt_gb: String,
+    /// 담보대출일자
+    #[serde(default, rename = "DEBT_DATE")]
+    pub debt_date: String,
+    /// 분할매수/매도 시작시간
+    #[serde(default, rename = "START_TM")]
+    pub start_tm: String,
+    /// 분할매수/매도 종료시간
+    #[serde(default, rename = "END_TM")]
+    pub end_tm: String,
+    /// 시간분할타입유형
+    #[serde(default, rename = "TM_DIV_TP")]
+    pub tm_div_tp: String,
+    /// 체결단가12
+    #[serde(default, rename = "CNTG_UNPR12")]
+    pub cntg_unpr12: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0gscni0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외선물옵션 실시간체결가[실시간-017]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfff020Response {
+    /// 종목코드
+    #[serde(default, rename = "SERIES_CD")]
+    pub series_cd: String,
+    /// 영업일자
+    #[serde(default, rename = "BSNS_DATE")]
+    pub bsns_date: String,
+    /// 장개시일자
+    #[serde(default, rename = "MRKT_OPEN_DATE")]
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    #[serde(default, rename = "MRKT_OPEN_TIME")]
+    pub mrkt_open_time: String,
+    /// 장종료일자
+    #[serde(default, rename = "MRKT_CLOSE_DATE")]
+    pub mrkt_close_date: String,
+    /// 장종료시각
+    #[serde(default, rename = "MRKT_CLOSE_TIME")]
+    pub mrkt_close_time: String,
+    /// 전일종가
+    #[serde(default, rename = "PREV_PRICE")]
+    pub prev_price: String,
+    /// 수신일자
+    #[serde(default, rename = "RECV_DATE")]
+    pub recv_date: String,
+    /// 수신시각
+    #[serde(default, rename = "RECV_TIME")]
+    pub recv_time: String,
+    /// 본장_전산장구분
+    #[serde(default, rename = "ACTIVE_FLAG")]
+    pub active_flag: String,
+    /// 체결가격
+    #[serde(default, rename = "LAST_PRICE")]
+    pub last_price: String,
+    /// 체결수량
+    #[serde(default, rename = "LAST_QNTT")]
+    pub last_qntt: String,
+    /// 전일대비가
+    #[serde(default, rename = "PREV_DIFF_PRICE")]
+    pub prev_diff_price: String,
+    /// 등락률
+    #[serde(default, rename = "PREV_DIFF_RATE")]
+    pub prev_diff_rate: String,
+    /// 시가
+    #[serde(default, rename = "OPEN_PRICE")]
+    pub open_price: String,
+    /// 고가
+    #[serde(default, rename = "HIGH_PRICE")]
+    pub high_price: String,
+    /// 저가
+    #[serde(default, rename = "LOW_PRICE")]
+    pub low_price: String,
+    /// 누적거래량
+    #[serde(default, rename = "VOL")]
+    pub vol: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PREV_SIGN")]
+    pub prev_sign: String,
+    /// 체결구분
+    #[serde(default, rename = "QUOTSIGN")]
+    pub quotsign: String,
+    /// 수신시각2 만분의일초
+    #[serde(default, rename = "RECV_TIME2")]
+    pub recv_time2: String,
+    /// 전일정산가
+    #[serde(default, rename = "PSTTL_PRICE")]
+    pub psttl_price: String,
+    /// 전일정산가대비
+    #[serde(default, rename = "PSTTL_SIGN")]
+    pub psttl_sign: String,
+    /// 전일정산가대비가격
+    #[serde(default, rename = "PSTTL_DIFF_PRICE")]
+    pub psttl_diff_price: String,
+    /// 전일정산가대비율
+    #[serde(default, rename = "PSTTL_DIFF_RATE")]
+    pub psttl_diff_rate: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfff020Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외선물옵션 실시간호가[실시간-018]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfff010Response {
+    /// 종목코드
+    #[serde(default, rename = "SERIES_CD")]
+    pub series_cd: String,
+    /// 수신일자
+    #[serde(default, rename = "RECV_DATE")]
+    pub recv_date: String,
+    /// 수신시각
+    #[serde(default, rename = "RECV_TIME")]
+    pub recv_time: String,
+    /// 전일종가
+    #[serde(default, rename = "PREV_PRICE")]
+    pub prev_price: String,
+    /// 매수1수량
+    #[serde(default, rename = "BID_QNTT_1")]
+    pub bid_qntt_1: String,
+    /// 매수1번호
+    #[serde(default, rename = "BID_NUM_1")]
+    pub bid_num_1: String,
+    /// 매수1호가
+    #[serde(default, rename = "BID_PRICE_1")]
+    pub bid_price_1: String,
+    /// 매도1수량
+    #[serde(default, rename = "ASK_QNTT_1")]
+    pub ask_qntt_1: String,
+    /// 매도1번호
+    #[serde(default, rename = "ASK_NUM_1")]
+    pub ask_num_1: String,
+    /// 매도1호가
+    #[serde(default, rename = "ASK_PRICE_1")]
+    pub ask_price_1: String,
+    /// 매수2수량
+    #[serde(default, rename = "BID_QNTT_2")]
+    pub bid_qntt_2: String,
+    /// 매수2번호
+    #[serde(default, rename = "BID_NUM_2")]
+    pub bid_num_2: String,
+    /// 매수2호가
+    #[serde(default, rename = "BID_PRICE_2")]
+    pub bid_price_2: String,
+    /// 매도2수량
+    #[serde(default, rename = "ASK_QNTT_2")]
+    pub ask_qntt_2: String,
+    /// 매도2번호
+    #[serde(default, rename = "ASK_NUM_2")]
+    pub ask_num_2: String,
+    /// 매도2호가
+    #[serde(default, rename = "ASK_PRICE_2")]
+    pub ask_price_2: String,
+    /// 매수3수량
+    #[serde(default, rename = "BID_QNTT_3")]
+    pub bid_qntt_3: String,
+    /// 매수3번호
+    #[serde(default, rename = "BID_NUM_3")]
+    pub bid_num_3: String,
+    /// 매수3호가
+    #[serde(default, rename = "BID_PRICE_3")]
+    pub bid_price_3: String,
+    /// 매도3수량
+    #[serde(default, rename = "ASK_QNTT_3")]
+    pub ask_qntt_3: String,
+    /// 매도3번호
+    #[serde(default, rename = "ASK_NUM_3")]
+    pub ask_num_3: String,
+    /// 매도3호가
+    #[serde(default, rename = "ASK_PRICE_3")]
+    pub ask_price_3: String,
+    /// 매수4수량
+    #[serde(default, rename = "BID_QNTT_4")]
+    pub bid_qntt_4: String,
+    /// 매수4번호
+    #[serde(default, rename = "BID_NUM_4")]
+    pub bid_num_4: String,
+    /// 매수4호가
+    #[serde(default, rename = "BID_PRICE_4")]
+    pub bid_price_4: String,
+    /// 매도4수량
+    #[serde(default, rename = "ASK_QNTT_4")]
+    pub ask_qntt_4: String,
+    /// 매도4번호
+    #[serde(default, rename = "ASK_NUM_4")]
+    pub ask_num_4: String,
+    /// 매도4호가
+    #[serde(default, rename = "ASK_PRICE_4")]
+    pub ask_price_4: String,
+    /// 매수5수량
+    #[serde(default, rename = "BID_QNTT_5")]
+    pub bid_qntt_5: String,
+    /// 매수5번호
+    #[serde(default, rename = "BID_NUM_5")]
+    pub bid_num_5: String,
+    /// 매수5호가
+    #[serde(default, rename = "BID_PRICE_5")]
+    pub bid_price_5: String,
+    /// 매도5수량
+    #[serde(default, rename = "ASK_QNTT_5")]
+    pub ask_qntt_5: String,
+    /// 매도5번호
+    #[serde(default, rename = "ASK_NUM_5")]
+    pub ask_num_5: String,
+    /// 매도5호가
+    #[serde(default, rename = "ASK_PRICE_5")]
+    pub ask_price_5: String,
+    /// 전일정산가
+    #[serde(default, rename = "STTL_PRICE")]
+    pub sttl_price: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfff010Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외선물옵션 실시간주문내역통보[실시간-019]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfff1c0Response {
+    /// 유저ID
+    #[serde(default, rename = "USER_ID")]
+    pub user_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACCT_NO")]
+    pub acct_no: String,
+    /// 주문일자
+    #[serde(default, rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 원주문일자
+    #[serde(default, rename = "ORGN_ORD_DT")]
+    pub orgn_ord_dt: String,
+    /// 원주문번호
+    #[serde(default, rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 종목명
+    #[serde(default, rename = "SERIES")]
+    pub series: String,
+    /// 정정취소구분코드
+    #[serde(default, rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 복합주문구분코드
+    #[serde(default, rename = "CPLX_ORD_DVSN_CD")]
+    pub cplx_ord_dvsn_cd: String,
+    /// 가격구분코드
+    #[serde(default, rename = "PRCE_TP")]
+    pub prce_tp: String,
+    /// FM거래소접수구분코드
+    #[serde(default, rename = "FM_EXCG_RCIT_DVSN_CD")]
+    pub fm_excg_rcit_dvsn_cd: String,
+    /// 주문수량
+    #[serde(default, rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// FMLIMIT가격
+    #[serde(default, rename = "FM_LMT_PRIC")]
+    pub fm_lmt_pric: String,
+    /// FMSTOP주문가격
+    #[serde(default, rename = "FM_STOP_ORD_PRIC")]
+    pub fm_stop_ord_pric: String,
+    /// 총체결수량
+    #[serde(default, rename = "TOT_CCLD_QTY")]
+    pub tot_ccld_qty: String,
+    /// 총체결단가
+    #[serde(default, rename = "TOT_CCLD_UV")]
+    pub tot_ccld_uv: String,
+    /// 잔량
+    #[serde(default, rename = "ORD_REMQ")]
+    pub ord_remq: String,
+    /// FM주문그룹일자
+    #[serde(default, rename = "FM_ORD_GRP_DT")]
+    pub fm_ord_grp_dt: String,
+    /// 주문그룹번호
+    #[serde(default, rename = "ORD_GRP_STNO")]
+    pub ord_grp_stno: String,
+    /// 주문상세일시
+    #[serde(default, rename = "ORD_DTL_DTIME")]
+    pub ord_dtl_dtime: String,
+    /// 조작상세일시
+    #[serde(default, rename = "OPRT_DTL_DTIME")]
+    pub oprt_dtl_dtime: String,
+    /// 주문자
+    #[serde(default, rename = "WORK_EMPL")]
+    pub work_empl: String,
+    /// 통화코드
+    #[serde(default, rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 청산여부(Y/N)
+    #[serde(default, rename = "LQD_YN")]
+    pub lqd_yn: String,
+    /// 청산LIMIT가격
+    #[serde(default, rename = "LQD_LMT_PRIC")]
+    pub lqd_lmt_pric: String,
+    /// 청산STOP가격
+    #[serde(default, rename = "LQD_STOP_PRIC")]
+    pub lqd_stop_pric: String,
+    /// 체결조건코드
+    #[serde(default, rename = "TRD_COND")]
+    pub trd_cond: String,
+    /// 기간주문유효상세일시
+    #[serde(default, rename = "TERM_ORD_VALD_DTIME")]
+    pub term_ord_vald_dtime: String,
+    /// 계좌청산유형구분코드
+    #[serde(default, rename = "SPEC_TP")]
+    pub spec_tp: String,
+    /// 행사예약주문여부
+    #[serde(default, rename = "ECIS_RSVN_ORD_YN")]
+    pub ecis_rsvn_ord_yn: String,
+    /// 선물옵션종목구분코드
+    #[serde(default, rename = "FUOP_ITEM_DVSN_CD")]
+    pub fuop_item_dvsn_cd: String,
+    /// 자동주문 전략구분
+    #[serde(default, rename = "AUTO_ORD_DVSN_CD")]
+    pub auto_ord_dvsn_cd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfff1c0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [해외선물옵션 실시간체결내역통보[실시간-020]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutHdfff2c0Response {
+    /// 유저ID
+    #[serde(default, rename = "USER_ID")]
+    pub user_id: String,
+    /// 계좌번호
+    #[serde(default, rename = "ACCT_NO")]
+    pub acct_no: String,
+    /// 주문일자
+    #[serde(default, rename = "ORD_DT")]
+    pub ord_dt: String,
+    /// 주문번호
+    #[serde(default, rename = "ODNO")]
+    pub odno: String,
+    /// 원주문일자
+    #[serde(default, rename = "ORGN_ORD_DT")]
+    pub orgn_ord_dt: String,
+    /// 원주문번호
+    #[serde(default, rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 종목명
+    #[serde(default, rename = "SERIES")]
+    pub series: String,
+    /// 정정취소구분코드
+    #[serde(default, rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 매도매수구분코드
+    #[serde(default, rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 복합주문구분코드
+    #[serde(default, rename = "CPLX_ORD_DVSN_CD")]
+    pub cplx_ord_dvsn_cd: String,
+    /// 가격구분코드
+    #[serde(default, rename = "PRCE_TP")]
+    pub prce_tp: String,
+    /// FM거래소접수구분코드
+    #[serde(default, rename = "FM_EXCG_RCIT_DVSN_CD")]
+    pub fm_excg_rcit_dvsn_cd: String,
+    /// 주문수량
+    #[serde(default, rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// FMLIMIT가격
+    #[serde(default, rename = "FM_LMT_PRIC")]
+    pub fm_lmt_pric: String,
+    /// FMSTOP주문가격
+    #[serde(default, rename = "FM_STOP_ORD_PRIC")]
+    pub fm_stop_ord_pric: String,
+    /// 총체결수량
+    #[serde(default, rename = "TOT_CCLD_QTY")]
+    pub tot_ccld_qty: String,
+    /// 총체결단가
+    #[serde(default, rename = "TOT_CCLD_UV")]
+    pub tot_ccld_uv: String,
+    /// 잔량
+    #[serde(default, rename = "ORD_REMQ")]
+    pub ord_remq: String,
+    /// FM주문그룹일자
+    #[serde(default, rename = "FM_ORD_GRP_DT")]
+    pub fm_ord_grp_dt: String,
+    /// 주문그룹번호
+    #[serde(default, rename = "ORD_GRP_STNO")]
+    pub ord_grp_stno: String,
+    /// 주문상세일시
+    #[serde(default, rename = "ORD_DTL_DTIME")]
+    pub ord_dtl_dtime: String,
+    /// 조작상세일시
+    #[serde(default, rename = "OPRT_DTL_DTIME")]
+    pub oprt_dtl_dtime: String,
+    /// 주문자
+    #[serde(default, rename = "WORK_EMPL")]
+    pub work_empl: String,
+    /// 체결일자
+    #[serde(default, rename = "CCLD_DT")]
+    pub ccld_dt: String,
+    /// 체결번호
+    #[serde(default, rename = "CCNO")]
+    pub ccno: String,
+    /// API 체결번호
+    #[serde(default, rename = "API_CCNO")]
+    pub api_ccno: String,
+    /// 체결수량
+    #[serde(default, rename = "CCLD_QTY")]
+    pub ccld_qty: String,
+    /// FM체결가격
+    #[serde(default, rename = "FM_CCLD_PRIC")]
+    pub fm_ccld_pric: String,
+    /// 통화코드
+    #[serde(default, rename = "CRCY_CD")]
+    pub crcy_cd: String,
+    /// 위탁수수료
+    #[serde(default, rename = "TRST_FEE")]
+    pub trst_fee: String,
+    /// 주문매체온라인여부
+    #[serde(default, rename = "ORD_MDIA_ONLINE_YN")]
+    pub ord_mdia_online_yn: String,
+    /// FM체결금액
+    #[serde(default, rename = "FM_CCLD_AMT")]
+    pub fm_ccld_amt: String,
+    /// 선물옵션종목구분코드
+    #[serde(default, rename = "FUOP_ITEM_DVSN_CD")]
+    pub fuop_item_dvsn_cd: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutHdfff2c0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [일반채권 실시간체결가 [실시간-052]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0bjcnt0Response {
+    /// 표준종목코드
+    #[serde(default, rename = "STND_ISCD")]
+    pub stnd_iscd: String,
+    /// 채권종목명
+    #[serde(default, rename = "BOND_ISNM")]
+    pub bond_isnm: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 전일대비부호
+    #[serde(default, rename = "PRDY_VRSS_SIGN")]
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    #[serde(default, rename = "PRDY_VRSS")]
+    pub prdy_vrss: String,
+    /// 전일대비율
+    #[serde(default, rename = "PRDY_CTRT")]
+    pub prdy_ctrt: String,
+    /// 현재가
+    #[serde(default, rename = "STCK_PRPR")]
+    pub stck_prpr: String,
+    /// 체결거래량
+    #[serde(default, rename = "CNTG_VOL")]
+    pub cntg_vol: String,
+    /// 시가
+    #[serde(default, rename = "STCK_OPRC")]
+    pub stck_oprc: String,
+    /// 고가
+    #[serde(default, rename = "STCK_HGPR")]
+    pub stck_hgpr: String,
+    /// 저가
+    #[serde(default, rename = "STCK_LWPR")]
+    pub stck_lwpr: String,
+    /// 전일종가
+    #[serde(default, rename = "STCK_PRDY_CLPR")]
+    pub stck_prdy_clpr: String,
+    /// 현재수익률
+    #[serde(default, rename = "BOND_CNTG_ERT")]
+    pub bond_cntg_ert: String,
+    /// 시가수익률
+    #[serde(default, rename = "OPRC_ERT")]
+    pub oprc_ert: String,
+    /// 고가수익률
+    #[serde(default, rename = "HGPR_ERT")]
+    pub hgpr_ert: String,
+    /// 저가수익률
+    #[serde(default, rename = "LWPR_ERT")]
+    pub lwpr_ert: String,
+    /// 누적거래량
+    #[serde(default, rename = "ACML_VOL")]
+    pub acml_vol: String,
+    /// 전일거래량
+    #[serde(default, rename = "PRDY_VOL")]
+    pub prdy_vol: String,
+    /// 체결유형코드
+    #[serde(default, rename = "CNTG_TYPE_CLS_CODE")]
+    pub cntg_type_cls_code: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0bjcnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [일반채권 실시간호가 [실시간-053]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0bjasp0Response {
+    /// 표준종목코드
+    #[serde(default, rename = "STND_ISCD")]
+    pub stnd_iscd: String,
+    /// 주식체결시간
+    #[serde(default, rename = "STCK_CNTG_HOUR")]
+    pub stck_cntg_hour: String,
+    /// 매도호가수익률
+    #[serde(default, rename = "ASKP_ERT1")]
+    pub askp_ert1: String,
+    /// 매수호가수익률1
+    #[serde(default, rename = "BIDP_ERT1")]
+    pub bidp_ert1: String,
+    /// 매도호가1
+    #[serde(default, rename = "ASKP1")]
+    pub askp1: String,
+    /// 매수호가1
+    #[serde(default, rename = "BIDP1")]
+    pub bidp1: String,
+    /// 매도호가잔량1
+    #[serde(default, rename = "ASKP_RSQN1")]
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    #[serde(default, rename = "BIDP_RSQN1")]
+    pub bidp_rsqn1: String,
+    /// 매도호가수익률2
+    #[serde(default, rename = "ASKP_ERT2")]
+    pub askp_ert2: String,
+    /// 매수호가수익률2
+    #[serde(default, rename = "BIDP_ERT2")]
+    pub bidp_ert2: String,
+    /// 매도호가2
+    #[serde(default, rename = "ASKP2")]
+    pub askp2: String,
+    /// 매수호가2
+    #[serde(default, rename = "BIDP2")]
+    pub bidp2: String,
+    /// 매도호가잔량2
+    #[serde(default, rename = "ASKP_RSQN2")]
+    pub askp_rsqn2: String,
+    /// 매수호가잔량2
+    #[serde(default, rename = "BIDP_RSQN2")]
+    pub bidp_rsqn2: String,
+    /// 매도호가수익률3
+    #[serde(default, rename = "ASKP_ERT3")]
+    pub askp_ert3: String,
+    /// 매수호가수익률3
+    #[serde(default, rename = "BIDP_ERT3")]
+    pub bidp_ert3: String,
+    /// 매도호가3
+    #[serde(default, rename = "ASKP3")]
+    pub askp3: String,
+    /// 매수호가3
+    #[serde(default, rename = "BIDP3")]
+    pub bidp3: String,
+    /// 매도호가잔량3
+    #[serde(default, rename = "ASKP_RSQN3")]
+    pub askp_rsqn3: String,
+    /// 매수호가잔량3
+    #[serde(default, rename = "BIDP_RSQN3")]
+    pub bidp_rsqn3: String,
+    /// 매도호가수익률4
+    #[serde(default, rename = "ASKP_ERT4")]
+    pub askp_ert4: String,
+    /// 매수호가수익률4
+    #[serde(default, rename = "BIDP_ERT4")]
+    pub bidp_ert4: String,
+    /// 매도호가4
+    #[serde(default, rename = "ASKP4")]
+    pub askp4: String,
+    /// 매수호가4
+    #[serde(default, rename = "BIDP4")]
+    pub bidp4: String,
+    /// 매도호가잔량4
+    #[serde(default, rename = "ASKP_RSQN4")]
+    pub askp_rsqn4: String,
+    /// 매수호가잔량4
+    #[serde(default, rename = "BIDP_RSQN4")]
+    pub bidp_rsqn4: String,
+    /// 매도호가수익률5
+    #[serde(default, rename = "ASKP_ERT5")]
+    pub askp_ert5: String,
+    /// 매수호가수익률5
+    #[serde(default, rename = "BIDP_ERT5")]
+    pub bidp_ert5: String,
+    /// 매도호가5
+    #[serde(default, rename = "ASKP5")]
+    pub askp5: String,
+    /// 매수호가5
+    #[serde(default, rename = "BIDP5")]
+    pub bidp5: String,
+    /// 매도호가잔량5
+    #[serde(default, rename = "ASKP_RSQN52")]
+    pub askp_rsqn52: String,
+    /// 매수호가잔량5
+    #[serde(default, rename = "BIDP_RSQN53")]
+    pub bidp_rsqn53: String,
+    /// 총매도호가잔량
+    #[serde(default, rename = "TOTAL_ASKP_RSQN")]
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    #[serde(default, rename = "TOTAL_BIDP_RSQN")]
+    pub total_bidp_rsqn: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0bjasp0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
+
+/// [채권지수 실시간체결가 [실시간-060]] 응답 구조체
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TryitoutH0bicnt0Response {
+    /// 지수ID
+    #[serde(default, rename = "NMIX_ID")]
+    pub nmix_id: String,
+    /// 기준일자1
+    #[serde(default, rename = "STND_DATE1")]
+    pub stnd_date1: String,
+    /// 전송시간
+    #[serde(default, rename = "TRNM_HOUR")]
+    pub trnm_hour: String,
+    /// 총수익지수시가지수
+    #[serde(default, rename = "TOTL_ERNN_NMIX_OPRC")]
+    pub totl_ernn_nmix_oprc: String,
+    /// 총수익지수최고가
+    #[serde(default, rename = "TOTL_ERNN_NMIX_HGPR")]
+    pub totl_ernn_nmix_hgpr: String,
+    /// 총수익지수최저가
+    #[serde(default, rename = "TOTL_ERNN_NMIX_LWPR")]
+    pub totl_ernn_nmix_lwpr: String,
+    /// 총수익지수
+    #[serde(default, rename = "TOTL_ERNN_NMIX")]
+    pub totl_ernn_nmix: String,
+    /// 전일총수익지수
+    #[serde(default, rename = "PRDY_TOTL_ERNN_NMIX")]
+    pub prdy_totl_ernn_nmix: String,
+    /// 총수익지수전일대비
+    #[serde(default, rename = "TOTL_ERNN_NMIX_PRDY_VRSS")]
+    pub totl_ernn_nmix_prdy_vrss: String,
+    /// 총수익지수전일대비부호
+    #[serde(default, rename = "TOTL_ERNN_NMIX_PRDY_VRSS_SIGN")]
+    pub totl_ernn_nmix_prdy_vrss_sign: String,
+    /// 총수익지수전일대비율
+    #[serde(default, rename = "TOTL_ERNN_NMIX_PRDY_CTRT")]
+    pub totl_ernn_nmix_prdy_ctrt: String,
+    /// 순가격지수
+    #[serde(default, rename = "CLEN_PRC_NMIX")]
+    pub clen_prc_nmix: String,
+    /// 시장가격지수
+    #[serde(default, rename = "MRKT_PRC_NMIX")]
+    pub mrkt_prc_nmix: String,
+    /// Call재투자지수
+    #[serde(default, rename = "BOND_CALL_RNVS_NMIX")]
+    pub bond_call_rnvs_nmix: String,
+    /// Zero재투자지수
+    #[serde(default, rename = "BOND_ZERO_RNVS_NMIX")]
+    pub bond_zero_rnvs_nmix: String,
+    /// 선물이론가격
+    #[serde(default, rename = "BOND_FUTS_THPR")]
+    pub bond_futs_thpr: String,
+    /// 평균듀레이션
+    #[serde(default, rename = "BOND_AVRG_DRTN_VAL")]
+    pub bond_avrg_drtn_val: String,
+    /// 평균컨벡서티
+    #[serde(default, rename = "BOND_AVRG_CNVX_VAL")]
+    pub bond_avrg_cnvx_val: String,
+    /// 평균YTM
+    #[serde(default, rename = "BOND_AVRG_YTM_VAL")]
+    pub bond_avrg_ytm_val: String,
+    /// 평균선도YTM
+    #[serde(default, rename = "BOND_AVRG_FRDL_YTM_VAL")]
+    pub bond_avrg_frdl_ytm_val: String,
+    /// 명세에 없는 필드
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnknownFields for TryitoutH0bicnt0Response {
+    fn unknown_fields(&self) -> Vec<String> {
+        self.extra.keys().cloned().collect()
+    }
+}
@@ -3,46 +3,49 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct OverseasCommon(pub(crate) KisClient);
+pub struct OverseasCommon<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct OverseasTrading(pub(crate) KisClient);
+pub struct OverseasTrading<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct OverseasQuotations(pub(crate) KisClient);
+pub struct OverseasQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct OverseasRanking(pub(crate) KisClient);
+pub struct OverseasRanking<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::Overseas {
-    pub fn common(&self) -> OverseasCommon {
+impl<E> crate::endpoints::Overseas<E> {
+    pub fn common(&self) -> OverseasCommon<E> {
         OverseasCommon(self.0.clone())
     }
-    pub fn trading(&self) -> OverseasTrading {
+    pub fn trading(&self) -> OverseasTrading<E> {
         OverseasTrading(self.0.clone())
     }
-    pub fn quotations(&self) -> OverseasQuotations {
+    pub fn quotations(&self) -> OverseasQuotations<E> {
         OverseasQuotations(self.0.clone())
     }
-    pub fn ranking(&self) -> OverseasRanking {
+    pub fn ranking(&self) -> OverseasRanking<E> {
         OverseasRanking(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl OverseasCommon {
+impl<E> OverseasCommon<E> {
     /// 접근토큰발급(P)[인증-001]
     /// - TR_ID: Real= / VTS=모의투자 미지원
     /// - Endpoint: /oauth2/tokenP
     pub async fn oauth2_token_p(
         &self,
         req: Oauth2TokenpRequest,
-    ) -> Result<Oauth2TokenpResponse, KisError> {
+    ) -> Result<Oauth2TokenpResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => ("", "https://openapi.koreainvestment.com:9443"),
             crate::client::KisEnv::Vts => (
@@ -59,7 +62,10 @@ impl OverseasCommon {
     pub async fn oauth2_revoke_p(
         &self,
         req: Oauth2RevokepRequest,
-    ) -> Result<Oauth2RevokepResponse, KisError> {
+    ) -> Result<Oauth2RevokepResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => ("", "https://openapi.koreainvestment.com:9443"),
             crate::client::KisEnv::Vts => (
@@ -76,7 +82,10 @@ impl OverseasCommon {
     pub async fn oauth2_approval(
         &self,
         req: Oauth2ApprovalRequest,
-    ) -> Result<Oauth2ApprovalResponse, KisError> {
+    ) -> Result<Oauth2ApprovalResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => ("", "https://openapi.koreainvestment.com:9443"),
             crate::client::KisEnv::Vts => (
@@ -89,7 +98,7 @@ impl OverseasCommon {
 }
 
 #[allow(non_snake_case)]
-impl OverseasTrading {
+impl<E> OverseasTrading<E> {
     /// 해외주식 주문[v1_해외주식-001]
     /// - TR_ID: Real=TTTT1002U / VTS=VTTT1002U
     /// - Endpoint: /uapi/overseas-stock/v1/trading/order
@@ -246,7 +255,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_order_resv_ccnl(
         &self,
         req: OverseasStockV1TradingOrderResvCcnlRequest,
-    ) -> Result<OverseasStockV1TradingOrderResvCcnlResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingOrderResvCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => (
                 "모의투자 미지원",
@@ -298,7 +310,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_inquire_nccs(
         &self,
         req: OverseasStockV1TradingInquireNccsRequest,
-    ) -> Result<OverseasStockV1TradingInquireNccsResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingInquireNccsResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS3018R", "https://openapi.koreainvestment.com:9443")
@@ -397,7 +412,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_order_resv_list_us(
         &self,
         req: OverseasStockV1TradingOrderResvListRequest,
-    ) -> Result<OverseasStockV1TradingOrderResvListResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingOrderResvListResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTT3039R", "https://openapi.koreainvestment.com:9443")
@@ -420,7 +438,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_order_resv_list_asia(
         &self,
         req: OverseasStockV1TradingOrderResvListRequest,
-    ) -> Result<OverseasStockV1TradingOrderResvListResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingOrderResvListResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS3014R", "https://openapi.koreainvestment.com:9443")
@@ -443,7 +464,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_inquire_paymt_stdr_balance(
         &self,
         req: OverseasStockV1TradingInquirePaymtStdrBalanceRequest,
-    ) -> Result<OverseasStockV1TradingInquirePaymtStdrBalanceResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingInquirePaymtStdrBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTRP6010R", "https://openapi.koreainvestment.com:9443")
@@ -466,7 +490,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_inquire_period_trans(
         &self,
         req: OverseasStockV1TradingInquirePeriodTransRequest,
-    ) -> Result<OverseasStockV1TradingInquirePeriodTransResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingInquirePeriodTransResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTOS4001R", "https://openapi.koreainvestment.com:9443")
@@ -489,7 +516,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_inquire_period_profit(
         &self,
         req: OverseasStockV1TradingInquirePeriodProfitRequest,
-    ) -> Result<OverseasStockV1TradingInquirePeriodProfitResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingInquirePeriodProfitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS3039R", "https://openapi.koreainvestment.com:9443")
@@ -512,7 +542,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_foreign_margin(
         &self,
         req: OverseasStockV1TradingForeignMarginRequest,
-    ) -> Result<OverseasStockV1TradingForeignMarginResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingForeignMarginResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC2101R", "https://openapi.koreainvestment.com:9443")
@@ -535,7 +568,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_daytime_order_buy(
         &self,
         req: OverseasStockV1TradingDaytimeOrderRequest,
-    ) -> Result<OverseasStockV1TradingDaytimeOrderResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingDaytimeOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS6036U", "https://openapi.koreainvestment.com:9443")
@@ -558,7 +594,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_daytime_order_sell(
         &self,
         req: OverseasStockV1TradingDaytimeOrderRequest,
-    ) -> Result<OverseasStockV1TradingDaytimeOrderResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingDaytimeOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS6037U", "https://openapi.koreainvestment.com:9443")
@@ -581,7 +620,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_daytime_order_rvsecncl(
         &self,
         req: OverseasStockV1TradingDaytimeOrderRvsecnclRequest,
-    ) -> Result<OverseasStockV1TradingDaytimeOrderRvsecnclResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingDaytimeOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS6038U", "https://openapi.koreainvestment.com:9443")
@@ -604,7 +646,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_algo_ordno(
         &self,
         req: OverseasStockV1TradingAlgoOrdnoRequest,
-    ) -> Result<OverseasStockV1TradingAlgoOrdnoResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingAlgoOrdnoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS6058R", "https://openapi.koreainvestment.com:9443")
@@ -627,7 +672,10 @@ impl OverseasTrading {
     pub async fn overseas_stock_v1_trading_inquire_algo_ccnl(
         &self,
         req: OverseasStockV1TradingInquireAlgoCcnlRequest,
-    ) -> Result<OverseasStockV1TradingInquireAlgoCcnlResponse, KisError> {
+    ) -> Result<OverseasStockV1TradingInquireAlgoCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTS6059R", "https://openapi.koreainvestment.com:9443")
@@ -646,14 +694,17 @@ impl OverseasTrading {
 }
 
 #[allow(non_snake_case)]
-impl OverseasQuotations {
+impl<E> OverseasQuotations<E> {
     /// 해외주식 현재가상세[v1_해외주식-029]
     /// - TR_ID: Real=HHDFS76200200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/overseas-price/v1/quotations/price-detail
     pub async fn overseas_price_v1_quotations_price_detail(
         &self,
         req: OverseasPriceV1QuotationsPriceDetailRequest,
-    ) -> Result<OverseasPriceV1QuotationsPriceDetailResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsPriceDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76200200", "https://openapi.koreainvestment.com:9443")
@@ -676,7 +727,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_inquire_asking_price(
         &self,
         req: OverseasPriceV1QuotationsInquireAskingPriceRequest,
-    ) -> Result<OverseasPriceV1QuotationsInquireAskingPriceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsInquireAskingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76200100", "https://openapi.koreainvestment.com:9443")
@@ -725,7 +779,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_inquire_ccnl(
         &self,
         req: OverseasPriceV1QuotationsInquireCcnlRequest,
-    ) -> Result<OverseasPriceV1QuotationsInquireCcnlResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsInquireCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76200300", "https://openapi.koreainvestment.com:9443")
@@ -748,7 +805,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_inquire_time_itemchartprice(
         &self,
         req: OverseasPriceV1QuotationsInquireTimeItemchartpriceRequest,
-    ) -> Result<OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76950200", "https://openapi.koreainvestment.com:9443")
@@ -771,7 +831,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_inquire_time_indexchartprice(
         &self,
         req: OverseasPriceV1QuotationsInquireTimeIndexchartpriceRequest,
-    ) -> Result<OverseasPriceV1QuotationsInquireTimeIndexchartpriceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsInquireTimeIndexchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST03030200", "https://openapi.koreainvestment.com:9443")
@@ -872,7 +935,10 @@ impl OverseasQuotations {
     pub async fn overseas_stock_v1_quotations_countries_holiday(
         &self,
         req: OverseasStockV1QuotationsCountriesHolidayRequest,
-    ) -> Result<OverseasStockV1QuotationsCountriesHolidayResponse, KisError> {
+    ) -> Result<OverseasStockV1QuotationsCountriesHolidayResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTOS5011R", "https://openapi.koreainvestment.com:9443")
@@ -895,7 +961,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_search_info(
         &self,
         req: OverseasPriceV1QuotationsSearchInfoRequest,
-    ) -> Result<OverseasPriceV1QuotationsSearchInfoResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsSearchInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF1702R", "https://openapi.koreainvestment.com:9443")
@@ -918,7 +987,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_industry_theme(
         &self,
         req: OverseasPriceV1QuotationsIndustryThemeRequest,
-    ) -> Result<OverseasPriceV1QuotationsIndustryThemeResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsIndustryThemeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76370000", "https://openapi.koreainvestment.com:9443")
@@ -941,7 +1013,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_industry_price(
         &self,
         req: OverseasPriceV1QuotationsIndustryPriceRequest,
-    ) -> Result<OverseasPriceV1QuotationsIndustryPriceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsIndustryPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76370100", "https://openapi.koreainvestment.com:9443")
@@ -964,7 +1039,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_multprice(
         &self,
         req: OverseasPriceV1QuotationsMultpriceRequest,
-    ) -> Result<OverseasPriceV1QuotationsMultpriceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsMultpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76220000", "https://openapi.koreainvestment.com:9443")
@@ -987,7 +1065,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_period_rights(
         &self,
         req: OverseasPriceV1QuotationsPeriodRightsRequest,
-    ) -> Result<OverseasPriceV1QuotationsPeriodRightsResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsPeriodRightsResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTRGT011R", "https://openapi.koreainvestment.com:9443")
@@ -1010,7 +1091,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_news_title(
         &self,
         req: OverseasPriceV1QuotationsNewsTitleRequest,
-    ) -> Result<OverseasPriceV1QuotationsNewsTitleResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsNewsTitleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHPSTH60100C1", "https://openapi.koreainvestment.com:9443")
@@ -1033,7 +1117,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_rights_by_ice(
         &self,
         req: OverseasPriceV1QuotationsRightsByIceRequest,
-    ) -> Result<OverseasPriceV1QuotationsRightsByIceResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsRightsByIceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS78330900", "https://openapi.koreainvestment.com:9443")
@@ -1056,7 +1143,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_colable_by_company(
         &self,
         req: OverseasPriceV1QuotationsColableByCompanyRequest,
-    ) -> Result<OverseasPriceV1QuotationsColableByCompanyResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsColableByCompanyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTLN4050R", "https://openapi.koreainvestment.com:9443")
@@ -1079,7 +1169,10 @@ impl OverseasQuotations {
     pub async fn overseas_price_v1_quotations_brknews_title(
         &self,
         req: OverseasPriceV1QuotationsBrknewsTitleRequest,
-    ) -> Result<OverseasPriceV1QuotationsBrknewsTitleResponse, KisError> {
+    ) -> Result<OverseasPriceV1QuotationsBrknewsTitleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST01011801", "https://openapi.koreainvestment.com:9443")
@@ -1098,14 +1191,17 @@ impl OverseasQuotations {
 }
 
 #[allow(non_snake_case)]
-impl OverseasRanking {
+impl<E> OverseasRanking<E> {
     /// 해외주식 가격급등락[해외주식-038]
     /// - TR_ID: Real=HHDFS76260000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/overseas-stock/v1/ranking/price-fluct
     pub async fn overseas_stock_v1_ranking_price_fluct(
         &self,
         req: OverseasStockV1RankingPriceFluctRequest,
-    ) -> Result<OverseasStockV1RankingPriceFluctResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingPriceFluctResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76260000", "https://openapi.koreainvestment.com:9443")
@@ -1128,7 +1224,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_volume_surge(
         &self,
         req: OverseasStockV1RankingVolumeSurgeRequest,
-    ) -> Result<OverseasStockV1RankingVolumeSurgeResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingVolumeSurgeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76270000", "https://openapi.koreainvestment.com:9443")
@@ -1151,7 +1250,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_volume_power(
         &self,
         req: OverseasStockV1RankingVolumePowerRequest,
-    ) -> Result<OverseasStockV1RankingVolumePowerResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingVolumePowerResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76280000", "https://openapi.koreainvestment.com:9443")
@@ -1174,7 +1276,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_updown_rate(
         &self,
         req: OverseasStockV1RankingUpdownRateRequest,
-    ) -> Result<OverseasStockV1RankingUpdownRateResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingUpdownRateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76290000", "https://openapi.koreainvestment.com:9443")
@@ -1197,7 +1302,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_new_highlow(
         &self,
         req: OverseasStockV1RankingNewHighlowRequest,
-    ) -> Result<OverseasStockV1RankingNewHighlowResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingNewHighlowResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76300000", "https://openapi.koreainvestment.com:9443")
@@ -1220,7 +1328,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_trade_vol(
         &self,
         req: OverseasStockV1RankingTradeVolRequest,
-    ) -> Result<OverseasStockV1RankingTradeVolResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingTradeVolResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76310010", "https://openapi.koreainvestment.com:9443")
@@ -1243,7 +1354,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_trade_pbmn(
         &self,
         req: OverseasStockV1RankingTradePbmnRequest,
-    ) -> Result<OverseasStockV1RankingTradePbmnResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingTradePbmnResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76320010", "https://openapi.koreainvestment.com:9443")
@@ -1266,7 +1380,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_trade_growth(
         &self,
         req: OverseasStockV1RankingTradeGrowthRequest,
-    ) -> Result<OverseasStockV1RankingTradeGrowthResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingTradeGrowthResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76330000", "https://openapi.koreainvestment.com:9443")
@@ -1289,7 +1406,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_trade_turnover(
         &self,
         req: OverseasStockV1RankingTradeTurnoverRequest,
-    ) -> Result<OverseasStockV1RankingTradeTurnoverResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingTradeTurnoverResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76340000", "https://openapi.koreainvestment.com:9443")
@@ -1312,7 +1432,10 @@ impl OverseasRanking {
     pub async fn overseas_stock_v1_ranking_market_cap(
         &self,
         req: OverseasStockV1RankingMarketCapRequest,
-    ) -> Result<OverseasStockV1RankingMarketCapResponse, KisError> {
+    ) -> Result<OverseasStockV1RankingMarketCapResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFS76350100", "https://openapi.koreainvestment.com:9443")
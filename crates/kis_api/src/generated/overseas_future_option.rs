@@ -3,34 +3,37 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct OverseasFutureOptionTrading(pub(crate) KisClient);
+pub struct OverseasFutureOptionTrading<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct OverseasFutureOptionQuotations(pub(crate) KisClient);
+pub struct OverseasFutureOptionQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::OverseasFutureOption {
-    pub fn trading(&self) -> OverseasFutureOptionTrading {
+impl<E> crate::endpoints::OverseasFutureOption<E> {
+    pub fn trading(&self) -> OverseasFutureOptionTrading<E> {
         OverseasFutureOptionTrading(self.0.clone())
     }
-    pub fn quotations(&self) -> OverseasFutureOptionQuotations {
+    pub fn quotations(&self) -> OverseasFutureOptionQuotations<E> {
         OverseasFutureOptionQuotations(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl OverseasFutureOptionTrading {
+impl<E> OverseasFutureOptionTrading<E> {
     /// 해외선물옵션 주문 [v1_해외선물-001]
     /// - TR_ID: Real=OTFM3001U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/overseas-futureoption/v1/trading/order
     pub async fn overseas_futureoption_v1_trading_order(
         &self,
         req: OverseasFutureoptionV1TradingOrderRequest,
-    ) -> Result<OverseasFutureoptionV1TradingOrderResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3001U", "https://openapi.koreainvestment.com:9443")
@@ -53,7 +56,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_order_rvsecncl_modify(
         &self,
         req: OverseasFutureoptionV1TradingOrderRvsecnclRequest,
-    ) -> Result<OverseasFutureoptionV1TradingOrderRvsecnclResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3002U", "https://openapi.koreainvestment.com:9443")
@@ -76,7 +82,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_order_rvsecncl_cancel(
         &self,
         req: OverseasFutureoptionV1TradingOrderRvsecnclRequest,
-    ) -> Result<OverseasFutureoptionV1TradingOrderRvsecnclResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3003U", "https://openapi.koreainvestment.com:9443")
@@ -99,7 +108,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_ccld(
         &self,
         req: OverseasFutureoptionV1TradingInquireCcldRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquireCcldResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquireCcldResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3116R", "https://openapi.koreainvestment.com:9443")
@@ -122,7 +134,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_unpd(
         &self,
         req: OverseasFutureoptionV1TradingInquireUnpdRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquireUnpdResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquireUnpdResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM1412R", "https://openapi.koreainvestment.com:9443")
@@ -145,7 +160,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_psamount(
         &self,
         req: OverseasFutureoptionV1TradingInquirePsamountRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquirePsamountResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquirePsamountResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3304R", "https://openapi.koreainvestment.com:9443")
@@ -168,7 +186,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_period_ccld(
         &self,
         req: OverseasFutureoptionV1TradingInquirePeriodCcldRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquirePeriodCcldResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquirePeriodCcldResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3118R", "https://openapi.koreainvestment.com:9443")
@@ -191,7 +212,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_daily_ccld(
         &self,
         req: OverseasFutureoptionV1TradingInquireDailyCcldRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquireDailyCcldResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquireDailyCcldResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3122R", "https://openapi.koreainvestment.com:9443")
@@ -214,7 +238,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_deposit(
         &self,
         req: OverseasFutureoptionV1TradingInquireDepositRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquireDepositResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquireDepositResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM1411R", "https://openapi.koreainvestment.com:9443")
@@ -237,7 +264,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_daily_order(
         &self,
         req: OverseasFutureoptionV1TradingInquireDailyOrderRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquireDailyOrderResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquireDailyOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3120R", "https://openapi.koreainvestment.com:9443")
@@ -260,7 +290,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_inquire_period_trans(
         &self,
         req: OverseasFutureoptionV1TradingInquirePeriodTransRequest,
-    ) -> Result<OverseasFutureoptionV1TradingInquirePeriodTransResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingInquirePeriodTransResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3114R", "https://openapi.koreainvestment.com:9443")
@@ -283,7 +316,10 @@ impl OverseasFutureOptionTrading {
     pub async fn overseas_futureoption_v1_trading_margin_detail(
         &self,
         req: OverseasFutureoptionV1TradingMarginDetailRequest,
-    ) -> Result<OverseasFutureoptionV1TradingMarginDetailResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1TradingMarginDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM3115R", "https://openapi.koreainvestment.com:9443")
@@ -302,14 +338,17 @@ impl OverseasFutureOptionTrading {
 }
 
 #[allow(non_snake_case)]
-impl OverseasFutureOptionQuotations {
+impl<E> OverseasFutureOptionQuotations<E> {
     /// 해외선물종목현재가 [v1_해외선물-009]
     /// - TR_ID: Real=HHDFC55010000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/overseas-futureoption/v1/quotations/inquire-price
     pub async fn overseas_futureoption_v1_quotations_inquire_price(
         &self,
         req: OverseasFutureoptionV1QuotationsInquirePriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsInquirePriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsInquirePriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55010000", "https://openapi.koreainvestment.com:9443")
@@ -332,7 +371,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_stock_detail(
         &self,
         req: OverseasFutureoptionV1QuotationsStockDetailRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsStockDetailResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsStockDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55010100", "https://openapi.koreainvestment.com:9443")
@@ -355,7 +397,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_inquire_asking_price(
         &self,
         req: OverseasFutureoptionV1QuotationsInquireAskingPriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsInquireAskingPriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsInquireAskingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC86000000", "https://openapi.koreainvestment.com:9443")
@@ -378,7 +423,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_inquire_time_futurechartprice(
         &self,
         req: OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55020400", "https://openapi.koreainvestment.com:9443")
@@ -401,7 +449,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_tick_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsTickCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsTickCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsTickCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55020200", "https://openapi.koreainvestment.com:9443")
@@ -424,7 +475,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_weekly_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsWeeklyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsWeeklyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsWeeklyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55020000", "https://openapi.koreainvestment.com:9443")
@@ -447,7 +501,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_daily_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsDailyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsDailyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsDailyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55020100", "https://openapi.koreainvestment.com:9443")
@@ -470,7 +527,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_monthly_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsMonthlyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsMonthlyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsMonthlyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55020300", "https://openapi.koreainvestment.com:9443")
@@ -493,7 +553,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_search_contract_detail(
         &self,
         req: OverseasFutureoptionV1QuotationsSearchContractDetailRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsSearchContractDetailResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsSearchContractDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFC55200000", "https://openapi.koreainvestment.com:9443")
@@ -516,7 +579,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_investor_unpd_trend(
         &self,
         req: OverseasFutureoptionV1QuotationsInvestorUnpdTrendRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsInvestorUnpdTrendResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsInvestorUnpdTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDDB95030000", "https://openapi.koreainvestment.com:9443")
@@ -539,7 +605,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_price(
         &self,
         req: OverseasFutureoptionV1QuotationsOptPriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptPriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55010000", "https://openapi.koreainvestment.com:9443")
@@ -562,7 +631,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_detail(
         &self,
         req: OverseasFutureoptionV1QuotationsOptDetailRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptDetailResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55010100", "https://openapi.koreainvestment.com:9443")
@@ -585,7 +657,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_asking_price(
         &self,
         req: OverseasFutureoptionV1QuotationsOptAskingPriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptAskingPriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptAskingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO86000000", "https://openapi.koreainvestment.com:9443")
@@ -608,7 +683,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_inquire_time_optchartprice(
         &self,
         req: OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55020400", "https://openapi.koreainvestment.com:9443")
@@ -631,7 +709,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_tick_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsOptTickCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptTickCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptTickCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55020200", "https://openapi.koreainvestment.com:9443")
@@ -654,7 +735,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_daily_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsOptDailyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptDailyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptDailyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55020100", "https://openapi.koreainvestment.com:9443")
@@ -677,7 +761,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_weekly_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsOptWeeklyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptWeeklyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptWeeklyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55020000", "https://openapi.koreainvestment.com:9443")
@@ -700,7 +787,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_opt_monthly_ccnl(
         &self,
         req: OverseasFutureoptionV1QuotationsOptMonthlyCcnlRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsOptMonthlyCcnlResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsOptMonthlyCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55020300", "https://openapi.koreainvestment.com:9443")
@@ -723,7 +813,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_search_opt_detail(
         &self,
         req: OverseasFutureoptionV1QuotationsSearchOptDetailRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsSearchOptDetailResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsSearchOptDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHDFO55200000", "https://openapi.koreainvestment.com:9443")
@@ -746,7 +839,10 @@ impl OverseasFutureOptionQuotations {
     pub async fn overseas_futureoption_v1_quotations_market_time(
         &self,
         req: OverseasFutureoptionV1QuotationsMarketTimeRequest,
-    ) -> Result<OverseasFutureoptionV1QuotationsMarketTimeResponse, KisError> {
+    ) -> Result<OverseasFutureoptionV1QuotationsMarketTimeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("OTFM2229R", "https://openapi.koreainvestment.com:9443")
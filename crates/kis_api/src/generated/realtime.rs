@@ -0,0 +1,3295 @@
+// This file is generated from kis-openapi.yaml. Do not edit manually.
+use crate::models::*;
+use crate::realtime::RealtimeRecord;
+
+/// [국내주식 실시간체결가 (KRX) [실시간-003]] `H0STCNT0` 레코드 (46개 필드)
+impl RealtimeRecord for TryitoutH0stcnt0Response {
+    const TR_ID: &'static str = "H0STCNT0";
+    const FIELD_COUNT: usize = 46;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            ccld_dvsn: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            vi_stnd_prc: f[45].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간호가 (KRX) [실시간-004]] `H0STASP0` 레코드 (59개 필드)
+impl RealtimeRecord for TryitoutH0stasp0Response {
+    const TR_ID: &'static str = "H0STASP0";
+    const FIELD_COUNT: usize = 59;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            hour_cls_code: f[2].to_string(),
+            askp1: f[3].to_string(),
+            askp2: f[4].to_string(),
+            askp3: f[5].to_string(),
+            askp4: f[6].to_string(),
+            askp5: f[7].to_string(),
+            askp6: f[8].to_string(),
+            askp7: f[9].to_string(),
+            askp8: f[10].to_string(),
+            askp9: f[11].to_string(),
+            askp10: f[12].to_string(),
+            bidp1: f[13].to_string(),
+            bidp2: f[14].to_string(),
+            bidp3: f[15].to_string(),
+            bidp4: f[16].to_string(),
+            bidp5: f[17].to_string(),
+            bidp6: f[18].to_string(),
+            bidp7: f[19].to_string(),
+            bidp8: f[20].to_string(),
+            bidp9: f[21].to_string(),
+            bidp10: f[22].to_string(),
+            askp_rsqn1: f[23].to_string(),
+            askp_rsqn2: f[24].to_string(),
+            askp_rsqn3: f[25].to_string(),
+            askp_rsqn4: f[26].to_string(),
+            askp_rsqn5: f[27].to_string(),
+            askp_rsqn6: f[28].to_string(),
+            askp_rsqn7: f[29].to_string(),
+            askp_rsqn8: f[30].to_string(),
+            askp_rsqn9: f[31].to_string(),
+            askp_rsqn10: f[32].to_string(),
+            bidp_rsqn1: f[33].to_string(),
+            bidp_rsqn2: f[34].to_string(),
+            bidp_rsqn3: f[35].to_string(),
+            bidp_rsqn4: f[36].to_string(),
+            bidp_rsqn5: f[37].to_string(),
+            bidp_rsqn6: f[38].to_string(),
+            bidp_rsqn7: f[39].to_string(),
+            bidp_rsqn8: f[40].to_string(),
+            bidp_rsqn9: f[41].to_string(),
+            bidp_rsqn10: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            ovtm_total_askp_rsqn: f[45].to_string(),
+            ovtm_total_bidp_rsqn: f[46].to_string(),
+            antc_cnpr: f[47].to_string(),
+            antc_cnqn: f[48].to_string(),
+            antc_vol: f[49].to_string(),
+            antc_cntg_vrss: f[50].to_string(),
+            antc_cntg_vrss_sign: f[51].to_string(),
+            antc_cntg_prdy_ctrt: f[52].to_string(),
+            acml_vol: f[53].to_string(),
+            total_askp_rsqn_icdc: f[54].to_string(),
+            total_bidp_rsqn_icdc: f[55].to_string(),
+            ovtm_total_askp_icdc: f[56].to_string(),
+            ovtm_total_bidp_icdc: f[57].to_string(),
+            stck_deal_cls_code: f[58].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간체결통보 [실시간-005]] `H0STCNI0` 레코드 (26개 필드)
+impl RealtimeRecord for TryitoutH0stcni0Response {
+    const TR_ID: &'static str = "H0STCNI0";
+    const FIELD_COUNT: usize = 26;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            cust_id: f[0].to_string(),
+            acnt_no: f[1].to_string(),
+            oder_no: f[2].to_string(),
+            ooder_no: f[3].to_string(),
+            seln_byov_cls: f[4].to_string(),
+            rctf_cls: f[5].to_string(),
+            oder_kind: f[6].to_string(),
+            oder_cond: f[7].to_string(),
+            stck_shrn_iscd: f[8].to_string(),
+            cntg_qty: f[9].to_string(),
+            cntg_unpr: f[10].to_string(),
+            stck_cntg_hour: f[11].to_string(),
+            rfus_yn: f[12].to_string(),
+            cntg_yn: f[13].to_string(),
+            acpt_yn: f[14].to_string(),
+            brnc_no: f[15].to_string(),
+            oder_qty: f[16].to_string(),
+            acnt_name: f[17].to_string(),
+            ord_cond_prc: f[18].to_string(),
+            ord_exg_gb: f[19].to_string(),
+            popup_yn: f[20].to_string(),
+            filler: f[21].to_string(),
+            crdt_cls: f[22].to_string(),
+            crdt_loan_date: f[23].to_string(),
+            cntg_isnm40: f[24].to_string(),
+            oder_prc: f[25].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간예상체결 (KRX) [실시간-041]] `H0STANC0` 레코드 (45개 필드)
+impl RealtimeRecord for TryitoutH0stanc0Response {
+    const TR_ID: &'static str = "H0STANC0";
+    const FIELD_COUNT: usize = 45;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간회원사 (KRX) [실시간-047]] `H0STMBC0` 레코드 (78개 필드)
+impl RealtimeRecord for TryitoutH0stmbc0Response {
+    const TR_ID: &'static str = "H0STMBC0";
+    const FIELD_COUNT: usize = 78;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            seln2_mbcr_name1: f[1].to_string(),
+            seln2_mbcr_name2: f[2].to_string(),
+            seln2_mbcr_name3: f[3].to_string(),
+            seln2_mbcr_name4: f[4].to_string(),
+            seln2_mbcr_name5: f[5].to_string(),
+            byov_mbcr_name1: f[6].to_string(),
+            byov_mbcr_name2: f[7].to_string(),
+            byov_mbcr_name3: f[8].to_string(),
+            byov_mbcr_name4: f[9].to_string(),
+            byov_mbcr_name5: f[10].to_string(),
+            total_seln_qty1: f[11].to_string(),
+            total_seln_qty2: f[12].to_string(),
+            total_seln_qty3: f[13].to_string(),
+            total_seln_qty4: f[14].to_string(),
+            total_seln_qty5: f[15].to_string(),
+            total_shnu_qty1: f[16].to_string(),
+            total_shnu_qty2: f[17].to_string(),
+            total_shnu_qty3: f[18].to_string(),
+            total_shnu_qty4: f[19].to_string(),
+            total_shnu_qty5: f[20].to_string(),
+            seln_mbcr_glob_yn_1: f[21].to_string(),
+            seln_mbcr_glob_yn_2: f[22].to_string(),
+            seln_mbcr_glob_yn_3: f[23].to_string(),
+            seln_mbcr_glob_yn_4: f[24].to_string(),
+            seln_mbcr_glob_yn_5: f[25].to_string(),
+            shnu_mbcr_glob_yn_1: f[26].to_string(),
+            shnu_mbcr_glob_yn_2: f[27].to_string(),
+            shnu_mbcr_glob_yn_3: f[28].to_string(),
+            shnu_mbcr_glob_yn_4: f[29].to_string(),
+            shnu_mbcr_glob_yn_5: f[30].to_string(),
+            seln_mbcr_no1: f[31].to_string(),
+            seln_mbcr_no2: f[32].to_string(),
+            seln_mbcr_no3: f[33].to_string(),
+            seln_mbcr_no4: f[34].to_string(),
+            seln_mbcr_no5: f[35].to_string(),
+            shnu_mbcr_no1: f[36].to_string(),
+            shnu_mbcr_no2: f[37].to_string(),
+            shnu_mbcr_no3: f[38].to_string(),
+            shnu_mbcr_no4: f[39].to_string(),
+            shnu_mbcr_no5: f[40].to_string(),
+            seln_mbcr_rlim1: f[41].to_string(),
+            seln_mbcr_rlim2: f[42].to_string(),
+            seln_mbcr_rlim3: f[43].to_string(),
+            seln_mbcr_rlim4: f[44].to_string(),
+            seln_mbcr_rlim5: f[45].to_string(),
+            shnu_mbcr_rlim1: f[46].to_string(),
+            shnu_mbcr_rlim2: f[47].to_string(),
+            shnu_mbcr_rlim3: f[48].to_string(),
+            shnu_mbcr_rlim4: f[49].to_string(),
+            shnu_mbcr_rlim5: f[50].to_string(),
+            seln_qty_icdc1: f[51].to_string(),
+            seln_qty_icdc2: f[52].to_string(),
+            seln_qty_icdc3: f[53].to_string(),
+            seln_qty_icdc4: f[54].to_string(),
+            seln_qty_icdc5: f[55].to_string(),
+            shnu_qty_icdc1: f[56].to_string(),
+            shnu_qty_icdc2: f[57].to_string(),
+            shnu_qty_icdc3: f[58].to_string(),
+            shnu_qty_icdc4: f[59].to_string(),
+            shnu_qty_icdc5: f[60].to_string(),
+            glob_total_seln_qty: f[61].to_string(),
+            glob_total_shnu_qty: f[62].to_string(),
+            glob_total_seln_qty_icdc: f[63].to_string(),
+            glob_total_shnu_qty_icdc: f[64].to_string(),
+            glob_ntby_qty: f[65].to_string(),
+            glob_seln_rlim: f[66].to_string(),
+            glob_shnu_rlim: f[67].to_string(),
+            seln2_mbcr_eng_name1: f[68].to_string(),
+            seln2_mbcr_eng_name2: f[69].to_string(),
+            seln2_mbcr_eng_name3: f[70].to_string(),
+            seln2_mbcr_eng_name4: f[71].to_string(),
+            seln2_mbcr_eng_name5: f[72].to_string(),
+            byov_mbcr_eng_name1: f[73].to_string(),
+            byov_mbcr_eng_name2: f[74].to_string(),
+            byov_mbcr_eng_name3: f[75].to_string(),
+            byov_mbcr_eng_name4: f[76].to_string(),
+            byov_mbcr_eng_name5: f[77].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (KRX) [실시간-048]] `H0STPGM0` 레코드 (11개 필드)
+impl RealtimeRecord for TryitoutH0stpgm0Response {
+    const TR_ID: &'static str = "H0STPGM0";
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            seln_cnqn: f[2].to_string(),
+            seln_tr_pbmn: f[3].to_string(),
+            shnu_cnqn: f[4].to_string(),
+            shnu_tr_pbmn: f[5].to_string(),
+            ntby_cnqn: f[6].to_string(),
+            ntby_tr_pbmn: f[7].to_string(),
+            seln_rsqn: f[8].to_string(),
+            shnu_rsqn: f[9].to_string(),
+            whol_ntby_qty: f[10].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 장운영정보 (KRX) [실시간-049]] `H0STMKO0` 레코드 (11개 필드)
+impl RealtimeRecord for TryitoutH0stmko0Response {
+    const TR_ID: &'static str = "H0STMKO0";
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            trht_yn: f[1].to_string(),
+            tr_susp_reas_cntt: f[2].to_string(),
+            mkop_cls_code: f[3].to_string(),
+            antc_mkop_cls_code: f[4].to_string(),
+            mrkt_trtm_cls_code: f[5].to_string(),
+            divi_app_cls_code: f[6].to_string(),
+            iscd_stat_cls_code: f[7].to_string(),
+            vi_cls_code: f[8].to_string(),
+            ovtm_vi_cls_code: f[9].to_string(),
+            exch_cls_code: f[10].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 시간외 실시간호가 (KRX) [실시간-025]] `H0STOAA0` 레코드 (54개 필드)
+impl RealtimeRecord for TryitoutH0stoaa0Response {
+    const TR_ID: &'static str = "H0STOAA0";
+    const FIELD_COUNT: usize = 54;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            hour_cls_code: f[2].to_string(),
+            askp1: f[3].to_string(),
+            askp2: f[4].to_string(),
+            askp3: f[5].to_string(),
+            askp4: f[6].to_string(),
+            askp5: f[7].to_string(),
+            askp6: f[8].to_string(),
+            askp7: f[9].to_string(),
+            askp8: f[10].to_string(),
+            askp9: f[11].to_string(),
+            bidp1: f[12].to_string(),
+            bidp2: f[13].to_string(),
+            bidp3: f[14].to_string(),
+            bidp4: f[15].to_string(),
+            bidp5: f[16].to_string(),
+            bidp6: f[17].to_string(),
+            bidp7: f[18].to_string(),
+            bidp8: f[19].to_string(),
+            bidp9: f[20].to_string(),
+            askp_rsqn1: f[21].to_string(),
+            askp_rsqn2: f[22].to_string(),
+            askp_rsqn3: f[23].to_string(),
+            askp_rsqn4: f[24].to_string(),
+            askp_rsqn5: f[25].to_string(),
+            askp_rsqn6: f[26].to_string(),
+            askp_rsqn7: f[27].to_string(),
+            askp_rsqn8: f[28].to_string(),
+            askp_rsqn9: f[29].to_string(),
+            bidp_rsqn1: f[30].to_string(),
+            bidp_rsqn2: f[31].to_string(),
+            bidp_rsqn3: f[32].to_string(),
+            bidp_rsqn4: f[33].to_string(),
+            bidp_rsqn5: f[34].to_string(),
+            bidp_rsqn6: f[35].to_string(),
+            bidp_rsqn7: f[36].to_string(),
+            bidp_rsqn8: f[37].to_string(),
+            bidp_rsqn9: f[38].to_string(),
+            total_askp_rsqn: f[39].to_string(),
+            total_bidp_rsqn: f[40].to_string(),
+            ovtm_total_askp_rsqn: f[41].to_string(),
+            ovtm_total_bidp_rsqn: f[42].to_string(),
+            antc_cnpr: f[43].to_string(),
+            antc_cnqn: f[44].to_string(),
+            antc_vol: f[45].to_string(),
+            antc_cntg_vrss: f[46].to_string(),
+            antc_cntg_vrss_sign: f[47].to_string(),
+            antc_cntg_prdy_ctrt: f[48].to_string(),
+            acml_vol: f[49].to_string(),
+            total_askp_rsqn_icdc: f[50].to_string(),
+            total_bidp_rsqn_icdc: f[51].to_string(),
+            ovtm_total_askp_icdc: f[52].to_string(),
+            ovtm_total_bidp_icdc: f[53].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 시간외 실시간체결가 (KRX) [실시간-042]] `H0STOUP0` 레코드 (43개 필드)
+impl RealtimeRecord for TryitoutH0stoup0Response {
+    const TR_ID: &'static str = "H0STOUP0";
+    const FIELD_COUNT: usize = 43;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 시간외 실시간예상체결 (KRX) [실시간-024]] `H0STOAC0` 레코드 (43개 필드)
+impl RealtimeRecord for TryitoutH0stoac0Response {
+    const TR_ID: &'static str = "H0STOAC0";
+    const FIELD_COUNT: usize = 43;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내지수 실시간체결 [실시간-026]] `H0UPCNT0` 레코드 (30개 필드)
+impl RealtimeRecord for TryitoutH0upcnt0Response {
+    const TR_ID: &'static str = "H0UPCNT0";
+    const FIELD_COUNT: usize = 30;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            bstp_cls_code: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            prpr_nmix: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            bstp_nmix_prdy_vrss: f[4].to_string(),
+            acml_vol: f[5].to_string(),
+            acml_tr_pbmn: f[6].to_string(),
+            pcas_vol: f[7].to_string(),
+            pcas_tr_pbmn: f[8].to_string(),
+            prdy_ctrt: f[9].to_string(),
+            oprc_nmix: f[10].to_string(),
+            nmix_hgpr: f[11].to_string(),
+            nmix_lwpr: f[12].to_string(),
+            oprc_vrss_nmix_prpr: f[13].to_string(),
+            oprc_vrss_nmix_sign: f[14].to_string(),
+            hgpr_vrss_nmix_prpr: f[15].to_string(),
+            hgpr_vrss_nmix_sign: f[16].to_string(),
+            lwpr_vrss_nmix_prpr: f[17].to_string(),
+            lwpr_vrss_nmix_sign: f[18].to_string(),
+            prdy_clpr_vrss_oprc_rate: f[19].to_string(),
+            prdy_clpr_vrss_hgpr_rate: f[20].to_string(),
+            prdy_clpr_vrss_lwpr_rate: f[21].to_string(),
+            uplm_issu_cnt: f[22].to_string(),
+            ascn_issu_cnt: f[23].to_string(),
+            stnr_issu_cnt: f[24].to_string(),
+            down_issu_cnt: f[25].to_string(),
+            lslm_issu_cnt: f[26].to_string(),
+            qtqt_ascn_issu_cnt: f[27].to_string(),
+            qtqt_down_issu_cnt: f[28].to_string(),
+            tick_vrss: f[29].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내지수 실시간예상체결 [실시간-027]] `H0UPANC0` 레코드 (30개 필드)
+impl RealtimeRecord for TryitoutH0upanc0Response {
+    const TR_ID: &'static str = "H0UPANC0";
+    const FIELD_COUNT: usize = 30;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            bstp_cls_code: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            prpr_nmix: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            bstp_nmix_prdy_vrss: f[4].to_string(),
+            acml_vol: f[5].to_string(),
+            acml_tr_pbmn: f[6].to_string(),
+            pcas_vol: f[7].to_string(),
+            pcas_tr_pbmn: f[8].to_string(),
+            prdy_ctrt: f[9].to_string(),
+            oprc_nmix: f[10].to_string(),
+            nmix_hgpr: f[11].to_string(),
+            nmix_lwpr: f[12].to_string(),
+            oprc_vrss_nmix_prpr: f[13].to_string(),
+            oprc_vrss_nmix_sign: f[14].to_string(),
+            hgpr_vrss_nmix_prpr: f[15].to_string(),
+            hgpr_vrss_nmix_sign: f[16].to_string(),
+            lwpr_vrss_nmix_prpr: f[17].to_string(),
+            lwpr_vrss_nmix_sign: f[18].to_string(),
+            prdy_clpr_vrss_oprc_rate: f[19].to_string(),
+            prdy_clpr_vrss_hgpr_rate: f[20].to_string(),
+            prdy_clpr_vrss_lwpr_rate: f[21].to_string(),
+            uplm_issu_cnt: f[22].to_string(),
+            ascn_issu_cnt: f[23].to_string(),
+            stnr_issu_cnt: f[24].to_string(),
+            down_issu_cnt: f[25].to_string(),
+            lslm_issu_cnt: f[26].to_string(),
+            qtqt_ascn_issu_cnt: f[27].to_string(),
+            qtqt_down_issu_cnt: f[28].to_string(),
+            tick_vrss: f[29].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내지수 실시간프로그램매매 [실시간-028]] `H0UPPGM0` 레코드 (88개 필드)
+impl RealtimeRecord for TryitoutH0uppgm0Response {
+    const TR_ID: &'static str = "H0UPPGM0";
+    const FIELD_COUNT: usize = 88;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            bstp_cls_code: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            arbt_seln_entm_cnqn: f[2].to_string(),
+            arbt_seln_onsl_cnqn: f[3].to_string(),
+            arbt_shnu_entm_cnqn: f[4].to_string(),
+            arbt_shnu_onsl_cnqn: f[5].to_string(),
+            nabt_seln_entm_cnqn: f[6].to_string(),
+            nabt_seln_onsl_cnqn: f[7].to_string(),
+            nabt_shnu_entm_cnqn: f[8].to_string(),
+            nabt_shnu_onsl_cnqn: f[9].to_string(),
+            arbt_seln_entm_cntg_amt: f[10].to_string(),
+            arbt_seln_onsl_cntg_amt: f[11].to_string(),
+            arbt_shnu_entm_cntg_amt: f[12].to_string(),
+            arbt_shnu_onsl_cntg_amt: f[13].to_string(),
+            nabt_seln_entm_cntg_amt: f[14].to_string(),
+            nabt_seln_onsl_cntg_amt: f[15].to_string(),
+            nabt_shnu_entm_cntg_amt: f[16].to_string(),
+            nabt_shnu_onsl_cntg_amt: f[17].to_string(),
+            arbt_smtn_seln_vol: f[18].to_string(),
+            arbt_smtm_seln_vol_rate: f[19].to_string(),
+            arbt_smtn_seln_tr_pbmn: f[20].to_string(),
+            arbt_smtm_seln_tr_pbmn_rate: f[21].to_string(),
+            arbt_smtn_shnu_vol: f[22].to_string(),
+            arbt_smtm_shnu_vol_rate: f[23].to_string(),
+            arbt_smtn_shnu_tr_pbmn: f[24].to_string(),
+            arbt_smtm_shnu_tr_pbmn_rate: f[25].to_string(),
+            arbt_smtn_ntby_qty: f[26].to_string(),
+            arbt_smtm_ntby_qty_rate: f[27].to_string(),
+            arbt_smtn_ntby_tr_pbmn: f[28].to_string(),
+            arbt_smtm_ntby_tr_pbmn_rate: f[29].to_string(),
+            nabt_smtn_seln_vol: f[30].to_string(),
+            nabt_smtm_seln_vol_rate: f[31].to_string(),
+            nabt_smtn_seln_tr_pbmn: f[32].to_string(),
+            nabt_smtm_seln_tr_pbmn_rate: f[33].to_string(),
+            nabt_smtn_shnu_vol: f[34].to_string(),
+            nabt_smtm_shnu_vol_rate: f[35].to_string(),
+            nabt_smtn_shnu_tr_pbmn: f[36].to_string(),
+            nabt_smtm_shnu_tr_pbmn_rate: f[37].to_string(),
+            nabt_smtn_ntby_qty: f[38].to_string(),
+            nabt_smtm_ntby_qty_rate: f[39].to_string(),
+            nabt_smtn_ntby_tr_pbmn: f[40].to_string(),
+            nabt_smtm_ntby_tr_pbmn_rate: f[41].to_string(),
+            whol_entm_seln_vol: f[42].to_string(),
+            entm_seln_vol_rate: f[43].to_string(),
+            whol_entm_seln_tr_pbmn: f[44].to_string(),
+            entm_seln_tr_pbmn_rate: f[45].to_string(),
+            whol_entm_shnu_vol: f[46].to_string(),
+            entm_shnu_vol_rate: f[47].to_string(),
+            whol_entm_shnu_tr_pbmn: f[48].to_string(),
+            entm_shnu_tr_pbmn_rate: f[49].to_string(),
+            whol_entm_ntby_qt: f[50].to_string(),
+            entm_ntby_qty_rat: f[51].to_string(),
+            whol_entm_ntby_tr_pbmn: f[52].to_string(),
+            entm_ntby_tr_pbmn_rate: f[53].to_string(),
+            whol_onsl_seln_vol: f[54].to_string(),
+            onsl_seln_vol_rate: f[55].to_string(),
+            whol_onsl_seln_tr_pbmn: f[56].to_string(),
+            onsl_seln_tr_pbmn_rate: f[57].to_string(),
+            whol_onsl_shnu_vol: f[58].to_string(),
+            onsl_shnu_vol_rate: f[59].to_string(),
+            whol_onsl_shnu_tr_pbmn: f[60].to_string(),
+            onsl_shnu_tr_pbmn_rate: f[61].to_string(),
+            whol_onsl_ntby_qty: f[62].to_string(),
+            onsl_ntby_qty_rate: f[63].to_string(),
+            whol_onsl_ntby_tr_pbmn: f[64].to_string(),
+            onsl_ntby_tr_pbmn_rate: f[65].to_string(),
+            total_seln_qty: f[66].to_string(),
+            whol_seln_vol_rate: f[67].to_string(),
+            total_seln_tr_pbmn: f[68].to_string(),
+            whol_seln_tr_pbmn_rate: f[69].to_string(),
+            shnu_cntg_smtn: f[70].to_string(),
+            whol_shun_vol_rate: f[71].to_string(),
+            total_shnu_tr_pbmn: f[72].to_string(),
+            whol_shun_tr_pbmn_rate: f[73].to_string(),
+            whol_ntby_qty: f[74].to_string(),
+            whol_smtm_ntby_qty_rate: f[75].to_string(),
+            whol_ntby_tr_pbmn: f[76].to_string(),
+            whol_ntby_tr_pbmn_rate: f[77].to_string(),
+            arbt_entm_ntby_qty: f[78].to_string(),
+            arbt_entm_ntby_tr_pbmn: f[79].to_string(),
+            arbt_onsl_ntby_qty: f[80].to_string(),
+            arbt_onsl_ntby_tr_pbmn: f[81].to_string(),
+            nabt_entm_ntby_qty: f[82].to_string(),
+            nabt_entm_ntby_tr_pbmn: f[83].to_string(),
+            nabt_onsl_ntby_qty: f[84].to_string(),
+            nabt_onsl_ntby_tr_pbmn: f[85].to_string(),
+            acml_vol: f[86].to_string(),
+            acml_tr_pbmn: f[87].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [ELW 실시간호가 [실시간-062]] `H0EWASP0` 레코드 (73개 필드)
+impl RealtimeRecord for TryitoutH0ewasp0Response {
+    const TR_ID: &'static str = "H0EWASP0";
+    const FIELD_COUNT: usize = 73;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            hour_cls_code: f[2].to_string(),
+            askp1: f[3].to_string(),
+            askp2: f[4].to_string(),
+            askp3: f[5].to_string(),
+            askp4: f[6].to_string(),
+            askp5: f[7].to_string(),
+            askp6: f[8].to_string(),
+            askp7: f[9].to_string(),
+            askp8: f[10].to_string(),
+            askp9: f[11].to_string(),
+            askp10: f[12].to_string(),
+            bidp1: f[13].to_string(),
+            bidp2: f[14].to_string(),
+            bidp3: f[15].to_string(),
+            bidp4: f[16].to_string(),
+            bidp5: f[17].to_string(),
+            bidp6: f[18].to_string(),
+            bidp7: f[19].to_string(),
+            bidp8: f[20].to_string(),
+            bidp9: f[21].to_string(),
+            bidp10: f[22].to_string(),
+            askp_rsqn1: f[23].to_string(),
+            askp_rsqn2: f[24].to_string(),
+            askp_rsqn3: f[25].to_string(),
+            askp_rsqn4: f[26].to_string(),
+            askp_rsqn5: f[27].to_string(),
+            askp_rsqn6: f[28].to_string(),
+            askp_rsqn7: f[29].to_string(),
+            askp_rsqn8: f[30].to_string(),
+            askp_rsqn9: f[31].to_string(),
+            askp_rsqn10: f[32].to_string(),
+            bidp_rsqn1: f[33].to_string(),
+            bidp_rsqn2: f[34].to_string(),
+            bidp_rsqn3: f[35].to_string(),
+            bidp_rsqn4: f[36].to_string(),
+            bidp_rsqn5: f[37].to_string(),
+            bidp_rsqn6: f[38].to_string(),
+            bidp_rsqn7: f[39].to_string(),
+            bidp_rsqn8: f[40].to_string(),
+            bidp_rsqn9: f[41].to_string(),
+            bidp_rsqn10: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            antc_cnpr: f[45].to_string(),
+            antc_cnqn: f[46].to_string(),
+            antc_cntg_vrss_sign: f[47].to_string(),
+            antc_cntg_vrss: f[48].to_string(),
+            antc_cntg_prdy_ctrt: f[49].to_string(),
+            lp_askp_rsqn1: f[50].to_string(),
+            lp_askp_rsqn2: f[51].to_string(),
+            lp_askp_rsqn3: f[52].to_string(),
+            lp_bidp_rsqn4: f[53].to_string(),
+            lp_askp_rsqn4: f[54].to_string(),
+            lp_bidp_rsqn5: f[55].to_string(),
+            lp_askp_rsqn5: f[56].to_string(),
+            lp_bidp_rsqn6: f[57].to_string(),
+            lp_askp_rsqn6: f[58].to_string(),
+            lp_bidp_rsqn7: f[59].to_string(),
+            lp_askp_rsqn7: f[60].to_string(),
+            lp_askp_rsqn8: f[61].to_string(),
+            lp_bidp_rsqn8: f[62].to_string(),
+            lp_askp_rsqn9: f[63].to_string(),
+            lp_bidp_rsqn9: f[64].to_string(),
+            lp_askp_rsqn10: f[65].to_string(),
+            lp_bidp_rsqn10: f[66].to_string(),
+            lp_bidp_rsqn1: f[67].to_string(),
+            lp_total_askp_rsqn: f[68].to_string(),
+            lp_bidp_rsqn2: f[69].to_string(),
+            lp_total_bidp_rsqn: f[70].to_string(),
+            lp_bidp_rsqn3: f[71].to_string(),
+            antc_vol: f[72].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [ELW 실시간체결가 [실시간-061]] `H0EWCNT0` 레코드 (63개 필드)
+impl RealtimeRecord for TryitoutH0ewcnt0Response {
+    const TR_ID: &'static str = "H0EWCNT0";
+    const FIELD_COUNT: usize = 63;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            tmvl_val: f[40].to_string(),
+            prit: f[41].to_string(),
+            prmm_val: f[42].to_string(),
+            gear: f[43].to_string(),
+            prls_qryr_rate: f[44].to_string(),
+            invl_val: f[45].to_string(),
+            prmm_rate: f[46].to_string(),
+            cfp: f[47].to_string(),
+            lvrg_val: f[48].to_string(),
+            delta: f[49].to_string(),
+            gama: f[50].to_string(),
+            vega: f[51].to_string(),
+            theta: f[52].to_string(),
+            rho: f[53].to_string(),
+            hts_ints_vltl: f[54].to_string(),
+            hts_thpr: f[55].to_string(),
+            vol_tnrt: f[56].to_string(),
+            prdy_smns_hour_acml_vol: f[57].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[58].to_string(),
+            apprch_rate: f[59].to_string(),
+            lp_hvol: f[60].to_string(),
+            lp_hldn_rate: f[61].to_string(),
+            lp_ntby_qty: f[62].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [ELW 실시간예상체결 [실시간-063]] `H0EWANC0` 레코드 (59개 필드)
+impl RealtimeRecord for TryitoutH0ewanc0Response {
+    const TR_ID: &'static str = "H0EWANC0";
+    const FIELD_COUNT: usize = 59;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            tmvl_val: f[40].to_string(),
+            prit: f[41].to_string(),
+            prmm_val: f[42].to_string(),
+            gear: f[43].to_string(),
+            prls_qryr_rate: f[44].to_string(),
+            invl_val: f[45].to_string(),
+            prmm_rate: f[46].to_string(),
+            cfp: f[47].to_string(),
+            lvrg_val: f[48].to_string(),
+            delta: f[49].to_string(),
+            gama: f[50].to_string(),
+            vega: f[51].to_string(),
+            theta: f[52].to_string(),
+            rho: f[53].to_string(),
+            hts_ints_vltl: f[54].to_string(),
+            hts_thpr: f[55].to_string(),
+            vol_tnrt: f[56].to_string(),
+            lp_hvol: f[57].to_string(),
+            lp_hldn_rate: f[58].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내ETF NAV추이 [실시간-051]] `H0STNAV0` 레코드 (8개 필드)
+impl RealtimeRecord for TryitoutH0stnav0Response {
+    const TR_ID: &'static str = "H0STNAV0";
+    const FIELD_COUNT: usize = 8;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            nav: f[1].to_string(),
+            nav_prdy_vrss_sign: f[2].to_string(),
+            nav_prdy_vrss: f[3].to_string(),
+            nav_prdy_ctrt: f[4].to_string(),
+            oprc_nav: f[5].to_string(),
+            hprc_nav: f[6].to_string(),
+            lprc_nav: f[7].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간체결가 (통합)] `H0UNCNT0` 레코드 (46개 필드)
+impl RealtimeRecord for TryitoutH0uncnt0Response {
+    const TR_ID: &'static str = "H0UNCNT0";
+    const FIELD_COUNT: usize = 46;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            vi_stnd_prc: f[45].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간호가 (통합)] `H0UNASP0` 레코드 (65개 필드)
+impl RealtimeRecord for TryitoutH0unasp0Response {
+    const TR_ID: &'static str = "H0UNASP0";
+    const FIELD_COUNT: usize = 65;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            hour_cls_code: f[2].to_string(),
+            askp1: f[3].to_string(),
+            askp2: f[4].to_string(),
+            askp3: f[5].to_string(),
+            askp4: f[6].to_string(),
+            askp5: f[7].to_string(),
+            askp6: f[8].to_string(),
+            askp7: f[9].to_string(),
+            askp8: f[10].to_string(),
+            askp9: f[11].to_string(),
+            askp10: f[12].to_string(),
+            bidp1: f[13].to_string(),
+            bidp2: f[14].to_string(),
+            bidp3: f[15].to_string(),
+            bidp4: f[16].to_string(),
+            bidp5: f[17].to_string(),
+            bidp6: f[18].to_string(),
+            bidp7: f[19].to_string(),
+            bidp8: f[20].to_string(),
+            bidp9: f[21].to_string(),
+            bidp10: f[22].to_string(),
+            askp_rsqn1: f[23].to_string(),
+            askp_rsqn2: f[24].to_string(),
+            askp_rsqn3: f[25].to_string(),
+            askp_rsqn4: f[26].to_string(),
+            askp_rsqn5: f[27].to_string(),
+            askp_rsqn6: f[28].to_string(),
+            askp_rsqn7: f[29].to_string(),
+            askp_rsqn8: f[30].to_string(),
+            askp_rsqn9: f[31].to_string(),
+            askp_rsqn10: f[32].to_string(),
+            bidp_rsqn1: f[33].to_string(),
+            bidp_rsqn2: f[34].to_string(),
+            bidp_rsqn3: f[35].to_string(),
+            bidp_rsqn4: f[36].to_string(),
+            bidp_rsqn5: f[37].to_string(),
+            bidp_rsqn6: f[38].to_string(),
+            bidp_rsqn7: f[39].to_string(),
+            bidp_rsqn8: f[40].to_string(),
+            bidp_rsqn9: f[41].to_string(),
+            bidp_rsqn10: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            ovtm_total_askp_rsqn: f[45].to_string(),
+            ovtm_total_bidp_rsqn: f[46].to_string(),
+            antc_cnpr: f[47].to_string(),
+            antc_cnqn: f[48].to_string(),
+            antc_vol: f[49].to_string(),
+            antc_cntg_vrss: f[50].to_string(),
+            antc_cntg_vrss_sign: f[51].to_string(),
+            antc_cntg_prdy_ctrt: f[52].to_string(),
+            acml_vol: f[53].to_string(),
+            total_askp_rsqn_icdc: f[54].to_string(),
+            total_bidp_rsqn_icdc: f[55].to_string(),
+            ovtm_total_askp_icdc: f[56].to_string(),
+            ovtm_total_bidp_icdc: f[57].to_string(),
+            stck_deal_cls_code: f[58].to_string(),
+            kmid_prc: f[59].to_string(),
+            kmid_total_rsqn: f[60].to_string(),
+            kmid_cls_code: f[61].to_string(),
+            nmid_prc: f[62].to_string(),
+            nmid_total_rsqn: f[63].to_string(),
+            nmid_cls_code: f[64].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간예상체결 (통합)] `H0UNANC0` 레코드 (46개 필드)
+impl RealtimeRecord for TryitoutH0unanc0Response {
+    const TR_ID: &'static str = "H0UNANC0";
+    const FIELD_COUNT: usize = 46;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            vi_stnd_prc: f[45].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간회원사 (통합)] `H0UNMBC0` 레코드 (78개 필드)
+impl RealtimeRecord for TryitoutH0unmbc0Response {
+    const TR_ID: &'static str = "H0UNMBC0";
+    const FIELD_COUNT: usize = 78;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            seln2_mbcr_name1: f[1].to_string(),
+            seln2_mbcr_name2: f[2].to_string(),
+            seln2_mbcr_name3: f[3].to_string(),
+            seln2_mbcr_name4: f[4].to_string(),
+            seln2_mbcr_name5: f[5].to_string(),
+            byov_mbcr_name1: f[6].to_string(),
+            byov_mbcr_name2: f[7].to_string(),
+            byov_mbcr_name3: f[8].to_string(),
+            byov_mbcr_name4: f[9].to_string(),
+            byov_mbcr_name5: f[10].to_string(),
+            total_seln_qty1: f[11].to_string(),
+            total_seln_qty2: f[12].to_string(),
+            total_seln_qty3: f[13].to_string(),
+            total_seln_qty4: f[14].to_string(),
+            total_seln_qty5: f[15].to_string(),
+            total_shnu_qty1: f[16].to_string(),
+            total_shnu_qty2: f[17].to_string(),
+            total_shnu_qty3: f[18].to_string(),
+            total_shnu_qty4: f[19].to_string(),
+            total_shnu_qty5: f[20].to_string(),
+            seln_mbcr_glob_yn_1: f[21].to_string(),
+            seln_mbcr_glob_yn_2: f[22].to_string(),
+            seln_mbcr_glob_yn_3: f[23].to_string(),
+            seln_mbcr_glob_yn_4: f[24].to_string(),
+            seln_mbcr_glob_yn_5: f[25].to_string(),
+            shnu_mbcr_glob_yn_1: f[26].to_string(),
+            shnu_mbcr_glob_yn_2: f[27].to_string(),
+            shnu_mbcr_glob_yn_3: f[28].to_string(),
+            shnu_mbcr_glob_yn_4: f[29].to_string(),
+            shnu_mbcr_glob_yn_5: f[30].to_string(),
+            seln_mbcr_no1: f[31].to_string(),
+            seln_mbcr_no2: f[32].to_string(),
+            seln_mbcr_no3: f[33].to_string(),
+            seln_mbcr_no4: f[34].to_string(),
+            seln_mbcr_no5: f[35].to_string(),
+            shnu_mbcr_no1: f[36].to_string(),
+            shnu_mbcr_no2: f[37].to_string(),
+            shnu_mbcr_no3: f[38].to_string(),
+            shnu_mbcr_no4: f[39].to_string(),
+            shnu_mbcr_no5: f[40].to_string(),
+            seln_mbcr_rlim1: f[41].to_string(),
+            seln_mbcr_rlim2: f[42].to_string(),
+            seln_mbcr_rlim3: f[43].to_string(),
+            seln_mbcr_rlim4: f[44].to_string(),
+            seln_mbcr_rlim5: f[45].to_string(),
+            shnu_mbcr_rlim1: f[46].to_string(),
+            shnu_mbcr_rlim2: f[47].to_string(),
+            shnu_mbcr_rlim3: f[48].to_string(),
+            shnu_mbcr_rlim4: f[49].to_string(),
+            shnu_mbcr_rlim5: f[50].to_string(),
+            seln_qty_icdc1: f[51].to_string(),
+            seln_qty_icdc2: f[52].to_string(),
+            seln_qty_icdc3: f[53].to_string(),
+            seln_qty_icdc4: f[54].to_string(),
+            seln_qty_icdc5: f[55].to_string(),
+            shnu_qty_icdc1: f[56].to_string(),
+            shnu_qty_icdc2: f[57].to_string(),
+            shnu_qty_icdc3: f[58].to_string(),
+            shnu_qty_icdc4: f[59].to_string(),
+            shnu_qty_icdc5: f[60].to_string(),
+            glob_total_seln_qty: f[61].to_string(),
+            glob_total_shnu_qty: f[62].to_string(),
+            glob_total_seln_qty_icdc: f[63].to_string(),
+            glob_total_shnu_qty_icdc: f[64].to_string(),
+            glob_ntby_qty: f[65].to_string(),
+            glob_seln_rlim: f[66].to_string(),
+            glob_shnu_rlim: f[67].to_string(),
+            seln2_mbcr_eng_name1: f[68].to_string(),
+            seln2_mbcr_eng_name2: f[69].to_string(),
+            seln2_mbcr_eng_name3: f[70].to_string(),
+            seln2_mbcr_eng_name4: f[71].to_string(),
+            seln2_mbcr_eng_name5: f[72].to_string(),
+            byov_mbcr_eng_name1: f[73].to_string(),
+            byov_mbcr_eng_name2: f[74].to_string(),
+            byov_mbcr_eng_name3: f[75].to_string(),
+            byov_mbcr_eng_name4: f[76].to_string(),
+            byov_mbcr_eng_name5: f[77].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (통합)] `H0UNPGM0` 레코드 (11개 필드)
+impl RealtimeRecord for TryitoutH0unpgm0Response {
+    const TR_ID: &'static str = "H0UNPGM0";
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            seln_cnqn: f[2].to_string(),
+            seln_tr_pbmn: f[3].to_string(),
+            shnu_cnqn: f[4].to_string(),
+            shnu_tr_pbmn: f[5].to_string(),
+            ntby_cnqn: f[6].to_string(),
+            ntby_tr_pbmn: f[7].to_string(),
+            seln_rsqn: f[8].to_string(),
+            shnu_rsqn: f[9].to_string(),
+            whol_ntby_qty: f[10].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 장운영정보 (통합)] `H0UNMKO0` 레코드 (10개 필드)
+impl RealtimeRecord for TryitoutH0unmko0Response {
+    const TR_ID: &'static str = "H0UNMKO0";
+    const FIELD_COUNT: usize = 10;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            trht_yn: f[0].to_string(),
+            tr_susp_reas_cntt: f[1].to_string(),
+            mkop_cls_code: f[2].to_string(),
+            antc_mkop_cls_code: f[3].to_string(),
+            mrkt_trtm_cls_code: f[4].to_string(),
+            divi_app_cls_code: f[5].to_string(),
+            iscd_stat_cls_code: f[6].to_string(),
+            vi_cls_code: f[7].to_string(),
+            ovtm_vi_cls_code: f[8].to_string(),
+            exch_cls_code: f[9].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간체결가 (NXT)] `H0NXCNT0` 레코드 (46개 필드)
+impl RealtimeRecord for TryitoutH0nxcnt0Response {
+    const TR_ID: &'static str = "H0NXCNT0";
+    const FIELD_COUNT: usize = 46;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            vi_stnd_prc: f[45].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간호가 (NXT)] `H0NXASP0` 레코드 (65개 필드)
+impl RealtimeRecord for TryitoutH0nxasp0Response {
+    const TR_ID: &'static str = "H0NXASP0";
+    const FIELD_COUNT: usize = 65;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            hour_cls_code: f[2].to_string(),
+            askp1: f[3].to_string(),
+            askp2: f[4].to_string(),
+            askp3: f[5].to_string(),
+            askp4: f[6].to_string(),
+            askp5: f[7].to_string(),
+            askp6: f[8].to_string(),
+            askp7: f[9].to_string(),
+            askp8: f[10].to_string(),
+            askp9: f[11].to_string(),
+            askp10: f[12].to_string(),
+            bidp1: f[13].to_string(),
+            bidp2: f[14].to_string(),
+            bidp3: f[15].to_string(),
+            bidp4: f[16].to_string(),
+            bidp5: f[17].to_string(),
+            bidp6: f[18].to_string(),
+            bidp7: f[19].to_string(),
+            bidp8: f[20].to_string(),
+            bidp9: f[21].to_string(),
+            bidp10: f[22].to_string(),
+            askp_rsqn1: f[23].to_string(),
+            askp_rsqn2: f[24].to_string(),
+            askp_rsqn3: f[25].to_string(),
+            askp_rsqn4: f[26].to_string(),
+            askp_rsqn5: f[27].to_string(),
+            askp_rsqn6: f[28].to_string(),
+            askp_rsqn7: f[29].to_string(),
+            askp_rsqn8: f[30].to_string(),
+            askp_rsqn9: f[31].to_string(),
+            askp_rsqn10: f[32].to_string(),
+            bidp_rsqn1: f[33].to_string(),
+            bidp_rsqn2: f[34].to_string(),
+            bidp_rsqn3: f[35].to_string(),
+            bidp_rsqn4: f[36].to_string(),
+            bidp_rsqn5: f[37].to_string(),
+            bidp_rsqn6: f[38].to_string(),
+            bidp_rsqn7: f[39].to_string(),
+            bidp_rsqn8: f[40].to_string(),
+            bidp_rsqn9: f[41].to_string(),
+            bidp_rsqn10: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            ovtm_total_askp_rsqn: f[45].to_string(),
+            ovtm_total_bidp_rsqn: f[46].to_string(),
+            antc_cnpr: f[47].to_string(),
+            antc_cnqn: f[48].to_string(),
+            antc_vol: f[49].to_string(),
+            antc_cntg_vrss: f[50].to_string(),
+            antc_cntg_vrss_sign: f[51].to_string(),
+            antc_cntg_prdy_ctrt: f[52].to_string(),
+            acml_vol: f[53].to_string(),
+            total_askp_rsqn_icdc: f[54].to_string(),
+            total_bidp_rsqn_icdc: f[55].to_string(),
+            ovtm_total_askp_icdc: f[56].to_string(),
+            ovtm_total_bidp_icdc: f[57].to_string(),
+            stck_deal_cls_code: f[58].to_string(),
+            kmid_prc: f[59].to_string(),
+            kmid_total_rsqn: f[60].to_string(),
+            kmid_cls_code: f[61].to_string(),
+            nmid_prc: f[62].to_string(),
+            nmid_total_rsqn: f[63].to_string(),
+            nmid_cls_code: f[64].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간예상체결 (NXT)] `H0NXANC0` 레코드 (46개 필드)
+impl RealtimeRecord for TryitoutH0nxanc0Response {
+    const TR_ID: &'static str = "H0NXANC0";
+    const FIELD_COUNT: usize = 46;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            wghn_avrg_stck_prc: f[6].to_string(),
+            stck_oprc: f[7].to_string(),
+            stck_hgpr: f[8].to_string(),
+            stck_lwpr: f[9].to_string(),
+            askp1: f[10].to_string(),
+            bidp1: f[11].to_string(),
+            cntg_vol: f[12].to_string(),
+            acml_vol: f[13].to_string(),
+            acml_tr_pbmn: f[14].to_string(),
+            seln_cntg_csnu: f[15].to_string(),
+            shnu_cntg_csnu: f[16].to_string(),
+            ntby_cntg_csnu: f[17].to_string(),
+            cttr: f[18].to_string(),
+            seln_cntg_smtn: f[19].to_string(),
+            shnu_cntg_smtn: f[20].to_string(),
+            cntg_cls_code: f[21].to_string(),
+            shnu_rate: f[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[23].to_string(),
+            oprc_hour: f[24].to_string(),
+            oprc_vrss_prpr_sign: f[25].to_string(),
+            oprc_vrss_prpr: f[26].to_string(),
+            hgpr_hour: f[27].to_string(),
+            hgpr_vrss_prpr_sign: f[28].to_string(),
+            hgpr_vrss_prpr: f[29].to_string(),
+            lwpr_hour: f[30].to_string(),
+            lwpr_vrss_prpr_sign: f[31].to_string(),
+            lwpr_vrss_prpr: f[32].to_string(),
+            bsop_date: f[33].to_string(),
+            new_mkop_cls_code: f[34].to_string(),
+            trht_yn: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            total_askp_rsqn: f[38].to_string(),
+            total_bidp_rsqn: f[39].to_string(),
+            vol_tnrt: f[40].to_string(),
+            prdy_smns_hour_acml_vol: f[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: f[42].to_string(),
+            hour_cls_code: f[43].to_string(),
+            mrkt_trtm_cls_code: f[44].to_string(),
+            vi_stnd_prc: f[45].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간회원사 (NXT)] `H0NXMBC0` 레코드 (78개 필드)
+impl RealtimeRecord for TryitoutH0nxmbc0Response {
+    const TR_ID: &'static str = "H0NXMBC0";
+    const FIELD_COUNT: usize = 78;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            seln2_mbcr_name1: f[1].to_string(),
+            seln2_mbcr_name2: f[2].to_string(),
+            seln2_mbcr_name3: f[3].to_string(),
+            seln2_mbcr_name4: f[4].to_string(),
+            seln2_mbcr_name5: f[5].to_string(),
+            byov_mbcr_name1: f[6].to_string(),
+            byov_mbcr_name2: f[7].to_string(),
+            byov_mbcr_name3: f[8].to_string(),
+            byov_mbcr_name4: f[9].to_string(),
+            byov_mbcr_name5: f[10].to_string(),
+            total_seln_qty1: f[11].to_string(),
+            total_seln_qty2: f[12].to_string(),
+            total_seln_qty3: f[13].to_string(),
+            total_seln_qty4: f[14].to_string(),
+            total_seln_qty5: f[15].to_string(),
+            total_shnu_qty1: f[16].to_string(),
+            total_shnu_qty2: f[17].to_string(),
+            total_shnu_qty3: f[18].to_string(),
+            total_shnu_qty4: f[19].to_string(),
+            total_shnu_qty5: f[20].to_string(),
+            seln_mbcr_glob_yn_1: f[21].to_string(),
+            seln_mbcr_glob_yn_2: f[22].to_string(),
+            seln_mbcr_glob_yn_3: f[23].to_string(),
+            seln_mbcr_glob_yn_4: f[24].to_string(),
+            seln_mbcr_glob_yn_5: f[25].to_string(),
+            shnu_mbcr_glob_yn_1: f[26].to_string(),
+            shnu_mbcr_glob_yn_2: f[27].to_string(),
+            shnu_mbcr_glob_yn_3: f[28].to_string(),
+            shnu_mbcr_glob_yn_4: f[29].to_string(),
+            shnu_mbcr_glob_yn_5: f[30].to_string(),
+            seln_mbcr_no1: f[31].to_string(),
+            seln_mbcr_no2: f[32].to_string(),
+            seln_mbcr_no3: f[33].to_string(),
+            seln_mbcr_no4: f[34].to_string(),
+            seln_mbcr_no5: f[35].to_string(),
+            shnu_mbcr_no1: f[36].to_string(),
+            shnu_mbcr_no2: f[37].to_string(),
+            shnu_mbcr_no3: f[38].to_string(),
+            shnu_mbcr_no4: f[39].to_string(),
+            shnu_mbcr_no5: f[40].to_string(),
+            seln_mbcr_rlim1: f[41].to_string(),
+            seln_mbcr_rlim2: f[42].to_string(),
+            seln_mbcr_rlim3: f[43].to_string(),
+            seln_mbcr_rlim4: f[44].to_string(),
+            seln_mbcr_rlim5: f[45].to_string(),
+            shnu_mbcr_rlim1: f[46].to_string(),
+            shnu_mbcr_rlim2: f[47].to_string(),
+            shnu_mbcr_rlim3: f[48].to_string(),
+            shnu_mbcr_rlim4: f[49].to_string(),
+            shnu_mbcr_rlim5: f[50].to_string(),
+            seln_qty_icdc1: f[51].to_string(),
+            seln_qty_icdc2: f[52].to_string(),
+            seln_qty_icdc3: f[53].to_string(),
+            seln_qty_icdc4: f[54].to_string(),
+            seln_qty_icdc5: f[55].to_string(),
+            shnu_qty_icdc1: f[56].to_string(),
+            shnu_qty_icdc2: f[57].to_string(),
+            shnu_qty_icdc3: f[58].to_string(),
+            shnu_qty_icdc4: f[59].to_string(),
+            shnu_qty_icdc5: f[60].to_string(),
+            glob_total_seln_qty: f[61].to_string(),
+            glob_total_shnu_qty: f[62].to_string(),
+            glob_total_seln_qty_icdc: f[63].to_string(),
+            glob_total_shnu_qty_icdc: f[64].to_string(),
+            glob_ntby_qty: f[65].to_string(),
+            glob_seln_rlim: f[66].to_string(),
+            glob_shnu_rlim: f[67].to_string(),
+            seln2_mbcr_eng_name1: f[68].to_string(),
+            seln2_mbcr_eng_name2: f[69].to_string(),
+            seln2_mbcr_eng_name3: f[70].to_string(),
+            seln2_mbcr_eng_name4: f[71].to_string(),
+            seln2_mbcr_eng_name5: f[72].to_string(),
+            byov_mbcr_eng_name1: f[73].to_string(),
+            byov_mbcr_eng_name2: f[74].to_string(),
+            byov_mbcr_eng_name3: f[75].to_string(),
+            byov_mbcr_eng_name4: f[76].to_string(),
+            byov_mbcr_eng_name5: f[77].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 실시간프로그램매매 (NXT)] `H0NXPGM0` 레코드 (11개 필드)
+impl RealtimeRecord for TryitoutH0nxpgm0Response {
+    const TR_ID: &'static str = "H0NXPGM0";
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            seln_cnqn: f[2].to_string(),
+            seln_tr_pbmn: f[3].to_string(),
+            shnu_cnqn: f[4].to_string(),
+            shnu_tr_pbmn: f[5].to_string(),
+            ntby_cnqn: f[6].to_string(),
+            ntby_tr_pbmn: f[7].to_string(),
+            seln_rsqn: f[8].to_string(),
+            shnu_rsqn: f[9].to_string(),
+            whol_ntby_qty: f[10].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [국내주식 장운영정보 (NXT)] `H0NXMKO0` 레코드 (11개 필드)
+impl RealtimeRecord for TryitoutH0nxmko0Response {
+    const TR_ID: &'static str = "H0NXMKO0";
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            mksc_shrn_iscd: f[0].to_string(),
+            trht_yn: f[1].to_string(),
+            tr_susp_reas_cntt: f[2].to_string(),
+            mkop_cls_code: f[3].to_string(),
+            antc_mkop_cls_code: f[4].to_string(),
+            mrkt_trtm_cls_code: f[5].to_string(),
+            divi_app_cls_code: f[6].to_string(),
+            iscd_stat_cls_code: f[7].to_string(),
+            vi_cls_code: f[8].to_string(),
+            ovtm_vi_cls_code: f[9].to_string(),
+            exch_cls_code: f[10].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [지수선물 실시간호가[실시간-011]] `H0IFASP0` 레코드 (38개 필드)
+impl RealtimeRecord for TryitoutH0ifasp0Response {
+    const TR_ID: &'static str = "H0IFASP0";
+    const FIELD_COUNT: usize = 38;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_askp1: f[2].to_string(),
+            futs_askp2: f[3].to_string(),
+            futs_askp3: f[4].to_string(),
+            futs_askp4: f[5].to_string(),
+            futs_askp5: f[6].to_string(),
+            futs_bidp1: f[7].to_string(),
+            futs_bidp2: f[8].to_string(),
+            futs_bidp3: f[9].to_string(),
+            futs_bidp4: f[10].to_string(),
+            futs_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [지수선물 실시간체결가[실시간-010]] `H0IFCNT0` 레코드 (50개 필드)
+impl RealtimeRecord for TryitoutH0ifcnt0Response {
+    const TR_ID: &'static str = "H0IFCNT0";
+    const FIELD_COUNT: usize = 50;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_prdy_vrss: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            futs_prdy_ctrt: f[4].to_string(),
+            futs_prpr: f[5].to_string(),
+            futs_oprc: f[6].to_string(),
+            futs_hgpr: f[7].to_string(),
+            futs_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            mrkt_basis: f[13].to_string(),
+            dprt: f[14].to_string(),
+            nmsc_fctn_stpl_prc: f[15].to_string(),
+            fmsc_fctn_stpl_prc: f[16].to_string(),
+            spead_prc: f[17].to_string(),
+            hts_otst_stpl_qty: f[18].to_string(),
+            otst_stpl_qty_icdc: f[19].to_string(),
+            oprc_hour: f[20].to_string(),
+            oprc_vrss_prpr_sign: f[21].to_string(),
+            oprc_vrss_nmix_prpr: f[22].to_string(),
+            hgpr_hour: f[23].to_string(),
+            hgpr_vrss_prpr_sign: f[24].to_string(),
+            hgpr_vrss_nmix_prpr: f[25].to_string(),
+            lwpr_hour: f[26].to_string(),
+            lwpr_vrss_prpr_sign: f[27].to_string(),
+            lwpr_vrss_nmix_prpr: f[28].to_string(),
+            shnu_rate: f[29].to_string(),
+            cttr: f[30].to_string(),
+            esdg: f[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[32].to_string(),
+            thpr_basis: f[33].to_string(),
+            futs_askp1: f[34].to_string(),
+            futs_bidp1: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            seln_cntg_csnu: f[38].to_string(),
+            shnu_cntg_csnu: f[39].to_string(),
+            ntby_cntg_csnu: f[40].to_string(),
+            seln_cntg_smtn: f[41].to_string(),
+            shnu_cntg_smtn: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[45].to_string(),
+            dscs_bltr_acml_qty: f[46].to_string(),
+            dynm_mxpr: f[47].to_string(),
+            dynm_llam: f[48].to_string(),
+            dynm_prc_limt_yn: f[49].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [지수옵션 실시간호가[실시간-015]] `H0IOASP0` 레코드 (38개 필드)
+impl RealtimeRecord for TryitoutH0ioasp0Response {
+    const TR_ID: &'static str = "H0IOASP0";
+    const FIELD_COUNT: usize = 38;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_askp1: f[2].to_string(),
+            optn_askp2: f[3].to_string(),
+            optn_askp3: f[4].to_string(),
+            optn_askp4: f[5].to_string(),
+            optn_askp5: f[6].to_string(),
+            optn_bidp1: f[7].to_string(),
+            optn_bidp2: f[8].to_string(),
+            optn_bidp3: f[9].to_string(),
+            optn_bidp4: f[10].to_string(),
+            optn_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [지수옵션  실시간체결가[실시간-014]] `H0IOCNT0` 레코드 (58개 필드)
+impl RealtimeRecord for TryitoutH0iocnt0Response {
+    const TR_ID: &'static str = "H0IOCNT0";
+    const FIELD_COUNT: usize = 58;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            optn_prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            optn_oprc: f[6].to_string(),
+            optn_hgpr: f[7].to_string(),
+            optn_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            hts_otst_stpl_qty: f[13].to_string(),
+            otst_stpl_qty_icdc: f[14].to_string(),
+            oprc_hour: f[15].to_string(),
+            oprc_vrss_prpr_sign: f[16].to_string(),
+            oprc_vrss_nmix_prpr: f[17].to_string(),
+            hgpr_hour: f[18].to_string(),
+            hgpr_vrss_prpr_sign: f[19].to_string(),
+            hgpr_vrss_nmix_prpr: f[20].to_string(),
+            lwpr_hour: f[21].to_string(),
+            lwpr_vrss_prpr_sign: f[22].to_string(),
+            lwpr_vrss_nmix_prpr: f[23].to_string(),
+            shnu_rate: f[24].to_string(),
+            prmm_val: f[25].to_string(),
+            invl_val: f[26].to_string(),
+            tmvl_val: f[27].to_string(),
+            delta: f[28].to_string(),
+            gama: f[29].to_string(),
+            vega: f[30].to_string(),
+            theta: f[31].to_string(),
+            rho: f[32].to_string(),
+            hts_ints_vltl: f[33].to_string(),
+            esdg: f[34].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[35].to_string(),
+            thpr_basis: f[36].to_string(),
+            unas_hist_vltl: f[37].to_string(),
+            cttr: f[38].to_string(),
+            dprt: f[39].to_string(),
+            mrkt_basis: f[40].to_string(),
+            optn_askp1: f[41].to_string(),
+            optn_bidp1: f[42].to_string(),
+            askp_rsqn1: f[43].to_string(),
+            bidp_rsqn1: f[44].to_string(),
+            seln_cntg_csnu: f[45].to_string(),
+            shnu_cntg_csnu: f[46].to_string(),
+            ntby_cntg_csnu: f[47].to_string(),
+            seln_cntg_smtn: f[48].to_string(),
+            shnu_cntg_smtn: f[49].to_string(),
+            total_askp_rsqn: f[50].to_string(),
+            total_bidp_rsqn: f[51].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[52].to_string(),
+            avrg_vltl: f[53].to_string(),
+            dscs_lrqn_vol: f[54].to_string(),
+            dynm_mxpr: f[55].to_string(),
+            dynm_llam: f[56].to_string(),
+            dynm_prc_limt_yn: f[57].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [선물옵션 실시간체결통보[실시간-012]] `H0IFCNI0` 레코드 (22개 필드)
+impl RealtimeRecord for TryitoutH0ifcni0Response {
+    const TR_ID: &'static str = "H0IFCNI0";
+    const FIELD_COUNT: usize = 22;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            cust_id: f[0].to_string(),
+            acnt_no: f[1].to_string(),
+            oder_no: f[2].to_string(),
+            ooder_no: f[3].to_string(),
+            seln_byov_cls: f[4].to_string(),
+            rctf_cls: f[5].to_string(),
+            oder_kind2: f[6].to_string(),
+            stck_shrn_iscd: f[7].to_string(),
+            cntg_qty: f[8].to_string(),
+            cntg_unpr: f[9].to_string(),
+            stck_cntg_hour: f[10].to_string(),
+            rfus_yn: f[11].to_string(),
+            cntg_yn: f[12].to_string(),
+            acpt_yn: f[13].to_string(),
+            brnc_no: f[14].to_string(),
+            oder_qty: f[15].to_string(),
+            acnt_name: f[16].to_string(),
+            cntg_isnm: f[17].to_string(),
+            oder_cond: f[18].to_string(),
+            ord_grp: f[19].to_string(),
+            ord_grpseq: f[20].to_string(),
+            order_prc: f[21].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [상품선물 실시간호가[실시간-023]] `H0CFASP0` 레코드 (38개 필드)
+impl RealtimeRecord for TryitoutH0cfasp0Response {
+    const TR_ID: &'static str = "H0CFASP0";
+    const FIELD_COUNT: usize = 38;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_askp1: f[2].to_string(),
+            futs_askp2: f[3].to_string(),
+            futs_askp3: f[4].to_string(),
+            futs_askp4: f[5].to_string(),
+            futs_askp5: f[6].to_string(),
+            futs_bidp1: f[7].to_string(),
+            futs_bidp2: f[8].to_string(),
+            futs_bidp3: f[9].to_string(),
+            futs_bidp4: f[10].to_string(),
+            futs_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [상품선물 실시간체결가[실시간-022]] `H0CFCNT0` 레코드 (50개 필드)
+impl RealtimeRecord for TryitoutH0cfcnt0Response {
+    const TR_ID: &'static str = "H0CFCNT0";
+    const FIELD_COUNT: usize = 50;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_prdy_vrss: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            futs_prdy_ctrt: f[4].to_string(),
+            futs_prpr: f[5].to_string(),
+            futs_oprc: f[6].to_string(),
+            futs_hgpr: f[7].to_string(),
+            futs_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            mrkt_basis: f[13].to_string(),
+            dprt: f[14].to_string(),
+            nmsc_fctn_stpl_prc: f[15].to_string(),
+            fmsc_fctn_stpl_prc: f[16].to_string(),
+            spead_prc: f[17].to_string(),
+            hts_otst_stpl_qty: f[18].to_string(),
+            otst_stpl_qty_icdc: f[19].to_string(),
+            oprc_hour: f[20].to_string(),
+            oprc_vrss_prpr_sign: f[21].to_string(),
+            oprc_vrss_nmix_prpr: f[22].to_string(),
+            hgpr_hour: f[23].to_string(),
+            hgpr_vrss_prpr_sign: f[24].to_string(),
+            hgpr_vrss_nmix_prpr: f[25].to_string(),
+            lwpr_hour: f[26].to_string(),
+            lwpr_vrss_prpr_sign: f[27].to_string(),
+            lwpr_vrss_nmix_prpr: f[28].to_string(),
+            shnu_rate: f[29].to_string(),
+            cttr: f[30].to_string(),
+            esdg: f[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[32].to_string(),
+            thpr_basis: f[33].to_string(),
+            futs_askp1: f[34].to_string(),
+            futs_bidp1: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            seln_cntg_csnu: f[38].to_string(),
+            shnu_cntg_csnu: f[39].to_string(),
+            ntby_cntg_csnu: f[40].to_string(),
+            seln_cntg_smtn: f[41].to_string(),
+            shnu_cntg_smtn: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[45].to_string(),
+            dscs_bltr_acml_qty: f[46].to_string(),
+            dynm_mxpr: f[47].to_string(),
+            dynm_llam: f[48].to_string(),
+            dynm_prc_limt_yn: f[49].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식선물 실시간호가 [실시간-030]] `H0ZFASP0` 레코드 (68개 필드)
+impl RealtimeRecord for TryitoutH0zfasp0Response {
+    const TR_ID: &'static str = "H0ZFASP0";
+    const FIELD_COUNT: usize = 68;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            askp1: f[2].to_string(),
+            askp2: f[3].to_string(),
+            askp3: f[4].to_string(),
+            askp4: f[5].to_string(),
+            askp5: f[6].to_string(),
+            askp6: f[7].to_string(),
+            askp7: f[8].to_string(),
+            askp8: f[9].to_string(),
+            askp9: f[10].to_string(),
+            askp10: f[11].to_string(),
+            bidp1: f[12].to_string(),
+            bidp2: f[13].to_string(),
+            bidp3: f[14].to_string(),
+            bidp4: f[15].to_string(),
+            bidp5: f[16].to_string(),
+            bidp6: f[17].to_string(),
+            bidp7: f[18].to_string(),
+            bidp8: f[19].to_string(),
+            bidp9: f[20].to_string(),
+            bidp10: f[21].to_string(),
+            askp_csnu1: f[22].to_string(),
+            askp_csnu2: f[23].to_string(),
+            askp_csnu3: f[24].to_string(),
+            askp_csnu4: f[25].to_string(),
+            askp_csnu5: f[26].to_string(),
+            askp_csnu6: f[27].to_string(),
+            askp_csnu7: f[28].to_string(),
+            askp_csnu8: f[29].to_string(),
+            askp_csnu9: f[30].to_string(),
+            askp_csnu10: f[31].to_string(),
+            bidp_csnu1: f[32].to_string(),
+            bidp_csnu2: f[33].to_string(),
+            bidp_csnu3: f[34].to_string(),
+            bidp_csnu4: f[35].to_string(),
+            bidp_csnu5: f[36].to_string(),
+            bidp_csnu6: f[37].to_string(),
+            bidp_csnu7: f[38].to_string(),
+            bidp_csnu8: f[39].to_string(),
+            bidp_csnu9: f[40].to_string(),
+            bidp_csnu10: f[41].to_string(),
+            askp_rsqn1: f[42].to_string(),
+            askp_rsqn2: f[43].to_string(),
+            askp_rsqn3: f[44].to_string(),
+            askp_rsqn4: f[45].to_string(),
+            askp_rsqn5: f[46].to_string(),
+            askp_rsqn6: f[47].to_string(),
+            askp_rsqn7: f[48].to_string(),
+            askp_rsqn8: f[49].to_string(),
+            askp_rsqn9: f[50].to_string(),
+            askp_rsqn10: f[51].to_string(),
+            bidp_rsqn1: f[52].to_string(),
+            bidp_rsqn2: f[53].to_string(),
+            bidp_rsqn3: f[54].to_string(),
+            bidp_rsqn4: f[55].to_string(),
+            bidp_rsqn5: f[56].to_string(),
+            bidp_rsqn6: f[57].to_string(),
+            bidp_rsqn7: f[58].to_string(),
+            bidp_rsqn8: f[59].to_string(),
+            bidp_rsqn9: f[60].to_string(),
+            bidp_rsqn10: f[61].to_string(),
+            total_askp_csnu: f[62].to_string(),
+            total_bidp_csnu: f[63].to_string(),
+            total_askp_rsqn: f[64].to_string(),
+            total_bidp_rsqn: f[65].to_string(),
+            total_askp_rsqn_icdc: f[66].to_string(),
+            total_bidp_rsqn_icdc: f[67].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식선물 실시간체결가 [실시간-029]] `H0ZFCNT0` 레코드 (49개 필드)
+impl RealtimeRecord for TryitoutH0zfcnt0Response {
+    const TR_ID: &'static str = "H0ZFCNT0";
+    const FIELD_COUNT: usize = 49;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            stck_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            futs_prdy_ctrt: f[5].to_string(),
+            stck_oprc: f[6].to_string(),
+            stck_hgpr: f[7].to_string(),
+            stck_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            mrkt_basis: f[13].to_string(),
+            dprt: f[14].to_string(),
+            nmsc_fctn_stpl_prc: f[15].to_string(),
+            fmsc_fctn_stpl_prc: f[16].to_string(),
+            spead_prc: f[17].to_string(),
+            hts_otst_stpl_qty: f[18].to_string(),
+            otst_stpl_qty_icdc: f[19].to_string(),
+            oprc_hour: f[20].to_string(),
+            oprc_vrss_prpr_sign: f[21].to_string(),
+            oprc_vrss_prpr: f[22].to_string(),
+            hgpr_hour: f[23].to_string(),
+            hgpr_vrss_prpr_sign: f[24].to_string(),
+            hgpr_vrss_prpr: f[25].to_string(),
+            lwpr_hour: f[26].to_string(),
+            lwpr_vrss_prpr_sign: f[27].to_string(),
+            lwpr_vrss_prpr: f[28].to_string(),
+            shnu_rate: f[29].to_string(),
+            cttr: f[30].to_string(),
+            esdg: f[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[32].to_string(),
+            thpr_basis: f[33].to_string(),
+            askp1: f[34].to_string(),
+            bidp1: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            seln_cntg_csnu: f[38].to_string(),
+            shnu_cntg_csnu: f[39].to_string(),
+            ntby_cntg_csnu: f[40].to_string(),
+            seln_cntg_smtn: f[41].to_string(),
+            shnu_cntg_smtn: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[45].to_string(),
+            dynm_mxpr: f[46].to_string(),
+            dynm_llam: f[47].to_string(),
+            dynm_prc_limt_yn: f[48].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식선물 실시간예상체결 [실시간-031]] `H0ZFANC0` 레코드 (8개 필드)
+impl RealtimeRecord for TryitoutH0zfanc0Response {
+    const TR_ID: &'static str = "H0ZFANC0";
+    const FIELD_COUNT: usize = 8;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            antc_cnpr: f[2].to_string(),
+            antc_cntg_vrss: f[3].to_string(),
+            antc_cntg_vrss_sign: f[4].to_string(),
+            antc_cntg_prdy_ctrt: f[5].to_string(),
+            antc_mkop_cls_code: f[6].to_string(),
+            antc_cnqn: f[7].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식옵션 실시간호가 [실시간-045]] `H0ZOASP0` 레코드 (68개 필드)
+impl RealtimeRecord for TryitoutH0zoasp0Response {
+    const TR_ID: &'static str = "H0ZOASP0";
+    const FIELD_COUNT: usize = 68;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_askp1: f[2].to_string(),
+            optn_askp2: f[3].to_string(),
+            optn_askp3: f[4].to_string(),
+            optn_askp4: f[5].to_string(),
+            optn_askp5: f[6].to_string(),
+            optn_bidp1: f[7].to_string(),
+            optn_bidp2: f[8].to_string(),
+            optn_bidp3: f[9].to_string(),
+            optn_bidp4: f[10].to_string(),
+            optn_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            optn_askp6: f[38].to_string(),
+            optn_askp7: f[39].to_string(),
+            optn_askp8: f[40].to_string(),
+            optn_askp9: f[41].to_string(),
+            optn_askp10: f[42].to_string(),
+            optn_bidp6: f[43].to_string(),
+            optn_bidp7: f[44].to_string(),
+            optn_bidp8: f[45].to_string(),
+            optn_bidp9: f[46].to_string(),
+            optn_bidp10: f[47].to_string(),
+            askp_csnu6: f[48].to_string(),
+            askp_csnu7: f[49].to_string(),
+            askp_csnu8: f[50].to_string(),
+            askp_csnu9: f[51].to_string(),
+            askp_csnu10: f[52].to_string(),
+            bidp_csnu6: f[53].to_string(),
+            bidp_csnu7: f[54].to_string(),
+            bidp_csnu8: f[55].to_string(),
+            bidp_csnu9: f[56].to_string(),
+            bidp_csnu10: f[57].to_string(),
+            askp_rsqn6: f[58].to_string(),
+            askp_rsqn7: f[59].to_string(),
+            askp_rsqn8: f[60].to_string(),
+            askp_rsqn9: f[61].to_string(),
+            askp_rsqn10: f[62].to_string(),
+            bidp_rsqn6: f[63].to_string(),
+            bidp_rsqn7: f[64].to_string(),
+            bidp_rsqn8: f[65].to_string(),
+            bidp_rsqn9: f[66].to_string(),
+            bidp_rsqn10: f[67].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식옵션 실시간체결가 [실시간-044]] `H0ZOCNT0` 레코드 (53개 필드)
+impl RealtimeRecord for TryitoutH0zocnt0Response {
+    const TR_ID: &'static str = "H0ZOCNT0";
+    const FIELD_COUNT: usize = 53;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            optn_prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            optn_oprc: f[6].to_string(),
+            optn_hgpr: f[7].to_string(),
+            optn_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            hts_otst_stpl_qty: f[13].to_string(),
+            otst_stpl_qty_icdc: f[14].to_string(),
+            oprc_hour: f[15].to_string(),
+            oprc_vrss_prpr_sign: f[16].to_string(),
+            oprc_vrss_nmix_prpr: f[17].to_string(),
+            hgpr_hour: f[18].to_string(),
+            hgpr_vrss_prpr_sign: f[19].to_string(),
+            hgpr_vrss_nmix_prpr: f[20].to_string(),
+            lwpr_hour: f[21].to_string(),
+            lwpr_vrss_prpr_sign: f[22].to_string(),
+            lwpr_vrss_nmix_prpr: f[23].to_string(),
+            shnu_rate: f[24].to_string(),
+            prmm_val: f[25].to_string(),
+            invl_val: f[26].to_string(),
+            tmvl_val: f[27].to_string(),
+            delta: f[28].to_string(),
+            gama: f[29].to_string(),
+            vega: f[30].to_string(),
+            theta: f[31].to_string(),
+            rho: f[32].to_string(),
+            hts_ints_vltl: f[33].to_string(),
+            esdg: f[34].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[35].to_string(),
+            thpr_basis: f[36].to_string(),
+            unas_hist_vltl: f[37].to_string(),
+            cttr: f[38].to_string(),
+            dprt: f[39].to_string(),
+            mrkt_basis: f[40].to_string(),
+            optn_askp1: f[41].to_string(),
+            optn_bidp1: f[42].to_string(),
+            askp_rsqn1: f[43].to_string(),
+            bidp_rsqn1: f[44].to_string(),
+            seln_cntg_csnu: f[45].to_string(),
+            shnu_cntg_csnu: f[46].to_string(),
+            ntby_cntg_csnu: f[47].to_string(),
+            seln_cntg_smtn: f[48].to_string(),
+            shnu_cntg_smtn: f[49].to_string(),
+            total_askp_rsqn: f[50].to_string(),
+            total_bidp_rsqn: f[51].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[52].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [주식옵션 실시간예상체결 [실시간-046]] `H0ZOANC0` 레코드 (7개 필드)
+impl RealtimeRecord for TryitoutH0zoanc0Response {
+    const TR_ID: &'static str = "H0ZOANC0";
+    const FIELD_COUNT: usize = 7;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            antc_cnpr: f[2].to_string(),
+            antc_cntg_vrss: f[3].to_string(),
+            antc_cntg_vrss_sign: f[4].to_string(),
+            antc_cntg_prdy_ctrt: f[5].to_string(),
+            antc_mkop_cls_code: f[6].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간옵션 실시간호가 [실시간-033]] `H0EUASP0` 레코드 (38개 필드)
+impl RealtimeRecord for TryitoutH0euasp0Response {
+    const TR_ID: &'static str = "H0EUASP0";
+    const FIELD_COUNT: usize = 38;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_askp1: f[2].to_string(),
+            optn_askp2: f[3].to_string(),
+            optn_askp3: f[4].to_string(),
+            optn_askp4: f[5].to_string(),
+            optn_askp5: f[6].to_string(),
+            optn_bidp1: f[7].to_string(),
+            optn_bidp2: f[8].to_string(),
+            optn_bidp3: f[9].to_string(),
+            optn_bidp4: f[10].to_string(),
+            optn_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간옵션 실시간체결가 [실시간-032]] `H0EUCNT0` 레코드 (56개 필드)
+impl RealtimeRecord for TryitoutH0eucnt0Response {
+    const TR_ID: &'static str = "H0EUCNT0";
+    const FIELD_COUNT: usize = 56;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            optn_prpr: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            optn_prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            optn_oprc: f[6].to_string(),
+            optn_hgpr: f[7].to_string(),
+            optn_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            hts_otst_stpl_qty: f[13].to_string(),
+            otst_stpl_qty_icdc: f[14].to_string(),
+            oprc_hour: f[15].to_string(),
+            oprc_vrss_prpr_sign: f[16].to_string(),
+            oprc_vrss_nmix_prpr: f[17].to_string(),
+            hgpr_hour: f[18].to_string(),
+            hgpr_vrss_prpr_sign: f[19].to_string(),
+            hgpr_vrss_nmix_prpr: f[20].to_string(),
+            lwpr_hour: f[21].to_string(),
+            lwpr_vrss_prpr_sign: f[22].to_string(),
+            lwpr_vrss_nmix_prpr: f[23].to_string(),
+            shnu_rate: f[24].to_string(),
+            prmm_val: f[25].to_string(),
+            invl_val: f[26].to_string(),
+            tmvl_val: f[27].to_string(),
+            delta: f[28].to_string(),
+            gama: f[29].to_string(),
+            vega: f[30].to_string(),
+            theta: f[31].to_string(),
+            rho: f[32].to_string(),
+            hts_ints_vltl: f[33].to_string(),
+            esdg: f[34].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[35].to_string(),
+            thpr_basis: f[36].to_string(),
+            unas_hist_vltl: f[37].to_string(),
+            cttr: f[38].to_string(),
+            dprt: f[39].to_string(),
+            mrkt_basis: f[40].to_string(),
+            optn_askp1: f[41].to_string(),
+            optn_bidp1: f[42].to_string(),
+            askp_rsqn1: f[43].to_string(),
+            bidp_rsqn1: f[44].to_string(),
+            seln_cntg_csnu: f[45].to_string(),
+            shnu_cntg_csnu: f[46].to_string(),
+            ntby_cntg_csnu: f[47].to_string(),
+            seln_cntg_smtn: f[48].to_string(),
+            shnu_cntg_smtn: f[49].to_string(),
+            total_askp_rsqn: f[50].to_string(),
+            total_bidp_rsqn: f[51].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[52].to_string(),
+            dynm_mxpr: f[53].to_string(),
+            dynm_prc_limt_yn: f[54].to_string(),
+            dynm_llam: f[55].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간옵션실시간예상체결 [실시간-034]] `H0EUANC0` 레코드 (8개 필드)
+impl RealtimeRecord for TryitoutH0euanc0Response {
+    const TR_ID: &'static str = "H0EUANC0";
+    const FIELD_COUNT: usize = 8;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            optn_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            antc_cnpr: f[2].to_string(),
+            antc_cntg_vrss: f[3].to_string(),
+            antc_cntg_vrss_sign: f[4].to_string(),
+            antc_cntg_prdy_ctrt: f[5].to_string(),
+            antc_mkop_cls_code: f[6].to_string(),
+            antc_cnqn: f[7].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간옵션실시간체결통보 [실시간-067]] `H0EUCNI0` 레코드 (19개 필드)
+impl RealtimeRecord for TryitoutH0eucni0Response {
+    const TR_ID: &'static str = "H0EUCNI0";
+    const FIELD_COUNT: usize = 19;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            cust_id: f[0].to_string(),
+            acnt_no: f[1].to_string(),
+            oder_no: f[2].to_string(),
+            ooder_no: f[3].to_string(),
+            seln_byov_cls: f[4].to_string(),
+            rctf_cls: f[5].to_string(),
+            oder_kind2: f[6].to_string(),
+            stck_shrn_iscd: f[7].to_string(),
+            cntg_qty: f[8].to_string(),
+            cntg_unpr: f[9].to_string(),
+            stck_cntg_hour: f[10].to_string(),
+            rfus_yn: f[11].to_string(),
+            cntg_yn: f[12].to_string(),
+            acpt_yn: f[13].to_string(),
+            brnc_no: f[14].to_string(),
+            oder_qty: f[15].to_string(),
+            acnt_name: f[16].to_string(),
+            cntg_isnm: f[17].to_string(),
+            oder_cond: f[18].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간선물 실시간호가 [실시간-065]] `H0MFASP0` 레코드 (38개 필드)
+impl RealtimeRecord for TryitoutH0mfasp0Response {
+    const TR_ID: &'static str = "H0MFASP0";
+    const FIELD_COUNT: usize = 38;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_askp1: f[2].to_string(),
+            futs_askp2: f[3].to_string(),
+            futs_askp3: f[4].to_string(),
+            futs_askp4: f[5].to_string(),
+            futs_askp5: f[6].to_string(),
+            futs_bidp1: f[7].to_string(),
+            futs_bidp2: f[8].to_string(),
+            futs_bidp3: f[9].to_string(),
+            futs_bidp4: f[10].to_string(),
+            futs_bidp5: f[11].to_string(),
+            askp_csnu1: f[12].to_string(),
+            askp_csnu2: f[13].to_string(),
+            askp_csnu3: f[14].to_string(),
+            askp_csnu4: f[15].to_string(),
+            askp_csnu5: f[16].to_string(),
+            bidp_csnu1: f[17].to_string(),
+            bidp_csnu2: f[18].to_string(),
+            bidp_csnu3: f[19].to_string(),
+            bidp_csnu4: f[20].to_string(),
+            bidp_csnu5: f[21].to_string(),
+            askp_rsqn1: f[22].to_string(),
+            askp_rsqn2: f[23].to_string(),
+            askp_rsqn3: f[24].to_string(),
+            askp_rsqn4: f[25].to_string(),
+            askp_rsqn5: f[26].to_string(),
+            bidp_rsqn1: f[27].to_string(),
+            bidp_rsqn2: f[28].to_string(),
+            bidp_rsqn3: f[29].to_string(),
+            bidp_rsqn4: f[30].to_string(),
+            bidp_rsqn5: f[31].to_string(),
+            total_askp_csnu: f[32].to_string(),
+            total_bidp_csnu: f[33].to_string(),
+            total_askp_rsqn: f[34].to_string(),
+            total_bidp_rsqn: f[35].to_string(),
+            total_askp_rsqn_icdc: f[36].to_string(),
+            total_bidp_rsqn_icdc: f[37].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간선물 실시간종목체결 [실시간-064]] `H0MFCNT0` 레코드 (49개 필드)
+impl RealtimeRecord for TryitoutH0mfcnt0Response {
+    const TR_ID: &'static str = "H0MFCNT0";
+    const FIELD_COUNT: usize = 49;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            futs_shrn_iscd: f[0].to_string(),
+            bsop_hour: f[1].to_string(),
+            futs_prdy_vrss: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            futs_prdy_ctrt: f[4].to_string(),
+            futs_prpr: f[5].to_string(),
+            futs_oprc: f[6].to_string(),
+            futs_hgpr: f[7].to_string(),
+            futs_lwpr: f[8].to_string(),
+            last_cnqn: f[9].to_string(),
+            acml_vol: f[10].to_string(),
+            acml_tr_pbmn: f[11].to_string(),
+            hts_thpr: f[12].to_string(),
+            mrkt_basis: f[13].to_string(),
+            dprt: f[14].to_string(),
+            nmsc_fctn_stpl_prc: f[15].to_string(),
+            fmsc_fctn_stpl_prc: f[16].to_string(),
+            spead_prc: f[17].to_string(),
+            hts_otst_stpl_qty: f[18].to_string(),
+            otst_stpl_qty_icdc: f[19].to_string(),
+            oprc_hour: f[20].to_string(),
+            oprc_vrss_prpr_sign: f[21].to_string(),
+            oprc_vrss_nmix_prpr: f[22].to_string(),
+            hgpr_hour: f[23].to_string(),
+            hgpr_vrss_prpr_sign: f[24].to_string(),
+            hgpr_vrss_nmix_prpr: f[25].to_string(),
+            lwpr_hour: f[26].to_string(),
+            lwpr_vrss_prpr_sign: f[27].to_string(),
+            lwpr_vrss_nmix_prpr: f[28].to_string(),
+            shnu_rate: f[29].to_string(),
+            cttr: f[30].to_string(),
+            esdg: f[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: f[32].to_string(),
+            thpr_basis: f[33].to_string(),
+            futs_askp1: f[34].to_string(),
+            futs_bidp1: f[35].to_string(),
+            askp_rsqn1: f[36].to_string(),
+            bidp_rsqn1: f[37].to_string(),
+            seln_cntg_csnu: f[38].to_string(),
+            shnu_cntg_csnu: f[39].to_string(),
+            ntby_cntg_csnu: f[40].to_string(),
+            seln_cntg_smtn: f[41].to_string(),
+            shnu_cntg_smtn: f[42].to_string(),
+            total_askp_rsqn: f[43].to_string(),
+            total_bidp_rsqn: f[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: f[45].to_string(),
+            dynm_mxpr: f[46].to_string(),
+            dynm_llam: f[47].to_string(),
+            dynm_prc_limt_yn: f[48].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [KRX야간선물 실시간체결통보 [실시간-066]] `H0MFCNI0` 레코드 (19개 필드)
+impl RealtimeRecord for TryitoutH0mfcni0Response {
+    const TR_ID: &'static str = "H0MFCNI0";
+    const FIELD_COUNT: usize = 19;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            cust_id: f[0].to_string(),
+            acnt_no: f[1].to_string(),
+            oder_no: f[2].to_string(),
+            ooder_no: f[3].to_string(),
+            seln_byov_cls: f[4].to_string(),
+            rctf_cls: f[5].to_string(),
+            oder_kind2: f[6].to_string(),
+            stck_shrn_iscd: f[7].to_string(),
+            cntg_qty: f[8].to_string(),
+            cntg_unpr: f[9].to_string(),
+            stck_cntg_hour: f[10].to_string(),
+            rfus_yn: f[11].to_string(),
+            cntg_yn: f[12].to_string(),
+            acpt_yn: f[13].to_string(),
+            brnc_no: f[14].to_string(),
+            oder_qty: f[15].to_string(),
+            acnt_name: f[16].to_string(),
+            cntg_isnm: f[17].to_string(),
+            oder_cond: f[18].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외주식 실시간호가[실시간-021]] `HDFSASP0` 레코드 (71개 필드)
+impl RealtimeRecord for TryitoutHdfsasp0Response {
+    const TR_ID: &'static str = "HDFSASP0";
+    const FIELD_COUNT: usize = 71;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            rsym: f[0].to_string(),
+            symb: f[1].to_string(),
+            zdiv: f[2].to_string(),
+            xymd: f[3].to_string(),
+            xhms: f[4].to_string(),
+            kymd: f[5].to_string(),
+            khms: f[6].to_string(),
+            bvol: f[7].to_string(),
+            avol: f[8].to_string(),
+            bdvl: f[9].to_string(),
+            advl: f[10].to_string(),
+            pbid1: f[11].to_string(),
+            pask1: f[12].to_string(),
+            vbid1: f[13].to_string(),
+            vask1: f[14].to_string(),
+            dbid1: f[15].to_string(),
+            dask1: f[16].to_string(),
+            pbid2: f[17].to_string(),
+            pask2: f[18].to_string(),
+            vbid2: f[19].to_string(),
+            vask2: f[20].to_string(),
+            dbid2: f[21].to_string(),
+            dask2: f[22].to_string(),
+            pbid3: f[23].to_string(),
+            pask3: f[24].to_string(),
+            vbid3: f[25].to_string(),
+            vask3: f[26].to_string(),
+            dbid3: f[27].to_string(),
+            dask3: f[28].to_string(),
+            pbid4: f[29].to_string(),
+            pask4: f[30].to_string(),
+            vbid4: f[31].to_string(),
+            vask4: f[32].to_string(),
+            dbid4: f[33].to_string(),
+            dask4: f[34].to_string(),
+            pbid5: f[35].to_string(),
+            pask5: f[36].to_string(),
+            vbid5: f[37].to_string(),
+            vask5: f[38].to_string(),
+            dbid5: f[39].to_string(),
+            dask5: f[40].to_string(),
+            pbid6: f[41].to_string(),
+            pask6: f[42].to_string(),
+            vbid6: f[43].to_string(),
+            vask6: f[44].to_string(),
+            dbid6: f[45].to_string(),
+            dask6: f[46].to_string(),
+            pbid7: f[47].to_string(),
+            pask7: f[48].to_string(),
+            vbid7: f[49].to_string(),
+            vask7: f[50].to_string(),
+            dbid7: f[51].to_string(),
+            dask7: f[52].to_string(),
+            pbid8: f[53].to_string(),
+            pask8: f[54].to_string(),
+            vbid8: f[55].to_string(),
+            vask8: f[56].to_string(),
+            dbid8: f[57].to_string(),
+            dask8: f[58].to_string(),
+            pbid9: f[59].to_string(),
+            pask9: f[60].to_string(),
+            vbid9: f[61].to_string(),
+            vask9: f[62].to_string(),
+            dbid9: f[63].to_string(),
+            dask9: f[64].to_string(),
+            pbid10: f[65].to_string(),
+            pask10: f[66].to_string(),
+            vbid10: f[67].to_string(),
+            vask10: f[68].to_string(),
+            dbid10: f[69].to_string(),
+            dask10: f[70].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외주식 지연호가(아시아)[실시간-008]] `HDFSASP1` 레코드 (17개 필드)
+impl RealtimeRecord for TryitoutHdfsasp1Response {
+    const TR_ID: &'static str = "HDFSASP1";
+    const FIELD_COUNT: usize = 17;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            rsym: f[0].to_string(),
+            symb: f[1].to_string(),
+            zdiv: f[2].to_string(),
+            xymd: f[3].to_string(),
+            xhms: f[4].to_string(),
+            kymd: f[5].to_string(),
+            khms: f[6].to_string(),
+            bvol: f[7].to_string(),
+            avol: f[8].to_string(),
+            bdvl: f[9].to_string(),
+            advl: f[10].to_string(),
+            pbid1: f[11].to_string(),
+            pask1: f[12].to_string(),
+            vbid1: f[13].to_string(),
+            vask1: f[14].to_string(),
+            dbid1: f[15].to_string(),
+            dask1: f[16].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외주식 실시간지연체결가[실시간-007]] `HDFSCNT0` 레코드 (26개 필드)
+impl RealtimeRecord for TryitoutHdfscnt0Response {
+    const TR_ID: &'static str = "HDFSCNT0";
+    const FIELD_COUNT: usize = 26;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            rsym: f[0].to_string(),
+            symb: f[1].to_string(),
+            zdiv: f[2].to_string(),
+            tymd: f[3].to_string(),
+            xymd: f[4].to_string(),
+            xhms: f[5].to_string(),
+            kymd: f[6].to_string(),
+            khms: f[7].to_string(),
+            open: f[8].to_string(),
+            high: f[9].to_string(),
+            low: f[10].to_string(),
+            last: f[11].to_string(),
+            sign: f[12].to_string(),
+            diff: f[13].to_string(),
+            rate: f[14].to_string(),
+            pbid: f[15].to_string(),
+            pask: f[16].to_string(),
+            vbid: f[17].to_string(),
+            vask: f[18].to_string(),
+            evol: f[19].to_string(),
+            tvol: f[20].to_string(),
+            tamt: f[21].to_string(),
+            bivl: f[22].to_string(),
+            asvl: f[23].to_string(),
+            strn: f[24].to_string(),
+            mtyp: f[25].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외주식 실시간체결통보[실시간-009]] `H0GSCNI0` 레코드 (25개 필드)
+impl RealtimeRecord for TryitoutH0gscni0Response {
+    const TR_ID: &'static str = "H0GSCNI0";
+    const FIELD_COUNT: usize = 25;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            cust_id: f[0].to_string(),
+            acnt_no: f[1].to_string(),
+            oder_no: f[2].to_string(),
+            ooder_no: f[3].to_string(),
+            seln_byov_cls: f[4].to_string(),
+            rctf_cls: f[5].to_string(),
+            oder_kind2: f[6].to_string(),
+            stck_shrn_iscd: f[7].to_string(),
+            cntg_qty: f[8].to_string(),
+            cntg_unpr: f[9].to_string(),
+            stck_cntg_hour: f[10].to_string(),
+            rfus_yn: f[11].to_string(),
+            cntg_yn: f[12].to_string(),
+            acpt_yn: f[13].to_string(),
+            brnc_no: f[14].to_string(),
+            oder_qty: f[15].to_string(),
+            acnt_name: f[16].to_string(),
+            cntg_isnm: f[17].to_string(),
+            oder_cond: f[18].to_string(),
+            debt_gb: f[19].to_string(),
+            debt_date: f[20].to_string(),
+            start_tm: f[21].to_string(),
+            end_tm: f[22].to_string(),
+            tm_div_tp: f[23].to_string(),
+            cntg_unpr12: f[24].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외선물옵션 실시간체결가[실시간-017]] `HDFFF020` 레코드 (25개 필드)
+impl RealtimeRecord for TryitoutHdfff020Response {
+    const TR_ID: &'static str = "HDFFF020";
+    const FIELD_COUNT: usize = 25;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            series_cd: f[0].to_string(),
+            bsns_date: f[1].to_string(),
+            mrkt_open_date: f[2].to_string(),
+            mrkt_open_time: f[3].to_string(),
+            mrkt_close_date: f[4].to_string(),
+            mrkt_close_time: f[5].to_string(),
+            prev_price: f[6].to_string(),
+            recv_date: f[7].to_string(),
+            recv_time: f[8].to_string(),
+            active_flag: f[9].to_string(),
+            last_price: f[10].to_string(),
+            last_qntt: f[11].to_string(),
+            prev_diff_price: f[12].to_string(),
+            prev_diff_rate: f[13].to_string(),
+            open_price: f[14].to_string(),
+            high_price: f[15].to_string(),
+            low_price: f[16].to_string(),
+            vol: f[17].to_string(),
+            prev_sign: f[18].to_string(),
+            quotsign: f[19].to_string(),
+            recv_time2: f[20].to_string(),
+            psttl_price: f[21].to_string(),
+            psttl_sign: f[22].to_string(),
+            psttl_diff_price: f[23].to_string(),
+            psttl_diff_rate: f[24].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외선물옵션 실시간호가[실시간-018]] `HDFFF010` 레코드 (35개 필드)
+impl RealtimeRecord for TryitoutHdfff010Response {
+    const TR_ID: &'static str = "HDFFF010";
+    const FIELD_COUNT: usize = 35;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            series_cd: f[0].to_string(),
+            recv_date: f[1].to_string(),
+            recv_time: f[2].to_string(),
+            prev_price: f[3].to_string(),
+            bid_qntt_1: f[4].to_string(),
+            bid_num_1: f[5].to_string(),
+            bid_price_1: f[6].to_string(),
+            ask_qntt_1: f[7].to_string(),
+            ask_num_1: f[8].to_string(),
+            ask_price_1: f[9].to_string(),
+            bid_qntt_2: f[10].to_string(),
+            bid_num_2: f[11].to_string(),
+            bid_price_2: f[12].to_string(),
+            ask_qntt_2: f[13].to_string(),
+            ask_num_2: f[14].to_string(),
+            ask_price_2: f[15].to_string(),
+            bid_qntt_3: f[16].to_string(),
+            bid_num_3: f[17].to_string(),
+            bid_price_3: f[18].to_string(),
+            ask_qntt_3: f[19].to_string(),
+            ask_num_3: f[20].to_string(),
+            ask_price_3: f[21].to_string(),
+            bid_qntt_4: f[22].to_string(),
+            bid_num_4: f[23].to_string(),
+            bid_price_4: f[24].to_string(),
+            ask_qntt_4: f[25].to_string(),
+            ask_num_4: f[26].to_string(),
+            ask_price_4: f[27].to_string(),
+            bid_qntt_5: f[28].to_string(),
+            bid_num_5: f[29].to_string(),
+            bid_price_5: f[30].to_string(),
+            ask_qntt_5: f[31].to_string(),
+            ask_num_5: f[32].to_string(),
+            ask_price_5: f[33].to_string(),
+            sttl_price: f[34].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외선물옵션 실시간주문내역통보[실시간-019]] `HDFFF1C0` 레코드 (33개 필드)
+impl RealtimeRecord for TryitoutHdfff1c0Response {
+    const TR_ID: &'static str = "HDFFF1C0";
+    const FIELD_COUNT: usize = 33;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            user_id: f[0].to_string(),
+            acct_no: f[1].to_string(),
+            ord_dt: f[2].to_string(),
+            odno: f[3].to_string(),
+            orgn_ord_dt: f[4].to_string(),
+            orgn_odno: f[5].to_string(),
+            series: f[6].to_string(),
+            rvse_cncl_dvsn_cd: f[7].to_string(),
+            sll_buy_dvsn_cd: f[8].to_string(),
+            cplx_ord_dvsn_cd: f[9].to_string(),
+            prce_tp: f[10].to_string(),
+            fm_excg_rcit_dvsn_cd: f[11].to_string(),
+            ord_qty: f[12].to_string(),
+            fm_lmt_pric: f[13].to_string(),
+            fm_stop_ord_pric: f[14].to_string(),
+            tot_ccld_qty: f[15].to_string(),
+            tot_ccld_uv: f[16].to_string(),
+            ord_remq: f[17].to_string(),
+            fm_ord_grp_dt: f[18].to_string(),
+            ord_grp_stno: f[19].to_string(),
+            ord_dtl_dtime: f[20].to_string(),
+            oprt_dtl_dtime: f[21].to_string(),
+            work_empl: f[22].to_string(),
+            crcy_cd: f[23].to_string(),
+            lqd_yn: f[24].to_string(),
+            lqd_lmt_pric: f[25].to_string(),
+            lqd_stop_pric: f[26].to_string(),
+            trd_cond: f[27].to_string(),
+            term_ord_vald_dtime: f[28].to_string(),
+            spec_tp: f[29].to_string(),
+            ecis_rsvn_ord_yn: f[30].to_string(),
+            fuop_item_dvsn_cd: f[31].to_string(),
+            auto_ord_dvsn_cd: f[32].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [해외선물옵션 실시간체결내역통보[실시간-020]] `HDFFF2C0` 레코드 (33개 필드)
+impl RealtimeRecord for TryitoutHdfff2c0Response {
+    const TR_ID: &'static str = "HDFFF2C0";
+    const FIELD_COUNT: usize = 33;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            user_id: f[0].to_string(),
+            acct_no: f[1].to_string(),
+            ord_dt: f[2].to_string(),
+            odno: f[3].to_string(),
+            orgn_ord_dt: f[4].to_string(),
+            orgn_odno: f[5].to_string(),
+            series: f[6].to_string(),
+            rvse_cncl_dvsn_cd: f[7].to_string(),
+            sll_buy_dvsn_cd: f[8].to_string(),
+            cplx_ord_dvsn_cd: f[9].to_string(),
+            prce_tp: f[10].to_string(),
+            fm_excg_rcit_dvsn_cd: f[11].to_string(),
+            ord_qty: f[12].to_string(),
+            fm_lmt_pric: f[13].to_string(),
+            fm_stop_ord_pric: f[14].to_string(),
+            tot_ccld_qty: f[15].to_string(),
+            tot_ccld_uv: f[16].to_string(),
+            ord_remq: f[17].to_string(),
+            fm_ord_grp_dt: f[18].to_string(),
+            ord_grp_stno: f[19].to_string(),
+            ord_dtl_dtime: f[20].to_string(),
+            oprt_dtl_dtime: f[21].to_string(),
+            work_empl: f[22].to_string(),
+            ccld_dt: f[23].to_string(),
+            ccno: f[24].to_string(),
+            api_ccno: f[25].to_string(),
+            ccld_qty: f[26].to_string(),
+            fm_ccld_pric: f[27].to_string(),
+            crcy_cd: f[28].to_string(),
+            trst_fee: f[29].to_string(),
+            ord_mdia_online_yn: f[30].to_string(),
+            fm_ccld_amt: f[31].to_string(),
+            fuop_item_dvsn_cd: f[32].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [일반채권 실시간체결가 [실시간-052]] `H0BJCNT0` 레코드 (19개 필드)
+impl RealtimeRecord for TryitoutH0bjcnt0Response {
+    const TR_ID: &'static str = "H0BJCNT0";
+    const FIELD_COUNT: usize = 19;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            stnd_iscd: f[0].to_string(),
+            bond_isnm: f[1].to_string(),
+            stck_cntg_hour: f[2].to_string(),
+            prdy_vrss_sign: f[3].to_string(),
+            prdy_vrss: f[4].to_string(),
+            prdy_ctrt: f[5].to_string(),
+            stck_prpr: f[6].to_string(),
+            cntg_vol: f[7].to_string(),
+            stck_oprc: f[8].to_string(),
+            stck_hgpr: f[9].to_string(),
+            stck_lwpr: f[10].to_string(),
+            stck_prdy_clpr: f[11].to_string(),
+            bond_cntg_ert: f[12].to_string(),
+            oprc_ert: f[13].to_string(),
+            hgpr_ert: f[14].to_string(),
+            lwpr_ert: f[15].to_string(),
+            acml_vol: f[16].to_string(),
+            prdy_vol: f[17].to_string(),
+            cntg_type_cls_code: f[18].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [일반채권 실시간호가 [실시간-053]] `H0BJASP0` 레코드 (34개 필드)
+impl RealtimeRecord for TryitoutH0bjasp0Response {
+    const TR_ID: &'static str = "H0BJASP0";
+    const FIELD_COUNT: usize = 34;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            stnd_iscd: f[0].to_string(),
+            stck_cntg_hour: f[1].to_string(),
+            askp_ert1: f[2].to_string(),
+            bidp_ert1: f[3].to_string(),
+            askp1: f[4].to_string(),
+            bidp1: f[5].to_string(),
+            askp_rsqn1: f[6].to_string(),
+            bidp_rsqn1: f[7].to_string(),
+            askp_ert2: f[8].to_string(),
+            bidp_ert2: f[9].to_string(),
+            askp2: f[10].to_string(),
+            bidp2: f[11].to_string(),
+            askp_rsqn2: f[12].to_string(),
+            bidp_rsqn2: f[13].to_string(),
+            askp_ert3: f[14].to_string(),
+            bidp_ert3: f[15].to_string(),
+            askp3: f[16].to_string(),
+            bidp3: f[17].to_string(),
+            askp_rsqn3: f[18].to_string(),
+            bidp_rsqn3: f[19].to_string(),
+            askp_ert4: f[20].to_string(),
+            bidp_ert4: f[21].to_string(),
+            askp4: f[22].to_string(),
+            bidp4: f[23].to_string(),
+            askp_rsqn4: f[24].to_string(),
+            bidp_rsqn4: f[25].to_string(),
+            askp_ert5: f[26].to_string(),
+            bidp_ert5: f[27].to_string(),
+            askp5: f[28].to_string(),
+            bidp5: f[29].to_string(),
+            askp_rsqn52: f[30].to_string(),
+            bidp_rsqn53: f[31].to_string(),
+            total_askp_rsqn: f[32].to_string(),
+            total_bidp_rsqn: f[33].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
+
+/// [채권지수 실시간체결가 [실시간-060]] `H0BICNT0` 레코드 (20개 필드)
+impl RealtimeRecord for TryitoutH0bicnt0Response {
+    const TR_ID: &'static str = "H0BICNT0";
+    const FIELD_COUNT: usize = 20;
+
+    fn from_fields(f: &[&str]) -> Option<Self> {
+        if f.len() < Self::FIELD_COUNT {
+            return None;
+        }
+        Some(Self {
+            nmix_id: f[0].to_string(),
+            stnd_date1: f[1].to_string(),
+            trnm_hour: f[2].to_string(),
+            totl_ernn_nmix_oprc: f[3].to_string(),
+            totl_ernn_nmix_hgpr: f[4].to_string(),
+            totl_ernn_nmix_lwpr: f[5].to_string(),
+            totl_ernn_nmix: f[6].to_string(),
+            prdy_totl_ernn_nmix: f[7].to_string(),
+            totl_ernn_nmix_prdy_vrss: f[8].to_string(),
+            totl_ernn_nmix_prdy_vrss_sign: f[9].to_string(),
+            totl_ernn_nmix_prdy_ctrt: f[10].to_string(),
+            clen_prc_nmix: f[11].to_string(),
+            mrkt_prc_nmix: f[12].to_string(),
+            bond_call_rnvs_nmix: f[13].to_string(),
+            bond_zero_rnvs_nmix: f[14].to_string(),
+            bond_futs_thpr: f[15].to_string(),
+            bond_avrg_drtn_val: f[16].to_string(),
+            bond_avrg_cnvx_val: f[17].to_string(),
+            bond_avrg_ytm_val: f[18].to_string(),
+            bond_avrg_frdl_ytm_val: f[19].to_string(),
+            extra: Default::default(),
+        })
+    }
+}
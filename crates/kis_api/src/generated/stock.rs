@@ -3,42 +3,43 @@
     clippy::tabs_in_doc_comments,
     clippy::doc_markdown
 )]
-use crate::client::KisClient;
+use crate::client::{AnyEnv, KisClient, RealOnly};
 use crate::error::KisError;
 use crate::models::*;
 
 #[allow(dead_code)]
-pub struct StockTrading(pub(crate) KisClient);
+pub struct StockTrading<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct StockQuotations(pub(crate) KisClient);
+pub struct StockQuotations<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct StockCommon(pub(crate) KisClient);
+pub struct StockCommon<E = AnyEnv>(pub(crate) KisClient<E>);
 
 #[allow(dead_code)]
-pub struct StockRanking(pub(crate) KisClient);
+pub struct StockRanking<E = AnyEnv>(pub(crate) KisClient<E>);
 
-impl crate::endpoints::Stock {
-    pub fn trading(&self) -> StockTrading {
+impl<E> crate::endpoints::Stock<E> {
+    pub fn trading(&self) -> StockTrading<E> {
         StockTrading(self.0.clone())
     }
-    pub fn quotations(&self) -> StockQuotations {
+    pub fn quotations(&self) -> StockQuotations<E> {
         StockQuotations(self.0.clone())
     }
-    pub fn common(&self) -> StockCommon {
+    pub fn common(&self) -> StockCommon<E> {
         StockCommon(self.0.clone())
     }
-    pub fn ranking(&self) -> StockRanking {
+    pub fn ranking(&self) -> StockRanking<E> {
         StockRanking(self.0.clone())
     }
 }
 
 #[allow(non_snake_case)]
-impl StockTrading {
+impl<E> StockTrading<E> {
     /// 주식주문(현금)[v1_국내주식-001]
     /// - TR_ID: Real=TTTC0011U / VTS=VTTC0011U
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-cash
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_cash_sell(
         &self,
         req: DomesticStockV1TradingOrderCashRequest,
@@ -64,6 +65,7 @@ impl StockTrading {
     /// 주식주문(현금)[v1_국내주식-001]
     /// - TR_ID: Real=TTTC0012U / VTS=VTTC0012U
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-cash
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_cash_buy(
         &self,
         req: DomesticStockV1TradingOrderCashRequest,
@@ -89,10 +91,14 @@ impl StockTrading {
     /// 주식주문(신용)[v1_국내주식-002]
     /// - TR_ID: Real=TTTC0051U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-credit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_credit_sell(
         &self,
         req: DomesticStockV1TradingOrderCreditRequest,
-    ) -> Result<DomesticStockV1TradingOrderCreditResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderCreditResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0051U", "https://openapi.koreainvestment.com:9443")
@@ -112,10 +118,14 @@ impl StockTrading {
     /// 주식주문(신용)[v1_국내주식-002]
     /// - TR_ID: Real=TTTC0052U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-credit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_credit_buy(
         &self,
         req: DomesticStockV1TradingOrderCreditRequest,
-    ) -> Result<DomesticStockV1TradingOrderCreditResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderCreditResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0052U", "https://openapi.koreainvestment.com:9443")
@@ -135,6 +145,7 @@ impl StockTrading {
     /// 주식주문(정정취소)[v1_국내주식-003]
     /// - TR_ID: Real=TTTC0013U / VTS=VTTC0013U
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-rvsecncl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_rvsecncl(
         &self,
         req: DomesticStockV1TradingOrderRvsecnclRequest,
@@ -160,10 +171,14 @@ impl StockTrading {
     /// 주식정정취소가능주문조회[v1_국내주식-004]
     /// - TR_ID: Real=TTTC0084R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-psbl-rvsecncl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_psbl_rvsecncl(
         &self,
         req: DomesticStockV1TradingInquirePsblRvsecnclRequest,
-    ) -> Result<DomesticStockV1TradingInquirePsblRvsecnclResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquirePsblRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0084R", "https://openapi.koreainvestment.com:9443")
@@ -183,6 +198,7 @@ impl StockTrading {
     /// 주식일별주문체결조회[v1_국내주식-005]
     /// - TR_ID: Real=TTTC0081R / VTS=VTTC0081R
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-daily-ccld
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_daily_ccld_recent(
         &self,
         req: DomesticStockV1TradingInquireDailyCcldRequest,
@@ -208,6 +224,7 @@ impl StockTrading {
     /// 주식일별주문체결조회[v1_국내주식-005]
     /// - TR_ID: Real=CTSC9215R / VTS=VTSC9215R
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-daily-ccld
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_daily_ccld_old(
         &self,
         req: DomesticStockV1TradingInquireDailyCcldRequest,
@@ -233,6 +250,7 @@ impl StockTrading {
     /// 주식잔고조회[v1_국내주식-006]
     /// - TR_ID: Real=TTTC8434R / VTS=VTTC8434R
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_balance(
         &self,
         req: DomesticStockV1TradingInquireBalanceRequest,
@@ -258,6 +276,7 @@ impl StockTrading {
     /// 매수가능조회[v1_국내주식-007]
     /// - TR_ID: Real=TTTC8908R / VTS=VTTC8908R
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-psbl-order
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_psbl_order(
         &self,
         req: DomesticStockV1TradingInquirePsblOrderRequest,
@@ -283,10 +302,14 @@ impl StockTrading {
     /// 매도가능수량조회 [국내주식-165]
     /// - TR_ID: Real=TTTC8408R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-psbl-sell
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_psbl_sell(
         &self,
         req: DomesticStockV1TradingInquirePsblSellRequest,
-    ) -> Result<DomesticStockV1TradingInquirePsblSellResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquirePsblSellResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8408R", "https://openapi.koreainvestment.com:9443")
@@ -306,10 +329,14 @@ impl StockTrading {
     /// 신용매수가능조회[v1_국내주식-042]
     /// - TR_ID: Real=TTTC8909R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-credit-psamount
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_credit_psamount(
         &self,
         req: DomesticStockV1TradingInquireCreditPsamountRequest,
-    ) -> Result<DomesticStockV1TradingInquireCreditPsamountResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquireCreditPsamountResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8909R", "https://openapi.koreainvestment.com:9443")
@@ -329,10 +356,14 @@ impl StockTrading {
     /// 주식예약주문[v1_국내주식-017]
     /// - TR_ID: Real=CTSC0008U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-resv
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_resv(
         &self,
         req: DomesticStockV1TradingOrderResvRequest,
-    ) -> Result<DomesticStockV1TradingOrderResvResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderResvResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC0008U", "https://openapi.koreainvestment.com:9443")
@@ -352,10 +383,14 @@ impl StockTrading {
     /// 주식예약주문정정취소[v1_국내주식-018,019]
     /// - TR_ID: Real=CTSC0009U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-resv-rvsecncl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_resv_rvsecncl_cancel_resv(
         &self,
         req: DomesticStockV1TradingOrderResvRvsecnclRequest,
-    ) -> Result<DomesticStockV1TradingOrderResvRvsecnclResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderResvRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC0009U", "https://openapi.koreainvestment.com:9443")
@@ -375,10 +410,14 @@ impl StockTrading {
     /// 주식예약주문정정취소[v1_국내주식-018,019]
     /// - TR_ID: Real=CTSC0013U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-resv-rvsecncl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_resv_rvsecncl_mod_resv(
         &self,
         req: DomesticStockV1TradingOrderResvRvsecnclRequest,
-    ) -> Result<DomesticStockV1TradingOrderResvRvsecnclResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderResvRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC0013U", "https://openapi.koreainvestment.com:9443")
@@ -398,10 +437,14 @@ impl StockTrading {
     /// 주식예약주문조회[v1_국내주식-020]
     /// - TR_ID: Real=CTSC0004R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/order-resv-ccnl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_order_resv_ccnl(
         &self,
         req: DomesticStockV1TradingOrderResvCcnlRequest,
-    ) -> Result<DomesticStockV1TradingOrderResvCcnlResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingOrderResvCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC0004R", "https://openapi.koreainvestment.com:9443")
@@ -421,10 +464,14 @@ impl StockTrading {
     /// 퇴직연금 체결기준잔고[v1_국내주식-032]
     /// - TR_ID: Real=TTTC2202R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/pension/inquire-present-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_pension_inquire_present_balance(
         &self,
         req: DomesticStockV1TradingPensionInquirePresentBalanceRequest,
-    ) -> Result<DomesticStockV1TradingPensionInquirePresentBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPensionInquirePresentBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC2202R", "https://openapi.koreainvestment.com:9443")
@@ -444,10 +491,14 @@ impl StockTrading {
     /// 퇴직연금 미체결내역[v1_국내주식-033]
     /// - TR_ID: Real=TTTC2201R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/pension/inquire-daily-ccld
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_pension_inquire_daily_ccld(
         &self,
         req: DomesticStockV1TradingPensionInquireDailyCcldRequest,
-    ) -> Result<DomesticStockV1TradingPensionInquireDailyCcldResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPensionInquireDailyCcldResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC2201R", "https://openapi.koreainvestment.com:9443")
@@ -467,10 +518,14 @@ impl StockTrading {
     /// 퇴직연금 매수가능조회[v1_국내주식-034]
     /// - TR_ID: Real=TTTC0503R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/pension/inquire-psbl-order
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_pension_inquire_psbl_order(
         &self,
         req: DomesticStockV1TradingPensionInquirePsblOrderRequest,
-    ) -> Result<DomesticStockV1TradingPensionInquirePsblOrderResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPensionInquirePsblOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0503R", "https://openapi.koreainvestment.com:9443")
@@ -490,10 +545,14 @@ impl StockTrading {
     /// 퇴직연금 예수금조회[v1_국내주식-035]
     /// - TR_ID: Real=TTTC0506R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/pension/inquire-deposit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_pension_inquire_deposit(
         &self,
         req: DomesticStockV1TradingPensionInquireDepositRequest,
-    ) -> Result<DomesticStockV1TradingPensionInquireDepositResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPensionInquireDepositResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0506R", "https://openapi.koreainvestment.com:9443")
@@ -513,10 +572,14 @@ impl StockTrading {
     /// 퇴직연금 잔고조회[v1_국내주식-036]
     /// - TR_ID: Real=TTTC2208R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/pension/inquire-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_pension_inquire_balance(
         &self,
         req: DomesticStockV1TradingPensionInquireBalanceRequest,
-    ) -> Result<DomesticStockV1TradingPensionInquireBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPensionInquireBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC2208R", "https://openapi.koreainvestment.com:9443")
@@ -536,10 +599,14 @@ impl StockTrading {
     /// 주식잔고조회_실현손익[v1_국내주식-041]
     /// - TR_ID: Real=TTTC8494R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-balance-rlz-pl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_balance_rlz_pl(
         &self,
         req: DomesticStockV1TradingInquireBalanceRlzPlRequest,
-    ) -> Result<DomesticStockV1TradingInquireBalanceRlzPlResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquireBalanceRlzPlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8494R", "https://openapi.koreainvestment.com:9443")
@@ -559,10 +626,14 @@ impl StockTrading {
     /// 투자계좌자산현황조회[v1_국내주식-048]
     /// - TR_ID: Real=CTRP6548R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-account-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_account_balance(
         &self,
         req: DomesticStockV1TradingInquireAccountBalanceRequest,
-    ) -> Result<DomesticStockV1TradingInquireAccountBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquireAccountBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTRP6548R", "https://openapi.koreainvestment.com:9443")
@@ -582,10 +653,14 @@ impl StockTrading {
     /// 기간별손익일별합산조회[v1_국내주식-052]
     /// - TR_ID: Real=TTTC8708R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-period-profit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_period_profit(
         &self,
         req: DomesticStockV1TradingInquirePeriodProfitRequest,
-    ) -> Result<DomesticStockV1TradingInquirePeriodProfitResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquirePeriodProfitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8708R", "https://openapi.koreainvestment.com:9443")
@@ -605,10 +680,14 @@ impl StockTrading {
     /// 기간별매매손익현황조회[v1_국내주식-060]
     /// - TR_ID: Real=TTTC8715R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/inquire-period-trade-profit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_inquire_period_trade_profit(
         &self,
         req: DomesticStockV1TradingInquirePeriodTradeProfitRequest,
-    ) -> Result<DomesticStockV1TradingInquirePeriodTradeProfitResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingInquirePeriodTradeProfitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC8715R", "https://openapi.koreainvestment.com:9443")
@@ -628,10 +707,14 @@ impl StockTrading {
     /// 주식통합증거금 현황 [국내주식-191]
     /// - TR_ID: Real=TTTC0869R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/intgr-margin
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_intgr_margin(
         &self,
         req: DomesticStockV1TradingIntgrMarginRequest,
-    ) -> Result<DomesticStockV1TradingIntgrMarginResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingIntgrMarginResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTC0869R", "https://openapi.koreainvestment.com:9443")
@@ -651,10 +734,14 @@ impl StockTrading {
     /// 기간별계좌권리현황조회 [국내주식-211]
     /// - TR_ID: Real=CTRGA011R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/trading/period-rights
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_trading_period_rights(
         &self,
         req: DomesticStockV1TradingPeriodRightsRequest,
-    ) -> Result<DomesticStockV1TradingPeriodRightsResponse, KisError> {
+    ) -> Result<DomesticStockV1TradingPeriodRightsResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTRGA011R", "https://openapi.koreainvestment.com:9443")
@@ -674,6 +761,7 @@ impl StockTrading {
     /// 선물옵션 주문[v1_국내선물-001]
     /// - TR_ID: Real=TTTO1101U / VTS=VTTO1101U
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_day_order(
         &self,
         req: DomesticFutureoptionV1TradingOrderRequest,
@@ -699,10 +787,14 @@ impl StockTrading {
     /// 선물옵션 주문[v1_국내선물-001]
     /// - TR_ID: Real=JTCE1001U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_legacy(
         &self,
         req: DomesticFutureoptionV1TradingOrderRequest,
-    ) -> Result<DomesticFutureoptionV1TradingOrderResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE1001U", "https://openapi.koreainvestment.com:9443")
@@ -725,10 +817,14 @@ impl StockTrading {
     /// 선물옵션 주문[v1_국내선물-001]
     /// - TR_ID: Real=STTN1101U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_new(
         &self,
         req: DomesticFutureoptionV1TradingOrderRequest,
-    ) -> Result<DomesticFutureoptionV1TradingOrderResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("STTN1101U", "https://openapi.koreainvestment.com:9443")
@@ -751,6 +847,7 @@ impl StockTrading {
     /// 선물옵션 정정취소주문[v1_국내선물-002]
     /// - TR_ID: Real=TTTO1103U / VTS=VTTO1103U
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order-rvsecncl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_rvsecncl_day_modify(
         &self,
         req: DomesticFutureoptionV1TradingOrderRvsecnclRequest,
@@ -776,10 +873,14 @@ impl StockTrading {
     /// 선물옵션 정정취소주문[v1_국내선물-002]
     /// - TR_ID: Real=JTCE1002U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order-rvsecncl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_rvsecncl_legacy(
         &self,
         req: DomesticFutureoptionV1TradingOrderRvsecnclRequest,
-    ) -> Result<DomesticFutureoptionV1TradingOrderRvsecnclResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE1002U", "https://openapi.koreainvestment.com:9443")
@@ -802,10 +903,14 @@ impl StockTrading {
     /// 선물옵션 정정취소주문[v1_국내선물-002]
     /// - TR_ID: Real=STTN1103U / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/order-rvsecncl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_order_rvsecncl_new(
         &self,
         req: DomesticFutureoptionV1TradingOrderRvsecnclRequest,
-    ) -> Result<DomesticFutureoptionV1TradingOrderRvsecnclResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingOrderRvsecnclResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("STTN1103U", "https://openapi.koreainvestment.com:9443")
@@ -828,6 +933,7 @@ impl StockTrading {
     /// 선물옵션 주문체결내역조회[v1_국내선물-003]
     /// - TR_ID: Real=TTTO5201R / VTS=VTTO5201R
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ccnl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ccnl(
         &self,
         req: DomesticFutureoptionV1TradingInquireCcnlRequest,
@@ -853,6 +959,7 @@ impl StockTrading {
     /// 선물옵션 잔고현황[v1_국내선물-004]
     /// - TR_ID: Real=CTFO6118R / VTS=VTFO6118R
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-balance
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_balance(
         &self,
         req: DomesticFutureoptionV1TradingInquireBalanceRequest,
@@ -878,6 +985,7 @@ impl StockTrading {
     /// 선물옵션 주문가능[v1_국내선물-005]
     /// - TR_ID: Real=TTTO5105R / VTS=VTTO5105R
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-psbl-order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_psbl_order(
         &self,
         req: DomesticFutureoptionV1TradingInquirePsblOrderRequest,
@@ -903,10 +1011,14 @@ impl StockTrading {
     /// (야간)선물옵션 주문체결 내역조회 [국내선물-009]
     /// - TR_ID: Real=JTCE5005R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ngt-ccnl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ngt_ccnl_legacy(
         &self,
         req: DomesticFutureoptionV1TradingInquireNgtCcnlRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireNgtCcnlResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireNgtCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE5005R", "https://openapi.koreainvestment.com:9443")
@@ -926,10 +1038,14 @@ impl StockTrading {
     /// (야간)선물옵션 주문체결 내역조회 [국내선물-009]
     /// - TR_ID: Real=STTN5201R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ngt-ccnl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ngt_ccnl_new(
         &self,
         req: DomesticFutureoptionV1TradingInquireNgtCcnlRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireNgtCcnlResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireNgtCcnlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("STTN5201R", "https://openapi.koreainvestment.com:9443")
@@ -949,10 +1065,14 @@ impl StockTrading {
     /// (야간)선물옵션 잔고현황 [국내선물-010]
     /// - TR_ID: Real=JTCE6001R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ngt-balance
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ngt_balance_legacy(
         &self,
         req: DomesticFutureoptionV1TradingInquireNgtBalanceRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireNgtBalanceResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireNgtBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE6001R", "https://openapi.koreainvestment.com:9443")
@@ -972,10 +1092,14 @@ impl StockTrading {
     /// (야간)선물옵션 잔고현황 [국내선물-010]
     /// - TR_ID: Real=CTFN6118R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ngt-balance
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ngt_balance_new(
         &self,
         req: DomesticFutureoptionV1TradingInquireNgtBalanceRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireNgtBalanceResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireNgtBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFN6118R", "https://openapi.koreainvestment.com:9443")
@@ -995,10 +1119,14 @@ impl StockTrading {
     /// (야간)선물옵션 주문가능 조회 [국내선물-011]
     /// - TR_ID: Real=JTCE1004R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-psbl-ngt-order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_psbl_ngt_order_legacy(
         &self,
         req: DomesticFutureoptionV1TradingInquirePsblNgtOrderRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE1004R", "https://openapi.koreainvestment.com:9443")
@@ -1018,10 +1146,14 @@ impl StockTrading {
     /// (야간)선물옵션 주문가능 조회 [국내선물-011]
     /// - TR_ID: Real=STTN5105R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-psbl-ngt-order
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_psbl_ngt_order_new(
         &self,
         req: DomesticFutureoptionV1TradingInquirePsblNgtOrderRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("STTN5105R", "https://openapi.koreainvestment.com:9443")
@@ -1041,10 +1173,14 @@ impl StockTrading {
     /// (야간)선물옵션 증거금 상세 [국내선물-024]
     /// - TR_ID: Real=JTCE6003R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/ngt-margin-detail
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_ngt_margin_detail_legacy(
         &self,
         req: DomesticFutureoptionV1TradingNgtMarginDetailRequest,
-    ) -> Result<DomesticFutureoptionV1TradingNgtMarginDetailResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingNgtMarginDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("JTCE6003R", "https://openapi.koreainvestment.com:9443")
@@ -1064,10 +1200,14 @@ impl StockTrading {
     /// (야간)선물옵션 증거금 상세 [국내선물-024]
     /// - TR_ID: Real=CTFN7107R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/ngt-margin-detail
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_ngt_margin_detail_new(
         &self,
         req: DomesticFutureoptionV1TradingNgtMarginDetailRequest,
-    ) -> Result<DomesticFutureoptionV1TradingNgtMarginDetailResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingNgtMarginDetailResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFN7107R", "https://openapi.koreainvestment.com:9443")
@@ -1087,10 +1227,14 @@ impl StockTrading {
     /// 선물옵션 잔고정산손익내역[v1_국내선물-013]
     /// - TR_ID: Real=CTFO6117R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-balance-settlement-pl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_balance_settlement_pl(
         &self,
         req: DomesticFutureoptionV1TradingInquireBalanceSettlementPlRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireBalanceSettlementPlResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireBalanceSettlementPlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFO6117R", "https://openapi.koreainvestment.com:9443")
@@ -1110,10 +1254,14 @@ impl StockTrading {
     /// 선물옵션 총자산현황[v1_국내선물-014]
     /// - TR_ID: Real=CTRP6550R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-deposit
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_deposit(
         &self,
         req: DomesticFutureoptionV1TradingInquireDepositRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireDepositResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireDepositResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTRP6550R", "https://openapi.koreainvestment.com:9443")
@@ -1133,10 +1281,14 @@ impl StockTrading {
     /// 선물옵션 잔고평가손익내역[v1_국내선물-015]
     /// - TR_ID: Real=CTFO6159R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-balance-valuation-pl
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_balance_valuation_pl(
         &self,
         req: DomesticFutureoptionV1TradingInquireBalanceValuationPlRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireBalanceValuationPlResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireBalanceValuationPlResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFO6159R", "https://openapi.koreainvestment.com:9443")
@@ -1156,10 +1308,14 @@ impl StockTrading {
     /// 선물옵션 기준일체결내역[v1_국내선물-016]
     /// - TR_ID: Real=CTFO5139R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-ccnl-bstime
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_ccnl_bstime(
         &self,
         req: DomesticFutureoptionV1TradingInquireCcnlBstimeRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireCcnlBstimeResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireCcnlBstimeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFO5139R", "https://openapi.koreainvestment.com:9443")
@@ -1179,10 +1335,14 @@ impl StockTrading {
     /// 선물옵션기간약정수수료일별[v1_국내선물-017]
     /// - TR_ID: Real=CTFO6119R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/trading/inquire-daily-amount-fee
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_trading_inquire_daily_amount_fee(
         &self,
         req: DomesticFutureoptionV1TradingInquireDailyAmountFeeRequest,
-    ) -> Result<DomesticFutureoptionV1TradingInquireDailyAmountFeeResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1TradingInquireDailyAmountFeeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTFO6119R", "https://openapi.koreainvestment.com:9443")
@@ -1201,10 +1361,11 @@ impl StockTrading {
 }
 
 #[allow(non_snake_case)]
-impl StockQuotations {
+impl<E> StockQuotations<E> {
     /// 주식현재가 시세[v1_국내주식-008]
     /// - TR_ID: Real=FHKST01010100 / VTS=FHKST01010100
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_price(
         &self,
         req: DomesticStockV1QuotationsInquirePriceRequest,
@@ -1231,10 +1392,14 @@ impl StockQuotations {
     /// 주식현재가 시세2[v1_국내주식-054]
     /// - TR_ID: Real=FHPST01010000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-price-2
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_price_2(
         &self,
         req: DomesticStockV1QuotationsInquirePrice2Request,
-    ) -> Result<DomesticStockV1QuotationsInquirePrice2Response, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquirePrice2Response, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01010000", "https://openapi.koreainvestment.com:9443")
@@ -1254,6 +1419,7 @@ impl StockQuotations {
     /// 주식현재가 체결[v1_국내주식-009]
     /// - TR_ID: Real=FHKST01010300 / VTS=FHKST01010300
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-ccnl
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_ccnl(
         &self,
         req: DomesticStockV1QuotationsInquireCcnlRequest,
@@ -1280,6 +1446,7 @@ impl StockQuotations {
     /// 주식현재가 일자별[v1_국내주식-010]
     /// - TR_ID: Real=FHKST01010400 / VTS=FHKST01010400
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-daily-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_daily_price(
         &self,
         req: DomesticStockV1QuotationsInquireDailyPriceRequest,
@@ -1306,6 +1473,7 @@ impl StockQuotations {
     /// 주식현재가 호가/예상체결[v1_국내주식-011]
     /// - TR_ID: Real=FHKST01010200 / VTS=FHKST01010200
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-asking-price-exp-ccn
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_asking_price_exp_ccn(
         &self,
         req: DomesticStockV1QuotationsInquireAskingPriceExpCcnRequest,
@@ -1332,6 +1500,7 @@ impl StockQuotations {
     /// 주식현재가 투자자[v1_국내주식-012]
     /// - TR_ID: Real=FHKST01010900 / VTS=FHKST01010900
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-investor
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_investor(
         &self,
         req: DomesticStockV1QuotationsInquireInvestorRequest,
@@ -1358,6 +1527,7 @@ impl StockQuotations {
     /// 주식현재가 회원사[v1_국내주식-013]
     /// - TR_ID: Real=FHKST01010600 / VTS=FHKST01010600
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-member
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_member(
         &self,
         req: DomesticStockV1QuotationsInquireMemberRequest,
@@ -1384,6 +1554,7 @@ impl StockQuotations {
     /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016]
     /// - TR_ID: Real=FHKST03010100 / VTS=FHKST03010100
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_daily_itemchartprice(
         &self,
         req: DomesticStockV1QuotationsInquireDailyItemchartpriceRequest,
@@ -1410,6 +1581,7 @@ impl StockQuotations {
     /// 주식당일분봉조회[v1_국내주식-022]
     /// - TR_ID: Real=FHKST03010200 / VTS=FHKST03010200
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-time-itemchartprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_time_itemchartprice(
         &self,
         req: DomesticStockV1QuotationsInquireTimeItemchartpriceRequest,
@@ -1436,10 +1608,14 @@ impl StockQuotations {
     /// 주식일별분봉조회 [국내주식-213]
     /// - TR_ID: Real=FHKST03010230 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-time-dailychartprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_time_dailychartprice(
         &self,
         req: DomesticStockV1QuotationsInquireTimeDailychartpriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireTimeDailychartpriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireTimeDailychartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST03010230", "https://openapi.koreainvestment.com:9443")
@@ -1459,6 +1635,7 @@ impl StockQuotations {
     /// 주식현재가 당일시간대별체결[v1_국내주식-023]
     /// - TR_ID: Real=FHPST01060000 / VTS=FHPST01060000
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-time-itemconclusion
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_time_itemconclusion(
         &self,
         req: DomesticStockV1QuotationsInquireTimeItemconclusionRequest,
@@ -1485,6 +1662,7 @@ impl StockQuotations {
     /// 주식현재가 시간외일자별주가[v1_국내주식-026]
     /// - TR_ID: Real=FHPST02320000 / VTS=FHPST02320000
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-daily-overtimeprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_daily_overtimeprice(
         &self,
         req: DomesticStockV1QuotationsInquireDailyOvertimepriceRequest,
@@ -1511,6 +1689,7 @@ impl StockQuotations {
     /// 주식현재가 시간외시간별체결[v1_국내주식-025]
     /// - TR_ID: Real=FHPST02310000 / VTS=FHPST02310000
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-time-overtimeconclusion
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_time_overtimeconclusion(
         &self,
         req: DomesticStockV1QuotationsInquireTimeOvertimeconclusionRequest,
@@ -1537,10 +1716,14 @@ impl StockQuotations {
     /// 국내주식 시간외현재가[국내주식-076]
     /// - TR_ID: Real=FHPST02300000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-overtime-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_overtime_price(
         &self,
         req: DomesticStockV1QuotationsInquireOvertimePriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireOvertimePriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireOvertimePriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02300000", "https://openapi.koreainvestment.com:9443")
@@ -1560,10 +1743,14 @@ impl StockQuotations {
     /// 국내주식 시간외호가[국내주식-077]
     /// - TR_ID: Real=FHPST02300400 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-overtime-asking-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_overtime_asking_price(
         &self,
         req: DomesticStockV1QuotationsInquireOvertimeAskingPriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireOvertimeAskingPriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireOvertimeAskingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02300400", "https://openapi.koreainvestment.com:9443")
@@ -1583,10 +1770,14 @@ impl StockQuotations {
     /// 국내주식 장마감 예상체결가[국내주식-120]
     /// - TR_ID: Real=FHKST117300C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/exp-closing-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_exp_closing_price(
         &self,
         req: DomesticStockV1QuotationsExpClosingPriceRequest,
-    ) -> Result<DomesticStockV1QuotationsExpClosingPriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsExpClosingPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST117300C0", "https://openapi.koreainvestment.com:9443")
@@ -1606,6 +1797,7 @@ impl StockQuotations {
     /// ELW 현재가 시세[v1_국내주식-014]
     /// - TR_ID: Real=FHKEW15010000 / VTS=FHKEW15010000
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-elw-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_elw_price(
         &self,
         req: DomesticStockV1QuotationsInquireElwPriceRequest,
@@ -1632,10 +1824,14 @@ impl StockQuotations {
     /// 국내업종 현재지수[v1_국내주식-063]
     /// - TR_ID: Real=FHPUP02100000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-index-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_index_price(
         &self,
         req: DomesticStockV1QuotationsInquireIndexPriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireIndexPriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireIndexPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPUP02100000", "https://openapi.koreainvestment.com:9443")
@@ -1655,10 +1851,14 @@ impl StockQuotations {
     /// 국내업종 일자별지수[v1_국내주식-065]
     /// - TR_ID: Real=FHPUP02120000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-index-daily-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_index_daily_price(
         &self,
         req: DomesticStockV1QuotationsInquireIndexDailyPriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireIndexDailyPriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireIndexDailyPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPUP02120000", "https://openapi.koreainvestment.com:9443")
@@ -1678,10 +1878,14 @@ impl StockQuotations {
     /// 국내업종 시간별지수(초)[국내주식-064]
     /// - TR_ID: Real=FHPUP02110100 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-index-tickprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_index_tickprice(
         &self,
         req: DomesticStockV1QuotationsInquireIndexTickpriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireIndexTickpriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireIndexTickpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPUP02110100", "https://openapi.koreainvestment.com:9443")
@@ -1701,10 +1905,14 @@ impl StockQuotations {
     /// 국내업종 시간별지수(분)[국내주식-119]
     /// - TR_ID: Real=FHPUP02110200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-index-timeprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_index_timeprice(
         &self,
         req: DomesticStockV1QuotationsInquireIndexTimepriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireIndexTimepriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireIndexTimepriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPUP02110200", "https://openapi.koreainvestment.com:9443")
@@ -1724,10 +1932,14 @@ impl StockQuotations {
     /// 업종 분봉조회[v1_국내주식-045]
     /// - TR_ID: Real=FHKUP03500200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-time-indexchartprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_time_indexchartprice(
         &self,
         req: DomesticStockV1QuotationsInquireTimeIndexchartpriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireTimeIndexchartpriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireTimeIndexchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKUP03500200", "https://openapi.koreainvestment.com:9443")
@@ -1747,6 +1959,7 @@ impl StockQuotations {
     /// 국내주식업종기간별시세(일/주/월/년)[v1_국내주식-021]
     /// - TR_ID: Real=FHKUP03500100 / VTS=FHKUP03500100
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-daily-indexchartprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_daily_indexchartprice(
         &self,
         req: DomesticStockV1QuotationsInquireDailyIndexchartpriceRequest,
@@ -1773,10 +1986,14 @@ impl StockQuotations {
     /// 국내업종 구분별전체시세[v1_국내주식-066]
     /// - TR_ID: Real=FHPUP02140000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-index-category-price
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_index_category_price(
         &self,
         req: DomesticStockV1QuotationsInquireIndexCategoryPriceRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireIndexCategoryPriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireIndexCategoryPriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPUP02140000", "https://openapi.koreainvestment.com:9443")
@@ -1796,10 +2013,14 @@ impl StockQuotations {
     /// 국내주식 예상체결지수 추이[국내주식-121]
     /// - TR_ID: Real=FHPST01840000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/exp-index-trend
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_exp_index_trend(
         &self,
         req: DomesticStockV1QuotationsExpIndexTrendRequest,
-    ) -> Result<DomesticStockV1QuotationsExpIndexTrendResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsExpIndexTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01840000", "https://openapi.koreainvestment.com:9443")
@@ -1819,10 +2040,14 @@ impl StockQuotations {
     /// 국내주식 예상체결 전체지수[국내주식-122]
     /// - TR_ID: Real=FHKUP11750000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/exp-total-index
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_exp_total_index(
         &self,
         req: DomesticStockV1QuotationsExpTotalIndexRequest,
-    ) -> Result<DomesticStockV1QuotationsExpTotalIndexResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsExpTotalIndexResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKUP11750000", "https://openapi.koreainvestment.com:9443")
@@ -1842,10 +2067,14 @@ impl StockQuotations {
     /// 변동성완화장치(VI) 현황 [v1_국내주식-055]
     /// - TR_ID: Real=FHPST01390000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-vi-status
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_vi_status(
         &self,
         req: DomesticStockV1QuotationsInquireViStatusRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireViStatusResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireViStatusResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01390000", "https://openapi.koreainvestment.com:9443")
@@ -1865,10 +2094,14 @@ impl StockQuotations {
     /// 금리 종합(국내채권/금리) [국내주식-155]
     /// - TR_ID: Real=FHPST07020000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/comp-interest
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_comp_interest(
         &self,
         req: DomesticStockV1QuotationsCompInterestRequest,
-    ) -> Result<DomesticStockV1QuotationsCompInterestResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsCompInterestResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST07020000", "https://openapi.koreainvestment.com:9443")
@@ -1888,10 +2121,14 @@ impl StockQuotations {
     /// 종합 시황/공시(제목) [국내주식-141]
     /// - TR_ID: Real=FHKST01011800 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/news-title
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_news_title(
         &self,
         req: DomesticStockV1QuotationsNewsTitleRequest,
-    ) -> Result<DomesticStockV1QuotationsNewsTitleResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsNewsTitleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST01011800", "https://openapi.koreainvestment.com:9443")
@@ -1911,10 +2148,14 @@ impl StockQuotations {
     /// 국내휴장일조회[국내주식-040]
     /// - TR_ID: Real=CTCA0903R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/chk-holiday
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_chk_holiday(
         &self,
         req: DomesticStockV1QuotationsChkHolidayRequest,
-    ) -> Result<DomesticStockV1QuotationsChkHolidayResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsChkHolidayResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTCA0903R", "https://openapi.koreainvestment.com:9443")
@@ -1934,10 +2175,14 @@ impl StockQuotations {
     /// 국내선물 영업일조회 [국내주식-160]
     /// - TR_ID: Real=HHMCM000002C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/market-time
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_market_time(
         &self,
         req: (),
-    ) -> Result<DomesticStockV1QuotationsMarketTimeResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsMarketTimeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHMCM000002C0", "https://openapi.koreainvestment.com:9443")
@@ -1957,10 +2202,14 @@ impl StockQuotations {
     /// 상품기본조회[v1_국내주식-029]
     /// - TR_ID: Real=CTPF1604R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/search-info
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_search_info(
         &self,
         req: DomesticStockV1QuotationsSearchInfoRequest,
-    ) -> Result<DomesticStockV1QuotationsSearchInfoResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsSearchInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF1604R", "https://openapi.koreainvestment.com:9443")
@@ -1980,10 +2229,14 @@ impl StockQuotations {
     /// 주식기본조회[v1_국내주식-067]
     /// - TR_ID: Real=CTPF1002R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/search-stock-info
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_search_stock_info(
         &self,
         req: DomesticStockV1QuotationsSearchStockInfoRequest,
-    ) -> Result<DomesticStockV1QuotationsSearchStockInfoResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsSearchStockInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTPF1002R", "https://openapi.koreainvestment.com:9443")
@@ -2003,10 +2256,14 @@ impl StockQuotations {
     /// 국내주식 당사 신용가능종목[국내주식-111]
     /// - TR_ID: Real=FHPST04770000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/credit-by-company
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_credit_by_company(
         &self,
         req: DomesticStockV1QuotationsCreditByCompanyRequest,
-    ) -> Result<DomesticStockV1QuotationsCreditByCompanyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsCreditByCompanyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04770000", "https://openapi.koreainvestment.com:9443")
@@ -2026,10 +2283,14 @@ impl StockQuotations {
     /// 국내주식 종목추정실적 [국내주식-187]
     /// - TR_ID: Real=HHKST668300C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/estimate-perform
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_estimate_perform(
         &self,
         req: DomesticStockV1QuotationsEstimatePerformRequest,
-    ) -> Result<DomesticStockV1QuotationsEstimatePerformResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsEstimatePerformResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKST668300C0", "https://openapi.koreainvestment.com:9443")
@@ -2049,10 +2310,14 @@ impl StockQuotations {
     /// 당사 대주가능 종목 [국내주식-195]
     /// - TR_ID: Real=CTSC2702R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/lendable-by-company
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_lendable_by_company(
         &self,
         req: DomesticStockV1QuotationsLendableByCompanyRequest,
-    ) -> Result<DomesticStockV1QuotationsLendableByCompanyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsLendableByCompanyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("CTSC2702R", "https://openapi.koreainvestment.com:9443")
@@ -2072,10 +2337,14 @@ impl StockQuotations {
     /// 국내주식 종목투자의견 [국내주식-188]
     /// - TR_ID: Real=FHKST663300C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/invest-opinion
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_invest_opinion(
         &self,
         req: DomesticStockV1QuotationsInvestOpinionRequest,
-    ) -> Result<DomesticStockV1QuotationsInvestOpinionResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInvestOpinionResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST663300C0", "https://openapi.koreainvestment.com:9443")
@@ -2095,10 +2364,14 @@ impl StockQuotations {
     /// 국내주식 증권사별 투자의견 [국내주식-189]
     /// - TR_ID: Real=FHKST663400C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/invest-opbysec
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_invest_opbysec(
         &self,
         req: DomesticStockV1QuotationsInvestOpbysecRequest,
-    ) -> Result<DomesticStockV1QuotationsInvestOpbysecResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInvestOpbysecResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST663400C0", "https://openapi.koreainvestment.com:9443")
@@ -2118,10 +2391,14 @@ impl StockQuotations {
     /// 종목조건검색 목록조회[국내주식-038]
     /// - TR_ID: Real=HHKST03900300 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/psearch-title
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_psearch_title(
         &self,
         req: DomesticStockV1QuotationsPsearchTitleRequest,
-    ) -> Result<DomesticStockV1QuotationsPsearchTitleResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsPsearchTitleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKST03900300", "https://openapi.koreainvestment.com:9443")
@@ -2141,10 +2418,14 @@ impl StockQuotations {
     /// 종목조건검색조회 [국내주식-039]
     /// - TR_ID: Real=HHKST03900400 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/psearch-result
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_psearch_result(
         &self,
         req: DomesticStockV1QuotationsPsearchResultRequest,
-    ) -> Result<DomesticStockV1QuotationsPsearchResultResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsPsearchResultResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKST03900400", "https://openapi.koreainvestment.com:9443")
@@ -2164,10 +2445,14 @@ impl StockQuotations {
     /// 관심종목 그룹조회 [국내주식-204]
     /// - TR_ID: Real=HHKCM113004C7 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/intstock-grouplist
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_intstock_grouplist(
         &self,
         req: DomesticStockV1QuotationsIntstockGrouplistRequest,
-    ) -> Result<DomesticStockV1QuotationsIntstockGrouplistResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsIntstockGrouplistResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKCM113004C7", "https://openapi.koreainvestment.com:9443")
@@ -2187,10 +2472,14 @@ impl StockQuotations {
     /// 관심종목(멀티종목) 시세조회 [국내주식-205]
     /// - TR_ID: Real=FHKST11300006 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/intstock-multprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_intstock_multprice(
         &self,
         req: DomesticStockV1QuotationsIntstockMultpriceRequest,
-    ) -> Result<DomesticStockV1QuotationsIntstockMultpriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsIntstockMultpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST11300006", "https://openapi.koreainvestment.com:9443")
@@ -2210,10 +2499,14 @@ impl StockQuotations {
     /// 관심종목 그룹별 종목조회 [국내주식-203]
     /// - TR_ID: Real=HHKCM113004C6 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/intstock-stocklist-by-group
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_intstock_stocklist_by_group(
         &self,
         req: DomesticStockV1QuotationsIntstockStocklistByGroupRequest,
-    ) -> Result<DomesticStockV1QuotationsIntstockStocklistByGroupResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsIntstockStocklistByGroupResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKCM113004C6", "https://openapi.koreainvestment.com:9443")
@@ -2233,10 +2526,14 @@ impl StockQuotations {
     /// 국내기관_외국인 매매종목가집계[국내주식-037]
     /// - TR_ID: Real=FHPTJ04400000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/foreign-institution-total
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_foreign_institution_total(
         &self,
         req: DomesticStockV1QuotationsForeignInstitutionTotalRequest,
-    ) -> Result<DomesticStockV1QuotationsForeignInstitutionTotalResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsForeignInstitutionTotalResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPTJ04400000", "https://openapi.koreainvestment.com:9443")
@@ -2256,10 +2553,14 @@ impl StockQuotations {
     /// 외국계 매매종목 가집계 [국내주식-161]
     /// - TR_ID: Real=FHKST644100C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/frgnmem-trade-estimate
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_frgnmem_trade_estimate(
         &self,
         req: DomesticStockV1QuotationsFrgnmemTradeEstimateRequest,
-    ) -> Result<DomesticStockV1QuotationsFrgnmemTradeEstimateResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsFrgnmemTradeEstimateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST644100C0", "https://openapi.koreainvestment.com:9443")
@@ -2279,10 +2580,14 @@ impl StockQuotations {
     /// 종목별 투자자매매동향(일별)
     /// - TR_ID: Real=FHPTJ04160001 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/investor-trade-by-stock-daily
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_investor_trade_by_stock_daily(
         &self,
         req: DomesticStockV1QuotationsInvestorTradeByStockDailyRequest,
-    ) -> Result<DomesticStockV1QuotationsInvestorTradeByStockDailyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInvestorTradeByStockDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPTJ04160001", "https://openapi.koreainvestment.com:9443")
@@ -2302,10 +2607,14 @@ impl StockQuotations {
     /// 시장별 투자자매매동향(시세)[v1_국내주식-074]
     /// - TR_ID: Real=FHPTJ04030000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-investor-time-by-market
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_investor_time_by_market(
         &self,
         req: DomesticStockV1QuotationsInquireInvestorTimeByMarketRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireInvestorTimeByMarketResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireInvestorTimeByMarketResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPTJ04030000", "https://openapi.koreainvestment.com:9443")
@@ -2325,10 +2634,14 @@ impl StockQuotations {
     /// 시장별 투자자매매동향(일별) [국내주식-075]
     /// - TR_ID: Real=FHPTJ04040000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-investor-daily-by-market
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_investor_daily_by_market(
         &self,
         req: DomesticStockV1QuotationsInquireInvestorDailyByMarketRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireInvestorDailyByMarketResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireInvestorDailyByMarketResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPTJ04040000", "https://openapi.koreainvestment.com:9443")
@@ -2348,10 +2661,14 @@ impl StockQuotations {
     /// 종목별 외국계 순매수추이 [국내주식-164]
     /// - TR_ID: Real=FHKST644400C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/frgnmem-pchs-trend
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_frgnmem_pchs_trend(
         &self,
         req: DomesticStockV1QuotationsFrgnmemPchsTrendRequest,
-    ) -> Result<DomesticStockV1QuotationsFrgnmemPchsTrendResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsFrgnmemPchsTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST644400C0", "https://openapi.koreainvestment.com:9443")
@@ -2371,10 +2688,14 @@ impl StockQuotations {
     /// 회원사 실시간 매매동향(틱) [국내주식-163]
     /// - TR_ID: Real=FHPST04320000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/frgnmem-trade-trend
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_frgnmem_trade_trend(
         &self,
         req: DomesticStockV1QuotationsFrgnmemTradeTrendRequest,
-    ) -> Result<DomesticStockV1QuotationsFrgnmemTradeTrendResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsFrgnmemTradeTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04320000", "https://openapi.koreainvestment.com:9443")
@@ -2394,10 +2715,14 @@ impl StockQuotations {
     /// 주식현재가 회원사 종목매매동향 [국내주식-197]
     /// - TR_ID: Real=FHPST04540000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-member-daily
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_member_daily(
         &self,
         req: DomesticStockV1QuotationsInquireMemberDailyRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireMemberDailyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireMemberDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04540000", "https://openapi.koreainvestment.com:9443")
@@ -2417,10 +2742,14 @@ impl StockQuotations {
     /// 종목별 프로그램매매추이(체결)[v1_국내주식-044]
     /// - TR_ID: Real=FHPPG04650101 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/program-trade-by-stock
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_program_trade_by_stock(
         &self,
         req: DomesticStockV1QuotationsProgramTradeByStockRequest,
-    ) -> Result<DomesticStockV1QuotationsProgramTradeByStockResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsProgramTradeByStockResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPPG04650101", "https://openapi.koreainvestment.com:9443")
@@ -2440,10 +2769,14 @@ impl StockQuotations {
     /// 종목별 프로그램매매추이(일별) [국내주식-113]
     /// - TR_ID: Real=FHPPG04650201 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/program-trade-by-stock-daily
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_program_trade_by_stock_daily(
         &self,
         req: DomesticStockV1QuotationsProgramTradeByStockDailyRequest,
-    ) -> Result<DomesticStockV1QuotationsProgramTradeByStockDailyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsProgramTradeByStockDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPPG04650201", "https://openapi.koreainvestment.com:9443")
@@ -2463,10 +2796,14 @@ impl StockQuotations {
     /// 종목별 외인기관 추정가집계[v1_국내주식-046]
     /// - TR_ID: Real=HHPTJ04160200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/investor-trend-estimate
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_investor_trend_estimate(
         &self,
         req: DomesticStockV1QuotationsInvestorTrendEstimateRequest,
-    ) -> Result<DomesticStockV1QuotationsInvestorTrendEstimateResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInvestorTrendEstimateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHPTJ04160200", "https://openapi.koreainvestment.com:9443")
@@ -2486,10 +2823,14 @@ impl StockQuotations {
     /// 종목별일별매수매도체결량 [v1_국내주식-056]
     /// - TR_ID: Real=FHKST03010800 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/inquire-daily-trade-volume
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_inquire_daily_trade_volume(
         &self,
         req: DomesticStockV1QuotationsInquireDailyTradeVolumeRequest,
-    ) -> Result<DomesticStockV1QuotationsInquireDailyTradeVolumeResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInquireDailyTradeVolumeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST03010800", "https://openapi.koreainvestment.com:9443")
@@ -2509,10 +2850,14 @@ impl StockQuotations {
     /// 프로그램매매 종합현황(시간) [국내주식-114]
     /// - TR_ID: Real=FHPPG04600101 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/comp-program-trade-today
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_comp_program_trade_today(
         &self,
         req: DomesticStockV1QuotationsCompProgramTradeTodayRequest,
-    ) -> Result<DomesticStockV1QuotationsCompProgramTradeTodayResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsCompProgramTradeTodayResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPPG04600101", "https://openapi.koreainvestment.com:9443")
@@ -2532,10 +2877,14 @@ impl StockQuotations {
     /// 프로그램매매 종합현황(일별)[국내주식-115]
     /// - TR_ID: Real=FHPPG04600001 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/comp-program-trade-daily
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_comp_program_trade_daily(
         &self,
         req: DomesticStockV1QuotationsCompProgramTradeDailyRequest,
-    ) -> Result<DomesticStockV1QuotationsCompProgramTradeDailyResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsCompProgramTradeDailyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPPG04600001", "https://openapi.koreainvestment.com:9443")
@@ -2555,10 +2904,14 @@ impl StockQuotations {
     /// 프로그램매매 투자자매매동향(당일) [국내주식-116]
     /// - TR_ID: Real=HHPPG046600C1 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/investor-program-trade-today
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_investor_program_trade_today(
         &self,
         req: DomesticStockV1QuotationsInvestorProgramTradeTodayRequest,
-    ) -> Result<DomesticStockV1QuotationsInvestorProgramTradeTodayResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsInvestorProgramTradeTodayResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHPPG046600C1", "https://openapi.koreainvestment.com:9443")
@@ -2578,10 +2931,14 @@ impl StockQuotations {
     /// 국내주식 신용잔고 일별추이[국내주식-110]
     /// - TR_ID: Real=FHPST04760000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/daily-credit-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_daily_credit_balance(
         &self,
         req: DomesticStockV1QuotationsDailyCreditBalanceRequest,
-    ) -> Result<DomesticStockV1QuotationsDailyCreditBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsDailyCreditBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04760000", "https://openapi.koreainvestment.com:9443")
@@ -2601,10 +2958,14 @@ impl StockQuotations {
     /// 국내주식 예상체결가 추이[국내주식-118]
     /// - TR_ID: Real=FHPST01810000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/exp-price-trend
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_exp_price_trend(
         &self,
         req: DomesticStockV1QuotationsExpPriceTrendRequest,
-    ) -> Result<DomesticStockV1QuotationsExpPriceTrendResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsExpPriceTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01810000", "https://openapi.koreainvestment.com:9443")
@@ -2624,10 +2985,14 @@ impl StockQuotations {
     /// 국내주식 공매도 일별추이[국내주식-134]
     /// - TR_ID: Real=FHPST04830000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/daily-short-sale
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_daily_short_sale(
         &self,
         req: DomesticStockV1QuotationsDailyShortSaleRequest,
-    ) -> Result<DomesticStockV1QuotationsDailyShortSaleResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsDailyShortSaleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04830000", "https://openapi.koreainvestment.com:9443")
@@ -2647,10 +3012,14 @@ impl StockQuotations {
     /// 국내주식 체결금액별 매매비중 [국내주식-192]
     /// - TR_ID: Real=FHKST111900C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/tradprt-byamt
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_tradprt_byamt(
         &self,
         req: DomesticStockV1QuotationsTradprtByamtRequest,
-    ) -> Result<DomesticStockV1QuotationsTradprtByamtResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsTradprtByamtResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST111900C0", "https://openapi.koreainvestment.com:9443")
@@ -2670,10 +3039,14 @@ impl StockQuotations {
     /// 국내 증시자금 종합 [국내주식-193]
     /// - TR_ID: Real=FHKST649100C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/mktfunds
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_mktfunds(
         &self,
         req: DomesticStockV1QuotationsMktfundsRequest,
-    ) -> Result<DomesticStockV1QuotationsMktfundsResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsMktfundsResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST649100C0", "https://openapi.koreainvestment.com:9443")
@@ -2693,10 +3066,14 @@ impl StockQuotations {
     /// 종목별 일별 대차거래추이 [국내주식-135]
     /// - TR_ID: Real=HHPST074500C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/daily-loan-trans
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_daily_loan_trans(
         &self,
         req: DomesticStockV1QuotationsDailyLoanTransRequest,
-    ) -> Result<DomesticStockV1QuotationsDailyLoanTransResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsDailyLoanTransResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHPST074500C0", "https://openapi.koreainvestment.com:9443")
@@ -2716,10 +3093,14 @@ impl StockQuotations {
     /// 국내주식 상하한가 포착 [국내주식-190]
     /// - TR_ID: Real=FHKST130000C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/capture-uplowprice
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_capture_uplowprice(
         &self,
         req: DomesticStockV1QuotationsCaptureUplowpriceRequest,
-    ) -> Result<DomesticStockV1QuotationsCaptureUplowpriceResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsCaptureUplowpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST130000C0", "https://openapi.koreainvestment.com:9443")
@@ -2739,10 +3120,14 @@ impl StockQuotations {
     /// 국내주식 매물대/거래비중 [국내주식-196]
     /// - TR_ID: Real=FHPST01130000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/pbar-tratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_pbar_tratio(
         &self,
         req: DomesticStockV1QuotationsPbarTratioRequest,
-    ) -> Result<DomesticStockV1QuotationsPbarTratioResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsPbarTratioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01130000", "https://openapi.koreainvestment.com:9443")
@@ -2762,10 +3147,14 @@ impl StockQuotations {
     /// 거래량순위[v1_국내주식-047]
     /// - TR_ID: Real=FHPST01710000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/quotations/volume-rank
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_quotations_volume_rank(
         &self,
         req: DomesticStockV1QuotationsVolumeRankRequest,
-    ) -> Result<DomesticStockV1QuotationsVolumeRankResponse, KisError> {
+    ) -> Result<DomesticStockV1QuotationsVolumeRankResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01710000", "https://openapi.koreainvestment.com:9443")
@@ -2785,10 +3174,14 @@ impl StockQuotations {
     /// 선물옵션 증거금률
     /// - TR_ID: Real=TTTO6032R / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/margin-rate
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_margin_rate(
         &self,
         req: DomesticFutureoptionV1QuotationsMarginRateRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsMarginRateResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsMarginRateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("TTTO6032R", "https://openapi.koreainvestment.com:9443")
@@ -2808,6 +3201,7 @@ impl StockQuotations {
     /// 선물옵션 시세[v1_국내선물-006]
     /// - TR_ID: Real=FHMIF10000000 / VTS=FHMIF10000000
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/inquire-price
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_inquire_price(
         &self,
         req: DomesticFutureoptionV1QuotationsInquirePriceRequest,
@@ -2834,6 +3228,7 @@ impl StockQuotations {
     /// 선물옵션 시세호가[v1_국내선물-007]
     /// - TR_ID: Real=FHMIF10010000 / VTS=FHMIF10010000
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/inquire-asking-price
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_inquire_asking_price(
         &self,
         req: DomesticFutureoptionV1QuotationsInquireAskingPriceRequest,
@@ -2860,6 +3255,7 @@ impl StockQuotations {
     /// 선물옵션기간별시세(일/주/월/년)[v1_국내선물-008]
     /// - TR_ID: Real=FHKIF03020100 / VTS=FHKIF03020100
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/inquire-daily-fuopchartprice
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_inquire_daily_fuopchartprice(
         &self,
         req: DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceRequest,
@@ -2886,10 +3282,14 @@ impl StockQuotations {
     /// 선물옵션 분봉조회[v1_국내선물-012]
     /// - TR_ID: Real=FHKIF03020200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/inquire-time-fuopchartprice
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_inquire_time_fuopchartprice(
         &self,
         req: DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKIF03020200", "https://openapi.koreainvestment.com:9443")
@@ -2909,10 +3309,14 @@ impl StockQuotations {
     /// 국내옵션전광판_옵션월물리스트[국내선물-020]
     /// - TR_ID: Real=FHPIO056104C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/display-board-option-list
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_display_board_option_list(
         &self,
         req: DomesticFutureoptionV1QuotationsDisplayBoardOptionListRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardOptionListResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardOptionListResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPIO056104C0", "https://openapi.koreainvestment.com:9443")
@@ -2932,10 +3336,14 @@ impl StockQuotations {
     /// 국내선물 기초자산 시세[국내선물-021]
     /// - TR_ID: Real=FHPIF05030000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/display-board-top
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_display_board_top(
         &self,
         req: DomesticFutureoptionV1QuotationsDisplayBoardTopRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardTopResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardTopResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPIF05030000", "https://openapi.koreainvestment.com:9443")
@@ -2955,10 +3363,14 @@ impl StockQuotations {
     /// 국내옵션전광판_콜풋[국내선물-022]
     /// - TR_ID: Real=FHPIF05030100 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/display-board-callput
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_display_board_callput(
         &self,
         req: DomesticFutureoptionV1QuotationsDisplayBoardCallputRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardCallputResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardCallputResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPIF05030100", "https://openapi.koreainvestment.com:9443")
@@ -2978,10 +3390,14 @@ impl StockQuotations {
     /// 국내옵션전광판_선물[국내선물-023]
     /// - TR_ID: Real=FHPIF05030200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/display-board-futures
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_display_board_futures(
         &self,
         req: DomesticFutureoptionV1QuotationsDisplayBoardFuturesRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardFuturesResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsDisplayBoardFuturesResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPIF05030200", "https://openapi.koreainvestment.com:9443")
@@ -3001,10 +3417,14 @@ impl StockQuotations {
     /// 선물옵션 일중예상체결추이[국내선물-018]
     /// - TR_ID: Real=FHPIF05110100 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-futureoption/v1/quotations/exp-price-trend
+    #[cfg(feature = "domestic-futureoption")]
     pub async fn domestic_futureoption_v1_quotations_exp_price_trend(
         &self,
         req: DomesticFutureoptionV1QuotationsExpPriceTrendRequest,
-    ) -> Result<DomesticFutureoptionV1QuotationsExpPriceTrendResponse, KisError> {
+    ) -> Result<DomesticFutureoptionV1QuotationsExpPriceTrendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPIF05110100", "https://openapi.koreainvestment.com:9443")
@@ -3023,14 +3443,18 @@ impl StockQuotations {
 }
 
 #[allow(non_snake_case)]
-impl StockCommon {
+impl<E> StockCommon<E> {
     /// 국내주식 대차대조표[v1_국내주식-078]
     /// - TR_ID: Real=FHKST66430100 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/balance-sheet
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_balance_sheet(
         &self,
         req: DomesticStockV1FinanceBalanceSheetRequest,
-    ) -> Result<DomesticStockV1FinanceBalanceSheetResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceBalanceSheetResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430100", "https://openapi.koreainvestment.com:9443")
@@ -3050,10 +3474,14 @@ impl StockCommon {
     /// 국내주식 손익계산서[v1_국내주식-079]
     /// - TR_ID: Real=FHKST66430200 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/income-statement
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_income_statement(
         &self,
         req: DomesticStockV1FinanceIncomeStatementRequest,
-    ) -> Result<DomesticStockV1FinanceIncomeStatementResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceIncomeStatementResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430200", "https://openapi.koreainvestment.com:9443")
@@ -3073,10 +3501,14 @@ impl StockCommon {
     /// 국내주식 재무비율[v1_국내주식-080]
     /// - TR_ID: Real=FHKST66430300 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/financial-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_financial_ratio(
         &self,
         req: DomesticStockV1FinanceFinancialRatioRequest,
-    ) -> Result<DomesticStockV1FinanceFinancialRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceFinancialRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430300", "https://openapi.koreainvestment.com:9443")
@@ -3096,10 +3528,14 @@ impl StockCommon {
     /// 국내주식 수익성비율[v1_국내주식-081]
     /// - TR_ID: Real=FHKST66430400 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/profit-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_profit_ratio(
         &self,
         req: DomesticStockV1FinanceProfitRatioRequest,
-    ) -> Result<DomesticStockV1FinanceProfitRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceProfitRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430400", "https://openapi.koreainvestment.com:9443")
@@ -3119,10 +3555,14 @@ impl StockCommon {
     /// 국내주식 기타주요비율[v1_국내주식-082]
     /// - TR_ID: Real=FHKST66430500 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/other-major-ratios
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_other_major_ratios(
         &self,
         req: DomesticStockV1FinanceOtherMajorRatiosRequest,
-    ) -> Result<DomesticStockV1FinanceOtherMajorRatiosResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceOtherMajorRatiosResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430500", "https://openapi.koreainvestment.com:9443")
@@ -3142,10 +3582,14 @@ impl StockCommon {
     /// 국내주식 안정성비율[v1_국내주식-083]
     /// - TR_ID: Real=FHKST66430600 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/stability-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_stability_ratio(
         &self,
         req: DomesticStockV1FinanceStabilityRatioRequest,
-    ) -> Result<DomesticStockV1FinanceStabilityRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceStabilityRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430600", "https://openapi.koreainvestment.com:9443")
@@ -3165,10 +3609,14 @@ impl StockCommon {
     /// 국내주식 성장성비율[v1_국내주식-085]
     /// - TR_ID: Real=FHKST66430800 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/finance/growth-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_finance_growth_ratio(
         &self,
         req: DomesticStockV1FinanceGrowthRatioRequest,
-    ) -> Result<DomesticStockV1FinanceGrowthRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1FinanceGrowthRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST66430800", "https://openapi.koreainvestment.com:9443")
@@ -3188,10 +3636,14 @@ impl StockCommon {
     /// 예탁원정보(배당일정)[국내주식-145]
     /// - TR_ID: Real=HHKDB669102C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/dividend
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_dividend(
         &self,
         req: DomesticStockV1KsdinfoDividendRequest,
-    ) -> Result<DomesticStockV1KsdinfoDividendResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoDividendResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669102C0", "https://openapi.koreainvestment.com:9443")
@@ -3211,10 +3663,14 @@ impl StockCommon {
     /// 예탁원정보(주식매수청구일정)[국내주식-146]
     /// - TR_ID: Real=HHKDB669103C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/purreq
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_purreq(
         &self,
         req: DomesticStockV1KsdinfoPurreqRequest,
-    ) -> Result<DomesticStockV1KsdinfoPurreqResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoPurreqResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669103C0", "https://openapi.koreainvestment.com:9443")
@@ -3234,10 +3690,14 @@ impl StockCommon {
     /// 예탁원정보(합병/분할일정)[국내주식-147]
     /// - TR_ID: Real=HHKDB669104C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/merger-split
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_merger_split(
         &self,
         req: DomesticStockV1KsdinfoMergerSplitRequest,
-    ) -> Result<DomesticStockV1KsdinfoMergerSplitResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoMergerSplitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669104C0", "https://openapi.koreainvestment.com:9443")
@@ -3257,10 +3717,14 @@ impl StockCommon {
     /// 예탁원정보(액면교체일정)[국내주식-148]
     /// - TR_ID: Real=HHKDB669105C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/rev-split
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_rev_split(
         &self,
         req: DomesticStockV1KsdinfoRevSplitRequest,
-    ) -> Result<DomesticStockV1KsdinfoRevSplitResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoRevSplitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669105C0", "https://openapi.koreainvestment.com:9443")
@@ -3280,10 +3744,14 @@ impl StockCommon {
     /// 예탁원정보(자본감소일정)[국내주식-149]
     /// - TR_ID: Real=HHKDB669106C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/cap-dcrs
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_cap_dcrs(
         &self,
         req: DomesticStockV1KsdinfoCapDcrsRequest,
-    ) -> Result<DomesticStockV1KsdinfoCapDcrsResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoCapDcrsResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669106C0", "https://openapi.koreainvestment.com:9443")
@@ -3303,10 +3771,14 @@ impl StockCommon {
     /// 예탁원정보(상장정보일정)[국내주식-150]
     /// - TR_ID: Real=HHKDB669107C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/list-info
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_list_info(
         &self,
         req: DomesticStockV1KsdinfoListInfoRequest,
-    ) -> Result<DomesticStockV1KsdinfoListInfoResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoListInfoResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669107C0", "https://openapi.koreainvestment.com:9443")
@@ -3326,10 +3798,14 @@ impl StockCommon {
     /// 예탁원정보(공모주청약일정)[국내주식-151]
     /// - TR_ID: Real=HHKDB669108C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/pub-offer
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_pub_offer(
         &self,
         req: DomesticStockV1KsdinfoPubOfferRequest,
-    ) -> Result<DomesticStockV1KsdinfoPubOfferResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoPubOfferResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669108C0", "https://openapi.koreainvestment.com:9443")
@@ -3349,10 +3825,14 @@ impl StockCommon {
     /// 예탁원정보(실권주일정)[국내주식-152]
     /// - TR_ID: Real=HHKDB669109C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/forfeit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_forfeit(
         &self,
         req: DomesticStockV1KsdinfoForfeitRequest,
-    ) -> Result<DomesticStockV1KsdinfoForfeitResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoForfeitResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669109C0", "https://openapi.koreainvestment.com:9443")
@@ -3372,10 +3852,14 @@ impl StockCommon {
     /// 예탁원정보(의무예치일정)[국내주식-153]
     /// - TR_ID: Real=HHKDB669110C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/mand-deposit
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_mand_deposit(
         &self,
         req: DomesticStockV1KsdinfoMandDepositRequest,
-    ) -> Result<DomesticStockV1KsdinfoMandDepositResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoMandDepositResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669110C0", "https://openapi.koreainvestment.com:9443")
@@ -3395,10 +3879,14 @@ impl StockCommon {
     /// 예탁원정보(유상증자일정) [국내주식-143]
     /// - TR_ID: Real=HHKDB669100C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/paidin-capin
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_paidin_capin(
         &self,
         req: DomesticStockV1KsdinfoPaidinCapinRequest,
-    ) -> Result<DomesticStockV1KsdinfoPaidinCapinResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoPaidinCapinResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669100C0", "https://openapi.koreainvestment.com:9443")
@@ -3418,10 +3906,14 @@ impl StockCommon {
     /// 예탁원정보(무상증자일정) [국내주식-144]
     /// - TR_ID: Real=HHKDB669101C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/bonus-issue
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_bonus_issue(
         &self,
         req: DomesticStockV1KsdinfoBonusIssueRequest,
-    ) -> Result<DomesticStockV1KsdinfoBonusIssueResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoBonusIssueResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669101C0", "https://openapi.koreainvestment.com:9443")
@@ -3441,10 +3933,14 @@ impl StockCommon {
     /// 예탁원정보(주주총회일정) [국내주식-154]
     /// - TR_ID: Real=HHKDB669111C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ksdinfo/sharehld-meet
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ksdinfo_sharehld_meet(
         &self,
         req: DomesticStockV1KsdinfoSharehldMeetRequest,
-    ) -> Result<DomesticStockV1KsdinfoSharehldMeetResponse, KisError> {
+    ) -> Result<DomesticStockV1KsdinfoSharehldMeetResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB669111C0", "https://openapi.koreainvestment.com:9443")
@@ -3463,14 +3959,18 @@ impl StockCommon {
 }
 
 #[allow(non_snake_case)]
-impl StockRanking {
+impl<E> StockRanking<E> {
     /// 국내주식 시간외예상체결등락률 [국내주식-140]
     /// - TR_ID: Real=FHKST11860000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/overtime-exp-trans-fluct
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_overtime_exp_trans_fluct(
         &self,
         req: DomesticStockV1RankingOvertimeExpTransFluctRequest,
-    ) -> Result<DomesticStockV1RankingOvertimeExpTransFluctResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingOvertimeExpTransFluctResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST11860000", "https://openapi.koreainvestment.com:9443")
@@ -3490,10 +3990,14 @@ impl StockRanking {
     /// 국내주식 등락률 순위[v1_국내주식-088]
     /// - TR_ID: Real=FHPST01700000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/fluctuation
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_fluctuation(
         &self,
         req: DomesticStockV1RankingFluctuationRequest,
-    ) -> Result<DomesticStockV1RankingFluctuationResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingFluctuationResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01700000", "https://openapi.koreainvestment.com:9443")
@@ -3513,10 +4017,14 @@ impl StockRanking {
     /// 국내주식 호가잔량 순위[국내주식-089]
     /// - TR_ID: Real=FHPST01720000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/quote-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_quote_balance(
         &self,
         req: DomesticStockV1RankingQuoteBalanceRequest,
-    ) -> Result<DomesticStockV1RankingQuoteBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingQuoteBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01720000", "https://openapi.koreainvestment.com:9443")
@@ -3536,10 +4044,14 @@ impl StockRanking {
     /// 국내주식 수익자산지표 순위[v1_국내주식-090]
     /// - TR_ID: Real=FHPST01730000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/profit-asset-index
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_profit_asset_index(
         &self,
         req: DomesticStockV1RankingProfitAssetIndexRequest,
-    ) -> Result<DomesticStockV1RankingProfitAssetIndexResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingProfitAssetIndexResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01730000", "https://openapi.koreainvestment.com:9443")
@@ -3559,10 +4071,14 @@ impl StockRanking {
     /// 국내주식 시가총액 상위[v1_국내주식-091]
     /// - TR_ID: Real=FHPST01740000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/market-cap
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_market_cap(
         &self,
         req: DomesticStockV1RankingMarketCapRequest,
-    ) -> Result<DomesticStockV1RankingMarketCapResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingMarketCapResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01740000", "https://openapi.koreainvestment.com:9443")
@@ -3582,10 +4098,14 @@ impl StockRanking {
     /// 국내주식 재무비율 순위[v1_국내주식-092]
     /// - TR_ID: Real=FHPST01750000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/finance-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_finance_ratio(
         &self,
         req: DomesticStockV1RankingFinanceRatioRequest,
-    ) -> Result<DomesticStockV1RankingFinanceRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingFinanceRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01750000", "https://openapi.koreainvestment.com:9443")
@@ -3605,10 +4125,14 @@ impl StockRanking {
     /// 국내주식 시간외잔량 순위[v1_국내주식-093]
     /// - TR_ID: Real=FHPST01760000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/after-hour-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_after_hour_balance(
         &self,
         req: DomesticStockV1RankingAfterHourBalanceRequest,
-    ) -> Result<DomesticStockV1RankingAfterHourBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingAfterHourBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01760000", "https://openapi.koreainvestment.com:9443")
@@ -3628,10 +4152,14 @@ impl StockRanking {
     /// 국내주식 우선주/괴리율 상위[v1_국내주식-094]
     /// - TR_ID: Real=FHPST01770000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/prefer-disparate-ratio
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_prefer_disparate_ratio(
         &self,
         req: DomesticStockV1RankingPreferDisparateRatioRequest,
-    ) -> Result<DomesticStockV1RankingPreferDisparateRatioResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingPreferDisparateRatioResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01770000", "https://openapi.koreainvestment.com:9443")
@@ -3651,10 +4179,14 @@ impl StockRanking {
     /// 국내주식 이격도 순위[v1_국내주식-095]
     /// - TR_ID: Real=FHPST01780000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/disparity
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_disparity(
         &self,
         req: DomesticStockV1RankingDisparityRequest,
-    ) -> Result<DomesticStockV1RankingDisparityResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingDisparityResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01780000", "https://openapi.koreainvestment.com:9443")
@@ -3674,10 +4206,14 @@ impl StockRanking {
     /// 국내주식 시장가치 순위[v1_국내주식-096]
     /// - TR_ID: Real=FHPST01790000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/market-value
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_market_value(
         &self,
         req: DomesticStockV1RankingMarketValueRequest,
-    ) -> Result<DomesticStockV1RankingMarketValueResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingMarketValueResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01790000", "https://openapi.koreainvestment.com:9443")
@@ -3697,10 +4233,14 @@ impl StockRanking {
     /// 국내주식 체결강도 상위[v1_국내주식-101]
     /// - TR_ID: Real=FHPST01680000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/volume-power
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_volume_power(
         &self,
         req: DomesticStockV1RankingVolumePowerRequest,
-    ) -> Result<DomesticStockV1RankingVolumePowerResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingVolumePowerResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01680000", "https://openapi.koreainvestment.com:9443")
@@ -3720,10 +4260,14 @@ impl StockRanking {
     /// 국내주식 관심종목등록 상위[v1_국내주식-102]
     /// - TR_ID: Real=FHPST01800000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/top-interest-stock
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_top_interest_stock(
         &self,
         req: DomesticStockV1RankingTopInterestStockRequest,
-    ) -> Result<DomesticStockV1RankingTopInterestStockResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingTopInterestStockResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01800000", "https://openapi.koreainvestment.com:9443")
@@ -3743,10 +4287,14 @@ impl StockRanking {
     /// 국내주식 예상체결 상승/하락상위[v1_국내주식-103]
     /// - TR_ID: Real=FHPST01820000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/exp-trans-updown
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_exp_trans_updown(
         &self,
         req: DomesticStockV1RankingExpTransUpdownRequest,
-    ) -> Result<DomesticStockV1RankingExpTransUpdownResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingExpTransUpdownResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01820000", "https://openapi.koreainvestment.com:9443")
@@ -3766,10 +4314,14 @@ impl StockRanking {
     /// 국내주식 당사매매종목 상위[v1_국내주식-104]
     /// - TR_ID: Real=FHPST01860000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/traded-by-company
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_traded_by_company(
         &self,
         req: DomesticStockV1RankingTradedByCompanyRequest,
-    ) -> Result<DomesticStockV1RankingTradedByCompanyResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingTradedByCompanyResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01860000", "https://openapi.koreainvestment.com:9443")
@@ -3789,10 +4341,14 @@ impl StockRanking {
     /// 국내주식 신고/신저근접종목 상위[v1_국내주식-105]
     /// - TR_ID: Real=FHPST01870000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/near-new-highlow
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_near_new_highlow(
         &self,
         req: DomesticStockV1RankingNearNewHighlowRequest,
-    ) -> Result<DomesticStockV1RankingNearNewHighlowResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingNearNewHighlowResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST01870000", "https://openapi.koreainvestment.com:9443")
@@ -3812,10 +4368,14 @@ impl StockRanking {
     /// 국내주식 배당률 상위[국내주식-106]
     /// - TR_ID: Real=HHKDB13470100 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/dividend-rate
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_dividend_rate(
         &self,
         req: DomesticStockV1RankingDividendRateRequest,
-    ) -> Result<DomesticStockV1RankingDividendRateResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingDividendRateResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHKDB13470100", "https://openapi.koreainvestment.com:9443")
@@ -3835,10 +4395,14 @@ impl StockRanking {
     /// 국내주식 대량체결건수 상위[국내주식-107]
     /// - TR_ID: Real=FHKST190900C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/bulk-trans-num
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_bulk_trans_num(
         &self,
         req: DomesticStockV1RankingBulkTransNumRequest,
-    ) -> Result<DomesticStockV1RankingBulkTransNumResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingBulkTransNumResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST190900C0", "https://openapi.koreainvestment.com:9443")
@@ -3858,10 +4422,14 @@ impl StockRanking {
     /// 국내주식 신용잔고 상위[국내주식-109]
     /// - TR_ID: Real=FHKST17010000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/credit-balance
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_credit_balance(
         &self,
         req: DomesticStockV1RankingCreditBalanceRequest,
-    ) -> Result<DomesticStockV1RankingCreditBalanceResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingCreditBalanceResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHKST17010000", "https://openapi.koreainvestment.com:9443")
@@ -3881,10 +4449,14 @@ impl StockRanking {
     /// 국내주식 공매도 상위종목[국내주식-133]
     /// - TR_ID: Real=FHPST04820000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/short-sale
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_short_sale(
         &self,
         req: DomesticStockV1RankingShortSaleRequest,
-    ) -> Result<DomesticStockV1RankingShortSaleResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingShortSaleResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST04820000", "https://openapi.koreainvestment.com:9443")
@@ -3904,10 +4476,14 @@ impl StockRanking {
     /// 국내주식 시간외등락율순위 [국내주식-138]
     /// - TR_ID: Real=FHPST02340000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/overtime-fluctuation
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_overtime_fluctuation(
         &self,
         req: DomesticStockV1RankingOvertimeFluctuationRequest,
-    ) -> Result<DomesticStockV1RankingOvertimeFluctuationResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingOvertimeFluctuationResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02340000", "https://openapi.koreainvestment.com:9443")
@@ -3927,10 +4503,14 @@ impl StockRanking {
     /// 국내주식 시간외거래량순위 [국내주식-139]
     /// - TR_ID: Real=FHPST02350000 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/overtime-volume
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_overtime_volume(
         &self,
         req: DomesticStockV1RankingOvertimeVolumeRequest,
-    ) -> Result<DomesticStockV1RankingOvertimeVolumeResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingOvertimeVolumeResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("FHPST02350000", "https://openapi.koreainvestment.com:9443")
@@ -3950,10 +4530,14 @@ impl StockRanking {
     /// HTS조회상위20종목 [국내주식-214]
     /// - TR_ID: Real=HHMCM000100C0 / VTS=모의투자 미지원
     /// - Endpoint: /uapi/domestic-stock/v1/ranking/hts-top-view
+    #[cfg(feature = "domestic-stock")]
     pub async fn domestic_stock_v1_ranking_hts_top_view(
         &self,
         req: (),
-    ) -> Result<DomesticStockV1RankingHtsTopViewResponse, KisError> {
+    ) -> Result<DomesticStockV1RankingHtsTopViewResponse, KisError>
+    where
+        E: RealOnly,
+    {
         let (tr_id, base_url) = match self.0.env() {
             crate::client::KisEnv::Real => {
                 ("HHMCM000100C0", "https://openapi.koreainvestment.com:9443")
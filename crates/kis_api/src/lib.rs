@@ -1,12 +1,48 @@
 pub mod auth;
+#[cfg(feature = "websocket")]
+pub mod bars;
 pub mod client;
+#[cfg(feature = "websocket")]
+mod delivery;
 pub mod endpoints;
 pub mod error;
+#[cfg(feature = "websocket")]
 pub mod event;
 pub mod generated;
 pub mod models;
+#[cfg(feature = "websocket")]
+pub mod orderbook;
+#[cfg(feature = "websocket")]
+pub mod realtime;
+#[cfg(feature = "recorder")]
+pub mod recorder;
+#[cfg(feature = "websocket")]
+pub mod stream;
 
-pub use client::{KisClient, KisEnv};
-pub use endpoints::{DomesticBond, Elw, EtfEtn, Overseas, OverseasFutureOption, Stock};
+pub use client::{AnyEnv, KisClient, KisEnv, Real, RealOnly, TypedEnv, Vts};
+#[cfg(feature = "bond")]
+pub use endpoints::DomesticBond;
+#[cfg(feature = "elw")]
+pub use endpoints::Elw;
+#[cfg(feature = "etfetn")]
+pub use endpoints::EtfEtn;
+#[cfg(feature = "overseas-stock")]
+pub use endpoints::Overseas;
+#[cfg(feature = "overseas-futureoption")]
+pub use endpoints::OverseasFutureOption;
+#[cfg(any(feature = "domestic-stock", feature = "domestic-futureoption"))]
+pub use endpoints::Stock;
 pub use error::KisError;
+#[cfg(feature = "websocket")]
+pub use event::{
+    ConnectionEvent, DerivativeProduct, DerivativeTradeData, ExpectedExecutionData, Greeks,
+    KisEvent, MarketPhase, MarketStatusEvent, MemberFlow, MemberTradingData, OrderMarket,
+    OrderNoticeKind, OrderSide, OverseasDerivativeTradeData, OverseasFeed, OverseasRealtimeKey,
+    ProgramTradingData, Venue, ViStatus,
+};
 pub use models::*;
+#[cfg(feature = "websocket")]
+pub use stream::{
+    ConnectionState, DeliveryMode, EventReceiver, GiveUp, KisStream, ReconnectPolicy, StreamStatus,
+    SubscribeAck, SubscriptionKind,
+};
@@ -1 +1,109 @@
 pub use crate::generated::models::*;
+use serde::Deserialize;
+
+/// 명세에 없는 응답 필드 보고.
+///
+/// 생성된 응답 모델은 스크래핑된 명세에 없는 키를 `extra`(flatten)에 보존한다.
+/// KIS가 예고 없이 필드를 추가하면 여기에 나타난다.
+pub trait UnknownFields {
+    /// `extra`에 담긴 필드 이름 목록. 출력 항목의 필드는 `output1.foo` 형태.
+    fn unknown_fields(&self) -> Vec<String>;
+}
+
+/// 문자열(`"12"`) 또는 숫자로 오는 정수 필드 역직렬화. 빈 문자열은 0.
+///
+/// KIS는 `*_cnt` 같은 정수 필드도 문자열로 내려주므로 생성된 모델의 `i64` 필드에 쓴다.
+#[allow(dead_code)] // 기능 조합에 따라 미사용
+pub(crate) fn i64_from_str<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Str(String),
+    }
+    match serde::Deserialize::deserialize(deserializer)? {
+        Raw::Int(n) => Ok(n),
+        Raw::Str(s) if s.trim().is_empty() => Ok(0),
+        Raw::Str(s) => s.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// 배열 그룹 역직렬화: 배열은 그대로, 단일 객체는 1건, `{}`/`null`은 빈 배열.
+///
+/// 응답 정규화는 `output1`/`output2`만 다루므로 bare `output`이 배열인 모델에 쓴다.
+#[allow(dead_code)] // 기능 조합에 따라 미사용
+pub(crate) fn vec_or_single<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        serde_json::Value::Null => vec![],
+        serde_json::Value::Object(obj) if obj.is_empty() => vec![],
+        other => vec![other],
+    };
+    items
+        .into_iter()
+        .map(|item| serde_json::from_value(item).map_err(serde::de::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_root_fields_are_preserved() {
+        let resp: Oauth2ApprovalResponse = serde_json::from_value(serde_json::json!({
+            "approval_key": "abc",
+            "new_field": "x"
+        }))
+        .unwrap();
+        assert_eq!(resp.approval_key, "abc");
+        assert_eq!(resp.extra["new_field"], "x");
+        assert_eq!(resp.unknown_fields(), vec!["new_field".to_string()]);
+    }
+
+    #[test]
+    fn known_fields_do_not_leak_into_extra() {
+        let resp: Oauth2ApprovalResponse =
+            serde_json::from_value(serde_json::json!({ "approval_key": "abc" })).unwrap();
+        assert!(resp.extra.is_empty());
+        assert!(resp.unknown_fields().is_empty());
+    }
+
+    #[cfg(feature = "domestic-stock")]
+    #[test]
+    fn unknown_output_item_fields_are_reported_with_group() {
+        let resp: DomesticStockV1TradingInquireBalanceResponse =
+            serde_json::from_value(serde_json::json!({
+                "rt_cd": "0",
+                "msg_cd": "MCA00000",
+                "msg1": "정상처리 되었습니다.",
+                "output1": [{ "pdno": "005930", "bfdy_buy_qty": "10", "new_col": "1" }],
+                "output2": []
+            }))
+            .unwrap();
+        assert_eq!(resp.output1[0].pdno, "005930");
+        assert_eq!(resp.output1[0].bfdy_buy_qty, rust_decimal_macros::dec!(10));
+        assert_eq!(resp.unknown_fields(), vec!["output1.new_col".to_string()]);
+    }
+
+    #[cfg(feature = "etfetn")]
+    #[test]
+    fn integer_fields_accept_kis_strings() {
+        let item: EtfetnV1QuotationsInquirePriceOutputItem =
+            serde_json::from_value(serde_json::json!({
+                "etf_cnfg_issu_cnt": "12",
+                "etf_cu_unit_scrt_cnt": ""
+            }))
+            .unwrap();
+        assert_eq!(item.etf_cnfg_issu_cnt, 12);
+        assert_eq!(item.etf_cu_unit_scrt_cnt, 0);
+    }
+}
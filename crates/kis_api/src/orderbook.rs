@@ -0,0 +1,577 @@
+//! REST 호가 스냅샷 + 실시간 호가로 유지하는 로컬 호가창.
+//!
+//! [`LocalOrderBook`]은 종목별 호가를 REST 조회 결과로 채운 뒤 실시간 `OrderBook` 이벤트
+//! (H0STASP0, HDFSASP0 등)로 갱신한다. 실시간 호가는 매번 전체 단계를 보내므로 갱신은
+//! 교체다. 갱신이 `stale_after` 동안 없거나 연결이 끊기면 오래된 호가로 표시한다.
+//!
+//! ```no_run
+//! # async fn run(client: kis_api::KisClient, stream: kis_api::KisStream) -> Result<(), kis_api::KisError> {
+//! use kis_api::orderbook::{domestic_snapshot, LocalOrderBook};
+//! use kis_api::{DomesticStockV1QuotationsInquireAskingPriceExpCcnRequest, OrderSide};
+//!
+//! let books = LocalOrderBook::new();
+//! books.spawn(stream.receiver());
+//! let resp = client
+//!     .stock()
+//!     .quotations()
+//!     .domestic_stock_v1_quotations_inquire_asking_price_exp_ccn(
+//!         DomesticStockV1QuotationsInquireAskingPriceExpCcnRequest {
+//!             fid_cond_mrkt_div_code: "J".into(),
+//!             fid_input_iscd: "005930".into(),
+//!         },
+//!     )
+//!     .await?;
+//! if let Some(snapshot) = domestic_snapshot("005930", &resp) {
+//!     books.seed(snapshot);
+//! }
+//!
+//! if let Some(book) = books.book("005930").filter(|b| !b.stale) {
+//!     println!("mid {:?}, 100주 매수 평균가 {:?}", book.mid(), book.vwap(OrderSide::Buy, 100.into()));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use rust_decimal::Decimal;
+use tokio::time::Instant;
+
+use crate::error::KisError;
+use crate::event::{
+    ConnectionEvent, KisEvent, OrderBookData, OrderSide, OverseasRealtimeKey, PriceLevel,
+};
+use crate::stream::EventReceiver;
+#[cfg(any(feature = "domestic-stock", feature = "overseas-stock"))]
+use crate::stream::{decimal_or_zero, price_levels};
+
+/// 실시간 갱신이 이 시간 동안 없으면 오래된 호가로 본다
+const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(10);
+
+/// 호가의 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSource {
+    /// REST 조회 스냅샷
+    Snapshot,
+    /// 실시간 호가
+    Realtime,
+}
+
+/// 호가창 키. 국내는 종목코드, 해외는 거래소 + 종목코드다.
+///
+/// 해외 종목은 거래소마다 호가가 다르므로(예: 미국 정규장과 주간거래) 따로 유지한다.
+/// 지연·실시간 시세 구분은 보지 않는다. `&str`(종목코드)이나 [`OverseasRealtimeKey`]에서 만든다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookKey {
+    exchange: Option<String>,
+    symbol: String,
+}
+
+impl BookKey {
+    fn of(book: &OrderBookData) -> Self {
+        match &book.overseas_key {
+            Some(key) => key.into(),
+            None => book.symbol.as_str().into(),
+        }
+    }
+
+    /// 구독 `tr_key` (종목코드 또는 `DNASAAPL` 형식 해외 실시간 키)
+    fn from_tr_key(tr_key: &str) -> Self {
+        match tr_key.parse::<OverseasRealtimeKey>() {
+            Ok(key) => (&key).into(),
+            Err(_) => tr_key.into(),
+        }
+    }
+}
+
+impl From<&str> for BookKey {
+    fn from(symbol: &str) -> Self {
+        Self {
+            exchange: None,
+            symbol: symbol.to_string(),
+        }
+    }
+}
+
+impl From<&OverseasRealtimeKey> for BookKey {
+    fn from(key: &OverseasRealtimeKey) -> Self {
+        Self {
+            exchange: Some(key.exchange.clone()),
+            symbol: key.ticker.clone(),
+        }
+    }
+}
+
+struct BookState {
+    data: OrderBookData,
+    source: BookSource,
+    updated_at: Instant,
+    /// 연결이 끊겨 다음 실시간 갱신 전까지 믿을 수 없음
+    interrupted: bool,
+}
+
+/// 호가창 조회 결과
+#[derive(Debug, Clone)]
+pub struct BookSnapshot {
+    pub symbol: String,
+    /// 매도호가, 1호가부터
+    pub asks: Vec<PriceLevel>,
+    /// 매수호가, 1호가부터
+    pub bids: Vec<PriceLevel>,
+    /// 거래소 호가 시각
+    pub time: DateTime<FixedOffset>,
+    pub source: BookSource,
+    /// 마지막 갱신 후 지난 시간
+    pub age: Duration,
+    /// 갱신이 끊겼거나 연결이 끊긴 뒤 갱신되지 않음. 주문 판단에 쓰지 않는다
+    pub stale: bool,
+}
+
+impl BookSnapshot {
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.first().copied()
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.first().copied()
+    }
+
+    /// 중간가 ((매도 1호가 + 매수 1호가) / 2)
+    pub fn mid(&self) -> Option<Decimal> {
+        Some((self.best_ask()?.price + self.best_bid()?.price) / Decimal::TWO)
+    }
+
+    /// 매도 1호가 - 매수 1호가
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// 상위 `depth`단계 잔량 불균형 ((매수 - 매도) / (매수 + 매도), -1 ~ 1). 잔량이 없으면 `None`
+    pub fn imbalance(&self, depth: usize) -> Option<Decimal> {
+        let sum = |levels: &[PriceLevel]| -> Decimal {
+            levels.iter().take(depth).map(|level| level.qty).sum()
+        };
+        let (bid, ask) = (sum(&self.bids), sum(&self.asks));
+        let total = bid + ask;
+        (!total.is_zero()).then(|| (bid - ask) / total)
+    }
+
+    /// `qty`를 시장가로 체결할 때의 평균 단가. 매수는 매도호가, 매도는 매수호가를 소진한다.
+    /// 보이는 잔량으로 다 채울 수 없으면 `None`
+    pub fn vwap(&self, side: OrderSide, qty: Decimal) -> Option<Decimal> {
+        if qty <= Decimal::ZERO {
+            return None;
+        }
+        let levels = match side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+        };
+        let mut remaining = qty;
+        let mut notional = Decimal::ZERO;
+        for level in levels {
+            let take = remaining.min(level.qty);
+            notional += take * level.price;
+            remaining -= take;
+            if remaining.is_zero() {
+                return Some(notional / qty);
+            }
+        }
+        None
+    }
+}
+
+/// 종목별 로컬 호가창. 복제해도 같은 호가창을 공유한다.
+///
+/// 해외 호가는 거래소별로 유지하므로([`BookKey`]) 실시간 키로 조회한다.
+#[derive(Clone)]
+pub struct LocalOrderBook {
+    books: Arc<RwLock<HashMap<BookKey, BookState>>>,
+    stale_after: Duration,
+}
+
+impl Default for LocalOrderBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalOrderBook {
+    pub fn new() -> Self {
+        Self {
+            books: Arc::new(RwLock::new(HashMap::new())),
+            stale_after: DEFAULT_STALE_AFTER,
+        }
+    }
+
+    /// 갱신이 없을 때 오래된 호가로 보는 시간 (기본 10초)
+    pub fn stale_after(mut self, duration: Duration) -> Self {
+        self.stale_after = duration;
+        self
+    }
+
+    /// REST 스냅샷으로 채운다. 실시간 호가가 갱신되고 있는 종목은 건드리지 않고,
+    /// 실시간 호가가 끊겼거나 오래됐으면 스냅샷으로 바꾼다
+    pub fn seed(&self, book: OrderBookData) {
+        let key = BookKey::of(&book);
+        let mut books = self.books.write().unwrap();
+        if books.get(&key).is_some_and(|state| {
+            state.source == BookSource::Realtime && !self.is_state_stale(state)
+        }) {
+            return;
+        }
+        books.insert(
+            key,
+            BookState {
+                data: book,
+                source: BookSource::Snapshot,
+                updated_at: Instant::now(),
+                interrupted: false,
+            },
+        );
+    }
+
+    /// 이벤트 반영. 실시간 호가는 종목 호가를 교체하고, 연결이 끊기면 그 세션에 구독된
+    /// 종목의 호가를 오래된 것으로 표시한다. 다른 세션의 호가는 그대로 둔다
+    pub fn apply(&self, event: &KisEvent) {
+        match event {
+            KisEvent::OrderBook(book) => {
+                self.books.write().unwrap().insert(
+                    BookKey::of(book),
+                    BookState {
+                        data: book.clone(),
+                        source: BookSource::Realtime,
+                        updated_at: Instant::now(),
+                        interrupted: false,
+                    },
+                );
+            }
+            // 재연결 포기(`GaveUp`)는 앞선 `Disconnected`로 이미 표시됐다
+            KisEvent::Connection(ConnectionEvent::Disconnected { tr_keys, .. }) => {
+                let mut books = self.books.write().unwrap();
+                for key in tr_keys {
+                    if let Some(state) = books.get_mut(&BookKey::from_tr_key(key)) {
+                        state.interrupted = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 모든 호가를 다음 실시간 갱신 전까지 오래된 것으로 표시
+    fn interrupt(&self) {
+        for state in self.books.write().unwrap().values_mut() {
+            state.interrupted = true;
+        }
+    }
+
+    fn is_state_stale(&self, state: &BookState) -> bool {
+        state.interrupted || state.updated_at.elapsed() >= self.stale_after
+    }
+
+    /// 종목 호가 조회. 국내는 종목코드, 해외는 [`OverseasRealtimeKey`]로 찾는다
+    pub fn book(&self, key: impl Into<BookKey>) -> Option<BookSnapshot> {
+        let books = self.books.read().unwrap();
+        let state = books.get(&key.into())?;
+        let age = state.updated_at.elapsed();
+        Some(BookSnapshot {
+            symbol: state.data.symbol.clone(),
+            asks: state.data.asks.clone(),
+            bids: state.data.bids.clone(),
+            time: state.data.time,
+            source: state.source,
+            age,
+            stale: self.is_state_stale(state),
+        })
+    }
+
+    /// 호가가 없거나 오래됐는지
+    pub fn is_stale(&self, key: impl Into<BookKey>) -> bool {
+        self.book(key).is_none_or(|book| book.stale)
+    }
+
+    /// `events`를 소비하며 호가를 갱신하는 태스크를 띄운다. 스트림이 닫히면 모든 호가를 오래된 것으로 표시하고 끝난다
+    pub fn spawn(&self, mut events: EventReceiver) -> tokio::task::JoinHandle<()> {
+        let books = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => books.apply(&event),
+                    // 실시간 호가는 매번 전체 단계라 다음 갱신이 놓친 것을 덮는다
+                    Err(KisError::Lagged(n)) => {
+                        tracing::warn!(target: "kis_api", "로컬 호가창이 이벤트 {n}건을 놓쳤습니다");
+                    }
+                    Err(_) => break,
+                }
+            }
+            books.interrupt();
+        })
+    }
+}
+
+/// 국내 주식현재가 호가/예상체결 결과 → 10단계 호가 스냅샷 (호가 접수 시간, 오늘 KST)
+#[cfg(feature = "domestic-stock")]
+pub fn domestic_snapshot(
+    symbol: &str,
+    response: &crate::models::DomesticStockV1QuotationsInquireAskingPriceExpCcnResponse,
+) -> Option<OrderBookData> {
+    let r = response.output1.first()?;
+    let asks = price_levels(
+        &[
+            &r.askp1, &r.askp2, &r.askp3, &r.askp4, &r.askp5, &r.askp6, &r.askp7, &r.askp8,
+            &r.askp9, &r.askp10,
+        ],
+        &[
+            &r.askp_rsqn1,
+            &r.askp_rsqn2,
+            &r.askp_rsqn3,
+            &r.askp_rsqn4,
+            &r.askp_rsqn5,
+            &r.askp_rsqn6,
+            &r.askp_rsqn7,
+            &r.askp_rsqn8,
+            &r.askp_rsqn9,
+            &r.askp_rsqn10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.bidp1, &r.bidp2, &r.bidp3, &r.bidp4, &r.bidp5, &r.bidp6, &r.bidp7, &r.bidp8,
+            &r.bidp9, &r.bidp10,
+        ],
+        &[
+            &r.bidp_rsqn1,
+            &r.bidp_rsqn2,
+            &r.bidp_rsqn3,
+            &r.bidp_rsqn4,
+            &r.bidp_rsqn5,
+            &r.bidp_rsqn6,
+            &r.bidp_rsqn7,
+            &r.bidp_rsqn8,
+            &r.bidp_rsqn9,
+            &r.bidp_rsqn10,
+        ],
+    );
+    let expected = response.output2.first();
+    Some(OrderBookData {
+        symbol: symbol.to_string(),
+        overseas_key: None,
+        asks,
+        bids,
+        total_ask_qty: decimal_or_zero(&r.total_askp_rsqn),
+        total_bid_qty: decimal_or_zero(&r.total_bidp_rsqn),
+        expected_price: expected
+            .and_then(|o| o.antc_cnpr.parse::<Decimal>().ok())
+            .filter(|p| !p.is_zero()),
+        expected_qty: expected.map(|o| o.antc_vol).filter(|q| !q.is_zero()),
+        session_code: None,
+        time: crate::stream::kst_at("", &r.aspr_acpt_hour)?,
+    })
+}
+
+/// 해외주식 현재가 호가 결과 → 10단계 호가 스냅샷.
+///
+/// 호가일시의 거래소 오프셋을 알 수 없어 시각은 조회 시각(UTC)으로 채운다.
+#[cfg(feature = "overseas-stock")]
+pub fn overseas_snapshot(
+    symbol: &str,
+    response: &crate::models::OverseasPriceV1QuotationsInquireAskingPriceResponse,
+) -> Option<OrderBookData> {
+    let summary = response.output1.first()?;
+    let r = response.output2.first()?;
+    let asks = price_levels(
+        &[
+            &r.pask1, &r.pask2, &r.pask3, &r.pask4, &r.pask5, &r.pask6, &r.pask7, &r.pask8,
+            &r.pask9, &r.pask10,
+        ],
+        &[
+            &r.vask1, &r.vask2, &r.vask3, &r.vask4, &r.vask5, &r.vask6, &r.vask7, &r.vask8,
+            &r.vask9, &r.vask10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.pbid1, &r.pbid2, &r.pbid3, &r.pbid4, &r.pbid5, &r.pbid6, &r.pbid7, &r.pbid8,
+            &r.pbid9, &r.pbid10,
+        ],
+        &[
+            &r.vbid1, &r.vbid2, &r.vbid3, &r.vbid4, &r.vbid5, &r.vbid6, &r.vbid7, &r.vbid8,
+            &r.vbid9, &r.vbid10,
+        ],
+    );
+    Some(OrderBookData {
+        symbol: symbol.to_string(),
+        overseas_key: summary.rsym.parse().ok(),
+        asks,
+        bids,
+        total_ask_qty: decimal_or_zero(&summary.avol),
+        total_bid_qty: decimal_or_zero(&summary.bvol),
+        expected_price: None,
+        expected_qty: None,
+        session_code: None,
+        time: chrono::Utc::now().fixed_offset(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, qty: Decimal) -> PriceLevel {
+        PriceLevel { price, qty }
+    }
+
+    fn book(asks: Vec<PriceLevel>, bids: Vec<PriceLevel>) -> OrderBookData {
+        OrderBookData {
+            symbol: "005930".into(),
+            overseas_key: None,
+            asks,
+            bids,
+            total_ask_qty: Decimal::ZERO,
+            total_bid_qty: Decimal::ZERO,
+            expected_price: None,
+            expected_qty: None,
+            session_code: None,
+            time: FixedOffset::east_opt(9 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(2026, 3, 20, 9, 30, 0)
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn snapshot_queries() {
+        let books = LocalOrderBook::new();
+        books.seed(book(
+            vec![level(dec!(75100), dec!(30)), level(dec!(75200), dec!(70))],
+            vec![level(dec!(75000), dec!(150)), level(dec!(74900), dec!(50))],
+        ));
+        let snapshot = books.book("005930").unwrap();
+        assert_eq!(snapshot.source, BookSource::Snapshot);
+        assert_eq!(snapshot.mid(), Some(dec!(75050)));
+        assert_eq!(snapshot.spread(), Some(dec!(100)));
+        // 1단계: (150 - 30) / 180
+        assert_eq!(snapshot.imbalance(1).unwrap().round_dp(4), dec!(0.6667));
+        assert_eq!(snapshot.imbalance(2).unwrap().round_dp(4), dec!(0.3333));
+        // 30주 @75100 + 20주 @75200
+        assert_eq!(snapshot.vwap(OrderSide::Buy, dec!(50)), Some(dec!(75140)));
+        assert_eq!(snapshot.vwap(OrderSide::Sell, dec!(100)), Some(dec!(75000)));
+        assert_eq!(snapshot.vwap(OrderSide::Buy, dec!(101)), None);
+        assert!(books.book("000660").is_none() && books.is_stale("000660"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn realtime_updates_replace_snapshot_and_go_stale() {
+        let (stream, injector) = crate::stream::KisStream::test_pair();
+        let books = LocalOrderBook::new().stale_after(Duration::from_secs(5));
+        books.spawn(stream.receiver());
+
+        books.seed(book(
+            vec![level(dec!(75100), dec!(1))],
+            vec![level(dec!(75000), dec!(1))],
+        ));
+        injector
+            .send(KisEvent::OrderBook(book(
+                vec![level(dec!(75200), dec!(10))],
+                vec![level(dec!(75100), dec!(20))],
+            )))
+            .await;
+        tokio::task::yield_now().await;
+        let snapshot = books.book("005930").unwrap();
+        assert_eq!(
+            (snapshot.source, snapshot.mid()),
+            (BookSource::Realtime, Some(dec!(75150)))
+        );
+        assert!(!snapshot.stale);
+
+        // 실시간 호가를 받은 뒤에는 늦게 도착한 REST 스냅샷이 덮어쓰지 않는다
+        books.seed(book(
+            vec![level(dec!(75100), dec!(1))],
+            vec![level(dec!(75000), dec!(1))],
+        ));
+        assert_eq!(books.book("005930").unwrap().mid(), Some(dec!(75150)));
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(books.is_stale("005930"));
+        // 실시간 호가가 오래됐으면 스냅샷으로 바꾼다
+        books.seed(book(
+            vec![level(dec!(75100), dec!(1))],
+            vec![level(dec!(75000), dec!(1))],
+        ));
+        let snapshot = books.book("005930").unwrap();
+        assert_eq!(
+            (snapshot.source, snapshot.mid(), snapshot.stale),
+            (BookSource::Snapshot, Some(dec!(75050)), false)
+        );
+        injector
+            .send(KisEvent::OrderBook(book(
+                vec![level(dec!(75200), dec!(10))],
+                vec![level(dec!(75100), dec!(20))],
+            )))
+            .await;
+        let mut other = book(
+            vec![level(dec!(180100), dec!(10))],
+            vec![level(dec!(180000), dec!(20))],
+        );
+        other.symbol = "000660".into();
+        injector.send(KisEvent::OrderBook(other)).await;
+        tokio::task::yield_now().await;
+        assert!(!books.is_stale("005930"));
+
+        // 연결이 끊기면 그 세션에 구독된 종목만 다음 갱신까지 오래된 호가
+        injector
+            .send(KisEvent::Connection(ConnectionEvent::Disconnected {
+                session: 0,
+                reason: "test".into(),
+                tr_keys: vec!["005930".into(), "DNASAAPL".into()],
+            }))
+            .await;
+        tokio::task::yield_now().await;
+        assert!(books.is_stale("005930"));
+        assert!(!books.is_stale("000660"));
+        // 끊긴 뒤에는 재조회한 스냅샷이 실시간 호가를 대신한다
+        books.seed(book(
+            vec![level(dec!(75300), dec!(1))],
+            vec![level(dec!(75200), dec!(1))],
+        ));
+        assert_eq!(books.book("005930").unwrap().mid(), Some(dec!(75250)));
+        assert!(!books.is_stale("005930"));
+    }
+
+    #[test]
+    fn overseas_books_are_kept_per_exchange() {
+        let books = LocalOrderBook::new();
+        let regular = OverseasRealtimeKey::realtime("NAS", "AAPL");
+        let daytime = OverseasRealtimeKey::delayed("BAQ", "AAPL");
+        for (key, bid) in [(&regular, dec!(190)), (&daytime, dec!(189))] {
+            let mut data = book(
+                vec![level(bid + dec!(1), dec!(1))],
+                vec![level(bid, dec!(1))],
+            );
+            data.symbol = "AAPL".into();
+            data.overseas_key = Some(key.clone());
+            books.seed(data);
+        }
+
+        assert_eq!(
+            books.book(&regular).unwrap().best_bid().unwrap().price,
+            dec!(190)
+        );
+        assert_eq!(
+            books.book(&daytime).unwrap().best_bid().unwrap().price,
+            dec!(189)
+        );
+        // 지연·실시간 시세 구분과 무관하게 같은 거래소 호가다
+        let delayed = OverseasRealtimeKey::delayed("NAS", "AAPL");
+        assert_eq!(
+            books.book(&delayed).unwrap().best_bid().unwrap().price,
+            dec!(190)
+        );
+        assert!(books.book("AAPL").is_none());
+    }
+}
@@ -0,0 +1,68 @@
+/// 실시간(WebSocket) TR 레코드 1건.
+///
+/// KIS 실시간 응답은 `암호화|TR_ID|건수|필드^필드^...` 형태이며, 필드는 명세 순서대로
+/// `^`로 구분된다. 각 TR의 응답 구조체(`Tryitout*Response`)에 대한 구현은
+/// `kis-openapi.yaml`에서 생성되므로 필드 인덱스가 명세와 항상 일치한다.
+pub trait RealtimeRecord: Sized {
+    /// 구독 TR ID (예: `H0STCNT0`)
+    const TR_ID: &'static str;
+    /// 레코드 1건의 필드 수
+    const FIELD_COUNT: usize;
+
+    /// 명세 순서의 필드 슬라이스에서 레코드 생성. 필드 수가 부족하면 `None`.
+    fn from_fields(fields: &[&str]) -> Option<Self>;
+
+    /// `^` 구분 문자열(레코드 1건) 파싱
+    fn parse(data: &str) -> Option<Self> {
+        let fields: Vec<&str> = data.split('^').collect();
+        Self::from_fields(&fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::*;
+
+    // KIS 개발가이드 H0STCNT0 응답 예시
+    const H0STCNT0_SAMPLE: &str = "005930^093354^71900^5^-100^-0.14^72023.83^72100^72400^71700^71900^71800^1^3052507^219853241700^5105^6937^1832^84.90^1366314^1159996^1^0.39^20.28^090020^5^-200^090820^5^-500^092619^2^200^20230612^20^N^65945^216924^1118750^2199206^0.05^2424142^125.92^0^^72100";
+
+    #[test]
+    fn parse_h0stcnt0_by_position() {
+        let r = TryitoutH0stcnt0Response::parse(H0STCNT0_SAMPLE).unwrap();
+        assert_eq!(r.mksc_shrn_iscd, "005930");
+        assert_eq!(r.stck_cntg_hour, "093354");
+        assert_eq!(r.stck_prpr, "71900");
+        assert_eq!(r.cntg_vol, "1");
+        assert_eq!(r.ccld_dvsn, "1");
+        assert_eq!(r.bsop_date, "20230612");
+        assert_eq!(r.vi_stnd_prc, "72100");
+    }
+
+    #[test]
+    fn field_count_matches_spec() {
+        assert_eq!(TryitoutH0stcnt0Response::TR_ID, "H0STCNT0");
+        assert_eq!(
+            H0STCNT0_SAMPLE.split('^').count(),
+            TryitoutH0stcnt0Response::FIELD_COUNT
+        );
+    }
+
+    #[test]
+    fn too_few_fields_returns_none() {
+        assert!(TryitoutH0stcnt0Response::parse("005930^093354^71900").is_none());
+        assert!(TryitoutHdfscnt0Response::parse("").is_none());
+    }
+
+    #[test]
+    fn parse_hdfscnt0_by_position() {
+        let mut fields = vec![""; TryitoutHdfscnt0Response::FIELD_COUNT];
+        fields[0] = "DNASNVDA";
+        fields[1] = "NVDA";
+        fields[11] = "134.20";
+        let r = TryitoutHdfscnt0Response::parse(&fields.join("^")).unwrap();
+        assert_eq!(r.rsym, "DNASNVDA");
+        assert_eq!(r.symb, "NVDA");
+        assert_eq!(r.last, "134.20");
+    }
+}
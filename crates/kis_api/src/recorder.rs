@@ -0,0 +1,406 @@
+//! 실시간 원본 프레임 기록·재생.
+//!
+//! [`KisStream::start_recording`]으로 수신한 텍스트 프레임을 수신 시각과 함께 gzip
+//! JSON Lines 파일에 이어 쓰고, [`Replayer`]로 같은 파싱·배포 경로를 거쳐 다시 흘려
+//! 보낸다. 구독 응답도 기록되므로 암호화 TR(체결통보)도 재생할 수 있다.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), kis_api::KisError> {
+//! use kis_api::recorder::{ReplaySpeed, Replayer};
+//!
+//! let replayer = Replayer::open("session.jsonl.gz")?.speed(ReplaySpeed::Accelerated(10.0));
+//! let mut rx = replayer.stream().receiver();
+//! tokio::spawn(replayer.run());
+//! while let Ok(event) = rx.recv().await {
+//!     println!("{event:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+use crate::error::KisError;
+use crate::stream::{FrameDecoder, KisStream, DEFAULT_EVENT_BUFFER};
+
+/// 기록 버퍼를 디스크로 내보내는 주기
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 기록된 원본 프레임 한 건
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawFrame {
+    /// 수신 시각
+    pub received_at: DateTime<Utc>,
+    /// 수신한 세션 번호 (복호화 키가 세션별이다)
+    pub session: usize,
+    /// 원본 텍스트 (PINGPONG·구독 응답 포함)
+    pub text: String,
+}
+
+/// 원본 프레임 기록기.
+///
+/// 파일을 열 때마다 새 gzip 멤버를 이어 붙인다. 비정상 종료로 끝이 잘린 파일도
+/// [`FrameReader`]가 잘린 지점까지 읽는다.
+pub struct FrameRecorder {
+    writer: GzEncoder<BufWriter<File>>,
+    last_flush: Instant,
+}
+
+impl FrameRecorder {
+    /// `path`를 추가 모드로 연다. 없으면 만든다.
+    pub fn append(path: impl AsRef<Path>) -> Result<Self, KisError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: GzEncoder::new(BufWriter::new(file), Compression::default()),
+            last_flush: Instant::now(),
+        })
+    }
+
+    /// 지금 수신한 프레임 기록
+    pub fn write(&mut self, session: usize, text: &str) -> Result<(), KisError> {
+        self.write_frame(&RawFrame {
+            received_at: Utc::now(),
+            session,
+            text: text.to_string(),
+        })
+    }
+
+    /// 프레임 기록. 버퍼는 1초마다 내보낸다.
+    pub fn write_frame(&mut self, frame: &RawFrame) -> Result<(), KisError> {
+        serde_json::to_writer(&mut self.writer, frame)?;
+        self.writer.write_all(b"\n")?;
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 버퍼를 디스크로 내보낸다
+    pub fn flush(&mut self) -> Result<(), KisError> {
+        self.writer.flush()?;
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+
+    /// gzip 멤버를 닫고 파일을 내보낸다
+    pub fn finish(self) -> Result<(), KisError> {
+        self.writer.finish()?.flush()?;
+        Ok(())
+    }
+}
+
+/// 수신 루프 밖에서 기록하는 전용 쓰기 스레드.
+///
+/// 수신 루프는 채널에 프레임을 넣기만 하므로 gzip 압축이나 디스크 지연이 시세 수신과
+/// 하트비트를 막지 않는다. 쓰기에 실패하면 스레드가 끝나고 이후 [`send`](Self::send)는
+/// `false`를 돌려준다.
+pub(crate) struct RecordingWriter {
+    tx: mpsc::Sender<RawFrame>,
+    thread: JoinHandle<Result<(), KisError>>,
+}
+
+impl RecordingWriter {
+    pub(crate) fn spawn(mut recorder: FrameRecorder) -> Result<Self, KisError> {
+        let (tx, rx) = mpsc::channel::<RawFrame>();
+        let thread = std::thread::Builder::new()
+            .name("kis-recorder".into())
+            .spawn(move || {
+                let result = loop {
+                    // 프레임이 뜸해도 버퍼가 오래 머물지 않게 주기적으로 내보낸다
+                    let written = match rx.recv_timeout(FLUSH_INTERVAL) {
+                        Ok(frame) => recorder.write_frame(&frame),
+                        Err(mpsc::RecvTimeoutError::Timeout) => recorder.flush(),
+                        Err(mpsc::RecvTimeoutError::Disconnected) => break recorder.finish(),
+                    };
+                    if let Err(e) = written {
+                        break Err(e);
+                    }
+                };
+                if let Err(e) = &result {
+                    tracing::warn!(target: "kis_api", "frame recording stopped: {e}");
+                }
+                result
+            })?;
+        Ok(Self { tx, thread })
+    }
+
+    /// 프레임을 쓰기 스레드로 넘긴다. 기다리지 않는다. 스레드가 멈췄으면 `false`
+    pub(crate) fn send(&self, frame: RawFrame) -> bool {
+        self.tx.send(frame).is_ok()
+    }
+
+    /// 남은 프레임을 모두 쓰고 gzip 멤버를 닫을 때까지 기다린다
+    pub(crate) fn finish(self) -> Result<(), KisError> {
+        drop(self.tx);
+        self.thread
+            .join()
+            .unwrap_or_else(|_| Err(std::io::Error::other("recorder thread panicked").into()))
+    }
+}
+
+/// 기록 파일 읽기. 파일 끝이 잘려 있으면 온전한 프레임까지만 돌려준다.
+pub struct FrameReader {
+    lines: std::io::Lines<BufReader<MultiGzDecoder<BufReader<File>>>>,
+}
+
+impl FrameReader {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, KisError> {
+        let file = File::open(path)?;
+        Ok(Self {
+            lines: BufReader::new(MultiGzDecoder::new(BufReader::new(file))).lines(),
+        })
+    }
+}
+
+impl Iterator for FrameReader {
+    type Item = Result<RawFrame, KisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.lines.next()? {
+            Ok(line) => Some(serde_json::from_str(&line).map_err(KisError::from)),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => None,
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+/// 재생 속도
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// 기록된 수신 간격 그대로
+    Original,
+    /// 수신 간격을 배율로 줄인다 (`2.0`이면 두 배 빠르게)
+    Accelerated(f64),
+    /// 기다리지 않고 최대한 빠르게
+    Max,
+}
+
+/// 기록 파일 재생기.
+///
+/// 실시간 수신 루프와 같은 파싱 경로로 이벤트를 만들어 [`KisStream`] 수신기에 배포한다.
+/// 재생이 끝나면 스트림이 닫혀 수신기는 `KisError::StreamClosed`를 받는다.
+pub struct Replayer {
+    frames: FrameReader,
+    speed: ReplaySpeed,
+    stream: KisStream,
+}
+
+impl Replayer {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, KisError> {
+        Ok(Self {
+            frames: FrameReader::open(path)?,
+            speed: ReplaySpeed::Original,
+            stream: KisStream::offline(DEFAULT_EVENT_BUFFER),
+        })
+    }
+
+    /// 재생 속도 지정 (기본 [`ReplaySpeed::Original`])
+    pub fn speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// 수신기를 만들 스트림. [`run`](Self::run) 전에 수신기를 만들어야 처음부터 받는다.
+    pub fn stream(&self) -> &KisStream {
+        &self.stream
+    }
+
+    /// 끝까지 재생하고 배포한 이벤트 수를 돌려준다.
+    pub async fn run(self) -> Result<usize, KisError> {
+        let Self {
+            frames,
+            speed,
+            stream,
+        } = self;
+        let mut decoder = FrameDecoder::default();
+        let mut origin: Option<(DateTime<Utc>, tokio::time::Instant)> = None;
+        let mut published = 0;
+
+        for frame in frames {
+            let frame = frame?;
+            let scale = match speed {
+                ReplaySpeed::Original => Some(1.0),
+                ReplaySpeed::Accelerated(factor) if factor > 0.0 => Some(1.0 / factor),
+                ReplaySpeed::Accelerated(_) | ReplaySpeed::Max => None,
+            };
+            if let Some(scale) = scale {
+                let (first_at, started) =
+                    *origin.get_or_insert((frame.received_at, tokio::time::Instant::now()));
+                let offset = (frame.received_at - first_at).to_std().unwrap_or_default();
+                tokio::time::sleep_until(started + offset.mul_f64(scale)).await;
+            }
+            for event in decoder.decode(frame.session, &frame.text, frame.received_at) {
+                stream.publish(event).await;
+                published += 1;
+            }
+        }
+        Ok(published)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KisEvent;
+    use crate::stream::DeliveryMode;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "kis-recorder-{name}-{}.jsonl.gz",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn trade_frame(price: &str) -> String {
+        let mut fields = vec![""; 46];
+        fields[0] = "005930";
+        fields[1] = "093000";
+        fields[2] = price;
+        fields[12] = "300";
+        fields[21] = "1";
+        format!("0|H0STCNT0|001|{}", fields.join("^"))
+    }
+
+    fn frame(ms: i64, text: String) -> RawFrame {
+        RawFrame {
+            received_at: DateTime::from_timestamp_millis(1_774_000_000_000 + ms).unwrap(),
+            session: 0,
+            text,
+        }
+    }
+
+    #[test]
+    fn appended_recordings_are_read_back_in_order() {
+        let path = temp_path("append");
+        let mut first = FrameRecorder::append(&path).unwrap();
+        first.write_frame(&frame(0, trade_frame("75400"))).unwrap();
+        first.finish().unwrap();
+        let mut second = FrameRecorder::append(&path).unwrap();
+        second
+            .write_frame(&frame(10, trade_frame("75500")))
+            .unwrap();
+        second.finish().unwrap();
+
+        let frames: Vec<_> = FrameReader::open(&path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            frames,
+            vec![
+                frame(0, trade_frame("75400")),
+                frame(10, trade_frame("75500"))
+            ]
+        );
+
+        // 끝이 잘린 파일은 온전한 프레임까지만 읽는다
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() - 4);
+        std::fs::write(&path, bytes).unwrap();
+        let frames: Vec<_> = FrameReader::open(&path).unwrap().collect();
+        assert!(frames.iter().all(Result::is_ok));
+        assert!(!frames.is_empty());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn recording_writer_writes_frames_off_thread() {
+        let path = temp_path("writer");
+        let writer = RecordingWriter::spawn(FrameRecorder::append(&path).unwrap()).unwrap();
+        assert!(writer.send(frame(0, trade_frame("75400"))));
+        assert!(writer.send(frame(10, trade_frame("75500"))));
+        writer.finish().unwrap();
+
+        let frames: Vec<_> = FrameReader::open(&path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            frames,
+            vec![
+                frame(0, trade_frame("75400")),
+                frame(10, trade_frame("75500"))
+            ]
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn replayed_market_status_keeps_recorded_time() {
+        let path = temp_path("status");
+        let mut recorder = FrameRecorder::append(&path).unwrap();
+        let recorded = frame(0, "0|H0STMKO0|001|005930^Y^^110^^^^51^1^N^1".into());
+        recorder.write_frame(&recorded).unwrap();
+        recorder.finish().unwrap();
+
+        let replayer = Replayer::open(&path).unwrap().speed(ReplaySpeed::Max);
+        let mut rx = replayer
+            .stream()
+            .receiver_with(DeliveryMode::Bounded(8), |_| true);
+        let handle = tokio::spawn(replayer.run());
+        let Ok(KisEvent::MarketStatus(status)) = rx.recv().await else {
+            panic!("expected MarketStatus");
+        };
+        assert!(status.halted);
+        assert_eq!(status.time, recorded.received_at);
+        assert_eq!(status.time.offset().local_minus_utc(), 9 * 3600);
+        assert_eq!(handle.await.unwrap().unwrap(), 1);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn replay_follows_recorded_timing() {
+        let path = temp_path("replay");
+        let mut recorder = FrameRecorder::append(&path).unwrap();
+        recorder
+            .write_frame(&frame(0, r#"{"header":{"tr_id":"PINGPONG"}}"#.into()))
+            .unwrap();
+        recorder
+            .write_frame(&frame(0, trade_frame("75400")))
+            .unwrap();
+        recorder
+            .write_frame(&frame(4_000, trade_frame("75500")))
+            .unwrap();
+        recorder.finish().unwrap();
+
+        let replayer = Replayer::open(&path)
+            .unwrap()
+            .speed(ReplaySpeed::Accelerated(2.0));
+        let mut rx = replayer
+            .stream()
+            .receiver_with(DeliveryMode::Bounded(8), |_| true);
+        let started = tokio::time::Instant::now();
+        let handle = tokio::spawn(replayer.run());
+
+        let mut prices = Vec::new();
+        while let Ok(event) = rx.recv().await {
+            if let KisEvent::Transaction(tx) = event {
+                prices.push(tx.price);
+            }
+        }
+        assert_eq!(handle.await.unwrap().unwrap(), 2);
+        assert_eq!(
+            prices,
+            vec![
+                rust_decimal_macros::dec!(75400),
+                rust_decimal_macros::dec!(75500)
+            ]
+        );
+        // 4초 간격을 두 배 빠르게
+        assert_eq!(started.elapsed(), Duration::from_secs(2));
+        let _ = std::fs::remove_file(&path);
+    }
+}
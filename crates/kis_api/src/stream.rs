@@ -2,16 +2,145 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures_util::{SinkExt, StreamExt};
-use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
-use crate::{KisConfig, KisError, KisEvent};
+pub use crate::delivery::{DeliveryMode, EventReceiver, BOUNDED_STALL_LIMIT};
+use crate::delivery::{EventFilter, Fanout};
+use crate::error::KisError;
+use crate::event::{
+    ConnectionEvent, DerivativeProduct, DerivativeTradeData, ExpectedExecutionData, Greeks,
+    KisEvent, MarketPhase, MarketStatusEvent, MemberFlow, MemberTradingData, OrderBookData,
+    OrderConfirmData, OrderMarket, OrderNoticeKind, OrderSide, OverseasDerivativeTradeData,
+    OverseasRealtimeKey, PriceLevel, ProgramTradingData, QuoteData, Venue, ViStatus,
+};
+use crate::models::{
+    TryitoutH0eucni0Response, TryitoutH0gscni0Response, TryitoutH0ifasp0Response,
+    TryitoutH0ifcni0Response, TryitoutH0mfcni0Response, TryitoutH0mfcnt0Response,
+    TryitoutH0nxmko0Response, TryitoutH0stanc0Response, TryitoutH0stasp0Response,
+    TryitoutH0stcni0Response, TryitoutH0stcnt0Response, TryitoutH0stmbc0Response,
+    TryitoutH0stmko0Response, TryitoutH0stpgm0Response, TryitoutH0unmko0Response,
+    TryitoutH0zfasp0Response, TryitoutH0zfcnt0Response, TryitoutH0zoasp0Response,
+    TryitoutH0zocnt0Response, TryitoutHdfff010Response, TryitoutHdfff020Response,
+    TryitoutHdfff1c0Response, TryitoutHdfff2c0Response, TryitoutHdfsasp0Response,
+    TryitoutHdfsasp1Response, TryitoutHdfscnt0Response,
+};
+use crate::realtime::RealtimeRecord;
 
-// ── Reconnection backoff constants ──────────────────────────────────────
-const BACKOFF_INITIAL_MS: u64 = 1_000;
-const BACKOFF_MAX_MS: u64 = 60_000;
-/// Jitter adds up to this fraction of the backoff interval (0.0–1.0).
-const BACKOFF_JITTER_FRACTION: f64 = 0.3;
+/// `KisClient::stream()`이 쓰는 이벤트 버퍼 크기 (broadcast 채널 용량)
+pub const DEFAULT_EVENT_BUFFER: usize = 1024;
+
+/// 세션(approval key)당 실시간 등록 한도 (KIS 정책)
+pub const MAX_SUBSCRIPTIONS_PER_SESSION: usize = 41;
+
+/// 구독/해제 응답 대기 기본 시간
+pub const DEFAULT_ACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// 무수신 감시 기본 시간. 이 시간 동안 PINGPONG도 데이터도 없으면 끊긴 것으로 본다.
+pub const DEFAULT_HEARTBEAT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// 재연결 정책. 스트림을 만들 때 지정한다 ([`KisClient::stream_with`](crate::KisClient::stream_with)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// 정상 연결(시세 수신 또는 재구독 완료) 이후 연속 재연결 시도 한도. `None`이면 무제한
+    pub max_attempts: Option<u32>,
+    /// 첫 재연결 대기 시간. 시도마다 두 배로 늘어난다
+    pub initial_backoff: std::time::Duration,
+    /// 대기 시간 상한 (지터 제외)
+    pub max_backoff: std::time::Duration,
+    /// 대기 시간에 더하는 무작위 비율 상한 (0.0–1.0)
+    pub jitter: f64,
+    /// 한도를 넘었을 때 동작
+    pub give_up: GiveUp,
+}
+
+/// 재연결을 포기할 때의 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GiveUp {
+    /// 스트림 전체 종료 (다른 세션 포함)
+    CloseStream,
+    /// 해당 세션만 종료. 나머지 세션은 계속 동작하며 그 세션의 구독은 끊긴 채로 남는다
+    StopSession,
+}
+
+impl Default for ReconnectPolicy {
+    /// 무제한 재시도, 1초부터 최대 60초, 지터 30%
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            initial_backoff: std::time::Duration::from_secs(1),
+            max_backoff: std::time::Duration::from_secs(60),
+            jitter: 0.3,
+            give_up: GiveUp::CloseStream,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// 재연결하지 않는다 (끊기면 바로 포기)
+    pub fn never() -> Self {
+        Self {
+            max_attempts: Some(0),
+            ..Self::default()
+        }
+    }
+
+    /// `attempt`번째(1부터) 재연결 전 대기 시간
+    pub fn backoff(&self, attempt: u32) -> std::time::Duration {
+        use rand::Rng;
+
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let base = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        let jitter = self.jitter.clamp(0.0, 1.0) * rand::thread_rng().gen::<f64>();
+        base + base.mul_f64(jitter)
+    }
+
+    fn exhausted(&self, attempt: u32) -> bool {
+        self.max_attempts.is_some_and(|max| attempt > max)
+    }
+}
+
+/// 세션 연결 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    /// 끊긴 뒤 재연결 중 (`attempt`는 1부터, 0이면 아직 시도 전)
+    Reconnecting {
+        attempt: u32,
+    },
+    /// 스트림 종료
+    Closed,
+}
+
+/// 세션 1개의 상태
+#[derive(Debug, Clone)]
+pub struct SessionStatus {
+    pub state: ConnectionState,
+    /// 마지막 수신(PINGPONG, 제어 메시지, 데이터) 시각
+    pub last_message: Option<std::time::Instant>,
+    /// 재연결 성공 횟수
+    pub reconnects: u32,
+}
+
+/// [`KisStream::status`] 결과
+#[derive(Debug, Clone)]
+pub struct StreamStatus {
+    pub sessions: Vec<SessionStatus>,
+}
+
+impl StreamStatus {
+    /// 모든 세션이 연결돼 있는지
+    pub fn is_connected(&self) -> bool {
+        self.sessions
+            .iter()
+            .all(|s| s.state == ConnectionState::Connected)
+    }
+}
 
 /// 구독 종류
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -24,133 +153,898 @@ pub enum SubscriptionKind {
     DomesticPrice,
     /// 실시간 호가 국내 (H0STASP0)
     DomesticOrderbook,
+    /// 국내주식 체결통보 (H0STCNI0, 모의 H0STCNI9). 구독 키는 HTS ID
+    OrderNotice,
+    /// 해외주식 체결통보 (H0GSCNI0, 모의 H0GSCNI9). 구독 키는 HTS ID
+    OverseasOrderNotice,
+    /// 국내 선물옵션 체결통보 (H0IFCNI0, 모의 H0IFCNI9). 구독 키는 HTS ID
+    FutureOptionOrderNotice,
+    /// KRX 야간선물 체결통보 (H0MFCNI0). 구독 키는 HTS ID
+    NightFutureOrderNotice,
+    /// KRX 야간옵션 체결통보 (H0EUCNI0). 구독 키는 HTS ID
+    NightOptionOrderNotice,
+    /// 국내주식 장운영정보 (H0STMKO0 / H0NXMKO0 / H0UNMKO0)
+    MarketStatus(Venue),
+    /// 국내주식 예상체결 (H0STANC0 / H0NXANC0 / H0UNANC0)
+    ExpectedExecution(Venue),
+    /// 국내주식 회원사 (H0STMBC0 / H0NXMBC0 / H0UNMBC0)
+    MemberTrading(Venue),
+    /// 국내주식 프로그램매매 (H0STPGM0 / H0NXPGM0 / H0UNPGM0)
+    ProgramTrading(Venue),
+    /// 국내 선물옵션 체결가 (H0IFCNT0 등, [`DerivativeProduct`] 참고)
+    DerivativePrice(DerivativeProduct),
+    /// 국내 선물옵션 호가 (H0IFASP0 등)
+    DerivativeOrderbook(DerivativeProduct),
+    /// 해외선물옵션 체결가 (HDFFF020)
+    OverseasDerivativePrice,
+    /// 해외선물옵션 호가 (HDFFF010)
+    OverseasDerivativeOrderbook,
+    /// 해외선물옵션 주문내역통보 (HDFFF1C0). 구독 키는 HTS ID
+    OverseasDerivativeOrderNotice,
+    /// 해외선물옵션 체결내역통보 (HDFFF2C0). 구독 키는 HTS ID
+    OverseasDerivativeFillNotice,
+}
+
+impl SubscriptionKind {
+    /// 구독 요청에 쓰는 KIS TR ID
+    pub fn tr_id(self) -> &'static str {
+        match self {
+            SubscriptionKind::Price => "HDFSCNT0",
+            SubscriptionKind::Orderbook => "HDFSASP0",
+            SubscriptionKind::DomesticPrice => "H0STCNT0",
+            SubscriptionKind::DomesticOrderbook => "H0STASP0",
+            SubscriptionKind::OrderNotice => "H0STCNI0",
+            SubscriptionKind::OverseasOrderNotice => "H0GSCNI0",
+            SubscriptionKind::FutureOptionOrderNotice => "H0IFCNI0",
+            SubscriptionKind::NightFutureOrderNotice => "H0MFCNI0",
+            SubscriptionKind::NightOptionOrderNotice => "H0EUCNI0",
+            SubscriptionKind::MarketStatus(Venue::Krx) => "H0STMKO0",
+            SubscriptionKind::MarketStatus(Venue::Nxt) => "H0NXMKO0",
+            SubscriptionKind::MarketStatus(Venue::Integrated) => "H0UNMKO0",
+            SubscriptionKind::ExpectedExecution(Venue::Krx) => "H0STANC0",
+            SubscriptionKind::ExpectedExecution(Venue::Nxt) => "H0NXANC0",
+            SubscriptionKind::ExpectedExecution(Venue::Integrated) => "H0UNANC0",
+            SubscriptionKind::MemberTrading(Venue::Krx) => "H0STMBC0",
+            SubscriptionKind::MemberTrading(Venue::Nxt) => "H0NXMBC0",
+            SubscriptionKind::MemberTrading(Venue::Integrated) => "H0UNMBC0",
+            SubscriptionKind::ProgramTrading(Venue::Krx) => "H0STPGM0",
+            SubscriptionKind::ProgramTrading(Venue::Nxt) => "H0NXPGM0",
+            SubscriptionKind::ProgramTrading(Venue::Integrated) => "H0UNPGM0",
+            SubscriptionKind::DerivativePrice(product) => match product {
+                DerivativeProduct::IndexFuture => "H0IFCNT0",
+                DerivativeProduct::IndexOption => "H0IOCNT0",
+                DerivativeProduct::CommodityFuture => "H0CFCNT0",
+                DerivativeProduct::StockFuture => "H0ZFCNT0",
+                DerivativeProduct::StockOption => "H0ZOCNT0",
+                DerivativeProduct::NightFuture => "H0MFCNT0",
+                DerivativeProduct::NightOption => "H0EUCNT0",
+            },
+            SubscriptionKind::DerivativeOrderbook(product) => match product {
+                DerivativeProduct::IndexFuture => "H0IFASP0",
+                DerivativeProduct::IndexOption => "H0IOASP0",
+                DerivativeProduct::CommodityFuture => "H0CFASP0",
+                DerivativeProduct::StockFuture => "H0ZFASP0",
+                DerivativeProduct::StockOption => "H0ZOASP0",
+                DerivativeProduct::NightFuture => "H0MFASP0",
+                DerivativeProduct::NightOption => "H0EUASP0",
+            },
+            SubscriptionKind::OverseasDerivativePrice => "HDFFF020",
+            SubscriptionKind::OverseasDerivativeOrderbook => "HDFFF010",
+            SubscriptionKind::OverseasDerivativeOrderNotice => "HDFFF1C0",
+            SubscriptionKind::OverseasDerivativeFillNotice => "HDFFF2C0",
+        }
+    }
+
+    /// 모의투자 TR ID. 체결통보만 실전과 다르다
+    pub fn vts_tr_id(self) -> &'static str {
+        match self {
+            SubscriptionKind::OrderNotice => "H0STCNI9",
+            SubscriptionKind::OverseasOrderNotice => "H0GSCNI9",
+            SubscriptionKind::FutureOptionOrderNotice => "H0IFCNI9",
+            kind => kind.tr_id(),
+        }
+    }
+
+    /// 이 구독에서 나오는 이벤트인지
+    pub fn produces(self, event: &KisEvent) -> bool {
+        match self {
+            SubscriptionKind::Price | SubscriptionKind::DomesticPrice => {
+                matches!(event, KisEvent::Transaction(_))
+            }
+            SubscriptionKind::Orderbook | SubscriptionKind::DomesticOrderbook => {
+                matches!(event, KisEvent::Quote(_) | KisEvent::OrderBook(_))
+            }
+            SubscriptionKind::OrderNotice => notice_from(event, OrderMarket::DomesticStock),
+            SubscriptionKind::OverseasOrderNotice => notice_from(event, OrderMarket::OverseasStock),
+            SubscriptionKind::FutureOptionOrderNotice => {
+                notice_from(event, OrderMarket::FutureOption)
+            }
+            SubscriptionKind::NightFutureOrderNotice => {
+                notice_from(event, OrderMarket::NightFuture)
+            }
+            SubscriptionKind::NightOptionOrderNotice => {
+                notice_from(event, OrderMarket::NightOption)
+            }
+            SubscriptionKind::MarketStatus(venue) => {
+                matches!(event, KisEvent::MarketStatus(d) if d.venue == venue)
+            }
+            SubscriptionKind::ExpectedExecution(venue) => {
+                matches!(event, KisEvent::ExpectedExecution(d) if d.venue == venue)
+            }
+            SubscriptionKind::MemberTrading(venue) => {
+                matches!(event, KisEvent::MemberTrading(d) if d.venue == venue)
+            }
+            SubscriptionKind::ProgramTrading(venue) => {
+                matches!(event, KisEvent::ProgramTrading(d) if d.venue == venue)
+            }
+            SubscriptionKind::DerivativePrice(product) => {
+                matches!(event, KisEvent::DerivativeTrade(d) if d.product == product)
+            }
+            SubscriptionKind::DerivativeOrderbook(_)
+            | SubscriptionKind::OverseasDerivativeOrderbook => {
+                matches!(event, KisEvent::Quote(_) | KisEvent::OrderBook(_))
+            }
+            SubscriptionKind::OverseasDerivativePrice => {
+                matches!(event, KisEvent::OverseasDerivativeTrade(_))
+            }
+            SubscriptionKind::OverseasDerivativeOrderNotice => matches!(
+                event,
+                KisEvent::OrderConfirm(d)
+                    if d.market == OrderMarket::OverseasFutureOption
+                        && d.kind != OrderNoticeKind::Filled
+            ),
+            SubscriptionKind::OverseasDerivativeFillNotice => matches!(
+                event,
+                KisEvent::OrderConfirm(d)
+                    if d.market == OrderMarket::OverseasFutureOption
+                        && d.kind == OrderNoticeKind::Filled
+            ),
+        }
+    }
+}
+
+fn notice_from(event: &KisEvent, market: OrderMarket) -> bool {
+    matches!(event, KisEvent::OrderConfirm(d) if d.market == market)
 }
 
 type SubscriptionKey = (String, SubscriptionKind);
 
-/// WebSocket 이벤트 수신기 (broadcast receiver 래퍼)
-pub struct EventReceiver {
-    inner: broadcast::Receiver<KisEvent>,
+/// 구독/해제 요청에 대한 서버 응답
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscribeAck {
+    pub tr_id: String,
+    pub tr_key: String,
+    /// 성공 `0`
+    pub rt_cd: String,
+    /// 응답코드 (예: `OPSP0000`)
+    pub msg_cd: String,
+    /// 응답메세지 (예: `SUBSCRIBE SUCCESS`, `MAX SUBSCRIBE OVER`)
+    pub msg1: String,
+}
+
+impl SubscribeAck {
+    /// approval key가 거부됐는지 (예: `invalid approval : NOT FOUND`)
+    pub fn is_approval_rejected(&self) -> bool {
+        self.rt_cd != "0" && is_approval_message(&self.msg1)
+    }
+
+    /// 등록/해제가 반영됐는지. 이미 등록된 구독(`ALREADY IN SUBSCRIBE`)도 성공으로 본다.
+    pub fn is_success(&self) -> bool {
+        self.rt_cd == "0" || self.msg1.contains("ALREADY IN SUBSCRIBE")
+    }
+}
+
+/// approval key 거부 응답 메시지인지
+fn is_approval_message(msg1: &str) -> bool {
+    msg1.to_ascii_lowercase().contains("invalid approval")
+}
+
+/// approval key 재발급 함수
+type KeyRefresher = Arc<
+    dyn Fn() -> futures_util::future::BoxFuture<'static, Result<String, KisError>> + Send + Sync,
+>;
+
+/// 세션 생성 정보: 처음 쓸 approval key와 재발급 함수
+struct SessionKey {
+    approval_key: String,
+    refresh: Option<KeyRefresher>,
+}
+
+impl SessionKey {
+    #[cfg(any(test, feature = "test-utils", feature = "recorder"))]
+    fn fixed(approval_key: String) -> Self {
+        Self {
+            approval_key,
+            refresh: None,
+        }
+    }
+
+    /// `client`로 키를 발급받고, 거부되면 같은 클라이언트로 재발급한다
+    async fn from_client<E: 'static>(
+        client: &crate::client::KisClient<E>,
+    ) -> Result<Self, KisError> {
+        let client = client.clone();
+        let approval_key = client.approval_key().await?;
+        let refresh: KeyRefresher = Arc::new(move || {
+            let client = client.clone();
+            Box::pin(async move { client.approval_key().await })
+        });
+        Ok(Self {
+            approval_key,
+            refresh: Some(refresh),
+        })
+    }
+}
+
+/// 등록된 구독: 담당 세션과 등록 시 받은 응답
+struct Subscription {
+    session: usize,
+    /// 등록 응답. 등록 요청이 진행 중이면 `None`
+    ack: Option<SubscribeAck>,
+    /// 구독/해제 요청이 진행 중이면 요청이 끝날 때 닫힌다
+    busy: Option<tokio::sync::watch::Receiver<()>>,
+}
+
+/// 응답을 기다리는 구독/해제 요청의 자리.
+///
+/// 요청 중에는 구독 목록 잠금을 놓고, 응답을 받으면 [`finish`](Self::finish)로 결과를 반영한다.
+/// 결과 없이 버려지면(호출 취소 등) 요청 전 상태로 되돌린다.
+struct Reservation<'a> {
+    subscriptions: &'a std::sync::RwLock<HashMap<SubscriptionKey, Subscription>>,
+    key: SubscriptionKey,
+    /// 반영할 등록 응답. `None`이면 목록에서 뺀다
+    outcome: Option<SubscribeAck>,
+    /// 같은 구독을 기다리는 호출을 깨운다 (drop 시 닫힘)
+    _done: tokio::sync::watch::Sender<()>,
+}
+
+impl Reservation<'_> {
+    /// 요청 결과 반영. `ack`가 `None`이면 구독을 목록에서 뺀다
+    fn finish(mut self, ack: Option<SubscribeAck>) {
+        self.outcome = ack;
+    }
 }
 
-impl EventReceiver {
-    /// 다음 이벤트를 기다린다. `KisError::Lagged(n)` 또는 `KisError::StreamClosed` 반환 가능.
-    pub async fn recv(&mut self) -> Result<KisEvent, KisError> {
-        match self.inner.recv().await {
-            Ok(event) => Ok(event),
-            Err(broadcast::error::RecvError::Lagged(n)) => Err(KisError::Lagged(n)),
-            Err(broadcast::error::RecvError::Closed) => Err(KisError::StreamClosed),
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        let mut subs = self.subscriptions.write().unwrap();
+        match self.outcome.take() {
+            Some(ack) => {
+                if let Some(sub) = subs.get_mut(&self.key) {
+                    sub.ack = Some(ack);
+                    sub.busy = None;
+                }
+            }
+            None => {
+                subs.remove(&self.key);
+            }
         }
     }
 }
 
+/// 응답을 기다리는 요청 (TR ID, tr_key)
+type PendingAcks = HashMap<(String, String), tokio::sync::oneshot::Sender<SubscribeAck>>;
+
 type WsSink = futures_util::stream::SplitSink<
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
     tokio_tungstenite::tungstenite::Message,
 >;
 
 /// WebSocket 실시간 스트림
+///
+/// 세션(WebSocket 연결) 하나당 구독은 [`MAX_SUBSCRIPTIONS_PER_SESSION`]건까지다.
+/// [`KisStream::pooled`]로 여러 세션을 묶으면 구독을 세션에 나눠 담고 이벤트는
+/// 하나의 수신기로 합쳐서 전달한다.
 #[derive(Clone)]
 pub struct KisStream {
     inner: Arc<StreamInner>,
 }
 
 struct StreamInner {
-    config: KisConfig,
-    approval_key: String,
-    tx: broadcast::Sender<KisEvent>,
-    subscriptions: RwLock<HashMap<SubscriptionKey, ()>>,
+    events: Fanout,
+    /// 구독 목록. 응답을 기다리는 동안에는 잠그지 않는다
+    subscriptions: std::sync::RwLock<HashMap<SubscriptionKey, Subscription>>,
     cancel: CancellationToken,
+    sessions: Vec<Session>,
+    /// 구독/해제 응답 대기 시간 (ms)
+    ack_timeout_ms: std::sync::atomic::AtomicU64,
+    /// 무수신 감시 시간 (ms, 0이면 끔)
+    heartbeat_timeout_ms: std::sync::atomic::AtomicU64,
+    reconnect: ReconnectPolicy,
+    /// 종목·시장별 마지막 장운영정보
+    market_status: std::sync::RwLock<HashMap<(String, Venue), MarketStatusEvent>>,
+    /// 원본 프레임 기록기 ([`KisStream::start_recording`])
+    #[cfg(feature = "recorder")]
+    recorder: std::sync::Mutex<Option<crate::recorder::RecordingWriter>>,
+}
+
+impl StreamInner {
+    fn new(sessions: Vec<Session>, event_buffer: usize, reconnect: ReconnectPolicy) -> Self {
+        Self {
+            events: Fanout::new(event_buffer),
+            subscriptions: std::sync::RwLock::new(HashMap::new()),
+            cancel: CancellationToken::new(),
+            sessions,
+            ack_timeout_ms: (DEFAULT_ACK_TIMEOUT.as_millis() as u64).into(),
+            heartbeat_timeout_ms: (DEFAULT_HEARTBEAT_TIMEOUT.as_millis() as u64).into(),
+            reconnect,
+            market_status: std::sync::RwLock::new(HashMap::new()),
+            #[cfg(feature = "recorder")]
+            recorder: std::sync::Mutex::new(None),
+        }
+    }
+
+    fn ack_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(
+            self.ack_timeout_ms
+                .load(std::sync::atomic::Ordering::Relaxed),
+        )
+    }
+
+    /// 등록된 구독을 요청 중으로 표시한다. 등록 중이거나 다른 요청이 진행 중이면 `None`
+    fn reserve_existing(&self, key: &SubscriptionKey) -> Option<(SubscribeAck, Reservation<'_>)> {
+        let mut subs = self.subscriptions.write().unwrap();
+        let sub = subs.get_mut(key)?;
+        if sub.busy.is_some() {
+            return None;
+        }
+        let ack = sub.ack.clone()?;
+        let (done, busy) = tokio::sync::watch::channel(());
+        sub.busy = Some(busy);
+        let reservation = Reservation {
+            subscriptions: &self.subscriptions,
+            key: key.clone(),
+            // 요청이 끝나지 않으면 등록 상태로 되돌린다
+            outcome: Some(ack.clone()),
+            _done: done,
+        };
+        Some((ack, reservation))
+    }
+
+    fn heartbeat_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(
+            self.heartbeat_timeout_ms
+                .load(std::sync::atomic::Ordering::Relaxed),
+        )
+    }
+
+    /// 기록 중이면 원본 프레임을 쓰기 스레드로 넘긴다. 쓰기 스레드가 멈췄으면 기록을 끝낸다.
+    #[cfg(feature = "recorder")]
+    fn record(&self, session: usize, text: &str) {
+        let mut guard = self.recorder.lock().unwrap();
+        if let Some(writer) = guard.as_ref() {
+            let frame = crate::recorder::RawFrame {
+                received_at: chrono::Utc::now(),
+                session,
+                text: text.to_string(),
+            };
+            if !writer.send(frame) {
+                *guard = None;
+            }
+        }
+    }
+
+    /// 세션 연결 상태 변화를 수신기에 알린다
+    async fn notify(&self, event: ConnectionEvent) {
+        self.events.publish(KisEvent::Connection(event)).await;
+    }
+
+    /// 스트림 상태(장운영정보 등)를 갱신하고 수신기에 배포한다
+    async fn publish(&self, event: KisEvent) {
+        if let KisEvent::MarketStatus(status) = &event {
+            self.market_status
+                .write()
+                .unwrap()
+                .insert((status.symbol.clone(), status.venue), status.clone());
+        }
+        self.events.publish(event).await;
+    }
+}
+
+/// WebSocket 연결 1개 (approval key 1개)
+struct Session {
+    index: usize,
+    ws_url: String,
+    approval_key: std::sync::RwLock<String>,
+    /// 서버가 approval key를 거부했을 때 새로 발급받는 함수
+    refresh_key: Option<KeyRefresher>,
+    /// 거부된 키 — 다음 재연결 전에 새로 발급받는다
+    key_rejected: std::sync::atomic::AtomicBool,
     /// WS writer (shared for sending subscribe/unsubscribe messages)
     ws_tx: Mutex<Option<WsSink>>,
+    /// 구독 응답으로 받은 TR ID별 복호화 키
+    ciphers: std::sync::RwLock<CipherKeys>,
+    /// 체결통보 미체결 잔량 계산용 누적 체결
+    fills: std::sync::Mutex<FillTracker>,
+    pending_acks: std::sync::Mutex<PendingAcks>,
+    status: std::sync::Mutex<SessionStatus>,
+}
+
+impl Session {
+    fn new(index: usize, ws_url: &str, key: SessionKey) -> Self {
+        Self {
+            index,
+            ws_url: ws_url.to_string(),
+            approval_key: std::sync::RwLock::new(key.approval_key),
+            refresh_key: key.refresh,
+            key_rejected: false.into(),
+            ws_tx: Mutex::new(None),
+            ciphers: std::sync::RwLock::new(HashMap::new()),
+            fills: std::sync::Mutex::new(FillTracker::default()),
+            pending_acks: std::sync::Mutex::new(HashMap::new()),
+            status: std::sync::Mutex::new(SessionStatus {
+                state: ConnectionState::Connected,
+                last_message: None,
+                reconnects: 0,
+            }),
+        }
+    }
+
+    /// 접속 환경에 맞는 구독 TR ID
+    fn tr_id(&self, kind: SubscriptionKind) -> &'static str {
+        if self.ws_url == crate::generated::config::VTS_WS_URL {
+            kind.vts_tr_id()
+        } else {
+            kind.tr_id()
+        }
+    }
+
+    fn approval_key(&self) -> String {
+        self.approval_key.read().unwrap().clone()
+    }
+
+    /// 거부된 approval key를 새로 발급받는다. 발급 함수가 없으면 기존 키를 계속 쓴다.
+    async fn refresh_rejected_key(&self) -> Result<(), KisError> {
+        use std::sync::atomic::Ordering;
+
+        if !self.key_rejected.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let Some(refresh) = &self.refresh_key else {
+            tracing::warn!(target: "kis_api", "approval key rejected but no client to refresh it");
+            return Ok(());
+        };
+        let key = refresh().await?;
+        *self.approval_key.write().unwrap() = key;
+        self.key_rejected.store(false, Ordering::Relaxed);
+        tracing::info!(target: "kis_api", "approval key refreshed (session {})", self.index);
+        Ok(())
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        self.status.lock().unwrap().state = state;
+    }
+
+    /// 응답을 기다리던 요청에 전달. tr_key가 비어 있으면 같은 TR ID의 요청에 전달한다.
+    fn resolve_ack(&self, ack: SubscribeAck) {
+        let mut pending = self.pending_acks.lock().unwrap();
+        let key = (ack.tr_id.clone(), ack.tr_key.clone());
+        let key = if pending.contains_key(&key) || !ack.tr_key.is_empty() {
+            key
+        } else {
+            match pending.keys().find(|(tr_id, _)| *tr_id == ack.tr_id) {
+                Some(key) => key.clone(),
+                None => return,
+            }
+        };
+        if let Some(waiter) = pending.remove(&key) {
+            let _ = waiter.send(ack);
+        }
+    }
+
+    /// 구독/해제 요청을 보내고 응답을 기다린다.
+    async fn request(
+        &self,
+        symbol: &str,
+        kind: SubscriptionKind,
+        subscribe: bool,
+        timeout: std::time::Duration,
+    ) -> Result<SubscribeAck, KisError> {
+        let key = (self.tr_id(kind).to_string(), symbol.to_string());
+        let (waiter, ack) = tokio::sync::oneshot::channel();
+        self.pending_acks
+            .lock()
+            .unwrap()
+            .insert(key.clone(), waiter);
+
+        if let Err(e) = send_subscribe_raw(self, symbol, kind, subscribe).await {
+            self.pending_acks.lock().unwrap().remove(&key);
+            return Err(e);
+        }
+
+        let ack = match tokio::time::timeout(timeout, ack).await {
+            Ok(Ok(ack)) => ack,
+            Ok(Err(_)) => {
+                return Err(KisError::WebSocket(
+                    "connection closed before subscribe ack".into(),
+                ))
+            }
+            Err(_) => {
+                self.pending_acks.lock().unwrap().remove(&key);
+                return Err(KisError::SubscribeTimeout {
+                    tr_id: key.0,
+                    tr_key: key.1,
+                });
+            }
+        };
+        if !ack.is_success() {
+            return Err(KisError::SubscribeRejected {
+                tr_id: ack.tr_id,
+                tr_key: ack.tr_key,
+                msg_cd: ack.msg_cd,
+                message: ack.msg1,
+            });
+        }
+        Ok(ack)
+    }
 }
 
 impl KisStream {
-    /// 연결 수립 + 수신 루프 시작. `KisClient::stream()` 이 내부적으로 호출.
-    pub(crate) async fn connect(config: KisConfig, approval_key: String) -> Result<Self, KisError> {
-        let (tx, _) = broadcast::channel(config.ws_event_buffer);
-        let cancel = CancellationToken::new();
+    /// 고정 approval key로 연결 + 수신 루프 시작. 키가 거부돼도 재발급하지 않는다.
+    #[cfg(test)]
+    async fn connect(
+        ws_url: &str,
+        approval_key: String,
+        event_buffer: usize,
+    ) -> Result<Self, KisError> {
+        Self::connect_sessions(
+            ws_url,
+            vec![SessionKey::fixed(approval_key)],
+            event_buffer,
+            ReconnectPolicy::default(),
+        )
+        .await
+    }
 
+    /// `client`의 approval key로 연결. `KisClient::stream_with()`이 내부적으로 호출.
+    ///
+    /// 서버가 키를 거부하면 재연결 전에 `client`로 새 키를 발급받는다.
+    pub(crate) async fn connect_client<E: 'static>(
+        client: &crate::client::KisClient<E>,
+        policy: ReconnectPolicy,
+    ) -> Result<Self, KisError> {
+        let key = SessionKey::from_client(client).await?;
+        Self::connect_sessions(client.ws_url(), vec![key], DEFAULT_EVENT_BUFFER, policy).await
+    }
+
+    /// 여러 앱키로 세션을 하나씩 열어 묶은 스트림.
+    ///
+    /// 구독 한도가 `클라이언트 수 × 41`건으로 늘어난다. 모든 클라이언트는 같은 환경이어야 한다.
+    pub async fn pooled<E: 'static>(
+        clients: &[crate::client::KisClient<E>],
+    ) -> Result<Self, KisError> {
+        Self::pooled_with(clients, ReconnectPolicy::default()).await
+    }
+
+    /// 재연결 정책을 지정한 [`pooled`](Self::pooled)
+    pub async fn pooled_with<E: 'static>(
+        clients: &[crate::client::KisClient<E>],
+        policy: ReconnectPolicy,
+    ) -> Result<Self, KisError> {
+        let Some(first) = clients.first() else {
+            return Err(KisError::WebSocket(
+                "pooled stream needs at least one client".into(),
+            ));
+        };
+        if clients.iter().any(|c| c.env() != first.env()) {
+            return Err(KisError::WebSocket(
+                "pooled stream clients must share the same environment".into(),
+            ));
+        }
+        let mut keys = Vec::with_capacity(clients.len());
+        for client in clients {
+            keys.push(SessionKey::from_client(client).await?);
+        }
+        Self::connect_sessions(first.ws_url(), keys, DEFAULT_EVENT_BUFFER, policy).await
+    }
+
+    async fn connect_sessions(
+        ws_url: &str,
+        keys: Vec<SessionKey>,
+        event_buffer: usize,
+        reconnect: ReconnectPolicy,
+    ) -> Result<Self, KisError> {
+        use tokio_tungstenite::connect_async;
+
+        let sessions = keys
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| Session::new(i, ws_url, key))
+            .collect();
         let stream = Self {
-            inner: Arc::new(StreamInner {
-                config: config.clone(),
-                approval_key: approval_key.clone(),
-                tx: tx.clone(),
-                subscriptions: RwLock::new(HashMap::new()),
-                cancel: cancel.clone(),
-                ws_tx: Mutex::new(None),
-            }),
+            inner: Arc::new(StreamInner::new(sessions, event_buffer, reconnect)),
         };
 
         // Initial connection — fail fast so caller knows immediately if URL is invalid.
-        let ws_read = {
-            use tokio_tungstenite::connect_async;
-
-            let (ws_stream, _) = connect_async(&stream.inner.config.ws_url)
+        let mut readers = Vec::with_capacity(stream.inner.sessions.len());
+        for session in &stream.inner.sessions {
+            let (ws_stream, _) = connect_async(session.ws_url.as_str())
                 .await
                 .map_err(|e| KisError::WebSocket(e.to_string()))?;
-
             let (ws_write, ws_read) = ws_stream.split();
-            *stream.inner.ws_tx.lock().await = Some(ws_write);
-            ws_read
-        };
+            *session.ws_tx.lock().await = Some(ws_write);
+            readers.push(ws_read);
+        }
 
-        // Spawn the reconnection loop (handles future disconnections)
-        let inner = stream.inner.clone();
-        tokio::spawn(async move {
-            run_connection_loop(inner, ws_read).await;
-        });
+        // Spawn the reconnection loop per session (handles future disconnections)
+        for (index, ws_read) in readers.into_iter().enumerate() {
+            let inner = stream.inner.clone();
+            tokio::spawn(async move {
+                run_connection_loop(inner, index, ws_read).await;
+            });
+        }
 
         Ok(stream)
     }
 
+    /// 연결 없는 스트림. 이벤트는 배포기로 직접 주입한다 (테스트 주입, 기록 재생).
+    ///
+    /// 지킬 연결이 없으므로 bounded 수신기는 자리가 날 때까지 기다린다.
+    #[cfg(any(test, feature = "test-utils", feature = "recorder"))]
+    pub(crate) fn offline(event_buffer: usize) -> Self {
+        let session = Session::new(0, "ws://localhost", SessionKey::fixed(String::new()));
+        let mut inner = StreamInner::new(vec![session], event_buffer, ReconnectPolicy::never());
+        inner.events = inner.events.wait_for_bounded();
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// 배포기로 이벤트 직접 전달 (수신 루프와 같은 경로)
+    #[cfg(feature = "recorder")]
+    pub(crate) async fn publish(&self, event: KisEvent) {
+        self.inner.publish(event).await;
+    }
+
+    /// 이후 수신하는 모든 원본 텍스트 프레임(제어 메시지 포함)을 `path`에 기록한다.
+    ///
+    /// 파일은 gzip 멤버 단위로 이어 붙이므로 같은 파일에 여러 번 기록할 수 있다.
+    /// 이미 기록 중이면 이전 기록을 마치고 새 파일로 바꾼다.
+    #[cfg(feature = "recorder")]
+    pub fn start_recording(&self, path: impl AsRef<std::path::Path>) -> Result<(), KisError> {
+        let writer =
+            crate::recorder::RecordingWriter::spawn(crate::recorder::FrameRecorder::append(path)?)?;
+        let previous = self.inner.recorder.lock().unwrap().replace(writer);
+        match previous {
+            Some(previous) => previous.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// 기록 종료. 남은 프레임을 모두 쓸 때까지 기다린다. 기록 중이 아니면 아무것도 하지 않는다.
+    #[cfg(feature = "recorder")]
+    pub fn stop_recording(&self) -> Result<(), KisError> {
+        let writer = self.inner.recorder.lock().unwrap().take();
+        match writer {
+            Some(writer) => writer.finish(),
+            None => Ok(()),
+        }
+    }
+
     /// 실시간 이벤트 수신기 획득
     pub fn receiver(&self) -> EventReceiver {
-        EventReceiver {
-            inner: self.inner.tx.subscribe(),
-        }
+        self.inner.events.receiver(DeliveryMode::Broadcast, None)
     }
 
-    /// 종목 구독 등록
-    pub async fn subscribe(&self, symbol: &str, kind: SubscriptionKind) -> Result<(), KisError> {
-        let key = (symbol.to_string(), kind);
-        let mut subs = self.inner.subscriptions.write().await;
-        if subs.contains_key(&key) {
-            return Ok(());
+    /// 시세 전달 방식을 지정한 수신기. `predicate`가 참인 이벤트만 받는다.
+    ///
+    /// 주문체결통보, 장운영정보와 연결 상태 이벤트는 어떤 방식이든 유실 없이 전달된다.
+    pub fn receiver_with<F>(&self, mode: DeliveryMode, predicate: F) -> EventReceiver
+    where
+        F: Fn(&KisEvent) -> bool + Send + Sync + 'static,
+    {
+        let filter: EventFilter = Arc::new(predicate);
+        self.inner.events.receiver(mode, Some(filter))
+    }
+
+    /// 한 종목·구독 종류의 이벤트만 받는 수신기.
+    ///
+    /// `symbol`은 구독할 때의 값이다. 해외 종목은 `tr_key`(예: `DNASNVDA`)와
+    /// 종목코드(`NVDA`) 모두 받는다. 데이터 공백을 알 수 있도록 연결 상태 이벤트도 함께 받는다.
+    pub fn receiver_for(&self, symbol: &str, kind: SubscriptionKind) -> EventReceiver {
+        let symbol = symbol.to_string();
+        self.receiver_matching(move |event| {
+            matches!(event, KisEvent::Connection(_))
+                || (kind.produces(event) && event.matches_symbol(&symbol))
+        })
+    }
+
+    /// `predicate`가 참인 이벤트만 받는 수신기
+    pub fn receiver_matching<F>(&self, predicate: F) -> EventReceiver
+    where
+        F: Fn(&KisEvent) -> bool + Send + Sync + 'static,
+    {
+        self.receiver_with(DeliveryMode::Broadcast, predicate)
+    }
+
+    /// 구독 가능한 최대 건수 (세션 수 × 41)
+    pub fn subscription_capacity(&self) -> usize {
+        self.inner.sessions.len() * MAX_SUBSCRIPTIONS_PER_SESSION
+    }
+
+    /// 현재 구독 건수 (등록 응답을 기다리는 구독은 제외)
+    pub fn subscription_count(&self) -> usize {
+        self.inner
+            .subscriptions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|sub| sub.ack.is_some())
+            .count()
+    }
+
+    /// 구독/해제 응답 대기 시간 변경 (기본 [`DEFAULT_ACK_TIMEOUT`])
+    pub fn set_ack_timeout(&self, timeout: std::time::Duration) {
+        self.inner.ack_timeout_ms.store(
+            timeout.as_millis() as u64,
+            std::sync::atomic::Ordering::Relaxed,
+        );
+    }
+
+    /// 무수신 감시 시간 변경 (기본 [`DEFAULT_HEARTBEAT_TIMEOUT`]). `Duration::ZERO`면 끈다.
+    ///
+    /// 이 시간 동안 아무 메시지도 받지 못한 세션은 끊긴 것으로 보고 재연결한다.
+    /// 다음 메시지를 받은 뒤부터 적용된다.
+    pub fn set_heartbeat_timeout(&self, timeout: std::time::Duration) {
+        self.inner.heartbeat_timeout_ms.store(
+            timeout.as_millis() as u64,
+            std::sync::atomic::Ordering::Relaxed,
+        );
+    }
+
+    /// 종목의 마지막 장운영정보. [`SubscriptionKind::MarketStatus`]를 구독해야 채워진다
+    pub fn market_status(&self, symbol: &str, venue: Venue) -> Option<MarketStatusEvent> {
+        self.inner
+            .market_status
+            .read()
+            .unwrap()
+            .get(&(symbol.to_string(), venue))
+            .cloned()
+    }
+
+    /// 세션별 연결 상태
+    pub fn status(&self) -> StreamStatus {
+        StreamStatus {
+            sessions: self
+                .inner
+                .sessions
+                .iter()
+                .map(|s| s.status.lock().unwrap().clone())
+                .collect(),
         }
+    }
 
-        self.send_subscribe_message(symbol, kind, true).await?;
-        subs.insert(key, ());
-        Ok(())
+    fn ack_timeout(&self) -> std::time::Duration {
+        self.inner.ack_timeout()
     }
 
-    /// 종목 구독 해제
-    pub async fn unsubscribe(&self, symbol: &str, kind: SubscriptionKind) -> Result<(), KisError> {
+    /// 종목 구독 등록. 서버 응답을 기다려 반환한다.
+    ///
+    /// 한도에 도달하면 `KisError::SubscriptionLimit`, 서버가 거부하면
+    /// `KisError::SubscribeRejected`, 응답이 없으면 `KisError::SubscribeTimeout`.
+    /// 실패한 구독은 재접속 시 복구 대상에 남지 않는다. 이미 등록된 구독은 등록 당시 응답을 반환한다.
+    /// 응답을 기다리는 동안 다른 구독 요청은 막지 않는다. 같은 구독에 대한 요청만 앞 요청이 끝날 때까지 기다린다.
+    pub async fn subscribe(
+        &self,
+        symbol: &str,
+        kind: SubscriptionKind,
+    ) -> Result<SubscribeAck, KisError> {
         let key = (symbol.to_string(), kind);
-        let mut subs = self.inner.subscriptions.write().await;
-        if !subs.contains_key(&key) {
-            return Ok(());
+        let (index, reservation) = loop {
+            let busy = {
+                let mut subs = self.inner.subscriptions.write().unwrap();
+                match subs.get(&key) {
+                    Some(Subscription {
+                        busy: Some(busy), ..
+                    }) => busy.clone(),
+                    Some(Subscription { ack: Some(ack), .. }) => return Ok(ack.clone()),
+                    _ => break self.reserve(&mut subs, key.clone())?,
+                }
+            };
+            wait_idle(busy).await;
+        };
+
+        let result = self.inner.sessions[index]
+            .request(symbol, kind, true, self.ack_timeout())
+            .await;
+        reservation.finish(result.as_ref().ok().cloned());
+        result
+    }
+
+    /// 구독이 가장 적은 세션에 자리를 잡는다. 등록 중인 구독도 센다.
+    fn reserve(
+        &self,
+        subs: &mut HashMap<SubscriptionKey, Subscription>,
+        key: SubscriptionKey,
+    ) -> Result<(usize, Reservation<'_>), KisError> {
+        let mut load = vec![0usize; self.inner.sessions.len()];
+        for sub in subs.values() {
+            load[sub.session] += 1;
+        }
+        let (index, count) = load
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|&(_, count)| count)
+            .unwrap_or((0, usize::MAX));
+        if count >= MAX_SUBSCRIPTIONS_PER_SESSION {
+            return Err(KisError::SubscriptionLimit(self.subscription_capacity()));
         }
 
-        self.send_subscribe_message(symbol, kind, false).await?;
-        subs.remove(&key);
-        Ok(())
+        let (done, busy) = tokio::sync::watch::channel(());
+        subs.insert(
+            key.clone(),
+            Subscription {
+                session: index,
+                ack: None,
+                busy: Some(busy),
+            },
+        );
+        Ok((
+            index,
+            Reservation {
+                subscriptions: &self.inner.subscriptions,
+                key,
+                outcome: None,
+                _done: done,
+            },
+        ))
     }
 
-    async fn send_subscribe_message(
+    /// 종목 구독 해제. 등록되지 않은 구독이면 요청 없이 `Ok(None)`.
+    ///
+    /// 서버가 해제를 거부해도(예: 이미 해제됨) 로컬 구독 목록에서는 제거한다.
+    pub async fn unsubscribe(
         &self,
         symbol: &str,
         kind: SubscriptionKind,
-        subscribe: bool,
-    ) -> Result<(), KisError> {
-        send_subscribe_raw(
-            &self.inner.ws_tx,
-            &self.inner.approval_key,
-            symbol,
-            kind,
-            subscribe,
-        )
-        .await
+    ) -> Result<Option<SubscribeAck>, KisError> {
+        let key = (symbol.to_string(), kind);
+        let (index, ack, reservation) = loop {
+            let busy = {
+                let subs = self.inner.subscriptions.read().unwrap();
+                match subs.get(&key) {
+                    None => return Ok(None),
+                    Some(Subscription {
+                        busy: Some(busy), ..
+                    }) => busy.clone(),
+                    Some(sub) => {
+                        let index = sub.session;
+                        drop(subs);
+                        match self.inner.reserve_existing(&key) {
+                            Some((ack, reservation)) => break (index, ack, reservation),
+                            None => continue,
+                        }
+                    }
+                }
+            };
+            wait_idle(busy).await;
+        };
+
+        let result = self.inner.sessions[index]
+            .request(symbol, kind, false, self.ack_timeout())
+            .await;
+        let removed = matches!(result, Ok(_) | Err(KisError::SubscribeRejected { .. }));
+        reservation.finish((!removed).then_some(ack));
+        result.map(Some)
+    }
+
+    /// 해외 종목 구독 등록. `key.tr_key()`로 [`subscribe`](Self::subscribe)를 호출한다.
+    pub async fn subscribe_overseas(
+        &self,
+        key: &OverseasRealtimeKey,
+        kind: SubscriptionKind,
+    ) -> Result<SubscribeAck, KisError> {
+        self.subscribe(&key.tr_key(), kind).await
+    }
+
+    /// 해외 종목 구독 해제
+    pub async fn unsubscribe_overseas(
+        &self,
+        key: &OverseasRealtimeKey,
+        kind: SubscriptionKind,
+    ) -> Result<Option<SubscribeAck>, KisError> {
+        self.unsubscribe(&key.tr_key(), kind).await
     }
 
     /// 스트림 종료
@@ -159,45 +1053,42 @@ impl KisStream {
     }
 }
 
-/// Send a subscribe/unsubscribe message over the provided writer mutex.
+/// 같은 구독에 대한 앞 요청이 끝날 때까지 기다린다
+async fn wait_idle(mut busy: tokio::sync::watch::Receiver<()>) {
+    while busy.changed().await.is_ok() {}
+}
+
+/// Send a subscribe/unsubscribe message over the session's writer.
 async fn send_subscribe_raw(
-    ws_tx: &Mutex<Option<WsSink>>,
-    approval_key: &str,
+    session: &Session,
     symbol: &str,
     kind: SubscriptionKind,
     subscribe: bool,
 ) -> Result<(), KisError> {
     use tokio_tungstenite::tungstenite::Message;
 
-    let tr_id = match kind {
-        SubscriptionKind::Price => "HDFSCNT0",
-        SubscriptionKind::Orderbook => "HDFSASP0",
-        SubscriptionKind::DomesticPrice => "H0STCNT0",
-        SubscriptionKind::DomesticOrderbook => "H0STASP0",
-    };
-
     let msg = serde_json::json!({
         "header": {
-            "approval_key": approval_key,
+            "approval_key": session.approval_key(),
             "custtype": "P",
             "tr_type": if subscribe { "1" } else { "2" },
             "content-type": "utf-8"
         },
         "body": {
             "input": {
-                "tr_id": tr_id,
+                "tr_id": session.tr_id(kind),
                 "tr_key": symbol
             }
         }
     });
 
-    let text = serde_json::to_string(&msg).map_err(KisError::Parse)?;
+    let text = serde_json::to_string(&msg)?;
 
-    let mut guard = ws_tx.lock().await;
+    let mut guard = session.ws_tx.lock().await;
     match *guard {
         Some(ref mut writer) => {
             writer
-                .send(Message::Text(text))
+                .send(Message::Text(text.into()))
                 .await
                 .map_err(|e| KisError::WebSocket(e.to_string()))?;
         }
@@ -209,30 +1100,69 @@ async fn send_subscribe_raw(
     Ok(())
 }
 
-/// Re-subscribe all active subscriptions after a reconnect.
-async fn resubscribe_all(inner: &StreamInner) {
-    let subs = inner.subscriptions.read().await;
-    let entries: Vec<_> = subs.keys().cloned().collect();
-    drop(subs);
+/// 재연결 후 세션의 구독을 다시 등록하고 결과를 알린다.
+///
+/// 응답은 수신 루프가 받으므로 수신 루프와 별도 태스크에서 돈다. 서버가 거부한 구독은
+/// 목록에서 빼고, 응답이 없거나 연결이 다시 끊긴 구독은 다음 재연결 때 다시 시도한다.
+/// approval key가 거부되면 구독은 모두 남겨 두고 멈춘다. 수신 루프가 연결을 끊고 키를
+/// 재발급한 뒤 다시 등록한다. 요청이 진행 중인 구독은 그 요청에 맡긴다.
+///
+/// 모든 구독에 서버 응답(등록 또는 구독별 거부)을 받았으면 `true`를 돌려준다.
+async fn resubscribe_all(inner: Arc<StreamInner>, index: usize) -> bool {
+    let session = &inner.sessions[index];
+    let keys: Vec<_> = inner
+        .subscriptions
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|(_, sub)| sub.session == index && sub.ack.is_some() && sub.busy.is_none())
+        .map(|(key, _)| key.clone())
+        .collect();
 
-    for (symbol, kind) in entries {
-        if let Err(e) =
-            send_subscribe_raw(&inner.ws_tx, &inner.approval_key, &symbol, kind, true).await
-        {
-            log::warn!("failed to re-subscribe {symbol}/{kind:?}: {e}");
+    let timeout = inner.ack_timeout();
+    let (mut count, mut failed) = (0, 0);
+    let mut restored = true;
+    for (symbol, kind) in keys {
+        let Some((ack, reservation)) = inner.reserve_existing(&(symbol.clone(), kind)) else {
+            continue;
+        };
+        match session.request(&symbol, kind, true, timeout).await {
+            Ok(ack) => {
+                count += 1;
+                reservation.finish(Some(ack));
+            }
+            Err(e) => {
+                failed += 1;
+                tracing::warn!(target: "kis_api", "failed to re-subscribe {symbol}/{kind:?}: {e}");
+                let approval = matches!(
+                    &e,
+                    KisError::SubscribeRejected { message, .. } if is_approval_message(message)
+                );
+                let rejected = matches!(e, KisError::SubscribeRejected { .. }) && !approval;
+                restored &= rejected;
+                reservation.finish((!rejected).then_some(ack));
+                inner
+                    .notify(ConnectionEvent::ResubscribeFailed {
+                        session: index,
+                        tr_id: session.tr_id(kind).to_string(),
+                        tr_key: symbol,
+                        reason: e.to_string(),
+                    })
+                    .await;
+                if approval {
+                    break;
+                }
+            }
         }
     }
-}
-
-/// Calculate backoff duration with jitter.
-fn backoff_duration(attempt: u32) -> std::time::Duration {
-    use rand::Rng;
-
-    let base_ms = BACKOFF_INITIAL_MS.saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX));
-    let capped_ms = base_ms.min(BACKOFF_MAX_MS);
-    let jitter_ms =
-        (capped_ms as f64 * BACKOFF_JITTER_FRACTION * rand::thread_rng().gen::<f64>()) as u64;
-    std::time::Duration::from_millis(capped_ms + jitter_ms)
+    inner
+        .notify(ConnectionEvent::Resubscribed {
+            session: index,
+            count,
+            failed,
+        })
+        .await;
+    restored
 }
 
 type WsReadHalf = futures_util::stream::SplitStream<
@@ -242,14 +1172,23 @@ type WsReadHalf = futures_util::stream::SplitStream<
 /// Core connection loop: reads messages, handles PINGPONG, and reconnects on failure.
 ///
 /// Accepts the read half from the initial connection (done in `connect`).
-/// On disconnect it reconnects with exponential backoff and re-subscribes.
-async fn run_connection_loop(inner: Arc<StreamInner>, initial_ws_read: WsReadHalf) {
+/// On disconnect it reconnects per the stream's [`ReconnectPolicy`] and re-subscribes.
+async fn run_connection_loop(inner: Arc<StreamInner>, index: usize, initial_ws_read: WsReadHalf) {
     use tokio_tungstenite::connect_async;
 
     let cancel = &inner.cancel;
+    let session = &inner.sessions[index];
+    let policy = &inner.reconnect;
+    // 마지막 정상 연결 이후 재연결 시도 횟수 (대기 시간·포기 판단·보고용)
     let mut attempt: u32 = 0;
+    let mut resubscribe: Option<tokio::task::JoinHandle<bool>> = None;
     let mut current_ws_read = Some(initial_ws_read);
 
+    session.set_state(ConnectionState::Connected);
+    inner
+        .notify(ConnectionEvent::Connected { session: index })
+        .await;
+
     loop {
         if cancel.is_cancelled() {
             break;
@@ -259,12 +1198,37 @@ async fn run_connection_loop(inner: Arc<StreamInner>, initial_ws_read: WsReadHal
         let ws_read = if let Some(initial) = current_ws_read.take() {
             initial
         } else {
+            attempt = attempt.saturating_add(1);
+            if policy.exhausted(attempt) {
+                let attempts = attempt - 1;
+                tracing::warn!(target: "kis_api",
+                    "WS giving up after {attempts} reconnect attempts (session {index})"
+                );
+                inner
+                    .notify(ConnectionEvent::GaveUp {
+                        session: index,
+                        attempts,
+                    })
+                    .await;
+                if policy.give_up == GiveUp::CloseStream {
+                    cancel.cancel();
+                }
+                break;
+            }
+
             // Backoff before reconnecting
-            let delay = backoff_duration(attempt.saturating_sub(1));
-            log::info!(
+            let delay = policy.backoff(attempt);
+            tracing::info!(target: "kis_api",
                 "WS reconnecting in {}ms (attempt {attempt})",
                 delay.as_millis()
             );
+            session.set_state(ConnectionState::Reconnecting { attempt });
+            inner
+                .notify(ConnectionEvent::Reconnecting {
+                    session: index,
+                    attempt,
+                })
+                .await;
 
             tokio::select! {
                 _ = cancel.cancelled() => break,
@@ -275,55 +1239,96 @@ async fn run_connection_loop(inner: Arc<StreamInner>, initial_ws_read: WsReadHal
                 break;
             }
 
+            // 거부된 approval key는 재연결 전에 새로 발급받는다
+            if let Err(e) = session.refresh_rejected_key().await {
+                tracing::warn!(target: "kis_api", "approval key refresh failed: {e}");
+                continue;
+            }
+
             // Establish new connection
-            match connect_async(&inner.config.ws_url).await {
+            match connect_async(session.ws_url.as_str()).await {
                 Ok((ws_stream, _)) => {
                     let (ws_write, ws_read) = ws_stream.split();
-                    *inner.ws_tx.lock().await = Some(ws_write);
+                    *session.ws_tx.lock().await = Some(ws_write);
 
-                    log::info!("WS reconnected successfully");
-                    // Re-subscribe all active subscriptions
-                    resubscribe_all(&inner).await;
+                    tracing::info!(target: "kis_api", "WS reconnected successfully");
+                    {
+                        let mut status = session.status.lock().unwrap();
+                        status.state = ConnectionState::Connected;
+                        status.reconnects += 1;
+                    }
+                    inner
+                        .notify(ConnectionEvent::Connected { session: index })
+                        .await;
+                    // 응답은 수신 루프가 받으므로 재구독은 따로 돌린다
+                    resubscribe = Some(tokio::spawn(resubscribe_all(inner.clone(), index)));
 
                     ws_read
                 }
                 Err(e) => {
-                    log::warn!("WS connect failed: {e}");
-                    attempt = attempt.saturating_add(1);
+                    tracing::warn!(target: "kis_api", "WS connect failed: {e}");
                     continue;
                 }
             }
         };
 
         // Read loop
-        let (disconnect_reason, had_data) = read_loop(&inner, ws_read, cancel).await;
+        let (disconnect_reason, had_data) = read_loop(&inner, session, ws_read, cancel).await;
 
         // Clear the writer since connection is dead
-        *inner.ws_tx.lock().await = None;
+        *session.ws_tx.lock().await = None;
+        // 응답을 기다리던 요청은 즉시 실패시킨다
+        session.pending_acks.lock().unwrap().clear();
 
-        match disconnect_reason {
+        let reason = match disconnect_reason {
             DisconnectReason::Cancelled => break,
-            DisconnectReason::Error(e) => {
-                log::warn!("WS disconnected: {e}");
+            DisconnectReason::Error(e) => e,
+            DisconnectReason::Eof => "connection closed by server".to_string(),
+            DisconnectReason::HeartbeatTimeout(timeout) => {
+                format!("no message for {}ms", timeout.as_millis())
             }
-            DisconnectReason::Eof => {
-                log::warn!("WS connection closed by server");
-            }
-        }
+            DisconnectReason::ApprovalRejected(msg) => format!("approval key rejected: {msg}"),
+        };
+        tracing::warn!(target: "kis_api", "WS disconnected: {reason}");
+        session.set_state(ConnectionState::Reconnecting { attempt: 0 });
+        let tr_keys: std::collections::BTreeSet<String> = inner
+            .subscriptions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, sub)| sub.session == index)
+            .map(|((symbol, _), _)| symbol.clone())
+            .collect();
+        inner
+            .notify(ConnectionEvent::Disconnected {
+                session: index,
+                reason,
+                tr_keys: tr_keys.into_iter().collect(),
+            })
+            .await;
 
-        // Only reset backoff after sustained connection (received data messages)
-        if had_data {
-            attempt = 0; // reset backoff — connection was healthy
-        } else {
-            attempt = attempt.saturating_add(1);
+        // 시세를 받았거나 재구독을 마쳤으면 정상 연결로 보고 시도 횟수를 되돌린다.
+        // 응답 대기는 위에서 실패시켰으므로 재구독 태스크는 곧 끝난다.
+        let restored = match resubscribe.take() {
+            Some(task) => task.await.unwrap_or(false),
+            None => false,
+        };
+        if had_data || restored {
+            attempt = 0;
         }
     }
+
+    session.set_state(ConnectionState::Closed);
 }
 
 enum DisconnectReason {
     Cancelled,
     Error(String),
     Eof,
+    /// 감시 시간 동안 아무 메시지도 없음
+    HeartbeatTimeout(std::time::Duration),
+    /// 서버가 approval key를 거부함 (응답 메시지)
+    ApprovalRejected(String),
 }
 
 /// Read messages from the WebSocket, handle PINGPONG, and dispatch events.
@@ -332,6 +1337,7 @@ enum DisconnectReason {
 /// data message was successfully parsed and broadcast during this connection.
 async fn read_loop(
     inner: &StreamInner,
+    session: &Session,
     ws_read: WsReadHalf,
     cancel: &CancellationToken,
 ) -> (DisconnectReason, bool) {
@@ -339,33 +1345,58 @@ async fn read_loop(
 
     let mut reader = ws_read;
     let mut had_data = false;
+    let mut heartbeat = inner.heartbeat_timeout();
+    let mut deadline = tokio::time::Instant::now() + heartbeat;
 
     loop {
         tokio::select! {
             _ = cancel.cancelled() => return (DisconnectReason::Cancelled, had_data),
+            _ = tokio::time::sleep_until(deadline), if !heartbeat.is_zero() => {
+                return (DisconnectReason::HeartbeatTimeout(heartbeat), had_data);
+            }
             msg = reader.next() => {
+                if let Some(Ok(_)) = msg {
+                    session.status.lock().unwrap().last_message = Some(std::time::Instant::now());
+                    heartbeat = inner.heartbeat_timeout();
+                    deadline = tokio::time::Instant::now() + heartbeat;
+                }
                 match msg {
                     Some(Ok(Message::Text(text))) => {
+                        #[cfg(feature = "recorder")]
+                        inner.record(session.index, &text);
                         match classify_text_message(&text) {
                             TextMessage::PingPong => {
                                 // Echo the PINGPONG message back to keep the connection alive
-                                let mut guard = inner.ws_tx.lock().await;
+                                let mut guard = session.ws_tx.lock().await;
                                 if let Some(ref mut writer) = *guard {
                                     if let Err(e) = writer.send(Message::Text(text)).await {
-                                        log::warn!("failed to send PINGPONG response: {e}");
+                                        tracing::warn!(target: "kis_api", "failed to send PINGPONG response: {e}");
                                         return (DisconnectReason::Error(e.to_string()), had_data);
                                     }
                                 }
-                                log::info!("PINGPONG heartbeat responded");
+                                tracing::debug!(target: "kis_api", "PINGPONG heartbeat responded");
                             }
-                            TextMessage::OtherJson => {
-                                // Other JSON control messages — log and skip
-                                log::info!("WS control message: {text}");
+                            TextMessage::OtherJson(json) => {
+                                // 구독 응답이면 암호화 TR의 복호화 키를 저장
+                                if let Some((tr_id, cipher)) = subscribe_ack_cipher(&json) {
+                                    session.ciphers.write().unwrap().insert(tr_id, cipher);
+                                }
+                                if let Some(ack) = parse_subscribe_ack(&json) {
+                                    let rejected = ack.is_approval_rejected().then(|| ack.msg1.clone());
+                                    session.resolve_ack(ack);
+                                    if let Some(msg) = rejected {
+                                        session.key_rejected.store(true, std::sync::atomic::Ordering::Relaxed);
+                                        return (DisconnectReason::ApprovalRejected(msg), had_data);
+                                    }
+                                }
+                                tracing::info!(target: "kis_api", "WS control message: {text}");
                             }
                             TextMessage::Data => {
-                                if let Some(event) = parse_ws_message(&text) {
+                                let mut events = parse_ws_message(&text, &session.ciphers.read().unwrap());
+                                session.fills.lock().unwrap().track(&mut events);
+                                for event in events {
                                     had_data = true;
-                                    let _ = inner.tx.send(event);
+                                    inner.publish(event).await;
                                 }
                             }
                         }
@@ -388,8 +1419,8 @@ async fn read_loop(
 enum TextMessage {
     /// KIS PINGPONG heartbeat — must echo back.
     PingPong,
-    /// Other JSON control message (not PINGPONG).
-    OtherJson,
+    /// Other JSON control message (not PINGPONG), e.g. subscribe ack.
+    OtherJson(serde_json::Value),
     /// Pipe-delimited market data.
     Data,
 }
@@ -398,27 +1429,131 @@ fn classify_text_message(text: &str) -> TextMessage {
     if !text.starts_with('{') {
         return TextMessage::Data;
     }
-    // Minimal JSON check for PINGPONG: look for "PINGPONG" in tr_id
-    if let Ok(v) = serde_json::from_str::<serde_json::Value>(text) {
-        if v.get("header")
-            .and_then(|h| h.get("tr_id"))
+    let v = serde_json::from_str::<serde_json::Value>(text).unwrap_or_default();
+    if v.get("header")
+        .and_then(|h| h.get("tr_id"))
+        .and_then(|t| t.as_str())
+        == Some("PINGPONG")
+    {
+        return TextMessage::PingPong;
+    }
+    TextMessage::OtherJson(v)
+}
+
+/// 구독 응답 `body.output`의 AES256 key/iv (체결통보 등 암호화 TR)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrameCipher {
+    key: String,
+    iv: String,
+}
+
+type CipherKeys = HashMap<String, FrameCipher>;
+
+impl FrameCipher {
+    /// base64 암호문 → 평문 (AES-256-CBC, PKCS7)
+    fn decrypt(&self, payload: &str) -> Option<String> {
+        use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+        use base64::Engine;
+
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(payload)
+            .ok()?;
+        let plain =
+            cbc::Decryptor::<aes::Aes256>::new_from_slices(self.key.as_bytes(), self.iv.as_bytes())
+                .ok()?
+                .decrypt_padded_vec_mut::<Pkcs7>(&data)
+                .ok()?;
+        String::from_utf8(plain).ok()
+    }
+}
+
+/// 구독/해제 응답 파싱. `header.tr_id`와 `body.rt_cd`가 있는 제어 메시지.
+fn parse_subscribe_ack(json: &serde_json::Value) -> Option<SubscribeAck> {
+    let header = json.get("header")?;
+    let body = json.get("body")?;
+    let text = |v: &serde_json::Value, key: &str| {
+        v.get(key)
             .and_then(|t| t.as_str())
-            == Some("PINGPONG")
-        {
-            return TextMessage::PingPong;
+            .unwrap_or_default()
+            .to_string()
+    };
+    Some(SubscribeAck {
+        tr_id: header.get("tr_id")?.as_str()?.to_string(),
+        tr_key: text(header, "tr_key"),
+        rt_cd: body.get("rt_cd")?.as_str()?.to_string(),
+        msg_cd: text(body, "msg_cd"),
+        msg1: text(body, "msg1"),
+    })
+}
+
+/// 구독 응답에서 (TR ID, 복호화 키) 추출. key/iv가 없는 응답은 `None`.
+fn subscribe_ack_cipher(json: &serde_json::Value) -> Option<(String, FrameCipher)> {
+    let tr_id = json.get("header")?.get("tr_id")?.as_str()?;
+    let output = json.get("body")?.get("output")?;
+    let key = output.get("key")?.as_str()?;
+    let iv = output.get("iv")?.as_str()?;
+    if key.is_empty() || iv.is_empty() {
+        return None;
+    }
+    Some((
+        tr_id.to_string(),
+        FrameCipher {
+            key: key.to_string(),
+            iv: iv.to_string(),
+        },
+    ))
+}
+
+/// 기록 재생용 프레임 해석기. 수신 루프처럼 세션별 복호화 키를 유지한다.
+#[cfg(feature = "recorder")]
+#[derive(Default)]
+pub(crate) struct FrameDecoder {
+    ciphers: HashMap<usize, CipherKeys>,
+    fills: FillTracker,
+}
+
+#[cfg(feature = "recorder")]
+impl FrameDecoder {
+    /// 원본 텍스트 프레임 → 이벤트. 제어 메시지는 복호화 키만 갱신하고 이벤트를 만들지 않는다.
+    ///
+    /// `received`는 기록된 수신 시각이다. 재생 시점이 아니라 수신 당시 날짜로 시각을 해석한다.
+    pub(crate) fn decode(
+        &mut self,
+        session: usize,
+        text: &str,
+        received: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<KisEvent> {
+        let ciphers = self.ciphers.entry(session).or_default();
+        match classify_text_message(text) {
+            TextMessage::PingPong => Vec::new(),
+            TextMessage::OtherJson(json) => {
+                if let Some((tr_id, cipher)) = subscribe_ack_cipher(&json) {
+                    ciphers.insert(tr_id, cipher);
+                }
+                Vec::new()
+            }
+            TextMessage::Data => {
+                let mut events = parse_frame(text, ciphers, received).unwrap_or_default();
+                self.fills.track(&mut events);
+                events
+            }
         }
     }
-    TextMessage::OtherJson
 }
 
-// ── HDFSCNT0 필드 인덱스 (KIS 해외 실시간 체결) ──────────────────────────
-const HDFSCNT0_F_SYMBOL: usize = 1;
-const HDFSCNT0_F_TIME: usize = 2; // HHMMSS (KST)
-const HDFSCNT0_F_PRICE: usize = 11;
-const HDFSCNT0_F_QTY: usize = 19;
-const HDFSCNT0_F_IS_BUY: usize = 21; // "1"=매수, "2"=매도
+/// `암호화|TR_ID|건수|데이터` 프레임 파싱. 암호화 플래그가 `1`이면 복호화 후 파싱한다.
+///
+/// 한 프레임에 건수만큼의 레코드가 이어 붙어 오므로 레코드마다 이벤트를 만든다.
+fn parse_ws_message(text: &str, ciphers: &CipherKeys) -> Vec<KisEvent> {
+    parse_frame(text, ciphers, chrono::Utc::now()).unwrap_or_default()
+}
 
-fn parse_ws_message(text: &str) -> Option<KisEvent> {
+/// `received`는 프레임 수신 시각. 영업일자가 없는 TR의 날짜를 정하는 데 쓴다.
+fn parse_frame(
+    text: &str,
+    ciphers: &CipherKeys,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<Vec<KisEvent>> {
     if text.starts_with('{') {
         return None; // JSON 제어 메시지
     }
@@ -426,47 +1561,243 @@ fn parse_ws_message(text: &str) -> Option<KisEvent> {
     if parts.len() < 4 {
         return None;
     }
-    let fields: Vec<&str> = parts[3].split('^').collect();
-    match parts[1] {
-        "HDFSCNT0" => parse_transaction(&fields),
-        "HDFSASP0" | "HDFSASP1" => parse_quote(&fields),
-        "H0STCNT0" => parse_domestic_transaction(&fields),
-        "H0STASP0" | "H0STASP1" => parse_domestic_quote(&fields),
-        _ => None,
+    let count: usize = parts[2].parse().ok()?;
+    let decrypted;
+    let data = if parts[0] == "1" {
+        let Some(cipher) = ciphers.get(parts[1]) else {
+            tracing::warn!(target: "kis_api", "[{}] 복호화 키 없이 암호화 데이터 수신", parts[1]);
+            return None;
+        };
+        decrypted = cipher.decrypt(parts[3])?;
+        decrypted.as_str()
+    } else {
+        parts[3]
+    };
+    let fields: Vec<&str> = data.split('^').collect();
+    let events = match parts[1] {
+        "HDFSCNT0" => records(&fields, count)
+            .filter_map(parse_transaction)
+            .collect(),
+        "HDFSASP0" => records(&fields, count).flat_map(parse_quote).collect(),
+        "HDFSASP1" => records(&fields, count).flat_map(parse_quote_asia).collect(),
+        "H0STCNT0" => records(&fields, count)
+            .filter_map(parse_domestic_transaction)
+            .collect(),
+        "H0STASP0" => records(&fields, count)
+            .flat_map(|r| parse_domestic_quote(r, received))
+            .collect(),
+        "H0STCNI0" | "H0STCNI9" => records(&fields, count)
+            .filter_map(|r| parse_domestic_notice(r, received))
+            .collect(),
+        "H0GSCNI0" | "H0GSCNI9" => records(&fields, count)
+            .filter_map(|r| parse_overseas_notice(r, received))
+            .collect(),
+        "H0IFCNI0" | "H0IFCNI9" => records(&fields, count)
+            .filter_map(|r| parse_futureoption_notice(r, received))
+            .collect(),
+        "H0MFCNI0" => records(&fields, count)
+            .filter_map(|r| parse_night_future_notice(r, received))
+            .collect(),
+        "H0EUCNI0" => records(&fields, count)
+            .filter_map(|r| parse_night_option_notice(r, received))
+            .collect(),
+        "H0STMKO0" => records(&fields, count)
+            .filter_map(|r| parse_krx_market_status(r, received))
+            .collect(),
+        "H0NXMKO0" => records(&fields, count)
+            .filter_map(|r| parse_nxt_market_status(r, received))
+            .collect(),
+        // 명세(10개 필드)에는 종목코드가 없다. KRX·NXT처럼 종목코드가 앞에 붙은
+        // 11개 필드로 오면 그 배치로 읽는다.
+        "H0UNMKO0" if fields.len() == count * TryitoutH0stmko0Response::FIELD_COUNT => {
+            records(&fields, count)
+                .filter_map(|r| {
+                    parse_krx_market_status(r, received).map(|e| with_venue(e, Venue::Integrated))
+                })
+                .collect()
+        }
+        "H0UNMKO0" => records(&fields, count)
+            .filter_map(|r| parse_integrated_market_status(r, received))
+            .collect(),
+        // 지수·상품·야간 선물은 필드 배치가 같다 (야간선물이 가장 짧다)
+        "H0IFCNT0" | "H0CFCNT0" | "H0MFCNT0" => {
+            let product = derivative_product(parts[1])?;
+            records(&fields, count)
+                .filter_map(|r| parse_future_trade(product, r, received))
+                .collect()
+        }
+        "H0ZFCNT0" => records(&fields, count)
+            .filter_map(|r| parse_stock_future_trade(r, received))
+            .collect(),
+        // 옵션 체결은 주식옵션 배치에 지수·야간 옵션이 필드를 덧붙인 형태
+        "H0IOCNT0" | "H0ZOCNT0" | "H0EUCNT0" => {
+            let product = derivative_product(parts[1])?;
+            records(&fields, count)
+                .filter_map(|r| parse_option_trade(product, r, received))
+                .collect()
+        }
+        // 5단계 호가는 선물·옵션 모두 필드 배치가 같다
+        "H0IFASP0" | "H0IOASP0" | "H0CFASP0" | "H0MFASP0" | "H0EUASP0" => records(&fields, count)
+            .flat_map(|r| parse_derivative_quote(r, received))
+            .collect(),
+        "H0ZFASP0" => records(&fields, count)
+            .flat_map(|r| parse_stock_future_quote(r, received))
+            .collect(),
+        "H0ZOASP0" => records(&fields, count)
+            .flat_map(|r| parse_stock_option_quote(r, received))
+            .collect(),
+        "HDFFF020" => records(&fields, count)
+            .filter_map(parse_overseas_derivative_trade)
+            .collect(),
+        "HDFFF010" => records(&fields, count)
+            .flat_map(parse_overseas_derivative_quote)
+            .collect(),
+        "HDFFF1C0" => records(&fields, count)
+            .filter_map(parse_overseas_derivative_order)
+            .collect(),
+        "HDFFF2C0" => records(&fields, count)
+            .filter_map(parse_overseas_derivative_fill)
+            .collect(),
+        // NXT·통합 TR은 KRX와 필드 배치가 같고 뒤에 필드가 더 붙을 뿐이므로 KRX 레코드로 읽는다
+        "H0STANC0" | "H0NXANC0" | "H0UNANC0" => {
+            let venue = venue_of(parts[1]);
+            records(&fields, count)
+                .filter_map(|r| parse_expected_execution(venue, r))
+                .collect()
+        }
+        "H0STMBC0" | "H0NXMBC0" | "H0UNMBC0" => {
+            let venue = venue_of(parts[1]);
+            records(&fields, count)
+                .filter_map(|r| parse_member_trading(venue, r, received))
+                .collect()
+        }
+        "H0STPGM0" | "H0NXPGM0" | "H0UNPGM0" => {
+            let venue = venue_of(parts[1]);
+            records(&fields, count)
+                .filter_map(|r| parse_program_trading(venue, r, received))
+                .collect()
+        }
+        _ => return None,
+    };
+    Some(events)
+}
+
+/// 필드를 레코드 `count`건으로 분할한다.
+///
+/// 레코드 길이는 명세의 `FIELD_COUNT`이지만, 전체 필드 수가 건수로 나누어떨어지면서
+/// 더 길면 KIS가 필드를 덧붙인 것으로 보고 그 길이를 쓴다.
+fn records<'a, R: RealtimeRecord + 'a>(
+    fields: &'a [&'a str],
+    count: usize,
+) -> impl Iterator<Item = R> + 'a {
+    let stride = match fields.len().checked_div(count) {
+        Some(n) if n > R::FIELD_COUNT && fields.len().is_multiple_of(count) => n,
+        _ => R::FIELD_COUNT,
+    };
+    fields
+        .chunks(stride.max(1))
+        .take(count)
+        .filter_map(R::from_fields)
+}
+
+fn kst() -> chrono::FixedOffset {
+    chrono::FixedOffset::east_opt(9 * 3600).expect("KST offset")
+}
+
+/// YYYYMMDD + HHMMSS를 `offset` 기준 현지 시각으로 해석
+fn local_datetime(
+    offset: chrono::FixedOffset,
+    yyyymmdd: &str,
+    hhmmss: &str,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    use chrono::{NaiveDate, NaiveTime, TimeZone};
+
+    let date = NaiveDate::parse_from_str(yyyymmdd, "%Y%m%d").ok()?;
+    let time = NaiveTime::parse_from_str(hhmmss, "%H%M%S").ok()?;
+    offset.from_local_datetime(&date.and_time(time)).single()
+}
+
+/// 국내 시각: 영업일자(YYYYMMDD) + HHMMSS(KST).
+///
+/// 영업일자가 비어 있으면 지금에 가장 가까운 날짜로 본다 ([`kst_near`]).
+pub(crate) fn kst_at(
+    yyyymmdd: &str,
+    hhmmss: &str,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    if yyyymmdd.is_empty() {
+        return kst_near(hhmmss, chrono::Utc::now());
+    }
+    local_datetime(kst(), yyyymmdd, hhmmss)
+}
+
+/// 영업일자가 없는 TR(H0STASP0, 선물옵션 체결·호가, 체결통보 등)의 HHMMSS(KST)를 `received`에 가장 가까운
+/// 날짜(전날·당일·다음날 중)로 해석한다. 자정 무렵 받은 23:59:59 프레임은 전날이 된다.
+pub(crate) fn kst_near(
+    hhmmss: &str,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let today = received.with_timezone(&kst()).date_naive();
+    [today.pred_opt(), Some(today), today.succ_opt()]
+        .into_iter()
+        .flatten()
+        .filter_map(|date| local_datetime(kst(), &date.format("%Y%m%d").to_string(), hhmmss))
+        .min_by_key(|time| (time.timestamp() - received.timestamp()).abs())
+}
+
+/// 해외 시각: 현지일자/현지시간(XYMD/XHMS)을 거래소 현지 오프셋으로 해석한다.
+///
+/// 오프셋은 같은 순간의 한국일자/한국시간(KYMD/KHMS)과의 차이로 구하므로
+/// 서머타임이 반영된다. 현지 필드가 없으면 한국일시를 KST로 쓴다.
+pub(crate) fn exchange_local_at(
+    xymd: &str,
+    xhms: &str,
+    kymd: &str,
+    khms: &str,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    use chrono::FixedOffset;
+
+    let korea = kst_at(kymd, khms)?;
+    if kymd.is_empty() {
+        return Some(korea);
+    }
+    let Some(local) = local_datetime(FixedOffset::east_opt(0)?, xymd, xhms) else {
+        return Some(korea);
+    };
+    // 현지 일시를 UTC로 읽은 값 - 실제 순간 = 현지 오프셋. 15분 단위로 반올림
+    let secs = (local.timestamp() - korea.timestamp()) as f64;
+    let offset = ((secs / 900.0).round() as i32) * 900;
+    Some(FixedOffset::east_opt(offset).map_or(korea, |offset| korea.with_timezone(&offset)))
+}
+
+/// 해외 레코드의 종목코드와 실시간 키.
+///
+/// `RSYM`(예: `DNASNVDA`)에서 거래소와 종목을 파싱하고, 파싱되지 않으면 `SYMB`를 그대로 쓴다.
+fn overseas_symbol(rsym: &str, symb: String) -> (String, Option<OverseasRealtimeKey>) {
+    match rsym.parse::<OverseasRealtimeKey>() {
+        Ok(key) => (key.ticker.clone(), Some(key)),
+        Err(_) => (symb, None),
     }
 }
 
-fn parse_transaction(fields: &[&str]) -> Option<KisEvent> {
+fn parse_transaction(r: TryitoutHdfscnt0Response) -> Option<KisEvent> {
     use crate::event::TransactionData;
-    use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
     use rust_decimal::Decimal;
     use std::str::FromStr;
 
-    let symbol = fields.get(HDFSCNT0_F_SYMBOL)?.to_string();
+    let (symbol, overseas_key) = overseas_symbol(&r.rsym, r.symb);
     if symbol.is_empty() {
         return None;
     }
 
-    let price = Decimal::from_str(fields.get(HDFSCNT0_F_PRICE)?).ok()?;
-    let qty = Decimal::from_str(fields.get(HDFSCNT0_F_QTY)?).ok()?;
-    let is_buy = match *fields.get(HDFSCNT0_F_IS_BUY)? {
-        "1" => true,
-        "2" => false,
-        _ => {
-            log::warn!("HDFSCNT0: unknown is_buy field");
-            return None;
-        }
-    };
-
-    // HHMMSS → DateTime<FixedOffset> (KST UTC+9)
-    let hhmmss = fields.get(HDFSCNT0_F_TIME)?;
-    let naive_time = NaiveTime::parse_from_str(hhmmss, "%H%M%S").ok()?;
-    let naive_dt = Utc::now().date_naive().and_time(naive_time);
-    let kst = FixedOffset::east_opt(9 * 3600)?;
-    let time = kst.from_local_datetime(&naive_dt).single()?;
+    let price = Decimal::from_str(&r.last).ok()?;
+    let qty = Decimal::from_str(&r.evol).ok()?;
+    // HDFSCNT0에는 체결 방향 필드가 없으므로 매도호가 이상 체결을 매수로 본다.
+    let is_buy = Decimal::from_str(&r.pask).is_ok_and(|ask| price >= ask);
+    let time = exchange_local_at(&r.xymd, &r.xhms, &r.kymd, &r.khms)?;
 
     Some(KisEvent::Transaction(TransactionData {
         symbol,
+        overseas_key,
         price,
         qty,
         time,
@@ -474,78 +1805,150 @@ fn parse_transaction(fields: &[&str]) -> Option<KisEvent> {
     }))
 }
 
-// ── HDFSASP0/1 필드 인덱스 (KIS 해외 실시간 호가) ──────────────────────────
-const HDFSASP_F_SYMBOL: usize = 1;
-const HDFSASP_F_TIME: usize = 2;
-const HDFSASP_F_ASK: usize = 14;
-const HDFSASP_F_BID: usize = 15;
-const HDFSASP_F_ASK_QTY: usize = 16;
-const HDFSASP_F_BID_QTY: usize = 17;
+fn parse_quote(r: TryitoutHdfsasp0Response) -> Vec<KisEvent> {
+    let asks = price_levels(
+        &[
+            &r.pask1, &r.pask2, &r.pask3, &r.pask4, &r.pask5, &r.pask6, &r.pask7, &r.pask8,
+            &r.pask9, &r.pask10,
+        ],
+        &[
+            &r.vask1, &r.vask2, &r.vask3, &r.vask4, &r.vask5, &r.vask6, &r.vask7, &r.vask8,
+            &r.vask9, &r.vask10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.pbid1, &r.pbid2, &r.pbid3, &r.pbid4, &r.pbid5, &r.pbid6, &r.pbid7, &r.pbid8,
+            &r.pbid9, &r.pbid10,
+        ],
+        &[
+            &r.vbid1, &r.vbid2, &r.vbid3, &r.vbid4, &r.vbid5, &r.vbid6, &r.vbid7, &r.vbid8,
+            &r.vbid9, &r.vbid10,
+        ],
+    );
+    overseas_book(
+        &r.rsym,
+        r.symb,
+        [&r.xymd, &r.xhms, &r.kymd, &r.khms],
+        &r.avol,
+        &r.bvol,
+        asks,
+        bids,
+    )
+}
+
+fn parse_quote_asia(r: TryitoutHdfsasp1Response) -> Vec<KisEvent> {
+    let asks = price_levels(&[&r.pask1], &[&r.vask1]);
+    let bids = price_levels(&[&r.pbid1], &[&r.vbid1]);
+    overseas_book(
+        &r.rsym,
+        r.symb,
+        [&r.xymd, &r.xhms, &r.kymd, &r.khms],
+        &r.avol,
+        &r.bvol,
+        asks,
+        bids,
+    )
+}
 
-fn parse_quote(fields: &[&str]) -> Option<KisEvent> {
-    use crate::event::QuoteData;
-    use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
+fn overseas_book(
+    rsym: &str,
+    symb: String,
+    [xymd, xhms, kymd, khms]: [&str; 4],
+    total_ask_qty: &str,
+    total_bid_qty: &str,
+    asks: Vec<PriceLevel>,
+    bids: Vec<PriceLevel>,
+) -> Vec<KisEvent> {
     use rust_decimal::Decimal;
     use std::str::FromStr;
 
-    let symbol = fields.get(HDFSASP_F_SYMBOL)?.to_string();
+    let (symbol, overseas_key) = overseas_symbol(rsym, symb);
     if symbol.is_empty() {
-        return None;
+        return vec![];
     }
+    let Some(time) = exchange_local_at(xymd, xhms, kymd, khms) else {
+        return vec![];
+    };
+    book_events(OrderBookData {
+        symbol,
+        overseas_key,
+        asks,
+        bids,
+        total_ask_qty: Decimal::from_str(total_ask_qty).unwrap_or_default(),
+        total_bid_qty: Decimal::from_str(total_bid_qty).unwrap_or_default(),
+        expected_price: None,
+        expected_qty: None,
+        session_code: None,
+        time,
+    })
+}
 
-    let ask_price = Decimal::from_str(fields.get(HDFSASP_F_ASK)?).ok()?;
-    let bid_price = Decimal::from_str(fields.get(HDFSASP_F_BID)?).ok()?;
-    let ask_qty = Decimal::from_str(fields.get(HDFSASP_F_ASK_QTY)?).ok()?;
-    let bid_qty = Decimal::from_str(fields.get(HDFSASP_F_BID_QTY)?).ok()?;
+/// 호가 단계 목록. 가격이 비었거나 0인 단계는 제외한다.
+pub(crate) fn price_levels(prices: &[&String], qtys: &[&String]) -> Vec<PriceLevel> {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
 
-    let hhmmss = fields.get(HDFSASP_F_TIME)?;
-    let naive_time = NaiveTime::parse_from_str(hhmmss, "%H%M%S").ok()?;
-    let naive_dt = Utc::now().date_naive().and_time(naive_time);
-    let kst = FixedOffset::east_opt(9 * 3600)?;
-    let time = kst.from_local_datetime(&naive_dt).single()?;
+    prices
+        .iter()
+        .zip(qtys)
+        .filter_map(|(price, qty)| {
+            let price = Decimal::from_str(price).ok().filter(|p| !p.is_zero())?;
+            Some(PriceLevel {
+                price,
+                qty: Decimal::from_str(qty).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
 
-    Some(KisEvent::Quote(QuoteData {
-        symbol,
-        ask_price,
-        bid_price,
-        ask_qty,
-        bid_qty,
-        time,
-    }))
+/// 0이거나 비어 있으면 `None`
+fn nonzero_decimal(s: &str) -> Option<rust_decimal::Decimal> {
+    use std::str::FromStr;
+
+    rust_decimal::Decimal::from_str(s)
+        .ok()
+        .filter(|d| !d.is_zero())
 }
 
-// ── H0STCNT0 필드 인덱스 (KIS 국내 실시간 체결) ──────────────────────────
-// 필드 구조 (pipe-separated after header): 종목코드^시간^현재가^...^체결량^...
-// ⚠ 정확한 인덱스는 KIS OpenAPI 개발가이드 "H0STCNT0" 항목에서 확인 필요
-const H0STCNT0_F_SYMBOL: usize = 0;
-const H0STCNT0_F_TIME: usize = 1; // HHMMSS (KST)
-const H0STCNT0_F_PRICE: usize = 2; // 주식현재가
-const H0STCNT0_F_QTY: usize = 9; // 체결거래량
-const H0STCNT0_F_IS_BUY: usize = 20; // 체결구분: "1"=매수, "2"=매도
+/// 호가 레코드 → 최우선 호가(`Quote`, 양쪽 1호가가 있을 때) + 전체 호가(`OrderBook`)
+fn book_events(book: OrderBookData) -> Vec<KisEvent> {
+    let quote = match (book.asks.first(), book.bids.first()) {
+        (Some(ask), Some(bid)) => Some(KisEvent::Quote(QuoteData {
+            symbol: book.symbol.clone(),
+            overseas_key: book.overseas_key.clone(),
+            ask_price: ask.price,
+            bid_price: bid.price,
+            ask_qty: ask.qty,
+            bid_qty: bid.qty,
+            time: book.time,
+        })),
+        _ => None,
+    };
+    quote
+        .into_iter()
+        .chain(std::iter::once(KisEvent::OrderBook(book)))
+        .collect()
+}
 
-fn parse_domestic_transaction(fields: &[&str]) -> Option<KisEvent> {
+fn parse_domestic_transaction(r: TryitoutH0stcnt0Response) -> Option<KisEvent> {
     use crate::event::TransactionData;
-    use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
     use rust_decimal::Decimal;
     use std::str::FromStr;
 
-    let symbol = fields.get(H0STCNT0_F_SYMBOL)?.to_string();
-    if symbol.is_empty() {
+    if r.mksc_shrn_iscd.is_empty() {
         return None;
     }
 
-    let price = Decimal::from_str(fields.get(H0STCNT0_F_PRICE)?).ok()?;
-    let qty = Decimal::from_str(fields.get(H0STCNT0_F_QTY)?).ok()?;
-    let is_buy = fields.get(H0STCNT0_F_IS_BUY).is_none_or(|v| *v == "1");
-
-    let hhmmss = fields.get(H0STCNT0_F_TIME)?;
-    let naive_time = NaiveTime::parse_from_str(hhmmss, "%H%M%S").ok()?;
-    let naive_dt = Utc::now().date_naive().and_time(naive_time);
-    let kst = FixedOffset::east_opt(9 * 3600)?;
-    let time = kst.from_local_datetime(&naive_dt).single()?;
+    let price = Decimal::from_str(&r.stck_prpr).ok()?;
+    let qty = Decimal::from_str(&r.cntg_vol).ok()?;
+    // 체결구분: 1=매수(+), 3=장전, 5=매도(-)
+    let is_buy = r.ccld_dvsn == "1";
+    let time = kst_at(&r.bsop_date, &r.stck_cntg_hour)?;
 
     Some(KisEvent::Transaction(TransactionData {
-        symbol,
+        symbol: r.mksc_shrn_iscd,
+        overseas_key: None,
         price,
         qty,
         time,
@@ -553,177 +1956,1359 @@ fn parse_domestic_transaction(fields: &[&str]) -> Option<KisEvent> {
     }))
 }
 
-// ── H0STASP0 필드 인덱스 (KIS 국내 실시간 호가) ──────────────────────────
-// ⚠ 정확한 인덱스는 KIS OpenAPI 개발가이드 "H0STASP0" 항목에서 확인 필요
-const H0STASP0_F_SYMBOL: usize = 0;
-const H0STASP0_F_TIME: usize = 1;
-const H0STASP0_F_ASK_PRICE: usize = 3; // 매도호가1
-const H0STASP0_F_BID_PRICE: usize = 4; // 매수호가1
-const H0STASP0_F_ASK_QTY: usize = 13; // 매도호가잔량1
-const H0STASP0_F_BID_QTY: usize = 14; // 매수호가잔량1
+fn parse_domestic_quote(
+    r: TryitoutH0stasp0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Vec<KisEvent> {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    if r.mksc_shrn_iscd.is_empty() {
+        return vec![];
+    }
+    // H0STASP0에는 영업일자 필드가 없다
+    let Some(time) = kst_near(&r.bsop_hour, received) else {
+        return vec![];
+    };
+    let asks = price_levels(
+        &[
+            &r.askp1, &r.askp2, &r.askp3, &r.askp4, &r.askp5, &r.askp6, &r.askp7, &r.askp8,
+            &r.askp9, &r.askp10,
+        ],
+        &[
+            &r.askp_rsqn1,
+            &r.askp_rsqn2,
+            &r.askp_rsqn3,
+            &r.askp_rsqn4,
+            &r.askp_rsqn5,
+            &r.askp_rsqn6,
+            &r.askp_rsqn7,
+            &r.askp_rsqn8,
+            &r.askp_rsqn9,
+            &r.askp_rsqn10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.bidp1, &r.bidp2, &r.bidp3, &r.bidp4, &r.bidp5, &r.bidp6, &r.bidp7, &r.bidp8,
+            &r.bidp9, &r.bidp10,
+        ],
+        &[
+            &r.bidp_rsqn1,
+            &r.bidp_rsqn2,
+            &r.bidp_rsqn3,
+            &r.bidp_rsqn4,
+            &r.bidp_rsqn5,
+            &r.bidp_rsqn6,
+            &r.bidp_rsqn7,
+            &r.bidp_rsqn8,
+            &r.bidp_rsqn9,
+            &r.bidp_rsqn10,
+        ],
+    );
+    book_events(OrderBookData {
+        asks,
+        bids,
+        total_ask_qty: Decimal::from_str(&r.total_askp_rsqn).unwrap_or_default(),
+        total_bid_qty: Decimal::from_str(&r.total_bidp_rsqn).unwrap_or_default(),
+        expected_price: nonzero_decimal(&r.antc_cnpr),
+        expected_qty: nonzero_decimal(&r.antc_cnqn),
+        session_code: Some(r.hour_cls_code).filter(|c| !c.is_empty()),
+        time,
+        symbol: r.mksc_shrn_iscd,
+        overseas_key: None,
+    })
+}
+
+/// 체결통보 TR 공통 필드 (H0STCNI0 / H0GSCNI0 / H0IFCNI0 / H0MFCNI0 / H0EUCNI0)
+struct NoticeFields {
+    cust_id: String,
+    acnt_no: String,
+    oder_no: String,
+    ooder_no: String,
+    /// 01 매도, 02 매수
+    seln_byov_cls: String,
+    /// 0 정상, 1 정정, 2 취소
+    rctf_cls: String,
+    symbol: String,
+    /// 체결통보면 체결수량, 그 외에는 주문수량
+    cntg_qty: String,
+    /// 체결통보면 체결단가, 그 외에는 주문단가
+    cntg_unpr: String,
+    hour: String,
+    /// 0 승인, 1 거부
+    rfus_yn: String,
+    /// 1 주문·정정·취소·거부, 2 체결
+    cntg_yn: String,
+    /// 1 주문접수, 2 확인, 3 취소(IOC/FOK)
+    acpt_yn: String,
+    oder_qty: String,
+    /// 주문가격 필드가 있는 TR만
+    oder_prc: String,
+}
 
-fn parse_domestic_quote(fields: &[&str]) -> Option<KisEvent> {
-    use crate::event::QuoteData;
-    use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
+fn parse_order_notice(
+    market: OrderMarket,
+    f: NoticeFields,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
     use rust_decimal::Decimal;
     use std::str::FromStr;
 
-    let symbol = fields.get(H0STASP0_F_SYMBOL)?.to_string();
-    if symbol.is_empty() {
+    if f.oder_no.is_empty() {
         return None;
     }
+    let side = match f.seln_byov_cls.as_str() {
+        "01" => Some(OrderSide::Sell),
+        "02" => Some(OrderSide::Buy),
+        other => {
+            tracing::warn!(target: "kis_api", "unknown order side {other:?} in order notice {}", f.oder_no);
+            None
+        }
+    };
+    let kind = if f.rfus_yn == "1" {
+        OrderNoticeKind::Rejected
+    } else if f.cntg_yn == "2" {
+        OrderNoticeKind::Filled
+    } else if f.rctf_cls == "2" || f.acpt_yn == "3" {
+        OrderNoticeKind::Cancelled
+    } else if f.rctf_cls == "1" {
+        OrderNoticeKind::Modified
+    } else {
+        OrderNoticeKind::Accepted
+    };
 
-    let ask_price = Decimal::from_str(fields.get(H0STASP0_F_ASK_PRICE)?).ok()?;
-    let bid_price = Decimal::from_str(fields.get(H0STASP0_F_BID_PRICE)?).ok()?;
-    let ask_qty = Decimal::from_str(fields.get(H0STASP0_F_ASK_QTY)?).ok()?;
-    let bid_qty = Decimal::from_str(fields.get(H0STASP0_F_BID_QTY)?).ok()?;
+    let qty = Decimal::from_str(&f.cntg_qty).unwrap_or_default();
+    let unit_price = nonzero_decimal(&f.cntg_unpr);
+    let order_qty = Decimal::from_str(&f.oder_qty).unwrap_or(qty);
+    let (filled_qty, filled_price, order_price) = if kind == OrderNoticeKind::Filled {
+        (
+            qty,
+            unit_price.unwrap_or_default(),
+            nonzero_decimal(&f.oder_prc),
+        )
+    } else {
+        let order_price = nonzero_decimal(&f.oder_prc).or(unit_price);
+        (Decimal::ZERO, Decimal::ZERO, order_price)
+    };
+    let remaining_qty = match kind {
+        OrderNoticeKind::Cancelled | OrderNoticeKind::Rejected => Decimal::ZERO,
+        _ => order_qty,
+    };
 
-    let hhmmss = fields.get(H0STASP0_F_TIME)?;
-    let naive_time = NaiveTime::parse_from_str(hhmmss, "%H%M%S").ok()?;
-    let naive_dt = Utc::now().date_naive().and_time(naive_time);
-    let kst = FixedOffset::east_opt(9 * 3600)?;
-    let time = kst.from_local_datetime(&naive_dt).single()?;
+    Some(KisEvent::OrderConfirm(OrderConfirmData {
+        market,
+        customer_id: f.cust_id,
+        account: f.acnt_no,
+        original_order_id: Some(f.ooder_no).filter(|o| !o.trim_start_matches('0').is_empty()),
+        order_id: f.oder_no,
+        symbol: f.symbol,
+        kind,
+        side,
+        order_qty,
+        order_price,
+        filled_qty,
+        filled_price,
+        remaining_qty,
+        time: kst_near(&f.hour, received)?,
+    }))
+}
 
-    Some(KisEvent::Quote(QuoteData {
-        symbol,
-        ask_price,
-        bid_price,
-        ask_qty,
-        bid_qty,
-        time,
+fn parse_domestic_notice(
+    r: TryitoutH0stcni0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_order_notice(
+        OrderMarket::DomesticStock,
+        NoticeFields {
+            cust_id: r.cust_id,
+            acnt_no: r.acnt_no,
+            oder_no: r.oder_no,
+            ooder_no: r.ooder_no,
+            seln_byov_cls: r.seln_byov_cls,
+            rctf_cls: r.rctf_cls,
+            symbol: r.stck_shrn_iscd,
+            cntg_qty: r.cntg_qty,
+            cntg_unpr: r.cntg_unpr,
+            hour: r.stck_cntg_hour,
+            rfus_yn: r.rfus_yn,
+            cntg_yn: r.cntg_yn,
+            acpt_yn: r.acpt_yn,
+            oder_qty: r.oder_qty,
+            oder_prc: r.oder_prc,
+        },
+        received,
+    )
+}
+
+fn parse_overseas_notice(
+    r: TryitoutH0gscni0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    // CNTG_UNPR12는 소수점이 포함된 단가
+    let cntg_unpr = if r.cntg_unpr12.is_empty() {
+        r.cntg_unpr
+    } else {
+        r.cntg_unpr12
+    };
+    parse_order_notice(
+        OrderMarket::OverseasStock,
+        NoticeFields {
+            cust_id: r.cust_id,
+            acnt_no: r.acnt_no,
+            oder_no: r.oder_no,
+            ooder_no: r.ooder_no,
+            seln_byov_cls: r.seln_byov_cls,
+            rctf_cls: r.rctf_cls,
+            symbol: r.stck_shrn_iscd,
+            cntg_qty: r.cntg_qty,
+            cntg_unpr,
+            hour: r.stck_cntg_hour,
+            rfus_yn: r.rfus_yn,
+            cntg_yn: r.cntg_yn,
+            acpt_yn: r.acpt_yn,
+            oder_qty: r.oder_qty,
+            oder_prc: String::new(),
+        },
+        received,
+    )
+}
+
+fn parse_futureoption_notice(
+    r: TryitoutH0ifcni0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_order_notice(
+        OrderMarket::FutureOption,
+        NoticeFields {
+            cust_id: r.cust_id,
+            acnt_no: r.acnt_no,
+            oder_no: r.oder_no,
+            ooder_no: r.ooder_no,
+            seln_byov_cls: r.seln_byov_cls,
+            rctf_cls: r.rctf_cls,
+            symbol: r.stck_shrn_iscd,
+            cntg_qty: r.cntg_qty,
+            cntg_unpr: r.cntg_unpr,
+            hour: r.stck_cntg_hour,
+            rfus_yn: r.rfus_yn,
+            cntg_yn: r.cntg_yn,
+            acpt_yn: r.acpt_yn,
+            oder_qty: r.oder_qty,
+            oder_prc: r.order_prc,
+        },
+        received,
+    )
+}
+
+fn parse_night_future_notice(
+    r: TryitoutH0mfcni0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_order_notice(
+        OrderMarket::NightFuture,
+        NoticeFields {
+            cust_id: r.cust_id,
+            acnt_no: r.acnt_no,
+            oder_no: r.oder_no,
+            ooder_no: r.ooder_no,
+            seln_byov_cls: r.seln_byov_cls,
+            rctf_cls: r.rctf_cls,
+            symbol: r.stck_shrn_iscd,
+            cntg_qty: r.cntg_qty,
+            cntg_unpr: r.cntg_unpr,
+            hour: r.stck_cntg_hour,
+            rfus_yn: r.rfus_yn,
+            cntg_yn: r.cntg_yn,
+            acpt_yn: r.acpt_yn,
+            oder_qty: r.oder_qty,
+            oder_prc: String::new(),
+        },
+        received,
+    )
+}
+
+fn parse_night_option_notice(
+    r: TryitoutH0eucni0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_order_notice(
+        OrderMarket::NightOption,
+        NoticeFields {
+            cust_id: r.cust_id,
+            acnt_no: r.acnt_no,
+            oder_no: r.oder_no,
+            ooder_no: r.ooder_no,
+            seln_byov_cls: r.seln_byov_cls,
+            rctf_cls: r.rctf_cls,
+            symbol: r.stck_shrn_iscd,
+            cntg_qty: r.cntg_qty,
+            cntg_unpr: r.cntg_unpr,
+            hour: r.stck_cntg_hour,
+            rfus_yn: r.rfus_yn,
+            cntg_yn: r.cntg_yn,
+            acpt_yn: r.acpt_yn,
+            oder_qty: r.oder_qty,
+            oder_prc: String::new(),
+        },
+        received,
+    )
+}
+
+/// 장운영정보 TR 공통 필드 (H0STMKO0 / H0NXMKO0 / H0UNMKO0)
+struct MarketStatusFields {
+    symbol: String,
+    trht_yn: String,
+    tr_susp_reas_cntt: String,
+    mkop_cls_code: String,
+    antc_mkop_cls_code: String,
+    iscd_stat_cls_code: String,
+    vi_cls_code: String,
+    ovtm_vi_cls_code: String,
+}
+
+fn parse_market_status(
+    venue: Venue,
+    f: MarketStatusFields,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    let halt_reason = f.tr_susp_reas_cntt.trim().to_string();
+    Some(KisEvent::MarketStatus(MarketStatusEvent {
+        symbol: f.symbol,
+        venue,
+        halted: f.trht_yn == "Y",
+        halt_reason: Some(halt_reason).filter(|r| !r.is_empty()),
+        phase: MarketPhase::from_code(&f.mkop_cls_code),
+        expected_phase_code: f.antc_mkop_cls_code,
+        vi: ViStatus::from_code(&f.vi_cls_code),
+        overtime_vi: ViStatus::from_code(&f.ovtm_vi_cls_code),
+        status_code: f.iscd_stat_cls_code,
+        time: received.with_timezone(&kst()),
     }))
 }
 
-#[cfg(any(test, feature = "test-utils"))]
-impl KisStream {
-    /// 테스트용 (KisStream, broadcast::Sender<KisEvent>) 쌍 반환.
-    /// Sender를 통해 테스트 코드에서 직접 이벤트 또는 drop으로 StreamClosed를 주입 가능.
-    pub fn test_pair() -> (KisStream, broadcast::Sender<KisEvent>) {
-        let (tx, _) = broadcast::channel(128);
-        let cancel = CancellationToken::new();
-        let stream = KisStream {
-            inner: Arc::new(StreamInner {
-                config: KisConfig {
-                    app_key: "test".into(),
-                    app_secret: "test".into(),
-                    account_num: "00000000-01".into(),
-                    rest_url: "http://localhost".into(),
-                    ws_url: "ws://localhost".into(),
-                    mock: true,
-                    is_domestic_virtual: false,
-                    token_cache_path: None,
-                    ws_event_buffer: 128,
-                },
-                approval_key: "test_key".into(),
-                tx: tx.clone(),
-                subscriptions: RwLock::new(HashMap::new()),
-                cancel,
-                ws_tx: Mutex::new(None),
-            }),
-        };
-        (stream, tx)
+fn with_venue(mut event: KisEvent, venue: Venue) -> KisEvent {
+    if let KisEvent::MarketStatus(status) = &mut event {
+        status.venue = venue;
     }
+    event
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_krx_market_status(
+    r: TryitoutH0stmko0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_market_status(
+        Venue::Krx,
+        MarketStatusFields {
+            symbol: r.mksc_shrn_iscd,
+            trht_yn: r.trht_yn,
+            tr_susp_reas_cntt: r.tr_susp_reas_cntt,
+            mkop_cls_code: r.mkop_cls_code,
+            antc_mkop_cls_code: r.antc_mkop_cls_code,
+            iscd_stat_cls_code: r.iscd_stat_cls_code,
+            vi_cls_code: r.vi_cls_code,
+            ovtm_vi_cls_code: r.ovtm_vi_cls_code,
+        },
+        received,
+    )
+}
 
-    #[test]
-    fn subscription_kind_eq() {
-        assert_eq!(SubscriptionKind::Price, SubscriptionKind::Price);
-        assert_ne!(SubscriptionKind::Price, SubscriptionKind::Orderbook);
+fn parse_nxt_market_status(
+    r: TryitoutH0nxmko0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_market_status(
+        Venue::Nxt,
+        MarketStatusFields {
+            symbol: r.mksc_shrn_iscd,
+            trht_yn: r.trht_yn,
+            tr_susp_reas_cntt: r.tr_susp_reas_cntt,
+            mkop_cls_code: r.mkop_cls_code,
+            antc_mkop_cls_code: r.antc_mkop_cls_code,
+            iscd_stat_cls_code: r.iscd_stat_cls_code,
+            vi_cls_code: r.vi_cls_code,
+            ovtm_vi_cls_code: r.ovtm_vi_cls_code,
+        },
+        received,
+    )
+}
+
+fn parse_integrated_market_status(
+    r: TryitoutH0unmko0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    parse_market_status(
+        Venue::Integrated,
+        MarketStatusFields {
+            symbol: String::new(),
+            trht_yn: r.trht_yn,
+            tr_susp_reas_cntt: r.tr_susp_reas_cntt,
+            mkop_cls_code: r.mkop_cls_code,
+            antc_mkop_cls_code: r.antc_mkop_cls_code,
+            iscd_stat_cls_code: r.iscd_stat_cls_code,
+            vi_cls_code: r.vi_cls_code,
+            ovtm_vi_cls_code: r.ovtm_vi_cls_code,
+        },
+        received,
+    )
+}
+
+/// 국내주식 TR ID의 시장 구분 (`H0ST…` KRX, `H0NX…` NXT, `H0UN…` 통합)
+fn venue_of(tr_id: &str) -> Venue {
+    match tr_id.get(..4) {
+        Some("H0NX") => Venue::Nxt,
+        Some("H0UN") => Venue::Integrated,
+        _ => Venue::Krx,
     }
+}
 
-    #[test]
-    fn subscription_kind_is_hashable() {
-        let mut map = HashMap::new();
-        map.insert(("AAPL".to_string(), SubscriptionKind::Price), ());
-        assert!(map.contains_key(&("AAPL".to_string(), SubscriptionKind::Price)));
+pub(crate) fn decimal_or_zero(s: &str) -> rust_decimal::Decimal {
+    use std::str::FromStr;
+
+    rust_decimal::Decimal::from_str(s.trim()).unwrap_or_default()
+}
+
+fn parse_expected_execution(venue: Venue, r: TryitoutH0stanc0Response) -> Option<KisEvent> {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    if r.mksc_shrn_iscd.is_empty() {
+        return None;
     }
+    Some(KisEvent::ExpectedExecution(ExpectedExecutionData {
+        price: Decimal::from_str(&r.stck_prpr).ok()?,
+        qty: decimal_or_zero(&r.cntg_vol),
+        change: decimal_or_zero(&r.prdy_vrss),
+        change_rate: decimal_or_zero(&r.prdy_ctrt),
+        accumulated_volume: decimal_or_zero(&r.acml_vol),
+        session_code: Some(r.hour_cls_code).filter(|c| !c.is_empty()),
+        time: kst_at(&r.bsop_date, &r.stck_cntg_hour)?,
+        symbol: r.mksc_shrn_iscd,
+        venue,
+    }))
+}
 
-    fn assert_send_sync<T: Send + Sync>() {}
+/// 거래원 1곳: (코드, 이름, 수량, 증감, 비중, 외국계 구분). 코드가 비면 제외한다
+fn member_flows(members: [[&String; 6]; 5]) -> Vec<MemberFlow> {
+    members
+        .into_iter()
+        .filter(|[no, ..]| !no.trim().is_empty())
+        .map(|[no, name, qty, change, ratio, foreign]| MemberFlow {
+            member_no: no.trim().to_string(),
+            name: name.trim().to_string(),
+            qty: decimal_or_zero(qty),
+            qty_change: decimal_or_zero(change),
+            ratio: decimal_or_zero(ratio),
+            foreign: matches!(foreign.as_str(), "Y" | "1"),
+        })
+        .collect()
+}
 
-    #[test]
-    fn kis_stream_is_send_sync() {
-        assert_send_sync::<KisStream>();
+fn parse_member_trading(
+    venue: Venue,
+    r: TryitoutH0stmbc0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    if r.mksc_shrn_iscd.is_empty() {
+        return None;
     }
+    let sellers = member_flows([
+        [
+            &r.seln_mbcr_no1,
+            &r.seln2_mbcr_name1,
+            &r.total_seln_qty1,
+            &r.seln_qty_icdc1,
+            &r.seln_mbcr_rlim1,
+            &r.seln_mbcr_glob_yn_1,
+        ],
+        [
+            &r.seln_mbcr_no2,
+            &r.seln2_mbcr_name2,
+            &r.total_seln_qty2,
+            &r.seln_qty_icdc2,
+            &r.seln_mbcr_rlim2,
+            &r.seln_mbcr_glob_yn_2,
+        ],
+        [
+            &r.seln_mbcr_no3,
+            &r.seln2_mbcr_name3,
+            &r.total_seln_qty3,
+            &r.seln_qty_icdc3,
+            &r.seln_mbcr_rlim3,
+            &r.seln_mbcr_glob_yn_3,
+        ],
+        [
+            &r.seln_mbcr_no4,
+            &r.seln2_mbcr_name4,
+            &r.total_seln_qty4,
+            &r.seln_qty_icdc4,
+            &r.seln_mbcr_rlim4,
+            &r.seln_mbcr_glob_yn_4,
+        ],
+        [
+            &r.seln_mbcr_no5,
+            &r.seln2_mbcr_name5,
+            &r.total_seln_qty5,
+            &r.seln_qty_icdc5,
+            &r.seln_mbcr_rlim5,
+            &r.seln_mbcr_glob_yn_5,
+        ],
+    ]);
+    let buyers = member_flows([
+        [
+            &r.shnu_mbcr_no1,
+            &r.byov_mbcr_name1,
+            &r.total_shnu_qty1,
+            &r.shnu_qty_icdc1,
+            &r.shnu_mbcr_rlim1,
+            &r.shnu_mbcr_glob_yn_1,
+        ],
+        [
+            &r.shnu_mbcr_no2,
+            &r.byov_mbcr_name2,
+            &r.total_shnu_qty2,
+            &r.shnu_qty_icdc2,
+            &r.shnu_mbcr_rlim2,
+            &r.shnu_mbcr_glob_yn_2,
+        ],
+        [
+            &r.shnu_mbcr_no3,
+            &r.byov_mbcr_name3,
+            &r.total_shnu_qty3,
+            &r.shnu_qty_icdc3,
+            &r.shnu_mbcr_rlim3,
+            &r.shnu_mbcr_glob_yn_3,
+        ],
+        [
+            &r.shnu_mbcr_no4,
+            &r.byov_mbcr_name4,
+            &r.total_shnu_qty4,
+            &r.shnu_qty_icdc4,
+            &r.shnu_mbcr_rlim4,
+            &r.shnu_mbcr_glob_yn_4,
+        ],
+        [
+            &r.shnu_mbcr_no5,
+            &r.byov_mbcr_name5,
+            &r.total_shnu_qty5,
+            &r.shnu_qty_icdc5,
+            &r.shnu_mbcr_rlim5,
+            &r.shnu_mbcr_glob_yn_5,
+        ],
+    ]);
+    Some(KisEvent::MemberTrading(MemberTradingData {
+        venue,
+        sellers,
+        buyers,
+        foreign_sell_qty: decimal_or_zero(&r.glob_total_seln_qty),
+        foreign_buy_qty: decimal_or_zero(&r.glob_total_shnu_qty),
+        foreign_net_qty: decimal_or_zero(&r.glob_ntby_qty),
+        // H0STMBC0에는 시각 필드가 없다
+        time: received.with_timezone(&kst()),
+        symbol: r.mksc_shrn_iscd,
+    }))
+}
 
-    #[test]
-    fn event_receiver_type_exists() {
-        // EventReceiver가 컴파일되는지 확인 (런타임 생성은 KisStream 필요)
-        let _ = std::mem::size_of::<EventReceiver>();
+fn parse_program_trading(
+    venue: Venue,
+    r: TryitoutH0stpgm0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    if r.mksc_shrn_iscd.is_empty() {
+        return None;
     }
+    Some(KisEvent::ProgramTrading(ProgramTradingData {
+        venue,
+        sell_qty: decimal_or_zero(&r.seln_cnqn),
+        sell_amount: decimal_or_zero(&r.seln_tr_pbmn),
+        buy_qty: decimal_or_zero(&r.shnu_cnqn),
+        buy_amount: decimal_or_zero(&r.shnu_tr_pbmn),
+        net_qty: decimal_or_zero(&r.ntby_cnqn),
+        net_amount: decimal_or_zero(&r.ntby_tr_pbmn),
+        ask_qty: decimal_or_zero(&r.seln_rsqn),
+        bid_qty: decimal_or_zero(&r.shnu_rsqn),
+        net_bid_qty: decimal_or_zero(&r.whol_ntby_qty),
+        time: kst_near(&r.stck_cntg_hour, received)?,
+        symbol: r.mksc_shrn_iscd,
+    }))
+}
 
-    #[test]
-    fn parse_hdfscnt0_transaction() {
-        let mut fields = vec![""; 26];
-        fields[1] = "NVDA";
-        fields[2] = "143022"; // 14:30:22 KST
-        fields[11] = "134.20";
-        fields[19] = "50";
-        fields[21] = "1"; // 매수
-        let data = fields.join("^");
-        let msg = format!("0|HDFSCNT0|1|{}", data);
+/// 체결가 TR ID의 파생상품 구분
+fn derivative_product(tr_id: &str) -> Option<DerivativeProduct> {
+    Some(match tr_id {
+        "H0IFCNT0" => DerivativeProduct::IndexFuture,
+        "H0IOCNT0" => DerivativeProduct::IndexOption,
+        "H0CFCNT0" => DerivativeProduct::CommodityFuture,
+        "H0ZFCNT0" => DerivativeProduct::StockFuture,
+        "H0ZOCNT0" => DerivativeProduct::StockOption,
+        "H0MFCNT0" => DerivativeProduct::NightFuture,
+        "H0EUCNT0" => DerivativeProduct::NightOption,
+        _ => return None,
+    })
+}
 
-        let result = parse_ws_message(&msg);
-        assert!(result.is_some(), "should parse HDFSCNT0");
-        if let Some(KisEvent::Transaction(tx)) = result {
-            use rust_decimal_macros::dec;
-            assert_eq!(tx.symbol, "NVDA");
-            assert_eq!(tx.price, dec!(134.20));
-            assert_eq!(tx.qty, dec!(50));
-            assert!(tx.is_buy);
-        } else {
-            panic!("expected Transaction event");
-        }
+/// 값이 있으면 파싱 (0도 유효한 값)
+fn decimal_opt(s: &str) -> Option<rust_decimal::Decimal> {
+    use std::str::FromStr;
+
+    rust_decimal::Decimal::from_str(s.trim()).ok()
+}
+
+fn parse_future_trade(
+    product: DerivativeProduct,
+    r: TryitoutH0mfcnt0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    if r.futs_shrn_iscd.is_empty() {
+        return None;
     }
+    Some(KisEvent::DerivativeTrade(DerivativeTradeData {
+        product,
+        price: decimal_opt(&r.futs_prpr)?,
+        qty: decimal_or_zero(&r.last_cnqn),
+        change: decimal_or_zero(&r.futs_prdy_vrss),
+        change_rate: decimal_or_zero(&r.futs_prdy_ctrt),
+        accumulated_volume: decimal_or_zero(&r.acml_vol),
+        open_interest: decimal_or_zero(&r.hts_otst_stpl_qty),
+        open_interest_change: decimal_or_zero(&r.otst_stpl_qty_icdc),
+        theoretical_price: nonzero_decimal(&r.hts_thpr),
+        market_basis: decimal_opt(&r.mrkt_basis),
+        theoretical_basis: decimal_opt(&r.thpr_basis),
+        ask_price: nonzero_decimal(&r.futs_askp1),
+        bid_price: nonzero_decimal(&r.futs_bidp1),
+        greeks: None,
+        time: kst_near(&r.bsop_hour, received)?,
+        symbol: r.futs_shrn_iscd,
+    }))
+}
 
-    #[test]
-    fn parse_json_returns_none() {
-        let json = r#"{"header":{"tr_id":"PINGPONG"},"body":{}}"#;
-        assert!(parse_ws_message(json).is_none());
+fn parse_stock_future_trade(
+    r: TryitoutH0zfcnt0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    if r.futs_shrn_iscd.is_empty() {
+        return None;
     }
+    Some(KisEvent::DerivativeTrade(DerivativeTradeData {
+        product: DerivativeProduct::StockFuture,
+        price: decimal_opt(&r.stck_prpr)?,
+        qty: decimal_or_zero(&r.last_cnqn),
+        change: decimal_or_zero(&r.prdy_vrss),
+        change_rate: decimal_or_zero(&r.futs_prdy_ctrt),
+        accumulated_volume: decimal_or_zero(&r.acml_vol),
+        open_interest: decimal_or_zero(&r.hts_otst_stpl_qty),
+        open_interest_change: decimal_or_zero(&r.otst_stpl_qty_icdc),
+        theoretical_price: nonzero_decimal(&r.hts_thpr),
+        market_basis: decimal_opt(&r.mrkt_basis),
+        theoretical_basis: decimal_opt(&r.thpr_basis),
+        ask_price: nonzero_decimal(&r.askp1),
+        bid_price: nonzero_decimal(&r.bidp1),
+        greeks: None,
+        time: kst_near(&r.bsop_hour, received)?,
+        symbol: r.futs_shrn_iscd,
+    }))
+}
 
-    #[test]
-    fn parse_unknown_trid_returns_none() {
-        assert!(parse_ws_message("0|UNKNOWN|1|data").is_none());
+fn parse_option_trade(
+    product: DerivativeProduct,
+    r: TryitoutH0zocnt0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Option<KisEvent> {
+    if r.optn_shrn_iscd.is_empty() {
+        return None;
     }
+    Some(KisEvent::DerivativeTrade(DerivativeTradeData {
+        product,
+        price: decimal_opt(&r.optn_prpr)?,
+        qty: decimal_or_zero(&r.last_cnqn),
+        change: decimal_or_zero(&r.optn_prdy_vrss),
+        change_rate: decimal_or_zero(&r.prdy_ctrt),
+        accumulated_volume: decimal_or_zero(&r.acml_vol),
+        open_interest: decimal_or_zero(&r.hts_otst_stpl_qty),
+        open_interest_change: decimal_or_zero(&r.otst_stpl_qty_icdc),
+        theoretical_price: nonzero_decimal(&r.hts_thpr),
+        market_basis: decimal_opt(&r.mrkt_basis),
+        theoretical_basis: decimal_opt(&r.thpr_basis),
+        ask_price: nonzero_decimal(&r.optn_askp1),
+        bid_price: nonzero_decimal(&r.optn_bidp1),
+        greeks: Some(Greeks {
+            delta: decimal_or_zero(&r.delta),
+            gamma: decimal_or_zero(&r.gama),
+            vega: decimal_or_zero(&r.vega),
+            theta: decimal_or_zero(&r.theta),
+            rho: decimal_or_zero(&r.rho),
+            implied_volatility: decimal_or_zero(&r.hts_ints_vltl),
+            intrinsic_value: decimal_or_zero(&r.invl_val),
+            time_value: decimal_or_zero(&r.tmvl_val),
+        }),
+        time: kst_near(&r.bsop_hour, received)?,
+        symbol: r.optn_shrn_iscd,
+    }))
+}
 
-    #[test]
-    fn parse_malformed_returns_none() {
-        assert!(parse_ws_message("not|enough").is_none());
-        assert!(parse_ws_message("").is_none());
+/// 파생상품 호가 레코드 → `Quote` + `OrderBook`
+fn derivative_book(
+    symbol: String,
+    bsop_hour: &str,
+    received: chrono::DateTime<chrono::Utc>,
+    asks: Vec<PriceLevel>,
+    bids: Vec<PriceLevel>,
+    total_ask_qty: &str,
+    total_bid_qty: &str,
+) -> Vec<KisEvent> {
+    if symbol.is_empty() {
+        return vec![];
     }
+    let Some(time) = kst_near(bsop_hour, received) else {
+        return vec![];
+    };
+    book_events(OrderBookData {
+        symbol,
+        overseas_key: None,
+        asks,
+        bids,
+        total_ask_qty: decimal_or_zero(total_ask_qty),
+        total_bid_qty: decimal_or_zero(total_bid_qty),
+        expected_price: None,
+        expected_qty: None,
+        session_code: None,
+        time,
+    })
+}
 
-    #[test]
-    fn parse_bad_decimal_returns_none() {
-        let mut fields = vec![""; 26];
-        fields[1] = "NVDA";
-        fields[2] = "143022";
-        fields[11] = "NOT_A_NUMBER";
-        fields[19] = "50";
-        fields[21] = "1";
-        let msg = format!("0|HDFSCNT0|1|{}", fields.join("^"));
-        assert!(parse_ws_message(&msg).is_none());
+fn parse_derivative_quote(
+    r: TryitoutH0ifasp0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Vec<KisEvent> {
+    let asks = price_levels(
+        &[
+            &r.futs_askp1,
+            &r.futs_askp2,
+            &r.futs_askp3,
+            &r.futs_askp4,
+            &r.futs_askp5,
+        ],
+        &[
+            &r.askp_rsqn1,
+            &r.askp_rsqn2,
+            &r.askp_rsqn3,
+            &r.askp_rsqn4,
+            &r.askp_rsqn5,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.futs_bidp1,
+            &r.futs_bidp2,
+            &r.futs_bidp3,
+            &r.futs_bidp4,
+            &r.futs_bidp5,
+        ],
+        &[
+            &r.bidp_rsqn1,
+            &r.bidp_rsqn2,
+            &r.bidp_rsqn3,
+            &r.bidp_rsqn4,
+            &r.bidp_rsqn5,
+        ],
+    );
+    derivative_book(
+        r.futs_shrn_iscd,
+        &r.bsop_hour,
+        received,
+        asks,
+        bids,
+        &r.total_askp_rsqn,
+        &r.total_bidp_rsqn,
+    )
+}
+
+fn parse_stock_future_quote(
+    r: TryitoutH0zfasp0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Vec<KisEvent> {
+    let asks = price_levels(
+        &[
+            &r.askp1, &r.askp2, &r.askp3, &r.askp4, &r.askp5, &r.askp6, &r.askp7, &r.askp8,
+            &r.askp9, &r.askp10,
+        ],
+        &[
+            &r.askp_rsqn1,
+            &r.askp_rsqn2,
+            &r.askp_rsqn3,
+            &r.askp_rsqn4,
+            &r.askp_rsqn5,
+            &r.askp_rsqn6,
+            &r.askp_rsqn7,
+            &r.askp_rsqn8,
+            &r.askp_rsqn9,
+            &r.askp_rsqn10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.bidp1, &r.bidp2, &r.bidp3, &r.bidp4, &r.bidp5, &r.bidp6, &r.bidp7, &r.bidp8,
+            &r.bidp9, &r.bidp10,
+        ],
+        &[
+            &r.bidp_rsqn1,
+            &r.bidp_rsqn2,
+            &r.bidp_rsqn3,
+            &r.bidp_rsqn4,
+            &r.bidp_rsqn5,
+            &r.bidp_rsqn6,
+            &r.bidp_rsqn7,
+            &r.bidp_rsqn8,
+            &r.bidp_rsqn9,
+            &r.bidp_rsqn10,
+        ],
+    );
+    derivative_book(
+        r.futs_shrn_iscd,
+        &r.bsop_hour,
+        received,
+        asks,
+        bids,
+        &r.total_askp_rsqn,
+        &r.total_bidp_rsqn,
+    )
+}
+
+fn parse_stock_option_quote(
+    r: TryitoutH0zoasp0Response,
+    received: chrono::DateTime<chrono::Utc>,
+) -> Vec<KisEvent> {
+    let asks = price_levels(
+        &[
+            &r.optn_askp1,
+            &r.optn_askp2,
+            &r.optn_askp3,
+            &r.optn_askp4,
+            &r.optn_askp5,
+            &r.optn_askp6,
+            &r.optn_askp7,
+            &r.optn_askp8,
+            &r.optn_askp9,
+            &r.optn_askp10,
+        ],
+        &[
+            &r.askp_rsqn1,
+            &r.askp_rsqn2,
+            &r.askp_rsqn3,
+            &r.askp_rsqn4,
+            &r.askp_rsqn5,
+            &r.askp_rsqn6,
+            &r.askp_rsqn7,
+            &r.askp_rsqn8,
+            &r.askp_rsqn9,
+            &r.askp_rsqn10,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.optn_bidp1,
+            &r.optn_bidp2,
+            &r.optn_bidp3,
+            &r.optn_bidp4,
+            &r.optn_bidp5,
+            &r.optn_bidp6,
+            &r.optn_bidp7,
+            &r.optn_bidp8,
+            &r.optn_bidp9,
+            &r.optn_bidp10,
+        ],
+        &[
+            &r.bidp_rsqn1,
+            &r.bidp_rsqn2,
+            &r.bidp_rsqn3,
+            &r.bidp_rsqn4,
+            &r.bidp_rsqn5,
+            &r.bidp_rsqn6,
+            &r.bidp_rsqn7,
+            &r.bidp_rsqn8,
+            &r.bidp_rsqn9,
+            &r.bidp_rsqn10,
+        ],
+    );
+    derivative_book(
+        r.optn_shrn_iscd,
+        &r.bsop_hour,
+        received,
+        asks,
+        bids,
+        &r.total_askp_rsqn,
+        &r.total_bidp_rsqn,
+    )
+}
+
+/// 부호 없이 오는 대비 값에 등락 부호(4 하한, 5 하락)를 붙인다
+fn signed_change(value: &str, sign: &str) -> rust_decimal::Decimal {
+    let value = decimal_or_zero(value);
+    match sign {
+        "4" | "5" if value.is_sign_positive() => -value,
+        _ => value,
     }
+}
 
-    #[test]
-    fn parse_hdfsasp0_quote() {
-        let mut fields = vec![""; 30];
-        fields[1] = "AAPL";
-        fields[2] = "150000";
-        fields[14] = "191.00"; // ask
-        fields[15] = "190.90"; // bid
-        fields[16] = "100"; // ask_qty
-        fields[17] = "200"; // bid_qty
-        let msg = format!("0|HDFSASP0|1|{}", fields.join("^"));
+/// `YYYYMMDDHHMMSS…` 형식의 상세일시 (KST)
+fn kst_dtime(s: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    kst_at(s.get(..8)?, s.get(8..14)?)
+}
 
-        let result = parse_ws_message(&msg);
+fn parse_overseas_derivative_trade(r: TryitoutHdfff020Response) -> Option<KisEvent> {
+    if r.series_cd.is_empty() {
+        return None;
+    }
+    Some(KisEvent::OverseasDerivativeTrade(
+        OverseasDerivativeTradeData {
+            price: decimal_opt(&r.last_price)?,
+            qty: decimal_or_zero(&r.last_qntt),
+            change: signed_change(&r.prev_diff_price, &r.prev_sign),
+            change_rate: signed_change(&r.prev_diff_rate, &r.prev_sign),
+            open: nonzero_decimal(&r.open_price),
+            high: nonzero_decimal(&r.high_price),
+            low: nonzero_decimal(&r.low_price),
+            prev_close: nonzero_decimal(&r.prev_price),
+            accumulated_volume: decimal_or_zero(&r.vol),
+            settlement_price: nonzero_decimal(&r.psttl_price),
+            business_date: chrono::NaiveDate::parse_from_str(&r.bsns_date, "%Y%m%d").ok(),
+            time: kst_at(&r.recv_date, r.recv_time.get(..6)?)?,
+            symbol: r.series_cd,
+        },
+    ))
+}
+
+fn parse_overseas_derivative_quote(r: TryitoutHdfff010Response) -> Vec<KisEvent> {
+    if r.series_cd.is_empty() {
+        return vec![];
+    }
+    let Some(time) = r.recv_time.get(..6).and_then(|t| kst_at(&r.recv_date, t)) else {
+        return vec![];
+    };
+    let asks = price_levels(
+        &[
+            &r.ask_price_1,
+            &r.ask_price_2,
+            &r.ask_price_3,
+            &r.ask_price_4,
+            &r.ask_price_5,
+        ],
+        &[
+            &r.ask_qntt_1,
+            &r.ask_qntt_2,
+            &r.ask_qntt_3,
+            &r.ask_qntt_4,
+            &r.ask_qntt_5,
+        ],
+    );
+    let bids = price_levels(
+        &[
+            &r.bid_price_1,
+            &r.bid_price_2,
+            &r.bid_price_3,
+            &r.bid_price_4,
+            &r.bid_price_5,
+        ],
+        &[
+            &r.bid_qntt_1,
+            &r.bid_qntt_2,
+            &r.bid_qntt_3,
+            &r.bid_qntt_4,
+            &r.bid_qntt_5,
+        ],
+    );
+    // HDFFF010에는 총 잔량 필드가 없어 5단계 합으로 채운다
+    book_events(OrderBookData {
+        symbol: r.series_cd,
+        overseas_key: None,
+        total_ask_qty: asks.iter().map(|l| l.qty).sum(),
+        total_bid_qty: bids.iter().map(|l| l.qty).sum(),
+        asks,
+        bids,
+        expected_price: None,
+        expected_qty: None,
+        session_code: None,
+        time,
+    })
+}
+
+/// 해외선물옵션 매도매수구분 (01 매도, 02 매수). 알 수 없으면 `None`
+fn overseas_derivative_side(code: &str, order_id: &str) -> Option<OrderSide> {
+    match code.trim_start_matches('0') {
+        "1" => Some(OrderSide::Sell),
+        "2" => Some(OrderSide::Buy),
+        other => {
+            tracing::warn!(target: "kis_api", "unknown order side {other:?} in order notice {order_id}");
+            None
+        }
+    }
+}
+
+/// 통보 시각. 조작상세일시가 없으면 주문상세일시, 둘 다 없으면 수신 시각을 쓴다
+/// (통보는 유실 없이 전달해야 하므로 시각 때문에 버리지 않는다).
+fn overseas_notice_time(
+    oprt_dtl_dtime: &str,
+    ord_dtl_dtime: &str,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    kst_dtime(oprt_dtl_dtime)
+        .or_else(|| kst_dtime(ord_dtl_dtime))
+        .unwrap_or_else(|| chrono::Utc::now().with_timezone(&kst()))
+}
+
+fn parse_overseas_derivative_order(r: TryitoutHdfff1c0Response) -> Option<KisEvent> {
+    if r.odno.is_empty() {
+        return None;
+    }
+    let side = overseas_derivative_side(&r.sll_buy_dvsn_cd, &r.odno);
+    // 정정취소구분: 0 신규, 1 정정, 2 취소
+    let kind = match r.rvse_cncl_dvsn_cd.trim_start_matches('0') {
+        "1" => OrderNoticeKind::Modified,
+        "2" => OrderNoticeKind::Cancelled,
+        _ => OrderNoticeKind::Accepted,
+    };
+    let order_qty = decimal_or_zero(&r.ord_qty);
+    let remaining_qty = match kind {
+        OrderNoticeKind::Cancelled => rust_decimal::Decimal::ZERO,
+        _ => decimal_opt(&r.ord_remq).unwrap_or(order_qty),
+    };
+    Some(KisEvent::OrderConfirm(OrderConfirmData {
+        market: OrderMarket::OverseasFutureOption,
+        customer_id: r.user_id,
+        account: r.acct_no,
+        original_order_id: Some(r.orgn_odno).filter(|o| !o.trim_start_matches('0').is_empty()),
+        order_id: r.odno,
+        kind,
+        side,
+        order_qty,
+        order_price: nonzero_decimal(&r.fm_lmt_pric).or(nonzero_decimal(&r.fm_stop_ord_pric)),
+        filled_qty: rust_decimal::Decimal::ZERO,
+        filled_price: rust_decimal::Decimal::ZERO,
+        remaining_qty,
+        time: overseas_notice_time(&r.oprt_dtl_dtime, &r.ord_dtl_dtime),
+        symbol: r.series,
+    }))
+}
+
+fn parse_overseas_derivative_fill(r: TryitoutHdfff2c0Response) -> Option<KisEvent> {
+    if r.odno.is_empty() {
+        return None;
+    }
+    let side = overseas_derivative_side(&r.sll_buy_dvsn_cd, &r.odno);
+    Some(KisEvent::OrderConfirm(OrderConfirmData {
+        market: OrderMarket::OverseasFutureOption,
+        customer_id: r.user_id,
+        account: r.acct_no,
+        original_order_id: Some(r.orgn_odno).filter(|o| !o.trim_start_matches('0').is_empty()),
+        order_id: r.odno,
+        kind: OrderNoticeKind::Filled,
+        side,
+        order_qty: decimal_or_zero(&r.ord_qty),
+        order_price: nonzero_decimal(&r.fm_lmt_pric).or(nonzero_decimal(&r.fm_stop_ord_pric)),
+        filled_qty: decimal_or_zero(&r.ccld_qty),
+        filled_price: decimal_or_zero(&r.fm_ccld_pric),
+        remaining_qty: decimal_or_zero(&r.ord_remq),
+        time: overseas_notice_time(&r.oprt_dtl_dtime, &r.ord_dtl_dtime),
+        symbol: r.series,
+    }))
+}
+
+/// 체결 추적을 유지하는 기간. 마지막 통보 후 이보다 오래된 주문은 장 종료로 끝난 것으로 본다
+const FILL_RETENTION: chrono::TimeDelta = chrono::TimeDelta::hours(24);
+
+/// 주문번호별 누적 체결 수량. 체결통보의 미체결 잔량을 계산한다.
+///
+/// 잔량이 0이 되거나 취소·거부·정정된 주문은 잊는다. 일부만 체결된 채 장이 끝난 주문은
+/// 마지막 통보 후 [`FILL_RETENTION`]이 지나면 잊는다.
+#[derive(Default)]
+struct FillTracker {
+    /// 주문번호 → (누적 체결 수량, 마지막 통보 시각)
+    filled: HashMap<String, (rust_decimal::Decimal, chrono::DateTime<chrono::FixedOffset>)>,
+}
+
+impl FillTracker {
+    fn track(&mut self, events: &mut [KisEvent]) {
+        for event in events {
+            if let KisEvent::OrderConfirm(notice) = event {
+                self.apply(notice);
+            }
+        }
+    }
+
+    fn apply(&mut self, notice: &mut OrderConfirmData) {
+        // 해외선물옵션 통보는 잔량이 실려 온다
+        if notice.market == OrderMarket::OverseasFutureOption {
+            return;
+        }
+        let now = notice.time;
+        self.filled
+            .retain(|_, (_, last)| now - *last < FILL_RETENTION);
+        match notice.kind {
+            OrderNoticeKind::Filled => {
+                let (filled, last) = self
+                    .filled
+                    .entry(notice.order_id.clone())
+                    .or_insert((rust_decimal::Decimal::ZERO, now));
+                *filled += notice.filled_qty;
+                *last = now;
+                notice.remaining_qty =
+                    (notice.order_qty - *filled).max(rust_decimal::Decimal::ZERO);
+                if notice.remaining_qty.is_zero() {
+                    self.filled.remove(&notice.order_id);
+                }
+            }
+            OrderNoticeKind::Accepted => {}
+            OrderNoticeKind::Modified | OrderNoticeKind::Cancelled | OrderNoticeKind::Rejected => {
+                if let Some(original) = &notice.original_order_id {
+                    self.filled.remove(original);
+                }
+                if notice.kind != OrderNoticeKind::Modified {
+                    self.filled.remove(&notice.order_id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl KisStream {
+    /// 테스트용 (KisStream, EventInjector) 쌍 반환.
+    /// EventInjector를 통해 테스트 코드에서 직접 이벤트를 주입하고, 둘 다 drop하면 StreamClosed.
+    pub fn test_pair() -> (KisStream, EventInjector) {
+        let stream = KisStream::offline(128);
+        let injector = EventInjector {
+            inner: stream.inner.clone(),
+        };
+        (stream, injector)
+    }
+}
+
+/// 테스트용 이벤트 주입기 ([`KisStream::test_pair`])
+#[cfg(any(test, feature = "test-utils"))]
+pub struct EventInjector {
+    inner: Arc<StreamInner>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl EventInjector {
+    /// 수신 루프와 같은 경로로 이벤트 배포
+    pub async fn send(&self, event: KisEvent) {
+        self.inner.publish(event).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscription_kind_eq() {
+        assert_eq!(SubscriptionKind::Price, SubscriptionKind::Price);
+        assert_ne!(SubscriptionKind::Price, SubscriptionKind::Orderbook);
+    }
+
+    #[test]
+    fn subscription_kind_is_hashable() {
+        let mut map = HashMap::new();
+        map.insert(("AAPL".to_string(), SubscriptionKind::Price), ());
+        assert!(map.contains_key(&("AAPL".to_string(), SubscriptionKind::Price)));
+    }
+
+    /// 평문 프레임의 첫 이벤트 (호가 레코드는 Quote 다음에 OrderBook이 온다)
+    fn parse_one(msg: &str) -> Option<KisEvent> {
+        parse_ws_message(msg, &HashMap::new()).into_iter().next()
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn kis_stream_is_send_sync() {
+        assert_send_sync::<KisStream>();
+    }
+
+    #[test]
+    fn event_receiver_type_exists() {
+        // EventReceiver가 컴파일되는지 확인 (런타임 생성은 KisStream 필요)
+        let _ = std::mem::size_of::<EventReceiver>();
+    }
+
+    #[test]
+    fn parse_hdfscnt0_transaction() {
+        let mut fields = vec![""; 26];
+        fields[0] = "DNASNVDA"; // RSYM
+        fields[1] = "NVDA"; // SYMB
+        fields[4] = "20260321"; // XYMD
+        fields[5] = "013022"; // XHMS 01:30:22 EDT
+        fields[6] = "20260321"; // KYMD
+        fields[7] = "143022"; // KHMS 14:30:22 KST
+        fields[11] = "134.20"; // LAST
+        fields[16] = "134.20"; // PASK — 매도호가 체결 = 매수
+        fields[19] = "50"; // EVOL
+        let data = fields.join("^");
+        let msg = format!("0|HDFSCNT0|1|{}", data);
+
+        let result = parse_one(&msg);
+        assert!(result.is_some(), "should parse HDFSCNT0");
+        if let Some(KisEvent::Transaction(tx)) = result {
+            use rust_decimal_macros::dec;
+            assert_eq!(tx.symbol, "NVDA");
+            assert_eq!(
+                tx.overseas_key,
+                Some(OverseasRealtimeKey::delayed("NAS", "NVDA"))
+            );
+            assert_eq!(tx.price, dec!(134.20));
+            assert_eq!(tx.qty, dec!(50));
+            assert_eq!(tx.time.to_rfc3339(), "2026-03-21T01:30:22-04:00");
+            assert!(tx.is_buy);
+        } else {
+            panic!("expected Transaction event");
+        }
+    }
+
+    #[test]
+    fn overseas_symbol_falls_back_to_symb() {
+        // RSYM이 실시간 키 형식이 아니면 SYMB를 종목코드로 쓴다
+        assert_eq!(overseas_symbol("", "NVDA".into()), ("NVDA".into(), None));
+        let (symbol, key) = overseas_symbol("DNASNVDA", "DNASNVDA".into());
+        assert_eq!(symbol, "NVDA");
+        assert_eq!(key.unwrap().exchange, "NAS");
+    }
+
+    #[test]
+    fn overseas_time_uses_exchange_local_offset() {
+        // 뉴욕 서머타임(EDT, UTC-4): 현지 2026-03-20 23:59:58 = 한국 2026-03-21 12:59:58
+        let t = exchange_local_at("20260320", "235958", "20260321", "125958").unwrap();
+        assert_eq!(t.offset().local_minus_utc(), -4 * 3600);
+        assert_eq!(t.to_rfc3339(), "2026-03-20T23:59:58-04:00");
+
+        // 표준시(EST, UTC-5): 현지 2026-01-05 10:00:00 = 한국 2026-01-06 00:00:00
+        let t = exchange_local_at("20260105", "100000", "20260106", "000000").unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-01-05T10:00:00-05:00");
+
+        // 현지 필드가 없으면 한국일시(KST)
+        let t = exchange_local_at("", "", "20260106", "000000").unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-01-06T00:00:00+09:00");
+    }
+
+    #[test]
+    fn domestic_time_uses_business_date() {
+        let t = kst_at("20260102", "235959").unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-01-02T23:59:59+09:00");
+        assert!(kst_at("2026010", "235959").is_none());
+    }
+
+    #[test]
+    fn time_without_business_date_uses_nearest_day() {
+        let at = |s: &str| {
+            chrono::DateTime::parse_from_rfc3339(s)
+                .unwrap()
+                .with_timezone(&chrono::Utc)
+        };
+        // 자정 직후 받은 23:59:59 프레임은 전날
+        let t = kst_near("235959", at("2026-03-21T00:00:01+09:00")).unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-03-20T23:59:59+09:00");
+        // 자정 직전 받은 00:00:01 프레임(수신 시계가 늦음)은 다음날
+        let t = kst_near("000001", at("2026-03-20T23:59:59+09:00")).unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-03-21T00:00:01+09:00");
+        let t = kst_near("093000", at("2026-03-20T09:30:02+09:00")).unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-03-20T09:30:00+09:00");
+
+        // H0STASP0에는 영업일자가 없어 수신 시각으로 날짜를 정한다
+        let mut fields = vec![""; TryitoutH0stasp0Response::FIELD_COUNT];
+        fields[0] = "005930";
+        fields[1] = "235959";
+        fields[3] = "75500";
+        fields[13] = "75400";
+        let msg = format!("0|H0STASP0|001|{}", fields.join("^"));
+        let events = parse_frame(&msg, &HashMap::new(), at("2026-03-21T00:00:01+09:00")).unwrap();
+        let book = order_book(events);
+        assert_eq!(book.time.to_rfc3339(), "2026-03-20T23:59:59+09:00");
+    }
+
+    #[test]
+    fn night_derivative_time_uses_nearest_day() {
+        let received = chrono::DateTime::parse_from_rfc3339("2026-03-21T00:00:01+09:00")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let time_of = |event: &KisEvent| match event {
+            KisEvent::DerivativeTrade(trade) => trade.time,
+            KisEvent::OrderBook(book) => book.time,
+            other => panic!("unexpected {other:?}"),
+        };
+
+        // 선물옵션 TR에는 영업일자가 없어 자정 직후 받은 23:59:59 체결·호가는 전날이다
+        let mut future = vec![""; TryitoutH0mfcnt0Response::FIELD_COUNT];
+        future[0] = "A05W09";
+        future[1] = "235959";
+        future[5] = "350.25";
+        let mut option = vec![""; 56];
+        option[0] = "B01W09350";
+        option[1] = "235959";
+        option[2] = "2.15";
+        let mut quote = vec![""; 38];
+        quote[0] = "B01W09350";
+        quote[1] = "235959";
+        quote[2] = "2.20";
+        quote[7] = "2.15";
+        for msg in [
+            format!("0|H0MFCNT0|001|{}", future.join("^")),
+            format!("0|H0EUCNT0|001|{}", option.join("^")),
+            format!("0|H0EUASP0|001|{}", quote.join("^")),
+        ] {
+            let events = parse_frame(&msg, &HashMap::new(), received).unwrap();
+            let event = events.last().expect("event");
+            assert_eq!(time_of(event).to_rfc3339(), "2026-03-20T23:59:59+09:00");
+        }
+    }
+
+    #[test]
+    fn parse_json_returns_none() {
+        let json = r#"{"header":{"tr_id":"PINGPONG"},"body":{}}"#;
+        assert!(parse_one(json).is_none());
+    }
+
+    #[test]
+    fn parse_unknown_trid_returns_none() {
+        assert!(parse_one("0|UNKNOWN|1|data").is_none());
+    }
+
+    #[test]
+    fn parse_malformed_returns_none() {
+        assert!(parse_one("not|enough").is_none());
+        assert!(parse_one("").is_none());
+    }
+
+    #[test]
+    fn parse_bad_decimal_returns_none() {
+        let mut fields = vec![""; 26];
+        fields[1] = "NVDA";
+        fields[7] = "143022";
+        fields[11] = "NOT_A_NUMBER";
+        fields[19] = "50";
+        let msg = format!("0|HDFSCNT0|1|{}", fields.join("^"));
+        assert!(parse_one(&msg).is_none());
+    }
+
+    #[test]
+    fn parse_hdfsasp0_quote() {
+        let mut fields = vec![""; 71];
+        fields[0] = "RNYSAAPL"; // RSYM
+        fields[1] = "AAPL"; // SYMB
+        fields[6] = "150000"; // KHMS
+        fields[11] = "190.90"; // PBID1
+        fields[12] = "191.00"; // PASK1
+        fields[13] = "200"; // VBID1
+        fields[14] = "100"; // VASK1
+        let msg = format!("0|HDFSASP0|1|{}", fields.join("^"));
+
+        let result = parse_one(&msg);
         assert!(result.is_some());
         if let Some(KisEvent::Quote(q)) = result {
             use rust_decimal_macros::dec;
             assert_eq!(q.symbol, "AAPL");
+            let key = q.overseas_key.unwrap();
+            assert_eq!(key.exchange, "NYS");
+            assert_eq!(key.feed, crate::event::OverseasFeed::Realtime);
             assert_eq!(q.ask_price, dec!(191.00));
             assert_eq!(q.bid_price, dec!(190.90));
         } else {
@@ -733,51 +3318,35 @@ mod tests {
 
     #[test]
     fn parse_hdfsasp1_also_works() {
-        let mut fields = vec![""; 30];
+        let mut fields = vec![""; 17];
         fields[1] = "SONY";
-        fields[2] = "090000";
-        fields[14] = "10.50";
-        fields[15] = "10.40";
-        fields[16] = "500";
-        fields[17] = "300";
+        fields[6] = "090000";
+        fields[11] = "10.40";
+        fields[12] = "10.50";
+        fields[13] = "300";
+        fields[14] = "500";
         let msg = format!("0|HDFSASP1|1|{}", fields.join("^"));
-        assert!(matches!(parse_ws_message(&msg), Some(KisEvent::Quote(_))));
+        assert!(matches!(parse_one(&msg), Some(KisEvent::Quote(_))));
     }
 
     #[test]
     fn domestic_subscription_kind_maps_to_h0stcnt0() {
         // Verify that DomesticPrice maps to the correct KIS TR ID
-        assert_eq!(
-            subscription_tr_id(SubscriptionKind::DomesticPrice),
-            "H0STCNT0"
-        );
-        assert_eq!(
-            subscription_tr_id(SubscriptionKind::DomesticOrderbook),
-            "H0STASP0"
-        );
-    }
-
-    // Helper exposed only for tests:
-    fn subscription_tr_id(kind: SubscriptionKind) -> &'static str {
-        match kind {
-            SubscriptionKind::Price => "HDFSCNT0",
-            SubscriptionKind::Orderbook => "HDFSASP0",
-            SubscriptionKind::DomesticPrice => "H0STCNT0",
-            SubscriptionKind::DomesticOrderbook => "H0STASP0",
-        }
+        assert_eq!(SubscriptionKind::DomesticPrice.tr_id(), "H0STCNT0");
+        assert_eq!(SubscriptionKind::DomesticOrderbook.tr_id(), "H0STASP0");
     }
 
     #[test]
     fn parse_h0stcnt0_transaction() {
-        let mut fields = vec![""; 21];
+        let mut fields = vec![""; 46];
         fields[0] = "005930"; // 삼성전자
         fields[1] = "093000"; // 09:30:00 KST
         fields[2] = "75400"; // 주식현재가
-        fields[9] = "300"; // 체결거래량
-        fields[20] = "1"; // 체결구분: 1=매수
+        fields[12] = "300"; // 체결거래량
+        fields[21] = "1"; // 체결구분: 1=매수
         let msg = format!("0|H0STCNT0|1|{}", fields.join("^"));
 
-        let result = parse_ws_message(&msg);
+        let result = parse_one(&msg);
         assert!(result.is_some(), "should parse H0STCNT0");
         if let Some(KisEvent::Transaction(tx)) = result {
             use rust_decimal_macros::dec;
@@ -792,15 +3361,15 @@ mod tests {
 
     #[test]
     fn parse_h0stcnt0_sell_transaction() {
-        let mut fields = vec![""; 21];
+        let mut fields = vec![""; 46];
         fields[0] = "000660"; // SK하이닉스
         fields[1] = "100000";
         fields[2] = "185000";
-        fields[9] = "100";
-        fields[20] = "2"; // 체결구분: 2=매도
+        fields[12] = "100";
+        fields[21] = "5"; // 체결구분: 5=매도
         let msg = format!("0|H0STCNT0|1|{}", fields.join("^"));
 
-        if let Some(KisEvent::Transaction(tx)) = parse_ws_message(&msg) {
+        if let Some(KisEvent::Transaction(tx)) = parse_one(&msg) {
             assert!(!tx.is_buy);
         } else {
             panic!("expected Transaction event");
@@ -809,16 +3378,16 @@ mod tests {
 
     #[test]
     fn parse_h0stasp0_quote() {
-        let mut fields = vec![""; 15];
+        let mut fields = vec![""; 59];
         fields[0] = "005930"; // 삼성전자
         fields[1] = "093000"; // 09:30:00 KST
         fields[3] = "75500"; // 매도호가1
-        fields[4] = "75400"; // 매수호가1
-        fields[13] = "1200"; // 매도호가잔량1
-        fields[14] = "800"; // 매수호가잔량1
+        fields[13] = "75400"; // 매수호가1
+        fields[23] = "1200"; // 매도호가잔량1
+        fields[33] = "800"; // 매수호가잔량1
         let msg = format!("0|H0STASP0|1|{}", fields.join("^"));
 
-        let result = parse_ws_message(&msg);
+        let result = parse_one(&msg);
         assert!(result.is_some(), "should parse H0STASP0");
         if let Some(KisEvent::Quote(q)) = result {
             use rust_decimal_macros::dec;
@@ -832,6 +3401,229 @@ mod tests {
         }
     }
 
+    fn h0stcnt0_record<'a>(price: &'a str, qty: &'a str) -> Vec<&'a str> {
+        let mut fields = vec![""; TryitoutH0stcnt0Response::FIELD_COUNT];
+        fields[0] = "005930";
+        fields[1] = "093000";
+        fields[2] = price;
+        fields[12] = qty;
+        fields[21] = "1";
+        fields
+    }
+
+    fn prices(events: &[KisEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|e| match e {
+                KisEvent::Transaction(tx) => tx.price.to_string(),
+                other => panic!("expected Transaction, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_record_frame_yields_one_event() {
+        let msg = format!("0|H0STCNT0|001|{}", h0stcnt0_record("75400", "1").join("^"));
+        let events = parse_ws_message(&msg, &HashMap::new());
+        assert_eq!(prices(&events), vec!["75400"]);
+    }
+
+    #[test]
+    fn multi_record_frame_yields_event_per_record() {
+        let records = [
+            h0stcnt0_record("75400", "1"),
+            h0stcnt0_record("75500", "2"),
+            h0stcnt0_record("75600", "3"),
+            h0stcnt0_record("75700", "4"),
+        ];
+        let data: Vec<String> = records.iter().map(|r| r.join("^")).collect();
+        let msg = format!("0|H0STCNT0|004|{}", data.join("^"));
+
+        let events = parse_ws_message(&msg, &HashMap::new());
+        assert_eq!(prices(&events), vec!["75400", "75500", "75600", "75700"]);
+        let qtys: Vec<String> = events
+            .iter()
+            .map(|e| match e {
+                KisEvent::Transaction(tx) => tx.qty.to_string(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(qtys, vec!["1", "2", "3", "4"]);
+    }
+
+    #[test]
+    fn multi_record_quote_frame() {
+        let mut a = vec![""; TryitoutHdfsasp0Response::FIELD_COUNT];
+        a[1] = "AAPL";
+        a[6] = "150000";
+        a[11] = "190.90";
+        a[12] = "191.00";
+        a[13] = "200";
+        a[14] = "100";
+        let mut b = a.clone();
+        b[12] = "191.10";
+        let msg = format!("0|HDFSASP0|002|{}^{}", a.join("^"), b.join("^"));
+
+        let asks: Vec<String> = parse_ws_message(&msg, &HashMap::new())
+            .iter()
+            .filter_map(|e| match e {
+                KisEvent::Quote(q) => Some(q.ask_price.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(asks, vec!["191.00", "191.10"]);
+    }
+
+    #[test]
+    fn truncated_frame_keeps_complete_records() {
+        let first = h0stcnt0_record("75400", "1").join("^");
+        let msg = format!("0|H0STCNT0|002|{first}^005930^093001");
+        assert_eq!(
+            prices(&parse_ws_message(&msg, &HashMap::new())),
+            vec!["75400"]
+        );
+    }
+
+    #[test]
+    fn records_use_wider_stride_when_fields_are_appended() {
+        // 명세보다 필드가 1개 많은 레코드 2건
+        let mut a = h0stcnt0_record("75400", "1");
+        a.push("NEW");
+        let mut b = h0stcnt0_record("75500", "2");
+        b.push("NEW");
+        let msg = format!("0|H0STCNT0|002|{}^{}", a.join("^"), b.join("^"));
+        assert_eq!(
+            prices(&parse_ws_message(&msg, &HashMap::new())),
+            vec!["75400", "75500"]
+        );
+    }
+
+    #[test]
+    fn bad_record_count_is_ignored() {
+        let msg = format!("0|H0STCNT0|abc|{}", h0stcnt0_record("75400", "1").join("^"));
+        assert!(parse_ws_message(&msg, &HashMap::new()).is_empty());
+    }
+
+    fn order_book(events: Vec<KisEvent>) -> OrderBookData {
+        events
+            .into_iter()
+            .find_map(|e| match e {
+                KisEvent::OrderBook(book) => Some(book),
+                _ => None,
+            })
+            .expect("expected OrderBook event")
+    }
+
+    #[test]
+    fn h0stasp0_emits_full_order_book() {
+        use rust_decimal_macros::dec;
+
+        let mut fields = vec![String::new(); TryitoutH0stasp0Response::FIELD_COUNT];
+        fields[0] = "005930".into();
+        fields[1] = "085950".into();
+        fields[2] = "B".into(); // 장전 예상
+        for i in 0..10 {
+            fields[3 + i] = (75500 + 100 * i).to_string(); // 매도호가1-10
+            fields[13 + i] = (75400 - 100 * i).to_string(); // 매수호가1-10
+            fields[23 + i] = (10 * (i + 1)).to_string(); // 매도잔량1-10
+            fields[33 + i] = (20 * (i + 1)).to_string(); // 매수잔량1-10
+        }
+        fields[43] = "550".into(); // 총 매도호가 잔량
+        fields[44] = "1100".into(); // 총 매수호가 잔량
+        fields[47] = "75450".into(); // 예상 체결가
+        fields[48] = "1234".into(); // 예상 체결량
+        let msg = format!("0|H0STASP0|001|{}", fields.join("^"));
+
+        let events = parse_ws_message(&msg, &HashMap::new());
+        assert!(matches!(events[0], KisEvent::Quote(_)));
+        let book = order_book(events);
+        assert_eq!(book.symbol, "005930");
+        assert_eq!(book.asks.len(), 10);
+        assert_eq!(book.bids.len(), 10);
+        assert_eq!(
+            book.asks[0],
+            PriceLevel {
+                price: dec!(75500),
+                qty: dec!(10)
+            }
+        );
+        assert_eq!(
+            book.bids[9],
+            PriceLevel {
+                price: dec!(74500),
+                qty: dec!(200)
+            }
+        );
+        assert_eq!(book.total_ask_qty, dec!(550));
+        assert_eq!(book.total_bid_qty, dec!(1100));
+        assert_eq!(book.expected_price, Some(dec!(75450)));
+        assert_eq!(book.expected_qty, Some(dec!(1234)));
+        assert_eq!(book.session_code.as_deref(), Some("B"));
+    }
+
+    #[test]
+    fn h0stasp0_without_auction_has_no_expected_fields() {
+        let mut fields = vec![""; TryitoutH0stasp0Response::FIELD_COUNT];
+        fields[0] = "005930";
+        fields[1] = "100000";
+        fields[2] = "0";
+        fields[3] = "75500";
+        fields[13] = "75400";
+        fields[47] = "0";
+        fields[48] = "0";
+        let msg = format!("0|H0STASP0|001|{}", fields.join("^"));
+
+        let book = order_book(parse_ws_message(&msg, &HashMap::new()));
+        assert_eq!(book.asks.len(), 1);
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.expected_price, None);
+        assert_eq!(book.expected_qty, None);
+    }
+
+    #[test]
+    fn one_sided_book_emits_order_book_only() {
+        let mut fields = vec![""; TryitoutH0stasp0Response::FIELD_COUNT];
+        fields[0] = "005930";
+        fields[1] = "100000";
+        fields[3] = "75500"; // 매수호가 없음 (상한가 등)
+        let msg = format!("0|H0STASP0|001|{}", fields.join("^"));
+
+        let events = parse_ws_message(&msg, &HashMap::new());
+        assert_eq!(events.len(), 1);
+        let book = order_book(events);
+        assert_eq!(book.asks.len(), 1);
+        assert!(book.bids.is_empty());
+    }
+
+    #[test]
+    fn hdfsasp0_emits_ten_levels_and_totals() {
+        use rust_decimal_macros::dec;
+
+        let mut fields = vec![String::new(); TryitoutHdfsasp0Response::FIELD_COUNT];
+        fields[1] = "AAPL".into();
+        fields[6] = "223000".into();
+        fields[7] = "9000".into(); // BVOL 매수총잔량
+        fields[8] = "7000".into(); // AVOL 매도총잔량
+        for level in 0..10 {
+            let base = 11 + level * 6;
+            fields[base] = format!("{}.00", 190 - level); // PBID
+            fields[base + 1] = format!("{}.00", 191 + level); // PASK
+            fields[base + 2] = "200".into(); // VBID
+            fields[base + 3] = "100".into(); // VASK
+        }
+        let msg = format!("0|HDFSASP0|001|{}", fields.join("^"));
+
+        let book = order_book(parse_ws_message(&msg, &HashMap::new()));
+        assert_eq!(book.asks.len(), 10);
+        assert_eq!(book.bids.len(), 10);
+        assert_eq!(book.asks[9].price, dec!(200.00));
+        assert_eq!(book.bids[9].price, dec!(181.00));
+        assert_eq!(book.total_bid_qty, dec!(9000));
+        assert_eq!(book.total_ask_qty, dec!(7000));
+        assert_eq!(book.expected_price, None);
+        assert_eq!(book.session_code, None);
+    }
+
     #[test]
     fn classify_pingpong_message() {
         let pingpong = r#"{"header":{"tr_id":"PINGPONG"},"body":{}}"#;
@@ -846,7 +3638,7 @@ mod tests {
         let json = r#"{"header":{"tr_id":"SUBSCRIBE"},"body":{}}"#;
         assert!(matches!(
             classify_text_message(json),
-            TextMessage::OtherJson
+            TextMessage::OtherJson(_)
         ));
     }
 
@@ -856,19 +3648,1555 @@ mod tests {
         assert!(matches!(classify_text_message(data), TextMessage::Data));
     }
 
+    // KIS 구독 응답 형식의 32/16바이트 key/iv
+    const TEST_KEY: &str = "abcdefghijklmnopqrstuvwxyz012345";
+    const TEST_IV: &str = "0123456789abcdef";
+
+    fn encrypt(plain: &str) -> String {
+        use aes::cipher::{block_padding::Pkcs7, BlockEncryptMut, KeyIvInit};
+        use base64::Engine;
+
+        let data =
+            cbc::Encryptor::<aes::Aes256>::new_from_slices(TEST_KEY.as_bytes(), TEST_IV.as_bytes())
+                .unwrap()
+                .encrypt_padded_vec_mut::<Pkcs7>(plain.as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(data)
+    }
+
+    fn subscribe_ack(tr_id: &str) -> String {
+        serde_json::json!({
+            "header": { "tr_id": tr_id, "tr_key": "HTSID01", "encrypt": "N" },
+            "body": {
+                "rt_cd": "0",
+                "msg_cd": "OPSP0000",
+                "msg1": "SUBSCRIBE SUCCESS",
+                "output": { "iv": TEST_IV, "key": TEST_KEY }
+            }
+        })
+        .to_string()
+    }
+
+    fn h0stcnt0_fields() -> String {
+        let mut fields = vec![""; 46];
+        fields[0] = "005930";
+        fields[1] = "093000";
+        fields[2] = "75400";
+        fields[12] = "300";
+        fields[21] = "1";
+        fields.join("^")
+    }
+
     #[test]
-    fn backoff_duration_increases() {
-        let d0 = backoff_duration(0);
-        let d3 = backoff_duration(3);
-        // d0 should be around 1s (+ jitter), d3 around 8s (+ jitter)
-        assert!(d0.as_millis() >= 1000);
-        assert!(d0.as_millis() <= 1300); // 1000 + 30% jitter max
-        assert!(d3.as_millis() >= 8000);
+    fn subscribe_ack_yields_cipher_per_tr_id() {
+        let TextMessage::OtherJson(json) = classify_text_message(&subscribe_ack("H0STCNI0")) else {
+            panic!("expected OtherJson");
+        };
+        let (tr_id, cipher) = subscribe_ack_cipher(&json).unwrap();
+        assert_eq!(tr_id, "H0STCNI0");
+        assert_eq!(cipher.key, TEST_KEY);
+        assert_eq!(cipher.iv, TEST_IV);
+
+        // 암호화가 없는 TR의 응답에는 key/iv가 비어 있다
+        let plain = serde_json::json!({
+            "header": { "tr_id": "H0STCNT0" },
+            "body": { "rt_cd": "0", "output": { "iv": "", "key": "" } }
+        });
+        assert!(subscribe_ack_cipher(&plain).is_none());
     }
 
     #[test]
-    fn backoff_duration_caps_at_max() {
-        let d_big = backoff_duration(20); // 2^20 * 1000 would be huge
-        assert!(d_big.as_millis() <= (BACKOFF_MAX_MS as u128) * 2); // max + jitter
+    fn encrypted_frame_is_decrypted_before_parsing() {
+        let ciphers = CipherKeys::from([(
+            "H0STCNT0".to_string(),
+            FrameCipher {
+                key: TEST_KEY.into(),
+                iv: TEST_IV.into(),
+            },
+        )]);
+        let msg = format!("1|H0STCNT0|001|{}", encrypt(&h0stcnt0_fields()));
+        match parse_ws_message(&msg, &ciphers).pop() {
+            Some(KisEvent::Transaction(tx)) => {
+                assert_eq!(tx.symbol, "005930");
+                assert_eq!(tx.price, rust_decimal_macros::dec!(75400));
+            }
+            other => panic!("expected Transaction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn encrypted_frame_without_key_is_dropped() {
+        let msg = format!("1|H0STCNT0|001|{}", encrypt(&h0stcnt0_fields()));
+        assert!(parse_one(&msg).is_none());
+    }
+
+    #[test]
+    fn encrypted_frame_with_wrong_key_is_dropped() {
+        let ciphers = CipherKeys::from([(
+            "H0STCNT0".to_string(),
+            FrameCipher {
+                key: "x".repeat(32),
+                iv: TEST_IV.into(),
+            },
+        )]);
+        let msg = format!("1|H0STCNT0|001|{}", encrypt(&h0stcnt0_fields()));
+        assert!(parse_ws_message(&msg, &ciphers).pop().is_none());
+    }
+
+    /// H0STCNI0 레코드: (주문번호, 원주문번호, 정정구분, 체결여부, 거부여부, 체결수량, 체결단가)
+    fn h0stcni0_fields(
+        order: &str,
+        original: &str,
+        rctf: &str,
+        cntg_yn: &str,
+        rfus_yn: &str,
+        qty: &str,
+        price: &str,
+    ) -> String {
+        let mut fields = vec![""; 26];
+        fields[0] = "HTSID01";
+        fields[1] = "5012345601";
+        fields[2] = order;
+        fields[3] = original;
+        fields[4] = "02";
+        fields[5] = rctf;
+        fields[8] = "005930";
+        fields[9] = qty;
+        fields[10] = price;
+        fields[11] = "093000";
+        fields[12] = rfus_yn;
+        fields[13] = cntg_yn;
+        fields[14] = "1";
+        fields[16] = "10";
+        fields[25] = "75400";
+        fields.join("^")
+    }
+
+    fn notice(msg: &str, ciphers: &CipherKeys, fills: &mut FillTracker) -> OrderConfirmData {
+        let mut events = parse_ws_message(msg, ciphers);
+        fills.track(&mut events);
+        match events.pop() {
+            Some(KisEvent::OrderConfirm(notice)) => notice,
+            other => panic!("expected OrderConfirm, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn order_notices_are_decrypted_and_track_remaining_qty() {
+        use rust_decimal_macros::dec;
+
+        let TextMessage::OtherJson(json) = classify_text_message(&subscribe_ack("H0STCNI0")) else {
+            panic!("expected ack");
+        };
+        let ciphers = CipherKeys::from([subscribe_ack_cipher(&json).unwrap()]);
+        let mut fills = FillTracker::default();
+        let frame = |fields: String| format!("1|H0STCNI0|001|{}", encrypt(&fields));
+
+        let accepted = notice(
+            &frame(h0stcni0_fields(
+                "0000012345",
+                "0000000000",
+                "0",
+                "1",
+                "0",
+                "10",
+                "75400",
+            )),
+            &ciphers,
+            &mut fills,
+        );
+        assert_eq!(accepted.kind, OrderNoticeKind::Accepted);
+        assert_eq!(accepted.market, OrderMarket::DomesticStock);
+        assert_eq!(accepted.side, Some(OrderSide::Buy));
+        assert_eq!(accepted.customer_id, "HTSID01");
+        assert_eq!(accepted.original_order_id, None);
+        assert_eq!(accepted.filled_qty, dec!(0));
+        assert_eq!(accepted.order_price, Some(dec!(75400)));
+        assert_eq!(accepted.remaining_qty, dec!(10));
+
+        let fill = |qty| {
+            frame(h0stcni0_fields(
+                "0000012345",
+                "",
+                "0",
+                "2",
+                "0",
+                qty,
+                "75300",
+            ))
+        };
+        let first = notice(&fill("4"), &ciphers, &mut fills);
+        assert_eq!(first.kind, OrderNoticeKind::Filled);
+        assert_eq!(
+            (first.filled_qty, first.filled_price),
+            (dec!(4), dec!(75300))
+        );
+        assert_eq!(first.remaining_qty, dec!(6));
+        assert_eq!(
+            notice(&fill("6"), &ciphers, &mut fills).remaining_qty,
+            dec!(0)
+        );
+        assert!(fills.filled.is_empty());
+
+        let cancelled = notice(
+            &frame(h0stcni0_fields(
+                "0000012346",
+                "0000012345",
+                "2",
+                "1",
+                "0",
+                "10",
+                "0",
+            )),
+            &ciphers,
+            &mut fills,
+        );
+        assert_eq!(cancelled.kind, OrderNoticeKind::Cancelled);
+        assert_eq!(cancelled.original_order_id.as_deref(), Some("0000012345"));
+
+        let rejected = notice(
+            &frame(h0stcni0_fields(
+                "0000012347",
+                "",
+                "0",
+                "1",
+                "1",
+                "10",
+                "75400",
+            )),
+            &ciphers,
+            &mut fills,
+        );
+        assert_eq!(rejected.kind, OrderNoticeKind::Rejected);
+        assert_eq!(rejected.remaining_qty, dec!(0));
+    }
+
+    #[test]
+    fn partially_filled_orders_are_forgotten_after_retention() {
+        use rust_decimal_macros::dec;
+
+        let fill = |order_id: &str, time| OrderConfirmData {
+            market: OrderMarket::DomesticStock,
+            customer_id: "HTSID01".into(),
+            account: "12345678".into(),
+            order_id: order_id.into(),
+            original_order_id: None,
+            symbol: "005930".into(),
+            kind: OrderNoticeKind::Filled,
+            side: Some(OrderSide::Buy),
+            order_qty: dec!(10),
+            order_price: Some(dec!(75400)),
+            filled_qty: dec!(4),
+            filled_price: dec!(75400),
+            remaining_qty: dec!(10),
+            time,
+        };
+        let day1 = kst_at("20260320", "151000").unwrap();
+        let mut fills = FillTracker::default();
+        fills.apply(&mut fill("0000012345", day1));
+        assert_eq!(fills.filled.len(), 1);
+
+        // 다음 날 통보가 오면 전날 일부 체결로 끝난 주문은 잊는다
+        let mut next = fill("0000000001", day1 + FILL_RETENTION);
+        fills.apply(&mut next);
+        assert_eq!(next.remaining_qty, dec!(6));
+        assert_eq!(fills.filled.keys().collect::<Vec<_>>(), vec!["0000000001"]);
+    }
+
+    #[test]
+    fn overseas_notice_prefers_decimal_price() {
+        let mut fields = vec![""; 25];
+        fields[0] = "HTSID01";
+        fields[2] = "0030012345";
+        fields[4] = "01";
+        fields[5] = "0";
+        fields[7] = "AAPL";
+        fields[8] = "3";
+        fields[9] = "0000190";
+        fields[10] = "223000";
+        fields[11] = "0";
+        fields[12] = "2";
+        fields[15] = "3";
+        fields[24] = "190.5500";
+        let msg = format!("0|H0GSCNI9|001|{}", fields.join("^"));
+        match parse_one(&msg) {
+            Some(KisEvent::OrderConfirm(n)) => {
+                assert_eq!(n.market, OrderMarket::OverseasStock);
+                assert_eq!(n.side, Some(OrderSide::Sell));
+                assert_eq!(n.filled_price, rust_decimal_macros::dec!(190.55));
+                assert!(SubscriptionKind::OverseasOrderNotice
+                    .produces(&KisEvent::OrderConfirm(n.clone())));
+                assert!(KisEvent::OrderConfirm(n).matches_symbol("HTSID01"));
+            }
+            other => panic!("expected OrderConfirm, got {other:?}"),
+        }
+        assert_eq!(SubscriptionKind::OrderNotice.vts_tr_id(), "H0STCNI9");
+        assert_eq!(SubscriptionKind::DomesticPrice.vts_tr_id(), "H0STCNT0");
+    }
+
+    #[tokio::test]
+    async fn market_status_frames_are_typed_and_remembered() {
+        let krx = "0|H0STMKO0|001|005930^Y^투자유의^110^112^^^51^1^N^1";
+        let Some(KisEvent::MarketStatus(status)) = parse_one(krx) else {
+            panic!("expected MarketStatus");
+        };
+        assert_eq!(status.symbol, "005930");
+        assert_eq!(status.venue, Venue::Krx);
+        assert!(status.halted && !status.is_tradable());
+        assert_eq!(status.halt_reason.as_deref(), Some("투자유의"));
+        assert_eq!(status.phase, MarketPhase::OpeningAuction);
+        assert!(status.phase.is_auction());
+        assert_eq!(status.vi, ViStatus::Static);
+        assert_eq!(status.overtime_vi, ViStatus::Released);
+
+        // 통합 TR이 종목코드를 붙여 11개 필드로 오는 경우
+        let integrated = "0|H0UNMKO0|001|005930^N^^112^^^^51^2^N^1";
+        let Some(KisEvent::MarketStatus(resumed)) = parse_one(integrated) else {
+            panic!("expected MarketStatus");
+        };
+        assert_eq!(
+            (resumed.symbol.as_str(), resumed.venue),
+            ("005930", Venue::Integrated)
+        );
+        assert_eq!(resumed.phase, MarketPhase::Continuous);
+        assert_eq!(resumed.vi, ViStatus::Dynamic);
+        assert!(SubscriptionKind::MarketStatus(Venue::Integrated)
+            .produces(&KisEvent::MarketStatus(resumed.clone())));
+        assert!(!SubscriptionKind::MarketStatus(Venue::Krx)
+            .produces(&KisEvent::MarketStatus(resumed.clone())));
+
+        let (stream, tx) = KisStream::test_pair();
+        tx.send(KisEvent::MarketStatus(status)).await;
+        tx.send(KisEvent::MarketStatus(resumed)).await;
+        assert!(stream.market_status("005930", Venue::Krx).unwrap().halted);
+        assert!(
+            !stream
+                .market_status("005930", Venue::Integrated)
+                .unwrap()
+                .halted
+        );
+        assert!(stream.market_status("000660", Venue::Krx).is_none());
+    }
+
+    #[test]
+    fn derivative_trade_and_book_frames() {
+        use rust_decimal_macros::dec;
+
+        // 지수선물은 야간선물보다 필드가 하나 더 붙는다
+        let mut fields = vec![""; 50];
+        fields[0] = "101W09";
+        fields[1] = "101500";
+        fields[2] = "1.25";
+        fields[4] = "0.36";
+        fields[5] = "350.25";
+        fields[9] = "3";
+        fields[10] = "120000";
+        fields[12] = "350.40";
+        fields[13] = "0.85";
+        fields[18] = "250000";
+        fields[19] = "-1200";
+        fields[33] = "-0.15";
+        fields[34] = "350.30";
+        fields[35] = "350.25";
+        let msg = format!("0|H0IFCNT0|001|{}", fields.join("^"));
+        let Some(KisEvent::DerivativeTrade(future)) = parse_one(&msg) else {
+            panic!("expected DerivativeTrade");
+        };
+        assert_eq!(future.product, DerivativeProduct::IndexFuture);
+        assert_eq!((future.price, future.qty), (dec!(350.25), dec!(3)));
+        assert_eq!(future.open_interest, dec!(250000));
+        assert_eq!(future.open_interest_change, dec!(-1200));
+        assert_eq!(future.theoretical_price, Some(dec!(350.40)));
+        assert_eq!(future.market_basis, Some(dec!(0.85)));
+        assert_eq!(future.theoretical_basis, Some(dec!(-0.15)));
+        assert!(future.greeks.is_none());
+        assert!(
+            SubscriptionKind::DerivativePrice(DerivativeProduct::IndexFuture)
+                .produces(&KisEvent::DerivativeTrade(future.clone()))
+        );
+        assert!(
+            !SubscriptionKind::DerivativePrice(DerivativeProduct::NightFuture)
+                .produces(&KisEvent::DerivativeTrade(future))
+        );
+
+        let mut fields = vec![""; 56];
+        fields[0] = "B01W09350";
+        fields[1] = "190000";
+        fields[2] = "2.15";
+        fields[9] = "10";
+        fields[12] = "2.10";
+        fields[13] = "8000";
+        fields[26] = "0.25";
+        fields[27] = "1.90";
+        fields[28] = "0.52";
+        fields[29] = "0.04";
+        fields[30] = "0.31";
+        fields[31] = "-0.12";
+        fields[32] = "0.05";
+        fields[33] = "15.2";
+        let msg = format!("0|H0EUCNT0|001|{}", fields.join("^"));
+        let Some(KisEvent::DerivativeTrade(option)) = parse_one(&msg) else {
+            panic!("expected DerivativeTrade");
+        };
+        assert_eq!(option.product, DerivativeProduct::NightOption);
+        assert_eq!(option.open_interest, dec!(8000));
+        let greeks = option.greeks.unwrap();
+        assert_eq!((greeks.delta, greeks.theta), (dec!(0.52), dec!(-0.12)));
+        assert_eq!(greeks.implied_volatility, dec!(15.2));
+        assert_eq!(
+            (greeks.intrinsic_value, greeks.time_value),
+            (dec!(0.25), dec!(1.90))
+        );
+
+        let mut fields = vec![""; 38];
+        fields[0] = "201W09350";
+        fields[1] = "101500";
+        fields[2] = "2.20";
+        fields[7] = "2.15";
+        fields[22] = "40";
+        fields[27] = "55";
+        fields[34] = "400";
+        fields[35] = "550";
+        let msg = format!("0|H0IOASP0|001|{}", fields.join("^"));
+        let events = parse_ws_message(&msg, &HashMap::new());
+        let Some(KisEvent::OrderBook(book)) = events.get(1) else {
+            panic!("expected Quote + OrderBook");
+        };
+        assert_eq!(book.asks[0].price, dec!(2.20));
+        assert_eq!(book.bids[0].qty, dec!(55));
+        assert_eq!(book.total_ask_qty, dec!(400));
+    }
+
+    #[test]
+    fn overseas_derivative_frames() {
+        use rust_decimal_macros::dec;
+
+        let mut fields = vec![""; 25];
+        fields[0] = "6AM24";
+        fields[1] = "20260320";
+        fields[6] = "0.6650";
+        fields[7] = "20260321";
+        fields[8] = "063015";
+        fields[10] = "0.6642";
+        fields[11] = "2";
+        fields[12] = "0.0008";
+        fields[13] = "0.12";
+        fields[17] = "15000";
+        fields[18] = "5";
+        fields[21] = "0.6648";
+        let msg = format!("0|HDFFF020|001|{}", fields.join("^"));
+        let Some(KisEvent::OverseasDerivativeTrade(trade)) = parse_one(&msg) else {
+            panic!("expected OverseasDerivativeTrade");
+        };
+        assert_eq!((trade.price, trade.qty), (dec!(0.6642), dec!(2)));
+        assert_eq!(
+            (trade.change, trade.change_rate),
+            (dec!(-0.0008), dec!(-0.12))
+        );
+        assert_eq!(trade.settlement_price, Some(dec!(0.6648)));
+        assert_eq!(
+            trade.business_date,
+            chrono::NaiveDate::from_ymd_opt(2026, 3, 20)
+        );
+        assert_eq!(trade.time, kst_at("20260321", "063015").unwrap());
+
+        let mut fields = vec![""; 35];
+        fields[0] = "6AM24";
+        fields[1] = "20260321";
+        fields[2] = "063015";
+        fields[4] = "12";
+        fields[6] = "0.6641";
+        fields[7] = "7";
+        fields[9] = "0.6643";
+        fields[13] = "3";
+        fields[15] = "0.6644";
+        let msg = format!("0|HDFFF010|001|{}", fields.join("^"));
+        let events = parse_ws_message(&msg, &HashMap::new());
+        let Some(KisEvent::OrderBook(book)) = events.get(1) else {
+            panic!("expected Quote + OrderBook");
+        };
+        assert_eq!(book.asks.len(), 2);
+        assert_eq!(book.bids[0].price, dec!(0.6641));
+        assert_eq!(
+            (book.total_ask_qty, book.total_bid_qty),
+            (dec!(10), dec!(12))
+        );
+
+        let mut fields = vec![""; 33];
+        fields[0] = "hts_user";
+        fields[1] = "12345678";
+        fields[3] = "0000000123";
+        fields[5] = "0000000000";
+        fields[6] = "6AM24";
+        fields[7] = "0";
+        fields[8] = "02";
+        fields[12] = "5";
+        fields[13] = "0.6640";
+        fields[17] = "5";
+        fields[20] = "20260321063010123";
+        let msg = format!("0|HDFFF1C0|001|{}", fields.join("^"));
+        let Some(KisEvent::OrderConfirm(order)) = parse_one(&msg) else {
+            panic!("expected OrderConfirm");
+        };
+        assert_eq!(order.market, OrderMarket::OverseasFutureOption);
+        assert_eq!(
+            (order.kind, order.side),
+            (OrderNoticeKind::Accepted, Some(OrderSide::Buy))
+        );
+        assert_eq!(order.original_order_id, None);
+        assert_eq!(order.order_price, Some(dec!(0.6640)));
+        assert_eq!(order.time, kst_at("20260321", "063010").unwrap());
+        let order = KisEvent::OrderConfirm(order);
+        assert!(SubscriptionKind::OverseasDerivativeOrderNotice.produces(&order));
+        assert!(!SubscriptionKind::OverseasDerivativeFillNotice.produces(&order));
+
+        let mut fields = vec![""; 33];
+        fields[0] = "hts_user";
+        fields[1] = "12345678";
+        fields[3] = "0000000123";
+        fields[6] = "6AM24";
+        fields[8] = "02";
+        fields[12] = "5";
+        fields[17] = "3";
+        fields[21] = "20260321063020000";
+        fields[26] = "2";
+        fields[27] = "0.6640";
+        let msg = format!("0|HDFFF2C0|001|{}", fields.join("^"));
+        let mut events = parse_ws_message(&msg, &HashMap::new());
+        // 잔량은 추적기가 아니라 통보 값을 쓴다
+        FillTracker::default().track(&mut events);
+        let Some(KisEvent::OrderConfirm(fill)) = events.pop() else {
+            panic!("expected OrderConfirm");
+        };
+        assert_eq!(fill.kind, OrderNoticeKind::Filled);
+        assert_eq!(
+            (fill.filled_qty, fill.filled_price),
+            (dec!(2), dec!(0.6640))
+        );
+        assert_eq!(fill.remaining_qty, dec!(3));
+        assert!(
+            SubscriptionKind::OverseasDerivativeFillNotice.produces(&KisEvent::OrderConfirm(fill))
+        );
+
+        // 알 수 없는 매도매수구분도 통보는 전달한다
+        fields[8] = "09";
+        let msg = format!("0|HDFFF2C0|001|{}", fields.join("^"));
+        let Some(KisEvent::OrderConfirm(fill)) = parse_one(&msg) else {
+            panic!("expected OrderConfirm");
+        };
+        assert_eq!((fill.side, fill.filled_qty), (None, dec!(2)));
+    }
+
+    #[test]
+    fn expected_member_and_program_frames_by_venue() {
+        use rust_decimal_macros::dec;
+
+        // NXT 예상체결은 KRX보다 필드가 하나 더 붙는다
+        let mut fields = vec![""; 46];
+        fields[0] = "005930";
+        fields[1] = "085500";
+        fields[2] = "75600";
+        fields[4] = "200";
+        fields[5] = "0.27";
+        fields[12] = "1500";
+        fields[33] = "20260321";
+        fields[43] = "B";
+        let msg = format!("0|H0NXANC0|001|{}", fields.join("^"));
+        let Some(KisEvent::ExpectedExecution(expected)) = parse_one(&msg) else {
+            panic!("expected ExpectedExecution");
+        };
+        assert_eq!(expected.venue, Venue::Nxt);
+        assert_eq!((expected.price, expected.qty), (dec!(75600), dec!(1500)));
+        assert_eq!(expected.session_code.as_deref(), Some("B"));
+        assert_eq!(expected.time, kst_at("20260321", "085500").unwrap());
+
+        let mut fields = vec![""; 78];
+        fields[0] = "005930";
+        fields[1] = "모건스탠리";
+        fields[11] = "12000";
+        fields[21] = "Y";
+        fields[31] = "00036";
+        fields[41] = "12.5";
+        fields[51] = "300";
+        fields[6] = "키움증권";
+        fields[16] = "8000";
+        fields[36] = "00050";
+        fields[65] = "-4000";
+        let msg = format!("0|H0UNMBC0|001|{}", fields.join("^"));
+        let Some(KisEvent::MemberTrading(members)) = parse_one(&msg) else {
+            panic!("expected MemberTrading");
+        };
+        assert_eq!(members.venue, Venue::Integrated);
+        assert_eq!(members.sellers.len(), 1);
+        assert_eq!(members.sellers[0].name, "모건스탠리");
+        assert!(members.sellers[0].foreign);
+        assert_eq!(members.sellers[0].qty_change, dec!(300));
+        assert_eq!(members.buyers[0].member_no, "00050");
+        assert_eq!(members.foreign_net_qty, dec!(-4000));
+
+        let msg = "0|H0STPGM0|001|005930^100000^500^37700000^800^60320000^300^22620000^10^20^150";
+        let Some(KisEvent::ProgramTrading(program)) = parse_one(msg) else {
+            panic!("expected ProgramTrading");
+        };
+        assert_eq!(program.venue, Venue::Krx);
+        assert_eq!(
+            (program.net_qty, program.net_amount),
+            (dec!(300), dec!(22620000))
+        );
+        assert!(SubscriptionKind::ProgramTrading(Venue::Krx)
+            .produces(&KisEvent::ProgramTrading(program)));
+        assert_eq!(
+            SubscriptionKind::MemberTrading(Venue::Nxt).tr_id(),
+            "H0NXMBC0"
+        );
+    }
+
+    #[cfg(feature = "recorder")]
+    #[test]
+    fn frame_decoder_keeps_cipher_keys_per_session() {
+        let mut decoder = FrameDecoder::default();
+        let msg = format!("1|H0STCNT0|001|{}", encrypt(&h0stcnt0_fields()));
+
+        let now = chrono::Utc::now();
+
+        assert!(decoder
+            .decode(0, &subscribe_ack("H0STCNT0"), now)
+            .is_empty());
+        assert!(decoder
+            .decode(0, r#"{"header":{"tr_id":"PINGPONG"}}"#, now)
+            .is_empty());
+        assert!(matches!(
+            decoder.decode(0, &msg, now).pop(),
+            Some(KisEvent::Transaction(tx)) if tx.symbol == "005930"
+        ));
+        // 다른 세션의 키는 쓰지 않는다
+        assert!(decoder.decode(1, &msg, now).is_empty());
+    }
+
+    #[test]
+    fn backoff_duration_increases() {
+        let policy = ReconnectPolicy::default();
+        let d1 = policy.backoff(1);
+        let d4 = policy.backoff(4);
+        // d1 should be around 1s (+ jitter), d4 around 8s (+ jitter)
+        assert!(d1.as_millis() >= 1000);
+        assert!(d1.as_millis() <= 1300); // 1000 + 30% jitter max
+        assert!(d4.as_millis() >= 8000);
+    }
+
+    #[test]
+    fn backoff_duration_caps_at_max() {
+        let policy = ReconnectPolicy::default();
+        let d_big = policy.backoff(40); // 2^39 * 1s would be huge
+        assert!(d_big <= policy.max_backoff.mul_f64(1.3)); // max + jitter
+    }
+
+    #[test]
+    fn reconnect_policy_limits() {
+        let policy = ReconnectPolicy {
+            max_attempts: Some(2),
+            initial_backoff: std::time::Duration::from_millis(10),
+            max_backoff: std::time::Duration::from_millis(15),
+            jitter: 0.0,
+            give_up: GiveUp::StopSession,
+        };
+        assert_eq!(policy.backoff(1).as_millis(), 10);
+        assert_eq!(policy.backoff(2).as_millis(), 15);
+        assert!(!policy.exhausted(2));
+        assert!(policy.exhausted(3));
+        assert!(ReconnectPolicy::never().exhausted(1));
+        assert!(!ReconnectPolicy::default().exhausted(u32::MAX));
+    }
+
+    // ── 로컬 WebSocket 서버를 상대로 한 연결 테스트 ──────────────────────
+
+    type ServerWs = tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>;
+
+    /// 127.0.0.1 임의 포트에 서버를 띄우고 (ws_url, 첫 연결 수락 future) 반환
+    async fn local_server() -> (String, tokio::task::JoinHandle<ServerWs>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(tcp).await.unwrap()
+        });
+        (url, handle)
+    }
+
+    /// 연결 `n`개를 받는 서버
+    async fn local_server_n(n: usize) -> (String, tokio::task::JoinHandle<Vec<ServerWs>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut conns = Vec::with_capacity(n);
+            for _ in 0..n {
+                let (tcp, _) = listener.accept().await.unwrap();
+                conns.push(tokio_tungstenite::accept_async(tcp).await.unwrap());
+            }
+            conns
+        });
+        (url, handle)
+    }
+
+    async fn next_text(ws: &mut ServerWs) -> String {
+        use tokio_tungstenite::tungstenite::Message;
+        loop {
+            match ws.next().await.unwrap().unwrap() {
+                Message::Text(text) => return text.to_string(),
+                _ => continue,
+            }
+        }
+    }
+
+    /// 다음 구독/해제 요청을 읽고 KIS 형식 응답을 보낸다. 받은 요청을 반환.
+    async fn respond(
+        ws: &mut ServerWs,
+        rt_cd: &str,
+        msg_cd: &str,
+        msg1: &str,
+    ) -> serde_json::Value {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let req: serde_json::Value = serde_json::from_str(&next_text(ws).await).unwrap();
+        let ack = serde_json::json!({
+            "header": {
+                "tr_id": req["body"]["input"]["tr_id"],
+                "tr_key": req["body"]["input"]["tr_key"],
+                "encrypt": "N"
+            },
+            "body": { "rt_cd": rt_cd, "msg_cd": msg_cd, "msg1": msg1 }
+        });
+        ws.send(Message::Text(ack.to_string().into()))
+            .await
+            .unwrap();
+        req
+    }
+
+    async fn respond_ok(ws: &mut ServerWs) -> serde_json::Value {
+        respond(ws, "0", "OPSP0000", "SUBSCRIBE SUCCESS").await
+    }
+
+    #[tokio::test]
+    async fn subscribe_sends_kis_request_and_delivers_events() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+        let mut rx = market_receiver(&stream);
+
+        let (ack, req) = tokio::join!(
+            stream.subscribe("005930", SubscriptionKind::DomesticPrice),
+            respond_ok(&mut ws)
+        );
+        let ack = ack.unwrap();
+        assert_eq!(ack.msg1, "SUBSCRIBE SUCCESS");
+        assert_eq!(ack.tr_key, "005930");
+        assert_eq!(req["header"]["approval_key"], "approval");
+        assert_eq!(req["header"]["tr_type"], "1");
+        assert_eq!(req["body"]["input"]["tr_id"], "H0STCNT0");
+        assert_eq!(req["body"]["input"]["tr_key"], "005930");
+
+        let mut fields = vec![""; 46];
+        fields[0] = "005930";
+        fields[1] = "093000";
+        fields[2] = "75400";
+        fields[12] = "10";
+        fields[21] = "1";
+        let frame = format!("0|H0STCNT0|001|{}", fields.join("^"));
+        ws.send(Message::Text(frame.into())).await.unwrap();
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match event {
+            KisEvent::Transaction(tx) => assert_eq!(tx.symbol, "005930"),
+            other => panic!("expected Transaction, got {other:?}"),
+        }
+
+        let (ack, req) = tokio::join!(
+            stream.unsubscribe("005930", SubscriptionKind::DomesticPrice),
+            respond(&mut ws, "0", "OPSP0000", "UNSUBSCRIBE SUCCESS")
+        );
+        assert_eq!(ack.unwrap().unwrap().msg1, "UNSUBSCRIBE SUCCESS");
+        assert_eq!(req["header"]["tr_type"], "2");
+        assert_eq!(stream.subscription_count(), 0);
+        // 등록되지 않은 구독 해제는 요청 없이 끝난다
+        assert!(stream
+            .unsubscribe("005930", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap()
+            .is_none());
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn rejected_subscription_is_typed_and_not_recorded() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+
+        let (result, _) = tokio::join!(
+            stream.subscribe("BADKEY", SubscriptionKind::DomesticPrice),
+            respond(&mut ws, "1", "OPSP0011", "invalid tr_key")
+        );
+        match result {
+            Err(KisError::SubscribeRejected {
+                tr_id,
+                tr_key,
+                msg_cd,
+                message,
+            }) => {
+                assert_eq!(tr_id, "H0STCNT0");
+                assert_eq!(tr_key, "BADKEY");
+                assert_eq!(msg_cd, "OPSP0011");
+                assert_eq!(message, "invalid tr_key");
+            }
+            other => panic!("expected SubscribeRejected, got {other:?}"),
+        }
+        assert_eq!(stream.subscription_count(), 0);
+
+        // 이미 서버에 등록된 구독은 성공으로 기록
+        let (result, _) = tokio::join!(
+            stream.subscribe("005930", SubscriptionKind::DomesticPrice),
+            respond(&mut ws, "1", "OPSP0002", "ALREADY IN SUBSCRIBE")
+        );
+        assert_eq!(result.unwrap().msg_cd, "OPSP0002");
+        assert_eq!(stream.subscription_count(), 1);
+
+        // 로컬에 등록된 구독은 요청 없이 기존 응답을 돌려준다
+        let again = stream
+            .subscribe("005930", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap();
+        assert_eq!(again.msg1, "ALREADY IN SUBSCRIBE");
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn overseas_key_is_sent_as_tr_key() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+        let key = OverseasRealtimeKey::delayed("NAS", "NVDA");
+
+        let (ack, req) = tokio::join!(
+            stream.subscribe_overseas(&key, SubscriptionKind::Price),
+            respond_ok(&mut ws)
+        );
+        assert_eq!(ack.unwrap().tr_key, "DNASNVDA");
+        assert_eq!(req["body"]["input"]["tr_id"], "HDFSCNT0");
+        assert_eq!(req["body"]["input"]["tr_key"], "DNASNVDA");
+
+        let (ack, req) = tokio::join!(
+            stream.unsubscribe_overseas(&key, SubscriptionKind::Price),
+            respond(&mut ws, "0", "OPSP0000", "UNSUBSCRIBE SUCCESS")
+        );
+        assert!(ack.unwrap().is_some());
+        assert_eq!(req["body"]["input"]["tr_key"], "DNASNVDA");
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn missing_ack_times_out() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let _ws = server.await.unwrap();
+        stream.set_ack_timeout(std::time::Duration::from_millis(50));
+
+        let err = stream
+            .subscribe("005930", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KisError::SubscribeTimeout { ref tr_key, .. } if tr_key == "005930"));
+        assert_eq!(stream.subscription_count(), 0);
+        stream.close();
+    }
+
+    #[test]
+    fn subscribe_ack_parsing() {
+        let json: serde_json::Value = serde_json::from_str(&subscribe_ack("H0STCNI0")).unwrap();
+        let ack = parse_subscribe_ack(&json).unwrap();
+        assert_eq!(ack.tr_id, "H0STCNI0");
+        assert_eq!(ack.tr_key, "HTSID01");
+        assert!(ack.is_success());
+
+        let pingpong = serde_json::json!({ "header": { "tr_id": "PINGPONG" } });
+        assert!(parse_subscribe_ack(&pingpong).is_none());
+    }
+
+    #[tokio::test]
+    async fn stream_decrypts_frames_with_key_from_subscribe_ack() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+        let mut rx = market_receiver(&stream);
+
+        ws.send(Message::Text(subscribe_ack("H0STCNT0").into()))
+            .await
+            .unwrap();
+        let frame = format!("1|H0STCNT0|001|{}", encrypt(&h0stcnt0_fields()));
+        ws.send(Message::Text(frame.into())).await.unwrap();
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, KisEvent::Transaction(tx) if tx.symbol == "005930"));
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn subscribe_beyond_session_cap_is_rejected() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+        tokio::spawn(async move {
+            loop {
+                respond_ok(&mut ws).await;
+            }
+        });
+
+        assert_eq!(
+            stream.subscription_capacity(),
+            MAX_SUBSCRIPTIONS_PER_SESSION
+        );
+        for i in 0..MAX_SUBSCRIPTIONS_PER_SESSION {
+            stream
+                .subscribe(&format!("{i:06}"), SubscriptionKind::DomesticPrice)
+                .await
+                .unwrap();
+        }
+        // 이미 등록된 구독은 한도와 무관
+        stream
+            .subscribe("000000", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap();
+        let err = stream
+            .subscribe("999999", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KisError::SubscriptionLimit(41)));
+        assert_eq!(stream.subscription_count(), 41);
+
+        // 해제하면 다시 등록 가능
+        stream
+            .unsubscribe("000000", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap();
+        stream
+            .subscribe("999999", SubscriptionKind::DomesticPrice)
+            .await
+            .unwrap();
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn pooled_sessions_split_subscriptions_and_merge_events() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (url, server) = local_server_n(2).await;
+        let stream = KisStream::connect_sessions(
+            &url,
+            vec![
+                SessionKey::fixed("key0".into()),
+                SessionKey::fixed("key1".into()),
+            ],
+            16,
+            ReconnectPolicy::default(),
+        )
+        .await
+        .unwrap();
+        let mut conns = server.await.unwrap();
+        let mut rx = market_receiver(&stream);
+        assert_eq!(stream.subscription_capacity(), 82);
+
+        // 두 구독이 서로 다른 세션(approval key)으로 나뉜다
+        let mut keys = Vec::new();
+        for symbol in ["005930", "000660"] {
+            let (conn0, conn1) = conns.split_at_mut(1);
+            let (ack, req) = tokio::join!(
+                stream.subscribe(symbol, SubscriptionKind::DomesticPrice),
+                async {
+                    tokio::select! {
+                        req = respond_ok(&mut conn0[0]) => req,
+                        req = respond_ok(&mut conn1[0]) => req,
+                    }
+                }
+            );
+            ack.unwrap();
+            keys.push(req["header"]["approval_key"].as_str().unwrap().to_string());
+        }
+        keys.sort();
+        assert_eq!(keys, vec!["key0", "key1"]);
+
+        // 어느 세션에서 온 이벤트든 같은 수신기로 들어온다
+        for ws in conns.iter_mut() {
+            let frame = format!("0|H0STCNT0|001|{}", h0stcnt0_fields());
+            ws.send(Message::Text(frame.into())).await.unwrap();
+        }
+        for _ in 0..2 {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches!(event, KisEvent::Transaction(_)));
+        }
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn slow_ack_does_not_block_other_subscriptions() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (url, server) = local_server_n(2).await;
+        let stream = KisStream::connect_sessions(
+            &url,
+            vec![
+                SessionKey::fixed("key0".into()),
+                SessionKey::fixed("key1".into()),
+            ],
+            16,
+            ReconnectPolicy::default(),
+        )
+        .await
+        .unwrap();
+        let mut conns = server.await.unwrap();
+
+        // 첫 구독은 응답을 받지 못한 채 대기
+        let slow = tokio::spawn({
+            let stream = stream.clone();
+            async move {
+                stream
+                    .subscribe("005930", SubscriptionKind::DomesticPrice)
+                    .await
+            }
+        });
+        let (conn0, conn1) = conns.split_at_mut(1);
+        let (slow_ws, fast_ws, slow_req) = tokio::select! {
+            req = next_text(&mut conn0[0]) => (&mut conn0[0], &mut conn1[0], req),
+            req = next_text(&mut conn1[0]) => (&mut conn1[0], &mut conn0[0], req),
+        };
+
+        // 그동안 다른 구독은 다른 세션에서 바로 끝난다
+        let (ack, _) = tokio::join!(
+            stream.subscribe("000660", SubscriptionKind::DomesticPrice),
+            respond_ok(fast_ws)
+        );
+        ack.unwrap();
+        assert_eq!(stream.subscription_count(), 1);
+        assert!(!slow.is_finished());
+
+        let req: serde_json::Value = serde_json::from_str(&slow_req).unwrap();
+        let ack = serde_json::json!({
+            "header": { "tr_id": req["body"]["input"]["tr_id"], "tr_key": "005930", "encrypt": "N" },
+            "body": { "rt_cd": "0", "msg_cd": "OPSP0000", "msg1": "SUBSCRIBE SUCCESS" }
+        });
+        slow_ws
+            .send(Message::Text(ack.to_string().into()))
+            .await
+            .unwrap();
+        slow.await.unwrap().unwrap();
+        assert_eq!(stream.subscription_count(), 2);
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn cancelled_subscribe_releases_its_slot() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+
+        let pending = stream.subscribe("005930", SubscriptionKind::DomesticPrice);
+        let cancelled = tokio::time::timeout(std::time::Duration::from_millis(50), pending).await;
+        assert!(cancelled.is_err());
+        next_text(&mut ws).await;
+        assert_eq!(stream.inner.subscriptions.read().unwrap().len(), 0);
+
+        let (ack, _) = tokio::join!(
+            stream.subscribe("005930", SubscriptionKind::DomesticPrice),
+            respond_ok(&mut ws)
+        );
+        ack.unwrap();
+        assert_eq!(stream.subscription_count(), 1);
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn pingpong_is_echoed() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect(&url, "approval".into(), 16)
+            .await
+            .unwrap();
+        let mut ws = server.await.unwrap();
+
+        let ping = r#"{"header":{"tr_id":"PINGPONG","datetime":"20240101093000"}}"#;
+        ws.send(Message::Text(ping.into())).await.unwrap();
+        let echoed = tokio::time::timeout(std::time::Duration::from_secs(5), next_text(&mut ws))
+            .await
+            .unwrap();
+        assert_eq!(echoed, ping);
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn connect_fails_fast_on_unreachable_url() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let err = KisStream::connect(&url, "approval".into(), 16).await.err();
+        assert!(matches!(err, Some(KisError::WebSocket(_))));
+    }
+
+    #[tokio::test]
+    async fn test_pair_delivers_injected_events() {
+        use crate::event::QuoteData;
+        use rust_decimal_macros::dec;
+
+        let (stream, tx) = KisStream::test_pair();
+        let mut rx = stream.receiver();
+        tx.send(KisEvent::Quote(QuoteData {
+            symbol: "AAPL".into(),
+            overseas_key: None,
+            ask_price: dec!(191.00),
+            bid_price: dec!(190.90),
+            ask_qty: dec!(1),
+            bid_qty: dec!(2),
+            time: kst_at("20260321", "093000").unwrap(),
+        }))
+        .await;
+        assert!(matches!(rx.recv().await, Ok(KisEvent::Quote(q)) if q.symbol == "AAPL"));
+
+        drop(tx);
+        drop(stream);
+        assert!(matches!(rx.recv().await, Err(KisError::StreamClosed)));
+    }
+
+    #[tokio::test]
+    async fn filtered_receivers_and_stream_impl() {
+        use crate::event::{OverseasRealtimeKey, TransactionData};
+        use futures_util::StreamExt;
+        use rust_decimal_macros::dec;
+
+        let (stream, tx) = KisStream::test_pair();
+        let trade = |symbol: &str, key: Option<OverseasRealtimeKey>| {
+            KisEvent::Transaction(TransactionData {
+                symbol: symbol.into(),
+                overseas_key: key,
+                price: dec!(100),
+                qty: dec!(1),
+                time: kst_at("20260321", "093000").unwrap(),
+                is_buy: true,
+            })
+        };
+        let mut samsung = stream.receiver_for("005930", SubscriptionKind::DomesticPrice);
+        let mut nvda = stream.receiver_for("DNASNVDA", SubscriptionKind::Price);
+        let mut nvda_book = stream.receiver_for("DNASNVDA", SubscriptionKind::Orderbook);
+        let mut big = stream.receiver_matching(|e| e.symbol().len() > 4);
+
+        tx.send(trade("000660", None)).await;
+        tx.send(trade(
+            "NVDA",
+            Some(OverseasRealtimeKey::delayed("NAS", "NVDA")),
+        ))
+        .await;
+        tx.send(trade("005930", None)).await;
+        let gap = ConnectionEvent::Disconnected {
+            session: 0,
+            reason: "test".into(),
+            tr_keys: vec![],
+        };
+        tx.send(KisEvent::Connection(gap.clone())).await;
+        drop(tx);
+        drop(stream);
+
+        // 연결 상태 이벤트는 무손실 채널로 먼저 도착한다
+        let got: Vec<String> = samsung
+            .by_ref()
+            .map(|e| e.unwrap().symbol().to_string())
+            .collect()
+            .await;
+        assert_eq!(got, vec!["", "005930"]);
+        assert!(matches!(nvda.next().await, Some(Ok(KisEvent::Connection(c))) if c == gap));
+        assert!(
+            matches!(nvda.next().await, Some(Ok(KisEvent::Transaction(t))) if t.symbol == "NVDA")
+        );
+        assert!(nvda.next().await.is_none());
+        assert!(matches!(
+            nvda_book.next().await,
+            Some(Ok(KisEvent::Connection(_)))
+        ));
+        assert!(nvda_book.next().await.is_none());
+        assert_eq!(big.recv().await.unwrap().symbol(), "000660");
+        assert_eq!(big.recv().await.unwrap().symbol(), "005930");
+        assert!(matches!(big.recv().await, Err(KisError::StreamClosed)));
+    }
+
+    /// 연결 상태 이벤트를 뺀 수신기
+    fn market_receiver(stream: &KisStream) -> EventReceiver {
+        stream.receiver_matching(|e| !matches!(e, KisEvent::Connection(_)))
+    }
+
+    /// 시세를 건너뛰고 다음 연결 이벤트를 기다린다
+    async fn next_connection(rx: &mut EventReceiver) -> ConnectionEvent {
+        loop {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .expect("connection event")
+                .unwrap();
+            if let KisEvent::Connection(c) = event {
+                return c;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn heartbeat_timeout_reconnects_and_reports_lifecycle() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let accept = || async {
+            let (tcp, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(tcp).await.unwrap()
+        };
+
+        let (stream, mut ws) =
+            tokio::join!(KisStream::connect(&url, "approval".into(), 16), accept());
+        let stream = stream.unwrap();
+        let mut rx = stream.receiver();
+        stream.set_heartbeat_timeout(std::time::Duration::from_millis(100));
+        assert!(stream.status().is_connected());
+
+        for symbol in ["005930", "000660"] {
+            let (ack, _) = tokio::join!(
+                stream.subscribe(symbol, SubscriptionKind::DomesticPrice),
+                respond_ok(&mut ws)
+            );
+            ack.unwrap();
+        }
+
+        assert_eq!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Connected { session: 0 }
+        );
+        // 서버가 아무것도 보내지 않으면 감시 시간 후 끊긴 것으로 본다
+        match next_connection(&mut rx).await {
+            ConnectionEvent::Disconnected {
+                session: 0,
+                reason,
+                tr_keys,
+            } => {
+                assert_eq!(tr_keys, ["000660", "005930"]);
+                assert!(reason.contains("no message"), "{reason}")
+            }
+            other => panic!("expected Disconnected, got {other:?}"),
+        }
+        assert!(!stream.status().is_connected());
+        assert_eq!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Reconnecting {
+                session: 0,
+                attempt: 1
+            }
+        );
+
+        let mut ws = accept().await;
+        assert_eq!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Connected { session: 0 }
+        );
+        // 재구독 응답을 기다려 결과를 알린다. 거부된 구독은 목록에서 빠진다
+        for _ in 0..2 {
+            let req: serde_json::Value = serde_json::from_str(&next_text(&mut ws).await).unwrap();
+            let tr_key = req["body"]["input"]["tr_key"].clone();
+            let (rt_cd, msg1) = if tr_key == "000660" {
+                ("1", "MAX SUBSCRIBE OVER")
+            } else {
+                ("0", "SUBSCRIBE SUCCESS")
+            };
+            let ack = serde_json::json!({
+                "header": { "tr_id": "H0STCNT0", "tr_key": tr_key, "encrypt": "N" },
+                "body": { "rt_cd": rt_cd, "msg_cd": "OPSP0000", "msg1": msg1 }
+            });
+            ws.send(tokio_tungstenite::tungstenite::Message::Text(
+                ack.to_string().into(),
+            ))
+            .await
+            .unwrap();
+        }
+        match next_connection(&mut rx).await {
+            ConnectionEvent::ResubscribeFailed {
+                session: 0,
+                tr_key,
+                reason,
+                ..
+            } => {
+                assert_eq!(tr_key, "000660");
+                assert!(reason.contains("MAX SUBSCRIBE OVER"), "{reason}");
+            }
+            other => panic!("expected ResubscribeFailed, got {other:?}"),
+        }
+        assert_eq!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Resubscribed {
+                session: 0,
+                count: 1,
+                failed: 1
+            }
+        );
+        assert_eq!(stream.subscription_count(), 1);
+
+        let status = stream.status();
+        assert!(status.is_connected());
+        assert_eq!(status.sessions[0].reconnects, 1);
+        assert!(status.sessions[0].last_message.is_some());
+
+        stream.close();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(stream.status().sessions[0].state, ConnectionState::Closed);
+    }
+
+    fn fast_policy(max_attempts: Option<u32>) -> ReconnectPolicy {
+        ReconnectPolicy {
+            max_attempts,
+            initial_backoff: std::time::Duration::from_millis(10),
+            max_backoff: std::time::Duration::from_millis(10),
+            jitter: 0.0,
+            give_up: GiveUp::CloseStream,
+        }
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (url, server) = local_server().await;
+        let stream = KisStream::connect_sessions(
+            &url,
+            vec![SessionKey::fixed("approval".into())],
+            16,
+            fast_policy(Some(2)),
+        )
+        .await
+        .unwrap();
+        let mut rx = stream.receiver();
+        // 서버를 닫으면 재연결이 모두 실패한다 (리스너도 함께 닫힘)
+        drop(server.await.unwrap());
+
+        assert!(matches!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Connected { .. }
+        ));
+        assert!(matches!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::Disconnected { .. }
+        ));
+        for attempt in 1..=2 {
+            assert_eq!(
+                next_connection(&mut rx).await,
+                ConnectionEvent::Reconnecting {
+                    session: 0,
+                    attempt
+                }
+            );
+        }
+        assert_eq!(
+            next_connection(&mut rx).await,
+            ConnectionEvent::GaveUp {
+                session: 0,
+                attempts: 2
+            }
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert_eq!(stream.status().sessions[0].state, ConnectionState::Closed);
+        // CloseStream: 스트림 전체가 취소된다
+        assert!(stream.inner.cancel.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn quiet_successful_reconnects_never_give_up() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let accept = || async {
+            let (tcp, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(tcp).await.unwrap()
+        };
+
+        let (stream, mut ws) = tokio::join!(
+            KisStream::connect_sessions(
+                &url,
+                vec![SessionKey::fixed("approval".into())],
+                16,
+                fast_policy(Some(1)),
+            ),
+            accept()
+        );
+        let stream = stream.unwrap();
+        let mut rx = stream.receiver();
+        let (ack, _) = tokio::join!(
+            stream.subscribe("HTSID01", SubscriptionKind::OrderNotice),
+            respond_ok(&mut ws)
+        );
+        ack.unwrap();
+
+        // 시세 없이 재연결·재구독만 성공해도 매번 첫 시도로 센다
+        for _ in 0..3 {
+            drop(ws);
+            ws = tokio::time::timeout(std::time::Duration::from_secs(5), accept())
+                .await
+                .expect("reconnect");
+            respond_ok(&mut ws).await;
+            loop {
+                match next_connection(&mut rx).await {
+                    ConnectionEvent::Resubscribed { count: 1, .. } => break,
+                    ConnectionEvent::Reconnecting { attempt, .. } => assert_eq!(attempt, 1),
+                    ConnectionEvent::GaveUp { .. } => panic!("gave up after successful reconnects"),
+                    _ => {}
+                }
+            }
+        }
+        assert!(stream.status().is_connected());
+        assert!(!stream.inner.cancel.is_cancelled());
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn rejected_approval_key_is_refreshed_before_reconnect() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let accept = || async {
+            let (tcp, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(tcp).await.unwrap()
+        };
+
+        let refreshes = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let refresh: KeyRefresher = {
+            let refreshes = refreshes.clone();
+            Arc::new(move || {
+                let n = refreshes.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Box::pin(async move { Ok(format!("fresh{n}")) })
+            })
+        };
+        let key = SessionKey {
+            approval_key: "stale".into(),
+            refresh: Some(refresh),
+        };
+        let (stream, mut ws) = tokio::join!(
+            KisStream::connect_sessions(&url, vec![key], 16, fast_policy(None)),
+            accept()
+        );
+        let stream = stream.unwrap();
+        let mut rx = stream.receiver();
+
+        let (result, req) = tokio::join!(
+            stream.subscribe("005930", SubscriptionKind::DomesticPrice),
+            respond(&mut ws, "1", "OPSP0011", "invalid approval : NOT FOUND")
+        );
+        assert_eq!(req["header"]["approval_key"], "stale");
+        assert!(matches!(result, Err(KisError::SubscribeRejected { .. })));
+
+        let mut ws = accept().await;
+        loop {
+            if let ConnectionEvent::Disconnected { reason, .. } = next_connection(&mut rx).await {
+                assert!(reason.contains("approval key rejected"), "{reason}");
+                break;
+            }
+        }
+        loop {
+            if let ConnectionEvent::Connected { .. } = next_connection(&mut rx).await {
+                break;
+            }
+        }
+        assert_eq!(refreshes.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let (ack, req) = tokio::join!(
+            stream.subscribe("005930", SubscriptionKind::DomesticPrice),
+            respond_ok(&mut ws)
+        );
+        ack.unwrap();
+        assert_eq!(req["header"]["approval_key"], "fresh0");
+        stream.close();
+    }
+
+    #[tokio::test]
+    async fn approval_rejected_on_resubscribe_keeps_subscriptions() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let accept = || async {
+            let (tcp, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(tcp).await.unwrap()
+        };
+
+        let refresh: KeyRefresher = Arc::new(|| Box::pin(async { Ok("fresh".to_string()) }));
+        let key = SessionKey {
+            approval_key: "stale".into(),
+            refresh: Some(refresh),
+        };
+        let (stream, ws) = tokio::join!(
+            KisStream::connect_sessions(&url, vec![key], 16, fast_policy(None)),
+            accept()
+        );
+        let stream = stream.unwrap();
+        let mut rx = stream.receiver();
+        let mut ws = ws;
+        for symbol in ["005930", "000660"] {
+            let (ack, _) = tokio::join!(
+                stream.subscribe(symbol, SubscriptionKind::DomesticPrice),
+                respond_ok(&mut ws)
+            );
+            ack.unwrap();
+        }
+
+        // 재연결 후 첫 재구독에서 키가 만료돼 거부된다
+        drop(ws);
+        let mut ws = accept().await;
+        let req = respond(&mut ws, "1", "OPSP0011", "invalid approval : NOT FOUND").await;
+        assert_eq!(req["header"]["approval_key"], "stale");
+        loop {
+            if let ConnectionEvent::Disconnected { reason, .. } = next_connection(&mut rx).await {
+                if reason.contains("approval key rejected") {
+                    break;
+                }
+            }
+        }
+        assert_eq!(stream.subscription_count(), 2);
+
+        // 키를 재발급한 뒤 두 구독을 모두 다시 등록한다
+        let mut ws = accept().await;
+        let mut keys = Vec::new();
+        for _ in 0..2 {
+            let req = respond_ok(&mut ws).await;
+            assert_eq!(req["header"]["approval_key"], "fresh");
+            keys.push(req["body"]["input"]["tr_key"].as_str().unwrap().to_string());
+        }
+        keys.sort();
+        assert_eq!(keys, ["000660", "005930"]);
+        loop {
+            if let ConnectionEvent::Resubscribed { count, failed, .. } =
+                next_connection(&mut rx).await
+            {
+                if failed == 0 {
+                    assert_eq!(count, 2);
+                    break;
+                }
+            }
+        }
+        assert_eq!(stream.subscription_count(), 2);
+        stream.close();
     }
 }
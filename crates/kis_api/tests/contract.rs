@@ -0,0 +1,928 @@
+// This file is generated from kis-openapi.yaml. Do not edit manually.
+//! 명세 기반 계약 테스트.
+//!
+//! `tests/fixtures/contract/`의 픽스처(응답 모델당 1개)를 `client::decode_response`로
+//! 역직렬화한다. KIS가 `output1`/`output2`를 배열, 단일 객체, 빈 객체(`{}`)로
+//! 섞어 보내므로 세 변형을 모두 검사한다.
+#![allow(unused_imports, dead_code)]
+
+use kis_api::client::decode_response;
+use kis_api::models::*;
+use kis_api::KisError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 픽스처에서 배열로 표기된 출력 그룹 (모델의 `Vec<T>` 필드)
+fn array_groups(body: &Map<String, Value>) -> Vec<String> {
+    body.iter()
+        .filter(|(k, v)| k.starts_with("output") && v.is_array())
+        .map(|(k, _)| k.clone())
+        .collect()
+}
+
+fn load(fixture: &str) -> Map<String, Value> {
+    let path = format!(
+        "{}/tests/fixtures/contract/{fixture}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    match serde_json::from_str(&text) {
+        Ok(Value::Object(map)) => map,
+        other => panic!("{path}: 객체가 아님 ({other:?})"),
+    }
+}
+
+/// 픽스처를 변형해 역직렬화하고, 배열 그룹의 항목 수와 필드 키를 검증한다.
+fn check_variant<R>(
+    fixture: &str,
+    variant: &str,
+    arrays: &[String],
+    body: Map<String, Value>,
+) -> Result<(), String>
+where
+    R: DeserializeOwned + Serialize + UnknownFields,
+{
+    let fail = |msg: String| format!("{fixture} [{variant}]: {msg}");
+    let resp: R = decode_response(Value::Object(body.clone())).map_err(|e| fail(e.to_string()))?;
+    let unknown = resp.unknown_fields();
+    if !unknown.is_empty() {
+        return Err(fail(format!("명세 밖 필드로 분류됨: {unknown:?}")));
+    }
+    let Value::Object(decoded) = serde_json::to_value(&resp).map_err(|e| fail(e.to_string()))?
+    else {
+        return Err(fail("객체로 직렬화되지 않음".into()));
+    };
+    for (key, sent) in &body {
+        if !key.starts_with("output") {
+            continue;
+        }
+        let sent_items: Vec<&Value> = match sent {
+            Value::Array(items) => items.iter().collect(),
+            Value::Object(obj) if obj.is_empty() => vec![],
+            other => vec![other],
+        };
+        let got_items: Vec<&Value> = match decoded.get(key) {
+            Some(Value::Array(items)) => items.iter().collect(),
+            Some(item @ Value::Object(_)) => vec![item],
+            _ => vec![],
+        };
+        if arrays.contains(key) && sent_items.len() != got_items.len() {
+            return Err(fail(format!(
+                "{key}: 항목 {}개 기대, {}개 수신",
+                sent_items.len(),
+                got_items.len()
+            )));
+        }
+        for (sent, got) in sent_items.iter().zip(&got_items) {
+            let (Value::Object(sent), Value::Object(got)) = (sent, got) else {
+                continue;
+            };
+            if let Some(missing) = sent.keys().find(|k| !got.contains_key(*k)) {
+                return Err(fail(format!("{key}.{missing}: 모델에 매핑되지 않음")));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 배열 / 단일 객체 / 빈 객체 세 변형을 모두 검사
+fn check<R>(fixture: &str) -> Result<(), String>
+where
+    R: DeserializeOwned + Serialize + UnknownFields,
+{
+    let body = load(fixture);
+    let arrays = array_groups(&body);
+    check_variant::<R>(fixture, "array", &arrays, body.clone())?;
+
+    let mut single = body.clone();
+    for key in &arrays {
+        if let Some(Value::Array(items)) = single.get_mut(key) {
+            if !items.is_empty() {
+                let first = items.remove(0);
+                single.insert(key.clone(), first);
+            }
+        }
+    }
+    check_variant::<R>(fixture, "single", &arrays, single)?;
+
+    let mut empty = body;
+    for (key, value) in empty.iter_mut() {
+        if key.starts_with("output") {
+            *value = Value::Object(Map::new());
+        }
+    }
+    check_variant::<R>(fixture, "empty", &arrays, empty)
+}
+
+fn assert_all(results: &[Result<(), String>]) {
+    let failures: Vec<&str> = results
+        .iter()
+        .filter_map(|r| r.as_ref().err().map(String::as_str))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{}개 모델 실패:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn error_header_is_not_deserialized_as_success() {
+    let body = serde_json::json!({
+        "rt_cd": "1",
+        "msg_cd": "EGW00123",
+        "msg1": "기간이 만료된 token 입니다."
+    });
+    match decode_response::<Oauth2ApprovalResponse>(body) {
+        Err(KisError::Api { rt_cd, msg_cd, .. }) => {
+            assert_eq!(rt_cd, "1");
+            assert_eq!(msg_cd, "EGW00123");
+        }
+        other => panic!("KisError::Api 기대, {other:?}"),
+    }
+}
+
+#[cfg(feature = "domestic-stock")]
+#[test]
+fn domestic_stock_responses() {
+    assert_all(&[
+        check::<DomesticStockV1TradingOrderCashResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderCash.json",
+        ),
+        check::<DomesticStockV1TradingOrderCreditResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderCredit.json",
+        ),
+        check::<DomesticStockV1TradingOrderRvsecnclResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderRvsecncl.json",
+        ),
+        check::<DomesticStockV1TradingInquirePsblRvsecnclResponse>(
+            "domestic_stock/DomesticStockV1TradingInquirePsblRvsecncl.json",
+        ),
+        check::<DomesticStockV1TradingInquireDailyCcldResponse>(
+            "domestic_stock/DomesticStockV1TradingInquireDailyCcld.json",
+        ),
+        check::<DomesticStockV1TradingInquireBalanceResponse>(
+            "domestic_stock/DomesticStockV1TradingInquireBalance.json",
+        ),
+        check::<DomesticStockV1TradingInquirePsblOrderResponse>(
+            "domestic_stock/DomesticStockV1TradingInquirePsblOrder.json",
+        ),
+        check::<DomesticStockV1TradingInquirePsblSellResponse>(
+            "domestic_stock/DomesticStockV1TradingInquirePsblSell.json",
+        ),
+        check::<DomesticStockV1TradingInquireCreditPsamountResponse>(
+            "domestic_stock/DomesticStockV1TradingInquireCreditPsamount.json",
+        ),
+        check::<DomesticStockV1TradingOrderResvResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderResv.json",
+        ),
+        check::<DomesticStockV1TradingOrderResvRvsecnclResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderResvRvsecncl.json",
+        ),
+        check::<DomesticStockV1TradingOrderResvCcnlResponse>(
+            "domestic_stock/DomesticStockV1TradingOrderResvCcnl.json",
+        ),
+        check::<DomesticStockV1TradingPensionInquirePresentBalanceResponse>(
+            "domestic_stock/DomesticStockV1TradingPensionInquirePresentBalance.json",
+        ),
+        check::<DomesticStockV1TradingPensionInquireDailyCcldResponse>(
+            "domestic_stock/DomesticStockV1TradingPensionInquireDailyCcld.json",
+        ),
+        check::<DomesticStockV1TradingPensionInquirePsblOrderResponse>(
+            "domestic_stock/DomesticStockV1TradingPensionInquirePsblOrder.json",
+        ),
+        check::<DomesticStockV1TradingPensionInquireDepositResponse>(
+            "domestic_stock/DomesticStockV1TradingPensionInquireDeposit.json",
+        ),
+        check::<DomesticStockV1TradingPensionInquireBalanceResponse>(
+            "domestic_stock/DomesticStockV1TradingPensionInquireBalance.json",
+        ),
+        check::<DomesticStockV1TradingInquireBalanceRlzPlResponse>(
+            "domestic_stock/DomesticStockV1TradingInquireBalanceRlzPl.json",
+        ),
+        check::<DomesticStockV1TradingInquireAccountBalanceResponse>(
+            "domestic_stock/DomesticStockV1TradingInquireAccountBalance.json",
+        ),
+        check::<DomesticStockV1TradingInquirePeriodProfitResponse>(
+            "domestic_stock/DomesticStockV1TradingInquirePeriodProfit.json",
+        ),
+        check::<DomesticStockV1TradingInquirePeriodTradeProfitResponse>(
+            "domestic_stock/DomesticStockV1TradingInquirePeriodTradeProfit.json",
+        ),
+        check::<DomesticStockV1TradingIntgrMarginResponse>(
+            "domestic_stock/DomesticStockV1TradingIntgrMargin.json",
+        ),
+        check::<DomesticStockV1TradingPeriodRightsResponse>(
+            "domestic_stock/DomesticStockV1TradingPeriodRights.json",
+        ),
+        check::<DomesticStockV1QuotationsInquirePriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquirePrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquirePrice2Response>(
+            "domestic_stock/DomesticStockV1QuotationsInquirePrice2.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireCcnlResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireCcnl.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireDailyPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireDailyPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireAskingPriceExpCcnResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireAskingPriceExpCcn.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireInvestorResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireInvestor.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireMemberResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireMember.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireDailyItemchartpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireDailyItemchartprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireTimeItemchartpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireTimeItemchartprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireTimeDailychartpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireTimeDailychartprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireTimeItemconclusionResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireTimeItemconclusion.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireDailyOvertimepriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireDailyOvertimeprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireTimeOvertimeconclusionResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireTimeOvertimeconclusion.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireOvertimePriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireOvertimePrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireOvertimeAskingPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireOvertimeAskingPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsExpClosingPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsExpClosingPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireElwPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireElwPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireIndexPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireIndexPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireIndexDailyPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireIndexDailyPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireIndexTickpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireIndexTickprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireIndexTimepriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireIndexTimeprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireTimeIndexchartpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireTimeIndexchartprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireDailyIndexchartpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireDailyIndexchartprice.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireIndexCategoryPriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireIndexCategoryPrice.json",
+        ),
+        check::<DomesticStockV1QuotationsExpIndexTrendResponse>(
+            "domestic_stock/DomesticStockV1QuotationsExpIndexTrend.json",
+        ),
+        check::<DomesticStockV1QuotationsExpTotalIndexResponse>(
+            "domestic_stock/DomesticStockV1QuotationsExpTotalIndex.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireViStatusResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireViStatus.json",
+        ),
+        check::<DomesticStockV1QuotationsCompInterestResponse>(
+            "domestic_stock/DomesticStockV1QuotationsCompInterest.json",
+        ),
+        check::<DomesticStockV1QuotationsNewsTitleResponse>(
+            "domestic_stock/DomesticStockV1QuotationsNewsTitle.json",
+        ),
+        check::<DomesticStockV1QuotationsChkHolidayResponse>(
+            "domestic_stock/DomesticStockV1QuotationsChkHoliday.json",
+        ),
+        check::<DomesticStockV1QuotationsMarketTimeResponse>(
+            "domestic_stock/DomesticStockV1QuotationsMarketTime.json",
+        ),
+        check::<DomesticStockV1QuotationsSearchInfoResponse>(
+            "domestic_stock/DomesticStockV1QuotationsSearchInfo.json",
+        ),
+        check::<DomesticStockV1QuotationsSearchStockInfoResponse>(
+            "domestic_stock/DomesticStockV1QuotationsSearchStockInfo.json",
+        ),
+        check::<DomesticStockV1FinanceBalanceSheetResponse>(
+            "domestic_stock/DomesticStockV1FinanceBalanceSheet.json",
+        ),
+        check::<DomesticStockV1FinanceIncomeStatementResponse>(
+            "domestic_stock/DomesticStockV1FinanceIncomeStatement.json",
+        ),
+        check::<DomesticStockV1FinanceFinancialRatioResponse>(
+            "domestic_stock/DomesticStockV1FinanceFinancialRatio.json",
+        ),
+        check::<DomesticStockV1FinanceProfitRatioResponse>(
+            "domestic_stock/DomesticStockV1FinanceProfitRatio.json",
+        ),
+        check::<DomesticStockV1FinanceOtherMajorRatiosResponse>(
+            "domestic_stock/DomesticStockV1FinanceOtherMajorRatios.json",
+        ),
+        check::<DomesticStockV1FinanceStabilityRatioResponse>(
+            "domestic_stock/DomesticStockV1FinanceStabilityRatio.json",
+        ),
+        check::<DomesticStockV1FinanceGrowthRatioResponse>(
+            "domestic_stock/DomesticStockV1FinanceGrowthRatio.json",
+        ),
+        check::<DomesticStockV1QuotationsCreditByCompanyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsCreditByCompany.json",
+        ),
+        check::<DomesticStockV1KsdinfoDividendResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoDividend.json",
+        ),
+        check::<DomesticStockV1KsdinfoPurreqResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoPurreq.json",
+        ),
+        check::<DomesticStockV1KsdinfoMergerSplitResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoMergerSplit.json",
+        ),
+        check::<DomesticStockV1KsdinfoRevSplitResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoRevSplit.json",
+        ),
+        check::<DomesticStockV1KsdinfoCapDcrsResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoCapDcrs.json",
+        ),
+        check::<DomesticStockV1KsdinfoListInfoResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoListInfo.json",
+        ),
+        check::<DomesticStockV1KsdinfoPubOfferResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoPubOffer.json",
+        ),
+        check::<DomesticStockV1KsdinfoForfeitResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoForfeit.json",
+        ),
+        check::<DomesticStockV1KsdinfoMandDepositResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoMandDeposit.json",
+        ),
+        check::<DomesticStockV1KsdinfoPaidinCapinResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoPaidinCapin.json",
+        ),
+        check::<DomesticStockV1KsdinfoBonusIssueResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoBonusIssue.json",
+        ),
+        check::<DomesticStockV1KsdinfoSharehldMeetResponse>(
+            "domestic_stock/DomesticStockV1KsdinfoSharehldMeet.json",
+        ),
+        check::<DomesticStockV1QuotationsEstimatePerformResponse>(
+            "domestic_stock/DomesticStockV1QuotationsEstimatePerform.json",
+        ),
+        check::<DomesticStockV1QuotationsLendableByCompanyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsLendableByCompany.json",
+        ),
+        check::<DomesticStockV1QuotationsInvestOpinionResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInvestOpinion.json",
+        ),
+        check::<DomesticStockV1QuotationsInvestOpbysecResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInvestOpbysec.json",
+        ),
+        check::<DomesticStockV1QuotationsPsearchTitleResponse>(
+            "domestic_stock/DomesticStockV1QuotationsPsearchTitle.json",
+        ),
+        check::<DomesticStockV1QuotationsPsearchResultResponse>(
+            "domestic_stock/DomesticStockV1QuotationsPsearchResult.json",
+        ),
+        check::<DomesticStockV1QuotationsIntstockGrouplistResponse>(
+            "domestic_stock/DomesticStockV1QuotationsIntstockGrouplist.json",
+        ),
+        check::<DomesticStockV1QuotationsIntstockMultpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsIntstockMultprice.json",
+        ),
+        check::<DomesticStockV1QuotationsIntstockStocklistByGroupResponse>(
+            "domestic_stock/DomesticStockV1QuotationsIntstockStocklistByGroup.json",
+        ),
+        check::<DomesticStockV1QuotationsForeignInstitutionTotalResponse>(
+            "domestic_stock/DomesticStockV1QuotationsForeignInstitutionTotal.json",
+        ),
+        check::<DomesticStockV1QuotationsFrgnmemTradeEstimateResponse>(
+            "domestic_stock/DomesticStockV1QuotationsFrgnmemTradeEstimate.json",
+        ),
+        check::<DomesticStockV1QuotationsInvestorTradeByStockDailyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInvestorTradeByStockDaily.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireInvestorTimeByMarketResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireInvestorTimeByMarket.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireInvestorDailyByMarketResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireInvestorDailyByMarket.json",
+        ),
+        check::<DomesticStockV1QuotationsFrgnmemPchsTrendResponse>(
+            "domestic_stock/DomesticStockV1QuotationsFrgnmemPchsTrend.json",
+        ),
+        check::<DomesticStockV1QuotationsFrgnmemTradeTrendResponse>(
+            "domestic_stock/DomesticStockV1QuotationsFrgnmemTradeTrend.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireMemberDailyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireMemberDaily.json",
+        ),
+        check::<DomesticStockV1QuotationsProgramTradeByStockResponse>(
+            "domestic_stock/DomesticStockV1QuotationsProgramTradeByStock.json",
+        ),
+        check::<DomesticStockV1QuotationsProgramTradeByStockDailyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsProgramTradeByStockDaily.json",
+        ),
+        check::<DomesticStockV1QuotationsInvestorTrendEstimateResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInvestorTrendEstimate.json",
+        ),
+        check::<DomesticStockV1QuotationsInquireDailyTradeVolumeResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInquireDailyTradeVolume.json",
+        ),
+        check::<DomesticStockV1QuotationsCompProgramTradeTodayResponse>(
+            "domestic_stock/DomesticStockV1QuotationsCompProgramTradeToday.json",
+        ),
+        check::<DomesticStockV1QuotationsCompProgramTradeDailyResponse>(
+            "domestic_stock/DomesticStockV1QuotationsCompProgramTradeDaily.json",
+        ),
+        check::<DomesticStockV1QuotationsInvestorProgramTradeTodayResponse>(
+            "domestic_stock/DomesticStockV1QuotationsInvestorProgramTradeToday.json",
+        ),
+        check::<DomesticStockV1QuotationsDailyCreditBalanceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsDailyCreditBalance.json",
+        ),
+        check::<DomesticStockV1QuotationsExpPriceTrendResponse>(
+            "domestic_stock/DomesticStockV1QuotationsExpPriceTrend.json",
+        ),
+        check::<DomesticStockV1QuotationsDailyShortSaleResponse>(
+            "domestic_stock/DomesticStockV1QuotationsDailyShortSale.json",
+        ),
+        check::<DomesticStockV1RankingOvertimeExpTransFluctResponse>(
+            "domestic_stock/DomesticStockV1RankingOvertimeExpTransFluct.json",
+        ),
+        check::<DomesticStockV1QuotationsTradprtByamtResponse>(
+            "domestic_stock/DomesticStockV1QuotationsTradprtByamt.json",
+        ),
+        check::<DomesticStockV1QuotationsMktfundsResponse>(
+            "domestic_stock/DomesticStockV1QuotationsMktfunds.json",
+        ),
+        check::<DomesticStockV1QuotationsDailyLoanTransResponse>(
+            "domestic_stock/DomesticStockV1QuotationsDailyLoanTrans.json",
+        ),
+        check::<DomesticStockV1QuotationsCaptureUplowpriceResponse>(
+            "domestic_stock/DomesticStockV1QuotationsCaptureUplowprice.json",
+        ),
+        check::<DomesticStockV1QuotationsPbarTratioResponse>(
+            "domestic_stock/DomesticStockV1QuotationsPbarTratio.json",
+        ),
+        check::<DomesticStockV1QuotationsVolumeRankResponse>(
+            "domestic_stock/DomesticStockV1QuotationsVolumeRank.json",
+        ),
+        check::<DomesticStockV1RankingFluctuationResponse>(
+            "domestic_stock/DomesticStockV1RankingFluctuation.json",
+        ),
+        check::<DomesticStockV1RankingQuoteBalanceResponse>(
+            "domestic_stock/DomesticStockV1RankingQuoteBalance.json",
+        ),
+        check::<DomesticStockV1RankingProfitAssetIndexResponse>(
+            "domestic_stock/DomesticStockV1RankingProfitAssetIndex.json",
+        ),
+        check::<DomesticStockV1RankingMarketCapResponse>(
+            "domestic_stock/DomesticStockV1RankingMarketCap.json",
+        ),
+        check::<DomesticStockV1RankingFinanceRatioResponse>(
+            "domestic_stock/DomesticStockV1RankingFinanceRatio.json",
+        ),
+        check::<DomesticStockV1RankingAfterHourBalanceResponse>(
+            "domestic_stock/DomesticStockV1RankingAfterHourBalance.json",
+        ),
+        check::<DomesticStockV1RankingPreferDisparateRatioResponse>(
+            "domestic_stock/DomesticStockV1RankingPreferDisparateRatio.json",
+        ),
+        check::<DomesticStockV1RankingDisparityResponse>(
+            "domestic_stock/DomesticStockV1RankingDisparity.json",
+        ),
+        check::<DomesticStockV1RankingMarketValueResponse>(
+            "domestic_stock/DomesticStockV1RankingMarketValue.json",
+        ),
+        check::<DomesticStockV1RankingVolumePowerResponse>(
+            "domestic_stock/DomesticStockV1RankingVolumePower.json",
+        ),
+        check::<DomesticStockV1RankingTopInterestStockResponse>(
+            "domestic_stock/DomesticStockV1RankingTopInterestStock.json",
+        ),
+        check::<DomesticStockV1RankingExpTransUpdownResponse>(
+            "domestic_stock/DomesticStockV1RankingExpTransUpdown.json",
+        ),
+        check::<DomesticStockV1RankingTradedByCompanyResponse>(
+            "domestic_stock/DomesticStockV1RankingTradedByCompany.json",
+        ),
+        check::<DomesticStockV1RankingNearNewHighlowResponse>(
+            "domestic_stock/DomesticStockV1RankingNearNewHighlow.json",
+        ),
+        check::<DomesticStockV1RankingDividendRateResponse>(
+            "domestic_stock/DomesticStockV1RankingDividendRate.json",
+        ),
+        check::<DomesticStockV1RankingBulkTransNumResponse>(
+            "domestic_stock/DomesticStockV1RankingBulkTransNum.json",
+        ),
+        check::<DomesticStockV1RankingCreditBalanceResponse>(
+            "domestic_stock/DomesticStockV1RankingCreditBalance.json",
+        ),
+        check::<DomesticStockV1RankingShortSaleResponse>(
+            "domestic_stock/DomesticStockV1RankingShortSale.json",
+        ),
+        check::<DomesticStockV1RankingOvertimeFluctuationResponse>(
+            "domestic_stock/DomesticStockV1RankingOvertimeFluctuation.json",
+        ),
+        check::<DomesticStockV1RankingOvertimeVolumeResponse>(
+            "domestic_stock/DomesticStockV1RankingOvertimeVolume.json",
+        ),
+        check::<DomesticStockV1RankingHtsTopViewResponse>(
+            "domestic_stock/DomesticStockV1RankingHtsTopView.json",
+        ),
+    ]);
+}
+
+#[cfg(feature = "domestic-futureoption")]
+#[test]
+fn domestic_futureoption_responses() {
+    assert_all(&[
+        check::<DomesticFutureoptionV1TradingOrderResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingOrder.json",
+        ),
+        check::<DomesticFutureoptionV1TradingOrderRvsecnclResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingOrderRvsecncl.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireCcnlResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireCcnl.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireBalanceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireBalance.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquirePsblOrderResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquirePsblOrder.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireNgtCcnlResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireNgtCcnl.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireNgtBalanceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireNgtBalance.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquirePsblNgtOrderResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquirePsblNgtOrder.json",
+        ),
+        check::<DomesticFutureoptionV1TradingNgtMarginDetailResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingNgtMarginDetail.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireBalanceSettlementPlResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireBalanceSettlementPl.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireDepositResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireDeposit.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireBalanceValuationPlResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireBalanceValuationPl.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireCcnlBstimeResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireCcnlBstime.json",
+        ),
+        check::<DomesticFutureoptionV1TradingInquireDailyAmountFeeResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1TradingInquireDailyAmountFee.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsMarginRateResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsMarginRate.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsInquirePriceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsInquirePrice.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsInquireAskingPriceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsInquireAskingPrice.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsInquireDailyFuopchartpriceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsInquireDailyFuopchartprice.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsInquireTimeFuopchartpriceResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsInquireTimeFuopchartprice.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsDisplayBoardOptionListResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsDisplayBoardOptionList.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsDisplayBoardTopResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsDisplayBoardTop.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsDisplayBoardCallputResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsDisplayBoardCallput.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsDisplayBoardFuturesResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsDisplayBoardFutures.json",
+        ),
+        check::<DomesticFutureoptionV1QuotationsExpPriceTrendResponse>(
+            "domestic_futureoption/DomesticFutureoptionV1QuotationsExpPriceTrend.json",
+        ),
+    ]);
+}
+
+#[cfg(feature = "overseas-stock")]
+#[test]
+fn overseas_stock_responses() {
+    assert_all(&[
+        check::<OverseasStockV1TradingOrderResponse>(
+            "overseas_stock/OverseasStockV1TradingOrder.json",
+        ),
+        check::<OverseasStockV1TradingOrderRvsecnclResponse>(
+            "overseas_stock/OverseasStockV1TradingOrderRvsecncl.json",
+        ),
+        check::<OverseasStockV1TradingOrderResvResponse>(
+            "overseas_stock/OverseasStockV1TradingOrderResv.json",
+        ),
+        check::<OverseasStockV1TradingOrderResvCcnlResponse>(
+            "overseas_stock/OverseasStockV1TradingOrderResvCcnl.json",
+        ),
+        check::<OverseasStockV1TradingInquirePsamountResponse>(
+            "overseas_stock/OverseasStockV1TradingInquirePsamount.json",
+        ),
+        check::<OverseasStockV1TradingInquireNccsResponse>(
+            "overseas_stock/OverseasStockV1TradingInquireNccs.json",
+        ),
+        check::<OverseasStockV1TradingInquireBalanceResponse>(
+            "overseas_stock/OverseasStockV1TradingInquireBalance.json",
+        ),
+        check::<OverseasStockV1TradingInquireCcnlResponse>(
+            "overseas_stock/OverseasStockV1TradingInquireCcnl.json",
+        ),
+        check::<OverseasStockV1TradingInquirePresentBalanceResponse>(
+            "overseas_stock/OverseasStockV1TradingInquirePresentBalance.json",
+        ),
+        check::<OverseasStockV1TradingOrderResvListResponse>(
+            "overseas_stock/OverseasStockV1TradingOrderResvList.json",
+        ),
+        check::<OverseasStockV1TradingInquirePaymtStdrBalanceResponse>(
+            "overseas_stock/OverseasStockV1TradingInquirePaymtStdrBalance.json",
+        ),
+        check::<OverseasStockV1TradingInquirePeriodTransResponse>(
+            "overseas_stock/OverseasStockV1TradingInquirePeriodTrans.json",
+        ),
+        check::<OverseasStockV1TradingInquirePeriodProfitResponse>(
+            "overseas_stock/OverseasStockV1TradingInquirePeriodProfit.json",
+        ),
+        check::<OverseasStockV1TradingForeignMarginResponse>(
+            "overseas_stock/OverseasStockV1TradingForeignMargin.json",
+        ),
+        check::<OverseasStockV1TradingDaytimeOrderResponse>(
+            "overseas_stock/OverseasStockV1TradingDaytimeOrder.json",
+        ),
+        check::<OverseasStockV1TradingDaytimeOrderRvsecnclResponse>(
+            "overseas_stock/OverseasStockV1TradingDaytimeOrderRvsecncl.json",
+        ),
+        check::<OverseasStockV1TradingAlgoOrdnoResponse>(
+            "overseas_stock/OverseasStockV1TradingAlgoOrdno.json",
+        ),
+        check::<OverseasStockV1TradingInquireAlgoCcnlResponse>(
+            "overseas_stock/OverseasStockV1TradingInquireAlgoCcnl.json",
+        ),
+        check::<OverseasPriceV1QuotationsPriceDetailResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsPriceDetail.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireAskingPriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireAskingPrice.json",
+        ),
+        check::<OverseasPriceV1QuotationsPriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsPrice.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireCcnlResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireCcnl.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireTimeItemchartpriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireTimeItemchartprice.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireTimeIndexchartpriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireTimeIndexchartprice.json",
+        ),
+        check::<OverseasPriceV1QuotationsDailypriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsDailyprice.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireDailyChartpriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireDailyChartprice.json",
+        ),
+        check::<OverseasPriceV1QuotationsInquireSearchResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsInquireSearch.json",
+        ),
+        check::<OverseasStockV1QuotationsCountriesHolidayResponse>(
+            "overseas_stock/OverseasStockV1QuotationsCountriesHoliday.json",
+        ),
+        check::<OverseasPriceV1QuotationsSearchInfoResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsSearchInfo.json",
+        ),
+        check::<OverseasPriceV1QuotationsIndustryThemeResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsIndustryTheme.json",
+        ),
+        check::<OverseasPriceV1QuotationsIndustryPriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsIndustryPrice.json",
+        ),
+        check::<OverseasPriceV1QuotationsMultpriceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsMultprice.json",
+        ),
+        check::<OverseasStockV1RankingPriceFluctResponse>(
+            "overseas_stock/OverseasStockV1RankingPriceFluct.json",
+        ),
+        check::<OverseasStockV1RankingVolumeSurgeResponse>(
+            "overseas_stock/OverseasStockV1RankingVolumeSurge.json",
+        ),
+        check::<OverseasStockV1RankingVolumePowerResponse>(
+            "overseas_stock/OverseasStockV1RankingVolumePower.json",
+        ),
+        check::<OverseasStockV1RankingUpdownRateResponse>(
+            "overseas_stock/OverseasStockV1RankingUpdownRate.json",
+        ),
+        check::<OverseasStockV1RankingNewHighlowResponse>(
+            "overseas_stock/OverseasStockV1RankingNewHighlow.json",
+        ),
+        check::<OverseasStockV1RankingTradeVolResponse>(
+            "overseas_stock/OverseasStockV1RankingTradeVol.json",
+        ),
+        check::<OverseasStockV1RankingTradePbmnResponse>(
+            "overseas_stock/OverseasStockV1RankingTradePbmn.json",
+        ),
+        check::<OverseasStockV1RankingTradeGrowthResponse>(
+            "overseas_stock/OverseasStockV1RankingTradeGrowth.json",
+        ),
+        check::<OverseasStockV1RankingTradeTurnoverResponse>(
+            "overseas_stock/OverseasStockV1RankingTradeTurnover.json",
+        ),
+        check::<OverseasStockV1RankingMarketCapResponse>(
+            "overseas_stock/OverseasStockV1RankingMarketCap.json",
+        ),
+        check::<OverseasPriceV1QuotationsPeriodRightsResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsPeriodRights.json",
+        ),
+        check::<OverseasPriceV1QuotationsNewsTitleResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsNewsTitle.json",
+        ),
+        check::<OverseasPriceV1QuotationsRightsByIceResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsRightsByIce.json",
+        ),
+        check::<OverseasPriceV1QuotationsColableByCompanyResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsColableByCompany.json",
+        ),
+        check::<OverseasPriceV1QuotationsBrknewsTitleResponse>(
+            "overseas_stock/OverseasPriceV1QuotationsBrknewsTitle.json",
+        ),
+    ]);
+}
+
+#[cfg(feature = "overseas-futureoption")]
+#[test]
+fn overseas_futureoption_responses() {
+    assert_all(&[
+        check::<OverseasFutureoptionV1TradingOrderResponse>("overseas_futureoption/OverseasFutureoptionV1TradingOrder.json"),
+        check::<OverseasFutureoptionV1TradingOrderRvsecnclResponse>("overseas_futureoption/OverseasFutureoptionV1TradingOrderRvsecncl.json"),
+        check::<OverseasFutureoptionV1TradingInquireCcldResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquireCcld.json"),
+        check::<OverseasFutureoptionV1TradingInquireUnpdResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquireUnpd.json"),
+        check::<OverseasFutureoptionV1TradingInquirePsamountResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquirePsamount.json"),
+        check::<OverseasFutureoptionV1TradingInquirePeriodCcldResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquirePeriodCcld.json"),
+        check::<OverseasFutureoptionV1TradingInquireDailyCcldResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquireDailyCcld.json"),
+        check::<OverseasFutureoptionV1TradingInquireDepositResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquireDeposit.json"),
+        check::<OverseasFutureoptionV1TradingInquireDailyOrderResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquireDailyOrder.json"),
+        check::<OverseasFutureoptionV1TradingInquirePeriodTransResponse>("overseas_futureoption/OverseasFutureoptionV1TradingInquirePeriodTrans.json"),
+        check::<OverseasFutureoptionV1TradingMarginDetailResponse>("overseas_futureoption/OverseasFutureoptionV1TradingMarginDetail.json"),
+        check::<OverseasFutureoptionV1QuotationsInquirePriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsInquirePrice.json"),
+        check::<OverseasFutureoptionV1QuotationsStockDetailResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsStockDetail.json"),
+        check::<OverseasFutureoptionV1QuotationsInquireAskingPriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsInquireAskingPrice.json"),
+        check::<OverseasFutureoptionV1QuotationsInquireTimeFuturechartpriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsInquireTimeFuturechartprice.json"),
+        check::<OverseasFutureoptionV1QuotationsTickCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsTickCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsWeeklyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsWeeklyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsDailyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsDailyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsMonthlyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsMonthlyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsSearchContractDetailResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsSearchContractDetail.json"),
+        check::<OverseasFutureoptionV1QuotationsInvestorUnpdTrendResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsInvestorUnpdTrend.json"),
+        check::<OverseasFutureoptionV1QuotationsOptPriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptPrice.json"),
+        check::<OverseasFutureoptionV1QuotationsOptDetailResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptDetail.json"),
+        check::<OverseasFutureoptionV1QuotationsOptAskingPriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptAskingPrice.json"),
+        check::<OverseasFutureoptionV1QuotationsInquireTimeOptchartpriceResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsInquireTimeOptchartprice.json"),
+        check::<OverseasFutureoptionV1QuotationsOptTickCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptTickCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsOptDailyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptDailyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsOptWeeklyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptWeeklyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsOptMonthlyCcnlResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsOptMonthlyCcnl.json"),
+        check::<OverseasFutureoptionV1QuotationsSearchOptDetailResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsSearchOptDetail.json"),
+        check::<OverseasFutureoptionV1QuotationsMarketTimeResponse>("overseas_futureoption/OverseasFutureoptionV1QuotationsMarketTime.json"),
+    ]);
+}
+
+#[cfg(feature = "bond")]
+#[test]
+fn bond_responses() {
+    assert_all(&[
+        check::<DomesticBondV1TradingBuyResponse>("bond/DomesticBondV1TradingBuy.json"),
+        check::<DomesticBondV1TradingSellResponse>("bond/DomesticBondV1TradingSell.json"),
+        check::<DomesticBondV1TradingOrderRvsecnclResponse>(
+            "bond/DomesticBondV1TradingOrderRvsecncl.json",
+        ),
+        check::<DomesticBondV1TradingInquirePsblRvsecnclResponse>(
+            "bond/DomesticBondV1TradingInquirePsblRvsecncl.json",
+        ),
+        check::<DomesticBondV1TradingInquireDailyCcldResponse>(
+            "bond/DomesticBondV1TradingInquireDailyCcld.json",
+        ),
+        check::<DomesticBondV1TradingInquireBalanceResponse>(
+            "bond/DomesticBondV1TradingInquireBalance.json",
+        ),
+        check::<DomesticBondV1TradingInquirePsblOrderResponse>(
+            "bond/DomesticBondV1TradingInquirePsblOrder.json",
+        ),
+        check::<DomesticBondV1QuotationsInquireAskingPriceResponse>(
+            "bond/DomesticBondV1QuotationsInquireAskingPrice.json",
+        ),
+        check::<DomesticBondV1QuotationsInquirePriceResponse>(
+            "bond/DomesticBondV1QuotationsInquirePrice.json",
+        ),
+        check::<DomesticBondV1QuotationsInquireCcnlResponse>(
+            "bond/DomesticBondV1QuotationsInquireCcnl.json",
+        ),
+        check::<DomesticBondV1QuotationsInquireDailyPriceResponse>(
+            "bond/DomesticBondV1QuotationsInquireDailyPrice.json",
+        ),
+        check::<DomesticBondV1QuotationsInquireDailyItemchartpriceResponse>(
+            "bond/DomesticBondV1QuotationsInquireDailyItemchartprice.json",
+        ),
+        check::<DomesticBondV1QuotationsAvgUnitResponse>(
+            "bond/DomesticBondV1QuotationsAvgUnit.json",
+        ),
+        check::<DomesticBondV1QuotationsIssueInfoResponse>(
+            "bond/DomesticBondV1QuotationsIssueInfo.json",
+        ),
+        check::<DomesticBondV1QuotationsSearchBondInfoResponse>(
+            "bond/DomesticBondV1QuotationsSearchBondInfo.json",
+        ),
+    ]);
+}
+
+#[cfg(feature = "elw")]
+#[test]
+fn elw_responses() {
+    assert_all(&[
+        check::<ElwV1QuotationsNewlyListedResponse>("elw/ElwV1QuotationsNewlyListed.json"),
+        check::<ElwV1RankingSensitivityResponse>("elw/ElwV1RankingSensitivity.json"),
+        check::<ElwV1QuotationsUdrlAssetPriceResponse>("elw/ElwV1QuotationsUdrlAssetPrice.json"),
+        check::<ElwV1QuotationsCondSearchResponse>("elw/ElwV1QuotationsCondSearch.json"),
+        check::<ElwV1RankingQuickChangeResponse>("elw/ElwV1RankingQuickChange.json"),
+        check::<ElwV1QuotationsUdrlAssetListResponse>("elw/ElwV1QuotationsUdrlAssetList.json"),
+        check::<ElwV1QuotationsCompareStocksResponse>("elw/ElwV1QuotationsCompareStocks.json"),
+        check::<ElwV1QuotationsLpTradeTrendResponse>("elw/ElwV1QuotationsLpTradeTrend.json"),
+        check::<ElwV1QuotationsIndicatorTrendCcnlResponse>(
+            "elw/ElwV1QuotationsIndicatorTrendCcnl.json",
+        ),
+        check::<ElwV1QuotationsIndicatorTrendMinuteResponse>(
+            "elw/ElwV1QuotationsIndicatorTrendMinute.json",
+        ),
+        check::<ElwV1QuotationsIndicatorTrendDailyResponse>(
+            "elw/ElwV1QuotationsIndicatorTrendDaily.json",
+        ),
+        check::<ElwV1QuotationsVolatilityTrendTickResponse>(
+            "elw/ElwV1QuotationsVolatilityTrendTick.json",
+        ),
+        check::<ElwV1QuotationsVolatilityTrendCcnlResponse>(
+            "elw/ElwV1QuotationsVolatilityTrendCcnl.json",
+        ),
+        check::<ElwV1QuotationsVolatilityTrendDailyResponse>(
+            "elw/ElwV1QuotationsVolatilityTrendDaily.json",
+        ),
+        check::<ElwV1QuotationsSensitivityTrendCcnlResponse>(
+            "elw/ElwV1QuotationsSensitivityTrendCcnl.json",
+        ),
+        check::<ElwV1QuotationsVolatilityTrendMinuteResponse>(
+            "elw/ElwV1QuotationsVolatilityTrendMinute.json",
+        ),
+        check::<ElwV1QuotationsSensitivityTrendDailyResponse>(
+            "elw/ElwV1QuotationsSensitivityTrendDaily.json",
+        ),
+        check::<ElwV1QuotationsExpirationStocksResponse>(
+            "elw/ElwV1QuotationsExpirationStocks.json",
+        ),
+        check::<ElwV1RankingIndicatorResponse>("elw/ElwV1RankingIndicator.json"),
+        check::<ElwV1RankingUpdownRateResponse>("elw/ElwV1RankingUpdownRate.json"),
+        check::<ElwV1RankingVolumeRankResponse>("elw/ElwV1RankingVolumeRank.json"),
+    ]);
+}
+
+#[cfg(feature = "etfetn")]
+#[test]
+fn etfetn_responses() {
+    assert_all(&[
+        check::<EtfetnV1QuotationsInquirePriceResponse>(
+            "etfetn/EtfetnV1QuotationsInquirePrice.json",
+        ),
+        check::<EtfetnV1QuotationsInquireComponentStockPriceResponse>(
+            "etfetn/EtfetnV1QuotationsInquireComponentStockPrice.json",
+        ),
+        check::<EtfetnV1QuotationsNavComparisonTrendResponse>(
+            "etfetn/EtfetnV1QuotationsNavComparisonTrend.json",
+        ),
+        check::<EtfetnV1QuotationsNavComparisonDailyTrendResponse>(
+            "etfetn/EtfetnV1QuotationsNavComparisonDailyTrend.json",
+        ),
+        check::<EtfetnV1QuotationsNavComparisonTimeTrendResponse>(
+            "etfetn/EtfetnV1QuotationsNavComparisonTimeTrend.json",
+        ),
+    ]);
+}
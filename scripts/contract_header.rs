@@ -0,0 +1,146 @@
+// tests/contract.rs 템플릿 (직접 작성). generate_code.py가 이 주석 대신 생성 파일 헤더를 붙이고,
+// 아래에 모델 영역별 테스트를 이어 쓴다.
+//! 명세 기반 계약 테스트.
+//!
+//! `tests/fixtures/contract/`의 픽스처(응답 모델당 1개)를 `client::decode_response`로
+//! 역직렬화한다. KIS가 `output1`/`output2`를 배열, 단일 객체, 빈 객체(`{}`)로
+//! 섞어 보내므로 세 변형을 모두 검사한다.
+#![allow(unused_imports, dead_code)]
+
+use kis_api::client::decode_response;
+use kis_api::models::*;
+use kis_api::KisError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 픽스처에서 배열로 표기된 출력 그룹 (모델의 `Vec<T>` 필드)
+fn array_groups(body: &Map<String, Value>) -> Vec<String> {
+    body.iter()
+        .filter(|(k, v)| k.starts_with("output") && v.is_array())
+        .map(|(k, _)| k.clone())
+        .collect()
+}
+
+fn load(fixture: &str) -> Map<String, Value> {
+    let path = format!(
+        "{}/tests/fixtures/contract/{fixture}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    match serde_json::from_str(&text) {
+        Ok(Value::Object(map)) => map,
+        other => panic!("{path}: 객체가 아님 ({other:?})"),
+    }
+}
+
+/// 픽스처를 변형해 역직렬화하고, 배열 그룹의 항목 수와 필드 키를 검증한다.
+fn check_variant<R>(
+    fixture: &str,
+    variant: &str,
+    arrays: &[String],
+    body: Map<String, Value>,
+) -> Result<(), String>
+where
+    R: DeserializeOwned + Serialize + UnknownFields,
+{
+    let fail = |msg: String| format!("{fixture} [{variant}]: {msg}");
+    let resp: R = decode_response(Value::Object(body.clone())).map_err(|e| fail(e.to_string()))?;
+    let unknown = resp.unknown_fields();
+    if !unknown.is_empty() {
+        return Err(fail(format!("명세 밖 필드로 분류됨: {unknown:?}")));
+    }
+    let Value::Object(decoded) = serde_json::to_value(&resp).map_err(|e| fail(e.to_string()))?
+    else {
+        return Err(fail("객체로 직렬화되지 않음".into()));
+    };
+    for (key, sent) in &body {
+        if !key.starts_with("output") {
+            continue;
+        }
+        let sent_items: Vec<&Value> = match sent {
+            Value::Array(items) => items.iter().collect(),
+            Value::Object(obj) if obj.is_empty() => vec![],
+            other => vec![other],
+        };
+        let got_items: Vec<&Value> = match decoded.get(key) {
+            Some(Value::Array(items)) => items.iter().collect(),
+            Some(item @ Value::Object(_)) => vec![item],
+            _ => vec![],
+        };
+        if arrays.contains(key) && sent_items.len() != got_items.len() {
+            return Err(fail(format!(
+                "{key}: 항목 {}개 기대, {}개 수신",
+                sent_items.len(),
+                got_items.len()
+            )));
+        }
+        for (sent, got) in sent_items.iter().zip(&got_items) {
+            let (Value::Object(sent), Value::Object(got)) = (sent, got) else {
+                continue;
+            };
+            if let Some(missing) = sent.keys().find(|k| !got.contains_key(*k)) {
+                return Err(fail(format!("{key}.{missing}: 모델에 매핑되지 않음")));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 배열 / 단일 객체 / 빈 객체 세 변형을 모두 검사
+fn check<R>(fixture: &str) -> Result<(), String>
+where
+    R: DeserializeOwned + Serialize + UnknownFields,
+{
+    let body = load(fixture);
+    let arrays = array_groups(&body);
+    check_variant::<R>(fixture, "array", &arrays, body.clone())?;
+
+    let mut single = body.clone();
+    for key in &arrays {
+        if let Some(Value::Array(items)) = single.get_mut(key) {
+            if !items.is_empty() {
+                let first = items.remove(0);
+                single.insert(key.clone(), first);
+            }
+        }
+    }
+    check_variant::<R>(fixture, "single", &arrays, single)?;
+
+    let mut empty = body;
+    for (key, value) in empty.iter_mut() {
+        if key.starts_with("output") {
+            *value = Value::Object(Map::new());
+        }
+    }
+    check_variant::<R>(fixture, "empty", &arrays, empty)
+}
+
+fn assert_all(results: &[Result<(), String>]) {
+    let failures: Vec<&str> = results
+        .iter()
+        .filter_map(|r| r.as_ref().err().map(String::as_str))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{}개 모델 실패:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn error_header_is_not_deserialized_as_success() {
+    let body = serde_json::json!({
+        "rt_cd": "1",
+        "msg_cd": "EGW00123",
+        "msg1": "기간이 만료된 token 입니다."
+    });
+    match decode_response::<Oauth2ApprovalResponse>(body) {
+        Err(KisError::Api { rt_cd, msg_cd, .. }) => {
+            assert_eq!(rt_cd, "1");
+            assert_eq!(msg_cd, "EGW00123");
+        }
+        other => panic!("KisError::Api 기대, {other:?}"),
+    }
+}